@@ -29,6 +29,7 @@ type Manager struct {
 	i18nMgr         *i18n.Manager        // Internationalization manager
 	usageStore      *UsageStore          // Usage tracking store
 	sessionID       string               // Current session ID for usage tracking
+	connectionName  string               // Connection prompts are recorded against
 	idGen           *utils.IDGen
 }
 
@@ -262,6 +263,12 @@ func (m *Manager) SetLanguage(language string) error {
 	return config.SaveConfig(m.config, m.configDir, m.i18nMgr)
 }
 
+// SetDisplay updates the result display settings
+func (m *Manager) SetDisplay(display config.DisplayConfig) error {
+	m.config.Display = display
+	return config.SaveConfig(m.config, m.configDir, m.i18nMgr)
+}
+
 // GenerateSystemPrompt creates a system prompt with database context
 func (m *Manager) GenerateSystemPrompt(tables []string, currentTable string) string {
 	var prompt strings.Builder
@@ -343,6 +350,7 @@ func (m *Manager) addToPromptHistory(userMessage, systemPrompt, aiResponse strin
 		AIResponse:   aiResponse,
 		Provider:     m.config.AI.Provider,
 		Model:        m.config.AI.Model,
+		Connection:   m.connectionName,
 		InputTokens:  inputTokens,
 		OutputTokens: outputTokens,
 		Cost:         cost,
@@ -398,6 +406,7 @@ func (m *Manager) InitializeVectorStore(connectionName string, connection core.C
 	if m.vectorStore != nil {
 		m.vectorStore.Close()
 	}
+	m.connectionName = connectionName
 
 	vectorStore, err := NewVectorStore(m.configDir, connectionName, connection)
 	if err != nil {
@@ -426,6 +435,7 @@ func (m *Manager) InitializeVectorStore(connectionName string, connection core.C
 
 // CloseVectorStore closes the vector store
 func (m *Manager) CloseVectorStore() error {
+	m.connectionName = ""
 	if m.vectorStore != nil {
 		err := m.vectorStore.Close()
 		m.vectorStore = nil
@@ -76,6 +76,7 @@ type PromptEntry struct {
 	AIResponse   string          `json:"ai_response"`
 	Provider     config.Provider `json:"provider"`
 	Model        string          `json:"model"`
+	Connection   string          `json:"connection,omitempty"`
 	InputTokens  int             `json:"input_tokens"`
 	OutputTokens int             `json:"output_tokens"`
 	Cost         float64         `json:"cost"`
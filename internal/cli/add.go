@@ -0,0 +1,92 @@
+package cli
+
+import (
+	"errors"
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+
+	"sqlterm/internal/config"
+	"sqlterm/internal/core"
+)
+
+// passwordEnv holds the password for sqlterm add when --password is not
+// given and there is no terminal to ask on, e.g. in setup scripts
+const passwordEnv = "SQLTERM_PASSWORD"
+
+// passwordSource is where sqlterm add can find a password, in the order it looks
+type passwordSource struct {
+	// flag is the --password value; flagSet tells an empty --password from none
+	flag    string
+	flagSet bool
+	// prompt asks on the terminal without echo; nil when input is not a terminal
+	prompt func() (string, error)
+	getenv func(string) string
+}
+
+// password returns --password when given, else what the terminal prompt
+// answers, else $SQLTERM_PASSWORD
+func (s passwordSource) password() (string, error) {
+	if s.flagSet {
+		return s.flag, nil
+	}
+	if s.prompt != nil {
+		password, err := s.prompt()
+		if err != nil {
+			return "", fmt.Errorf("failed to read the password: %w", err)
+		}
+		return password, nil
+	}
+	return s.getenv(passwordEnv), nil
+}
+
+// sqliteDatabasePath resolves the database file of a new SQLite connection to
+// an absolute path, so the connection works from any directory, and checks
+// the directory it goes in exists. :memory: and file: URIs are kept as given.
+func sqliteDatabasePath(database string) (string, error) {
+	path := core.SQLitePath(database)
+	if path == "" {
+		return "", errors.New("a SQLite connection needs the path of its database file")
+	}
+	if path == ":memory:" || strings.HasPrefix(path, "file:") {
+		return path, nil
+	}
+
+	path, err := filepath.Abs(path)
+	if err != nil {
+		return "", fmt.Errorf("failed to resolve %s: %w", database, err)
+	}
+	dir := filepath.Dir(path)
+	if info, err := os.Stat(dir); err != nil || !info.IsDir() {
+		return "", fmt.Errorf("directory %s does not exist; create it first or choose another path for the database file", dir)
+	}
+	return path, nil
+}
+
+// templateConnection fills in the connection template named template for
+// sqlterm add --from-template. args are name=value pairs for its
+// placeholders, optionally after the name to save the connection under in
+// place of the one the template gives.
+func templateConnection(configMgr *config.Manager, template string, args []string) (*core.ConnectionConfig, error) {
+	t, err := configMgr.LoadTemplate(template)
+	if err != nil {
+		return nil, err
+	}
+	name := ""
+	if len(args) > 0 && !strings.Contains(args[0], "=") {
+		name, args = args[0], args[1:]
+	}
+	values, err := config.ParseTemplateValues(args)
+	if err != nil {
+		return nil, err
+	}
+	conn, err := t.Expand(values)
+	if err != nil {
+		return nil, err
+	}
+	if name != "" {
+		conn.Name = name
+	}
+	return conn, nil
+}
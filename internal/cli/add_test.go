@@ -0,0 +1,127 @@
+package cli
+
+import (
+	"errors"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"sqlterm/internal/config"
+	"sqlterm/internal/core"
+)
+
+func TestSQLiteDatabasePath(t *testing.T) {
+	dir := t.TempDir()
+	t.Chdir(dir)
+	home, err := os.UserHomeDir()
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+
+	tests := []struct {
+		name     string
+		database string
+		expected string
+	}{
+		{"relative", "app.db", filepath.Join(dir, "app.db")},
+		{"relative with dots", "./data/../app.db", filepath.Join(dir, "app.db")},
+		{"absolute", filepath.Join(dir, "app.db"), filepath.Join(dir, "app.db")},
+		{"home", "~/app.db", filepath.Join(home, "app.db")},
+		{"quoted", `"app.db"`, filepath.Join(dir, "app.db")},
+		{"memory", ":memory:", ":memory:"},
+		{"URI", "file:app.db?mode=ro", "file:app.db?mode=ro"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got, err := sqliteDatabasePath(tt.database)
+			if err != nil {
+				t.Fatalf("Unexpected error: %v", err)
+			}
+			if got != tt.expected {
+				t.Errorf("Expected %s, got %s", tt.expected, got)
+			}
+		})
+	}
+
+	if _, err := sqliteDatabasePath(filepath.Join("missing", "app.db")); err == nil || !strings.Contains(err.Error(), "does not exist") {
+		t.Errorf("Expected a missing directory to be refused, got %v", err)
+	}
+	if _, err := sqliteDatabasePath(""); err == nil {
+		t.Error("Expected an empty path to be refused")
+	}
+}
+
+func TestPasswordSource(t *testing.T) {
+	getenv := func(key string) string {
+		if key == passwordEnv {
+			return "from-env"
+		}
+		return ""
+	}
+	prompt := func() (string, error) { return "typed", nil }
+
+	tests := []struct {
+		name     string
+		source   passwordSource
+		expected string
+	}{
+		{"flag first", passwordSource{flag: "from-flag", flagSet: true, prompt: prompt, getenv: getenv}, "from-flag"},
+		{"empty flag means no password", passwordSource{flag: "", flagSet: true, prompt: prompt, getenv: getenv}, ""},
+		{"prompt on a terminal", passwordSource{prompt: prompt, getenv: getenv}, "typed"},
+		{"environment otherwise", passwordSource{getenv: getenv}, "from-env"},
+		{"nothing", passwordSource{getenv: func(string) string { return "" }}, ""},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got, err := tt.source.password()
+			if err != nil {
+				t.Fatalf("Unexpected error: %v", err)
+			}
+			if got != tt.expected {
+				t.Errorf("Expected %q, got %q", tt.expected, got)
+			}
+		})
+	}
+
+	failing := passwordSource{prompt: func() (string, error) { return "", errors.New("interrupted") }, getenv: getenv}
+	if _, err := failing.password(); err == nil {
+		t.Error("Expected a failed prompt to be reported")
+	}
+}
+
+func TestTemplateConnection(t *testing.T) {
+	dir := t.TempDir()
+	config.SetConfigDirOverride(dir)
+	defer config.SetConfigDirOverride("")
+
+	configMgr := config.NewManager()
+	path := configMgr.TemplatePath("tenant")
+	if err := os.MkdirAll(filepath.Dir(path), 0755); err != nil {
+		t.Fatal(err)
+	}
+	data := "database_type: postgres\nhost: db.internal\nport: 5432\ndatabase: app_{tenant}\nusername: app\n"
+	if err := os.WriteFile(path, []byte(data), 0600); err != nil {
+		t.Fatal(err)
+	}
+
+	conn, err := templateConnection(configMgr, "tenant", []string{"tenant=acme"})
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if conn.Name != "tenant-acme" || conn.Database != "app_acme" || conn.DatabaseType != core.PostgreSQL {
+		t.Errorf("Unexpected connection %+v", conn)
+	}
+
+	if conn, err := templateConnection(configMgr, "tenant", []string{"acme-prod", "tenant=acme"}); err != nil || conn.Name != "acme-prod" {
+		t.Errorf("Expected the given name to be kept, got %+v, %v", conn, err)
+	}
+	if _, err := templateConnection(configMgr, "tenant", nil); !errors.Is(err, config.ErrTemplateValues) || !strings.Contains(err.Error(), "tenant=<tenant>") {
+		t.Errorf("Expected the missing value to be named, got %v", err)
+	}
+	if _, err := templateConnection(configMgr, "missing", []string{"tenant=acme"}); err == nil {
+		t.Error("Expected an unknown template to fail")
+	}
+}
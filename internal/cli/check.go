@@ -0,0 +1,91 @@
+package cli
+
+import (
+	"fmt"
+	"os"
+
+	"sqlterm/internal/config"
+	"sqlterm/internal/core"
+	"sqlterm/internal/i18n"
+
+	"github.com/spf13/cobra"
+	"golang.org/x/term"
+)
+
+var checkCmd = &cobra.Command{
+	Use:               "check [connection...]",
+	Short:             "", // Will be set in init()
+	Long:              "", // Will be set in init()
+	ValidArgsFunction: completeConnectionArgs,
+	SilenceUsage:      true,
+	RunE: func(cmd *cobra.Command, args []string) error {
+		timeout, _ := cmd.Flags().GetDuration("timeout")
+
+		configs, err := loadCheckConnections(config.NewManager(), args)
+		if err != nil {
+			return err
+		}
+
+		i18nMgr, err := i18n.NewManager("en_au")
+		if err != nil {
+			return err
+		}
+
+		results := core.CheckConnections(configs, core.NewConnection, core.DefaultCheckConcurrency, timeout)
+		if out := outputSink(cmd); out.JSON() {
+			for _, result := range results {
+				if err := out.Document(result.Record()); err != nil {
+					return err
+				}
+			}
+			return checkFailures(results)
+		}
+		color := os.Getenv("NO_COLOR") == "" && term.IsTerminal(int(os.Stdout.Fd()))
+		if err := core.WriteCheckResults(cmd.OutOrStdout(), results, color, i18nMgr); err != nil {
+			return err
+		}
+		return checkFailures(results)
+	},
+}
+
+// loadCheckConnections loads the named connections, or every saved one when none are named
+func loadCheckConnections(configMgr *config.Manager, names []string) ([]*core.ConnectionConfig, error) {
+	if len(names) == 0 {
+		configs, err := configMgr.ListConnections()
+		if err != nil {
+			return nil, fmt.Errorf("failed to load connections: %w", err)
+		}
+		if len(configs) == 0 {
+			return nil, fmt.Errorf("no saved connections to check")
+		}
+		return configs, nil
+	}
+
+	configs := make([]*core.ConnectionConfig, 0, len(names))
+	for _, name := range names {
+		connConfig, err := configMgr.LoadConnection(name)
+		if err != nil {
+			return nil, fmt.Errorf("failed to load connection %s: %w", name, err)
+		}
+		configs = append(configs, connConfig)
+	}
+	return configs, nil
+}
+
+// checkFailures returns an error when any check failed, so the exit code can be scripted on
+func checkFailures(results []core.CheckResult) error {
+	failed := 0
+	for _, result := range results {
+		if result.Err != nil {
+			failed++
+		}
+	}
+	if failed > 0 {
+		return fmt.Errorf("%d of %d connections failed", failed, len(results))
+	}
+	return nil
+}
+
+func init() {
+	checkCmd.Flags().Duration("timeout", core.DefaultCheckTimeout, "How long each connection may take to connect and answer")
+}
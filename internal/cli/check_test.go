@@ -0,0 +1,30 @@
+package cli
+
+import (
+	"errors"
+	"testing"
+
+	"sqlterm/internal/core"
+)
+
+func TestCheckFailures(t *testing.T) {
+	ok := core.CheckResult{Config: &core.ConnectionConfig{Name: "local"}}
+	failed := core.CheckResult{Config: &core.ConnectionConfig{Name: "prod"}, Err: errors.New("refused")}
+
+	tests := []struct {
+		name    string
+		results []core.CheckResult
+		wantErr bool
+	}{
+		{"all reachable", []core.CheckResult{ok, ok}, false},
+		{"one failed", []core.CheckResult{ok, failed}, true},
+		{"nothing checked", nil, false},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if err := checkFailures(tt.results); (err != nil) != tt.wantErr {
+				t.Errorf("checkFailures() error = %v, wantErr %v", err, tt.wantErr)
+			}
+		})
+	}
+}
@@ -0,0 +1,123 @@
+package cli
+
+import (
+	"fmt"
+	"io"
+	"slices"
+	"strings"
+
+	"sqlterm/internal/config"
+
+	"github.com/spf13/cobra"
+)
+
+var completionsCmd = &cobra.Command{
+	Use:       "completions <bash|zsh|fish|powershell>",
+	Short:     "", // Will be set in init()
+	Args:      cobra.MatchAll(cobra.ExactArgs(1), cobra.OnlyValidArgs),
+	ValidArgs: []string{"bash", "zsh", "fish", "powershell"},
+	RunE: func(cmd *cobra.Command, args []string) error {
+		return writeCompletionScript(cmd.OutOrStdout(), args[0])
+	},
+}
+
+// completeConnectionsCmd prints saved connection names, one per line, for shell scripts
+var completeConnectionsCmd = &cobra.Command{
+	Use:    "__complete-connections",
+	Hidden: true,
+	Args:   cobra.NoArgs,
+	RunE: func(cmd *cobra.Command, args []string) error {
+		names, err := connectionNames()
+		if err != nil {
+			return err
+		}
+		for _, name := range names {
+			fmt.Fprintln(cmd.OutOrStdout(), name)
+		}
+		return nil
+	},
+}
+
+// writeCompletionScript writes the completion script for shell to w
+func writeCompletionScript(w io.Writer, shell string) error {
+	switch shell {
+	case "bash":
+		return rootCmd.GenBashCompletionV2(w, true)
+	case "zsh":
+		return rootCmd.GenZshCompletion(w)
+	case "fish":
+		return rootCmd.GenFishCompletion(w, true)
+	case "powershell":
+		return rootCmd.GenPowerShellCompletionWithDesc(w)
+	default:
+		return fmt.Errorf("unsupported shell %q", shell)
+	}
+}
+
+// connectionNames returns the names of saved connections
+func connectionNames() ([]string, error) {
+	connections, err := config.NewManager().ListConnections()
+	if err != nil {
+		return nil, err
+	}
+
+	names := make([]string, len(connections))
+	for i, conn := range connections {
+		names[i] = conn.Name
+	}
+	return names, nil
+}
+
+// completeConnectionArg completes the first argument with saved connection names
+func completeConnectionArg(cmd *cobra.Command, args []string, toComplete string) ([]string, cobra.ShellCompDirective) {
+	if len(args) > 0 {
+		return nil, cobra.ShellCompDirectiveNoFileComp
+	}
+	names, err := connectionNames()
+	if err != nil {
+		return nil, cobra.ShellCompDirectiveError
+	}
+	return names, cobra.ShellCompDirectiveNoFileComp
+}
+
+// completeConnectionArgs completes every argument with saved connection names not already given
+func completeConnectionArgs(cmd *cobra.Command, args []string, toComplete string) ([]string, cobra.ShellCompDirective) {
+	names, err := connectionNames()
+	if err != nil {
+		return nil, cobra.ShellCompDirectiveError
+	}
+	return slices.DeleteFunc(names, func(name string) bool { return slices.Contains(args, name) }), cobra.ShellCompDirectiveNoFileComp
+}
+
+// completeTemplateNames completes the --from-template flag with connection template names
+func completeTemplateNames(cmd *cobra.Command, args []string, toComplete string) ([]string, cobra.ShellCompDirective) {
+	templates, err := config.NewManager().ListTemplates()
+	if err != nil {
+		return nil, cobra.ShellCompDirectiveError
+	}
+	names := make([]string, len(templates))
+	for i, template := range templates {
+		names[i] = template.Name
+	}
+	return names, cobra.ShellCompDirectiveNoFileComp
+}
+
+// completeTemplateValues completes the arguments of sqlterm add --from-template
+// with name= for each placeholder of the template not given yet
+func completeTemplateValues(cmd *cobra.Command, args []string, toComplete string) ([]string, cobra.ShellCompDirective) {
+	name, _ := cmd.Flags().GetString("from-template")
+	if name == "" || strings.Contains(toComplete, "=") {
+		return nil, cobra.ShellCompDirectiveDefault
+	}
+	template, err := config.NewManager().LoadTemplate(name)
+	if err != nil {
+		return nil, cobra.ShellCompDirectiveError
+	}
+	var candidates []string
+	for _, placeholder := range template.Placeholders {
+		if !slices.ContainsFunc(args, func(arg string) bool { return strings.HasPrefix(arg, placeholder+"=") }) {
+			candidates = append(candidates, placeholder+"=")
+		}
+	}
+	return candidates, cobra.ShellCompDirectiveNoFileComp | cobra.ShellCompDirectiveNoSpace
+}
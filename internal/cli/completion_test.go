@@ -0,0 +1,57 @@
+package cli
+
+import (
+	"bytes"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"sqlterm/internal/config"
+)
+
+func TestWriteCompletionScript(t *testing.T) {
+	for _, shell := range []string{"bash", "zsh", "fish", "powershell"} {
+		t.Run(shell, func(t *testing.T) {
+			var buf bytes.Buffer
+			if err := writeCompletionScript(&buf, shell); err != nil {
+				t.Fatalf("Unexpected error: %v", err)
+			}
+			if !strings.Contains(buf.String(), "sqlterm") {
+				t.Errorf("Expected a completion script for sqlterm, got %d bytes", buf.Len())
+			}
+		})
+	}
+
+	if err := writeCompletionScript(&bytes.Buffer{}, "tcsh"); err == nil {
+		t.Error("Expected an error for an unsupported shell")
+	}
+}
+
+func TestCompleteConnectionsCmd(t *testing.T) {
+	dir := t.TempDir()
+	config.SetConfigDirOverride(dir)
+	defer config.SetConfigDirOverride("")
+
+	connectionsDir := filepath.Join(dir, "connections")
+	if err := os.MkdirAll(connectionsDir, 0755); err != nil {
+		t.Fatal(err)
+	}
+	for _, name := range []string{"local", "prod"} {
+		data := "name: " + name + "\ndatabase_type: 2\ndatabase: " + name + ".db\n"
+		if err := os.WriteFile(filepath.Join(connectionsDir, name+".yaml"), []byte(data), 0600); err != nil {
+			t.Fatal(err)
+		}
+	}
+
+	var buf bytes.Buffer
+	completeConnectionsCmd.SetOut(&buf)
+	defer completeConnectionsCmd.SetOut(nil)
+	if err := completeConnectionsCmd.RunE(completeConnectionsCmd, nil); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+
+	if got := buf.String(); got != "local\nprod\n" {
+		t.Errorf("Expected the saved connection names, got %q", got)
+	}
+}
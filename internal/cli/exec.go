@@ -0,0 +1,242 @@
+package cli
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"io"
+	"os"
+	"slices"
+	"strings"
+	"time"
+
+	"sqlterm/internal/ai"
+	"sqlterm/internal/config"
+	"sqlterm/internal/core"
+
+	"github.com/spf13/cobra"
+	"golang.org/x/term"
+)
+
+var execCmd = &cobra.Command{
+	Use:               "exec <connection> [query]",
+	Short:             "", // Will be set in init()
+	Long:              "", // Will be set in init()
+	Args:              cobra.RangeArgs(1, 2),
+	ValidArgsFunction: completeConnectionArg,
+	RunE: func(cmd *cobra.Command, args []string) error {
+		format, _ := cmd.Flags().GetString("format")
+		noHeader, _ := cmd.Flags().GetBool("no-header")
+		opts := execOptions{format: format, header: !noHeader}
+		opts.outputPath, _ = cmd.Flags().GetString("output")
+		if cmd.Flags().Changed("null-string") {
+			nullString, _ := cmd.Flags().GetString("null-string")
+			opts.nullString = &nullString
+		}
+		opts.yes, _ = cmd.Flags().GetBool("yes")
+		opts.withMetadata, _ = cmd.Flags().GetBool("with-metadata")
+		opts.noMetadata, _ = cmd.Flags().GetBool("no-metadata")
+		opts.singleTransaction, _ = cmd.Flags().GetBool("single-transaction")
+		if outputSink(cmd).JSON() && !cmd.Flags().Changed("format") {
+			opts.format = core.ExportJSON
+		}
+
+		query := ""
+		if len(args) > 1 && args[1] != "-" {
+			query = args[1]
+		} else {
+			input, err := io.ReadAll(cmd.InOrStdin())
+			if err != nil {
+				return fmt.Errorf("failed to read query: %w", err)
+			}
+			query = string(input)
+		}
+
+		return runExec(cmd, args[0], query, opts)
+	},
+}
+
+// execOptions are the exec flags; sqlterm -e --exit runs with their
+// defaults. nullString is only set when --null-string is given.
+type execOptions struct {
+	format            string
+	header            bool
+	outputPath        string
+	nullString        *string
+	yes               bool
+	withMetadata      bool
+	noMetadata        bool
+	singleTransaction bool
+}
+
+// runExec runs the statements in query on the saved connection name and
+// writes their rows to stdout or opts.outputPath, as sqlterm exec does
+func runExec(cmd *cobra.Command, name, query string, opts execOptions) error {
+	if opts.withMetadata && opts.noMetadata {
+		return errors.New("--with-metadata and --no-metadata cannot be used together")
+	}
+	if !slices.Contains(core.ExportFormats, opts.format) {
+		return fmt.Errorf("unknown format %q (expected one of %s)", opts.format, strings.Join(core.ExportFormats, ", "))
+	}
+
+	configMgr := config.NewManager()
+	connConfig, err := configMgr.LoadConnection(name)
+	if err != nil {
+		return fmt.Errorf("failed to load connection %s: %w", name, err)
+	}
+
+	// A read-only project refuses writes whatever the flags, as conversation mode does
+	if cwd, err := os.Getwd(); err == nil {
+		project, err := config.FindProject(cwd)
+		if err != nil {
+			return fmt.Errorf("failed to read project settings: %w", err)
+		}
+		if project != nil && project.ReadOnly {
+			if !core.IsReadOnlyQuery(connConfig.DatabaseType, query) {
+				return fmt.Errorf("%w: the project at %s is read-only and the query modifies data", core.ErrReadOnly, project.Root)
+			}
+			connConfig.ReadOnly = true
+		}
+	}
+
+	// Nobody is there to confirm, so writes need --yes on connections that confirm them
+	if connConfig.ConfirmsDestructive() && !opts.yes && !core.IsReadOnlyQuery(connConfig.DatabaseType, query) {
+		return fmt.Errorf("%s confirms statements that modify data; pass --yes to run them", connConfig.Name)
+	}
+
+	// Keep stdout open when the writer is closed
+	out := io.Writer(struct{ io.Writer }{cmd.OutOrStdout()})
+	if opts.outputPath != "" {
+		file, err := os.Create(core.ExpandPath(opts.outputPath))
+		if err != nil {
+			return fmt.Errorf("failed to create output file: %w", err)
+		}
+		out = file
+	}
+
+	writer, err := core.NewRowWriter(out, opts.format, nil)
+	if err != nil {
+		return err
+	}
+	if delimited, ok := writer.(*core.DelimitedWriter); ok && opts.nullString != nil {
+		delimited.NullString = *opts.nullString
+	}
+
+	// Count rows on stderr while a long export runs, unless output is for scripts
+	var progress core.ExportProgress
+	if opts.outputPath != "" && outputSink(cmd).Mode == core.OutputText && term.IsTerminal(int(os.Stderr.Fd())) {
+		progress = func(rows int) {
+			fmt.Fprintf(os.Stderr, "\r%d rows written to %s", rows, opts.outputPath)
+		}
+	}
+
+	// Without either flag, export_metadata in config.yaml decides
+	var meta *core.ExportMetadata
+	if opts.withMetadata || (!opts.noMetadata && exportMetadataDefault(configMgr)) {
+		meta = &core.ExportMetadata{
+			Connection: connConfig.Name,
+			Database:   connConfig.Database,
+			ExportedAt: time.Now().UTC(),
+			Version:    Version,
+		}
+	}
+
+	// JSON rows are keyed by column name, so they always need the header
+	execErr := execStatements(configMgr, connConfig, query, writer, opts.header || opts.format == core.ExportJSON, meta, progress, opts.singleTransaction)
+	if progress != nil {
+		fmt.Fprint(os.Stderr, "\r\033[K")
+	}
+	if err := writer.Close(); err != nil && execErr == nil {
+		execErr = fmt.Errorf("failed to write output: %w", err)
+	}
+	return execErr
+}
+
+// execStatements runs each statement in query and streams its rows to writer,
+// calling progress as rows are written when it is not nil. With meta, each
+// result is surrounded by metadata naming its statement. With
+// singleTransaction the statements run in one transaction that a failure
+// rolls back.
+func execStatements(configMgr *config.Manager, connConfig *core.ConnectionConfig, query string, writer core.RowWriter, header bool, meta *core.ExportMetadata, progress core.ExportProgress, singleTransaction bool) error {
+	statements := core.SplitStatements(connConfig.DatabaseType, query)
+	if len(statements) == 0 {
+		return errors.New("no query given")
+	}
+
+	conn, err := core.NewConnection(connConfig)
+	if err != nil {
+		return fmt.Errorf("failed to connect: %w", err)
+	}
+	if connConfig.AuditLog {
+		audited := core.NewAuditedConnection(conn, connConfig, core.NewAuditLog(configMgr.AuditLogPath(connConfig), core.DefaultAuditLogMaxSize))
+		if aiManager, err := ai.NewManager(configMgr.GetConfigDir()); err == nil && aiManager != nil {
+			audited.Redact = aiManager.GetConfig().RedactHistory
+		}
+		conn = audited
+	}
+	defer conn.Close()
+
+	stream := func(statement string, result *core.QueryResult) error {
+		var statementMeta *core.ExportMetadata
+		if meta != nil && len(result.Columns) > 0 {
+			copied := *meta
+			copied.Query = statement
+			statementMeta = &copied
+		}
+		_, err := core.StreamResultWithMetadata(result, writer, header && len(result.Columns) > 0, statementMeta, progress)
+		return err
+	}
+
+	if singleTransaction {
+		for i, statement := range statements {
+			if core.BreaksTransaction(connConfig.DatabaseType, statement) {
+				fmt.Fprintf(os.Stderr, "warning: statement %d commits or cannot run inside a transaction, so it may not be rolled back: %s\n", i+1, truncateStatement(statement))
+			}
+		}
+		err := core.RunInTransaction(context.Background(), conn, statements, func(i int, result *core.QueryResult) error {
+			return stream(statements[i], result)
+		})
+		var statementErr *core.StatementError
+		if errors.As(err, &statementErr) {
+			return core.WithErrorPointer(fmt.Errorf("query failed: %s: %w", truncateStatement(statementErr.Query), err), statementErr.Query)
+		}
+		return err
+	}
+
+	for _, statement := range statements {
+		result, err := conn.ExecuteContext(context.Background(), statement)
+		if err != nil {
+			return core.WithErrorPointer(fmt.Errorf("query failed: %s: %w", truncateStatement(statement), err), statement)
+		}
+		if err := stream(statement, result); err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+// exportMetadataDefault reports whether config.yaml turns export metadata on
+func exportMetadataDefault(configMgr *config.Manager) bool {
+	aiManager, err := ai.NewManager(configMgr.GetConfigDir())
+	return err == nil && aiManager != nil && aiManager.GetConfig().Display.ExportMetadata
+}
+
+// truncateStatement shortens statement to its first line for error messages
+func truncateStatement(statement string) string {
+	first, _, more := strings.Cut(statement, "\n")
+	if more || len(first) > 60 {
+		return strings.TrimSpace(first[:min(len(first), 60)]) + "..."
+	}
+	return first
+}
+
+func init() {
+	execCmd.Flags().StringP("format", "f", core.ExportTSV, "Output format ("+strings.Join(core.ExportFormats, ", ")+")")
+	execCmd.Flags().Bool("no-header", false, "Omit the row of column names (ignored for json)")
+	execCmd.Flags().StringP("output", "o", "", "Write results to a file instead of stdout")
+	execCmd.Flags().String("null-string", core.DefaultNullString, "Text written for NULL in tsv and null output")
+	execCmd.Flags().Bool("with-metadata", false, "Start each result with its query, connection and time (# comments in csv and tsv, a _metadata line in json)")
+	execCmd.Flags().Bool("no-metadata", false, "Leave metadata out even when export_metadata is on in config.yaml")
+	execCmd.Flags().Bool("yes", false, "Run statements that modify data on connections that confirm them")
+	execCmd.Flags().Bool("single-transaction", false, "Run every statement in one transaction, rolling back all of them if one fails")
+}
@@ -0,0 +1,42 @@
+//go:build !no_sqlite
+
+package cli
+
+import (
+	"bytes"
+	"errors"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"sqlterm/internal/config"
+	"sqlterm/internal/core"
+
+	"github.com/spf13/cobra"
+)
+
+func TestRunExec_ReadOnlyProject(t *testing.T) {
+	dir := t.TempDir()
+	useConfigDir(t, &core.ConnectionConfig{Name: "local", DatabaseType: core.SQLite, Database: filepath.Join(dir, "app.db")})
+	if err := os.WriteFile(filepath.Join(dir, config.ProjectFileName), []byte("read_only: true\n"), 0644); err != nil {
+		t.Fatal(err)
+	}
+	t.Chdir(dir)
+
+	var out bytes.Buffer
+	cmd := &cobra.Command{}
+	cmd.SetOut(&out)
+	opts := execOptions{format: core.ExportCSV, header: true, yes: true}
+
+	err := runExec(cmd, "local", "CREATE TABLE t (id INTEGER)", opts)
+	if !errors.Is(err, core.ErrReadOnly) {
+		t.Fatalf("Expected the read-only project to refuse the statement even with --yes, got %v", err)
+	}
+	if err := runExec(cmd, "local", "SELECT 1 AS one", opts); err != nil {
+		t.Fatalf("Expected a read to run, got %v", err)
+	}
+	if !strings.Contains(out.String(), "one") {
+		t.Errorf("Expected the query's rows, got %q", out.String())
+	}
+}
@@ -0,0 +1,98 @@
+package cli
+
+import (
+	"fmt"
+	"io"
+	"os"
+	"slices"
+	"strconv"
+	"strings"
+	"time"
+
+	"sqlterm/internal/config"
+	"sqlterm/internal/core"
+
+	"github.com/spf13/cobra"
+)
+
+var historyCmd = &cobra.Command{
+	Use:   "history",
+	Short: "", // Will be set in init()
+}
+
+var historyExportCmd = &cobra.Command{
+	Use:          "export",
+	Short:        "", // Will be set in init()
+	Long:         "", // Will be set in init()
+	Args:         cobra.NoArgs,
+	SilenceUsage: true,
+	RunE: func(cmd *cobra.Command, args []string) error {
+		format, _ := cmd.Flags().GetString("format")
+		sinceFlag, _ := cmd.Flags().GetString("since")
+		outputPath, _ := cmd.Flags().GetString("output")
+		if outputSink(cmd).JSON() && !cmd.Flags().Changed("format") {
+			format = core.ExportJSON
+		}
+		if !slices.Contains(core.ExportFormats, format) {
+			return fmt.Errorf("unknown format %q (expected one of %s)", format, strings.Join(core.ExportFormats, ", "))
+		}
+
+		var since time.Time
+		if sinceFlag != "" {
+			var err error
+			if since, err = parseSince(sinceFlag, time.Now()); err != nil {
+				return err
+			}
+		}
+
+		configMgr := config.NewManager()
+		store := core.LoadResultStore(configMgr.ResultHistoryPath(), core.DefaultResultCapacity)
+		export := core.HistoryExport(store.Entries(), since)
+
+		// Keep stdout open when the writer is closed
+		out := io.Writer(struct{ io.Writer }{cmd.OutOrStdout()})
+		if outputPath != "" {
+			file, err := os.Create(core.ExpandPath(outputPath))
+			if err != nil {
+				return fmt.Errorf("failed to create output file: %w", err)
+			}
+			out = file
+		}
+		writer, err := core.NewRowWriter(out, format, nil)
+		if err != nil {
+			return err
+		}
+		_, err = core.StreamResult(core.NewStaticQueryResult(export.Columns, export.Rows), writer, true, nil)
+		if closeErr := writer.Close(); closeErr != nil && err == nil {
+			err = fmt.Errorf("failed to write output: %w", closeErr)
+		}
+		return err
+	},
+}
+
+// parseSince reads --since as an age before now, such as 7d, 2w or 36h, or
+// as a date such as 2026-10-01
+func parseSince(value string, now time.Time) (time.Time, error) {
+	if date, err := time.ParseInLocation(time.DateOnly, value, now.Location()); err == nil {
+		return date, nil
+	}
+
+	units := map[byte]time.Duration{'d': 24 * time.Hour, 'w': 7 * 24 * time.Hour}
+	if unit, ok := units[value[len(value)-1]]; ok {
+		n, err := strconv.Atoi(value[:len(value)-1])
+		if err == nil && n >= 0 {
+			return now.Add(-time.Duration(n) * unit), nil
+		}
+	}
+	if age, err := time.ParseDuration(value); err == nil && age >= 0 {
+		return now.Add(-age), nil
+	}
+	return time.Time{}, fmt.Errorf("invalid --since %q (expected an age such as 7d, 2w or 36h, or a date such as 2026-10-01)", value)
+}
+
+func init() {
+	historyExportCmd.Flags().StringP("format", "f", core.ExportCSV, "Output format ("+strings.Join(core.ExportFormats, ", ")+")")
+	historyExportCmd.Flags().String("since", "", "Only export queries run within this age (7d, 2w, 36h) or since this date (2026-10-01)")
+	historyExportCmd.Flags().StringP("output", "o", "", "Write the history to a file instead of stdout")
+	historyCmd.AddCommand(historyExportCmd)
+}
@@ -0,0 +1,25 @@
+package cli
+
+import (
+	"sqlterm/internal/core"
+
+	"github.com/spf13/cobra"
+)
+
+var (
+	jsonOutput  bool
+	quietOutput bool
+)
+
+// outputSink returns where cmd writes, following the --json and --quiet flags.
+// --json wins when both are given.
+func outputSink(cmd *cobra.Command) *core.OutputSink {
+	mode := core.OutputText
+	switch {
+	case jsonOutput:
+		mode = core.OutputJSON
+	case quietOutput:
+		mode = core.OutputQuiet
+	}
+	return &core.OutputSink{Mode: mode, Out: cmd.OutOrStdout(), Err: cmd.ErrOrStderr()}
+}
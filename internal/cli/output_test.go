@@ -0,0 +1,81 @@
+package cli
+
+import (
+	"bytes"
+	"testing"
+
+	"sqlterm/internal/config"
+	"sqlterm/internal/core"
+)
+
+// useConfigDir points the config manager at a fresh directory holding connections
+func useConfigDir(t *testing.T, connections ...*core.ConnectionConfig) {
+	t.Helper()
+	config.SetConfigDirOverride(t.TempDir())
+	t.Cleanup(func() { config.SetConfigDirOverride("") })
+
+	configMgr := config.NewManager()
+	for _, conn := range connections {
+		if err := configMgr.SaveConnection(conn); err != nil {
+			t.Fatalf("Failed to save connection: %v", err)
+		}
+	}
+}
+
+func TestListConnections_JSON(t *testing.T) {
+	useConfigDir(t,
+		&core.ConnectionConfig{Name: "prod", DatabaseType: core.PostgreSQL, Host: "db.example.com", Port: 5432,
+			Database: "app", Username: "admin", Password: "secret", Environment: "production", Color: "red"},
+		&core.ConnectionConfig{Name: "local", DatabaseType: core.SQLite, Host: "localhost", Database: "app.db"},
+	)
+
+	var out bytes.Buffer
+	if err := listConnections(&core.OutputSink{Mode: core.OutputJSON, Out: &out}); err != nil {
+		t.Fatalf("listConnections() failed: %v", err)
+	}
+
+	want := `[{"name":"local","type":"sqlite","database":"app.db"},` +
+		`{"name":"prod","type":"postgres","host":"db.example.com","port":5432,"database":"app","username":"admin","environment":"production","color":"red"}]` + "\n"
+	if got := out.String(); got != want {
+		t.Errorf("JSON output mismatch\ngot:  %s\nwant: %s", got, want)
+	}
+}
+
+func TestListConnections_JSONEmpty(t *testing.T) {
+	useConfigDir(t)
+
+	var out bytes.Buffer
+	if err := listConnections(&core.OutputSink{Mode: core.OutputJSON, Out: &out}); err != nil {
+		t.Fatalf("listConnections() failed: %v", err)
+	}
+	if got := out.String(); got != "[]\n" {
+		t.Errorf("Expected an empty array with no status lines, got %q", got)
+	}
+}
+
+func TestListConnections_Quiet(t *testing.T) {
+	useConfigDir(t, &core.ConnectionConfig{Name: "local", DatabaseType: core.SQLite, Database: "app.db"})
+
+	var out bytes.Buffer
+	if err := listConnections(&core.OutputSink{Mode: core.OutputQuiet, Out: &out}); err != nil {
+		t.Fatalf("listConnections() failed: %v", err)
+	}
+	if got, want := out.String(), "1. local (sqlite) - sqlite://:0/app.db\n"; got != want {
+		t.Errorf("Expected only the connection line\ngot:  %q\nwant: %q", got, want)
+	}
+}
+
+func TestCheckResultRecord(t *testing.T) {
+	conn := &core.ConnectionConfig{Name: "prod", DatabaseType: core.PostgreSQL, Host: "db", Port: 5432}
+
+	var out bytes.Buffer
+	sink := &core.OutputSink{Mode: core.OutputJSON, Out: &out}
+	sink.Document(core.CheckResult{Config: conn, Latency: 12_400_000, Version: "16.2"}.Record())
+	sink.Document(core.CheckResult{Config: conn, Err: core.ErrCheckTimeout}.Record())
+
+	want := `{"name":"prod","type":"postgres","host":"db:5432","ok":true,"latency_ms":12,"version":"16.2"}` + "\n" +
+		`{"name":"prod","type":"postgres","host":"db:5432","ok":false,"error":"no answer"}` + "\n"
+	if got := out.String(); got != want {
+		t.Errorf("NDJSON output mismatch\ngot:  %s\nwant: %s", got, want)
+	}
+}
@@ -1,22 +1,38 @@
 package cli
 
 import (
+	"bufio"
+	"context"
+	"errors"
 	"fmt"
+	"io"
 	"os"
+	"strings"
 
 	"sqlterm/internal/ai"
 	"sqlterm/internal/config"
 	"sqlterm/internal/conversation"
 	"sqlterm/internal/core"
+	"sqlterm/internal/diag"
 	"sqlterm/internal/i18n"
 
 	"github.com/spf13/cobra"
 	"github.com/spf13/viper"
+	"golang.org/x/term"
 )
 
 var (
-	cfgFile string
-	verbose bool
+	cfgFile       string
+	configDir     string
+	verbose       bool
+	noAutoConnect bool
+
+	// Statements to run at startup: -C names the connection, -e gives each
+	// statement and --exit skips conversation mode afterwards
+	startupConnection string
+	startupStatements []string
+	exitAfterExecute  bool
+	assumeYes         bool
 
 	// Version information (set from main)
 	Version   string = "dev"
@@ -29,6 +45,7 @@ func SetVersionInfo(version, buildTime, gitCommit string) {
 	Version = version
 	BuildTime = buildTime
 	GitCommit = gitCommit
+	diag.SetVersionInfo(version, buildTime, gitCommit)
 }
 
 var rootCmd = &cobra.Command{
@@ -36,12 +53,37 @@ var rootCmd = &cobra.Command{
 	Short: "", // Will be set in init()
 	Long:  "", // Will be set in init()
 	RunE: func(cmd *cobra.Command, args []string) error {
-		return runConversation()
+		if exitAfterExecute {
+			return executeAndExit(cmd)
+		}
+		return runConversation(!noAutoConnect)
 	},
 }
 
 func Execute() error {
-	return rootCmd.Execute()
+	err := rootCmd.Execute()
+	reportFileWarnings()
+	return err
+}
+
+// reportFileWarnings prints the config-dir files that could not be used.
+// Conversation mode shows these itself; other commands report them on the way out.
+func reportFileWarnings() {
+	warnings := core.TakeFileWarnings()
+	if len(warnings) == 0 {
+		return
+	}
+	i18nMgr, err := i18n.NewManager("en_au")
+	if err != nil {
+		return
+	}
+	for _, warning := range warnings {
+		message := fmt.Sprintf(i18nMgr.Get("file_skipped"), warning.Path, warning.Err)
+		if warning.MovedTo != "" {
+			message = fmt.Sprintf(i18nMgr.Get("file_set_aside"), warning.Path, warning.Err, warning.MovedTo)
+		}
+		fmt.Fprintf(os.Stderr, i18nMgr.Get("generic_warning"), message)
+	}
 }
 
 func init() {
@@ -63,18 +105,52 @@ func init() {
 		addCmd.Short = i18nMgr.Get("add_command_short")
 		versionCmd.Short = i18nMgr.Get("version_command_short")
 		versionCmd.Long = i18nMgr.Get("version_command_long")
+		diagCmd.Short = i18nMgr.Get("diag_command_short")
+		diagCmd.Long = i18nMgr.Get("diag_command_long")
+		execCmd.Short = i18nMgr.Get("exec_command_short")
+		execCmd.Long = i18nMgr.Get("exec_command_long")
+		checkCmd.Short = i18nMgr.Get("check_command_short")
+		checkCmd.Long = i18nMgr.Get("check_command_long")
+		completionsCmd.Short = i18nMgr.Get("completions_command_short")
+		historyCmd.Short = i18nMgr.Get("history_command_short")
+		historyExportCmd.Short = i18nMgr.Get("history_export_command_short")
+		historyExportCmd.Long = i18nMgr.Get("history_export_command_long")
+		tablesCmd.Short = i18nMgr.Get("tables_command_short")
+		tablesCmd.Long = i18nMgr.Get("tables_command_long")
+		describeCmd.Short = i18nMgr.Get("describe_command_short")
+		describeCmd.Long = i18nMgr.Get("describe_command_long")
 
 		// Update flag descriptions
 		updateFlagDescriptions(i18nMgr)
 	}
 
 	rootCmd.PersistentFlags().StringVar(&cfgFile, "config", "", getI18nString(i18nMgr, "config_file_flag", "config file (default is $HOME/.sqlterm.yaml)"))
+	rootCmd.PersistentFlags().StringVar(&configDir, "config-dir", "", getI18nString(i18nMgr, "config_dir_flag", "directory for connections, sessions and settings (overrides $SQLTERM_CONFIG_DIR and $XDG_CONFIG_HOME)"))
 	rootCmd.PersistentFlags().BoolVarP(&verbose, "verbose", "v", false, getI18nString(i18nMgr, "verbose_output_flag", "verbose output"))
+	rootCmd.PersistentFlags().BoolVar(&jsonOutput, "json", false, getI18nString(i18nMgr, "json_output_flag", "write list, add, check, exec, tables and describe output as JSON"))
+	rootCmd.PersistentFlags().BoolVarP(&quietOutput, "quiet", "q", false, getI18nString(i18nMgr, "quiet_output_flag", "print only data and errors"))
+	rootCmd.Flags().BoolVar(&noAutoConnect, "no-auto-connect", false, getI18nString(i18nMgr, "no_auto_connect_flag", "start without connecting to the default connection"))
+	rootCmd.Flags().StringVarP(&startupConnection, "connection", "C", "", getI18nString(i18nMgr, "startup_connection_flag", "saved connection to open instead of the default one"))
+	rootCmd.Flags().StringArrayVarP(&startupStatements, "execute", "e", nil, getI18nString(i18nMgr, "execute_flag", "statement to run once connected; repeat to run several in order"))
+	rootCmd.Flags().BoolVar(&exitAfterExecute, "exit", false, getI18nString(i18nMgr, "exit_flag", "print the results of the -e statements as sqlterm exec does and exit"))
+	rootCmd.Flags().BoolVar(&assumeYes, "yes", false, getI18nString(i18nMgr, "execute_yes_flag", "with --exit, run statements that modify data on connections that confirm them"))
+	rootCmd.RegisterFlagCompletionFunc("connection", completeConnectionFlag)
 
 	rootCmd.AddCommand(connectCmd)
 	rootCmd.AddCommand(listCmd)
 	rootCmd.AddCommand(addCmd)
 	rootCmd.AddCommand(versionCmd)
+	rootCmd.AddCommand(diagCmd)
+	rootCmd.AddCommand(execCmd)
+	rootCmd.AddCommand(checkCmd)
+	rootCmd.AddCommand(completionsCmd)
+	rootCmd.AddCommand(historyCmd)
+	rootCmd.AddCommand(tablesCmd)
+	rootCmd.AddCommand(describeCmd)
+	rootCmd.AddCommand(completeConnectionsCmd)
+
+	// completions replaces cobra's default completion command
+	rootCmd.CompletionOptions.DisableDefaultCmd = true
 }
 
 // getI18nString safely gets an i18n string with fallback
@@ -112,6 +188,8 @@ func updateFlagDescriptions(i18nMgr *i18n.Manager) {
 }
 
 func initConfig() {
+	config.SetConfigDirOverride(configDir)
+
 	if cfgFile != "" {
 		viper.SetConfigFile(cfgFile)
 	} else {
@@ -132,14 +210,64 @@ func initConfig() {
 	}
 }
 
-func runConversation() error {
+func runConversation(autoConnect bool) error {
 	app, err := conversation.NewApp()
 	if err != nil {
 		return fmt.Errorf("failed to create conversation app: %w", err)
 	}
+	app.SetAutoConnect(autoConnect)
+	app.SetStartupConnection(startupConnection)
+	app.SetStartupStatements(startupStatements)
 	return app.Run()
 }
 
+// executeAndExit runs the -e statements without entering conversation mode,
+// exactly as sqlterm exec would with its default flags
+func executeAndExit(cmd *cobra.Command) error {
+	if len(startupStatements) == 0 {
+		return errors.New("--exit needs at least one statement given with -e")
+	}
+	name := startupConnection
+	if name == "" {
+		name = defaultConnectionName(config.NewManager())
+	}
+	if name == "" {
+		return errors.New("no connection to run the statements on; name one with -C or set default_connection")
+	}
+
+	opts := execOptions{format: core.ExportTSV, header: true, yes: assumeYes}
+	if outputSink(cmd).JSON() {
+		opts.format = core.ExportJSON
+	}
+	return runExec(cmd, name, joinStatements(startupStatements), opts)
+}
+
+// defaultConnectionName names the connection conversation mode opens at
+// startup: the project's default_connection, then the one in config.yaml
+func defaultConnectionName(configMgr *config.Manager) string {
+	if cwd, err := os.Getwd(); err == nil {
+		if project, err := config.FindProject(cwd); err == nil && project != nil && project.DefaultConnection != "" {
+			return project.DefaultConnection
+		}
+	}
+	if aiManager, err := ai.NewManager(configMgr.GetConfigDir()); err == nil && aiManager != nil {
+		return aiManager.GetConfig().DefaultConnection
+	}
+	return ""
+}
+
+// joinStatements makes one script of statements given separately, so each
+// -e runs as its own statement whether or not it ends in a semicolon
+func joinStatements(statements []string) string {
+	trimmed := make([]string, 0, len(statements))
+	for _, statement := range statements {
+		if statement = strings.TrimRight(strings.TrimSpace(statement), ";"); statement != "" {
+			trimmed = append(trimmed, statement)
+		}
+	}
+	return strings.Join(trimmed, ";\n")
+}
+
 var connectCmd = &cobra.Command{
 	Use:   "connect",
 	Short: "", // Will be set in init()
@@ -185,45 +313,118 @@ var listCmd = &cobra.Command{
 	Use:   "list",
 	Short: "", // Will be set in init()
 	RunE: func(cmd *cobra.Command, args []string) error {
-		return listConnections()
+		return listConnections(outputSink(cmd))
 	},
 }
 
 var addCmd = &cobra.Command{
-	Use:   "add [name]",
+	Use:   "add <name> [database]",
 	Short: "", // Will be set in init()
-	Args:  cobra.ExactArgs(1),
+	Args: func(cmd *cobra.Command, args []string) error {
+		// From a template the arguments are [name] name=value...
+		if cmd.Flags().Changed("from-template") {
+			return nil
+		}
+		return cobra.RangeArgs(1, 2)(cmd, args)
+	},
+	ValidArgsFunction: completeTemplateValues,
 	RunE: func(cmd *cobra.Command, args []string) error {
+		if template, _ := cmd.Flags().GetString("from-template"); template != "" {
+			conn, err := templateConnection(config.NewManager(), template, args)
+			if err != nil {
+				return err
+			}
+			if backend, ok := core.LookupBackend(conn.DatabaseType); ok && !backend.FileBased && conn.Password == "" {
+				if conn.Password, err = addPassword(cmd); err != nil {
+					return err
+				}
+			}
+			return addConnection(outputSink(cmd), conn)
+		}
+
 		name := args[0]
 		dbType, _ := cmd.Flags().GetString("db-type")
+		if dbType == "" {
+			return errors.New(`required flag "db-type" not set; pass --db-type, or --from-template to add from a template`)
+		}
 		host, _ := cmd.Flags().GetString("host")
 		port, _ := cmd.Flags().GetInt("port")
 		database, _ := cmd.Flags().GetString("database")
 		username, _ := cmd.Flags().GetString("username")
+		environment, _ := cmd.Flags().GetString("environment")
+		color, _ := cmd.Flags().GetString("color")
 
 		dbTypeEnum, err := core.ParseDatabaseType(dbType)
 		if err != nil {
 			return err
 		}
 
-		if port == 0 {
-			port = core.GetDefaultPort(dbTypeEnum)
+		if len(args) == 2 {
+			if database != "" && database != args[1] {
+				return fmt.Errorf("database given twice: %q and --database %q", args[1], database)
+			}
+			database = args[1]
+		}
+		if database == "" {
+			return errors.New("no database given; pass it after the name or with --database")
+		}
+
+		if color != "" && !core.IsConnectionColor(color) {
+			return fmt.Errorf("unknown color %q (expected one of %s)", color, strings.Join(core.ConnectionColors(), ", "))
 		}
 
 		config := &core.ConnectionConfig{
 			Name:         name,
 			DatabaseType: dbTypeEnum,
-			Host:         host,
-			Port:         port,
 			Database:     database,
-			Username:     username,
 			SSL:          false,
+			Environment:  environment,
+			Color:        color,
 		}
 
-		return addConnection(config)
+		// File databases have no server, so host, port, username and password don't apply
+		if backend, ok := core.LookupBackend(dbTypeEnum); ok && backend.FileBased {
+			if config.Database, err = sqliteDatabasePath(database); err != nil {
+				return err
+			}
+			return addConnection(outputSink(cmd), config)
+		}
+
+		if username == "" {
+			return fmt.Errorf("--username is required for %s connections", dbTypeEnum)
+		}
+		if port == 0 {
+			port = core.GetDefaultPort(dbTypeEnum)
+		}
+		config.Host, config.Port, config.Username = host, port, username
+
+		if config.Password, err = addPassword(cmd); err != nil {
+			return err
+		}
+
+		return addConnection(outputSink(cmd), config)
 	},
 }
 
+// addPassword finds the password of the connection sqlterm add saves
+func addPassword(cmd *cobra.Command) (string, error) {
+	password, _ := cmd.Flags().GetString("password")
+	source := passwordSource{flag: password, flagSet: cmd.Flags().Changed("password"), getenv: os.Getenv}
+	if term.IsTerminal(int(os.Stdin.Fd())) {
+		source.prompt = promptPassword
+	}
+	return source.password()
+}
+
+// promptPassword asks for a password on the terminal without echoing it
+func promptPassword() (string, error) {
+	i18nMgr, _ := i18n.NewManager("en_au")
+	fmt.Fprint(os.Stderr, i18nMgr.Get("enter_password"))
+	password, err := term.ReadPassword(int(os.Stdin.Fd()))
+	fmt.Fprintln(os.Stderr)
+	return string(password), err
+}
+
 var versionCmd = &cobra.Command{
 	Use:   "version",
 	Short: "", // Will be set in init()
@@ -241,6 +442,15 @@ var versionCmd = &cobra.Command{
 	},
 }
 
+var diagCmd = &cobra.Command{
+	Use:   "diag",
+	Short: "", // Will be set in init()
+	Long:  "", // Will be set in init()
+	Run: func(cmd *cobra.Command, args []string) {
+		fmt.Print(diag.Collect(config.NewManager(), nil, nil))
+	},
+}
+
 func init() {
 	// Set up flags with English fallbacks - will be updated in initI18n()
 	connectCmd.Flags().StringP("db-type", "t", "", "Database type (mysql, postgres, sqlite)")
@@ -256,22 +466,26 @@ func init() {
 	addCmd.Flags().StringP("db-type", "t", "", "Database type (mysql, postgres, sqlite)")
 	addCmd.Flags().StringP("host", "H", "localhost", "Host")
 	addCmd.Flags().IntP("port", "p", 0, "Port")
-	addCmd.Flags().StringP("database", "d", "", "Database name")
+	addCmd.Flags().StringP("database", "d", "", "Database name, or the database file for sqlite")
 	addCmd.Flags().StringP("username", "u", "", "Username")
-	addCmd.MarkFlagRequired("db-type")
-	addCmd.MarkFlagRequired("database")
-	addCmd.MarkFlagRequired("username")
+	addCmd.Flags().StringP("password", "P", "", "Password (asked for on a terminal, else read from $SQLTERM_PASSWORD)")
+	addCmd.Flags().StringP("environment", "e", "", "Environment tag, e.g. development, staging, production")
+	addCmd.Flags().String("color", "", "Prompt color (red, green, yellow, blue, magenta, cyan)")
+	addCmd.Flags().String("from-template", "", "Connection template to fill in with name=value arguments")
+	addCmd.RegisterFlagCompletionFunc("from-template", completeTemplateNames)
 }
 
 func connectAndRunConversation(connConfig *core.ConnectionConfig) error {
 	// Initialize i18n manager for CLI
 	configMgr := config.NewManager()
 	language := "en_au" // Default language
+	timeout := core.DefaultConnectTimeout
 
-	// Try to get language from AI config
+	// Try to get language and connect timeout from AI config
 	if aiManager, err := ai.NewManager(configMgr.GetConfigDir()); err == nil && aiManager != nil {
 		if aiConfig := aiManager.GetConfig(); aiConfig != nil {
 			language = aiConfig.Language
+			timeout, _ = aiConfig.ConnectTimeoutDuration()
 		}
 	}
 
@@ -281,18 +495,30 @@ func connectAndRunConversation(connConfig *core.ConnectionConfig) error {
 		i18nMgr, _ = i18n.NewManager("en_au")
 	}
 
+	if core.MissingPassword(connConfig) && !confirmMissingPassword(os.Stdin, os.Stdout, connConfig, i18nMgr) {
+		fmt.Println(i18nMgr.Get("connect_cancelled"))
+		return nil
+	}
+
 	fmt.Printf(i18nMgr.Get("connecting_to"), connConfig.Name)
 
-	conn, err := core.NewConnection(connConfig)
+	conn, err := core.Connect(context.Background(), connConfig, timeout)
 	if err != nil {
-		return fmt.Errorf("failed to connect: %w", err)
-	}
-
-	if err := conn.Ping(); err != nil {
-		return fmt.Errorf("connection test failed: %w", err)
+		err = connectionTestFailed(err)
+		if recordErr := configMgr.RecordFailure(connConfig.Name, err); recordErr != nil {
+			fmt.Printf(i18nMgr.Get("generic_warning"), recordErr)
+		}
+		return err
 	}
 
 	fmt.Printf(i18nMgr.Get("connected_successfully"), connConfig.Name)
+	version := ""
+	if server, ok := conn.(core.ServerInfo); ok {
+		version, _ = server.ServerVersion()
+	}
+	if err := configMgr.RecordUse(connConfig.Name, version); err != nil {
+		fmt.Printf(i18nMgr.Get("generic_warning"), err)
+	}
 	fmt.Print(i18nMgr.Get("starting_conversation_mode"))
 
 	app, err := conversation.NewApp()
@@ -304,7 +530,7 @@ func connectAndRunConversation(connConfig *core.ConnectionConfig) error {
 	return app.Run()
 }
 
-func listConnections() error {
+func listConnections(out *core.OutputSink) error {
 	// Initialize i18n
 	i18nMgr, err := i18n.NewManager("en_au")
 	if err != nil {
@@ -312,24 +538,40 @@ func listConnections() error {
 	}
 
 	configManager := config.NewManager()
-	connections, err := configManager.ListConnections()
+	order := config.ConnectionOrderRecent
+	if _, settings, err := config.LoadConfig(configManager.GetConfigDir()); err == nil {
+		order = settings.ConnectionOrder
+	}
+	connections, recent, err := configManager.OrderedConnections(order)
 	if err != nil {
 		return fmt.Errorf("failed to load connections: %w", err)
 	}
 
+	if out.JSON() {
+		records := make([]core.ConnectionRecord, 0, len(connections))
+		for _, conn := range connections {
+			records = append(records, conn.Record())
+		}
+		return out.Document(records)
+	}
+
 	if len(connections) == 0 {
-		fmt.Println(i18nMgr.Get("no_saved_connections_found"))
-		fmt.Println(i18nMgr.Get("add_connection_instruction"))
+		out.Statusln(i18nMgr.Get("no_saved_connections_found"))
+		out.Statusln(i18nMgr.Get("add_connection_instruction"))
 		return nil
 	}
 
-	fmt.Println(i18nMgr.Get("saved_connections_cli"))
+	out.Statusln(i18nMgr.Get("saved_connections_cli"))
+	color := os.Getenv("NO_COLOR") == "" && term.IsTerminal(int(os.Stdout.Fd()))
 	for i, conn := range connections {
-		fmt.Printf("%d. %s (%s) - %s://%s:%d/%s\n",
+		if header := config.ConnectionsHeader(i18nMgr, i, recent, len(connections)); header != "" {
+			out.Statusln(header)
+		}
+		out.Dataf("%d. %s (%s) - %s://%s:%d/%s\n",
 			i+1,
-			conn.Name,
-			conn.DatabaseType,
-			conn.DatabaseType.String(),
+			conn.Label(conn.Name, color),
+			conn.TypeName(),
+			conn.TypeName(),
 			conn.Host,
 			conn.Port,
 			conn.Database)
@@ -338,14 +580,35 @@ func listConnections() error {
 	return nil
 }
 
-func addConnection(cfg *core.ConnectionConfig) error {
+// confirmMissingPassword asks whether to log in to another machine without a
+// password, which is usually a --password left off by mistake
+func confirmMissingPassword(in io.Reader, out io.Writer, cfg *core.ConnectionConfig, i18nMgr *i18n.Manager) bool {
+	fmt.Fprint(out, i18nMgr.GetWithArgs("confirm_missing_password", cfg.Host, cfg.Username))
+	answer, _ := bufio.NewReader(in).ReadString('\n')
+	answer = strings.ToLower(strings.TrimSpace(answer))
+	return answer == "y" || answer == "yes"
+}
+
+// connectionTestFailed points at the password or at the host when the error
+// tells which one is wrong
+func connectionTestFailed(err error) error {
+	switch {
+	case errors.Is(err, core.ErrAuthenticationFailed):
+		return fmt.Errorf("connection test failed, check the username and password: %w", err)
+	case errors.Is(err, core.ErrServerUnreachable):
+		return fmt.Errorf("connection test failed, check the host, port and network: %w", err)
+	}
+	return fmt.Errorf("connection test failed: %w", err)
+}
+
+func addConnection(out *core.OutputSink, cfg *core.ConnectionConfig) error {
 	// Initialize i18n
 	i18nMgr, err := i18n.NewManager("en_au")
 	if err != nil {
 		i18nMgr, _ = i18n.NewManager("en_au")
 	}
 
-	fmt.Printf(i18nMgr.Get("testing_connection_cli"), cfg.Name)
+	out.Statusf(i18nMgr.Get("testing_connection_cli"), cfg.Name)
 
 	conn, err := core.NewConnection(cfg)
 	if err != nil {
@@ -353,19 +616,22 @@ func addConnection(cfg *core.ConnectionConfig) error {
 	}
 
 	if err := conn.Ping(); err != nil {
-		return fmt.Errorf("connection test failed: %w", err)
+		return connectionTestFailed(err)
 	}
 
-	fmt.Println(i18nMgr.Get("connection_test_successful"))
+	out.Statusln(i18nMgr.Get("connection_test_successful"))
 
 	configManager := config.NewManager()
 	if err := configManager.SaveConnection(cfg); err != nil {
 		return fmt.Errorf("failed to save connection: %w", err)
 	}
 
-	fmt.Printf(i18nMgr.Get("connection_saved_cli"), cfg.Name)
-	fmt.Println(i18nMgr.Get("use_list_instruction"))
-	fmt.Println(i18nMgr.Get("use_sqlterm_instruction"))
+	if out.JSON() {
+		return out.Document(cfg.Record())
+	}
+	out.Statusf(i18nMgr.Get("connection_saved_cli"), cfg.Name)
+	out.Statusln(i18nMgr.Get("use_list_instruction"))
+	out.Statusln(i18nMgr.Get("use_sqlterm_instruction"))
 
 	return nil
 }
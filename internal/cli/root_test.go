@@ -2,6 +2,7 @@ package cli
 
 import (
 	"bytes"
+	"strings"
 	"testing"
 
 	"github.com/spf13/cobra"
@@ -465,3 +466,35 @@ func BenchmarkDatabaseTypeString(b *testing.B) {
 	}
 	_ = result // Prevent unused variable warning
 }
+
+func TestConfirmMissingPassword(t *testing.T) {
+	i18nMgr, err := i18n.NewManager("en_au")
+	if err != nil {
+		t.Fatalf("Failed to create i18n manager: %v", err)
+	}
+	cfg := &core.ConnectionConfig{DatabaseType: core.PostgreSQL, Host: "prod-db.internal", Username: "admin"}
+
+	var out bytes.Buffer
+	if !confirmMissingPassword(strings.NewReader("y\n"), &out, cfg, i18nMgr) {
+		t.Error("Expected y to go ahead")
+	}
+	if got := out.String(); got != "Connect to prod-db.internal as admin with NO password? (y/N): " {
+		t.Errorf("Unexpected prompt %q", got)
+	}
+	for _, answer := range []string{"\n", "n\n", ""} {
+		if confirmMissingPassword(strings.NewReader(answer), &out, cfg, i18nMgr) {
+			t.Errorf("Expected %q to call the connection off", answer)
+		}
+	}
+}
+
+func TestJoinStatements(t *testing.T) {
+	got := joinStatements([]string{"SELECT now()", "  UPDATE t SET a = ';';  ", "", "SELECT 2;"})
+	want := "SELECT now();\nUPDATE t SET a = ';';\nSELECT 2"
+	if got != want {
+		t.Errorf("Expected %q, got %q", want, got)
+	}
+	if statements := core.SplitStatements(core.MySQL, got); len(statements) != 3 {
+		t.Errorf("Expected each -e to be its own statement, got %q", statements)
+	}
+}
@@ -0,0 +1,131 @@
+package cli
+
+import (
+	"fmt"
+
+	"sqlterm/internal/config"
+	"sqlterm/internal/core"
+	"sqlterm/internal/i18n"
+
+	"github.com/spf13/cobra"
+)
+
+var tablesCmd = &cobra.Command{
+	Use:          "tables --connection <name>",
+	Short:        "", // Will be set in init()
+	Long:         "", // Will be set in init()
+	Args:         cobra.NoArgs,
+	SilenceUsage: true,
+	RunE: func(cmd *cobra.Command, args []string) error {
+		conn, connConfig, err := openSchemaConnection(cmd)
+		if err != nil {
+			return err
+		}
+		defer conn.Close()
+
+		i18nMgr, err := i18n.NewManager("en_au")
+		if err != nil {
+			return err
+		}
+		return writeTables(outputSink(cmd), conn, connConfig, i18nMgr)
+	},
+}
+
+var describeCmd = &cobra.Command{
+	Use:          "describe <table> --connection <name>",
+	Short:        "", // Will be set in init()
+	Long:         "", // Will be set in init()
+	Args:         cobra.ExactArgs(1),
+	SilenceUsage: true,
+	RunE: func(cmd *cobra.Command, args []string) error {
+		conn, connConfig, err := openSchemaConnection(cmd)
+		if err != nil {
+			return err
+		}
+		defer conn.Close()
+
+		i18nMgr, err := i18n.NewManager("en_au")
+		if err != nil {
+			return err
+		}
+		return writeTableDescription(outputSink(cmd), conn, connConfig, args[0], i18nMgr)
+	},
+}
+
+// openSchemaConnection connects to the saved connection named by --connection
+func openSchemaConnection(cmd *cobra.Command) (core.Connection, *core.ConnectionConfig, error) {
+	name, _ := cmd.Flags().GetString("connection")
+	connConfig, err := config.NewManager().LoadConnection(name)
+	if err != nil {
+		return nil, nil, fmt.Errorf("failed to load connection %s: %w", name, err)
+	}
+	conn, err := core.NewConnection(connConfig)
+	if err != nil {
+		return nil, nil, fmt.Errorf("failed to connect: %w", err)
+	}
+	return conn, connConfig, nil
+}
+
+// writeTables lists the tables of conn one per line, or as a JSON array of names
+func writeTables(out *core.OutputSink, conn core.Connection, connConfig *core.ConnectionConfig, i18nMgr *i18n.Manager) error {
+	tables, err := conn.ListTables()
+	if err != nil {
+		return fmt.Errorf("failed to list tables: %w", err)
+	}
+
+	if out.JSON() {
+		if tables == nil {
+			tables = []string{}
+		}
+		return out.Document(tables)
+	}
+	if len(tables) == 0 {
+		out.Statusf(i18nMgr.Get("no_tables_found")+"\n", connConfig.Database)
+		return nil
+	}
+	out.Statusf(i18nMgr.Get("tables_in_database")+"\n", connConfig.Database)
+	for _, table := range tables {
+		out.Dataf("%s\n", table)
+	}
+	return nil
+}
+
+// writeTableDescription shows the structure of table as /describe does, or as
+// a JSON document. A table the database does not have is an error rather than
+// whatever the server says about a query on it.
+func writeTableDescription(out *core.OutputSink, conn core.Connection, connConfig *core.ConnectionConfig, table string, i18nMgr *i18n.Manager) error {
+	name, ok, err := core.TableExists(conn, table)
+	if err != nil {
+		return fmt.Errorf("failed to list tables: %w", err)
+	}
+	if !ok {
+		return fmt.Errorf("%w: %s in %s", core.ErrTableNotFound, table, connConfig.Name)
+	}
+
+	info, err := conn.DescribeTable(name)
+	if err != nil {
+		return fmt.Errorf("failed to describe table: %w", err)
+	}
+	if out.JSON() {
+		return out.Document(info.Record())
+	}
+	out.Dataf("%s", core.TableMarkdown(info, i18nMgr))
+	return nil
+}
+
+// completeConnectionFlag completes --connection with saved connection names
+func completeConnectionFlag(cmd *cobra.Command, args []string, toComplete string) ([]string, cobra.ShellCompDirective) {
+	names, err := connectionNames()
+	if err != nil {
+		return nil, cobra.ShellCompDirectiveError
+	}
+	return names, cobra.ShellCompDirectiveNoFileComp
+}
+
+func init() {
+	for _, cmd := range []*cobra.Command{tablesCmd, describeCmd} {
+		cmd.Flags().StringP("connection", "c", "", "Saved connection to read the schema from")
+		cmd.MarkFlagRequired("connection")
+		cmd.RegisterFlagCompletionFunc("connection", completeConnectionFlag)
+	}
+}
@@ -0,0 +1,91 @@
+package cli
+
+import (
+	"bytes"
+	"errors"
+	"strings"
+	"testing"
+
+	"sqlterm/internal/core"
+	"sqlterm/internal/core/coretest"
+	"sqlterm/internal/i18n"
+)
+
+func schemaFixture() (*coretest.MockConnection, *core.ConnectionConfig) {
+	defaultStatus := "'new'"
+	conn := coretest.NewMockConnection().
+		AddTable(&core.TableInfo{Name: "customers", Columns: []core.ColumnInfo{{Name: "id", Type: "INTEGER", Key: "PRI"}}}).
+		AddTable(&core.TableInfo{
+			Name: "orders",
+			Columns: []core.ColumnInfo{
+				{Name: "id", Type: "INTEGER", Key: "PRI"},
+				{Name: "customer_id", Type: "INTEGER"},
+				{Name: "status", Type: "TEXT", Nullable: true, Default: &defaultStatus},
+			},
+			PrimaryKeys: []string{"id"},
+			ForeignKeys: []core.ForeignKeyInfo{{Name: "fk_customer", Column: "customer_id", ReferencedTable: "customers", ReferencedColumn: "id"}},
+		})
+	return conn, &core.ConnectionConfig{Name: "shop", DatabaseType: core.SQLite, Database: "shop.db"}
+}
+
+func TestWriteTables(t *testing.T) {
+	conn, connConfig := schemaFixture()
+	i18nMgr, _ := i18n.NewManager("en_au")
+
+	tests := []struct {
+		mode core.OutputMode
+		want string
+	}{
+		{core.OutputText, "📋 Tables in shop.db:\ncustomers\norders\n"},
+		{core.OutputQuiet, "customers\norders\n"},
+		{core.OutputJSON, `["customers","orders"]` + "\n"},
+	}
+	for _, tt := range tests {
+		var out bytes.Buffer
+		if err := writeTables(&core.OutputSink{Mode: tt.mode, Out: &out}, conn, connConfig, i18nMgr); err != nil {
+			t.Fatalf("writeTables() failed: %v", err)
+		}
+		if out.String() != tt.want {
+			t.Errorf("Mode %v output mismatch\ngot:  %q\nwant: %q", tt.mode, out.String(), tt.want)
+		}
+	}
+}
+
+func TestWriteTableDescription(t *testing.T) {
+	conn, connConfig := schemaFixture()
+	i18nMgr, _ := i18n.NewManager("en_au")
+
+	var out bytes.Buffer
+	if err := writeTableDescription(&core.OutputSink{Mode: core.OutputQuiet, Out: &out}, conn, connConfig, "ORDERS", i18nMgr); err != nil {
+		t.Fatalf("writeTableDescription() failed: %v", err)
+	}
+	if !strings.Contains(out.String(), "# 📊 Table: orders") || !strings.Contains(out.String(), "**customer_id**") {
+		t.Errorf("Expected the /describe markdown, got:\n%s", out.String())
+	}
+
+	out.Reset()
+	if err := writeTableDescription(&core.OutputSink{Mode: core.OutputJSON, Out: &out}, conn, connConfig, "orders", i18nMgr); err != nil {
+		t.Fatalf("writeTableDescription() failed: %v", err)
+	}
+	want := `{"name":"orders","columns":[{"name":"id","type":"INTEGER","nullable":false,"key":"PRI","default":null},` +
+		`{"name":"customer_id","type":"INTEGER","nullable":false,"default":null},` +
+		`{"name":"status","type":"TEXT","nullable":true,"default":"'new'"}],` +
+		`"primary_keys":["id"],"foreign_keys":[{"name":"fk_customer","column":"customer_id","references":"customers.id"}]}` + "\n"
+	if out.String() != want {
+		t.Errorf("JSON output mismatch\ngot:  %s\nwant: %s", out.String(), want)
+	}
+}
+
+func TestWriteTableDescription_MissingTable(t *testing.T) {
+	conn, connConfig := schemaFixture()
+	i18nMgr, _ := i18n.NewManager("en_au")
+
+	var out bytes.Buffer
+	err := writeTableDescription(&core.OutputSink{Mode: core.OutputText, Out: &out}, conn, connConfig, "order", i18nMgr)
+	if !errors.Is(err, core.ErrTableNotFound) || err.Error() != "no such table: order in shop" {
+		t.Errorf("Expected a clean not-found error, got %v", err)
+	}
+	if out.Len() != 0 {
+		t.Errorf("Expected nothing on stdout, got %q", out.String())
+	}
+}
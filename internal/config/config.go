@@ -4,6 +4,9 @@ import (
 	"fmt"
 	"os"
 	"path/filepath"
+	"time"
+
+	"sqlterm/internal/core"
 	"sqlterm/internal/i18n"
 
 	"gopkg.in/yaml.v3"
@@ -60,14 +63,19 @@ func LoadConfig(configDir string) (*i18n.Manager, *Config, error) {
 		}
 	}
 
+	// A broken config file should not stop sqlterm from starting: run with the
+	// defaults and report it. An unparseable file is set aside so saving
+	// settings later does not overwrite it.
 	data, err := os.ReadFile(configPath)
 	if err != nil {
-		return nil, nil, fmt.Errorf(i18nMgr.Get("failed_to_read_config_file"), err)
+		core.WarnFile(configPath, fmt.Errorf(i18nMgr.Get("failed_to_read_config_file"), err), false)
+		return i18nMgr, DefaultConfig(), nil
 	}
 
 	var config Config
 	if err := yaml.Unmarshal(data, &config); err != nil {
-		return nil, nil, fmt.Errorf(i18nMgr.Get("failed_to_parse_config_file"), err)
+		core.WarnFile(configPath, fmt.Errorf(i18nMgr.Get("failed_to_parse_config_file"), err), true)
+		return i18nMgr, DefaultConfig(), nil
 	}
 
 	// Ensure maps are initialized
@@ -165,3 +173,15 @@ func (c *Config) SetLanguage(language string) {
 func (c *Config) FormatProviderInfo() string {
 	return fmt.Sprintf("%s/%s", c.AI.Provider, c.AI.Model)
 }
+
+// ConnectTimeoutDuration resolves connect_timeout, defaulting to core.DefaultConnectTimeout
+func (c *Config) ConnectTimeoutDuration() (time.Duration, error) {
+	if c.ConnectTimeout == "" {
+		return core.DefaultConnectTimeout, nil
+	}
+	timeout, err := time.ParseDuration(c.ConnectTimeout)
+	if err != nil || timeout < 0 {
+		return core.DefaultConnectTimeout, fmt.Errorf("invalid connect timeout %q", c.ConnectTimeout)
+	}
+	return timeout, nil
+}
@@ -3,8 +3,11 @@ package config
 import (
 	"os"
 	"path/filepath"
+	"strings"
 	"testing"
+	"time"
 
+	"sqlterm/internal/core"
 	"sqlterm/internal/i18n"
 )
 
@@ -377,6 +380,72 @@ func TestManager_GetConfigDir(t *testing.T) {
 	}
 }
 
+func TestManager_ListConnections_Cached(t *testing.T) {
+	manager := &Manager{configDir: t.TempDir()}
+
+	for _, name := range []string{"local", "staging"} {
+		if err := manager.SaveConnection(&core.ConnectionConfig{Name: name, DatabaseType: core.SQLite}); err != nil {
+			t.Fatalf("SaveConnection() failed: %v", err)
+		}
+	}
+
+	connections, err := manager.ListConnections()
+	if err != nil || len(connections) != 2 {
+		t.Fatalf("Expected 2 connections, got %d (%v)", len(connections), err)
+	}
+	loads := manager.loads
+
+	// Completion lists connections on every keystroke; none of these should re-read the files
+	for i := 0; i < 100; i++ {
+		if _, err := manager.ListConnections(); err != nil {
+			t.Fatalf("ListConnections() failed: %v", err)
+		}
+	}
+	if manager.loads != loads {
+		t.Errorf("Expected cached listings to read no connection files, got %d extra reads", manager.loads-loads)
+	}
+
+	if err := manager.DeleteConnection("staging"); err != nil {
+		t.Fatalf("DeleteConnection() failed: %v", err)
+	}
+	connections, _ = manager.ListConnections()
+	if len(connections) != 1 || connections[0].Name != "local" {
+		t.Errorf("Expected the deleted connection to disappear from the listing, got %v", connections)
+	}
+}
+
+func TestManager_ConnectionFromNewerVersion(t *testing.T) {
+	manager := &Manager{configDir: t.TempDir()}
+	path := manager.ConnectionPath("analytics")
+	if err := os.MkdirAll(filepath.Dir(path), 0755); err != nil {
+		t.Fatal(err)
+	}
+	future := "name: analytics\ndatabase_type: cockroach\nhost: crdb.internal\nfollower_reads: true\n"
+	if err := os.WriteFile(path, []byte(future), 0600); err != nil {
+		t.Fatal(err)
+	}
+
+	connections, err := manager.ListConnections()
+	if err != nil || len(connections) != 1 || connections[0].TypeName() != "cockroach" {
+		t.Fatalf("Expected the connection to be listed with its type name, got %v (%v)", connections, err)
+	}
+
+	config, err := manager.LoadConnection("analytics")
+	if err != nil {
+		t.Fatalf("LoadConnection() failed: %v", err)
+	}
+	config.Host = "crdb-2.internal"
+	if err := manager.SaveConnection(config); err != nil {
+		t.Fatalf("SaveConnection() failed: %v", err)
+	}
+	data, _ := os.ReadFile(path)
+	for _, want := range []string{"database_type: cockroach", "host: crdb-2.internal", "follower_reads: true"} {
+		if !strings.Contains(string(data), want) {
+			t.Errorf("Expected the saved file to contain %q, got:\n%s", want, data)
+		}
+	}
+}
+
 // Helper function for string containment check
 func contains(s, substr string) bool {
 	return len(s) >= len(substr) &&
@@ -394,3 +463,30 @@ func containsAtPosition(s, substr string) bool {
 	}
 	return false
 }
+
+func TestConfig_ConnectTimeoutDuration(t *testing.T) {
+	testCases := []struct {
+		name     string
+		timeout  string
+		expected time.Duration
+		hasError bool
+	}{
+		{name: "Default", timeout: "", expected: core.DefaultConnectTimeout},
+		{name: "Custom", timeout: "3s", expected: 3 * time.Second},
+		{name: "Driver default", timeout: "0", expected: 0},
+		{name: "Invalid", timeout: "soon", expected: core.DefaultConnectTimeout, hasError: true},
+		{name: "Negative", timeout: "-1s", expected: core.DefaultConnectTimeout, hasError: true},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.name, func(t *testing.T) {
+			timeout, err := (&Config{ConnectTimeout: tc.timeout}).ConnectTimeoutDuration()
+			if (err != nil) != tc.hasError {
+				t.Errorf("Expected error=%v, got %v", tc.hasError, err)
+			}
+			if timeout != tc.expected {
+				t.Errorf("Expected timeout %v, got %v", tc.expected, timeout)
+			}
+		})
+	}
+}
@@ -0,0 +1,62 @@
+package config
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"sync"
+)
+
+// ConfigDirEnv overrides where sqlterm keeps its configuration and session data
+const ConfigDirEnv = "SQLTERM_CONFIG_DIR"
+
+var (
+	configDirMu       sync.RWMutex
+	configDirOverride string
+)
+
+// SetConfigDirOverride makes ResolveConfigDir return dir, e.g. from the --config-dir flag
+func SetConfigDirOverride(dir string) {
+	configDirMu.Lock()
+	defer configDirMu.Unlock()
+	configDirOverride = dir
+}
+
+// ResolveConfigDir returns the directory everything sqlterm stores derives from.
+// In order it honours the --config-dir flag, $SQLTERM_CONFIG_DIR, $XDG_CONFIG_HOME,
+// then the platform default (~/.config on Linux, ~/Library/Application Support on
+// macOS, %AppData% on Windows). When the chosen directory does not exist yet, an
+// existing ~/.config/sqlterm from an older release keeps being used so nobody
+// loses saved connections.
+func ResolveConfigDir() (string, error) {
+	configDirMu.RLock()
+	override := configDirOverride
+	configDirMu.RUnlock()
+
+	if override != "" {
+		return filepath.Abs(override)
+	}
+	if dir := os.Getenv(ConfigDirEnv); dir != "" {
+		return filepath.Abs(dir)
+	}
+	var dir string
+	if xdg := os.Getenv("XDG_CONFIG_HOME"); xdg != "" && filepath.IsAbs(xdg) {
+		dir = filepath.Join(xdg, "sqlterm")
+	} else {
+		base, err := os.UserConfigDir()
+		if err != nil {
+			return "", fmt.Errorf("failed to find user config directory: %w", err)
+		}
+		dir = filepath.Join(base, "sqlterm")
+	}
+
+	if _, err := os.Stat(dir); os.IsNotExist(err) {
+		if home, err := os.UserHomeDir(); err == nil {
+			legacy := filepath.Join(home, ".config", "sqlterm")
+			if info, err := os.Stat(legacy); err == nil && info.IsDir() {
+				return legacy, nil
+			}
+		}
+	}
+	return dir, nil
+}
@@ -0,0 +1,83 @@
+package config
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+)
+
+func TestResolveConfigDir(t *testing.T) {
+	flagDir := t.TempDir()
+	envDir := t.TempDir()
+	xdgDir := t.TempDir()
+
+	testCases := []struct {
+		name     string
+		override string
+		env      string
+		xdg      string
+		expected string
+	}{
+		{name: "Flag wins", override: flagDir, env: envDir, xdg: xdgDir, expected: flagDir},
+		{name: "Environment variable", env: envDir, xdg: xdgDir, expected: envDir},
+		{name: "XDG config home", xdg: xdgDir, expected: filepath.Join(xdgDir, "sqlterm")},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.name, func(t *testing.T) {
+			SetConfigDirOverride(tc.override)
+			defer SetConfigDirOverride("")
+			t.Setenv(ConfigDirEnv, tc.env)
+			t.Setenv("XDG_CONFIG_HOME", tc.xdg)
+			t.Setenv("HOME", t.TempDir())
+
+			dir, err := ResolveConfigDir()
+			if err != nil {
+				t.Fatalf("ResolveConfigDir() failed: %v", err)
+			}
+			if dir != tc.expected {
+				t.Errorf("Expected '%s', got '%s'", tc.expected, dir)
+			}
+		})
+	}
+}
+
+func TestResolveConfigDir_PlatformDefault(t *testing.T) {
+	t.Setenv(ConfigDirEnv, "")
+	t.Setenv("XDG_CONFIG_HOME", "")
+
+	dir, err := ResolveConfigDir()
+	if err != nil {
+		t.Skipf("No user config directory on this platform: %v", err)
+	}
+	if !filepath.IsAbs(dir) || filepath.Base(dir) != "sqlterm" {
+		t.Errorf("Expected an absolute path ending in sqlterm, got '%s'", dir)
+	}
+}
+
+func TestResolveConfigDir_Legacy(t *testing.T) {
+	home := t.TempDir()
+	xdg := t.TempDir()
+	legacy := filepath.Join(home, ".config", "sqlterm")
+	if err := os.MkdirAll(legacy, 0755); err != nil {
+		t.Fatal(err)
+	}
+	t.Setenv("HOME", home)
+	t.Setenv(ConfigDirEnv, "")
+	t.Setenv("XDG_CONFIG_HOME", xdg)
+
+	dir, err := ResolveConfigDir()
+	if err != nil {
+		t.Fatalf("ResolveConfigDir() failed: %v", err)
+	}
+	if dir != legacy {
+		t.Errorf("Expected the existing '%s' while '%s' does not exist, got '%s'", legacy, filepath.Join(xdg, "sqlterm"), dir)
+	}
+
+	if err := os.Mkdir(filepath.Join(xdg, "sqlterm"), 0755); err != nil {
+		t.Fatal(err)
+	}
+	if dir, err := ResolveConfigDir(); err != nil || dir != filepath.Join(xdg, "sqlterm") {
+		t.Errorf("Expected the XDG directory once it exists, got '%s', %v", dir, err)
+	}
+}
@@ -0,0 +1,112 @@
+package config
+
+import (
+	"fmt"
+	"strings"
+	"time"
+
+	"sqlterm/internal/core"
+)
+
+// Timezone settings understood besides IANA zone names
+const (
+	TimezoneUTC   = "utc"
+	TimezoneLocal = "local"
+)
+
+// Hyperlinks settings: auto links URLs in result cells when the terminal
+// seems to support OSC 8 hyperlinks
+const (
+	HyperlinksAuto = "auto"
+	HyperlinksOn   = "on"
+	HyperlinksOff  = "off"
+)
+
+// DateTimeFormatDefault selects the built-in datetime layouts
+const DateTimeFormatDefault = "default"
+
+// dateTimeFormatPresets maps friendly names to Go time layouts
+var dateTimeFormatPresets = map[string]string{
+	"iso":     "2006-01-02T15:04:05Z07:00",
+	"rfc1123": time.RFC1123Z,
+	"us":      "01/02/2006 03:04:05 PM",
+	"eu":      "02/01/2006 15:04:05",
+}
+
+// Location resolves the configured timezone, defaulting to UTC
+func (d DisplayConfig) Location() (*time.Location, error) {
+	switch strings.ToLower(d.Timezone) {
+	case "", TimezoneUTC:
+		return time.UTC, nil
+	case TimezoneLocal:
+		return time.Local, nil
+	default:
+		loc, err := time.LoadLocation(d.Timezone)
+		if err != nil {
+			return nil, fmt.Errorf("unknown timezone %q: %w", d.Timezone, err)
+		}
+		return loc, nil
+	}
+}
+
+// DateTimeLayout resolves the configured datetime format to a Go layout; empty means the default
+func (d DisplayConfig) DateTimeLayout() string {
+	if d.DateTimeFormat == "" || d.DateTimeFormat == DateTimeFormatDefault {
+		return ""
+	}
+	if layout, ok := dateTimeFormatPresets[strings.ToLower(d.DateTimeFormat)]; ok {
+		return layout
+	}
+	return d.DateTimeFormat
+}
+
+// QueryLineWidth resolves the configured query_width, defaulting to core.DefaultQueryWidth
+func (d DisplayConfig) QueryLineWidth() int {
+	if d.QueryWidth <= 0 {
+		return core.DefaultQueryWidth
+	}
+	return d.QueryWidth
+}
+
+// HyperlinksMode resolves the configured hyperlinks setting, defaulting to HyperlinksAuto
+func (d DisplayConfig) HyperlinksMode() string {
+	switch strings.ToLower(d.Hyperlinks) {
+	case HyperlinksOn:
+		return HyperlinksOn
+	case HyperlinksOff:
+		return HyperlinksOff
+	default:
+		return HyperlinksAuto
+	}
+}
+
+// SortOptions converts sort_collation and sort_numeric into core sort
+// options; an unknown collation falls back to core.CollationUnicode
+func (d DisplayConfig) SortOptions() core.SortOptions {
+	collation, ok := core.ParseCollation(d.SortCollation)
+	if !ok {
+		collation = core.CollationUnicode
+	}
+	return core.SortOptions{Collation: collation, Numeric: d.SortNumeric}
+}
+
+// FormatOptions converts the display settings into core formatting options
+func (d DisplayConfig) FormatOptions() (core.FormatOptions, error) {
+	opts := core.DefaultFormatOptions()
+
+	loc, err := d.Location()
+	if err != nil {
+		return opts, err
+	}
+	opts.Location = loc
+	opts.DateTimeLayout = d.DateTimeLayout()
+	opts.ThousandsSeparator = d.ThousandsSeparator
+	if d.FloatPrecision != nil {
+		opts.FloatPrecision = *d.FloatPrecision
+	}
+	opts.RowLimit = d.RowLimit
+	opts.MaxCellWidth = d.MaxCellWidth
+	opts.HideTruncatedLength = d.HideTruncatedLength
+
+	return opts, nil
+}
@@ -0,0 +1,122 @@
+package config
+
+import (
+	"testing"
+	"time"
+
+	"sqlterm/internal/core"
+)
+
+func TestDisplayConfig_FormatOptions(t *testing.T) {
+	instant := core.DateTimeTzValue{Value: time.Date(2024, 3, 9, 3, 30, 0, 0, time.UTC)}
+
+	utc, err := DisplayConfig{}.FormatOptions()
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+
+	shanghai, err := DisplayConfig{Timezone: "Asia/Shanghai", DateTimeFormat: "iso"}.FormatOptions()
+	if err != nil {
+		t.Skipf("Timezone database not available: %v", err)
+	}
+
+	if got := core.FormatValue(instant, utc); got != "2024-03-09 03:30:00+0000" {
+		t.Errorf("Expected UTC rendering by default, got '%s'", got)
+	}
+
+	if got := core.FormatValue(instant, shanghai); got != "2024-03-09T11:30:00+08:00" {
+		t.Errorf("Expected Shanghai ISO rendering, got '%s'", got)
+	}
+}
+
+func TestDisplayConfig_Location(t *testing.T) {
+	testCases := []struct {
+		name     string
+		timezone string
+		expected *time.Location
+		hasError bool
+	}{
+		{name: "Default is UTC", timezone: "", expected: time.UTC},
+		{name: "Explicit UTC", timezone: "UTC", expected: time.UTC},
+		{name: "Local", timezone: "local", expected: time.Local},
+		{name: "Unknown zone", timezone: "Mars/Olympus", hasError: true},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.name, func(t *testing.T) {
+			loc, err := DisplayConfig{Timezone: tc.timezone}.Location()
+			if tc.hasError {
+				if err == nil {
+					t.Error("Expected error for unknown timezone")
+				}
+				return
+			}
+			if err != nil {
+				t.Fatalf("Unexpected error: %v", err)
+			}
+			if loc != tc.expected {
+				t.Errorf("Expected %v, got %v", tc.expected, loc)
+			}
+		})
+	}
+}
+
+func TestDisplayConfig_FloatPrecision(t *testing.T) {
+	opts, _ := DisplayConfig{}.FormatOptions()
+	if opts.FloatPrecision != -1 {
+		t.Errorf("Expected shortest float form by default, got precision %d", opts.FloatPrecision)
+	}
+
+	precision := 3
+	opts, _ = DisplayConfig{FloatPrecision: &precision, ThousandsSeparator: true}.FormatOptions()
+	if opts.FloatPrecision != 3 || !opts.ThousandsSeparator {
+		t.Errorf("Expected configured precision and separator, got %+v", opts)
+	}
+}
+
+func TestSchemaConfig_TTL(t *testing.T) {
+	testCases := []struct {
+		name     string
+		cacheTTL string
+		expected time.Duration
+		hasError bool
+	}{
+		{name: "Default", cacheTTL: "", expected: core.DefaultSchemaCacheTTL},
+		{name: "Custom", cacheTTL: "90s", expected: 90 * time.Second},
+		{name: "Disabled", cacheTTL: "0", expected: 0},
+		{name: "Invalid", cacheTTL: "soon", expected: core.DefaultSchemaCacheTTL, hasError: true},
+		{name: "Negative", cacheTTL: "-1m", expected: core.DefaultSchemaCacheTTL, hasError: true},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.name, func(t *testing.T) {
+			ttl, err := SchemaConfig{CacheTTL: tc.cacheTTL}.TTL()
+			if (err != nil) != tc.hasError {
+				t.Errorf("Expected error=%v, got %v", tc.hasError, err)
+			}
+			if ttl != tc.expected {
+				t.Errorf("Expected TTL %v, got %v", tc.expected, ttl)
+			}
+		})
+	}
+}
+
+func TestDisplayConfig_SortOptions(t *testing.T) {
+	if opts := (DisplayConfig{}).SortOptions(); opts.Collation != core.CollationUnicode || opts.Numeric {
+		t.Errorf("Expected Unicode collation without numeric sorting by default, got %+v", opts)
+	}
+	if opts := (DisplayConfig{SortCollation: "bytes", SortNumeric: true}).SortOptions(); opts.Collation != core.CollationBytes || !opts.Numeric {
+		t.Errorf("Expected byte collation with numeric sorting, got %+v", opts)
+	}
+	if opts := (DisplayConfig{SortCollation: "icu"}).SortOptions(); opts.Collation != core.CollationUnicode {
+		t.Errorf("Expected an unknown collation to fall back to Unicode, got %+v", opts)
+	}
+}
+
+func TestDisplayConfig_HyperlinksMode(t *testing.T) {
+	for setting, want := range map[string]string{"": HyperlinksAuto, "auto": HyperlinksAuto, "ON": HyperlinksOn, "off": HyperlinksOff, "sometimes": HyperlinksAuto} {
+		if got := (DisplayConfig{Hyperlinks: setting}).HyperlinksMode(); got != want {
+			t.Errorf("Expected %q for %q, got %q", want, setting, got)
+		}
+	}
+}
@@ -4,6 +4,8 @@ import (
 	"fmt"
 	"os"
 	"path/filepath"
+	"sync"
+	"time"
 
 	"sqlterm/internal/core"
 
@@ -12,15 +14,21 @@ import (
 
 type Manager struct {
 	configDir string
+
+	// Parsed connections are reused until the connections directory changes,
+	// so completion can list them on every keystroke without touching each file
+	mu          sync.Mutex
+	connections []*core.ConnectionConfig
+	modTime     time.Time
+	loads       int
 }
 
 func NewManager() *Manager {
-	home, err := os.UserHomeDir()
+	configDir, err := ResolveConfigDir()
 	if err != nil {
-		panic(fmt.Sprintf("failed to get user home directory: %v", err))
+		panic(err.Error())
 	}
 
-	configDir := filepath.Join(home, ".config", "sqlterm")
 	if err := os.MkdirAll(configDir, 0755); err != nil {
 		panic(fmt.Sprintf("failed to create config directory: %v", err))
 	}
@@ -34,6 +42,25 @@ func (m *Manager) GetConfigDir() string {
 	return m.configDir
 }
 
+// AuditLogPath returns where statements on conn are audited: its audit_log_path,
+// or audit/<name>.log under the config directory
+func (m *Manager) AuditLogPath(conn *core.ConnectionConfig) string {
+	if conn.AuditLogPath != "" {
+		return core.ExpandPath(conn.AuditLogPath)
+	}
+	return filepath.Join(m.configDir, "audit", conn.Name+".log")
+}
+
+// ResultHistoryPath returns where the numbered query history of sessions is saved
+func (m *Manager) ResultHistoryPath() string {
+	return filepath.Join(m.configDir, "sessions", "result_history.yaml")
+}
+
+// ConnectionPath returns the file the saved connection name is kept in
+func (m *Manager) ConnectionPath(name string) string {
+	return filepath.Join(m.configDir, "connections", name+".yaml")
+}
+
 func (m *Manager) SaveConnection(config *core.ConnectionConfig) error {
 	connectionsDir := filepath.Join(m.configDir, "connections")
 	if err := os.MkdirAll(connectionsDir, 0755); err != nil {
@@ -52,17 +79,18 @@ func (m *Manager) SaveConnection(config *core.ConnectionConfig) error {
 		return fmt.Errorf("failed to write config file: %w", err)
 	}
 
+	m.invalidateConnections()
 	return nil
 }
 
 func (m *Manager) LoadConnection(name string) (*core.ConnectionConfig, error) {
-	filename := fmt.Sprintf("%s.yaml", name)
-	filepath := filepath.Join(m.configDir, "connections", filename)
-
-	data, err := os.ReadFile(filepath)
+	data, err := os.ReadFile(m.ConnectionPath(name))
 	if err != nil {
 		return nil, fmt.Errorf("failed to read config file: %w", err)
 	}
+	m.mu.Lock()
+	m.loads++
+	m.mu.Unlock()
 
 	var config core.ConnectionConfig
 	if err := yaml.Unmarshal(data, &config); err != nil {
@@ -72,10 +100,12 @@ func (m *Manager) LoadConnection(name string) (*core.ConnectionConfig, error) {
 	return &config, nil
 }
 
+// ListConnections returns the saved connections. The result is cached and only
+// re-read when the connections directory's modification time changes.
 func (m *Manager) ListConnections() ([]*core.ConnectionConfig, error) {
 	connectionsDir := filepath.Join(m.configDir, "connections")
 
-	entries, err := os.ReadDir(connectionsDir)
+	info, err := os.Stat(connectionsDir)
 	if os.IsNotExist(err) {
 		return []*core.ConnectionConfig{}, nil
 	}
@@ -83,24 +113,55 @@ func (m *Manager) ListConnections() ([]*core.ConnectionConfig, error) {
 		return nil, fmt.Errorf("failed to read connections directory: %w", err)
 	}
 
+	m.mu.Lock()
+	if m.connections != nil && info.ModTime().Equal(m.modTime) {
+		connections := m.connections
+		m.mu.Unlock()
+		return connections, nil
+	}
+	m.mu.Unlock()
+
+	entries, err := os.ReadDir(connectionsDir)
+	if err != nil {
+		return nil, fmt.Errorf("failed to read connections directory: %w", err)
+	}
+
 	var connections []*core.ConnectionConfig
 	for _, entry := range entries {
-		if entry.IsDir() || filepath.Ext(entry.Name()) != ".yaml" {
+		if entry.IsDir() || !isConnectionFile(entry.Name()) {
 			continue
 		}
 
 		name := entry.Name()[:len(entry.Name())-5] // Remove .yaml extension
 		config, err := m.LoadConnection(name)
 		if err != nil {
-			continue // Skip corrupted files
+			// Skip corrupted files so the others stay usable; connecting to this one still fails
+			core.WarnFile(filepath.Join(connectionsDir, entry.Name()), err, false)
+			continue
 		}
 
 		connections = append(connections, config)
 	}
+	if connections == nil {
+		connections = []*core.ConnectionConfig{}
+	}
+
+	m.mu.Lock()
+	m.connections = connections
+	m.modTime = info.ModTime()
+	m.mu.Unlock()
 
 	return connections, nil
 }
 
+// invalidateConnections forces the next ListConnections to re-read the directory.
+// Directory mtimes can be coarse, so writes made through the manager do not rely on them.
+func (m *Manager) invalidateConnections() {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	m.connections = nil
+}
+
 func (m *Manager) DeleteConnection(name string) error {
 	filename := fmt.Sprintf("%s.yaml", name)
 	filepath := filepath.Join(m.configDir, "connections", filename)
@@ -109,5 +170,6 @@ func (m *Manager) DeleteConnection(name string) error {
 		return fmt.Errorf("failed to delete config file: %w", err)
 	}
 
+	m.invalidateConnections()
 	return nil
 }
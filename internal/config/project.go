@@ -0,0 +1,88 @@
+package config
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+
+	"gopkg.in/yaml.v3"
+)
+
+// Project markers: a sqlterm.yaml file or a .sqlterm directory
+const (
+	ProjectFileName = "sqlterm.yaml"
+	ProjectDirName  = ".sqlterm"
+)
+
+// Project is a directory tree sqlterm was started in, found by walking up
+// from the working directory to the nearest project marker
+type Project struct {
+	Root string `yaml:"-"`
+	// Settings is the file the project settings were read from; empty when there is none
+	Settings string `yaml:"-"`
+
+	// DefaultConnection is connected to at startup when no connection is given
+	DefaultConnection string `yaml:"default_connection,omitempty"`
+	// ReadOnly rejects statements that could modify data or schema
+	ReadOnly bool `yaml:"read_only,omitempty"`
+	// RowLimit overrides display.row_limit from the user settings
+	RowLimit int `yaml:"row_limit,omitempty"`
+	// InitSQL runs after every connection is opened, e.g. SET search_path TO app
+	InitSQL []string `yaml:"init_sql,omitempty"`
+}
+
+// ApplyDisplay merges the project settings over the user's display settings.
+// Project values win whenever they are set. A nil project leaves d unchanged.
+func (p *Project) ApplyDisplay(d DisplayConfig) DisplayConfig {
+	if p != nil && p.RowLimit > 0 {
+		d.RowLimit = p.RowLimit
+	}
+	return d
+}
+
+// FindProject walks up from start looking for a project marker; it returns nil when there is none
+func FindProject(start string) (*Project, error) {
+	dir, err := filepath.Abs(start)
+	if err != nil {
+		return nil, err
+	}
+
+	for {
+		if project, ok, err := loadProject(dir); ok || err != nil {
+			return project, err
+		}
+
+		parent := filepath.Dir(dir)
+		if parent == dir {
+			return nil, nil
+		}
+		dir = parent
+	}
+}
+
+// loadProject reads the project settings in dir, if dir is a project root
+func loadProject(dir string) (*Project, bool, error) {
+	settings := filepath.Join(dir, ProjectFileName)
+	if info, err := os.Stat(settings); err != nil || info.IsDir() {
+		info, err := os.Stat(filepath.Join(dir, ProjectDirName))
+		if err != nil || !info.IsDir() {
+			return nil, false, nil
+		}
+		settings = filepath.Join(dir, ProjectDirName, "config.yaml")
+	}
+
+	project := &Project{Root: dir}
+	data, err := os.ReadFile(settings)
+	if os.IsNotExist(err) {
+		return project, true, nil
+	}
+	if err != nil {
+		return nil, true, fmt.Errorf("failed to read project settings: %w", err)
+	}
+	if err := yaml.Unmarshal(data, project); err != nil {
+		return nil, true, fmt.Errorf("failed to parse %s: %w", settings, err)
+	}
+	project.Root = dir
+	project.Settings = settings
+	return project, true, nil
+}
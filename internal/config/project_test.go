@@ -0,0 +1,103 @@
+package config
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+)
+
+func TestFindProject(t *testing.T) {
+	root := t.TempDir()
+	nested := filepath.Join(root, "reports", "monthly")
+	os.MkdirAll(nested, 0755)
+
+	if project, err := FindProject(nested); err != nil || project != nil && project.Root == root {
+		t.Fatalf("Expected no project before a marker exists, got %v, %v", project, err)
+	}
+
+	os.WriteFile(filepath.Join(root, ProjectFileName), []byte("default_connection: analytics\n"), 0644)
+
+	project, err := FindProject(nested)
+	if err != nil || project == nil {
+		t.Fatalf("Expected a project, got %v, %v", project, err)
+	}
+	if project.Root != root {
+		t.Errorf("Expected root '%s', got '%s'", root, project.Root)
+	}
+	if project.DefaultConnection != "analytics" {
+		t.Errorf("Expected default connection 'analytics', got '%s'", project.DefaultConnection)
+	}
+}
+
+func TestFindProject_DotDirectory(t *testing.T) {
+	root := t.TempDir()
+	os.MkdirAll(filepath.Join(root, ProjectDirName), 0755)
+	os.MkdirAll(filepath.Join(root, "sql"), 0755)
+
+	project, err := FindProject(filepath.Join(root, "sql"))
+	if err != nil || project == nil || project.Root != root {
+		t.Fatalf("Expected a project rooted at '%s', got %v, %v", root, project, err)
+	}
+	if project.DefaultConnection != "" {
+		t.Errorf("Expected no default connection, got '%s'", project.DefaultConnection)
+	}
+}
+
+func TestFindProject_InvalidSettings(t *testing.T) {
+	root := t.TempDir()
+	os.WriteFile(filepath.Join(root, ProjectFileName), []byte("default_connection: [unclosed\n"), 0644)
+
+	if _, err := FindProject(root); err == nil {
+		t.Error("Expected invalid project settings to be reported")
+	}
+}
+
+func TestFindProject_Settings(t *testing.T) {
+	root := t.TempDir()
+	settings := `default_connection: analytics
+read_only: true
+row_limit: 50
+init_sql:
+  - SET search_path TO app, analytics
+  - SET TIME ZONE 'UTC'
+`
+	os.WriteFile(filepath.Join(root, ProjectFileName), []byte(settings), 0644)
+
+	project, err := FindProject(root)
+	if err != nil || project == nil {
+		t.Fatalf("Expected a project, got %v, %v", project, err)
+	}
+
+	if project.Settings != filepath.Join(root, ProjectFileName) {
+		t.Errorf("Expected settings path to be recorded, got '%s'", project.Settings)
+	}
+	if !project.ReadOnly || project.RowLimit != 50 || len(project.InitSQL) != 2 {
+		t.Errorf("Expected all settings to be read, got %+v", project)
+	}
+}
+
+func TestProject_ApplyDisplay(t *testing.T) {
+	user := DisplayConfig{RowLimit: 100, ThousandsSeparator: true}
+
+	testCases := []struct {
+		name     string
+		project  *Project
+		expected int
+	}{
+		{name: "No project", project: nil, expected: 100},
+		{name: "Project without a limit", project: &Project{}, expected: 100},
+		{name: "Project limit wins", project: &Project{RowLimit: 10}, expected: 10},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.name, func(t *testing.T) {
+			merged := tc.project.ApplyDisplay(user)
+			if merged.RowLimit != tc.expected {
+				t.Errorf("Expected row limit %d, got %d", tc.expected, merged.RowLimit)
+			}
+			if !merged.ThousandsSeparator {
+				t.Error("Expected other user settings to be kept")
+			}
+		})
+	}
+}
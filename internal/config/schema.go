@@ -0,0 +1,20 @@
+package config
+
+import (
+	"fmt"
+	"time"
+
+	"sqlterm/internal/core"
+)
+
+// TTL resolves the configured schema cache lifetime, defaulting to core.DefaultSchemaCacheTTL
+func (s SchemaConfig) TTL() (time.Duration, error) {
+	if s.CacheTTL == "" {
+		return core.DefaultSchemaCacheTTL, nil
+	}
+	ttl, err := time.ParseDuration(s.CacheTTL)
+	if err != nil || ttl < 0 {
+		return core.DefaultSchemaCacheTTL, fmt.Errorf("invalid schema cache TTL %q", s.CacheTTL)
+	}
+	return ttl, nil
+}
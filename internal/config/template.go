@@ -0,0 +1,302 @@
+package config
+
+import (
+	"errors"
+	"fmt"
+	"os"
+	"path/filepath"
+	"slices"
+	"strings"
+
+	"sqlterm/internal/core"
+
+	"gopkg.in/yaml.v3"
+)
+
+var (
+	// ErrTemplateSyntax is returned for a template value with a brace that opens or closes no placeholder
+	ErrTemplateSyntax = errors.New("invalid connection template")
+	// ErrTemplateValues is returned when the values given for a template do not match its placeholders
+	ErrTemplateValues = errors.New("template values do not match its placeholders")
+	// ErrTemplateName is returned for a template name that would lead outside the templates directory
+	ErrTemplateName = errors.New("invalid template name")
+)
+
+// ConnectionTemplate is a connection file whose values hold {placeholders},
+// such as database: "app_{tenant}", for adding many connections that differ
+// only in those values. {{ and }} stand for literal braces.
+type ConnectionTemplate struct {
+	Name string
+	// Placeholders are the names the template needs values for, in the order they first appear
+	Placeholders []string
+
+	data []byte
+}
+
+// TemplatePath returns the file the connection template name is kept in
+func (m *Manager) TemplatePath(name string) string {
+	return filepath.Join(m.configDir, "templates", name+".yaml")
+}
+
+// LoadTemplate reads the connection template name. The name must be a file
+// name in the templates directory, not a path.
+func (m *Manager) LoadTemplate(name string) (*ConnectionTemplate, error) {
+	if !isFileName(name) {
+		return nil, fmt.Errorf("%w: %q", ErrTemplateName, name)
+	}
+	data, err := os.ReadFile(m.TemplatePath(name))
+	if err != nil {
+		return nil, fmt.Errorf("failed to read template file: %w", err)
+	}
+	return ParseTemplate(name, data)
+}
+
+// ListTemplates returns the connection templates by name. Files that cannot
+// be read are reported and skipped.
+func (m *Manager) ListTemplates() ([]*ConnectionTemplate, error) {
+	dir := filepath.Join(m.configDir, "templates")
+	entries, err := os.ReadDir(dir)
+	if os.IsNotExist(err) {
+		return []*ConnectionTemplate{}, nil
+	}
+	if err != nil {
+		return nil, fmt.Errorf("failed to read templates directory: %w", err)
+	}
+
+	templates := []*ConnectionTemplate{}
+	for _, entry := range entries {
+		if entry.IsDir() || !isConnectionFile(entry.Name()) {
+			continue
+		}
+		template, err := m.LoadTemplate(strings.TrimSuffix(entry.Name(), ".yaml"))
+		if err != nil {
+			core.WarnFile(filepath.Join(dir, entry.Name()), err, false)
+			continue
+		}
+		templates = append(templates, template)
+	}
+	return templates, nil
+}
+
+// ParseTemplate reads a connection template and finds its placeholders
+func ParseTemplate(name string, data []byte) (*ConnectionTemplate, error) {
+	root, err := parseTemplateNode(data)
+	if err != nil {
+		return nil, err
+	}
+	template := &ConnectionTemplate{Name: name, data: data}
+	err = walkTemplateValues(root, func(value *yaml.Node) error {
+		_, err := ExpandPlaceholders(value.Value, func(placeholder string) (string, bool) {
+			if !slices.Contains(template.Placeholders, placeholder) {
+				template.Placeholders = append(template.Placeholders, placeholder)
+			}
+			return "", true
+		})
+		return err
+	})
+	if err != nil {
+		return nil, fmt.Errorf("template %s: %w", name, err)
+	}
+	return template, nil
+}
+
+// Expand fills the placeholders with values and returns the connection. Every
+// placeholder needs a value and every value a placeholder. The values are
+// used exactly as given; nothing in them is expanded further. A template
+// without a name gives the connection one made of its own name and the
+// values, e.g. tenant-acme.
+func (t *ConnectionTemplate) Expand(values map[string]string) (*core.ConnectionConfig, error) {
+	var missing, unknown []string
+	for _, placeholder := range t.Placeholders {
+		if _, ok := values[placeholder]; !ok {
+			missing = append(missing, placeholder)
+		}
+	}
+	for name := range values {
+		if !slices.Contains(t.Placeholders, name) {
+			unknown = append(unknown, name)
+		}
+	}
+	slices.Sort(unknown)
+	switch {
+	case len(missing) > 0:
+		return nil, fmt.Errorf("%w: template %s needs %s; no value given for %s", ErrTemplateValues,
+			t.Name, t.Usage(), strings.Join(missing, ", "))
+	case len(unknown) > 0:
+		return nil, fmt.Errorf("%w: template %s has no placeholder %s; it takes %s", ErrTemplateValues,
+			t.Name, strings.Join(unknown, ", "), t.Usage())
+	}
+
+	root, err := parseTemplateNode(t.data)
+	if err != nil {
+		return nil, err
+	}
+	err = walkTemplateValues(root, func(value *yaml.Node) error {
+		expanded, err := ExpandPlaceholders(value.Value, func(placeholder string) (string, bool) {
+			v, ok := values[placeholder]
+			return v, ok
+		})
+		if err != nil || expanded == value.Value {
+			return err
+		}
+		value.Value = expanded
+		// An unquoted value is read by what it becomes, so port: {port} gives a number
+		if value.Style == 0 {
+			value.Tag = ""
+		}
+		return nil
+	})
+	if err != nil {
+		return nil, fmt.Errorf("template %s: %w", t.Name, err)
+	}
+
+	var config core.ConnectionConfig
+	if err := root.Decode(&config); err != nil {
+		return nil, fmt.Errorf("failed to read template %s: %w", t.Name, err)
+	}
+	if config.Name == "" {
+		config.Name = t.Name
+		for _, placeholder := range t.Placeholders {
+			config.Name += "-" + values[placeholder]
+		}
+	}
+	if !isFileName(config.Name) {
+		return nil, fmt.Errorf("%w: template %s gives the connection name %q, which cannot be a file name", ErrTemplateValues, t.Name, config.Name)
+	}
+	return &config, nil
+}
+
+// Usage lists the arguments the template takes, e.g. "tenant=<tenant> region=<region>"
+func (t *ConnectionTemplate) Usage() string {
+	if len(t.Placeholders) == 0 {
+		return "no values"
+	}
+	args := make([]string, len(t.Placeholders))
+	for i, placeholder := range t.Placeholders {
+		args[i] = placeholder + "=<" + placeholder + ">"
+	}
+	return strings.Join(args, " ")
+}
+
+// ParseTemplateValues reads name=value arguments. A value keeps any = it
+// holds and is taken as typed.
+func ParseTemplateValues(args []string) (map[string]string, error) {
+	values := make(map[string]string, len(args))
+	for _, arg := range args {
+		name, value, ok := strings.Cut(arg, "=")
+		if !ok || !isPlaceholderName(name) {
+			return nil, fmt.Errorf("%w: expected name=value, got %q", ErrTemplateValues, arg)
+		}
+		if _, ok := values[name]; ok {
+			return nil, fmt.Errorf("%w: %s given twice", ErrTemplateValues, name)
+		}
+		values[name] = value
+	}
+	return values, nil
+}
+
+// ExpandPlaceholders replaces each {name} in text with what lookup returns
+// for it, and {{ and }} with single braces. It fails on a brace that does not
+// belong to a placeholder, or a name lookup has no value for.
+func ExpandPlaceholders(text string, lookup func(name string) (string, bool)) (string, error) {
+	var sb strings.Builder
+	for i := 0; i < len(text); i++ {
+		switch c := text[i]; {
+		case c == '{' && strings.HasPrefix(text[i:], "{{"):
+			sb.WriteByte('{')
+			i++
+		case c == '}' && strings.HasPrefix(text[i:], "}}"):
+			sb.WriteByte('}')
+			i++
+		case c == '{':
+			end := strings.IndexByte(text[i:], '}')
+			if end < 0 || !isPlaceholderName(text[i+1:i+end]) {
+				return "", fmt.Errorf("%w: %q has a { at %d that starts no {name}; write {{ for a brace", ErrTemplateSyntax, text, i+1)
+			}
+			name := text[i+1 : i+end]
+			value, ok := lookup(name)
+			if !ok {
+				return "", fmt.Errorf("%w: no value for {%s}", ErrTemplateValues, name)
+			}
+			sb.WriteString(value)
+			i += end
+		case c == '}':
+			return "", fmt.Errorf("%w: %q has a } at %d that ends no {name}; write }} for a brace", ErrTemplateSyntax, text, i+1)
+		default:
+			sb.WriteByte(c)
+		}
+	}
+	return sb.String(), nil
+}
+
+// isFileName reports whether name can be used as a file name in a config
+// directory: not empty, without path separators, and not . or .. or anything
+// holding ..
+func isFileName(name string) bool {
+	return name != "" && name != "." && !strings.Contains(name, "..") && !strings.ContainsAny(name, `/\`)
+}
+
+// isPlaceholderName reports whether name can name a placeholder: letters,
+// digits and underscores, not starting with a digit
+func isPlaceholderName(name string) bool {
+	if name == "" || name[0] >= '0' && name[0] <= '9' {
+		return false
+	}
+	for _, r := range name {
+		if !(r == '_' || r >= 'a' && r <= 'z' || r >= 'A' && r <= 'Z' || r >= '0' && r <= '9') {
+			return false
+		}
+	}
+	return true
+}
+
+// parseTemplateNode reads a template file into its top-level mapping
+func parseTemplateNode(data []byte) (*yaml.Node, error) {
+	var doc yaml.Node
+	if err := yaml.Unmarshal(data, &doc); err != nil {
+		return nil, fmt.Errorf("failed to unmarshal template: %w", err)
+	}
+	if len(doc.Content) == 0 || doc.Content[0].Kind != yaml.MappingNode {
+		return nil, fmt.Errorf("%w: expected the settings of a connection", ErrTemplateSyntax)
+	}
+	return doc.Content[0], nil
+}
+
+// walkTemplateValues calls fn on every scalar value under node, leaving keys
+// alone. An unquoted {name} reads in YAML as a mapping of name to nothing;
+// such a value is turned back into the text it was written as first.
+func walkTemplateValues(node *yaml.Node, fn func(value *yaml.Node) error) error {
+	switch node.Kind {
+	case yaml.ScalarNode:
+		return fn(node)
+	case yaml.MappingNode:
+		if placeholder, ok := flowPlaceholder(node); ok {
+			*node = yaml.Node{Kind: yaml.ScalarNode, Value: "{" + placeholder + "}", Line: node.Line, Column: node.Column}
+			return fn(node)
+		}
+		for i := 1; i < len(node.Content); i += 2 {
+			if err := walkTemplateValues(node.Content[i], fn); err != nil {
+				return err
+			}
+		}
+	case yaml.SequenceNode:
+		for _, item := range node.Content {
+			if err := walkTemplateValues(item, fn); err != nil {
+				return err
+			}
+		}
+	}
+	return nil
+}
+
+// flowPlaceholder reports whether node is an unquoted {name}
+func flowPlaceholder(node *yaml.Node) (string, bool) {
+	if node.Style&yaml.FlowStyle == 0 || len(node.Content) != 2 {
+		return "", false
+	}
+	key, value := node.Content[0], node.Content[1]
+	if key.Kind != yaml.ScalarNode || !isPlaceholderName(key.Value) || value.ShortTag() != "!!null" || value.Value != "" {
+		return "", false
+	}
+	return key.Value, true
+}
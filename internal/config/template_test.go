@@ -0,0 +1,194 @@
+package config
+
+import (
+	"errors"
+	"os"
+	"path/filepath"
+	"slices"
+	"strings"
+	"testing"
+
+	"sqlterm/internal/core"
+)
+
+func TestExpandPlaceholders(t *testing.T) {
+	values := map[string]string{"tenant": "acme", "region": "eu", "odd": "{region} $HOME `id`"}
+	lookup := func(name string) (string, bool) {
+		v, ok := values[name]
+		return v, ok
+	}
+
+	tests := []struct {
+		name     string
+		text     string
+		expected string
+	}{
+		{"plain", "localhost", "localhost"},
+		{"whole value", "{tenant}", "acme"},
+		{"inside text", "app_{tenant}_{region}", "app_acme_eu"},
+		{"repeated", "{tenant}-{tenant}", "acme-acme"},
+		{"escaped braces", "{{tenant}}", "{tenant}"},
+		{"escaped around a placeholder", "{{{tenant}}}", "{acme}"},
+		{"lone escapes", "a{{b}}c", "a{b}c"},
+		{"values are not expanded again", "{odd}", "{region} $HOME `id`"},
+		{"shell syntax is kept", "$(whoami)_{tenant}", "$(whoami)_acme"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got, err := ExpandPlaceholders(tt.text, lookup)
+			if err != nil {
+				t.Fatalf("Unexpected error: %v", err)
+			}
+			if got != tt.expected {
+				t.Errorf("Expected %q, got %q", tt.expected, got)
+			}
+		})
+	}
+
+	for _, text := range []string{"{", "app_{tenant", "{}", "{1st}", "{ten ant}", "}", "a}b", "{tenant}}"} {
+		if got, err := ExpandPlaceholders(text, lookup); !errors.Is(err, ErrTemplateSyntax) {
+			t.Errorf("Expected %q to be refused, got %q, %v", text, got, err)
+		}
+	}
+	if _, err := ExpandPlaceholders("{missing}", lookup); !errors.Is(err, ErrTemplateValues) {
+		t.Errorf("Expected a placeholder without a value to fail, got %v", err)
+	}
+}
+
+const tenantTemplate = `database_type: postgres
+host: db.internal
+port: {port}
+database: {tenant}
+username: "app_{tenant}"
+password: "p{{w}}d"
+environment: '{env}'
+`
+
+func TestConnectionTemplate_Expand(t *testing.T) {
+	template, err := ParseTemplate("tenant", []byte(tenantTemplate))
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if want := []string{"port", "tenant", "env"}; !slices.Equal(template.Placeholders, want) {
+		t.Errorf("Expected placeholders %v, got %v", want, template.Placeholders)
+	}
+
+	config, err := template.Expand(map[string]string{"tenant": "acme", "port": "6432", "env": "staging"})
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	expected := core.ConnectionConfig{
+		Name:         "tenant-6432-acme-staging",
+		DatabaseType: core.PostgreSQL,
+		Host:         "db.internal",
+		Port:         6432,
+		Database:     "acme",
+		Username:     "app_acme",
+		Password:     "p{w}d",
+		Environment:  "staging",
+	}
+	if config.Name != expected.Name || config.DatabaseType != expected.DatabaseType || config.Host != expected.Host ||
+		config.Port != expected.Port || config.Database != expected.Database || config.Username != expected.Username ||
+		config.Password != expected.Password || config.Environment != expected.Environment || len(config.Extra) != 0 {
+		t.Errorf("Expected %+v, got %+v", expected, config)
+	}
+
+	// A template is expanded afresh each time
+	again, _ := template.Expand(map[string]string{"tenant": "globex", "port": "5432", "env": "production"})
+	if again.Database != "globex" || again.Port != 5432 {
+		t.Errorf("Expected a second expansion with its own values, got %+v", again)
+	}
+}
+
+func TestConnectionTemplate_Expand_Values(t *testing.T) {
+	template, err := ParseTemplate("tenant", []byte("name: \"{tenant}-{region}\"\ndatabase_type: mysql\ndatabase: \"{tenant}\"\n"))
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+
+	config, err := template.Expand(map[string]string{"tenant": "acme", "region": "eu"})
+	if err != nil || config.Name != "acme-eu" {
+		t.Errorf("Expected the template's own name expanded, got %+v, %v", config, err)
+	}
+
+	_, err = template.Expand(map[string]string{"tenant": "acme"})
+	if !errors.Is(err, ErrTemplateValues) || !strings.Contains(err.Error(), "needs tenant=<tenant> region=<region>; no value given for region") {
+		t.Errorf("Expected the missing value and what the template needs, got %v", err)
+	}
+	_, err = template.Expand(map[string]string{"tenant": "acme", "region": "eu", "tennant": "x"})
+	if !errors.Is(err, ErrTemplateValues) || !strings.Contains(err.Error(), "no placeholder tennant") {
+		t.Errorf("Expected the unknown value refused, got %v", err)
+	}
+	if _, err := template.Expand(map[string]string{"tenant": "../acme", "region": "eu"}); !errors.Is(err, ErrTemplateValues) {
+		t.Errorf("Expected a name that is no file name to be refused, got %v", err)
+	}
+}
+
+func TestParseTemplate_Invalid(t *testing.T) {
+	for _, data := range []string{"database: \"app_{tenant\"\n", "- not a connection\n", "database: [\n"} {
+		if _, err := ParseTemplate("broken", []byte(data)); err == nil {
+			t.Errorf("Expected %q to be refused", data)
+		}
+	}
+}
+
+func TestParseTemplateValues(t *testing.T) {
+	values, err := ParseTemplateValues([]string{"tenant=acme", "password=a=b", "empty="})
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if values["tenant"] != "acme" || values["password"] != "a=b" || values["empty"] != "" {
+		t.Errorf("Unexpected values %v", values)
+	}
+
+	for _, args := range [][]string{{"acme"}, {"=acme"}, {"ten ant=acme"}, {"tenant=a", "tenant=b"}} {
+		if _, err := ParseTemplateValues(args); !errors.Is(err, ErrTemplateValues) {
+			t.Errorf("Expected %v to be refused, got %v", args, err)
+		}
+	}
+}
+
+func TestManager_LoadTemplate_Name(t *testing.T) {
+	dir := t.TempDir()
+	manager := &Manager{configDir: dir}
+	connections := filepath.Join(dir, "connections")
+	if err := os.MkdirAll(connections, 0755); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if err := os.WriteFile(filepath.Join(connections, "prod.yaml"), []byte("database_type: postgres\n"), 0600); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+
+	for _, name := range []string{"../connections/prod", `..\connections\prod`, "sub/tenant", "..", ".", ""} {
+		if _, err := manager.LoadTemplate(name); !errors.Is(err, ErrTemplateName) {
+			t.Errorf("Expected %q to be refused as a template name, got %v", name, err)
+		}
+	}
+}
+
+func TestManager_ListTemplates(t *testing.T) {
+	core.TakeFileWarnings()
+	manager := &Manager{configDir: t.TempDir()}
+	if templates, err := manager.ListTemplates(); err != nil || len(templates) != 0 {
+		t.Fatalf("Expected no templates yet, got %v, %v", templates, err)
+	}
+
+	dir := filepath.Dir(manager.TemplatePath("tenant"))
+	if err := os.MkdirAll(dir, 0755); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	for name, data := range map[string]string{"tenant": tenantTemplate, "broken": "database: \"{\"\n"} {
+		if err := os.WriteFile(manager.TemplatePath(name), []byte(data), 0600); err != nil {
+			t.Fatalf("Unexpected error: %v", err)
+		}
+	}
+
+	templates, err := manager.ListTemplates()
+	if err != nil || len(templates) != 1 || templates[0].Name != "tenant" {
+		t.Fatalf("Expected only the readable template, got %v, %v", templates, err)
+	}
+	if warnings := core.TakeFileWarnings(); len(warnings) != 1 {
+		t.Errorf("Expected the broken template to be reported, got %v", warnings)
+	}
+}
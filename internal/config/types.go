@@ -18,8 +18,58 @@ type AIConfig struct {
 	DefaultModels map[string]string `yaml:"default_models"`
 }
 
+// DisplayConfig holds how query results are formatted
+type DisplayConfig struct {
+	DateTimeFormat      string `yaml:"datetime_format,omitempty"`
+	Timezone            string `yaml:"timezone,omitempty"`
+	ThousandsSeparator  bool   `yaml:"thousands_separator"`
+	FloatPrecision      *int   `yaml:"float_precision,omitempty"`
+	FormatExports       bool   `yaml:"format_exports"`
+	RowLimit            int    `yaml:"row_limit,omitempty"`
+	MaxCellWidth        int    `yaml:"max_cell_width,omitempty"`
+	HideTruncatedLength bool   `yaml:"hide_truncated_length,omitempty"`
+	QueryWidth          int    `yaml:"query_width,omitempty"`
+	SortCollation       string `yaml:"sort_collation,omitempty"`
+	SortNumeric         bool   `yaml:"sort_numeric,omitempty"`
+	ServerTiming        bool   `yaml:"server_timing,omitempty"`
+	ExportMetadata      bool   `yaml:"export_metadata,omitempty"`
+	Hyperlinks          string `yaml:"hyperlinks,omitempty"`
+}
+
+// SchemaConfig holds how table metadata is cached
+type SchemaConfig struct {
+	// CacheTTL is a Go duration such as "5m"; "0" turns the cache off
+	CacheTTL string `yaml:"cache_ttl,omitempty"`
+}
+
 // Config holds the main configuration with AI section
 type Config struct {
-	Language string   `yaml:"language"`
-	AI       AIConfig `yaml:"ai"`
+	Language string        `yaml:"language"`
+	AI       AIConfig      `yaml:"ai"`
+	Display  DisplayConfig `yaml:"display"`
+	Schema   SchemaConfig  `yaml:"schema"`
+	// RedactHistory replaces literals with ? in saved history and audit logs
+	RedactHistory bool `yaml:"redact_history,omitempty"`
+	// HistoryLimit caps the entries kept in history.txt; 0 keeps the default of 500
+	HistoryLimit int `yaml:"history_limit,omitempty"`
+	// ResultMemoryMB caps the memory of the result rows kept for /show, in MiB; 0 keeps the default of 256
+	ResultMemoryMB int `yaml:"result_memory_mb,omitempty"`
+	// Editor is the command /editor opens queries with; empty uses $VISUAL, then $EDITOR, then vi
+	Editor string `yaml:"editor,omitempty"`
+	// MaxPinValues caps how many values a :pin.column reference inlines; 0 keeps the default of 1000
+	MaxPinValues int `yaml:"max_pin_values,omitempty"`
+	// Clipboard forces how /copy reaches the clipboard: auto (default), native, osc52 or register
+	Clipboard string `yaml:"clipboard,omitempty"`
+	// SingleLine runs /exec <query> on Enter even when the statement is not terminated
+	SingleLine bool `yaml:"single_line,omitempty"`
+	// StopOnError ends @file, /editor and /gexec runs at the first statement that fails
+	StopOnError bool `yaml:"stop_on_error,omitempty"`
+	// AutoLimit adds LIMIT n to SELECTs run at the prompt that have none; 0 turns it off
+	AutoLimit int `yaml:"auto_limit,omitempty"`
+	// ConnectionOrder lists connections most recently used first (recent, the default) or by name
+	ConnectionOrder string `yaml:"connection_order,omitempty"`
+	// DefaultConnection is connected to when conversation mode starts, unless the project names its own
+	DefaultConnection string `yaml:"default_connection,omitempty"`
+	// ConnectTimeout is a Go duration such as "10s" that connecting may take; "0" waits as long as the driver does
+	ConnectTimeout string `yaml:"connect_timeout,omitempty"`
 }
@@ -0,0 +1,154 @@
+package config
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"sort"
+	"strings"
+	"time"
+
+	"sqlterm/internal/core"
+	"sqlterm/internal/i18n"
+
+	"gopkg.in/yaml.v3"
+)
+
+// UsageFile is where connection usage is kept, beside the connection files
+// rather than in them so connecting never rewrites a user's connection
+const UsageFile = "connection_usage.yaml"
+
+// MaxRecentConnections bounds how many connections are listed as recent
+const MaxRecentConnections = 5
+
+// Connection orders for the connection_order setting
+const (
+	ConnectionOrderRecent = "recent"
+	ConnectionOrderName   = "name"
+)
+
+// ConnectionUsage records when a saved connection was last connected to and
+// how often, with what was learnt from the last attempt, so connection lists
+// can show it without connecting
+type ConnectionUsage struct {
+	LastUsedAt time.Time `yaml:"last_used_at"`
+	UseCount   int       `yaml:"use_count"`
+	// LastVersion is the server version reported on the last successful connect
+	LastVersion string `yaml:"last_version,omitempty"`
+	// LastError is why the last connect failed; a successful one clears it
+	LastError   string    `yaml:"last_error,omitempty"`
+	LastErrorAt time.Time `yaml:"last_error_at,omitempty"`
+}
+
+// LoadUsage returns the recorded usage of each connection by name. A missing
+// file means nothing was used yet, and a corrupt one is set aside and reported.
+func (m *Manager) LoadUsage() map[string]ConnectionUsage {
+	usage := make(map[string]ConnectionUsage)
+	path := filepath.Join(m.configDir, UsageFile)
+	data, err := os.ReadFile(path)
+	if err != nil {
+		if !os.IsNotExist(err) {
+			core.WarnFile(path, err, false)
+		}
+		return usage
+	}
+	if err := yaml.Unmarshal(data, &usage); err != nil {
+		core.WarnFile(path, err, true)
+		return make(map[string]ConnectionUsage)
+	}
+	if usage == nil {
+		usage = make(map[string]ConnectionUsage)
+	}
+	return usage
+}
+
+// RecordUse notes a successful connection to name. version is the server
+// version it reported, or "" to keep the one seen before.
+func (m *Manager) RecordUse(name, version string) error {
+	return m.updateUsage(name, func(entry *ConnectionUsage) {
+		entry.LastUsedAt = time.Now().UTC().Truncate(time.Second)
+		entry.UseCount++
+		if version != "" {
+			entry.LastVersion = version
+		}
+		entry.LastError, entry.LastErrorAt = "", time.Time{}
+	})
+}
+
+// RecordFailure notes that connecting to name failed with err
+func (m *Manager) RecordFailure(name string, err error) error {
+	return m.updateUsage(name, func(entry *ConnectionUsage) {
+		entry.LastError = err.Error()
+		entry.LastErrorAt = time.Now().UTC().Truncate(time.Second)
+	})
+}
+
+// updateUsage applies update to the recorded usage of name and saves it
+func (m *Manager) updateUsage(name string, update func(*ConnectionUsage)) error {
+	usage := m.LoadUsage()
+	entry := usage[name]
+	update(&entry)
+	usage[name] = entry
+
+	data, err := yaml.Marshal(usage)
+	if err != nil {
+		return fmt.Errorf("failed to marshal connection usage: %w", err)
+	}
+	if err := os.WriteFile(filepath.Join(m.configDir, UsageFile), data, 0600); err != nil {
+		return fmt.Errorf("failed to write connection usage: %w", err)
+	}
+	return nil
+}
+
+// OrderedConnections returns the saved connections in the given order, along
+// with how many of them lead the list as recently used
+func (m *Manager) OrderedConnections(order string) ([]*core.ConnectionConfig, int, error) {
+	connections, err := m.ListConnections()
+	if err != nil {
+		return nil, 0, err
+	}
+	ordered, recent := OrderConnections(connections, m.LoadUsage(), order)
+	return ordered, recent, nil
+}
+
+// OrderConnections sorts connections for listing. With ConnectionOrderName
+// they are sorted by name alone. Otherwise up to MaxRecentConnections that
+// were used come first, most recent first, followed by the rest by name;
+// recent is how many lead the list. connections itself is left as it is.
+func OrderConnections(connections []*core.ConnectionConfig, usage map[string]ConnectionUsage, order string) (ordered []*core.ConnectionConfig, recent int) {
+	ordered = append([]*core.ConnectionConfig(nil), connections...)
+	sortByName(ordered)
+	if order == ConnectionOrderName {
+		return ordered, 0
+	}
+
+	sort.SliceStable(ordered, func(i, j int) bool {
+		return usage[ordered[i].Name].LastUsedAt.After(usage[ordered[j].Name].LastUsedAt)
+	})
+	for recent < len(ordered) && recent < MaxRecentConnections && !usage[ordered[recent].Name].LastUsedAt.IsZero() {
+		recent++
+	}
+	// Past the recent ones the list goes back to names
+	sortByName(ordered[recent:])
+	return ordered, recent
+}
+
+// ConnectionsHeader returns the heading to print before the connection at
+// index i of a list whose first recent entries are recently used, or ""
+func ConnectionsHeader(i18nMgr *i18n.Manager, i, recent, total int) string {
+	switch {
+	case recent == 0:
+		return ""
+	case i == 0:
+		return i18nMgr.Get("connections_recent_header")
+	case i == recent && i < total:
+		return i18nMgr.Get("connections_other_header")
+	}
+	return ""
+}
+
+func sortByName(connections []*core.ConnectionConfig) {
+	sort.SliceStable(connections, func(i, j int) bool {
+		return strings.ToLower(connections[i].Name) < strings.ToLower(connections[j].Name)
+	})
+}
@@ -0,0 +1,158 @@
+package config
+
+import (
+	"errors"
+	"os"
+	"path/filepath"
+	"slices"
+	"testing"
+	"time"
+
+	"sqlterm/internal/core"
+	"sqlterm/internal/i18n"
+)
+
+func connectionNames(connections []*core.ConnectionConfig) []string {
+	names := make([]string, len(connections))
+	for i, conn := range connections {
+		names[i] = conn.Name
+	}
+	return names
+}
+
+func TestOrderConnections(t *testing.T) {
+	var connections []*core.ConnectionConfig
+	for _, name := range []string{"zeta", "alpha", "Mid", "beta"} {
+		connections = append(connections, &core.ConnectionConfig{Name: name})
+	}
+	now := time.Now()
+	usage := map[string]ConnectionUsage{
+		"zeta": {LastUsedAt: now.Add(-time.Hour), UseCount: 3},
+		"beta": {LastUsedAt: now, UseCount: 1},
+	}
+
+	ordered, recent := OrderConnections(connections, usage, ConnectionOrderRecent)
+	if got, want := connectionNames(ordered), []string{"beta", "zeta", "alpha", "Mid"}; !slices.Equal(got, want) {
+		t.Errorf("Recent order = %v, want %v", got, want)
+	}
+	if recent != 2 {
+		t.Errorf("Expected 2 recent connections, got %d", recent)
+	}
+
+	ordered, recent = OrderConnections(connections, usage, ConnectionOrderName)
+	if got, want := connectionNames(ordered), []string{"alpha", "beta", "Mid", "zeta"}; !slices.Equal(got, want) || recent != 0 {
+		t.Errorf("Name order = %v (recent %d), want %v", got, recent, want)
+	}
+
+	if connections[0].Name != "zeta" {
+		t.Error("Expected the given slice to be left as it was")
+	}
+}
+
+func TestOrderConnections_RecentCap(t *testing.T) {
+	usage := make(map[string]ConnectionUsage)
+	var connections []*core.ConnectionConfig
+	for i, name := range []string{"a", "b", "c", "d", "e", "f", "g"} {
+		connections = append(connections, &core.ConnectionConfig{Name: name})
+		usage[name] = ConnectionUsage{LastUsedAt: time.Unix(int64(i), 0)}
+	}
+
+	ordered, recent := OrderConnections(connections, usage, "")
+	if recent != MaxRecentConnections {
+		t.Errorf("Expected %d recent connections, got %d", MaxRecentConnections, recent)
+	}
+	// The five newest lead, and the older ones go back to name order
+	if got, want := connectionNames(ordered), []string{"g", "f", "e", "d", "c", "a", "b"}; !slices.Equal(got, want) {
+		t.Errorf("Order = %v, want %v", got, want)
+	}
+}
+
+func TestManager_RecordUse(t *testing.T) {
+	m := &Manager{configDir: t.TempDir()}
+	if usage := m.LoadUsage(); len(usage) != 0 {
+		t.Fatalf("Expected no usage before any connect, got %v", usage)
+	}
+
+	for range 2 {
+		if err := m.RecordUse("prod", ""); err != nil {
+			t.Fatalf("RecordUse() failed: %v", err)
+		}
+	}
+	entry := m.LoadUsage()["prod"]
+	if entry.UseCount != 2 || entry.LastUsedAt.IsZero() {
+		t.Errorf("Expected two recorded uses with a time, got %+v", entry)
+	}
+}
+
+func TestManager_RecordFailure(t *testing.T) {
+	m := &Manager{configDir: t.TempDir()}
+	if err := m.RecordUse("prod", "PostgreSQL 16.2"); err != nil {
+		t.Fatalf("RecordUse() failed: %v", err)
+	}
+	if err := m.RecordFailure("prod", errors.New("connection refused")); err != nil {
+		t.Fatalf("RecordFailure() failed: %v", err)
+	}
+
+	entry := m.LoadUsage()["prod"]
+	if entry.LastError != "connection refused" || entry.LastErrorAt.IsZero() || entry.UseCount != 1 {
+		t.Errorf("Expected the failure to be recorded beside the earlier use, got %+v", entry)
+	}
+	if entry.LastVersion != "PostgreSQL 16.2" {
+		t.Errorf("Expected a failure to keep the last known version, got %q", entry.LastVersion)
+	}
+
+	// A failed attempt does not make a connection recent
+	if err := m.RecordFailure("staging", errors.New("timeout")); err != nil {
+		t.Fatal(err)
+	}
+	if entry := m.LoadUsage()["staging"]; !entry.LastUsedAt.IsZero() {
+		t.Errorf("Expected no use time after only a failure, got %v", entry.LastUsedAt)
+	}
+
+	if err := m.RecordUse("prod", ""); err != nil {
+		t.Fatal(err)
+	}
+	entry = m.LoadUsage()["prod"]
+	if entry.LastError != "" || !entry.LastErrorAt.IsZero() || entry.LastVersion != "PostgreSQL 16.2" {
+		t.Errorf("Expected a successful connect to clear the error and keep the version, got %+v", entry)
+	}
+}
+
+func TestManager_LoadUsage_Corrupt(t *testing.T) {
+	m := &Manager{configDir: t.TempDir()}
+	path := filepath.Join(m.configDir, UsageFile)
+	if err := os.WriteFile(path, []byte("prod: [unclosed"), 0600); err != nil {
+		t.Fatal(err)
+	}
+	t.Cleanup(func() { core.TakeFileWarnings() })
+
+	if usage := m.LoadUsage(); len(usage) != 0 {
+		t.Errorf("Expected a corrupt file to count as no usage, got %v", usage)
+	}
+	if _, err := os.Stat(path + core.CorruptSuffix); err != nil {
+		t.Errorf("Expected the corrupt file to be set aside: %v", err)
+	}
+}
+
+func TestConnectionsHeader(t *testing.T) {
+	i18nMgr, err := i18n.NewManager("en_au")
+	if err != nil {
+		t.Fatalf("Failed to create i18n manager: %v", err)
+	}
+
+	testCases := []struct {
+		i, recent, total int
+		expected         string
+	}{
+		{0, 2, 4, "Recent:"},
+		{1, 2, 4, ""},
+		{2, 2, 4, "Others:"},
+		{0, 0, 4, ""},
+		{2, 2, 2, ""},
+	}
+	for _, tc := range testCases {
+		if got := ConnectionsHeader(i18nMgr, tc.i, tc.recent, tc.total); got != tc.expected {
+			t.Errorf("ConnectionsHeader(%d, %d, %d) = %q, want %q", tc.i, tc.recent, tc.total, got, tc.expected)
+		}
+	}
+}
@@ -0,0 +1,151 @@
+package config
+
+import (
+	"context"
+	"fmt"
+	"os"
+	"path/filepath"
+	"reflect"
+	"sort"
+	"strings"
+	"time"
+
+	"sqlterm/internal/core"
+
+	"github.com/fsnotify/fsnotify"
+)
+
+// connectionsDebounce groups the burst of events an editor or a save produces into one reload
+const connectionsDebounce = 300 * time.Millisecond
+
+// ConnectionsDiff lists connection names that changed between two reloads
+type ConnectionsDiff struct {
+	Added   []string
+	Removed []string
+	Changed []string
+}
+
+// Empty reports whether nothing changed
+func (d ConnectionsDiff) Empty() bool {
+	return len(d.Added) == 0 && len(d.Removed) == 0 && len(d.Changed) == 0
+}
+
+// String renders the diff compactly, e.g. "+staging-replica -old ~prod"
+func (d ConnectionsDiff) String() string {
+	var parts []string
+	for _, name := range d.Added {
+		parts = append(parts, "+"+name)
+	}
+	for _, name := range d.Removed {
+		parts = append(parts, "-"+name)
+	}
+	for _, name := range d.Changed {
+		parts = append(parts, "~"+name)
+	}
+	return strings.Join(parts, " ")
+}
+
+// ReloadConnections re-reads the connections directory and reports what changed since the last listing
+func (m *Manager) ReloadConnections() (ConnectionsDiff, error) {
+	m.mu.Lock()
+	previous := m.connections
+	m.connections = nil
+	m.mu.Unlock()
+
+	current, err := m.ListConnections()
+	if err != nil {
+		return ConnectionsDiff{}, err
+	}
+	return diffConnections(previous, current), nil
+}
+
+func diffConnections(previous, current []*core.ConnectionConfig) ConnectionsDiff {
+	before := make(map[string]core.ConnectionConfig, len(previous))
+	for _, conn := range previous {
+		before[conn.Name] = *conn
+	}
+
+	var diff ConnectionsDiff
+	for _, conn := range current {
+		old, ok := before[conn.Name]
+		switch {
+		case !ok:
+			diff.Added = append(diff.Added, conn.Name)
+		case !reflect.DeepEqual(old, *conn):
+			diff.Changed = append(diff.Changed, conn.Name)
+		}
+		delete(before, conn.Name)
+	}
+	for name := range before {
+		diff.Removed = append(diff.Removed, name)
+	}
+
+	sort.Strings(diff.Added)
+	sort.Strings(diff.Removed)
+	sort.Strings(diff.Changed)
+	return diff
+}
+
+// WatchConnections reloads connections whenever files in the connections directory
+// change, calling onChange with the differences. It returns an error when the
+// platform cannot watch the directory; /reload-connections covers that case.
+// The watcher stops when ctx is done.
+func (m *Manager) WatchConnections(ctx context.Context, onChange func(ConnectionsDiff)) error {
+	connectionsDir := filepath.Join(m.configDir, "connections")
+	if err := os.MkdirAll(connectionsDir, 0755); err != nil {
+		return fmt.Errorf("failed to create connections directory: %w", err)
+	}
+
+	watcher, err := fsnotify.NewWatcher()
+	if err != nil {
+		return fmt.Errorf("failed to start connections watcher: %w", err)
+	}
+	if err := watcher.Add(connectionsDir); err != nil {
+		watcher.Close()
+		return fmt.Errorf("failed to watch connections directory: %w", err)
+	}
+
+	// Take a snapshot so the first reload reports only real changes
+	if _, err := m.ListConnections(); err != nil {
+		watcher.Close()
+		return err
+	}
+
+	go func() {
+		defer watcher.Close()
+
+		debounce := time.NewTimer(connectionsDebounce)
+		debounce.Stop()
+
+		for {
+			select {
+			case <-ctx.Done():
+				return
+			case event, ok := <-watcher.Events:
+				if !ok {
+					return
+				}
+				if isConnectionFile(event.Name) {
+					debounce.Reset(connectionsDebounce)
+				}
+			case _, ok := <-watcher.Errors:
+				if !ok {
+					return
+				}
+			case <-debounce.C:
+				diff, err := m.ReloadConnections()
+				if err == nil && !diff.Empty() {
+					onChange(diff)
+				}
+			}
+		}
+	}()
+
+	return nil
+}
+
+// isConnectionFile filters out editor swap files, backups and partial writes
+func isConnectionFile(path string) bool {
+	name := filepath.Base(path)
+	return filepath.Ext(name) == ".yaml" && !strings.HasPrefix(name, ".") && !strings.HasSuffix(name, "~")
+}
@@ -0,0 +1,90 @@
+package config
+
+import (
+	"context"
+	"os"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"sqlterm/internal/core"
+)
+
+func TestManager_ReloadConnections(t *testing.T) {
+	manager := &Manager{configDir: t.TempDir()}
+	manager.SaveConnection(&core.ConnectionConfig{Name: "prod", Host: "db1"})
+	manager.SaveConnection(&core.ConnectionConfig{Name: "old"})
+	manager.ListConnections()
+
+	// Simulate another process editing the directory
+	other := &Manager{configDir: manager.configDir}
+	other.SaveConnection(&core.ConnectionConfig{Name: "staging-replica"})
+	other.SaveConnection(&core.ConnectionConfig{Name: "prod", Host: "db2"})
+	other.DeleteConnection("old")
+
+	diff, err := manager.ReloadConnections()
+	if err != nil {
+		t.Fatalf("ReloadConnections() failed: %v", err)
+	}
+	if got := diff.String(); got != "+staging-replica -old ~prod" {
+		t.Errorf("Expected '+staging-replica -old ~prod', got '%s'", got)
+	}
+
+	diff, _ = manager.ReloadConnections()
+	if !diff.Empty() {
+		t.Errorf("Expected no changes on a second reload, got '%s'", diff)
+	}
+}
+
+func TestIsConnectionFile(t *testing.T) {
+	testCases := []struct {
+		path     string
+		expected bool
+	}{
+		{"/cfg/connections/prod.yaml", true},
+		{"/cfg/connections/.prod.yaml.swp", false},
+		{"/cfg/connections/.prod.yaml", false},
+		{"/cfg/connections/prod.yaml~", false},
+		{"/cfg/connections/prod.yaml.tmp", false},
+	}
+
+	for _, tc := range testCases {
+		if got := isConnectionFile(tc.path); got != tc.expected {
+			t.Errorf("isConnectionFile(%q) = %v, expected %v", tc.path, got, tc.expected)
+		}
+	}
+}
+
+func TestManager_WatchConnections(t *testing.T) {
+	manager := &Manager{configDir: t.TempDir()}
+
+	ctx, cancel := context.WithCancel(context.Background())
+	defer cancel()
+
+	changes := make(chan ConnectionsDiff, 4)
+	if err := manager.WatchConnections(ctx, func(diff ConnectionsDiff) { changes <- diff }); err != nil {
+		t.Skipf("File watching not available: %v", err)
+	}
+
+	// A burst of writes, including a temp file, is reported once
+	dir := filepath.Join(manager.configDir, "connections")
+	os.WriteFile(filepath.Join(dir, ".replica.yaml.swp"), []byte("x"), 0600)
+	for i := 0; i < 3; i++ {
+		os.WriteFile(filepath.Join(dir, "replica.yaml"), []byte("name: replica\n"), 0600)
+	}
+
+	select {
+	case diff := <-changes:
+		if diff.String() != "+replica" {
+			t.Errorf("Expected '+replica', got '%s'", diff)
+		}
+	case <-time.After(5 * time.Second):
+		t.Fatal("Expected a reload after the connections directory changed")
+	}
+
+	select {
+	case diff := <-changes:
+		t.Errorf("Expected rapid events to be debounced, got a second reload '%s'", diff)
+	case <-time.After(2 * connectionsDebounce):
+	}
+}
@@ -11,15 +11,18 @@ import (
 	"slices"
 	"strconv"
 	"strings"
+	"sync"
 	"time"
 
 	"sqlterm/internal/ai"
 	"sqlterm/internal/config"
 	"sqlterm/internal/core"
+	"sqlterm/internal/diag"
 	"sqlterm/internal/i18n"
 	"sqlterm/internal/session"
 
 	"github.com/chzyer/readline"
+	"golang.org/x/term"
 )
 
 type App struct {
@@ -30,6 +33,48 @@ type App struct {
 	sessionMgr *session.Manager
 	aiManager  *ai.Manager
 	i18nMgr    *i18n.Manager
+
+	messages        chan AppMessage
+	ui              uiState
+	schemaCache     *core.SchemaCache
+	recentFiles     *recentFiles
+	project         *config.Project
+	moreResult      *core.StoredResult
+	lastStored      *core.StoredResult
+	pins            map[string]*core.StoredResult
+	results         *core.ResultStore
+	layouts         *columnLayouts
+	links           core.Hyperlinks
+	lastQuery       string
+	unlimitedQuery  string
+	lastCrumb       string
+	navStack        []navEntry
+	generatedBy     int
+	buffers         *queryBuffers
+	tasks           taskRegistry
+	notifications   notificationQueue
+	errorHistory    []errorEntry
+	connectedAt     time.Time
+	clipboard       *clipboard
+	restoreOnce     sync.Once
+	shutdownOnce    sync.Once
+	keepAliveCancel context.CancelFunc
+	watchCancel     context.CancelFunc
+	noAutoConnect   bool
+
+	// startupConnection and startupStatements come from -C and -e on the command line
+	startupConnection string
+	startupStatements []string
+}
+
+// maxErrorHistory bounds how many past errors /errors can show
+const maxErrorHistory = 50
+
+// errorEntry records an error reported at the prompt so it can be re-read after it scrolls away
+type errorEntry struct {
+	Source  core.ResultSource
+	Command string
+	Err     error
 }
 
 func NewApp() (*App, error) {
@@ -64,6 +109,18 @@ func NewApp() (*App, error) {
 		sessionMgr: sessionMgr,
 		aiManager:  aiManager,
 		i18nMgr:    i18nMgr,
+		messages:   make(chan AppMessage, messageBufferSize),
+	}
+	app.recentFiles = loadRecentFiles(filepath.Join(configMgr.GetConfigDir(), "recent_files.txt"))
+	app.layouts = loadColumnLayouts(filepath.Join(configMgr.GetConfigDir(), "column_layouts.yaml"))
+	app.results = core.LoadResultStore(configMgr.ResultHistoryPath(), core.DefaultResultCapacity)
+	app.results.SetMemoryBudget(app.resultMemoryBudget())
+	if wd, err := os.Getwd(); err == nil {
+		if project, err := config.FindProject(wd); err != nil {
+			fmt.Printf(i18nMgr.Get("project_settings_warning"), err)
+		} else {
+			app.project = project
+		}
 	}
 
 	// Ensure sessions directory exists for history file
@@ -76,32 +133,47 @@ func NewApp() (*App, error) {
 	completer := NewAutoCompleter(app)
 
 	rl, err := readline.NewEx(&readline.Config{
-		Prompt:       "sqlterm > ",
-		AutoComplete: completer,
-		HistoryFile:  filepath.Join(configMgr.GetConfigDir(), "sessions", "global_history.txt"),
+		Prompt:                 "sqlterm > ",
+		AutoComplete:           completer,
+		Listener:               &keyListener{app: app},
+		FuncFilterInputRune:    newPasteDetector().filter,
+		HistoryFile:            filepath.Join(configMgr.GetConfigDir(), "sessions", "global_history.txt"),
+		DisableAutoSaveHistory: app.redactHistory(),
+		HistoryLimit:           app.historyLimit(),
 	})
 	if err != nil {
 		return nil, fmt.Errorf(i18nMgr.Get("failed_to_create_readline"), err)
 	}
 
 	app.rl = rl
+	app.noteFileWarnings()
 	return app, nil
 }
 
 func (a *App) SetConnection(conn core.Connection, config *core.ConnectionConfig) {
+	conn = a.auditConnection(conn, config)
 	a.connection = conn
 	a.config = config
+	a.schemaCache = nil
+	a.connectedAt = time.Now()
+	a.forgetResults()
 	a.updatePrompt()
+	a.startKeepAlive(conn, config.Name)
 
 	// Ensure session directory and configuration exist
 	if err := a.sessionMgr.EnsureSessionDir(config.Name); err != nil {
 		fmt.Printf(a.i18nMgr.Get("session_init_warning"), err)
 	}
 
+	a.buffers = loadQueryBuffers(filepath.Join(a.sessionMgr.GetSessionDir(config.Name), "buffers.yaml"))
+
+	a.runInitSQL(conn)
+
 	// Switch to session-specific history file
 	if err := a.switchToSessionHistory(config.Name); err != nil {
 		fmt.Printf(a.i18nMgr.Get("session_history_warning"), err)
 	}
+	a.restoreDraft()
 
 	// Initialize vector store for AI context if AI manager is available
 	if a.aiManager != nil {
@@ -109,7 +181,7 @@ func (a *App) SetConnection(conn core.Connection, config *core.ConnectionConfig)
 		if err := a.aiManager.InitializeVectorStore(config.Name, conn); err != nil {
 			fmt.Printf(a.i18nMgr.Get("vector_db_init_warning"), err)
 		} else {
-			fmt.Printf(a.i18nMgr.Get("vector_db_ready"), config.Name)
+			fmt.Printf(a.i18nMgr.Get("vector_db_ready"), a.sessionMgr.GetSessionDir(config.Name))
 		}
 	}
 }
@@ -117,7 +189,7 @@ func (a *App) SetConnection(conn core.Connection, config *core.ConnectionConfig)
 func (a *App) updatePrompt() {
 	var prompt string
 	if a.config != nil {
-		prompt = fmt.Sprintf("sqlterm (%s) > ", a.config.Database)
+		prompt = fmt.Sprintf("sqlterm (%s) > ", a.config.Label(a.config.Database, colorEnabled()))
 	} else {
 		prompt = "sqlterm > "
 	}
@@ -155,9 +227,13 @@ func (a *App) switchToSessionHistory(connectionName string) error {
 
 	// Create new readline instance with session-specific history
 	newConfig := &readline.Config{
-		Prompt:       oldConfig.Prompt,
-		AutoComplete: oldConfig.AutoComplete,
-		HistoryFile:  historyFile,
+		Prompt:                 oldConfig.Prompt,
+		AutoComplete:           oldConfig.AutoComplete,
+		Listener:               oldConfig.Listener,
+		FuncFilterInputRune:    oldConfig.FuncFilterInputRune,
+		HistoryFile:            historyFile,
+		DisableAutoSaveHistory: oldConfig.DisableAutoSaveHistory,
+		HistoryLimit:           oldConfig.HistoryLimit,
 	}
 
 	rl, err := readline.NewEx(newConfig)
@@ -201,8 +277,12 @@ func (a *App) migrateLegacyHistory(sessionHistoryFile string) error {
 
 // ClearConnection clears the current database connection and switches back to global history
 func (a *App) ClearConnection() error {
+	a.stopKeepAlive()
 	a.connection = nil
 	a.config = nil
+	a.schemaCache = nil
+	a.buffers = nil
+	a.forgetResults()
 	a.updatePrompt()
 
 	// Close vector store if active
@@ -228,9 +308,13 @@ func (a *App) switchToGlobalHistory() error {
 
 	// Create new readline instance with global history
 	newConfig := &readline.Config{
-		Prompt:       oldConfig.Prompt,
-		AutoComplete: oldConfig.AutoComplete,
-		HistoryFile:  globalHistoryFile,
+		Prompt:                 oldConfig.Prompt,
+		AutoComplete:           oldConfig.AutoComplete,
+		Listener:               oldConfig.Listener,
+		FuncFilterInputRune:    oldConfig.FuncFilterInputRune,
+		HistoryFile:            globalHistoryFile,
+		DisableAutoSaveHistory: oldConfig.DisableAutoSaveHistory,
+		HistoryLimit:           oldConfig.HistoryLimit,
 	}
 
 	rl, err := readline.NewEx(newConfig)
@@ -244,11 +328,23 @@ func (a *App) switchToGlobalHistory() error {
 	return nil
 }
 
+// restoreTerminal closes readline, which puts the terminal back into cooked mode.
+// It is safe to call more than once.
+func (a *App) restoreTerminal() {
+	a.restoreOnce.Do(func() {
+		if a.rl != nil {
+			a.rl.Close()
+		}
+	})
+}
+
 func (a *App) Run() error {
-	defer a.rl.Close()
+	defer a.shutdown()
 	defer func() {
-		if a.aiManager != nil {
-			a.aiManager.CloseVectorStore()
+		if r := recover(); r != nil {
+			// Restore the terminal first so the panic message and stack trace are readable
+			a.restoreTerminal()
+			panic(r)
 		}
 	}()
 
@@ -256,22 +352,48 @@ func (a *App) Run() error {
 	fmt.Println(a.i18nMgr.Get("prompt_welcome"))
 	fmt.Println()
 
+	a.startConnectionWatcher()
+	a.startup()
+	a.flushNotifications(os.Stdout)
+	a.runStartupStatements()
+
+	var interrupts promptInterrupts
 	for {
 		line, err := a.rl.Readline()
 		if err == readline.ErrInterrupt {
+			switch interrupts.press(time.Now(), line) {
+			case interruptHint:
+				fmt.Println(a.i18nMgr.Get("ctrl_c_again_to_exit"))
+			case interruptExit:
+				return nil
+			}
 			continue
 		} else if err == io.EOF {
 			break
 		}
+		interrupts.reset()
 
 		line = strings.TrimSpace(line)
 		if line == "" {
 			continue
 		}
+		a.saveHistory(line)
+		if text, lines := expandPaste(line); lines > 1 {
+			a.showPaste(text, lines)
+			line = text
+		}
+		if line = a.continueInput(line); line == "" {
+			continue
+		}
 
 		if err := a.processLine(line); err != nil {
-			fmt.Printf(a.i18nMgr.Get("generic_error"), err)
+			if errors.Is(err, errQuit) {
+				break
+			}
+			a.reportError(line, err)
 		}
+		a.drainMessages()
+		a.flushNotifications(os.Stdout)
 	}
 
 	return nil
@@ -301,25 +423,109 @@ func (a *App) processCommand(line string) error {
 	case "/help":
 		return a.handleHelp(args)
 	case "/quit", "/exit":
-		os.Exit(0)
+		return errQuit
 	case "/connect":
 		return a.handleConnect(args)
+	case "/connect-template":
+		return a.handleConnectTemplate(args)
 	case "/list-connections":
-		return a.handleListConnections()
+		return a.handleListConnections(args)
+	case "/reload-connections":
+		return a.handleReloadConnections()
+	case "/reconnect":
+		return a.handleReconnect()
+	case "/gexec":
+		return a.handleGexec()
 	case "/tables":
 		return a.handleListTables()
 	case "/describe":
 		return a.handleDescribeTable(args)
+	case "/count":
+		return a.handleCount(args)
+	case "/refresh":
+		return a.handleRefresh()
+	case "/more":
+		return a.handleMore()
+	case "/edit":
+		return a.handleEdit(line)
+	case "/follow":
+		return a.handleFollow(args)
+	case "/where":
+		return a.handleWhere(args)
+	case "/back":
+		return a.handleBack()
+	case "/row":
+		return a.handleRow(args)
+	case "/buffer":
+		return a.handleBuffer(args)
+	case "/editor":
+		return a.handleEditor()
+	case "/attach":
+		return a.handleAttach(args)
+	case "/detach":
+		return a.handleDetach(args)
+	case "/audit":
+		return a.handleAudit(args)
+	case "/tasks":
+		return a.handleTasks(args)
+	case "/columns":
+		return a.handleColumns(args)
+	case "/pin":
+		return a.handlePin(args)
+	case "/pins":
+		return a.handlePins(args)
+	case "/unpin":
+		return a.handleUnpin(args)
+	case "/check":
+		return a.handleCheck(args)
+	case "/copy":
+		return a.handleCopy(args)
+	case "/copy-query":
+		return a.handleCopyQuery()
+	case "/paste":
+		return a.handlePaste()
+	case "/sample":
+		return a.handleSample(args)
+	case "/palette":
+		return a.handlePalette(args)
+	case "/nolimit":
+		return a.handleNoLimit()
+	case "/functions":
+		return a.handleFunctions()
+	case "/triggers":
+		return a.handleTriggers(args)
+	case "/sort":
+		return a.handleSort(args)
+	case "/show":
+		return a.handleShow(args)
+	case "/sql":
+		return a.handleSQL(args)
+	case "/rerun":
+		return a.handleRerun(args)
+	case "/keys":
+		return a.handleKeys(args)
 	case "/status":
 		a.handleStatus()
 	case "/exec":
-		return a.handleExecQuery(args)
+		return a.handleExecQuery(line)
 	case "/config":
 		return a.handleConfig(args)
 	case "/last-ai-call":
 		return a.handleShowPrompts(args)
+	case "/clear":
+		return a.handleClear(args)
 	case "/clear-conversation":
 		return a.handleClearConversation()
+	case "/redraw":
+		return a.handleRedraw()
+	case "/processlist":
+		return a.handleProcessList()
+	case "/kill":
+		return a.handleKillProcess(args)
+	case "/errors":
+		return a.handleErrors(args)
+	case "/diag":
+		a.handleDiag()
 	default:
 		fmt.Printf(a.i18nMgr.Get("unknown_command"), command)
 	}
@@ -339,6 +545,9 @@ func (a *App) processQueryFile(line string) error {
 	}
 
 	filename := parts[0][1:] // Remove @ prefix
+	// A trailing ! runs each file in one transaction: @migrate.sql!
+	atomic := strings.HasSuffix(filename, "!")
+	filename = strings.TrimSuffix(filename, "!")
 	var queryRange []int
 
 	if len(parts) > 1 {
@@ -359,31 +568,62 @@ func (a *App) processQueryFile(line string) error {
 		}
 	}
 
-	return a.executeFile(filename, queryRange)
+	if isGlobPattern(filename) {
+		return a.executeGlob(filename, queryRange, atomic)
+	}
+	return a.executeFile(filename, queryRange, atomic)
 }
 
-func (a *App) processQuery(query string, resultWriter io.Writer) error {
+func (a *App) processQuery(ctx context.Context, query string, resultWriter io.Writer) error {
 	if a.connection == nil {
 		fmt.Println(a.i18nMgr.Get("no_database_connection"))
 		return nil
 	}
 
-	result, err := a.connection.Execute(query)
+	query, err := a.expandPins(query)
+	if err != nil {
+		return err
+	}
+
+	if err := a.checkReadOnly(query); err != nil {
+		return err
+	}
+	if !a.allowDestructive(query) {
+		return nil
+	}
+
+	result, err := a.runQuery(ctx, a.connection, query)
+	if err != nil {
+		return core.WithErrorPointer(fmt.Errorf(a.i18nMgr.Get("query_execution_failed"), err), query)
+	}
+	a.schema().InvalidateAfter(a.dbType(), query)
+
+	// Keep the rows so /more can show the rest without running the query again
+	fetchStart := time.Now()
+	stored, err := core.StoreResult(result, core.MaxStoredRows)
 	if err != nil {
 		return fmt.Errorf(a.i18nMgr.Get("query_execution_failed"), err)
 	}
+	stored.Timing = a.queryTiming(ctx, query, time.Since(fetchStart))
+	stored.Source = a.resultSource()
+	a.recordResult(query, stored)
+	a.forgetResults()
+	a.lastStored, a.lastQuery = stored, query
 
 	// Save as markdown and display with glamour
 	if a.config != nil {
 		if err := a.sessionMgr.EnsureSessionDir(a.config.Name); err != nil {
 			fmt.Printf(a.i18nMgr.Get("failed_create_session_dir_warning"), err)
 		} else {
-			err := core.SaveQueryResultAsMarkdown(result, query, a.config.Name, resultWriter, a.i18nMgr)
+			err := core.SaveResultPageAsMarkdown(stored, a.columnLayout(stored.Columns), query, resultWriter, a.resultFormat(stored.Columns), a.i18nMgr)
 			if err != nil {
 				fmt.Printf(a.i18nMgr.Get("failed_save_markdown_warning"), err)
 			}
 		}
 	}
+	if stored.Remaining() {
+		a.moreResult = stored
+	}
 
 	return nil
 }
@@ -392,6 +632,7 @@ func (a *App) prepareQueryResultMarkdown() (string, *os.File, error) {
 	if err := a.sessionMgr.EnsureSessionDir(a.config.Name); err != nil {
 		return "", nil, fmt.Errorf(a.i18nMgr.Get("failed_to_create_session_dir"), err)
 	}
+	a.links = nil
 	// Generate filename with timestamp
 	configDir := a.configMgr.GetConfigDir()
 	// Create sessions directory structure
@@ -447,19 +688,16 @@ func (a *App) preparePromptHistoryMarkdown() (string, *os.File, error) {
 	return filename, writer, err
 }
 
-func (a *App) executeFile(filename string, queryRange []int) error {
+// executeFile runs the statements of a query file, or those in queryRange.
+// With atomic they run in one transaction that any failure rolls back.
+func (a *App) executeFile(filename string, queryRange []int, atomic bool) error {
 	if a.connection == nil {
 		fmt.Println(a.i18nMgr.Get("no_database_connection"))
 		return nil
 	}
 
-	// Try to find the file in current directory or queries directory
-	var filepath string
-	if _, err := os.Stat(filename); err == nil {
-		filepath = filename
-	} else if _, err := os.Stat("queries/" + filename); err == nil {
-		filepath = "queries/" + filename
-	} else {
+	filepath, ok := resolveQueryFile(filename, a.projectRoot())
+	if !ok {
 		return fmt.Errorf(a.i18nMgr.Get("file_not_found"), filename)
 	}
 
@@ -474,6 +712,10 @@ func (a *App) executeFile(filename string, queryRange []int) error {
 		return fmt.Errorf(a.i18nMgr.Get("failed_to_read_file"), err)
 	}
 
+	if err := a.recentFiles.add(filepath); err != nil {
+		fmt.Printf(a.i18nMgr.Get("generic_warning"), err)
+	}
+
 	queries := a.parseQueries(string(content))
 	fmt.Printf(a.i18nMgr.Get("executing_sql_file"), filename)
 	fmt.Printf(a.i18nMgr.Get("found_queries_in_file"), len(queries))
@@ -483,26 +725,67 @@ func (a *App) executeFile(filename string, queryRange []int) error {
 		start, end = queryRange[0], queryRange[1]
 	}
 
+	first, last := min(max(start-1, 0), len(queries)), min(end, len(queries))
+	if atomic {
+		return a.runAtomic(queries[first:max(first, last)])
+	}
+	return a.runStatements(queries[first:max(first, last)])
+}
+
+// stopOnError reports whether a run of several statements ends at the first
+// that fails, rather than going on with the rest
+func (a *App) stopOnError() bool {
+	return a.aiManager != nil && a.aiManager.GetConfig().StopOnError
+}
+
+// runStatements runs queries in order into one result file and shows it,
+// stopping early when interrupted. When more than one statement ran, a
+// summary of their timings follows and becomes the last result.
+func (a *App) runStatements(queries []string) error {
 	mdPath, writer, err := a.prepareQueryResultMarkdown()
 	if err != nil {
 		fmt.Println("Warning:", err.Error())
 		return nil
 	}
 
-	for i := start - 1; i < end && i < len(queries); i++ {
-		query := strings.TrimSpace(queries[i])
+	ctx, stop := a.interruptContext(context.Background())
+	defer stop()
+
+	var outcomes []core.StatementOutcome
+	for _, query := range queries {
+		query = strings.TrimSpace(query)
 		if query == "" {
 			continue
 		}
 
-		err = a.processQuery(query, writer)
+		before, start := a.lastStored, time.Now()
+		err = a.processQuery(ctx, query, writer)
+		outcome := core.StatementOutcome{Query: query, Duration: time.Since(start), Status: core.StatusOK}
+		if a.lastStored != nil && a.lastStored != before {
+			outcome.Rows = len(a.lastStored.Rows)
+		}
+		if ctx.Err() != nil {
+			outcome.Status = core.StatusCancelled
+			outcomes = append(outcomes, outcome)
+			fmt.Println(a.i18nMgr.Get("query_cancelled"))
+			break
+		}
+		outcomes = append(outcomes, outcome)
 		if err != nil {
+			outcomes[len(outcomes)-1].Status = core.StatusError
 			fmt.Printf(a.i18nMgr.Get("query_failed"), err)
+			if a.stopOnError() {
+				fmt.Println(a.i18nMgr.Get("stopped_on_error"))
+				break
+			}
 		}
 	}
+	if len(outcomes) > 1 {
+		a.writeRunSummary(outcomes, writer)
+	}
 	writer.Close()
 
-	if err := a.sessionMgr.ViewMarkdown(mdPath); err != nil {
+	if err := a.viewMarkdown(mdPath); err != nil {
 		fmt.Printf(a.i18nMgr.Get("generic_warning"), err)
 	}
 	fmt.Printf("📍 %s: %s\n", a.i18nMgr.Get("file_location"), mdPath)
@@ -510,38 +793,73 @@ func (a *App) executeFile(filename string, queryRange []int) error {
 	return nil
 }
 
-func (a *App) parseQueries(content string) []string {
-	var queries []string
-	var currentQuery strings.Builder
+// writeRunSummary adds the timing of each statement to the result file and
+// keeps the summary as the last result, so /copy, /sort and exports work on it
+func (a *App) writeRunSummary(outcomes []core.StatementOutcome, writer io.Writer) {
+	summary := core.StatementSummary(outcomes)
+	summary.Source = a.resultSource()
+	query := fmt.Sprintf(a.i18nMgr.Get("run_summary_query"), len(outcomes))
+	a.recordResult(query, summary)
+	a.forgetResults()
+	a.lastStored, a.lastQuery = summary, query
 
-	scanner := bufio.NewScanner(strings.NewReader(content))
-	for scanner.Scan() {
-		line := strings.TrimSpace(scanner.Text())
-		if line == "" || strings.HasPrefix(line, "--") {
-			continue
-		}
+	fmt.Fprintf(writer, "\n## %s\n\n", a.i18nMgr.Get("run_summary_header"))
+	if err := core.SaveResultPageAsMarkdown(summary, a.columnLayout(summary.Columns), query, writer, a.resultFormat(summary.Columns), a.i18nMgr); err != nil {
+		fmt.Printf(a.i18nMgr.Get("failed_save_markdown_warning"), err)
+	}
+	if summary.Remaining() {
+		a.moreResult = summary
+	}
+}
 
-		currentQuery.WriteString(line)
-		currentQuery.WriteString(" ")
+// executeGlob runs every file matching pattern in lexical order after
+// confirming the file count. With atomic each file is its own transaction.
+func (a *App) executeGlob(pattern string, queryRange []int, atomic bool) error {
+	if a.connection == nil {
+		fmt.Println(a.i18nMgr.Get("no_database_connection"))
+		return nil
+	}
 
-		if strings.HasSuffix(line, ";") {
-			queries = append(queries, strings.TrimSuffix(currentQuery.String(), ";"))
-			currentQuery.Reset()
-		}
+	files, err := expandQueryGlob(pattern, a.projectRoot())
+	if err != nil {
+		return fmt.Errorf(a.i18nMgr.Get("invalid_file_pattern"), pattern, err)
+	}
+	if len(files) == 0 {
+		return fmt.Errorf(a.i18nMgr.Get("file_not_found"), pattern)
 	}
 
-	if currentQuery.Len() > 0 {
-		queries = append(queries, currentQuery.String())
+	for _, file := range files {
+		fmt.Printf("  %s\n", file)
 	}
+	if !a.confirm(fmt.Sprintf(a.i18nMgr.Get("confirm_execute_files"), len(files), pattern)) {
+		a.notify(NotifyInfo, a.i18nMgr.Get("execute_files_cancelled"))
+		return nil
+	}
+
+	for i, file := range files {
+		fmt.Printf(a.i18nMgr.Get("execute_files_progress"), i+1, len(files), file)
+		if err := a.executeFile(file, queryRange, atomic); err != nil {
+			fmt.Printf(a.i18nMgr.Get("query_failed"), err)
+		}
+	}
+
+	a.notify(NotifySuccess, a.i18nMgr.Get("execute_files_completed"), len(files))
+	return nil
+}
 
-	return queries
+// parseQueries splits file content into statements, ignoring semicolons in strings and comments
+func (a *App) parseQueries(content string) []string {
+	return core.SplitStatements(a.dbType(), content)
 }
 
+// truncateQuery fits query on one line for lists and progress messages, at
+// most query_width columns wide. /copy-query and /editor show it in full.
 func (a *App) truncateQuery(query string) string {
-	if len(query) > 50 {
-		return query[:47] + "..."
+	var display config.DisplayConfig
+	if a.aiManager != nil {
+		display = a.aiManager.GetConfig().Display
 	}
-	return query
+	return core.ElideLine(query, display.QueryLineWidth())
 }
 
 func (a *App) handleHelp(args []string) error {
@@ -578,6 +896,7 @@ func (a *App) handleHelp(args []string) error {
 
 func (a *App) printHelp() {
 	fmt.Println(a.i18nMgr.Get("help_full"))
+	fmt.Printf(a.i18nMgr.Get("help_config_dir")+"\n", a.configMgr.GetConfigDir())
 }
 
 func (a *App) handleConnect(args []string) error {
@@ -585,20 +904,31 @@ func (a *App) handleConnect(args []string) error {
 		return a.interactiveConnect()
 	}
 
-	name := args[0]
-	config, err := a.configMgr.LoadConnection(name)
+	config, err := a.loadConnection(args[0])
 	if err != nil {
-		return errors.New(a.i18nMgr.GetWithArgs("failed_to_load_connection", name, err))
+		return err
 	}
+	_, err = a.connectTo(config)
+	return err
+}
 
-	fmt.Printf(a.i18nMgr.Get("connecting_to"), config.Name)
-	conn, err := core.NewConnection(config)
-	if err != nil {
-		return fmt.Errorf(a.i18nMgr.Get("failed_to_connect"), err)
+// connectTo opens config and makes it the current connection, reporting
+// whether it did. Leaving out a missing password or cancelling the attempt
+// is not an error.
+func (a *App) connectTo(config *core.ConnectionConfig) (bool, error) {
+	if !a.confirmMissingPassword(config) {
+		return false, nil
 	}
 
-	if err := conn.Ping(); err != nil {
-		return fmt.Errorf(a.i18nMgr.Get("connection_test_failed"), err)
+	fmt.Printf(a.i18nMgr.Get("connecting_to"), config.Name)
+	conn, err := a.openConnection(config)
+	if errors.Is(err, context.Canceled) {
+		a.notify(NotifyInfo, a.i18nMgr.Get("connect_cancelled"))
+		return false, nil
+	}
+	if err != nil {
+		a.recordConnectionFailure(config.Name, err)
+		return false, err
 	}
 
 	if a.connection != nil {
@@ -607,8 +937,86 @@ func (a *App) handleConnect(args []string) error {
 
 	a.SetConnection(conn, config)
 	fmt.Printf(a.i18nMgr.Get("connected_to"), config.Name, config.Database)
+	a.recordConnectionUse(config.Name)
 
-	return nil
+	return true, nil
+}
+
+// loadConnection loads the saved connection name
+func (a *App) loadConnection(name string) (*core.ConnectionConfig, error) {
+	config, err := a.configMgr.LoadConnection(name)
+	if err != nil {
+		return nil, errors.New(a.i18nMgr.GetWithArgs("failed_to_load_connection", name, err))
+	}
+	return config, nil
+}
+
+// openConnection connects to config and pings it behind a spinner, giving
+// up after the connect timeout. Ctrl+C stops waiting, which returns
+// context.Canceled.
+func (a *App) openConnection(config *core.ConnectionConfig) (core.Connection, error) {
+	ctx, stop := a.interruptContext(context.Background())
+	defer stop()
+
+	timeout := a.connectTimeout()
+	label := a.i18nMgr.GetWithArgs("connect_waiting", config.Name)
+	if timeout > 0 {
+		label = a.i18nMgr.GetWithArgs("connect_waiting_timeout", config.Name, timeout)
+	}
+	session := config
+	if a.project != nil && a.project.ReadOnly {
+		// The server refuses writes too, should one get past checkReadOnly
+		readOnly := *config
+		readOnly.ReadOnly = true
+		session = &readOnly
+	}
+	var conn core.Connection
+	var err error
+	a.spin(label, func() {
+		conn, err = core.Connect(ctx, session, timeout)
+	})
+	if errors.Is(err, context.Canceled) {
+		return nil, err
+	}
+	if err != nil {
+		return nil, a.connectionTestFailed(config, err)
+	}
+	return conn, nil
+}
+
+// connectTimeout returns how long connecting may take, as set by
+// connect_timeout in the config file
+func (a *App) connectTimeout() time.Duration {
+	if a.aiManager == nil {
+		return core.DefaultConnectTimeout
+	}
+	timeout, _ := a.aiManager.GetConfig().ConnectTimeoutDuration()
+	return timeout
+}
+
+// confirmMissingPassword asks before logging in to another machine without
+// a password, which is usually a field left blank by mistake
+func (a *App) confirmMissingPassword(config *core.ConnectionConfig) bool {
+	if !core.MissingPassword(config) {
+		return true
+	}
+	if a.confirm(a.i18nMgr.GetWithArgs("confirm_missing_password", config.Host, config.Username)) {
+		return true
+	}
+	a.notify(NotifyInfo, a.i18nMgr.Get("connect_cancelled"))
+	return false
+}
+
+// connectionTestFailed explains a failed ping, pointing at the password or
+// at the host when the error tells which one is wrong
+func (a *App) connectionTestFailed(config *core.ConnectionConfig, err error) error {
+	switch {
+	case errors.Is(err, core.ErrAuthenticationFailed):
+		return fmt.Errorf(a.i18nMgr.Get("connection_auth_failed"), config.Username, err)
+	case errors.Is(err, core.ErrServerUnreachable):
+		return fmt.Errorf(a.i18nMgr.Get("connection_unreachable"), config.Host, config.Port, err)
+	}
+	return fmt.Errorf(a.i18nMgr.Get("connection_test_failed"), err)
 }
 
 func (a *App) interactiveConnect() error {
@@ -620,33 +1028,30 @@ func (a *App) interactiveConnect() error {
 	name, _ := reader.ReadString('\n')
 	name = strings.TrimSpace(name)
 
+	backends := core.EnabledBackends()
 	fmt.Println(a.i18nMgr.Get("select_database_type"))
-	fmt.Println(a.i18nMgr.Get("mysql_option"))
-	fmt.Println(a.i18nMgr.Get("postgresql_option"))
-	fmt.Println(a.i18nMgr.Get("sqlite_option"))
-	fmt.Print(a.i18nMgr.Get("enter_choice"))
+	for i, dbType := range backends {
+		backend, _ := core.LookupBackend(dbType)
+		fmt.Printf("  %d. %s\n", i+1, backend.DisplayName)
+	}
+	fmt.Printf(a.i18nMgr.Get("enter_choice"), len(backends))
 
 	choice, _ := reader.ReadString('\n')
 	choice = strings.TrimSpace(choice)
 
-	var dbType core.DatabaseType
-	switch choice {
-	case "1":
-		dbType = core.MySQL
-	case "2":
-		dbType = core.PostgreSQL
-	case "3":
-		dbType = core.SQLite
-	default:
+	index, err := strconv.Atoi(choice)
+	if err != nil || index < 1 || index > len(backends) {
 		return fmt.Errorf(a.i18nMgr.Get("invalid_choice"), choice)
 	}
+	dbType := backends[index-1]
+	backend, _ := core.LookupBackend(dbType)
 
 	config := &core.ConnectionConfig{
 		Name:         name,
 		DatabaseType: dbType,
 	}
 
-	if dbType != core.SQLite {
+	if !backend.FileBased {
 		fmt.Print(a.i18nMgr.Get("enter_host"))
 		host, _ := reader.ReadString('\n')
 		host = strings.TrimSpace(host)
@@ -680,16 +1085,37 @@ func (a *App) interactiveConnect() error {
 	fmt.Print(a.i18nMgr.Get("enter_database_name"))
 	database, _ := reader.ReadString('\n')
 	config.Database = strings.TrimSpace(database)
+	if backend.FileBased {
+		config.Database = core.SQLitePath(config.Database)
+	}
+
+	fmt.Printf(a.i18nMgr.Get("enter_environment"), strings.Join(core.Environments, "/"))
+	environment, _ := reader.ReadString('\n')
+	config.Environment = strings.TrimSpace(environment)
+
+	if !backend.FileBased {
+		fmt.Print(a.i18nMgr.Get("enter_advanced_pool"))
+		answer, _ := reader.ReadString('\n')
+		if answer = strings.ToLower(strings.TrimSpace(answer)); answer == "y" || answer == "yes" {
+			if err := a.readPoolSettings(reader, config); err != nil {
+				return err
+			}
+		}
+	}
+
+	if !a.confirmMissingPassword(config) {
+		return nil
+	}
 
 	// Test connection
 	fmt.Printf(a.i18nMgr.Get("testing_connection"), config.Name)
-	conn, err := core.NewConnection(config)
-	if err != nil {
-		return fmt.Errorf(a.i18nMgr.Get("failed_to_connect"), err)
+	conn, err := a.openConnection(config)
+	if errors.Is(err, context.Canceled) {
+		a.notify(NotifyInfo, a.i18nMgr.Get("connect_cancelled"))
+		return nil
 	}
-
-	if err := conn.Ping(); err != nil {
-		return fmt.Errorf(a.i18nMgr.Get("connection_test_failed"), err)
+	if err != nil {
+		return err
 	}
 
 	if a.connection != nil {
@@ -703,36 +1129,65 @@ func (a *App) interactiveConnect() error {
 	if err := a.configMgr.SaveConnection(config); err != nil {
 		fmt.Printf(a.i18nMgr.Get("failed_save_connection_warning"), err)
 	} else {
-		fmt.Print(a.i18nMgr.Get("connection_saved"))
+		a.notify(NotifySuccess, a.i18nMgr.Get("connection_saved"))
+		a.recordConnectionUse(config.Name)
 	}
 
 	return nil
 }
 
-func (a *App) handleListConnections() error {
-	connections, err := a.configMgr.ListConnections()
-	if err != nil {
-		return fmt.Errorf(a.i18nMgr.Get("failed_to_list_connections"), err)
+// readPoolSettings asks for the connection pool settings of config; an empty
+// answer keeps the default
+func (a *App) readPoolSettings(reader *bufio.Reader, config *core.ConnectionConfig) error {
+	for _, setting := range []struct {
+		promptID string
+		value    *int
+	}{
+		{"enter_max_connections", &config.MaxConnections},
+		{"enter_min_connections", &config.MinConnections},
+		{"enter_acquire_timeout", &config.AcquireTimeoutSecs},
+		{"enter_idle_timeout", &config.IdleTimeoutSecs},
+	} {
+		fmt.Print(a.i18nMgr.Get(setting.promptID))
+		answer, _ := reader.ReadString('\n')
+		answer = strings.TrimSpace(answer)
+		if answer == "" {
+			continue
+		}
+		n, err := strconv.Atoi(answer)
+		if err != nil || n < 0 {
+			return fmt.Errorf(a.i18nMgr.Get("invalid_pool_setting"), answer)
+		}
+		*setting.value = n
 	}
+	return nil
+}
 
-	if len(connections) == 0 {
-		fmt.Println(a.i18nMgr.Get("no_saved_connections_found"))
-		return nil
+// connectionOrder returns how connection lists are sorted, as set by connection_order in the config file
+func (a *App) connectionOrder() string {
+	if a.aiManager == nil {
+		return config.ConnectionOrderRecent
 	}
+	return a.aiManager.GetConfig().ConnectionOrder
+}
 
-	fmt.Println(a.i18nMgr.Get("saved_connections"))
-	for i, conn := range connections {
-		fmt.Printf("  %d. %s (%s) - %s://%s:%d/%s\n",
-			i+1,
-			conn.Name,
-			conn.DatabaseType,
-			conn.DatabaseType.String(),
-			conn.Host,
-			conn.Port,
-			conn.Database)
+// recordConnectionUse moves name to the top of the recent connections and
+// remembers the server version of the connection just made
+func (a *App) recordConnectionUse(name string) {
+	version := ""
+	if server, ok := a.connection.(core.ServerInfo); ok {
+		version, _ = server.ServerVersion()
+	}
+	if err := a.configMgr.RecordUse(name, version); err != nil {
+		fmt.Printf(a.i18nMgr.Get("generic_warning"), err)
 	}
+}
 
-	return nil
+// recordConnectionFailure remembers why connecting to the saved connection name failed
+func (a *App) recordConnectionFailure(name string, err error) {
+	if recordErr := a.configMgr.RecordFailure(name, err); recordErr != nil {
+		fmt.Printf(a.i18nMgr.Get("generic_warning"), recordErr)
+	}
 }
 
 func (a *App) handleListTables() error {
@@ -741,7 +1196,7 @@ func (a *App) handleListTables() error {
 		return nil
 	}
 
-	tables, err := a.connection.ListTables()
+	tables, hit, err := a.cachedTables()
 	if err != nil {
 		return fmt.Errorf(a.i18nMgr.Get("failed_to_list_tables"), err)
 	}
@@ -755,12 +1210,18 @@ func (a *App) handleListTables() error {
 	for i, table := range tables {
 		fmt.Printf("  %d. %s\n", i+1, table)
 	}
+	if note := a.cacheNote(hit); note != "" {
+		fmt.Printf("  %s\n", note)
+	}
 
 	return nil
 }
 
+// handleDescribeTable shows the structure of a table with an estimate of its
+// size: /describe <table> [--count]. The exact COUNT(*) can take minutes on a
+// large table, so it only runs with --count, after the structure is shown.
 func (a *App) handleDescribeTable(args []string) error {
-	if len(args) == 0 {
+	if len(args) == 0 || len(args) > 2 || (len(args) == 2 && args[1] != "--count") {
 		fmt.Println(a.i18nMgr.Get("usage_describe_table"))
 		return nil
 	}
@@ -770,116 +1231,175 @@ func (a *App) handleDescribeTable(args []string) error {
 		return nil
 	}
 
-	tableName := args[0]
-	tableInfo, err := a.connection.DescribeTable(tableName)
+	tableName, exact := args[0], len(args) == 2
+	if a.describeRoutine(tableName) {
+		return nil
+	}
+
+	var tableInfo *core.TableInfo
+	var hit core.CacheHit
+	var err error
+	rows := ""
+	a.spin(a.i18nMgr.GetWithArgs("loading_table_details", tableName), func() {
+		tableInfo, hit, err = a.cachedTable(tableName)
+		if err == nil && !exact {
+			rows = a.estimatedRows(tableName)
+		}
+	})
 	if err != nil {
 		return fmt.Errorf(a.i18nMgr.Get("failed_to_describe_table"), err)
 	}
 
 	// Generate markdown content
 	markdown := a.generateTableMarkdown(tableInfo)
+	if rows != "" {
+		markdown += "\n" + rows + "\n"
+	}
+	if note := a.cacheNote(hit); note != "" {
+		markdown += "\n_" + note + "_\n"
+	}
 
 	// Display with glamour
-	return a.displayMarkdown(markdown)
+	if err := a.displayMarkdown(markdown); err != nil || !exact {
+		return err
+	}
+	return a.countRows(tableName)
 }
 
-func (a *App) generateTableMarkdown(tableInfo *core.TableInfo) string {
-	var sb strings.Builder
+// estimatedRows describes the estimated size of table, or returns "" when
+// the connection cannot estimate it
+func (a *App) estimatedRows(table string) string {
+	counter, ok := a.connection.(core.RowCounter)
+	if !ok {
+		return ""
+	}
+	rows, exact, err := counter.EstimateRows(table)
+	if err != nil {
+		return ""
+	}
+	count := core.FormatValue(core.IntValue{Value: rows}, a.formatOptions())
+	if exact {
+		return fmt.Sprintf(a.i18nMgr.Get("table_rows_exact"), count)
+	}
+	return fmt.Sprintf(a.i18nMgr.Get("table_rows_estimate"), count, table)
+}
 
-	// Title
-	sb.WriteString(fmt.Sprintf("# 📊 %s: %s\n\n", a.i18nMgr.Get("table_header"), tableInfo.Name))
+// handleCount counts the rows of a table exactly: /count <table>
+func (a *App) handleCount(args []string) error {
+	if len(args) != 1 {
+		fmt.Println(a.i18nMgr.Get("usage_count"))
+		return nil
+	}
+	if a.connection == nil {
+		fmt.Println(a.i18nMgr.Get("no_database_connection"))
+		return nil
+	}
+	return a.countRows(args[0])
+}
 
-	// Columns section
-	sb.WriteString(fmt.Sprintf("## 📋 %s\n\n", a.i18nMgr.Get("columns_header")))
-	sb.WriteString(a.i18nMgr.Get("column_table_header"))
-	sb.WriteString(a.i18nMgr.Get("column_table_separator"))
+// countRows runs COUNT(*) on table behind a spinner; Ctrl+C cancels it
+func (a *App) countRows(table string) error {
+	counter, ok := a.connection.(core.RowCounter)
+	if !ok {
+		fmt.Println(a.i18nMgr.Get("row_count_not_supported"))
+		return nil
+	}
 
-	for _, col := range tableInfo.Columns {
-		nullable := a.i18nMgr.Get("not_nullable")
-		if col.Nullable {
-			nullable = a.i18nMgr.Get("nullable")
-		}
+	ctx, stop := a.interruptContext(context.Background())
+	defer stop()
 
-		key := ""
-		if col.Key != "" {
-			key = fmt.Sprintf(a.i18nMgr.Get("key_format"), col.Key)
+	var rows int64
+	var err error
+	a.spin(a.i18nMgr.GetWithArgs("counting_rows", table), func() {
+		rows, err = counter.CountRows(ctx, table)
+	})
+	if ctx.Err() != nil {
+		fmt.Println(a.i18nMgr.Get("query_cancelled"))
+		return nil
+	}
+	if err != nil {
+		if errors.Is(err, core.ErrRowCountNotSupported) {
+			fmt.Println(a.i18nMgr.Get("row_count_not_supported"))
+			return nil
 		}
+		return err
+	}
+	fmt.Printf(a.i18nMgr.Get("table_rows_counted"), table, core.FormatValue(core.IntValue{Value: rows}, a.formatOptions()))
+	return nil
+}
 
-		defaultVal := ""
-		if col.Default != nil {
-			defaultVal = fmt.Sprintf("`%s`", *col.Default)
-		}
+func (a *App) generateTableMarkdown(tableInfo *core.TableInfo) string {
+	return core.TableMarkdown(tableInfo, a.i18nMgr)
+}
+
+func (a *App) displayMarkdown(markdown string) error {
+	// Use the shared markdown renderer
+	renderer := core.NewMarkdownRenderer(a.i18nMgr).WithHyperlinks(a.takeHyperlinks())
+	return renderer.RenderAndDisplay(markdown)
+}
+
+// viewMarkdown shows a result file, linking the URL cells formatted for it
+func (a *App) viewMarkdown(mdPath string) error {
+	return a.sessionMgr.ViewMarkdownWithLinks(mdPath, a.takeHyperlinks())
+}
 
-		sb.WriteString(fmt.Sprintf("| **%s** | `%s` | %s | %s | %s |\n",
-			col.Name, col.Type, nullable, key, defaultVal))
+func (a *App) handleProcessList() error {
+	if a.connection == nil {
+		fmt.Println(a.i18nMgr.Get("no_database_connection"))
+		return nil
 	}
 
-	// Primary keys section
-	if len(tableInfo.PrimaryKeys) > 0 {
-		sb.WriteString(fmt.Sprintf("\n## 🔑 %s\n\n", a.i18nMgr.Get("primary_keys_header")))
-		for _, pk := range tableInfo.PrimaryKeys {
-			sb.WriteString(fmt.Sprintf("- **%s**\n", pk))
-		}
+	admin, ok := a.connection.(core.ServerAdmin)
+	if !ok {
+		fmt.Print(a.i18nMgr.Get("server_admin_not_supported"))
+		return nil
 	}
 
-	// Constraints section
-	if len(tableInfo.Constraints) > 0 {
-		sb.WriteString(fmt.Sprintf("\n## ⚠️ %s\n\n", a.i18nMgr.Get("constraints_header")))
-		for _, constraint := range tableInfo.Constraints {
-			sb.WriteString(fmt.Sprintf("### %s (%s)\n", constraint.Name, constraint.Type))
-			sb.WriteString(fmt.Sprintf("- **%s:** %s\n", a.i18nMgr.Get("column_header"), constraint.Column))
-			if constraint.Check != "" {
-				sb.WriteString(fmt.Sprintf("- **%s:** `%s`\n", a.i18nMgr.Get("check_header"), constraint.Check))
-			}
-			sb.WriteString("\n")
+	processes, err := admin.ListProcesses()
+	if err != nil {
+		if errors.Is(err, core.ErrServerAdminNotSupported) {
+			fmt.Print(a.i18nMgr.Get("server_admin_not_supported"))
+			return nil
+		}
+		if errors.Is(err, core.ErrInsufficientPrivileges) {
+			fmt.Print(a.i18nMgr.Get("processlist_permission_denied"))
 		}
+		return fmt.Errorf(a.i18nMgr.Get("failed_to_list_processes"), err)
 	}
 
-	// Foreign keys section
-	if len(tableInfo.ForeignKeys) > 0 {
-		sb.WriteString(fmt.Sprintf("\n## 🔗 %s\n\n", a.i18nMgr.Get("foreign_keys_header")))
-		for _, fk := range tableInfo.ForeignKeys {
-			sb.WriteString(fmt.Sprintf("### %s\n", fk.Name))
-			sb.WriteString(fmt.Sprintf("- **%s:** %s\n", a.i18nMgr.Get("column_header"), fk.Column))
-			sb.WriteString(fmt.Sprintf("- **References:** %s.%s\n", fk.ReferencedTable, fk.ReferencedColumn))
-			if fk.OnDelete != "" {
-				sb.WriteString(fmt.Sprintf("- **On Delete:** %s\n", fk.OnDelete))
-			}
-			if fk.OnUpdate != "" {
-				sb.WriteString(fmt.Sprintf("- **On Update:** %s\n", fk.OnUpdate))
-			}
-			sb.WriteString("\n")
-		}
+	if len(processes) == 0 {
+		fmt.Println(a.i18nMgr.Get("no_processes_found"))
+		return nil
 	}
 
-	return sb.String()
+	return a.displayMarkdown(a.generateProcessListMarkdown(processes))
 }
 
-func (a *App) displayMarkdown(markdown string) error {
-	// Use the shared markdown renderer
-	renderer := core.NewMarkdownRenderer(a.i18nMgr)
-	return renderer.RenderAndDisplay(markdown)
-}
+func (a *App) generateProcessListMarkdown(processes []core.ProcessInfo) string {
+	var sb strings.Builder
 
-func (a *App) handleStatus() {
-	if a.connection == nil {
-		fmt.Println(a.i18nMgr.Get("status_not_connected"))
-		fmt.Println(a.i18nMgr.Get("use_connect_to_establish_connection"))
-		return
-	}
+	sb.WriteString(fmt.Sprintf("# 🖥️ %s (%d)\n\n", a.i18nMgr.Get("process_list_header"), len(processes)))
+	sb.WriteString(a.i18nMgr.Get("process_table_header"))
+	sb.WriteString(a.i18nMgr.Get("process_table_separator"))
 
-	fmt.Printf(a.i18nMgr.Get("status_connected"), a.config.Name)
-	fmt.Printf(a.i18nMgr.Get("database_info"), a.config.Database)
-	fmt.Printf(a.i18nMgr.Get("type_info"), a.config.DatabaseType)
-	if a.config.DatabaseType != core.SQLite {
-		fmt.Printf(a.i18nMgr.Get("host_info"), a.config.Host, a.config.Port)
-		fmt.Printf(a.i18nMgr.Get("username_info"), a.config.Username)
+	for _, p := range processes {
+		query := strings.Join(strings.Fields(p.Query), " ")
+		query = strings.ReplaceAll(a.truncateQuery(query), "|", "\\|")
+		if query != "" {
+			query = fmt.Sprintf("`%s`", query)
+		}
+		sb.WriteString(fmt.Sprintf("| %d | %s | %s | %s | %s | %s |\n",
+			p.ID, p.User, p.Database, p.State, p.Duration, query))
 	}
+
+	sb.WriteString(fmt.Sprintf("\n%s\n", a.i18nMgr.Get("process_list_kill_hint")))
+	return sb.String()
 }
 
-func (a *App) handleExecQuery(args []string) error {
+func (a *App) handleKillProcess(args []string) error {
 	if len(args) == 0 {
-		return a.handleMultilineExec()
+		fmt.Println(a.i18nMgr.Get("usage_kill_process"))
+		return nil
 	}
 
 	if a.connection == nil {
@@ -887,28 +1407,86 @@ func (a *App) handleExecQuery(args []string) error {
 		return nil
 	}
 
-	line := strings.Join(args, " ")
+	admin, ok := a.connection.(core.ServerAdmin)
+	if !ok || a.config.DatabaseType == core.SQLite {
+		fmt.Print(a.i18nMgr.Get("server_admin_not_supported"))
+		return nil
+	}
 
-	// Check if it's a CSV export
-	if strings.Contains(line, " > ") {
-		return a.processQueryWithCSVExport(line)
+	id, err := strconv.ParseInt(args[0], 10, 64)
+	if err != nil || id <= 0 {
+		return fmt.Errorf(a.i18nMgr.Get("invalid_process_id"), args[0])
 	}
-	mdPath, writer, err := a.prepareQueryResultMarkdown()
-	if err != nil {
-		fmt.Println("Warning:", err.Error())
+
+	if !a.confirm(fmt.Sprintf(a.i18nMgr.Get("confirm_kill_process"), id)) {
+		a.notify(NotifyInfo, a.i18nMgr.Get("kill_process_cancelled"))
 		return nil
 	}
-	err = a.processQuery(line, writer)
-	writer.Close()
+
+	if err := admin.KillProcess(id); err != nil {
+		switch {
+		case errors.Is(err, core.ErrProcessNotFound):
+			fmt.Printf(a.i18nMgr.Get("process_not_found"), id)
+			return nil
+		case errors.Is(err, core.ErrInsufficientPrivileges):
+			fmt.Print(a.i18nMgr.Get("kill_permission_denied"))
+		}
+		return fmt.Errorf(a.i18nMgr.Get("failed_to_kill_process"), err)
+	}
+
+	a.notify(NotifySuccess, a.i18nMgr.Get("process_killed"), id)
+	return nil
+}
+
+// confirm asks a yes/no question on the prompt line; anything but y/yes counts as no
+func (a *App) confirm(question string) bool {
+	answer := a.ask(question)
+	return answer == "y" || answer == "yes"
+}
+
+// ask puts question on the prompt line and returns the answer trimmed and in
+// lower case, or "" when there is no prompt or it was interrupted
+func (a *App) ask(question string) string {
+	return strings.ToLower(a.askText(question))
+}
+
+// askText asks question on the prompt line and returns the answer as typed,
+// trimmed
+func (a *App) askText(question string) string {
+	if a.rl == nil {
+		return ""
+	}
+
+	a.rl.HistoryDisable()
+	defer a.rl.HistoryEnable()
+	defer a.updatePrompt()
+
+	a.rl.SetPrompt(question)
+	answer, err := a.rl.Readline()
 	if err != nil {
-		fmt.Println("Warning:", err.Error())
+		return ""
+	}
+	return strings.TrimSpace(answer)
+}
+
+// handleExecQuery runs the rest of the line, keeping its line breaks so a
+// pasted query's -- comments end where they did: /exec [query]
+func (a *App) handleExecQuery(line string) error {
+	line, _ = execQuery(line)
+	if line == "" {
+		return a.handleMultilineExec()
+	}
+
+	if a.connection == nil {
+		fmt.Println(a.i18nMgr.Get("no_database_connection"))
 		return nil
 	}
-	if err := a.sessionMgr.ViewMarkdown(mdPath); err != nil {
-		fmt.Printf(a.i18nMgr.Get("generic_warning"), err)
+
+	// Check if it's a CSV export
+	if strings.Contains(line, " > ") {
+		return a.processQueryWithCSVExport(line)
 	}
-	fmt.Printf("📍 %s: %s\n", a.i18nMgr.Get("file_location"), mdPath)
-	return nil
+	return a.executeTyped(line)
 }
 
 func (a *App) handleMultilineExec() error {
@@ -922,10 +1500,14 @@ func (a *App) handleMultilineExec() error {
 	fmt.Println(a.i18nMgr.Get("multi_line_sql_end_with_semicolon"))
 	fmt.Println(a.i18nMgr.Get("multi_line_sql_cancel"))
 	fmt.Println(a.i18nMgr.Get("multi_line_sql_csv_export"))
+	if a.buffers != nil {
+		fmt.Printf(a.i18nMgr.Get("multi_line_buffer"), a.buffers.current().Name, a.buffers.Active+1, len(a.buffers.Buffers))
+	}
 	fmt.Println()
 
 	var queryLines []string
 	lineNumber := 1
+	defer a.clearDraft()
 
 	// Temporarily disable history for multi-line input
 	a.rl.HistoryDisable()
@@ -937,24 +1519,38 @@ func (a *App) handleMultilineExec() error {
 		a.rl.SetPrompt(prompt)
 
 		line, err := a.rl.Readline()
+		if (err == readline.ErrInterrupt || err == io.EOF || isQuit(line)) && a.keepInput(queryLines) {
+			// Don't throw away a half-typed query on a stray Ctrl+C, Ctrl+D or /quit
+			a.rl.HistoryDisable() // confirm re-enables history on return
+			continue
+		}
 		if err != nil {
 			// User pressed Ctrl+C or EOF
 			fmt.Println(a.i18nMgr.Get("multi_line_input_cancelled"))
 			a.updatePrompt() // Restore original prompt
 			return nil
 		}
+		if isQuit(line) {
+			return errQuit
+		}
 
 		line = strings.TrimSpace(line)
+		if text, lines := expandPaste(line); lines > 1 {
+			// A pasted script only runs once Enter is pressed after it
+			a.showPaste(text, lines)
+			line = text
+			lineNumber += lines - 1
+		}
 
 		if line != "" {
 			queryLines = append(queryLines, line)
+			a.saveDraft("/exec " + strings.Join(queryLines, " "))
 
-			// Check if this line ends with semicolon - if so, we're done
-			// Also handle cases like "; -- comment" or "; > file.csv"
-			if strings.Contains(line, ";") {
-				// Find the position of the last semicolon
-				lastSemi := strings.LastIndex(line, ";")
-				afterSemi := strings.TrimSpace(line[lastSemi+1:])
+			// The query is complete once this line holds a terminating semicolon,
+			// ignoring those in strings and comments. Also handle "; -- comment" or "; > file.csv"
+			buffer := strings.Join(queryLines, "\n")
+			if lastSemi := core.LastTerminator(a.dbType(), buffer); lastSemi >= len(buffer)-len(line) {
+				afterSemi := strings.TrimSpace(buffer[lastSemi+1:])
 
 				// If there's nothing after the semicolon, or only CSV export syntax, we're done
 				if afterSemi == "" || strings.HasPrefix(afterSemi, ">") || strings.HasPrefix(afterSemi, "--") {
@@ -973,11 +1569,15 @@ func (a *App) handleMultilineExec() error {
 		return nil
 	}
 
-	// Join all lines into a single query
-	fullQuery := strings.Join(queryLines, " ")
+	// Join all lines into a single query; newlines keep -- comments from swallowing later lines
+	fullQuery := strings.Join(queryLines, "\n")
+	if a.buffers != nil {
+		a.buffers.setQuery(fullQuery)
+		a.saveBuffers()
+	}
 
 	// Add the complete multi-line query as a single history entry
-	historyEntry := "/exec " + fullQuery
+	historyEntry := a.historyEntry("/exec " + strings.ReplaceAll(fullQuery, "\n", " "))
 	if err := a.rl.SaveHistory(historyEntry); err != nil {
 		fmt.Printf(a.i18nMgr.Get("failed_save_command_history_warning"), err)
 	}
@@ -991,18 +1591,35 @@ func (a *App) handleMultilineExec() error {
 	}
 
 	// Regular execution
+	return a.executeTyped(fullQuery)
+}
+
+// executeAndView runs a single query, saves the result as markdown and displays it.
+// Ctrl+C while the query runs cancels it instead of killing sqlterm.
+func (a *App) executeAndView(query string) error {
 	mdPath, writer, err := a.prepareQueryResultMarkdown()
 	if err != nil {
 		fmt.Println("Warning:", err.Error())
 		return nil
 	}
-	err = a.processQuery(fullQuery, writer)
+
+	ctx, stop := a.interruptContext(context.Background())
+	defer stop()
+
+	err = a.processQuery(ctx, query, writer)
 	writer.Close()
+	if ctx.Err() != nil {
+		fmt.Println(a.i18nMgr.Get("query_cancelled"))
+		return nil
+	}
 	if err != nil {
 		fmt.Println("Warning:", err.Error())
+		if core.IsConnectionLost(err) {
+			return a.offerReconnect(query)
+		}
 		return nil
 	}
-	if err := a.sessionMgr.ViewMarkdown(mdPath); err != nil {
+	if err := a.viewMarkdown(mdPath); err != nil {
 		fmt.Printf(a.i18nMgr.Get("generic_warning"), err)
 	}
 	fmt.Printf("📍 %s: %s\n", a.i18nMgr.Get("file_location"), mdPath)
@@ -1020,22 +1637,45 @@ func (a *App) processQueryWithCSVExport(line string) error {
 		return errors.New(a.i18nMgr.Get("invalid_csv_export_syntax"))
 	}
 
-	query := strings.TrimSpace(parts[0])
-	filename := strings.TrimSpace(parts[1])
+	query, err := a.expandPins(strings.TrimSpace(parts[0]))
+	if err != nil {
+		return err
+	}
+	filename := core.ExpandPath(parts[1])
 
 	fmt.Printf(a.i18nMgr.Get("executing_query_streaming"), filename)
 
-	result, err := a.connection.Execute(query)
+	ctx, stop := a.interruptContext(context.Background())
+	defer stop()
+
+	if err := a.checkReadOnly(query); err != nil {
+		return err
+	}
+	if !a.allowDestructive(query) {
+		return nil
+	}
+
+	result, err := a.connection.ExecuteContext(ctx, query)
 	if err != nil {
+		if ctx.Err() != nil {
+			fmt.Println(a.i18nMgr.Get("query_cancelled"))
+			return nil
+		}
 		return fmt.Errorf(a.i18nMgr.Get("query_execution_failed"), err)
 	}
 
-	rows, err := core.SaveQueryResultAsStreamingCSV(result, filename)
+	progress, clearProgress := a.exportProgress(filename)
+	rows, err := core.SaveQueryResultToFile(result, filename, a.exportFormat(), a.exportMetadata(query), progress)
+	clearProgress()
 	if err != nil {
+		if ctx.Err() != nil {
+			fmt.Println(a.i18nMgr.Get("query_cancelled"))
+			return nil
+		}
 		return fmt.Errorf("failed to save CSV: %w", err)
 	}
 
-	fmt.Printf(a.i18nMgr.Get("exported_rows_to_file"), rows, filename)
+	a.notify(NotifySuccess, a.i18nMgr.Get("export_completed"), rows, filename)
 	return nil
 }
 
@@ -1051,7 +1691,7 @@ func (a *App) processFileCommandWithCSVExport(line string) error {
 	}
 
 	fileCmd := strings.TrimSpace(parts[0])
-	csvFilename := strings.TrimSpace(parts[1])
+	csvFilename := core.ExpandPath(parts[1])
 
 	// Parse the file command
 	cmdParts := strings.Fields(fileCmd)
@@ -1089,13 +1729,8 @@ func (a *App) executeFileWithCSVExport(filename string, queryRange []int, csvFil
 		return nil
 	}
 
-	// Try to find the file in current directory or queries directory
-	var filepath string
-	if _, err := os.Stat(filename); err == nil {
-		filepath = filename
-	} else if _, err := os.Stat("queries/" + filename); err == nil {
-		filepath = "queries/" + filename
-	} else {
+	filepath, ok := resolveQueryFile(filename, a.projectRoot())
+	if !ok {
 		return fmt.Errorf(a.i18nMgr.Get("file_not_found"), filename)
 	}
 
@@ -1110,6 +1745,10 @@ func (a *App) executeFileWithCSVExport(filename string, queryRange []int, csvFil
 		return fmt.Errorf(a.i18nMgr.Get("failed_to_read_file"), err)
 	}
 
+	if err := a.recentFiles.add(filepath); err != nil {
+		fmt.Printf(a.i18nMgr.Get("generic_warning"), err)
+	}
+
 	queries := a.parseQueries(string(content))
 	fmt.Printf(a.i18nMgr.Get("executing_sql_file"), filename)
 	fmt.Printf(a.i18nMgr.Get("found_queries_in_file"), len(queries))
@@ -1133,6 +1772,9 @@ func (a *App) executeFileWithCSVExport(filename string, queryRange []int, csvFil
 	var totalRowsExported int
 	queryNumber := 0
 
+	ctx, stop := a.interruptContext(context.Background())
+	defer stop()
+
 	for i := start - 1; i < end && i < len(queries); i++ {
 		query := strings.TrimSpace(queries[i])
 		if query == "" {
@@ -1140,7 +1782,23 @@ func (a *App) executeFileWithCSVExport(filename string, queryRange []int, csvFil
 		}
 
 		fmt.Printf(a.i18nMgr.Get("query_number_truncated_query"), i+1, a.truncateQuery(query))
-		result, err := a.connection.Execute(query)
+		query, err := a.expandPins(query)
+		if err != nil {
+			fmt.Printf(a.i18nMgr.Get("query_failed"), err)
+			continue
+		}
+		if err := a.checkReadOnly(query); err != nil {
+			fmt.Printf(a.i18nMgr.Get("query_failed"), err)
+			continue
+		}
+		if !a.allowDestructive(query) {
+			continue
+		}
+		result, err := a.connection.ExecuteContext(ctx, query)
+		if ctx.Err() != nil {
+			fmt.Println(a.i18nMgr.Get("query_cancelled"))
+			break
+		}
 		if err != nil {
 			fmt.Printf(a.i18nMgr.Get("query_failed"), err)
 			continue
@@ -1157,7 +1815,9 @@ func (a *App) executeFileWithCSVExport(filename string, queryRange []int, csvFil
 			outputPath = core.GenerateNumberedCSVPath(csvFilename, queryNumber)
 		}
 
-		rows, err := core.SaveQueryResultAsStreamingCSV(result, outputPath)
+		progress, clearProgress := a.exportProgress(outputPath)
+		rows, err := core.SaveQueryResultToFile(result, outputPath, a.exportFormat(), a.exportMetadata(query), progress)
+		clearProgress()
 		if err != nil {
 			fmt.Printf("❌ Failed to save CSV: %v\n", err)
 			continue
@@ -1205,19 +1865,25 @@ func (a *App) processAIChat(message string) error {
 	var tables []string
 	if a.connection != nil {
 		var err error
-		tables, err = a.connection.ListTables()
+		tables, _, err = a.cachedTables()
 		if err != nil {
 			fmt.Printf("Warning: failed to get table list for AI context: %v\n", err)
 		}
 	}
 
-	// Create context with timeout for AI requests
-	ctx, cancel := context.WithTimeout(context.Background(), 2*time.Minute)
+	// Create context with timeout for AI requests; Ctrl+C cancels the request
+	interruptCtx, stop := a.interruptContext(context.Background())
+	defer stop()
+	ctx, cancel := context.WithTimeout(interruptCtx, 2*time.Minute)
 	defer cancel()
 
 	// Use new conversational chat system
 	response, err := a.aiManager.ChatWithConversation(ctx, message, tables)
 	if err != nil {
+		if interruptCtx.Err() != nil {
+			fmt.Println(a.i18nMgr.Get("ai_request_cancelled"))
+			return nil
+		}
 		// Provide more helpful error messages for common issues
 		if strings.Contains(err.Error(), "timeout") || strings.Contains(err.Error(), "deadline exceeded") {
 			fmt.Print(a.i18nMgr.Get("ai_timeout_message"))
@@ -1287,6 +1953,8 @@ func (a *App) handleConfig(args []string) error {
 		return a.handleConfigAI(args[1:])
 	case "language":
 		return a.handleConfigLanguage(args[1:])
+	case "display":
+		return a.handleConfigDisplay(args[1:])
 	default:
 		fmt.Printf(a.i18nMgr.Get("unknown_config_section"), section)
 		a.printConfigHelp([]string{})
@@ -1307,6 +1975,11 @@ func (a *App) handleConfigStatus() error {
 	}
 	fmt.Println()
 
+	if a.aiManager != nil {
+		a.printDisplaySettings(a.aiManager.GetConfig().Display)
+		fmt.Println()
+	}
+
 	// AI configuration status
 	fmt.Println("🤖 AI Configuration:")
 	if a.aiManager == nil {
@@ -1622,6 +2295,249 @@ func (a *App) handleConfigLanguage(args []string) error {
 	return nil
 }
 
+func (a *App) handleConfigDisplay(args []string) error {
+	if a.aiManager == nil {
+		return errors.New(a.i18nMgr.Get("ai_manager_not_initialized"))
+	}
+
+	display := a.aiManager.GetConfig().Display
+
+	if len(args) == 0 || args[0] == "status" {
+		a.printDisplaySettings(display)
+		return nil
+	}
+
+	if len(args) < 2 {
+		return a.printConfigDisplayHelp()
+	}
+
+	setting := args[0]
+	value := strings.Join(args[1:], " ")
+
+	switch setting {
+	case "timezone":
+		display.Timezone = value
+		if _, err := display.Location(); err != nil {
+			return errors.New(a.i18nMgr.GetWithArgs("invalid_display_timezone", value))
+		}
+	case "datetime-format":
+		display.DateTimeFormat = value
+	case "thousands":
+		switch value {
+		case "on":
+			display.ThousandsSeparator = true
+		case "off":
+			display.ThousandsSeparator = false
+		default:
+			return errors.New(a.i18nMgr.GetWithArgs("invalid_display_value", setting, "on, off"))
+		}
+	case "float-precision":
+		if value == "auto" {
+			display.FloatPrecision = nil
+		} else {
+			precision, err := strconv.Atoi(value)
+			if err != nil || precision < 0 || precision > 15 {
+				return errors.New(a.i18nMgr.GetWithArgs("invalid_display_value", setting, "auto, 0-15"))
+			}
+			display.FloatPrecision = &precision
+		}
+	case "export":
+		switch value {
+		case "iso":
+			display.FormatExports = false
+		case "formatted":
+			display.FormatExports = true
+		default:
+			return errors.New(a.i18nMgr.GetWithArgs("invalid_display_value", setting, "iso, formatted"))
+		}
+	case "cell-width":
+		if value == "off" {
+			display.MaxCellWidth = -1
+		} else {
+			width, err := strconv.Atoi(value)
+			if err != nil || width < 10 {
+				return errors.New(a.i18nMgr.GetWithArgs("invalid_display_value", setting, "off, 10 or more"))
+			}
+			display.MaxCellWidth = width
+		}
+	case "cell-length":
+		switch value {
+		case "on":
+			display.HideTruncatedLength = false
+		case "off":
+			display.HideTruncatedLength = true
+		default:
+			return errors.New(a.i18nMgr.GetWithArgs("invalid_display_value", setting, "on, off"))
+		}
+	case "sort":
+		collation, ok := core.ParseCollation(value)
+		if !ok {
+			return errors.New(a.i18nMgr.GetWithArgs("invalid_display_value", setting, "unicode, bytes"))
+		}
+		display.SortCollation = collation
+	case "sort-numeric":
+		switch value {
+		case "on":
+			display.SortNumeric = true
+		case "off":
+			display.SortNumeric = false
+		default:
+			return errors.New(a.i18nMgr.GetWithArgs("invalid_display_value", setting, "on, off"))
+		}
+	case "server-timing":
+		switch value {
+		case "on":
+			display.ServerTiming = true
+		case "off":
+			display.ServerTiming = false
+		default:
+			return errors.New(a.i18nMgr.GetWithArgs("invalid_display_value", setting, "on, off"))
+		}
+	case "export-metadata":
+		switch value {
+		case "on":
+			display.ExportMetadata = true
+		case "off":
+			display.ExportMetadata = false
+		default:
+			return errors.New(a.i18nMgr.GetWithArgs("invalid_display_value", setting, "on, off"))
+		}
+	case "hyperlinks":
+		switch value {
+		case config.HyperlinksAuto, config.HyperlinksOn, config.HyperlinksOff:
+			display.Hyperlinks = value
+		default:
+			return errors.New(a.i18nMgr.GetWithArgs("invalid_display_value", setting, "auto, on, off"))
+		}
+	default:
+		fmt.Printf(a.i18nMgr.Get("unknown_display_setting"), setting)
+		return a.printConfigDisplayHelp()
+	}
+
+	if err := a.aiManager.SetDisplay(display); err != nil {
+		return fmt.Errorf(a.i18nMgr.Get("failed_to_update_display"), err)
+	}
+
+	fmt.Printf(a.i18nMgr.Get("display_setting_updated"), setting, value)
+	return nil
+}
+
+func (a *App) printDisplaySettings(display config.DisplayConfig) {
+	timezone := display.Timezone
+	if timezone == "" {
+		timezone = config.TimezoneUTC
+	}
+	datetimeFormat := display.DateTimeFormat
+	if datetimeFormat == "" {
+		datetimeFormat = config.DateTimeFormatDefault
+	}
+	thousands := "off"
+	if display.ThousandsSeparator {
+		thousands = "on"
+	}
+	precision := "auto"
+	if display.FloatPrecision != nil {
+		precision = strconv.Itoa(*display.FloatPrecision)
+	}
+	export := "iso"
+	if display.FormatExports {
+		export = "formatted"
+	}
+
+	cellWidth := strconv.Itoa(core.DefaultMaxCellWidth)
+	switch {
+	case display.MaxCellWidth < 0:
+		cellWidth = "off"
+	case display.MaxCellWidth > 0:
+		cellWidth = strconv.Itoa(display.MaxCellWidth)
+	}
+	cellLength := "on"
+	if display.HideTruncatedLength {
+		cellLength = "off"
+	}
+
+	sortNumeric := "off"
+	if display.SortNumeric {
+		sortNumeric = "on"
+	}
+
+	serverTiming := "off"
+	if display.ServerTiming {
+		serverTiming = "on"
+	}
+	exportMetadata := "off"
+	if display.ExportMetadata {
+		exportMetadata = "on"
+	}
+
+	fmt.Printf(a.i18nMgr.Get("display_settings_status"), timezone, datetimeFormat, thousands, precision, export, cellWidth, cellLength,
+		display.SortOptions().Collation, sortNumeric, serverTiming, exportMetadata, display.HyperlinksMode())
+}
+
+// displayConfig returns the display settings with the project's merged over them
+func (a *App) displayConfig() config.DisplayConfig {
+	var display config.DisplayConfig
+	if a.aiManager != nil {
+		display = a.aiManager.GetConfig().Display
+	}
+	return a.project.ApplyDisplay(display)
+}
+
+// formatOptions returns the configured result formatting, falling back to the defaults
+func (a *App) formatOptions() core.FormatOptions {
+	opts, err := a.displayConfig().FormatOptions()
+	if err != nil {
+		return core.DefaultFormatOptions()
+	}
+	return opts
+}
+
+// exportProgress counts the rows written to filename on one line that
+// updates in place, when stdout is a terminal. clear removes the line once the
+// export ends.
+func (a *App) exportProgress(filename string) (progress core.ExportProgress, clear func()) {
+	if !term.IsTerminal(int(os.Stdout.Fd())) {
+		return nil, func() {}
+	}
+
+	shown := false
+	progress = func(rows int) {
+		shown = true
+		fmt.Printf("\r"+a.i18nMgr.Get("export_progress"), rows, filename)
+	}
+	clear = func() {
+		if shown {
+			fmt.Print("\r\033[K")
+		}
+	}
+	return progress, clear
+}
+
+// exportFormat returns nil so exports stay machine-readable unless formatted exports are enabled
+func (a *App) exportFormat() *core.FormatOptions {
+	if a.aiManager == nil || !a.aiManager.GetConfig().Display.FormatExports {
+		return nil
+	}
+
+	opts := a.formatOptions()
+	return &opts
+}
+
+// exportMetadata describes query for the comment header of an export, or
+// returns nil when export metadata is off
+func (a *App) exportMetadata(query string) *core.ExportMetadata {
+	if a.aiManager == nil || !a.aiManager.GetConfig().Display.ExportMetadata || a.config == nil {
+		return nil
+	}
+	return &core.ExportMetadata{
+		Query:      query,
+		Connection: a.config.Name,
+		Database:   a.config.Database,
+		ExportedAt: time.Now().UTC(),
+		Version:    diag.Version,
+	}
+}
+
 func (a *App) handleConfigAIOpenRouter(args []string) error {
 	if a.aiManager == nil {
 		return errors.New(a.i18nMgr.Get("ai_manager_not_initialized"))
@@ -1674,6 +2590,8 @@ func (a *App) printConfigHelp(args []string) error {
 		return a.printConfigAIHelp()
 	case "language":
 		return a.printConfigLanguageHelp()
+	case "display":
+		return a.printConfigDisplayHelp()
 	case "status":
 		return a.printConfigStatusHelp()
 	default:
@@ -2076,6 +2994,9 @@ func (a *App) handleShowPrompts(args []string) error {
 		timeStr := entry.Timestamp.Format("2006-01-02 15:04:05")
 
 		writeOutput(a.i18nMgr.GetWithArgs("request_number", i+1, timeStr))
+		if entry.Connection != "" {
+			writeOutput(a.i18nMgr.GetWithArgs("request_connection", entry.Connection))
+		}
 
 		// Provider, model, tokens, cost info
 		writeOutput(a.i18nMgr.GetWithArgs("provider_info", entry.Provider, entry.Model, entry.InputTokens, entry.OutputTokens))
@@ -2117,7 +3038,7 @@ func (a *App) handleShowPrompts(args []string) error {
 		writer.Close()
 		if mdPath != "" {
 			// Display the markdown file using the same method as query results
-			if err := a.sessionMgr.ViewMarkdown(mdPath); err != nil {
+			if err := a.viewMarkdown(mdPath); err != nil {
 				fmt.Printf(a.i18nMgr.Get("generic_warning"), err)
 			}
 			fmt.Printf(a.i18nMgr.Get("conversation_history_saved"), mdPath)
@@ -2127,6 +3048,58 @@ func (a *App) handleShowPrompts(args []string) error {
 	return nil
 }
 
+// reportError prints err from the command line under the prompt and keeps it for /errors
+func (a *App) reportError(line string, err error) {
+	a.recordError(line, err)
+	fmt.Printf(a.i18nMgr.Get("generic_error"), err)
+}
+
+// recordError appends to the bounded error history, dropping the oldest entries first
+func (a *App) recordError(command string, err error) {
+	a.errorHistory = append(a.errorHistory, errorEntry{
+		Source:  a.resultSource(),
+		Command: command,
+		Err:     err,
+	})
+	if len(a.errorHistory) > maxErrorHistory {
+		a.errorHistory = a.errorHistory[len(a.errorHistory)-maxErrorHistory:]
+	}
+}
+
+func (a *App) handleErrors(args []string) error {
+	if len(args) > 0 && args[0] == "clear" {
+		a.errorHistory = nil
+		a.notify(NotifyInfo, a.i18nMgr.Get("error_history_cleared"))
+		return nil
+	}
+
+	if len(a.errorHistory) == 0 {
+		fmt.Println(a.i18nMgr.Get("no_error_history"))
+		return nil
+	}
+
+	count := len(a.errorHistory)
+	if len(args) > 0 {
+		if parsedCount, err := strconv.Atoi(args[0]); err == nil && parsedCount > 0 && parsedCount < count {
+			count = parsedCount
+		}
+	}
+
+	fmt.Printf(a.i18nMgr.Get("error_history_header"), count)
+	for i := len(a.errorHistory) - count; i < len(a.errorHistory); i++ {
+		entry := a.errorHistory[i]
+		fmt.Printf("  %s %s\n", entry.Source.Label(), a.truncateQuery(entry.Command))
+		fmt.Printf("      ❌ %v\n", entry.Err)
+	}
+
+	return nil
+}
+
+// handleDiag prints runtime diagnostics for bug reports
+func (a *App) handleDiag() {
+	fmt.Print(diag.Collect(a.configMgr, a.connection, a.config))
+}
+
 func (a *App) handleClearConversation() error {
 	if a.aiManager == nil {
 		fmt.Println(a.i18nMgr.Get("ai_not_configured_short"))
@@ -2180,6 +3153,13 @@ func (a *App) printConfigLanguageHelp() error {
 	return nil
 }
 
+func (a *App) printConfigDisplayHelp() error {
+	fmt.Print(a.i18nMgr.Get("help_config_display_title"))
+	fmt.Print(a.i18nMgr.Get("help_config_display_commands"))
+	fmt.Print(a.i18nMgr.Get("help_config_display_examples"))
+	return nil
+}
+
 func (a *App) printConfigStatusHelp() error {
 	fmt.Print(a.i18nMgr.Get("help_config_status_title"))
 	fmt.Print(a.i18nMgr.Get("help_config_status_description"))
@@ -0,0 +1,194 @@
+package conversation
+
+import (
+	"bytes"
+	"context"
+	"errors"
+	"strings"
+	"testing"
+	"time"
+
+	"sqlterm/internal/core"
+	"sqlterm/internal/core/coretest"
+)
+
+func newMockUsersConnection() *coretest.MockConnection {
+	return coretest.NewMockConnection().
+		AddTable(&core.TableInfo{
+			Name: "users",
+			Columns: []core.ColumnInfo{
+				{Name: "id", Type: "int", Key: "PRI"},
+				{Name: "name", Type: "varchar(255)", Nullable: true},
+			},
+			PrimaryKeys: []string{"id"},
+		}).
+		AddTable(&core.TableInfo{Name: "orders"}).
+		AddResponse("SELECT id, name FROM users",
+			[]core.Column{{Name: "id", Type: "INT"}, {Name: "name", Type: "VARCHAR"}},
+			[][]core.Value{
+				{core.IntValue{Value: 1}, core.StringValue{Value: "alice"}},
+				{core.IntValue{Value: 2}, core.NullValue{}},
+			})
+}
+
+func TestApp_Flow_QueryAgainstMockConnection(t *testing.T) {
+	app := createTestApp(t)
+	conn := newMockUsersConnection()
+	app.connection = conn
+	app.config = &core.ConnectionConfig{Name: "mock-db", Database: "shop"}
+
+	var buf bytes.Buffer
+	if err := app.processQuery(context.Background(), "SELECT id, name FROM users;", &buf); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+
+	output := buf.String()
+	for _, expected := range []string{"alice", "| id", "name"} {
+		if !strings.Contains(output, expected) {
+			t.Errorf("Expected result markdown to contain '%s', got:\n%s", expected, output)
+		}
+	}
+
+	if queries := conn.ExecutedQueries(); len(queries) != 1 || queries[0] != "SELECT id, name FROM users;" {
+		t.Errorf("Expected the query to reach the connection once, got %v", queries)
+	}
+	if source := app.lastStored.Source; source.Connection != "mock-db" || source.Database != "shop" || source.Time.IsZero() {
+		t.Errorf("Expected the result to be stamped with its connection, got %+v", source)
+	}
+}
+
+func TestApp_Flow_QueryNotices(t *testing.T) {
+	app := createTestApp(t)
+	app.results = core.NewResultStore(core.DefaultResultCapacity)
+	query := "INSERT IGNORE INTO users (name) VALUES ('a name longer than the column')"
+	app.connection = newMockUsersConnection().
+		AddResponse(query, nil, nil).
+		AddNotices(query, core.Notice{Level: "Warning", Code: "1265", Message: "Data truncated for column 'name' at row 1"})
+	app.config = &core.ConnectionConfig{Name: "mock-db"}
+
+	var buf bytes.Buffer
+	if err := app.processQuery(context.Background(), query, &buf); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if !strings.Contains(buf.String(), "> ⚠️ Warning 1265: Data truncated for column 'name' at row 1") {
+		t.Errorf("Expected the warning under the result, got:\n%s", buf.String())
+	}
+	entries := app.results.Entries()
+	if len(entries) != 1 || len(entries[0].Notices) != 1 {
+		t.Errorf("Expected the warning kept in the result history, got %+v", entries)
+	}
+}
+
+func TestApp_Flow_QueryError(t *testing.T) {
+	app := createTestApp(t)
+	app.connection = coretest.NewMockConnection().AddQueryError("SELECT * FROM missing", errors.New("table missing does not exist"))
+	app.config = &core.ConnectionConfig{Name: "mock-db"}
+
+	var buf bytes.Buffer
+	err := app.processQuery(context.Background(), "SELECT * FROM missing", &buf)
+	if err == nil || !strings.Contains(err.Error(), "table missing does not exist") {
+		t.Errorf("Expected the driver error to surface, got %v", err)
+	}
+
+	if buf.Len() != 0 {
+		t.Errorf("Expected no result output on error, got:\n%s", buf.String())
+	}
+}
+
+func TestApp_Flow_QueryCancelled(t *testing.T) {
+	app := createTestApp(t)
+	conn := newMockUsersConnection()
+	conn.Latency = time.Minute
+	app.connection = conn
+	app.config = &core.ConnectionConfig{Name: "mock-db"}
+
+	ctx, cancel := context.WithCancel(context.Background())
+	cancel()
+
+	err := app.processQuery(ctx, "SELECT id, name FROM users", &bytes.Buffer{})
+	if !errors.Is(err, context.Canceled) {
+		t.Errorf("Expected a cancelled query to return context.Canceled, got %v", err)
+	}
+}
+
+func TestApp_Flow_TablesAndDescribe(t *testing.T) {
+	app := createTestApp(t)
+	app.connection = newMockUsersConnection()
+	app.config = &core.ConnectionConfig{Name: "mock-db", Database: "shop"}
+
+	if err := app.processCommand("/tables"); err != nil {
+		t.Errorf("Unexpected error listing tables: %v", err)
+	}
+
+	err := app.processCommand("/describe nope")
+	if err == nil || !strings.Contains(err.Error(), "nope does not exist") {
+		t.Errorf("Expected describe of unknown table to fail, got %v", err)
+	}
+
+	info, err := app.connection.DescribeTable("users")
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	markdown := app.generateTableMarkdown(info)
+	if !strings.Contains(markdown, "varchar(255)") {
+		t.Errorf("Expected table markdown to include column types, got:\n%s", markdown)
+	}
+}
+
+func TestApp_Flow_ListTablesError(t *testing.T) {
+	app := createTestApp(t)
+	app.connection = coretest.NewMockConnection().FailMethod("ListTables", errors.New("connection reset"))
+	app.config = &core.ConnectionConfig{Name: "mock-db"}
+
+	err := app.processCommand("/tables")
+	if err == nil || !strings.Contains(err.Error(), "connection reset") {
+		t.Errorf("Expected list tables error to surface, got %v", err)
+	}
+}
+
+func TestApp_Flow_SchemaCache(t *testing.T) {
+	app := createTestApp(t)
+	conn := newMockUsersConnection().AddResponse("DROP TABLE orders", nil, nil)
+	app.connection = conn
+	app.config = &core.ConnectionConfig{Name: "mock-db", Database: "shop"}
+
+	if err := app.processCommand("/tables"); err != nil {
+		t.Fatalf("Unexpected error listing tables: %v", err)
+	}
+
+	// Served from the cache, so the server is not asked again
+	conn.FailMethod("ListTables", errors.New("connection reset"))
+	if err := app.processCommand("/tables"); err != nil {
+		t.Errorf("Expected the cached table list to be used, got %v", err)
+	}
+
+	// DDL invalidates the cache
+	if err := app.processQuery(context.Background(), "DROP TABLE orders", &bytes.Buffer{}); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if err := app.processCommand("/tables"); err == nil {
+		t.Error("Expected the table list to be fetched again after DDL")
+	}
+
+	conn.FailMethod("ListTables", nil)
+	if err := app.processCommand("/refresh"); err != nil {
+		t.Errorf("Unexpected error refreshing: %v", err)
+	}
+}
+
+func TestFormatAge(t *testing.T) {
+	testCases := []struct {
+		age      time.Duration
+		expected string
+	}{
+		{45 * time.Second, "45s"},
+		{3*time.Minute + 20*time.Second, "3m"},
+		{2 * time.Hour, "2h"},
+	}
+
+	for _, tc := range testCases {
+		if got := formatAge(tc.age); got != tc.expected {
+			t.Errorf("formatAge(%v) = '%s', expected '%s'", tc.age, got, tc.expected)
+		}
+	}
+}
@@ -1,75 +1,36 @@
 package conversation
 
 import (
+	"fmt"
 	"os"
 	"path/filepath"
 	"strings"
 	"testing"
+	"time"
 
 	"sqlterm/internal/ai"
 	"sqlterm/internal/config"
 	"sqlterm/internal/core"
+	"sqlterm/internal/core/coretest"
 	"sqlterm/internal/i18n"
 	"sqlterm/internal/session"
 )
 
-// Mock implementations for testing
-
-type mockConnection struct {
-	connected bool
-	tables    []string
-	dbType    core.DatabaseType
-	name      string
-}
-
-func (m *mockConnection) Connect() error {
-	m.connected = true
-	return nil
-}
-
-func (m *mockConnection) Close() error {
-	m.connected = false
-	return nil
-}
-
-func (m *mockConnection) IsConnected() bool {
-	return m.connected
-}
-
-func (m *mockConnection) Execute(query string) (*core.QueryResult, error) {
-	// Mock query execution - return minimal QueryResult
-	return &core.QueryResult{
-		Columns: []core.Column{
-			{Name: "id", Type: "INTEGER"},
-			{Name: "name", Type: "VARCHAR"},
-		},
-	}, nil
-}
-
-func (m *mockConnection) Ping() error {
-	return nil
-}
-
-func (m *mockConnection) ListTables() ([]string, error) {
-	return m.tables, nil
-}
-
-func (m *mockConnection) DescribeTable(tableName string) (*core.TableInfo, error) {
-	return &core.TableInfo{
-		Name: tableName,
-		Columns: []core.ColumnInfo{
-			{Name: "id", Type: "INTEGER", Key: "PRI"},
-			{Name: "name", Type: "VARCHAR(255)", Nullable: true},
-		},
-	}, nil
-}
-
-func (m *mockConnection) GetDatabaseType() core.DatabaseType {
-	return m.dbType
-}
-
-func (m *mockConnection) GetConnectionName() string {
-	return m.name
+// newMockTablesConnection returns a mock connection holding tables, each with
+// an id primary key and a name column
+func newMockTablesConnection(tables ...string) *coretest.MockConnection {
+	conn := coretest.NewMockConnection()
+	for _, table := range tables {
+		conn.AddTable(&core.TableInfo{
+			Name: table,
+			Columns: []core.ColumnInfo{
+				{Name: "id", Type: "INTEGER", Key: "PRI"},
+				{Name: "name", Type: "VARCHAR(255)", Nullable: true},
+			},
+			PrimaryKeys: []string{"id"},
+		})
+	}
+	return conn
 }
 
 func createTestApp(t *testing.T) *App {
@@ -103,6 +64,7 @@ func createTestApp(t *testing.T) *App {
 		sessionMgr: sessionMgr,
 		aiManager:  aiManager,
 		i18nMgr:    i18nMgr,
+		messages:   make(chan AppMessage, messageBufferSize),
 	}
 
 	return app
@@ -141,11 +103,7 @@ func TestApp_SetConnection(t *testing.T) {
 	// Disable AI manager to avoid vector database initialization issues
 	app.aiManager = nil
 
-	mockConn := &mockConnection{
-		dbType: core.PostgreSQL,
-		name:   "test-db",
-		tables: []string{"users", "posts"},
-	}
+	mockConn := newMockTablesConnection("users", "posts")
 
 	config := &core.ConnectionConfig{
 		Name:     "test-db",
@@ -172,11 +130,7 @@ func TestApp_ClearConnection(t *testing.T) {
 	// Disable AI manager to avoid vector database initialization issues
 	app.aiManager = nil
 
-	mockConn := &mockConnection{
-		dbType:    core.PostgreSQL,
-		name:      "test-db",
-		connected: true,
-	}
+	mockConn := newMockTablesConnection()
 
 	config := &core.ConnectionConfig{
 		Name: "test-db",
@@ -198,6 +152,14 @@ func TestApp_ClearConnection(t *testing.T) {
 	}
 }
 
+func TestApp_restoreTerminal_Idempotent(t *testing.T) {
+	app := createTestApp(t)
+
+	// Must not panic when called repeatedly, e.g. from the panic handler and the deferred cleanup
+	app.restoreTerminal()
+	app.restoreTerminal()
+}
+
 func TestApp_processCommand(t *testing.T) {
 	app := createTestApp(t)
 
@@ -261,17 +223,17 @@ func TestApp_parseQueries(t *testing.T) {
 		{
 			name:     "Single query",
 			content:  "SELECT * FROM users;",
-			expected: []string{"SELECT * FROM users; "},
+			expected: []string{"SELECT * FROM users"},
 		},
 		{
 			name:     "Multiple queries",
 			content:  "SELECT * FROM users; SELECT * FROM posts;",
-			expected: []string{"SELECT * FROM users; SELECT * FROM posts; "},
+			expected: []string{"SELECT * FROM users", "SELECT * FROM posts"},
 		},
 		{
 			name:     "Query with comments",
 			content:  "-- Get all users\nSELECT * FROM users;\n-- Get all posts\nSELECT * FROM posts;",
-			expected: []string{"SELECT * FROM users; ", "SELECT * FROM posts; "},
+			expected: []string{"SELECT * FROM users", "SELECT * FROM posts"},
 		},
 		{
 			name:     "Empty content",
@@ -288,6 +250,16 @@ func TestApp_parseQueries(t *testing.T) {
 			content:  "SELECT * FROM users",
 			expected: []string{"SELECT * FROM users"},
 		},
+		{
+			name:     "Semicolon in string",
+			content:  "SELECT ';' AS sep;\nSELECT 2;",
+			expected: []string{"SELECT ';' AS sep", "SELECT 2"},
+		},
+		{
+			name:     "Semicolon in comment",
+			content:  "-- first; note\nSELECT 1; /* a; b */ SELECT 2",
+			expected: []string{"SELECT 1", "SELECT 2"},
+		},
 	}
 
 	for _, tc := range testCases {
@@ -336,6 +308,11 @@ func TestApp_truncateQuery(t *testing.T) {
 			query:    strings.Repeat("a", 100),
 			expected: strings.Repeat("a", 47) + "...",
 		},
+		{
+			name:     "Multi-line query",
+			query:    "SELECT id,\n       name\nFROM users;",
+			expected: "SELECT id, name FROM users;",
+		},
 	}
 
 	for _, tc := range testCases {
@@ -404,6 +381,86 @@ func TestApp_generateTableMarkdown(t *testing.T) {
 	}
 }
 
+func TestApp_generateProcessListMarkdown(t *testing.T) {
+	app := createTestApp(t)
+
+	processes := []core.ProcessInfo{
+		{
+			ID:       42,
+			User:     "app",
+			Database: "shop",
+			State:    "Query (Waiting for table metadata lock)",
+			Duration: 90 * time.Second,
+			Query:    "SELECT a | b\nFROM orders",
+		},
+		{
+			ID:       7,
+			User:     "root",
+			Database: "shop",
+			State:    "Sleep",
+		},
+	}
+
+	markdown := app.generateProcessListMarkdown(processes)
+
+	if !strings.Contains(markdown, "| ID | User | Database | State | Duration | Query |") {
+		t.Error("Markdown should contain process table header")
+	}
+
+	if !strings.Contains(markdown, "| 42 | app | shop | Query (Waiting for table metadata lock) | 1m30s | `SELECT a \\| b FROM orders` |") {
+		t.Errorf("Markdown should contain escaped single-line query row, got:\n%s", markdown)
+	}
+
+	if !strings.Contains(markdown, "| 7 | root | shop | Sleep | 0s |  |") {
+		t.Error("Markdown should contain idle session row with empty query")
+	}
+}
+
+func TestApp_handleKillProcess_NoConnection(t *testing.T) {
+	app := createTestApp(t)
+
+	if err := app.handleKillProcess([]string{"42"}); err != nil {
+		t.Errorf("handleKillProcess() should not return error without connection, got: %v", err)
+	}
+
+	if err := app.handleKillProcess([]string{}); err != nil {
+		t.Errorf("handleKillProcess() should not return error with no args, got: %v", err)
+	}
+}
+
+func TestApp_recordError(t *testing.T) {
+	app := createTestApp(t)
+
+	for i := 0; i < maxErrorHistory+5; i++ {
+		app.recordError(fmt.Sprintf("SELECT %d", i), fmt.Errorf("error %d", i))
+	}
+
+	if len(app.errorHistory) != maxErrorHistory {
+		t.Fatalf("Expected error history to be capped at %d, got %d", maxErrorHistory, len(app.errorHistory))
+	}
+
+	if app.errorHistory[0].Command != "SELECT 5" {
+		t.Errorf("Expected oldest entries to be dropped first, got '%s'", app.errorHistory[0].Command)
+	}
+
+	last := app.errorHistory[len(app.errorHistory)-1]
+	if last.Err.Error() != fmt.Sprintf("error %d", maxErrorHistory+4) {
+		t.Errorf("Expected newest error last, got '%v'", last.Err)
+	}
+
+	if err := app.handleErrors([]string{"3"}); err != nil {
+		t.Errorf("handleErrors() failed: %v", err)
+	}
+
+	if err := app.handleErrors([]string{"clear"}); err != nil {
+		t.Errorf("handleErrors(clear) failed: %v", err)
+	}
+
+	if len(app.errorHistory) != 0 {
+		t.Error("Error history should be empty after clear")
+	}
+}
+
 func TestApp_handleConnect_WithArgs(t *testing.T) {
 	app := createTestApp(t)
 
@@ -453,12 +510,7 @@ func TestApp_handleListTables_WithConnection(t *testing.T) {
 	// Disable AI manager to avoid vector database initialization issues
 	app.aiManager = nil
 
-	mockConn := &mockConnection{
-		tables:    []string{"users", "posts", "comments"},
-		connected: true,
-		dbType:    core.PostgreSQL,
-		name:      "test-db",
-	}
+	mockConn := newMockTablesConnection("users", "posts", "comments")
 
 	config := &core.ConnectionConfig{
 		Name: "test-db",
@@ -485,12 +537,7 @@ func TestApp_handleDescribeTable(t *testing.T) {
 	// Disable AI manager to avoid vector database initialization issues
 	app.aiManager = nil
 
-	mockConn := &mockConnection{
-		tables:    []string{"users", "posts"},
-		connected: true,
-		dbType:    core.PostgreSQL,
-		name:      "test-db",
-	}
+	mockConn := newMockTablesConnection("users", "posts")
 
 	config := &core.ConnectionConfig{
 		Name: "test-db",
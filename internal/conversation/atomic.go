@@ -0,0 +1,100 @@
+package conversation
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"strings"
+
+	"sqlterm/internal/core"
+)
+
+// runAtomic runs queries in one transaction, listing each as it completes.
+// A failing statement rolls back the ones before it; results are counted,
+// not shown. Statements that would end the transaction early are listed
+// first and the run goes ahead only once confirmed.
+func (a *App) runAtomic(queries []string) error {
+	var statements []string
+	for _, query := range queries {
+		if query = strings.TrimSpace(query); query != "" {
+			statements = append(statements, query)
+		}
+	}
+	if len(statements) == 0 {
+		return nil
+	}
+
+	for i, statement := range statements {
+		expanded, err := a.expandPins(statement)
+		if err != nil {
+			return err
+		}
+		statements[i] = expanded
+	}
+	script := strings.Join(statements, ";\n")
+	if err := a.checkReadOnly(script); err != nil {
+		return err
+	}
+	if !a.confirmTransactionBreakers(statements) || !a.allowDestructive(script) {
+		return nil
+	}
+
+	ctx, stop := a.interruptContext(context.Background())
+	defer stop()
+
+	fmt.Printf(a.i18nMgr.Get("atomic_begin"), len(statements))
+	err := core.RunInTransaction(ctx, a.connection, statements, func(i int, result *core.QueryResult) error {
+		rows := 0
+		for range result.Itor() {
+			rows++
+		}
+		result.Close()
+		if err := result.Error(); err != nil {
+			return err
+		}
+		fmt.Printf(a.i18nMgr.Get("atomic_statement_done"), i+1, len(statements), a.truncateQuery(statements[i]), rows)
+		return nil
+	})
+	a.schema().InvalidateAfter(a.dbType(), script)
+
+	var statementErr *core.StatementError
+	switch {
+	case ctx.Err() != nil:
+		fmt.Println(a.i18nMgr.Get("atomic_cancelled"))
+		return nil
+	case errors.As(err, &statementErr):
+		fmt.Printf(a.i18nMgr.Get("atomic_rolled_back"), statementErr.Index, len(statements), a.truncateQuery(statementErr.Query))
+		return core.WithErrorPointer(statementErr.Err, statementErr.Query)
+	case errors.Is(err, core.ErrTransactionsNotSupported):
+		fmt.Println(a.i18nMgr.Get("transactions_not_supported"))
+		return nil
+	case err != nil:
+		return err
+	}
+	a.notify(NotifySuccess, a.i18nMgr.Get("atomic_committed"), len(statements))
+	return nil
+}
+
+// confirmTransactionBreakers lists the statements that commit or refuse to
+// run inside a transaction on this connection, and asks whether to go on
+func (a *App) confirmTransactionBreakers(statements []string) bool {
+	var breakers []int
+	for i, statement := range statements {
+		if core.BreaksTransaction(a.config.DatabaseType, statement) {
+			breakers = append(breakers, i)
+		}
+	}
+	if len(breakers) == 0 {
+		return true
+	}
+
+	fmt.Print(a.i18nMgr.Get("atomic_breakers_warning"))
+	for _, i := range breakers {
+		fmt.Printf("  %d. %s\n", i+1, a.truncateQuery(statements[i]))
+	}
+	if a.confirm(a.i18nMgr.Get("confirm_atomic_breakers")) {
+		return true
+	}
+	a.notify(NotifyInfo, a.i18nMgr.Get("atomic_not_run"))
+	return false
+}
@@ -0,0 +1,76 @@
+package conversation
+
+import (
+	"fmt"
+
+	"sqlterm/internal/core"
+)
+
+// handleAttach attaches another SQLite database file to the connection: /attach <path> [alias]
+func (a *App) handleAttach(args []string) error {
+	if !a.canAttach() {
+		return nil
+	}
+	if len(args) == 0 || len(args) > 2 {
+		fmt.Println(a.i18nMgr.Get("usage_attach"))
+		return nil
+	}
+
+	alias := core.AttachAlias(args[0])
+	if len(args) == 2 {
+		alias = args[1]
+	}
+	if err := a.execSchemaStatement(core.AttachStatement(args[0], alias)); err != nil {
+		return err
+	}
+	a.notify(NotifySuccess, a.i18nMgr.Get("database_attached"), core.SQLitePath(args[0]), alias)
+	return nil
+}
+
+// handleDetach detaches a database attached with /attach: /detach <alias>
+func (a *App) handleDetach(args []string) error {
+	if !a.canAttach() {
+		return nil
+	}
+	if len(args) != 1 {
+		fmt.Println(a.i18nMgr.Get("usage_detach"))
+		return nil
+	}
+
+	if err := a.execSchemaStatement(core.DetachStatement(args[0])); err != nil {
+		return err
+	}
+	a.notify(NotifySuccess, a.i18nMgr.Get("database_detached"), args[0])
+	return nil
+}
+
+// canAttach reports whether the connection is SQLite, explaining why not otherwise
+func (a *App) canAttach() bool {
+	if a.connection == nil {
+		fmt.Println(a.i18nMgr.Get("no_database_connection"))
+		return false
+	}
+	if a.config.DatabaseType != core.SQLite {
+		fmt.Println(a.i18nMgr.Get("attach_sqlite_only"))
+		return false
+	}
+	return true
+}
+
+// execSchemaStatement runs a statement that changes which tables are visible
+// and drops the cached table list so listings and completion pick it up
+func (a *App) execSchemaStatement(statement string) error {
+	result, err := a.connection.Execute(statement)
+	if err != nil {
+		return err
+	}
+	for range result.Itor() {
+	}
+	result.Close()
+	if err := result.Error(); err != nil {
+		return err
+	}
+
+	a.schema().Invalidate()
+	return nil
+}
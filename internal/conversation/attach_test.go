@@ -0,0 +1,37 @@
+package conversation
+
+import (
+	"slices"
+	"testing"
+
+	"sqlterm/internal/core"
+	"sqlterm/internal/core/coretest"
+)
+
+func TestApp_handleAttach(t *testing.T) {
+	app := createTestApp(t)
+	attach := core.AttachStatement("/data/sales-2024.db", "sales_2024")
+	conn := coretest.NewMockConnection().
+		AddResponse(attach, nil, nil).
+		AddResponse(core.DetachStatement("sales_2024"), nil, nil)
+	app.connection = conn
+	app.config = &core.ConnectionConfig{Name: "local", DatabaseType: core.SQLite}
+
+	if err := app.handleAttach([]string{"/data/sales-2024.db"}); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if err := app.handleDetach([]string{"sales_2024"}); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if queries := conn.ExecutedQueries(); !slices.Equal(queries, []string{attach, core.DetachStatement("sales_2024")}) {
+		t.Errorf("Expected ATTACH with the alias from the file name, then DETACH, got %v", queries)
+	}
+
+	app.config.DatabaseType = core.MySQL
+	if err := app.handleAttach([]string{"other.db"}); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if len(conn.ExecutedQueries()) != 2 {
+		t.Error("Expected nothing to run on a connection that is not SQLite")
+	}
+}
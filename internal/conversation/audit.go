@@ -0,0 +1,78 @@
+package conversation
+
+import (
+	"fmt"
+	"strconv"
+
+	"sqlterm/internal/core"
+)
+
+// defaultAuditTail is how many entries /audit tail shows without a count
+const defaultAuditTail = 20
+
+// auditConnection wraps conn in an audit log when the connection opts in with audit_log
+func (a *App) auditConnection(conn core.Connection, config *core.ConnectionConfig) core.Connection {
+	if !config.AuditLog {
+		return conn
+	}
+
+	log := core.NewAuditLog(a.configMgr.AuditLogPath(config), core.DefaultAuditLogMaxSize)
+	audited := core.NewAuditedConnection(conn, config, log)
+	audited.Redact = a.redactHistory()
+	audited.OnError = func(err error) {
+		a.notify(NotifyWarning, a.i18nMgr.Get("audit_write_failed"), err)
+	}
+	fmt.Printf(a.i18nMgr.Get("audit_enabled"), log.Path())
+	return audited
+}
+
+// handleAudit shows the last entries of the current connection's audit log: /audit tail [count]
+func (a *App) handleAudit(args []string) error {
+	audited, ok := a.connection.(*core.AuditedConnection)
+	if !ok {
+		fmt.Println(a.i18nMgr.Get("audit_not_enabled"))
+		return nil
+	}
+
+	if len(args) > 0 && args[0] == "tail" {
+		args = args[1:]
+	}
+	count := defaultAuditTail
+	if len(args) > 0 {
+		parsed, err := strconv.Atoi(args[0])
+		if err != nil || parsed <= 0 {
+			return fmt.Errorf(a.i18nMgr.Get("audit_invalid_count"), args[0])
+		}
+		count = parsed
+	}
+
+	entries, err := audited.Log().Tail(count)
+	if err != nil {
+		return fmt.Errorf(a.i18nMgr.Get("audit_read_failed"), err)
+	}
+	if len(entries) == 0 {
+		fmt.Println(a.i18nMgr.Get("audit_empty"))
+		return nil
+	}
+
+	fmt.Printf(a.i18nMgr.Get("audit_header"), len(entries), audited.Log().Path())
+	for _, entry := range entries {
+		status := "✅"
+		if !entry.Success() {
+			status = "❌"
+		}
+		fmt.Printf("  %s %s %s@%s %dms rows=%d [%s] %s\n",
+			status,
+			entry.Time.Local().Format("2006-01-02 15:04:05"),
+			entry.User,
+			entry.Connection,
+			entry.DurationMS,
+			entry.Rows,
+			entry.Hash,
+			a.truncateQuery(entry.Statement))
+		if !entry.Success() {
+			fmt.Printf("      %s\n", entry.Error)
+		}
+	}
+	return nil
+}
@@ -4,10 +4,19 @@ import (
 	"os"
 	"path/filepath"
 	"strings"
+
+	"sqlterm/internal/core"
+	"sqlterm/internal/utils"
 )
 
 type AutoCompleter struct {
-	app *App
+	app   *App
+	files completionCache
+
+	// setLine replaces the line being edited; nil uses the readline buffer
+	setLine func(string)
+	// args are the arguments typed before the one being completed
+	args []string
 }
 
 func NewAutoCompleter(app *App) *AutoCompleter {
@@ -15,6 +24,14 @@ func NewAutoCompleter(app *App) *AutoCompleter {
 }
 
 func (ac *AutoCompleter) Do(line []rune, pos int) (newLine [][]rune, length int) {
+	// Do runs on readline's goroutine while the terminal is in raw mode, where a panic
+	// would kill the process and leave the shell garbled. Offer no completions instead.
+	defer func() {
+		if r := recover(); r != nil {
+			newLine, length = nil, 0
+		}
+	}()
+
 	lineStr := string(line)
 	words := strings.Fields(lineStr)
 
@@ -27,12 +44,6 @@ func (ac *AutoCompleter) Do(line []rune, pos int) (newLine [][]rune, length int)
 	var completionLength int
 
 	switch {
-	case strings.HasPrefix(lineStr, "/connect ") && len(words) > 1:
-		candidates = ac.getConnectionCandidates(words, lineStr)
-		completionLength = ac.getCompletionLength(lineStr)
-	case strings.HasPrefix(lineStr, "/describe ") && len(words) > 1:
-		candidates = ac.getTableCandidates(words, lineStr)
-		completionLength = ac.getCompletionLength(lineStr)
 	case strings.HasPrefix(lineStr, "/config "):
 		candidates = ac.getConfigCandidates(words, lineStr)
 		completionLength = ac.getCompletionLength(lineStr)
@@ -45,10 +56,13 @@ func (ac *AutoCompleter) Do(line []rune, pos int) (newLine [][]rune, length int)
 	case strings.Contains(lineStr, " > ") && !strings.HasPrefix(lineStr, "/"):
 		candidates = ac.getCSVCandidates(words, lineStr)
 		completionLength = ac.getCompletionLength(lineStr)
-	case len(words) == 1 && strings.HasPrefix(words[0], "/"):
+	case len(words) == 1 && strings.HasPrefix(words[0], "/") && !strings.HasSuffix(lineStr, " "):
 		// Command completion for partial commands like /co -> /connect
 		candidates = ac.getCommandCandidates(words[0])
 		completionLength = len(words[0])
+	case strings.HasPrefix(lineStr, "/"):
+		// Arguments complete from the command's spec, see commands
+		candidates, completionLength = ac.getArgumentCandidates(words, lineStr)
 	default:
 		return nil, 0
 	}
@@ -57,14 +71,8 @@ func (ac *AutoCompleter) Do(line []rune, pos int) (newLine [][]rune, length int)
 }
 
 func (ac *AutoCompleter) getCommands() [][]rune {
-	commands := []string{
-		"/help", "/quit", "/exit", "/connect", "/list-connections",
-		"/tables", "/describe", "/status", "/exec", "/config",
-		"/prompts", "/clear-conversation",
-	}
-
-	result := make([][]rune, len(commands))
-	for i, cmd := range commands {
+	result := make([][]rune, len(commandNames))
+	for i, cmd := range commandNames {
 		result[i] = []rune(cmd)
 	}
 	return result
@@ -140,14 +148,8 @@ func (ac *AutoCompleter) findCommonPrefix(candidates []string) string {
 
 // New candidate-getting functions that return full matches for intelligent processing
 func (ac *AutoCompleter) getCommandCandidates(partial string) []string {
-	commands := []string{
-		"/help", "/quit", "/exit", "/connect", "/list-connections",
-		"/tables", "/describe", "/status", "/exec", "/config",
-		"/prompts", "/clear-conversation",
-	}
-
 	var candidates []string
-	for _, cmd := range commands {
+	for _, cmd := range commandNames {
 		if strings.HasPrefix(cmd, partial) {
 			// Return the completion part (what should be appended)
 			completion := cmd[len(partial):]
@@ -158,64 +160,87 @@ func (ac *AutoCompleter) getCommandCandidates(partial string) []string {
 	return candidates
 }
 
-func (ac *AutoCompleter) getConnectionCandidates(words []string, line string) []string {
-	if len(words) < 2 {
-		return nil
-	}
-
-	connections, err := ac.app.configMgr.ListConnections()
-	if err != nil {
+// completeName completes word, the last word of line, from names. Readline can
+// only append to the line, so names that start with word exactly are offered
+// as the rest of the name. When none do, the word is replaced by the best
+// case-insensitive, substring or fuzzy match instead.
+func (ac *AutoCompleter) completeName(line, word string, names []string) []string {
+	matches := utils.RankMatches(word, names)
+	if len(matches) == 0 {
 		return nil
 	}
 
 	var candidates []string
-	currentWord := ""
-	if len(words) > 1 {
-		currentWord = words[len(words)-1]
-	}
-
-	for _, conn := range connections {
-		if strings.HasPrefix(conn.Name, currentWord) {
-			// Return the completion part
-			completion := conn.Name[len(currentWord):]
-			candidates = append(candidates, completion)
+	for _, match := range matches {
+		if strings.HasPrefix(match.Text, word) {
+			candidates = append(candidates, match.Text[len(word):])
 		}
 	}
-
+	if len(candidates) == 0 && strings.HasSuffix(line, word) {
+		ac.replaceLine(line[:len(line)-len(word)] + matches[0].Text)
+	}
 	return candidates
 }
 
-func (ac *AutoCompleter) getTableCandidates(words []string, line string) []string {
-	if len(words) < 2 || ac.app.connection == nil {
-		return nil
+// replaceLine swaps the line being edited for one readline could not reach by appending
+func (ac *AutoCompleter) replaceLine(line string) {
+	if ac.setLine != nil {
+		ac.setLine(line)
+		return
+	}
+	if ac.app.rl != nil {
+		ac.app.rl.Operation.SetBuffer(line)
 	}
+}
 
-	tables, err := ac.app.connection.ListTables()
-	if err != nil {
-		return nil
+func (ac *AutoCompleter) getFileCandidates(line string) []string {
+	// Remove the @ prefix
+	path := strings.TrimPrefix(line, "@")
+
+	// Recently executed files come first, wherever they live
+	candidates := ac.getRecentFileCandidates(path)
+	seen := make(map[string]bool, len(candidates))
+	for _, candidate := range candidates {
+		seen[candidate] = true
 	}
 
-	var candidates []string
-	currentWord := ""
-	if len(words) > 1 {
-		currentWord = words[len(words)-1]
+	if strings.HasPrefix(path, projectRootPrefix) {
+		return append(candidates, ac.listFiles(path, ac.getProjectFileCandidates)...)
 	}
 
-	for _, table := range tables {
-		if strings.HasPrefix(table, currentWord) {
-			// Return the completion part
-			completion := table[len(currentWord):]
-			candidates = append(candidates, completion)
+	for _, candidate := range ac.listFiles(path, func(path string) []string {
+		return append(ac.getPathCandidates(path), ac.getProjectFileCandidates(path)...)
+	}) {
+		if !seen[candidate] {
+			seen[candidate] = true
+			candidates = append(candidates, candidate)
 		}
 	}
-
 	return candidates
 }
 
-func (ac *AutoCompleter) getFileCandidates(line string) []string {
-	// Remove the @ prefix
-	path := strings.TrimPrefix(line, "@")
+// listFiles runs a directory walk for path off the prompt goroutine; see completionCache
+func (ac *AutoCompleter) listFiles(path string, walk func(string) []string) []string {
+	wd, _ := os.Getwd()
+	return ac.files.complete(wd+"\x00"+path, func() []string {
+		return walk(path)
+	})
+}
+
+// getRecentFileCandidates offers recently executed files that start with the typed path
+func (ac *AutoCompleter) getRecentFileCandidates(path string) []string {
+	var candidates []string
+	for _, file := range ac.app.recentFiles.list() {
+		display := displayPath(file)
+		if strings.HasPrefix(display, path) {
+			candidates = append(candidates, display[len(path):])
+		}
+	}
+	return candidates
+}
 
+// getPathCandidates lists .sql files and directories matching the typed path
+func (ac *AutoCompleter) getPathCandidates(path string) []string {
 	var candidates []string
 
 	// If path is empty, show all .sql files and directories from current directory
@@ -224,12 +249,9 @@ func (ac *AutoCompleter) getFileCandidates(line string) []string {
 		return candidates
 	}
 
-	// If path ends with /, it's a directory - show its contents
-	if strings.HasSuffix(path, "/") {
-		dir := strings.TrimSuffix(path, "/")
-		if dir == "" {
-			dir = "."
-		}
+	// If path ends with a separator, it's a directory - show its contents
+	if hasTrailingSeparator(path) {
+		dir := filepath.Clean(path)
 		ac.addFileCandidates(&candidates, dir, "", path)
 		return candidates
 	}
@@ -244,7 +266,7 @@ func (ac *AutoCompleter) getFileCandidates(line string) []string {
 
 	// If path contains directory, search in that specific directory
 	if dir != "" {
-		ac.addFileCandidates(&candidates, dir, baseName, dir+"/")
+		ac.addFileCandidates(&candidates, dir, baseName, dir+pathSep)
 	} else {
 		// Search recursively from current directory
 		ac.addRecursiveFileCandidates(&candidates, ".", baseName, "")
@@ -269,7 +291,7 @@ func (ac *AutoCompleter) getCSVCandidates(words []string, line string) []string
 	}
 
 	baseName := filepath.Base(filename)
-	if filename == "" || strings.HasSuffix(filename, "/") {
+	if filename == "" || hasTrailingSeparator(filename) {
 		baseName = ""
 	}
 
@@ -280,7 +302,7 @@ func (ac *AutoCompleter) getCSVCandidates(words []string, line string) []string
 
 	// If filename contains directory, search in that directory
 	if dir != "" && dir != "." {
-		ac.addCSVCandidates(&candidates, dir, baseName, dir+"/")
+		ac.addCSVCandidates(&candidates, dir, baseName, dir+pathSep)
 	}
 
 	return candidates
@@ -306,7 +328,7 @@ func (ac *AutoCompleter) shouldSkipDirectory(name string) bool {
 
 // New candidate-based helper functions for intelligent completion
 func (ac *AutoCompleter) addFileCandidates(candidates *[]string, dir, baseName, prefix string) {
-	entries, err := os.ReadDir(dir)
+	entries, err := os.ReadDir(core.ExpandPath(dir))
 	if err != nil {
 		return
 	}
@@ -325,7 +347,7 @@ func (ac *AutoCompleter) addFileCandidates(candidates *[]string, dir, baseName,
 
 			// Only suggest .sql files and directories
 			if entry.IsDir() {
-				*candidates = append(*candidates, completion+"/")
+				*candidates = append(*candidates, completion+pathSep)
 			} else if strings.HasSuffix(name, ".sql") {
 				*candidates = append(*candidates, completion)
 			}
@@ -365,7 +387,7 @@ func (ac *AutoCompleter) addRecursiveFileCandidates(candidates *[]string, dir, b
 		subDir := filepath.Join(dir, name)
 
 		// Find all matching files in subdirectory
-		ac.addRecursiveFileCandidateMatches(candidates, subDir, baseName, name+"/")
+		ac.addRecursiveFileCandidateMatches(candidates, subDir, baseName, name+pathSep)
 	}
 }
 
@@ -392,7 +414,7 @@ func (ac *AutoCompleter) addRecursiveFileCandidateMatches(candidates *[]string,
 			}
 
 			if entry.IsDir() {
-				*candidates = append(*candidates, fullCompletion+"/")
+				*candidates = append(*candidates, fullCompletion+pathSep)
 			} else if strings.HasSuffix(name, ".sql") {
 				*candidates = append(*candidates, fullCompletion)
 			}
@@ -401,13 +423,13 @@ func (ac *AutoCompleter) addRecursiveFileCandidateMatches(candidates *[]string,
 		// Continue searching subdirectories
 		if entry.IsDir() && !ac.shouldSkipDirectory(name) {
 			subDir := filepath.Join(dir, name)
-			ac.addRecursiveFileCandidateMatches(candidates, subDir, baseName, pathPrefix+name+"/")
+			ac.addRecursiveFileCandidateMatches(candidates, subDir, baseName, pathPrefix+name+pathSep)
 		}
 	}
 }
 
 func (ac *AutoCompleter) addCSVCandidates(candidates *[]string, dir, baseName, prefix string) {
-	entries, err := os.ReadDir(dir)
+	entries, err := os.ReadDir(core.ExpandPath(dir))
 	if err != nil {
 		return
 	}
@@ -419,7 +441,7 @@ func (ac *AutoCompleter) addCSVCandidates(candidates *[]string, dir, baseName, p
 			completion := name[len(baseName):]
 
 			if entry.IsDir() {
-				*candidates = append(*candidates, completion+"/")
+				*candidates = append(*candidates, completion+pathSep)
 			} else {
 				*candidates = append(*candidates, completion)
 			}
@@ -462,7 +484,7 @@ func (ac *AutoCompleter) getConfigCandidates(words []string, line string) []stri
 
 	// Main config sections
 	if len(words) == 2 {
-		sections := []string{"ai", "language"}
+		sections := []string{"ai", "language", "display"}
 		var candidates []string
 		currentWord := words[1]
 		for _, section := range sections {
@@ -552,6 +574,43 @@ func (ac *AutoCompleter) getConfigCandidates(words []string, line string) []stri
 			}
 			return candidates
 		}
+	case "display":
+		var options []string
+		switch len(words) {
+		case 3:
+			options = []string{"status", "timezone", "datetime-format", "thousands", "float-precision", "export", "cell-width", "cell-length", "sort", "sort-numeric", "server-timing", "export-metadata", "hyperlinks"}
+		case 4:
+			switch words[2] {
+			case "timezone":
+				options = []string{"utc", "local"}
+			case "datetime-format":
+				options = []string{"default", "iso", "rfc1123", "us", "eu"}
+			case "thousands":
+				options = []string{"on", "off"}
+			case "float-precision":
+				options = []string{"auto"}
+			case "export":
+				options = []string{"iso", "formatted"}
+			case "cell-width":
+				options = []string{"off"}
+			case "cell-length":
+				options = []string{"on", "off"}
+			case "sort":
+				options = []string{"unicode", "bytes"}
+			case "sort-numeric", "server-timing", "export-metadata":
+				options = []string{"on", "off"}
+			case "hyperlinks":
+				options = []string{"auto", "on", "off"}
+			}
+		}
+		var candidates []string
+		currentWord := words[len(words)-1]
+		for _, option := range options {
+			if strings.HasPrefix(option, currentWord) {
+				candidates = append(candidates, option[len(currentWord):])
+			}
+		}
+		return candidates
 	}
 
 	return nil
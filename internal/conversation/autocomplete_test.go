@@ -1,11 +1,14 @@
 package conversation
 
 import (
+	"errors"
 	"os"
 	"path/filepath"
+	"strings"
 	"testing"
 
 	"sqlterm/internal/core"
+	"sqlterm/internal/core/coretest"
 )
 
 func TestAutoCompleter_NewAutoCompleter(t *testing.T) {
@@ -69,12 +72,12 @@ func TestAutoCompleter_getCommandCandidates(t *testing.T) {
 		{
 			name:     "Connect command prefix",
 			partial:  "/con",
-			expected: []string{"nect", "fig"},
+			expected: []string{"nect", "nect-template", "fig"},
 		},
 		{
 			name:     "Multiple matches",
 			partial:  "/",
-			expected: []string{"help", "quit", "exit", "connect", "list-connections", "tables", "describe", "status", "exec", "config", "prompts", "clear-conversation"},
+			expected: []string{"help", "quit", "exit", "connect", "connect-template", "list-connections", "reload-connections", "reconnect", "tables", "describe", "count", "status", "exec", "config", "prompts", "clear", "clear-conversation", "redraw", "processlist", "kill", "errors", "diag", "refresh", "more", "edit", "follow", "where", "back", "row", "buffer", "editor", "attach", "detach", "audit", "tasks", "columns", "pin", "pins", "unpin", "check", "copy", "copy-query", "paste", "sample", "palette", "nolimit", "functions", "triggers", "sort", "show", "sql", "rerun", "gexec", "keys"},
 		},
 		{
 			name:     "No matches",
@@ -112,37 +115,31 @@ func TestAutoCompleter_getConfigCandidates(t *testing.T) {
 
 	testCases := []struct {
 		name     string
-		words    []string
 		line     string
 		expected []string
 	}{
 		{
 			name:     "Main config sections",
-			words:    []string{"/config", "a"},
 			line:     "/config a",
 			expected: []string{"i"},
 		},
 		{
 			name:     "AI subcommands",
-			words:    []string{"/config", "ai", "p"},
 			line:     "/config ai p",
 			expected: []string{"rovider"},
 		},
 		{
 			name:     "AI provider candidates",
-			words:    []string{"/config", "ai", "provider", "o"},
 			line:     "/config ai provider o",
 			expected: []string{"penrouter", "llama"},
 		},
 		{
 			name:     "Language candidates",
-			words:    []string{"/config", "language", "e"},
 			line:     "/config language e",
 			expected: []string{"n_au"},
 		},
 		{
 			name:     "No matches",
-			words:    []string{"/config", "invalid"},
 			line:     "/config invalid",
 			expected: []string{},
 		},
@@ -166,23 +163,18 @@ func TestAutoCompleter_getConfigCandidates(t *testing.T) {
 	}
 }
 
-func TestAutoCompleter_getTableCandidates(t *testing.T) {
+func TestAutoCompleter_getArgumentCandidates_Tables(t *testing.T) {
 	app := createTestApp(t)
 	ac := NewAutoCompleter(app)
 
 	// Test without connection
-	candidates := ac.getTableCandidates([]string{"/describe", "u"}, "/describe u")
+	candidates, _ := ac.getArgumentCandidates([]string{"/describe", "u"}, "/describe u")
 	if len(candidates) != 0 {
 		t.Error("Should return no candidates without connection")
 	}
 
 	// Test with connection
-	mockConn := &mockConnection{
-		tables:    []string{"users", "user_profiles", "posts"},
-		connected: true,
-		dbType:    core.PostgreSQL,
-		name:      "test-db",
-	}
+	mockConn := newMockTablesConnection("users", "user_profiles", "posts")
 
 	config := &core.ConnectionConfig{
 		Name: "test-db",
@@ -195,33 +187,29 @@ func TestAutoCompleter_getTableCandidates(t *testing.T) {
 
 	testCases := []struct {
 		name     string
-		words    []string
 		line     string
 		expected []string
 	}{
 		{
 			name:     "Partial table name",
-			words:    []string{"/describe", "u"},
 			line:     "/describe u",
-			expected: []string{"sers", "ser_profiles"},
+			expected: []string{"ser_profiles", "sers"},
 		},
 		{
 			name:     "No matches",
-			words:    []string{"/describe", "xyz"},
 			line:     "/describe xyz",
 			expected: []string{},
 		},
 		{
 			name:     "Empty current word",
-			words:    []string{"/describe", ""},
 			line:     "/describe ",
-			expected: []string{"users", "user_profiles", "posts"},
+			expected: []string{"posts", "user_profiles", "users"},
 		},
 	}
 
 	for _, tc := range testCases {
 		t.Run(tc.name, func(t *testing.T) {
-			candidates := ac.getTableCandidates(tc.words, tc.line)
+			candidates, _ := ac.getArgumentCandidates(strings.Fields(tc.line), tc.line)
 
 			if len(candidates) != len(tc.expected) {
 				t.Errorf("Expected %d candidates, got %d", len(tc.expected), len(candidates))
@@ -237,6 +225,25 @@ func TestAutoCompleter_getTableCandidates(t *testing.T) {
 	}
 }
 
+func TestAutoCompleter_TableCandidatesAreCached(t *testing.T) {
+	app := createTestApp(t)
+	ac := NewAutoCompleter(app)
+	conn := newMockUsersConnection()
+	app.connection = conn
+
+	if candidates, _ := ac.getArgumentCandidates([]string{"/describe", "u"}, "/describe u"); len(candidates) != 1 {
+		t.Fatalf("Expected one candidate, got %v", candidates)
+	}
+
+	// Later keystrokes must not go back to the server
+	conn.FailMethod("ListTables", errors.New("server should not be queried"))
+	for i := 0; i < 100; i++ {
+		if candidates, _ := ac.getArgumentCandidates([]string{"/describe", "o"}, "/describe o"); len(candidates) != 1 {
+			t.Fatalf("Expected cached candidates on keystroke %d, got %v", i, candidates)
+		}
+	}
+}
+
 func TestAutoCompleter_getFileCandidates(t *testing.T) {
 	app := createTestApp(t)
 	ac := NewAutoCompleter(app)
@@ -332,25 +339,21 @@ func TestAutoCompleter_getCSVCandidates(t *testing.T) {
 
 	testCases := []struct {
 		name     string
-		words    []string
 		line     string
 		expected []string
 	}{
 		{
 			name:     "CSV files with partial name",
-			words:    []string{"SELECT", "*", "FROM", "users", ">", "o"},
 			line:     "SELECT * FROM users > o",
 			expected: []string{"utput.csv", ".csv"},
 		},
 		{
 			name:     "All files with empty name",
-			words:    []string{"SELECT", "*", "FROM", "users", ">", ""},
 			line:     "SELECT * FROM users > ",
 			expected: []string{"data.csv", "export.xlsx", "output.csv", "results.txt"},
 		},
 		{
 			name:     "No matches",
-			words:    []string{"SELECT", "*", "FROM", "users", ">", "xyz"},
 			line:     "SELECT * FROM users > xyz",
 			expected: []string{".csv"},
 		},
@@ -609,7 +612,7 @@ func TestAutoCompleter_Do(t *testing.T) {
 			name:        "Empty line",
 			line:        "",
 			pos:         0,
-			expectCount: 12, // Number of commands
+			expectCount: len(commandNames),
 		},
 		{
 			name:        "Command completion",
@@ -648,6 +651,27 @@ func TestAutoCompleter_Do(t *testing.T) {
 	}
 }
 
+type panickingConnection struct {
+	*coretest.MockConnection
+}
+
+func (p *panickingConnection) ListTables() ([]string, error) {
+	panic("driver exploded")
+}
+
+func TestAutoCompleter_Do_RecoversFromPanic(t *testing.T) {
+	app := createTestApp(t)
+	app.connection = &panickingConnection{coretest.NewMockConnection()}
+	ac := NewAutoCompleter(app)
+
+	line := []rune("/describe us")
+	newLine, length := ac.Do(line, len(line))
+
+	if newLine != nil || length != 0 {
+		t.Errorf("Expected no completions after panic, got %v (length %d)", newLine, length)
+	}
+}
+
 // Benchmark tests
 func BenchmarkAutoCompleter_getCommandCandidates(b *testing.B) {
 	app := createTestApp(&testing.T{})
@@ -682,3 +706,38 @@ func BenchmarkAutoCompleter_processCompletions(b *testing.B) {
 		ac.processCompletions(candidates, 2)
 	}
 }
+
+func TestAutoCompleter_completeName(t *testing.T) {
+	app := createTestApp(t)
+	ac := NewAutoCompleter(app)
+	var replaced string
+	ac.setLine = func(line string) { replaced = line }
+	names := []string{"ProdDB", "staging", "tbl_users", "app_users"}
+
+	testCases := []struct {
+		name       string
+		line       string
+		word       string
+		candidates []string
+		replaced   string
+	}{
+		{name: "Prefix appends", line: "/connect sta", word: "sta", candidates: []string{"ging"}},
+		{name: "Different case replaces", line: "/connect proDB", word: "proDB", replaced: "/connect ProdDB"},
+		{name: "Substring replaces with the best match", line: "/describe users", word: "users", replaced: "/describe tbl_users"},
+		{name: "Fuzzy replaces", line: "/connect stg", word: "stg", replaced: "/connect staging"},
+		{name: "No match", line: "/connect xyz", word: "xyz"},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.name, func(t *testing.T) {
+			replaced = ""
+			candidates := ac.completeName(tc.line, tc.word, names)
+			if strings.Join(candidates, ",") != strings.Join(tc.candidates, ",") {
+				t.Errorf("Expected candidates %v, got %v", tc.candidates, candidates)
+			}
+			if replaced != tc.replaced {
+				t.Errorf("Expected the line to become %q, got %q", tc.replaced, replaced)
+			}
+		})
+	}
+}
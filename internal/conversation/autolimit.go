@@ -0,0 +1,51 @@
+package conversation
+
+import (
+	"fmt"
+
+	"sqlterm/internal/core"
+)
+
+// autoLimit returns the auto_limit row cap from the config file, or 0 when it is off
+func (a *App) autoLimit() int {
+	if a.aiManager == nil {
+		return 0
+	}
+	return max(a.aiManager.GetConfig().AutoLimit, 0)
+}
+
+// executeTyped runs a query entered at the prompt or from a buffer. These are
+// the queries auto_limit guards; @file runs, exports and the exec subcommand
+// send queries exactly as written.
+func (a *App) executeTyped(query string) error {
+	limit := a.autoLimit()
+	limited, ok := core.AutoLimit(a.dbType(), query, limit)
+	if !ok {
+		return a.executeAndView(query)
+	}
+
+	before := a.lastStored
+	if err := a.executeAndView(limited); err != nil {
+		return err
+	}
+	// Fewer rows than the limit means the limit cut nothing off
+	if a.lastStored == nil || a.lastStored == before || len(a.lastStored.Rows) < limit {
+		return nil
+	}
+	a.unlimitedQuery = query
+	fmt.Printf(a.i18nMgr.Get("auto_limited"), limit)
+	return nil
+}
+
+// handleNoLimit runs the last auto-limited query again as written: /nolimit
+func (a *App) handleNoLimit() error {
+	if a.unlimitedQuery == "" {
+		fmt.Println(a.i18nMgr.Get("no_auto_limited_query"))
+		return nil
+	}
+	if a.connection == nil {
+		fmt.Println(a.i18nMgr.Get("no_database_connection"))
+		return nil
+	}
+	return a.executeAndView(a.unlimitedQuery)
+}
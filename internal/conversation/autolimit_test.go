@@ -0,0 +1,46 @@
+package conversation
+
+import (
+	"testing"
+
+	"sqlterm/internal/core/coretest"
+)
+
+func TestApp_autoLimit(t *testing.T) {
+	app := createTestApp(t)
+	if app.aiManager == nil {
+		t.Skip("AI manager unavailable")
+	}
+
+	if got := app.autoLimit(); got != 0 {
+		t.Errorf("Expected auto_limit to be off by default, got %d", got)
+	}
+	app.aiManager.GetConfig().AutoLimit = 500
+	if got := app.autoLimit(); got != 500 {
+		t.Errorf("Expected 500, got %d", got)
+	}
+	app.aiManager.GetConfig().AutoLimit = -1
+	if got := app.autoLimit(); got != 0 {
+		t.Errorf("Expected a negative limit to turn it off, got %d", got)
+	}
+}
+
+func TestApp_handleNoLimit(t *testing.T) {
+	app := createTestApp(t)
+	conn := coretest.NewMockConnection()
+	app.connection = conn
+
+	if err := app.handleNoLimit(); err != nil {
+		t.Fatalf("handleNoLimit() should only print a message, got %v", err)
+	}
+	if queries := conn.ExecutedQueries(); len(queries) != 0 {
+		t.Errorf("Expected nothing to run without an auto-limited query, ran %v", queries)
+	}
+
+	// A new result replaces the auto-limited one
+	app.unlimitedQuery = "SELECT * FROM users"
+	app.forgetResults()
+	if app.unlimitedQuery != "" {
+		t.Error("Expected a new result to forget the auto-limited query")
+	}
+}
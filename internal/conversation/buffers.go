@@ -0,0 +1,290 @@
+package conversation
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"strconv"
+	"strings"
+
+	"sqlterm/internal/core"
+
+	"gopkg.in/yaml.v3"
+)
+
+// defaultBufferName names the buffer every connection starts with
+const defaultBufferName = "main"
+
+// queryBuffer is a named working query. Path is the file it was last written
+// to, and Dirty reports that the query changed since it was written.
+type queryBuffer struct {
+	Name  string `yaml:"name"`
+	Query string `yaml:"query,omitempty"`
+	Path  string `yaml:"path,omitempty"`
+	Dirty bool   `yaml:"dirty,omitempty"`
+}
+
+// queryBuffers holds the open buffers of a connection and which one /exec
+// writes to. They are saved in the connection's session directory so they
+// are open again on the next connect.
+type queryBuffers struct {
+	path    string
+	Buffers []*queryBuffer `yaml:"buffers"`
+	Active  int            `yaml:"active"`
+}
+
+// loadQueryBuffers reads the buffers stored at path. A missing or unreadable
+// file starts with one empty buffer; a corrupt one is also set aside and reported.
+func loadQueryBuffers(path string) *queryBuffers {
+	b := &queryBuffers{path: path}
+	if data, err := os.ReadFile(path); err == nil {
+		if err := yaml.Unmarshal(data, b); err != nil {
+			// Start afresh rather than overwrite the broken file on the next save
+			core.WarnFile(path, err, true)
+			b.Buffers, b.Active = nil, 0
+		}
+	} else if !os.IsNotExist(err) {
+		core.WarnFile(path, err, false)
+	}
+	if len(b.Buffers) == 0 {
+		b.Buffers = []*queryBuffer{{Name: defaultBufferName}}
+	}
+	if b.Active < 0 || b.Active >= len(b.Buffers) {
+		b.Active = 0
+	}
+	return b
+}
+
+// current returns the active buffer
+func (b *queryBuffers) current() *queryBuffer {
+	return b.Buffers[b.Active]
+}
+
+// find returns the index of the buffer with the given name or 1-based number, or -1
+func (b *queryBuffers) find(nameOrNumber string) int {
+	for i, buffer := range b.Buffers {
+		if buffer.Name == nameOrNumber {
+			return i
+		}
+	}
+	if n, err := strconv.Atoi(nameOrNumber); err == nil && n >= 1 && n <= len(b.Buffers) {
+		return n - 1
+	}
+	return -1
+}
+
+// add opens an empty buffer and makes it active. An empty name picks the
+// first free "queryN".
+func (b *queryBuffers) add(name string) bool {
+	if name == "" {
+		for n := len(b.Buffers) + 1; name == "" || b.find(name) >= 0; n++ {
+			name = fmt.Sprintf("query%d", n)
+		}
+	}
+	if b.find(name) >= 0 {
+		return false
+	}
+	b.Buffers = append(b.Buffers, &queryBuffer{Name: name})
+	b.Active = len(b.Buffers) - 1
+	return true
+}
+
+// remove closes the buffer at i. Closing the last buffer leaves an empty one open.
+func (b *queryBuffers) remove(i int) {
+	b.Buffers = append(b.Buffers[:i], b.Buffers[i+1:]...)
+	if len(b.Buffers) == 0 {
+		b.Buffers = []*queryBuffer{{Name: defaultBufferName}}
+	}
+	if b.Active > i || b.Active >= len(b.Buffers) {
+		b.Active--
+	}
+	b.Active = max(b.Active, 0)
+}
+
+// cycle activates the buffer step places after the active one, wrapping around
+func (b *queryBuffers) cycle(step int) {
+	n := len(b.Buffers)
+	b.Active = ((b.Active+step)%n + n) % n
+}
+
+// setQuery stores query in the active buffer
+func (b *queryBuffers) setQuery(query string) {
+	buffer := b.current()
+	if buffer.Query != query {
+		buffer.Query = query
+		buffer.Dirty = true
+	}
+}
+
+// save writes the buffers to their session file
+func (b *queryBuffers) save() error {
+	if b.path == "" {
+		return nil
+	}
+	data, err := yaml.Marshal(b)
+	if err != nil {
+		return err
+	}
+	if err := os.MkdirAll(filepath.Dir(b.path), 0755); err != nil {
+		return err
+	}
+	return os.WriteFile(b.path, data, 0600)
+}
+
+// saveBuffers persists the buffers, warning rather than failing the command
+func (a *App) saveBuffers() {
+	if err := a.buffers.save(); err != nil {
+		fmt.Printf(a.i18nMgr.Get("buffers_save_warning"), err)
+	}
+}
+
+// handleBuffer manages the named query buffers used by multi-line /exec:
+// /buffer [new [name]|close [name]|next|prev|run|write [file]|edit|<name>]
+func (a *App) handleBuffer(args []string) error {
+	if a.connection == nil || a.buffers == nil {
+		fmt.Println(a.i18nMgr.Get("no_database_connection"))
+		return nil
+	}
+	if len(args) == 0 {
+		a.listBuffers()
+		return nil
+	}
+
+	rest := ""
+	if len(args) > 1 {
+		rest = strings.Join(args[1:], " ")
+	}
+
+	switch args[0] {
+	case "new":
+		if !a.buffers.add(rest) {
+			fmt.Printf(a.i18nMgr.Get("buffer_exists"), rest)
+			return nil
+		}
+		a.saveBuffers()
+		fmt.Printf(a.i18nMgr.Get("buffer_created"), a.buffers.current().Name)
+	case "close":
+		return a.closeBuffer(rest)
+	case "next", "prev":
+		step := 1
+		if args[0] == "prev" {
+			step = -1
+		}
+		a.buffers.cycle(step)
+		a.saveBuffers()
+		a.showBuffer()
+	case "run":
+		return a.runBuffer()
+	case "write":
+		return a.writeBuffer(rest)
+	case "edit":
+		return a.editBuffer()
+	default:
+		i := a.buffers.find(strings.Join(args, " "))
+		if i < 0 {
+			fmt.Printf(a.i18nMgr.Get("buffer_not_found"), strings.Join(args, " "))
+			return nil
+		}
+		a.buffers.Active = i
+		a.saveBuffers()
+		a.showBuffer()
+	}
+	return nil
+}
+
+// listBuffers prints the open buffers, marking the active one and those with unsaved changes
+func (a *App) listBuffers() {
+	for i, buffer := range a.buffers.Buffers {
+		marker := " "
+		if i == a.buffers.Active {
+			marker = "*"
+		}
+		name := buffer.Name
+		if buffer.Dirty {
+			name += a.i18nMgr.Get("buffer_modified")
+		}
+		fmt.Printf("%s %d. %-20s %s\n", marker, i+1, name, a.bufferSummary(buffer))
+	}
+}
+
+// bufferSummary is the first line of a buffer's query, shortened for listings
+func (a *App) bufferSummary(buffer *queryBuffer) string {
+	if buffer.Query == "" {
+		return a.i18nMgr.Get("buffer_empty")
+	}
+	return a.truncateQuery(strings.SplitN(buffer.Query, "\n", 2)[0])
+}
+
+// showBuffer prints the name and query of the active buffer after switching to it
+func (a *App) showBuffer() {
+	buffer := a.buffers.current()
+	fmt.Printf(a.i18nMgr.Get("buffer_switched"), buffer.Name, a.buffers.Active+1, len(a.buffers.Buffers))
+	if buffer.Query == "" {
+		fmt.Println(a.i18nMgr.Get("buffer_empty"))
+		return
+	}
+	fmt.Println(buffer.Query)
+}
+
+// closeBuffer closes the named buffer, or the active one, asking first when it has unsaved changes
+func (a *App) closeBuffer(name string) error {
+	i := a.buffers.Active
+	if name != "" {
+		if i = a.buffers.find(name); i < 0 {
+			fmt.Printf(a.i18nMgr.Get("buffer_not_found"), name)
+			return nil
+		}
+	}
+
+	buffer := a.buffers.Buffers[i]
+	if buffer.Dirty && buffer.Query != "" && !a.confirm(fmt.Sprintf(a.i18nMgr.Get("confirm_close_buffer"), buffer.Name)) {
+		fmt.Println(a.i18nMgr.Get("close_buffer_cancelled"))
+		return nil
+	}
+
+	a.buffers.remove(i)
+	a.saveBuffers()
+	fmt.Printf(a.i18nMgr.Get("buffer_closed"), buffer.Name)
+	return nil
+}
+
+// runBuffer executes the query of the active buffer
+func (a *App) runBuffer() error {
+	buffer := a.buffers.current()
+	if buffer.Query == "" {
+		fmt.Printf(a.i18nMgr.Get("buffer_has_no_query"), buffer.Name)
+		return nil
+	}
+
+	fmt.Print(a.i18nMgr.Get("executing_query"))
+	fmt.Printf(a.i18nMgr.Get("query_truncated"), a.truncateQuery(buffer.Query))
+	if strings.Contains(buffer.Query, " > ") {
+		return a.processQueryWithCSVExport(buffer.Query)
+	}
+	return a.executeTyped(buffer.Query)
+}
+
+// writeBuffer saves the active buffer's query to file, or to the file it was last written to
+func (a *App) writeBuffer(file string) error {
+	buffer := a.buffers.current()
+	if file == "" {
+		file = buffer.Path
+	}
+	if file == "" {
+		fmt.Printf(a.i18nMgr.Get("buffer_has_no_file"), buffer.Name)
+		return nil
+	}
+
+	path, err := filepath.Abs(core.ExpandPath(file))
+	if err != nil {
+		return err
+	}
+	if err := os.WriteFile(path, []byte(strings.TrimRight(buffer.Query, "\n")+"\n"), 0644); err != nil {
+		return err
+	}
+
+	buffer.Path, buffer.Dirty = path, false
+	a.saveBuffers()
+	fmt.Printf(a.i18nMgr.Get("buffer_written"), buffer.Name, displayPath(path))
+	return nil
+}
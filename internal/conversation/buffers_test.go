@@ -0,0 +1,73 @@
+package conversation
+
+import (
+	"path/filepath"
+	"testing"
+)
+
+func bufferNames(b *queryBuffers) []string {
+	names := make([]string, len(b.Buffers))
+	for i, buffer := range b.Buffers {
+		names[i] = buffer.Name
+	}
+	return names
+}
+
+func TestQueryBuffers(t *testing.T) {
+	b := loadQueryBuffers(filepath.Join(t.TempDir(), "buffers.yaml"))
+	if b.current().Name != defaultBufferName {
+		t.Fatalf("Expected to start with the %s buffer, got %v", defaultBufferName, bufferNames(b))
+	}
+
+	b.add("report")
+	b.add("")
+	if b.add("report") {
+		t.Error("Expected a duplicate name to be refused")
+	}
+	if got := bufferNames(b); len(got) != 3 || got[2] != "query3" || b.Active != 2 {
+		t.Fatalf("Expected a generated name and the new buffer active, got %v (active %d)", got, b.Active)
+	}
+
+	b.cycle(1)
+	if b.Active != 0 {
+		t.Errorf("Expected cycling past the last buffer to wrap to the first, got %d", b.Active)
+	}
+	b.cycle(-1)
+	if b.Active != 2 {
+		t.Errorf("Expected cycling back to wrap to the last, got %d", b.Active)
+	}
+	if b.find("report") != 1 || b.find("2") != 1 || b.find("missing") != -1 {
+		t.Error("Expected buffers to be found by name and by number")
+	}
+
+	b.Active = 1
+	b.remove(0)
+	if b.current().Name != "report" {
+		t.Errorf("Expected the active buffer to stay active after closing another, got %s", b.current().Name)
+	}
+	b.remove(1)
+	b.remove(0)
+	if got := bufferNames(b); len(got) != 1 || got[0] != defaultBufferName {
+		t.Errorf("Expected an empty buffer after closing all of them, got %v", got)
+	}
+}
+
+func TestQueryBuffers_SaveAndLoad(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "session", "buffers.yaml")
+	b := loadQueryBuffers(path)
+	b.setQuery("SELECT 1;")
+	b.add("second")
+	b.setQuery("SELECT 2;")
+	if err := b.save(); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+
+	loaded := loadQueryBuffers(path)
+	if loaded.Active != 1 || len(loaded.Buffers) != 2 {
+		t.Fatalf("Expected both buffers with the second active, got %v (active %d)", bufferNames(loaded), loaded.Active)
+	}
+	first := loaded.Buffers[0]
+	if first.Query != "SELECT 1;" || !first.Dirty {
+		t.Errorf("Expected the first buffer's unsaved query to be restored, got %+v", first)
+	}
+}
@@ -0,0 +1,37 @@
+package conversation
+
+import (
+	"errors"
+	"fmt"
+	"os"
+
+	"sqlterm/internal/core"
+)
+
+// handleCheck connects to and pings saved connections, all of them or those named:
+// /check [name...]
+func (a *App) handleCheck(args []string) error {
+	var configs []*core.ConnectionConfig
+	if len(args) == 0 {
+		all, err := a.configMgr.ListConnections()
+		if err != nil {
+			return fmt.Errorf(a.i18nMgr.Get("failed_to_list_connections"), err)
+		}
+		configs = all
+	}
+	for _, name := range args {
+		config, err := a.configMgr.LoadConnection(name)
+		if err != nil {
+			return errors.New(a.i18nMgr.GetWithArgs("failed_to_load_connection", name, err))
+		}
+		configs = append(configs, config)
+	}
+	if len(configs) == 0 {
+		fmt.Println(a.i18nMgr.Get("no_saved_connections_found"))
+		return nil
+	}
+
+	fmt.Printf(a.i18nMgr.Get("checking_connections"), len(configs))
+	results := core.CheckConnections(configs, core.NewConnection, core.DefaultCheckConcurrency, core.DefaultCheckTimeout)
+	return core.WriteCheckResults(os.Stdout, results, colorEnabled(), a.i18nMgr)
+}
@@ -0,0 +1,290 @@
+package conversation
+
+import (
+	"encoding/base64"
+	"fmt"
+	"io"
+	"os"
+	"os/exec"
+	"runtime"
+	"strings"
+
+	"sqlterm/internal/core"
+
+	"golang.org/x/term"
+)
+
+// clipboardBackend is where copied text goes
+type clipboardBackend int
+
+const (
+	// clipboardRegister keeps text inside sqlterm; only /paste can read it back
+	clipboardRegister clipboardBackend = iota
+	// clipboardOSC52 asks the terminal to set the clipboard, which works over SSH
+	clipboardOSC52
+	// clipboardNative runs the system clipboard tool, such as pbcopy or xclip
+	clipboardNative
+)
+
+func (b clipboardBackend) String() string {
+	switch b {
+	case clipboardNative:
+		return "native"
+	case clipboardOSC52:
+		return "osc52"
+	default:
+		return "register"
+	}
+}
+
+// clipboardAvailability says which backends can work in this session
+type clipboardAvailability struct {
+	Native bool
+	OSC52  bool
+}
+
+// selectClipboard picks the backend for setting, which is auto (or empty),
+// native, osc52 or register. Auto takes the first available of native, OSC 52
+// and the register.
+func selectClipboard(setting string, available clipboardAvailability) (clipboardBackend, error) {
+	switch strings.ToLower(strings.TrimSpace(setting)) {
+	case "", "auto":
+		switch {
+		case available.Native:
+			return clipboardNative, nil
+		case available.OSC52:
+			return clipboardOSC52, nil
+		default:
+			return clipboardRegister, nil
+		}
+	case "native":
+		return clipboardNative, nil
+	case "osc52":
+		return clipboardOSC52, nil
+	case "register":
+		return clipboardRegister, nil
+	default:
+		return clipboardRegister, fmt.Errorf("unknown clipboard backend %q (use auto, native, osc52 or register)", setting)
+	}
+}
+
+// clipboard copies through the selected backend and always keeps the text in
+// its register, so /paste works whichever backend took the copy
+type clipboard struct {
+	backend   clipboardBackend
+	available clipboardAvailability
+	copyCmd   []string
+	pasteCmd  []string
+	register  string
+	// noticeShown is set once the user is told copies only paste inside sqlterm
+	noticeShown bool
+	// out receives OSC 52 sequences
+	out io.Writer
+	// run executes a clipboard tool with stdin and returns its output
+	run func(command []string, stdin string) (string, error)
+}
+
+// newClipboard detects what this session supports and selects a backend for setting
+func newClipboard(setting string) (*clipboard, error) {
+	copyCmd, pasteCmd := nativeClipboardCommands()
+	available := clipboardAvailability{
+		Native: copyCmd != nil,
+		OSC52:  term.IsTerminal(int(os.Stdout.Fd())) && os.Getenv("TERM") != "dumb",
+	}
+	backend, err := selectClipboard(setting, available)
+	return &clipboard{
+		backend:   backend,
+		available: available,
+		copyCmd:   copyCmd,
+		pasteCmd:  pasteCmd,
+		out:       os.Stdout,
+		run:       runClipboardCommand,
+	}, err
+}
+
+// Copy stores text and hands it to the backend. When the native tool fails,
+// OSC 52 is tried next if the terminal can take it. The backend that took
+// the text is returned.
+func (c *clipboard) Copy(text string) (clipboardBackend, error) {
+	c.register = text
+
+	backend := c.backend
+	if backend == clipboardNative {
+		if c.copyCmd == nil {
+			return clipboardRegister, fmt.Errorf("no clipboard tool found (install xclip, xsel or wl-clipboard)")
+		}
+		if _, err := c.run(c.copyCmd, text); err == nil {
+			return clipboardNative, nil
+		} else if !c.available.OSC52 {
+			return clipboardRegister, err
+		}
+		backend = clipboardOSC52
+	}
+	if backend == clipboardOSC52 {
+		if _, err := io.WriteString(c.out, osc52Sequence(text, os.Getenv("TMUX") != "")); err != nil {
+			return clipboardRegister, err
+		}
+		return clipboardOSC52, nil
+	}
+	return clipboardRegister, nil
+}
+
+// Paste reads the system clipboard when the native tool is in use, and the
+// register otherwise or when the tool fails
+func (c *clipboard) Paste() string {
+	if c.backend == clipboardNative && c.pasteCmd != nil {
+		if text, err := c.run(c.pasteCmd, ""); err == nil {
+			return text
+		}
+	}
+	return c.register
+}
+
+// osc52Sequence asks the terminal to put text on the system clipboard. Inside
+// tmux the sequence is wrapped so tmux passes it on to the outer terminal.
+func osc52Sequence(text string, tmux bool) string {
+	seq := "\033]52;c;" + base64.StdEncoding.EncodeToString([]byte(text)) + "\a"
+	if tmux {
+		return "\033Ptmux;" + strings.ReplaceAll(seq, "\033", "\033\033") + "\033\\"
+	}
+	return seq
+}
+
+// nativeClipboardCommands finds the system tools that copy and paste, or nil
+// when there are none, e.g. on a headless server without a display
+func nativeClipboardCommands() ([]string, []string) {
+	var candidates [][2][]string
+	switch runtime.GOOS {
+	case "darwin":
+		candidates = [][2][]string{{{"pbcopy"}, {"pbpaste"}}}
+	case "windows":
+		candidates = [][2][]string{{{"clip.exe"}, {"powershell.exe", "-NoProfile", "-Command", "Get-Clipboard"}}}
+	default:
+		if os.Getenv("WAYLAND_DISPLAY") != "" {
+			candidates = append(candidates, [2][]string{{"wl-copy"}, {"wl-paste", "--no-newline"}})
+		}
+		if os.Getenv("DISPLAY") != "" {
+			candidates = append(candidates,
+				[2][]string{{"xclip", "-selection", "clipboard"}, {"xclip", "-selection", "clipboard", "-o"}},
+				[2][]string{{"xsel", "--clipboard", "--input"}, {"xsel", "--clipboard", "--output"}})
+		}
+	}
+
+	for _, candidate := range candidates {
+		if _, err := exec.LookPath(candidate[0][0]); err == nil {
+			return candidate[0], candidate[1]
+		}
+	}
+	return nil, nil
+}
+
+func runClipboardCommand(command []string, stdin string) (string, error) {
+	cmd := exec.Command(command[0], command[1:]...)
+	cmd.Stdin = strings.NewReader(stdin)
+	out, err := cmd.Output()
+	if err != nil {
+		return "", fmt.Errorf("%s: %w", command[0], err)
+	}
+	return string(out), nil
+}
+
+// clipboardFor returns the session clipboard, creating it on first use from the clipboard setting
+func (a *App) clipboardFor() *clipboard {
+	if a.clipboard == nil {
+		setting := ""
+		if a.aiManager != nil {
+			setting = a.aiManager.GetConfig().Clipboard
+		}
+		clip, err := newClipboard(setting)
+		if err != nil {
+			fmt.Printf(a.i18nMgr.Get("generic_warning"), err)
+		}
+		a.clipboard = clip
+	}
+	return a.clipboard
+}
+
+// copyText copies text, telling the user once when it can only be pasted inside sqlterm
+func (a *App) copyText(text, what string) {
+	clip := a.clipboardFor()
+	backend, err := clip.Copy(text)
+	if err != nil {
+		fmt.Printf(a.i18nMgr.Get("clipboard_copy_failed"), err)
+	}
+	if backend == clipboardRegister && !clip.noticeShown {
+		clip.noticeShown = true
+		a.notify(NotifyWarning, a.i18nMgr.Get("clipboard_register_only"))
+	}
+	a.notify(NotifySuccess, a.i18nMgr.Get("copied_to_clipboard"), what, backend)
+}
+
+// handleCopyQuery copies the query behind the last result: /copy-query
+func (a *App) handleCopyQuery() error {
+	if strings.TrimSpace(a.lastQuery) == "" {
+		fmt.Println(a.i18nMgr.Get("no_query_to_copy"))
+		return nil
+	}
+	a.copyText(a.lastQuery, a.i18nMgr.Get("copied_query"))
+	return nil
+}
+
+// handleCopy copies a row of the last result as tab-separated values, or
+// one cell of it: /copy <row> [column]
+func (a *App) handleCopy(args []string) error {
+	if len(args) == 0 || len(args) > 2 {
+		fmt.Println(a.i18nMgr.Get("usage_copy"))
+		return nil
+	}
+	if a.lastStored == nil {
+		fmt.Println(a.i18nMgr.Get("no_result_to_copy"))
+		return nil
+	}
+
+	row, err := a.resultRow(args[0])
+	if err != nil {
+		return err
+	}
+	values := a.lastStored.Rows[row]
+
+	if len(args) == 2 {
+		column := resultColumn(a.lastStored.Columns, args[1])
+		if column < 0 {
+			fmt.Printf(a.i18nMgr.Get("unknown_result_column"), args[1])
+			return nil
+		}
+		text := core.DefaultNullString
+		if !values[column].IsNull() {
+			text = core.FormatISO(values[column])
+		}
+		a.copyText(text, fmt.Sprintf(a.i18nMgr.Get("copied_cell"), row+1, a.lastStored.Columns[column].Name))
+		return nil
+	}
+
+	var sb strings.Builder
+	writer := core.NewTSVWriter(&sb)
+	if err := writer.WriteRow(values); err != nil {
+		return err
+	}
+	if err := writer.Close(); err != nil {
+		return err
+	}
+	a.copyText(strings.TrimSuffix(sb.String(), "\n"), fmt.Sprintf(a.i18nMgr.Get("copied_row"), row+1))
+	return nil
+}
+
+// handlePaste puts the clipboard on the next prompt line for editing. Lines
+// are joined with spaces because a newline would submit the line.
+func (a *App) handlePaste() error {
+	text := strings.TrimSpace(a.clipboardFor().Paste())
+	if text == "" {
+		fmt.Println(a.i18nMgr.Get("clipboard_empty"))
+		return nil
+	}
+	text = strings.NewReplacer("\r\n", " ", "\n", " ", "\r", " ").Replace(text)
+	if a.rl == nil {
+		fmt.Println(text)
+		return nil
+	}
+	_, err := a.rl.WriteStdin([]byte(text))
+	return err
+}
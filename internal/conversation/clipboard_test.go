@@ -0,0 +1,132 @@
+package conversation
+
+import (
+	"bytes"
+	"encoding/base64"
+	"errors"
+	"strings"
+	"testing"
+
+	"sqlterm/internal/core"
+)
+
+func TestSelectClipboard(t *testing.T) {
+	tests := []struct {
+		name      string
+		setting   string
+		available clipboardAvailability
+		want      clipboardBackend
+		wantErr   bool
+	}{
+		{"auto prefers native", "auto", clipboardAvailability{Native: true, OSC52: true}, clipboardNative, false},
+		{"auto over ssh", "", clipboardAvailability{OSC52: true}, clipboardOSC52, false},
+		{"auto with nothing", "auto", clipboardAvailability{}, clipboardRegister, false},
+		{"forced osc52", "OSC52", clipboardAvailability{Native: true}, clipboardOSC52, false},
+		{"forced register", "register", clipboardAvailability{Native: true, OSC52: true}, clipboardRegister, false},
+		{"forced native", "native", clipboardAvailability{}, clipboardNative, false},
+		{"unknown", "xclip", clipboardAvailability{Native: true}, clipboardRegister, true},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got, err := selectClipboard(tt.setting, tt.available)
+			if got != tt.want || (err != nil) != tt.wantErr {
+				t.Errorf("selectClipboard(%q) = %v, %v, want %v (error %v)", tt.setting, got, err, tt.want, tt.wantErr)
+			}
+		})
+	}
+}
+
+func TestClipboard_Register(t *testing.T) {
+	clip := &clipboard{backend: clipboardRegister}
+	backend, err := clip.Copy("SELECT 1")
+	if err != nil || backend != clipboardRegister {
+		t.Fatalf("Copy() = %v, %v", backend, err)
+	}
+	if got := clip.Paste(); got != "SELECT 1" {
+		t.Errorf("Paste() = %q, want the copied text", got)
+	}
+}
+
+func TestClipboard_NativeFallback(t *testing.T) {
+	failing := func(command []string, stdin string) (string, error) { return "", errors.New("can't open display") }
+
+	t.Run("falls back to osc52", func(t *testing.T) {
+		var out bytes.Buffer
+		clip := &clipboard{
+			backend:   clipboardNative,
+			available: clipboardAvailability{Native: true, OSC52: true},
+			copyCmd:   []string{"xclip"},
+			pasteCmd:  []string{"xclip", "-o"},
+			out:       &out,
+			run:       failing,
+		}
+		backend, err := clip.Copy("hello")
+		if err != nil || backend != clipboardOSC52 {
+			t.Fatalf("Copy() = %v, %v, want osc52", backend, err)
+		}
+		if !strings.Contains(out.String(), base64.StdEncoding.EncodeToString([]byte("hello"))) {
+			t.Errorf("Expected an OSC 52 sequence, got %q", out.String())
+		}
+		if got := clip.Paste(); got != "hello" {
+			t.Errorf("Paste() = %q, want the register when the tool fails", got)
+		}
+	})
+
+	t.Run("falls back to the register", func(t *testing.T) {
+		clip := &clipboard{backend: clipboardNative, copyCmd: []string{"xclip"}, run: failing}
+		backend, err := clip.Copy("hello")
+		if err == nil || backend != clipboardRegister {
+			t.Errorf("Copy() = %v, %v, want the register and the tool's error", backend, err)
+		}
+	})
+}
+
+func TestOSC52Sequence(t *testing.T) {
+	if got := osc52Sequence("hi", false); got != "\033]52;c;aGk=\a" {
+		t.Errorf("osc52Sequence() = %q", got)
+	}
+	if got := osc52Sequence("hi", true); got != "\033Ptmux;\033\033]52;c;aGk=\a\033\\" {
+		t.Errorf("osc52Sequence() in tmux = %q", got)
+	}
+}
+
+func TestApp_HandleCopy(t *testing.T) {
+	app := createTestApp(t)
+	app.clipboard = &clipboard{backend: clipboardRegister}
+	app.lastQuery = "SELECT id, email FROM users"
+	app.lastStored = &core.StoredResult{
+		Columns: []core.Column{{Name: "id"}, {Name: "email"}},
+		Rows: [][]core.Value{
+			{core.StringValue{Value: "1"}, core.StringValue{Value: "a@x.com"}},
+			{core.StringValue{Value: "2"}, core.StringValue{Null: true}},
+		},
+	}
+
+	tests := []struct {
+		name string
+		run  func() error
+		want string
+	}{
+		{"row", func() error { return app.handleCopy([]string{"1"}) }, "1\ta@x.com"},
+		{"cell", func() error { return app.handleCopy([]string{"1", "EMAIL"}) }, "a@x.com"},
+		{"null cell", func() error { return app.handleCopy([]string{"2", "email"}) }, core.DefaultNullString},
+		{"query", app.handleCopyQuery, "SELECT id, email FROM users"},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if err := tt.run(); err != nil {
+				t.Fatalf("Unexpected error: %v", err)
+			}
+			if got := app.clipboard.register; got != tt.want {
+				t.Errorf("Copied %q, want %q", got, tt.want)
+			}
+		})
+	}
+
+	if !app.clipboard.noticeShown {
+		t.Error("Expected the register-only notice to be shown")
+	}
+	if err := app.handleCopy([]string{"3"}); err == nil {
+		t.Error("Expected an error for a row past the end")
+	}
+}
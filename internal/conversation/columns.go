@@ -0,0 +1,310 @@
+package conversation
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"slices"
+	"strconv"
+
+	"sqlterm/internal/core"
+
+	"golang.org/x/term"
+	"gopkg.in/yaml.v3"
+)
+
+// columnLayouts remembers the columns chosen with /columns for each result
+// shape, keyed by core.ColumnSignature, so running the same query later shows
+// the same columns at the same widths. A nil *columnLayouts shows every column.
+type columnLayouts struct {
+	path    string
+	Layouts map[string]core.ColumnLayout `yaml:"layouts"`
+	// Widths holds the widths set with /columns width by query-order column index
+	Widths map[string]map[int]int `yaml:"widths,omitempty"`
+}
+
+// loadColumnLayouts reads the layouts stored at path; a missing file has none,
+// and a corrupt one is set aside and reported
+func loadColumnLayouts(path string) *columnLayouts {
+	l := &columnLayouts{path: path}
+	if data, err := os.ReadFile(path); err == nil {
+		if err := yaml.Unmarshal(data, l); err != nil {
+			// Start afresh rather than overwrite the broken file on the next save
+			core.WarnFile(path, err, true)
+			l.Layouts, l.Widths = nil, nil
+		}
+	} else if !os.IsNotExist(err) {
+		core.WarnFile(path, err, false)
+	}
+	if l.Layouts == nil {
+		l.Layouts = make(map[string]core.ColumnLayout)
+	}
+	if l.Widths == nil {
+		l.Widths = make(map[string]map[int]int)
+	}
+	return l
+}
+
+// get returns the layout for a result with columns, or nil to show them all
+func (l *columnLayouts) get(columns []core.Column) core.ColumnLayout {
+	if l == nil {
+		return nil
+	}
+	layout := l.Layouts[core.ColumnSignature(columns)]
+	if !layout.Valid(len(columns)) {
+		return nil
+	}
+	return layout
+}
+
+// set stores layout for a result with columns; nil forgets it
+func (l *columnLayouts) set(columns []core.Column, layout core.ColumnLayout) error {
+	if l == nil {
+		return nil
+	}
+	if layout == nil {
+		delete(l.Layouts, core.ColumnSignature(columns))
+	} else {
+		l.Layouts[core.ColumnSignature(columns)] = layout
+	}
+	return l.save()
+}
+
+// widths returns the widths set for a result with columns by query-order
+// index, or nil when every column is sized by its values
+func (l *columnLayouts) widths(columns []core.Column) map[int]int {
+	if l == nil {
+		return nil
+	}
+	widths := make(map[int]int)
+	for i, width := range l.Widths[core.ColumnSignature(columns)] {
+		if i >= 0 && i < len(columns) && width > 0 {
+			widths[i] = width
+		}
+	}
+	if len(widths) == 0 {
+		return nil
+	}
+	return widths
+}
+
+// setWidth sets the width of column i of a result with columns; 0 sizes it by its values again
+func (l *columnLayouts) setWidth(columns []core.Column, i, width int) error {
+	if l == nil {
+		return nil
+	}
+	signature := core.ColumnSignature(columns)
+	widths := l.Widths[signature]
+	if widths == nil {
+		widths = make(map[int]int)
+		l.Widths[signature] = widths
+	}
+	if width > 0 {
+		widths[i] = width
+	} else {
+		delete(widths, i)
+	}
+	if len(widths) == 0 {
+		delete(l.Widths, signature)
+	}
+	return l.save()
+}
+
+// resetWidths sizes every column of a result with columns by its values again
+func (l *columnLayouts) resetWidths(columns []core.Column) {
+	if l != nil {
+		delete(l.Widths, core.ColumnSignature(columns))
+	}
+}
+
+func (l *columnLayouts) save() error {
+	data, err := yaml.Marshal(l)
+	if err != nil {
+		return err
+	}
+	if err := os.MkdirAll(filepath.Dir(l.path), 0755); err != nil {
+		return err
+	}
+	return os.WriteFile(l.path, data, 0600)
+}
+
+// columnLayout returns the layout results with columns are shown in
+func (a *App) columnLayout(columns []core.Column) core.ColumnLayout {
+	return a.layouts.get(columns)
+}
+
+// resultFormat returns the format options for showing a result with columns,
+// with the widths set with /columns width in display order, the table
+// fitted to the terminal and URL cells collected for linking
+func (a *App) resultFormat(columns []core.Column) core.FormatOptions {
+	opts := a.withHyperlinks(a.formatOptions())
+	widths := a.layouts.widths(columns)
+	if widths == nil {
+		return opts
+	}
+	shown := a.shownColumns(columns)
+	opts.ColumnWidths = make([]int, len(shown))
+	for position, i := range shown {
+		opts.ColumnWidths[position] = widths[i]
+	}
+	opts.TableWidth = terminalWidth()
+	return opts
+}
+
+// terminalWidth returns how many columns wide the terminal is, or 0 when output is not a terminal
+func terminalWidth() int {
+	width, _, err := term.GetSize(int(os.Stdout.Fd()))
+	if err != nil {
+		return 0
+	}
+	return width
+}
+
+// shownColumns returns a copy of the layout of columns with every index spelled out
+func (a *App) shownColumns(columns []core.Column) core.ColumnLayout {
+	if layout := a.columnLayout(columns); layout != nil {
+		return slices.Clone(layout)
+	}
+	layout := make(core.ColumnLayout, len(columns))
+	for i := range layout {
+		layout[i] = i
+	}
+	return layout
+}
+
+// handleColumns chooses which columns of the last result are shown, in which
+// order and how wide:
+// /columns [hide <col>...|show <col>...|move <col> <position>|width <col> <n|auto>|reset]
+func (a *App) handleColumns(args []string) error {
+	if a.lastStored == nil {
+		fmt.Println(a.i18nMgr.Get("no_result_for_columns"))
+		return nil
+	}
+	if len(args) == 0 {
+		a.listColumns()
+		return nil
+	}
+
+	columns := a.lastStored.Columns
+	layout := a.shownColumns(columns)
+	switch {
+	case args[0] == "hide" && len(args) > 1:
+		hidden, ok := a.resolveColumns(columns, args[1:])
+		if !ok {
+			return nil
+		}
+		layout = slices.DeleteFunc(layout, func(i int) bool { return slices.Contains(hidden, i) })
+		if len(layout) == 0 {
+			fmt.Println(a.i18nMgr.Get("columns_all_hidden"))
+			return nil
+		}
+	case args[0] == "show" && len(args) > 1:
+		shown, ok := a.resolveColumns(columns, args[1:])
+		if !ok {
+			return nil
+		}
+		for _, i := range shown {
+			if !slices.Contains(layout, i) {
+				layout = append(layout, i)
+			}
+		}
+	case args[0] == "move" && len(args) == 3:
+		moved, ok := a.resolveColumns(columns, args[1:2])
+		if !ok {
+			return nil
+		}
+		position, err := strconv.Atoi(args[2])
+		from := slices.Index(layout, moved[0])
+		if err != nil || position < 1 || position > len(layout) || from < 0 {
+			fmt.Printf(a.i18nMgr.Get("invalid_column_position"), args[2], len(layout))
+			return nil
+		}
+		layout = slices.Insert(slices.Delete(layout, from, from+1), position-1, moved[0])
+	case args[0] == "width" && len(args) == 3:
+		resized, ok := a.resolveColumns(columns, args[1:2])
+		if !ok {
+			return nil
+		}
+		width, ok := parseColumnWidth(args[2])
+		if !ok {
+			fmt.Printf(a.i18nMgr.Get("invalid_column_width"), args[2], core.MinColumnWidth)
+			return nil
+		}
+		if err := a.layouts.setWidth(columns, resized[0], width); err != nil {
+			fmt.Printf(a.i18nMgr.Get("column_layout_save_warning"), err)
+		}
+	case args[0] == "reset" && len(args) == 1:
+		layout = nil
+		a.layouts.resetWidths(columns)
+	default:
+		fmt.Println(a.i18nMgr.Get("usage_columns"))
+		return nil
+	}
+
+	if isIdentityLayout(layout, len(columns)) {
+		layout = nil
+	}
+	if err := a.layouts.set(columns, layout); err != nil {
+		fmt.Printf(a.i18nMgr.Get("column_layout_save_warning"), err)
+	}
+
+	a.lastStored.Rewind()
+	return a.viewStored()
+}
+
+// listColumns prints the columns of the last result in display order, then the hidden ones
+func (a *App) listColumns() {
+	columns := a.lastStored.Columns
+	layout := a.shownColumns(columns)
+	widths := a.layouts.widths(columns)
+	for position, i := range layout {
+		fmt.Printf("%3d. [x] %s", position+1, columns[i].Name)
+		if width, ok := widths[i]; ok {
+			fmt.Printf(a.i18nMgr.Get("column_width_note"), width)
+		}
+		fmt.Println()
+	}
+	for i, column := range columns {
+		if !slices.Contains(layout, i) {
+			fmt.Printf("     [ ] %s\n", column.Name)
+		}
+	}
+}
+
+// resolveColumns returns the indexes of the named result columns, reporting the first unknown name
+func (a *App) resolveColumns(columns []core.Column, names []string) ([]int, bool) {
+	indexes := make([]int, len(names))
+	for i, name := range names {
+		if indexes[i] = resultColumn(columns, name); indexes[i] < 0 {
+			fmt.Printf(a.i18nMgr.Get("unknown_result_column"), name)
+			return nil, false
+		}
+	}
+	return indexes, true
+}
+
+// parseColumnWidth reads the width given to /columns width: a number of
+// terminal columns, or auto (or 0) to size the column by its values
+func parseColumnWidth(arg string) (int, bool) {
+	if arg == "auto" {
+		return 0, true
+	}
+	width, err := strconv.Atoi(arg)
+	if err != nil || (width != 0 && width < core.MinColumnWidth) {
+		return 0, false
+	}
+	return width, true
+}
+
+// isIdentityLayout reports whether layout shows all n columns in query order
+func isIdentityLayout(layout core.ColumnLayout, n int) bool {
+	if len(layout) != n {
+		return false
+	}
+	for position, i := range layout {
+		if position != i {
+			return false
+		}
+	}
+	return true
+}
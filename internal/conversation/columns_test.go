@@ -0,0 +1,117 @@
+package conversation
+
+import (
+	"bytes"
+	"context"
+	"path/filepath"
+	"slices"
+	"strings"
+	"testing"
+
+	"sqlterm/internal/core"
+)
+
+func TestApp_handleColumns(t *testing.T) {
+	t.Setenv("SQLTERM_CONFIG_DIR", t.TempDir())
+	app := createTestApp(t)
+	app.connection = newMockOrdersConnection()
+	app.config = &core.ConnectionConfig{Name: "mock-db", DatabaseType: core.MySQL}
+	layoutsPath := filepath.Join(t.TempDir(), "column_layouts.yaml")
+	app.layouts = loadColumnLayouts(layoutsPath)
+
+	var buf bytes.Buffer
+	if err := app.processQuery(context.Background(), "SELECT * FROM orders", &buf); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	columns := app.lastStored.Columns
+
+	tests := []struct {
+		name string
+		args []string
+		want core.ColumnLayout
+	}{
+		{"hide", []string{"hide", "note"}, core.ColumnLayout{0, 1}},
+		{"move", []string{"move", "user_id", "1"}, core.ColumnLayout{1, 0}},
+		{"unknown column", []string{"hide", "missing"}, core.ColumnLayout{1, 0}},
+		{"position out of range", []string{"move", "id", "3"}, core.ColumnLayout{1, 0}},
+		{"hide every column", []string{"hide", "id", "user_id"}, core.ColumnLayout{1, 0}},
+		{"show", []string{"show", "note"}, core.ColumnLayout{1, 0, 2}},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if err := app.handleColumns(tt.args); err != nil {
+				t.Fatalf("Unexpected error: %v", err)
+			}
+			if layout := app.columnLayout(columns); !slices.Equal(layout, tt.want) {
+				t.Errorf("Expected layout %v, got %v", tt.want, layout)
+			}
+		})
+	}
+
+	// The layout is remembered for the next run of the same query
+	app.layouts = loadColumnLayouts(layoutsPath)
+	if err := app.handleColumns([]string{"hide", "note"}); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	app.layouts = loadColumnLayouts(layoutsPath)
+	buf.Reset()
+	if err := app.processQuery(context.Background(), "SELECT * FROM orders", &buf); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if out := buf.String(); !strings.Contains(out, "| user_id | id") || strings.Contains(out, "note") {
+		t.Errorf("Expected user_id before id and note hidden, got:\n%s", out)
+	}
+
+	if err := app.handleColumns([]string{"reset"}); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if layout := loadColumnLayouts(layoutsPath).get(columns); layout != nil {
+		t.Errorf("Expected reset to forget the layout, got %v", layout)
+	}
+}
+
+func TestApp_handleColumns_Width(t *testing.T) {
+	t.Setenv("SQLTERM_CONFIG_DIR", t.TempDir())
+	app := createTestApp(t)
+	app.connection = newMockOrdersConnection()
+	app.config = &core.ConnectionConfig{Name: "mock-db", DatabaseType: core.MySQL}
+	layoutsPath := filepath.Join(t.TempDir(), "column_layouts.yaml")
+	app.layouts = loadColumnLayouts(layoutsPath)
+
+	var buf bytes.Buffer
+	if err := app.processQuery(context.Background(), "SELECT * FROM orders", &buf); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	columns := app.lastStored.Columns
+
+	for _, args := range [][]string{{"width", "note", "12"}, {"width", "id", "2"}, {"width", "id", "wide"}} {
+		if err := app.handleColumns(args); err != nil {
+			t.Fatalf("Unexpected error: %v", err)
+		}
+	}
+	// Widths follow the columns when they are reordered, and survive a restart
+	if err := app.handleColumns([]string{"move", "note", "1"}); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	app.layouts = loadColumnLayouts(layoutsPath)
+	if got := app.resultFormat(columns).ColumnWidths; !slices.Equal(got, []int{12, 0, 0}) {
+		t.Errorf("Expected only note to have a width, got %v", got)
+	}
+
+	if err := app.handleColumns([]string{"width", "note", "auto"}); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if got := app.resultFormat(columns).ColumnWidths; got != nil {
+		t.Errorf("Expected auto to size note by its values again, got %v", got)
+	}
+
+	if err := app.handleColumns([]string{"width", "user_id", "9"}); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if err := app.handleColumns([]string{"reset"}); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if widths := loadColumnLayouts(layoutsPath).widths(columns); widths != nil {
+		t.Errorf("Expected reset to forget the widths, got %v", widths)
+	}
+}
@@ -0,0 +1,284 @@
+package conversation
+
+import (
+	"os"
+	"path/filepath"
+	"sort"
+	"strconv"
+	"strings"
+
+	"sqlterm/internal/core"
+)
+
+// argCompleter lists the values an argument can take; word is what has been typed of it
+type argCompleter func(ac *AutoCompleter, word string) []string
+
+// commandSpec is a slash command and what its arguments complete from. Args
+// completes the arguments by position, then Rest completes any after them;
+// nil completes nothing.
+type commandSpec struct {
+	Name string
+	Args []argCompleter
+	Rest argCompleter
+}
+
+// commands lists the slash commands in display order with their argument
+// completion. A command added here is offered by Tab and must appear in the
+// help; /config and /exec exports complete through their own branches.
+var commands = []commandSpec{
+	{Name: "/help"},
+	{Name: "/quit"},
+	{Name: "/exit"},
+	{Name: "/connect", Args: []argCompleter{connectionNames}},
+	{Name: "/connect-template", Args: []argCompleter{templateNames}, Rest: templateValues},
+	{Name: "/list-connections", Args: []argCompleter{connectionNames}},
+	{Name: "/reload-connections"},
+	{Name: "/reconnect"},
+	{Name: "/tables"},
+	{Name: "/describe", Args: []argCompleter{tableNames, options("--count")}},
+	{Name: "/count", Args: []argCompleter{tableNames}},
+	{Name: "/status"},
+	{Name: "/exec"},
+	{Name: "/config"},
+	{Name: "/prompts"},
+	{Name: "/clear", Args: []argCompleter{options("--history")}},
+	{Name: "/clear-conversation"},
+	{Name: "/redraw"},
+	{Name: "/processlist"},
+	{Name: "/kill"},
+	{Name: "/errors", Args: []argCompleter{options("clear")}},
+	{Name: "/diag"},
+	{Name: "/refresh"},
+	{Name: "/more"},
+	{Name: "/edit", Args: []argCompleter{nil, resultColumns}},
+	{Name: "/follow", Args: []argCompleter{nil, resultColumns}},
+	{Name: "/where", Args: []argCompleter{resultColumns, filterOps}},
+	{Name: "/back"},
+	{Name: "/row", Args: []argCompleter{nil, options("--json")}},
+	{Name: "/buffer", Args: []argCompleter{bufferNames}},
+	{Name: "/editor"},
+	{Name: "/attach", Args: []argCompleter{filePaths}},
+	{Name: "/detach"},
+	{Name: "/audit", Args: []argCompleter{options("tail")}},
+	{Name: "/tasks", Args: []argCompleter{options("cancel")}},
+	{Name: "/columns", Args: []argCompleter{options("hide", "show", "move", "width", "reset")}, Rest: resultColumns},
+	{Name: "/pin", Args: []argCompleter{options("last"), options("as")}},
+	{Name: "/pins", Args: []argCompleter{options("list")}},
+	{Name: "/unpin", Args: []argCompleter{pinNames}},
+	{Name: "/check", Rest: connectionNames},
+	{Name: "/copy", Args: []argCompleter{nil, resultColumns}},
+	{Name: "/copy-query"},
+	{Name: "/paste"},
+	{Name: "/sample", Args: []argCompleter{tableNames}},
+	{Name: "/palette"},
+	{Name: "/nolimit"},
+	{Name: "/functions"},
+	{Name: "/triggers", Args: []argCompleter{tableNames}},
+	{Name: "/sort", Args: []argCompleter{resultColumns, options("asc", "desc")}},
+	{Name: "/show", Args: []argCompleter{resultNumbers}},
+	{Name: "/sql", Args: []argCompleter{resultNumbers}},
+	{Name: "/rerun", Args: []argCompleter{resultNumbers, options("--on"), connectionNames}},
+	{Name: "/gexec"},
+	{Name: "/keys"},
+}
+
+// commandNames lists the slash commands offered for completion, in display order
+var commandNames = specNames(commands)
+
+func specNames(specs []commandSpec) []string {
+	names := make([]string, len(specs))
+	for i, spec := range specs {
+		names[i] = spec.Name
+	}
+	return names
+}
+
+// lookupCommand finds the spec of a slash command
+func lookupCommand(name string) (commandSpec, bool) {
+	for _, spec := range commands {
+		if spec.Name == name {
+			return spec, true
+		}
+	}
+	return commandSpec{}, false
+}
+
+// arg returns the completer of argument i, counting from 0
+func (s commandSpec) arg(i int) argCompleter {
+	if i < len(s.Args) {
+		return s.Args[i]
+	}
+	return s.Rest
+}
+
+// getArgumentCandidates completes the argument being typed after a slash
+// command from the command's spec, returning the candidates and how much of
+// the line they complete
+func (ac *AutoCompleter) getArgumentCandidates(words []string, line string) ([]string, int) {
+	spec, ok := lookupCommand(words[0])
+	if !ok {
+		return nil, 0
+	}
+
+	args, word := words[1:], ""
+	if !strings.HasSuffix(line, " ") {
+		args, word = args[:len(args)-1], args[len(args)-1]
+	}
+	complete := spec.arg(len(args))
+	if complete == nil {
+		return nil, 0
+	}
+	ac.args = args
+	return ac.completeName(line, word, complete(ac, word)), len(word)
+}
+
+// options completes an argument from a fixed list of keywords
+func options(values ...string) argCompleter {
+	return func(*AutoCompleter, string) []string {
+		return values
+	}
+}
+
+func connectionNames(ac *AutoCompleter, _ string) []string {
+	connections, err := ac.app.configMgr.ListConnections()
+	if err != nil {
+		return nil
+	}
+	names := make([]string, len(connections))
+	for i, conn := range connections {
+		names[i] = conn.Name
+	}
+	return names
+}
+
+// templateNames completes the names of the connection templates
+func templateNames(ac *AutoCompleter, _ string) []string {
+	templates, err := ac.app.configMgr.ListTemplates()
+	if err != nil {
+		return nil
+	}
+	names := make([]string, len(templates))
+	for i, template := range templates {
+		names[i] = template.Name
+	}
+	return names
+}
+
+// templateValues completes name= for the placeholders of the template named
+// first that have no value yet, and --save. A value being typed is left alone.
+func templateValues(ac *AutoCompleter, word string) []string {
+	if strings.Contains(word, "=") {
+		return nil
+	}
+	template, err := ac.app.configMgr.LoadTemplate(ac.args[0])
+	if err != nil {
+		return nil
+	}
+	given := make(map[string]bool, len(ac.args))
+	for _, arg := range ac.args[1:] {
+		name, _, _ := strings.Cut(arg, "=")
+		given[name] = true
+	}
+	var candidates []string
+	for _, placeholder := range template.Placeholders {
+		if !given[placeholder] {
+			candidates = append(candidates, placeholder+"=")
+		}
+	}
+	if !given["--save"] {
+		candidates = append(candidates, "--save")
+	}
+	return candidates
+}
+
+func tableNames(ac *AutoCompleter, _ string) []string {
+	if ac.app.connection == nil {
+		return nil
+	}
+	schema, conn := ac.app.schema(), ac.app.connection
+	return waitForCompletion(func() []string {
+		tables, _, err := schema.Tables(conn.ListTables)
+		if err != nil {
+			return nil
+		}
+		return tables
+	})
+}
+
+// resultColumns completes the column names of the last result
+func resultColumns(ac *AutoCompleter, _ string) []string {
+	if ac.app.lastStored == nil {
+		return nil
+	}
+	names := make([]string, len(ac.app.lastStored.Columns))
+	for i, column := range ac.app.lastStored.Columns {
+		names[i] = column.Name
+	}
+	return names
+}
+
+// filterOps completes the operators of /where
+func filterOps(_ *AutoCompleter, _ string) []string {
+	return filterOpNames()
+}
+
+// resultNumbers completes the numbers of recent results, newest first
+func resultNumbers(ac *AutoCompleter, _ string) []string {
+	if ac.app.results == nil {
+		return nil
+	}
+	entries := ac.app.results.Entries()
+	var numbers []string
+	for i := len(entries) - 1; i >= max(len(entries)-maxListedResults, 0); i-- {
+		numbers = append(numbers, strconv.Itoa(entries[i].Number))
+	}
+	return numbers
+}
+
+// bufferNames completes the open buffers of the connection and the /buffer subcommands
+func bufferNames(ac *AutoCompleter, _ string) []string {
+	names := []string{"new", "close", "next", "prev", "run", "write", "edit"}
+	if ac.app.buffers != nil {
+		for _, buffer := range ac.app.buffers.Buffers {
+			names = append(names, buffer.Name)
+		}
+	}
+	return names
+}
+
+func pinNames(ac *AutoCompleter, _ string) []string {
+	names := make([]string, 0, len(ac.app.pins))
+	for name := range ac.app.pins {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+	return names
+}
+
+// filePaths completes the files and directories next to the typed path
+func filePaths(_ *AutoCompleter, word string) []string {
+	dir, prefix := filepath.Dir(word), ""
+	if hasTrailingSeparator(word) {
+		dir = filepath.Clean(word)
+	}
+	if dir != "." || strings.HasPrefix(word, "."+pathSep) {
+		prefix = strings.TrimSuffix(dir, pathSep) + pathSep
+	}
+
+	entries, err := os.ReadDir(core.ExpandPath(dir))
+	if err != nil {
+		return nil
+	}
+	var paths []string
+	for _, entry := range entries {
+		if strings.HasPrefix(entry.Name(), ".") {
+			continue
+		}
+		path := prefix + entry.Name()
+		if entry.IsDir() {
+			path += pathSep
+		}
+		paths = append(paths, path)
+	}
+	return paths
+}
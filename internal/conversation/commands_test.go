@@ -0,0 +1,78 @@
+package conversation
+
+import (
+	"os"
+	"path/filepath"
+	"regexp"
+	"slices"
+	"testing"
+
+	"sqlterm/internal/core"
+	"sqlterm/internal/i18n"
+)
+
+func TestCommands_AreInHelp(t *testing.T) {
+	for _, language := range []string{"en_au", "zh_cn"} {
+		i18nMgr, err := i18n.NewManager(language)
+		if err != nil {
+			t.Fatalf("NewManager(%s) error = %v", language, err)
+		}
+		help := i18nMgr.Get("help_full")
+		for _, spec := range commands {
+			if !regexp.MustCompile(regexp.QuoteMeta(spec.Name) + `([^a-z-]|$)`).MatchString(help) {
+				t.Errorf("Expected %s in the %s help", spec.Name, language)
+			}
+		}
+	}
+}
+
+func TestAutoCompleter_getArgumentCandidates(t *testing.T) {
+	app := createTestApp(t)
+	app.aiManager = nil
+	app.results = core.NewResultStore(core.DefaultResultCapacity)
+	ac := NewAutoCompleter(app)
+	app.lastStored = &core.StoredResult{Columns: []core.Column{{Name: "id"}, {Name: "name"}, {Name: "notes"}}}
+	app.results.Add("SELECT 1", &core.StoredResult{})
+	app.results.Add("SELECT 2", &core.StoredResult{})
+
+	testCases := []struct {
+		line     string
+		expected []string
+	}{
+		{line: "/sort n", expected: []string{"ame", "otes"}},
+		{line: "/sort name d", expected: []string{"esc"}},
+		{line: "/columns hide na", expected: []string{"me"}},
+		{line: "/columns hide id no", expected: []string{"tes"}},
+		{line: "/columns r", expected: []string{"eset"}},
+		{line: "/row 3 --", expected: []string{"json"}},
+		{line: "/show ", expected: []string{"2", "1"}},
+		{line: "/help ", expected: nil},
+		{line: "/nosuchcommand x", expected: nil},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.line, func(t *testing.T) {
+			newLine, _ := ac.Do([]rune(tc.line), len(tc.line))
+			var got []string
+			for _, candidate := range newLine {
+				got = append(got, string(candidate))
+			}
+			if !slices.Equal(got, tc.expected) {
+				t.Errorf("Expected %v, got %v", tc.expected, got)
+			}
+		})
+	}
+}
+
+func TestFilePaths(t *testing.T) {
+	dir := t.TempDir()
+	os.WriteFile(filepath.Join(dir, "main.db"), nil, 0644)
+	os.Mkdir(filepath.Join(dir, "archive"), 0755)
+	os.WriteFile(filepath.Join(dir, ".hidden"), nil, 0644)
+
+	got := filePaths(nil, dir+pathSep)
+	want := []string{filepath.Join(dir, "archive") + pathSep, filepath.Join(dir, "main.db")}
+	if !slices.Equal(got, want) {
+		t.Errorf("Expected %v, got %v", want, got)
+	}
+}
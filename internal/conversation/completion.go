@@ -0,0 +1,74 @@
+package conversation
+
+import (
+	"sync"
+	"time"
+)
+
+// Tab waits at most completionWait for sources that go to disk or the server;
+// slower results are kept for completionTTL so the next Tab can use them
+const (
+	completionWait = 150 * time.Millisecond
+	completionTTL  = 2 * time.Second
+)
+
+// completionCache computes candidates in the background so a slow directory
+// walk cannot freeze the prompt. Every request starts a new generation; a
+// result that arrives after a newer request was made is dropped, so
+// completions never come from input the user has since changed.
+type completionCache struct {
+	mu         sync.Mutex
+	generation uint64
+	key        string
+	candidates []string
+	at         time.Time
+}
+
+// complete returns the candidates for key, reusing a recent result for the same key.
+// It returns nil when fetch takes longer than completionWait.
+func (c *completionCache) complete(key string, fetch func() []string) []string {
+	c.mu.Lock()
+	if c.key == key && time.Since(c.at) < completionTTL {
+		candidates := c.candidates
+		c.mu.Unlock()
+		return candidates
+	}
+	c.generation++
+	generation := c.generation
+	c.mu.Unlock()
+
+	return waitForCompletion(func() []string {
+		candidates := fetch()
+
+		c.mu.Lock()
+		defer c.mu.Unlock()
+		if c.generation == generation {
+			c.key, c.candidates, c.at = key, candidates, time.Now()
+		}
+		return candidates
+	})
+}
+
+// waitForCompletion runs fetch in the background and returns its result if it
+// finishes within completionWait. A slower fetch keeps running so anything it
+// caches on the way is ready for the next Tab.
+func waitForCompletion[T any](fetch func() T) T {
+	done := make(chan T, 1)
+	go func() {
+		var result T
+		// Like Do, offer no completions rather than crash on a panic
+		defer func() {
+			recover()
+			done <- result
+		}()
+		result = fetch()
+	}()
+
+	select {
+	case result := <-done:
+		return result
+	case <-time.After(completionWait):
+		var zero T
+		return zero
+	}
+}
@@ -0,0 +1,67 @@
+package conversation
+
+import (
+	"strings"
+	"testing"
+	"time"
+)
+
+func TestCompletionCache_SlowFetchIsReadyOnNextTab(t *testing.T) {
+	var cache completionCache
+	release := make(chan struct{})
+	fetches := 0
+	fetch := func() []string {
+		fetches++
+		<-release
+		return []string{"report.sql"}
+	}
+
+	if candidates := cache.complete("@rep", fetch); candidates != nil {
+		t.Fatalf("Expected no candidates while the walk is still running, got %v", candidates)
+	}
+
+	close(release)
+	deadline := time.Now().Add(time.Second)
+	for {
+		candidates := cache.complete("@rep", fetch)
+		if strings.Join(candidates, ",") == "report.sql" {
+			break
+		}
+		if time.Now().After(deadline) {
+			t.Fatal("Expected the finished walk to be served on a later Tab")
+		}
+		time.Sleep(10 * time.Millisecond)
+	}
+	if fetches != 1 {
+		t.Errorf("Expected the cached walk to be reused, got %d walks", fetches)
+	}
+}
+
+func TestCompletionCache_DropsStaleResults(t *testing.T) {
+	var cache completionCache
+	release := make(chan struct{})
+
+	// The walk for the old input finishes only after the user typed more
+	cache.complete("@r", func() []string {
+		<-release
+		return []string{"report.sql", "rollup.sql"}
+	})
+	cache.complete("@re", func() []string { return []string{"report.sql"} })
+	close(release)
+	time.Sleep(50 * time.Millisecond)
+
+	cache.mu.Lock()
+	defer cache.mu.Unlock()
+	if cache.key != "@re" || len(cache.candidates) != 1 {
+		t.Errorf("Expected the late result for an older input to be dropped, got %q: %v", cache.key, cache.candidates)
+	}
+}
+
+func TestWaitForCompletion_RecoversFromPanic(t *testing.T) {
+	candidates := waitForCompletion(func() []string {
+		panic("boom")
+	})
+	if candidates != nil {
+		t.Errorf("Expected no candidates after a panic, got %v", candidates)
+	}
+}
@@ -0,0 +1,121 @@
+package conversation
+
+import (
+	"fmt"
+	"strconv"
+	"strings"
+	"time"
+
+	"sqlterm/internal/config"
+	"sqlterm/internal/core"
+)
+
+// lastUsedFormat shows when a connection was last used or failed, in local time
+const lastUsedFormat = "2006-01-02 15:04"
+
+// handleListConnections lists the saved connections with when each was last
+// used, or shows what is known about one without connecting to it:
+// /list-connections [name]
+func (a *App) handleListConnections(args []string) error {
+	if len(args) > 1 {
+		fmt.Println(a.i18nMgr.Get("usage_list_connections"))
+		return nil
+	}
+	if len(args) == 1 {
+		return a.showConnectionDetails(args[0])
+	}
+
+	connections, recent, err := a.configMgr.OrderedConnections(a.connectionOrder())
+	if err != nil {
+		return fmt.Errorf(a.i18nMgr.Get("failed_to_list_connections"), err)
+	}
+
+	if len(connections) == 0 {
+		fmt.Println(a.i18nMgr.Get("no_saved_connections_found"))
+		return nil
+	}
+
+	fmt.Println(a.i18nMgr.Get("saved_connections"))
+	color := colorEnabled()
+	usage := a.configMgr.LoadUsage()
+	now := time.Now()
+	for i, conn := range connections {
+		if header := config.ConnectionsHeader(a.i18nMgr, i, recent, len(connections)); header != "" {
+			fmt.Printf("  %s\n", header)
+		}
+		fmt.Printf("  %d. %s (%s) - %s://%s:%d/%s%s\n",
+			i+1,
+			conn.Label(conn.Name, color),
+			conn.TypeName(),
+			conn.TypeName(),
+			conn.Host,
+			conn.Port,
+			conn.Database,
+			a.connectionNote(usage[conn.Name], now))
+	}
+
+	return nil
+}
+
+// connectionNote ends a connection's list line with whether its last connect
+// failed, or else how long ago it was used
+func (a *App) connectionNote(usage config.ConnectionUsage, now time.Time) string {
+	switch {
+	case usage.LastError != "" && usage.LastErrorAt.After(usage.LastUsedAt):
+		return "  " + a.i18nMgr.Get("connection_last_failed")
+	case !usage.LastUsedAt.IsZero():
+		return "  " + a.i18nMgr.GetWithArgs("connection_used_ago", formatAge(now.Sub(usage.LastUsedAt)))
+	}
+	return ""
+}
+
+// showConnectionDetails prints the settings of a saved connection with what
+// was recorded on the last attempts to connect to it
+func (a *App) showConnectionDetails(name string) error {
+	config, err := a.loadConnection(name)
+	if err != nil {
+		return err
+	}
+	fmt.Printf("📋 %s\n", config.Label(config.Name, colorEnabled()))
+	fmt.Print(formatStatusFields(a.connectionDetails(config, a.configMgr.LoadUsage()[config.Name], time.Now())))
+	return nil
+}
+
+// connectionDetails gathers what is known about a saved connection from its
+// file and its recorded usage, without a network call
+func (a *App) connectionDetails(conn *core.ConnectionConfig, usage config.ConnectionUsage, now time.Time) []statusField {
+	label := a.i18nMgr.Get
+	fields := []statusField{{label("connection_detail_type"), conn.TypeName()}}
+	if conn.Environment != "" {
+		fields = append(fields, statusField{label("connection_detail_environment"), conn.Environment})
+	}
+	if b, ok := core.LookupBackend(conn.DatabaseType); !ok || !b.FileBased {
+		fields = append(fields, statusField{label("status_host"), conn.Host + ":" + strconv.Itoa(conn.Port)})
+	}
+	fields = append(fields, statusField{label("status_database"), conn.Database})
+	if conn.Username != "" {
+		fields = append(fields, statusField{label("status_user"), conn.Username})
+	}
+
+	password := label("connection_password_none")
+	if conn.Password != "" {
+		password = label("connection_password_saved")
+	}
+	fields = append(fields, statusField{label("connection_detail_password"), password})
+
+	lastUsed := label("connection_never_used")
+	if !usage.LastUsedAt.IsZero() {
+		lastUsed = a.i18nMgr.GetWithArgs("connection_last_used_value",
+			usage.LastUsedAt.Local().Format(lastUsedFormat), formatAge(now.Sub(usage.LastUsedAt)), usage.UseCount)
+	}
+	fields = append(fields, statusField{label("connection_detail_last_used"), lastUsed})
+	if usage.LastVersion != "" {
+		fields = append(fields, statusField{label("status_server_version"), usage.LastVersion})
+	}
+	if usage.LastError != "" {
+		message, _, _ := strings.Cut(usage.LastError, "\n")
+		fields = append(fields, statusField{label("connection_detail_last_error"),
+			fmt.Sprintf("%s  %s", usage.LastErrorAt.Local().Format(lastUsedFormat), message)})
+	}
+	return fields
+}
@@ -0,0 +1,72 @@
+package conversation
+
+import (
+	"strings"
+	"testing"
+	"time"
+
+	"sqlterm/internal/config"
+	"sqlterm/internal/core"
+)
+
+func TestApp_connectionNote(t *testing.T) {
+	app := createTestApp(t)
+	now := time.Now()
+
+	tests := []struct {
+		name  string
+		usage config.ConnectionUsage
+		want  string
+	}{
+		{"never used", config.ConnectionUsage{}, ""},
+		{"used", config.ConnectionUsage{LastUsedAt: now.Add(-3 * time.Hour)}, "  · used 3h ago"},
+		{"failed since", config.ConnectionUsage{LastUsedAt: now.Add(-3 * time.Hour), LastError: "refused", LastErrorAt: now}, "  ⚠️ last connect failed"},
+		{"failed before any use", config.ConnectionUsage{LastError: "refused", LastErrorAt: now}, "  ⚠️ last connect failed"},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := app.connectionNote(tt.usage, now); got != tt.want {
+				t.Errorf("connectionNote() = %q, want %q", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestApp_connectionDetails(t *testing.T) {
+	app := createTestApp(t)
+	now := time.Now()
+	conn := &core.ConnectionConfig{Name: "prod", DatabaseType: core.PostgreSQL, Host: "db.internal", Port: 5432,
+		Database: "shop", Username: "admin", Password: "secret", Environment: "production"}
+	usage := config.ConnectionUsage{
+		LastUsedAt:  now.Add(-2 * time.Hour),
+		UseCount:    7,
+		LastVersion: "PostgreSQL 16.2",
+		LastError:   "connection test failed: connection refused\nretry later",
+		LastErrorAt: now,
+	}
+
+	details := formatStatusFields(app.connectionDetails(conn, usage, now))
+	for _, want := range []string{
+		"Type         postgres",
+		"Environment  production",
+		"Host         db.internal:5432",
+		"User         admin",
+		"Password     saved in the connection file",
+		"(2h ago, 7 connects)",
+		"Server       PostgreSQL 16.2",
+		"connection refused\n",
+	} {
+		if !strings.Contains(details, want) {
+			t.Errorf("Expected the details to contain %q, got:\n%s", want, details)
+		}
+	}
+	if strings.Contains(details, "secret") || strings.Contains(details, "retry later") {
+		t.Errorf("Expected no password and only the first line of the error, got:\n%s", details)
+	}
+
+	// A SQLite file that was never opened has no host, password or history
+	details = formatStatusFields(app.connectionDetails(&core.ConnectionConfig{Name: "local", DatabaseType: core.SQLite, Database: "app.db"}, config.ConnectionUsage{}, now))
+	if strings.Contains(details, "Host") || !strings.Contains(details, "Password   none saved") || !strings.Contains(details, "Last used  never") {
+		t.Errorf("Unexpected details for an unused SQLite connection:\n%s", details)
+	}
+}
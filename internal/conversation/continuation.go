@@ -0,0 +1,94 @@
+package conversation
+
+import (
+	"fmt"
+	"io"
+	"strings"
+
+	"sqlterm/internal/core"
+
+	"github.com/chzyer/readline"
+)
+
+// continuationPrompt is shown while an /exec statement is still open
+const continuationPrompt = "....> "
+
+// needsContinuation reports whether line is an /exec statement that is not
+// finished yet: a quote, comment or parenthesis is open, or it has no
+// terminating semicolon
+func (a *App) needsContinuation(line string) bool {
+	if a.singleLineInput() {
+		return false
+	}
+	query, ok := execQuery(line)
+	return ok && query != "" && !core.StatementComplete(a.dbType(), query)
+}
+
+// continueInput reads continuation lines until the statement started on line
+// is terminated, or Enter is pressed on an empty line to run it as it is. It
+// returns "" when the input is cancelled with Ctrl+C or Ctrl+D, and the /quit
+// typed when leaving is confirmed. The lines are kept as a draft meanwhile.
+func (a *App) continueInput(line string) string {
+	if a.rl == nil || !a.needsContinuation(line) {
+		return line
+	}
+
+	a.rl.HistoryDisable()
+	defer a.rl.HistoryEnable()
+	a.rl.SetPrompt(continuationPrompt)
+	defer a.updatePrompt()
+
+	lines := []string{line}
+	a.saveDraft(line)
+	defer a.clearDraft()
+	for {
+		next, err := a.rl.Readline()
+		if (err == readline.ErrInterrupt || err == io.EOF || isQuit(next)) && a.keepInput(lines) {
+			a.rl.HistoryDisable() // confirm re-enables history on return
+			a.rl.SetPrompt(continuationPrompt)
+			continue
+		}
+		if err != nil {
+			fmt.Println(a.i18nMgr.Get("multi_line_input_cancelled"))
+			return ""
+		}
+		if isQuit(next) {
+			// Discarding was confirmed; hand /quit back to be run
+			return strings.TrimSpace(next)
+		}
+
+		next = strings.TrimRight(next, " \t")
+		if strings.TrimSpace(next) == "" {
+			break
+		}
+		if text, n := expandPaste(next); n > 1 {
+			a.showPaste(text, n)
+			next = text
+		}
+		lines = append(lines, next)
+		a.saveDraft(strings.Join(lines, " "))
+		if query, _ := execQuery(strings.Join(lines, "\n")); core.StatementComplete(a.dbType(), query) {
+			break
+		}
+	}
+
+	// Keep the whole statement as one history entry, as multi-line /exec does
+	full := strings.Join(lines, "\n")
+	if err := a.rl.SaveHistory(a.historyEntry(strings.ReplaceAll(full, "\n", " "))); err != nil {
+		fmt.Printf(a.i18nMgr.Get("failed_save_command_history_warning"), err)
+	}
+	return full
+}
+
+// execQuery returns the query of an /exec line
+func execQuery(line string) (string, bool) {
+	if fields := strings.Fields(line); len(fields) == 0 || fields[0] != "/exec" {
+		return "", false
+	}
+	return strings.TrimSpace(strings.TrimPrefix(strings.TrimSpace(line), "/exec")), true
+}
+
+// singleLineInput reports whether Enter always submits, as set by single_line in the config file
+func (a *App) singleLineInput() bool {
+	return a.aiManager != nil && a.aiManager.GetConfig().SingleLine
+}
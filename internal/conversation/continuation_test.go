@@ -0,0 +1,56 @@
+package conversation
+
+import "testing"
+
+func TestApp_needsContinuation(t *testing.T) {
+	app := createTestApp(t)
+
+	testCases := []struct {
+		line     string
+		expected bool
+	}{
+		{"/exec SELECT 1;", false},
+		{"/exec SELECT 1", true},
+		{"/exec WITH t AS (", true},
+		{"/exec SELECT 'a;", true},
+		{"/exec SELECT 1; > out.csv", false},
+		{"/exec", false},
+		{"/execute SELECT 1", false},
+		{"/tables", false},
+		{"how many users are there", false},
+		{"@report.sql", false},
+	}
+	for _, tc := range testCases {
+		if got := app.needsContinuation(tc.line); got != tc.expected {
+			t.Errorf("needsContinuation(%q) = %v, expected %v", tc.line, got, tc.expected)
+		}
+	}
+
+	if app.aiManager == nil {
+		t.Skip("AI manager unavailable")
+	}
+	app.aiManager.GetConfig().SingleLine = true
+	if app.needsContinuation("/exec SELECT 1") {
+		t.Error("Expected single_line to run /exec on the first Enter")
+	}
+}
+
+func TestExecQuery(t *testing.T) {
+	testCases := []struct {
+		line   string
+		query  string
+		isExec bool
+	}{
+		{"/exec SELECT 1", "SELECT 1", true},
+		{"  /exec\tSELECT 1 -- c\nFROM t;", "SELECT 1 -- c\nFROM t;", true},
+		{"/exec", "", true},
+		{"/execute x", "", false},
+		{"SELECT 1", "", false},
+	}
+	for _, tc := range testCases {
+		query, isExec := execQuery(tc.line)
+		if query != tc.query || isExec != tc.isExec {
+			t.Errorf("execQuery(%q) = %q, %v, expected %q, %v", tc.line, query, isExec, tc.query, tc.isExec)
+		}
+	}
+}
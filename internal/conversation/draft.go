@@ -0,0 +1,78 @@
+package conversation
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+)
+
+// draftFile keeps the statement being typed in the session directory, so a
+// crash or a closed terminal does not lose it. The next connect puts it back
+// on the prompt line.
+const draftFile = "draft.txt"
+
+// inputDirty reports whether lines hold anything leaving now would lose
+func inputDirty(lines []string) bool {
+	for _, line := range lines {
+		if strings.TrimSpace(line) != "" {
+			return true
+		}
+	}
+	return false
+}
+
+// isQuit reports whether line asks to leave sqlterm
+func isQuit(line string) bool {
+	switch strings.TrimSpace(line) {
+	case "/quit", "/exit":
+		return true
+	}
+	return false
+}
+
+// keepInput asks before Ctrl+C, Ctrl+D or /quit throws away the lines typed
+// so far, and reports whether to go on reading them instead
+func (a *App) keepInput(lines []string) bool {
+	return inputDirty(lines) && !a.confirm(fmt.Sprintf(a.i18nMgr.Get("confirm_discard_multiline"), len(lines)))
+}
+
+// draftPath returns where the connection's unsent input is kept, or "" when not connected
+func (a *App) draftPath() string {
+	if a.config == nil || a.sessionMgr == nil {
+		return ""
+	}
+	return filepath.Join(a.sessionMgr.GetSessionDir(a.config.Name), draftFile)
+}
+
+// saveDraft keeps line, the input as it would be typed at the prompt, until
+// clearDraft. A draft that cannot be written is not worth interrupting typing for.
+func (a *App) saveDraft(line string) {
+	if path := a.draftPath(); path != "" {
+		_ = os.WriteFile(path, []byte(line), 0600)
+	}
+}
+
+// clearDraft forgets the unsent input once it has run or been discarded
+func (a *App) clearDraft() {
+	if path := a.draftPath(); path != "" {
+		_ = os.Remove(path)
+	}
+}
+
+// restoreDraft puts input left by a session that ended mid-statement back on
+// the prompt line
+func (a *App) restoreDraft() {
+	path := a.draftPath()
+	if path == "" || a.rl == nil {
+		return
+	}
+	data, err := os.ReadFile(path)
+	if err != nil || !inputDirty([]string{string(data)}) {
+		return
+	}
+	a.clearDraft()
+	if _, err := a.rl.WriteStdin(data); err == nil {
+		a.notify(NotifyInfo, a.i18nMgr.Get("draft_restored"))
+	}
+}
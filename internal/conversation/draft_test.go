@@ -0,0 +1,76 @@
+package conversation
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+
+	"sqlterm/internal/core"
+)
+
+func TestInputDirty(t *testing.T) {
+	tests := []struct {
+		name  string
+		lines []string
+		want  bool
+	}{
+		{"nothing typed", nil, false},
+		{"empty lines", []string{"", ""}, false},
+		{"whitespace only", []string{"   ", "\t"}, false},
+		{"a statement", []string{"/exec SELECT *", "  FROM orders"}, true},
+		{"text after blank lines", []string{"", " WHERE id = 1"}, true},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := inputDirty(tt.lines); got != tt.want {
+				t.Errorf("inputDirty(%q) = %v, want %v", tt.lines, got, tt.want)
+			}
+		})
+	}
+}
+
+func TestIsQuit(t *testing.T) {
+	for _, line := range []string{"/quit", "/exit", "  /quit "} {
+		if !isQuit(line) {
+			t.Errorf("Expected %q to quit", line)
+		}
+	}
+	for _, line := range []string{"", "/quitx", "/quit now", "quit"} {
+		if isQuit(line) {
+			t.Errorf("Expected %q not to quit", line)
+		}
+	}
+}
+
+func TestApp_keepInput(t *testing.T) {
+	app := createTestApp(t)
+
+	if app.keepInput([]string{"", "  "}) {
+		t.Error("Expected blank input to be left without asking")
+	}
+	// Without a terminal to confirm on, discarding is declined
+	if !app.keepInput([]string{"/exec SELECT 1"}) {
+		t.Error("Expected typed input to be kept unless discarding is confirmed")
+	}
+}
+
+func TestApp_draft(t *testing.T) {
+	app := createTestApp(t)
+	app.saveDraft("/exec SELECT 1")
+	app.clearDraft()
+
+	app.config = &core.ConnectionConfig{Name: "shop"}
+	path := app.draftPath()
+	if err := os.MkdirAll(filepath.Dir(path), 0755); err != nil {
+		t.Fatal(err)
+	}
+
+	app.saveDraft("/exec SELECT * FROM orders")
+	if data, err := os.ReadFile(path); err != nil || string(data) != "/exec SELECT * FROM orders" {
+		t.Errorf("Expected the draft to be saved, got %q (%v)", data, err)
+	}
+	app.clearDraft()
+	if _, err := os.Stat(path); !os.IsNotExist(err) {
+		t.Errorf("Expected the draft to be removed, got %v", err)
+	}
+}
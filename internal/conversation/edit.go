@@ -0,0 +1,174 @@
+package conversation
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"strings"
+
+	"sqlterm/internal/core"
+)
+
+// handleEdit changes one cell of the last result: /edit <row> <column> <value>.
+// The UPDATE is shown for review and runs with bind parameters once confirmed.
+func (a *App) handleEdit(line string) error {
+	if a.connection == nil {
+		fmt.Println(a.i18nMgr.Get("no_database_connection"))
+		return nil
+	}
+
+	rowArg, column, value, ok := splitEditArgs(line)
+	if !ok {
+		fmt.Println(a.i18nMgr.Get("usage_edit"))
+		return nil
+	}
+	if a.lastStored == nil {
+		fmt.Println(a.i18nMgr.Get("no_result_to_edit"))
+		return nil
+	}
+
+	row, err := a.resultRow(rowArg)
+	if err != nil {
+		return err
+	}
+
+	edit, columnIndex, err := a.cellEdit(a.lastStored.Rows[row], column, parseEditValue(value))
+	if err != nil {
+		return fmt.Errorf(a.i18nMgr.Get("edit_not_possible"), err)
+	}
+
+	preview := edit.Preview(a.config.DatabaseType)
+	if err := a.checkReadOnly(preview); err != nil {
+		return err
+	}
+	fmt.Printf("\n%s\n\n", preview)
+	if !a.confirm(a.i18nMgr.Get("confirm_edit")) {
+		a.notify(NotifyInfo, a.i18nMgr.Get("edit_cancelled"))
+		return nil
+	}
+
+	if err := a.runEdit(edit); err != nil {
+		return fmt.Errorf(a.i18nMgr.Get("edit_failed"), err)
+	}
+
+	// Keep the stored row in step with the table so /more and later edits see the new value
+	cell := &a.lastStored.Rows[row][columnIndex]
+	*cell = edit.StoredValue(*cell)
+	a.notify(NotifySuccess, a.i18nMgr.Get("edit_applied"), edit.Column, row+1)
+	return nil
+}
+
+// cellEdit builds the edit of column in row of the last result. The result must
+// come from a single-table SELECT of plain columns that includes every primary
+// key column.
+func (a *App) cellEdit(row []core.Value, column string, value *string) (core.CellEdit, int, error) {
+	table, err := core.EditableTable(a.dbType(), a.lastQuery)
+	if err != nil {
+		return core.CellEdit{}, 0, err
+	}
+
+	info, _, err := a.cachedTable(table)
+	if err != nil {
+		return core.CellEdit{}, 0, err
+	}
+	if len(info.PrimaryKeys) == 0 {
+		return core.CellEdit{}, 0, core.ErrEditNoPrimaryKey
+	}
+
+	columnIndex := resultColumn(a.lastStored.Columns, column)
+	if columnIndex < 0 || !tableHasColumn(info, a.lastStored.Columns[columnIndex].Name) {
+		return core.CellEdit{}, 0, fmt.Errorf(a.i18nMgr.Get("edit_unknown_column"), column, table)
+	}
+
+	edit := core.CellEdit{Table: table, Column: a.lastStored.Columns[columnIndex].Name, Value: value}
+	for _, key := range info.PrimaryKeys {
+		keyIndex := resultColumn(a.lastStored.Columns, key)
+		if keyIndex < 0 {
+			return core.CellEdit{}, 0, core.ErrEditNoPrimaryKey
+		}
+		if row[keyIndex].IsNull() {
+			return core.CellEdit{}, 0, core.ErrEditNoPrimaryKey
+		}
+		edit.Key = append(edit.Key, core.KeyValue{Column: key, Value: row[keyIndex]})
+	}
+	return edit, columnIndex, nil
+}
+
+// runEdit executes the UPDATE for edit with its values bound as parameters.
+// When the connection reports changed rows, anything but one is an error.
+func (a *App) runEdit(edit core.CellEdit) error {
+	ctx, stop := a.interruptContext(context.Background())
+	defer stop()
+
+	query, args := edit.Statement(a.config.DatabaseType)
+	if executor, ok := a.connection.(core.RowsExecutor); ok {
+		rows, err := executor.ExecParams(ctx, query, args...)
+		// An audited connection answers this even when the one it wraps cannot
+		if !errors.Is(err, core.ErrParamsNotSupported) {
+			if err == nil && rows != 1 {
+				err = fmt.Errorf("%w: %d rows changed", core.ErrEditRowCount, rows)
+			}
+			return err
+		}
+	}
+
+	executor, ok := a.connection.(core.ParamExecutor)
+	if !ok {
+		return core.ErrParamsNotSupported
+	}
+	result, err := executor.ExecuteParams(ctx, query, args...)
+	if err != nil {
+		return err
+	}
+	for range result.Itor() {
+	}
+	err = result.Error()
+	result.Close()
+	return err
+}
+
+// splitEditArgs splits "/edit <row> <column> <value>"; the value keeps its inner spaces
+func splitEditArgs(line string) (row, column, value string, ok bool) {
+	rest := strings.TrimSpace(strings.TrimPrefix(strings.TrimSpace(line), "/edit"))
+	row, rest, _ = strings.Cut(rest, " ")
+	column, value, _ = strings.Cut(strings.TrimLeft(rest, " "), " ")
+	value = strings.TrimSpace(value)
+	return row, column, value, row != "" && column != "" && value != ""
+}
+
+// parseEditValue reads the new cell value: NULL sets NULL, and a value in
+// single quotes is taken literally so 'NULL' stays a string
+func parseEditValue(text string) *string {
+	if strings.EqualFold(text, "NULL") {
+		return nil
+	}
+	if len(text) >= 2 && text[0] == '\'' && text[len(text)-1] == '\'' {
+		text = strings.ReplaceAll(text[1:len(text)-1], "''", "'")
+	}
+	return &text
+}
+
+// resultColumn returns the index of the result column called name, or -1
+func resultColumn(columns []core.Column, name string) int {
+	for i, column := range columns {
+		if column.Name == name {
+			return i
+		}
+	}
+	for i, column := range columns {
+		if strings.EqualFold(column.Name, name) {
+			return i
+		}
+	}
+	return -1
+}
+
+// tableHasColumn reports whether name is a column of the table rather than an expression
+func tableHasColumn(info *core.TableInfo, name string) bool {
+	for _, column := range info.Columns {
+		if strings.EqualFold(column.Name, name) {
+			return true
+		}
+	}
+	return false
+}
@@ -0,0 +1,102 @@
+package conversation
+
+import (
+	"bytes"
+	"context"
+	"errors"
+	"reflect"
+	"testing"
+
+	"sqlterm/internal/core"
+)
+
+func TestApp_Flow_EditCell(t *testing.T) {
+	app := createTestApp(t)
+	update := "UPDATE `users` SET `name` = ? WHERE `id` = ?"
+	conn := newMockUsersConnection().AddResponse(update, nil, nil).AddRowsAffected(update, 1)
+	app.connection = conn
+	app.config = &core.ConnectionConfig{Name: "mock-db", DatabaseType: core.MySQL}
+
+	if err := app.handleEdit("/edit 2 name bob"); err != nil {
+		t.Fatalf("Expected a message rather than an error without a result, got %v", err)
+	}
+
+	var buf bytes.Buffer
+	if err := app.processQuery(context.Background(), "SELECT id, name FROM users", &buf); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+
+	edit, column, err := app.cellEdit(app.lastStored.Rows[1], "NAME", parseEditValue("'bob smith'"))
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if column != 1 || edit.Table != "users" || *edit.Value != "bob smith" || len(edit.Key) != 1 {
+		t.Errorf("Expected an edit of users.name for id 2, got %+v", edit)
+	}
+
+	if err := app.runEdit(edit); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	queries := conn.ExecutedQueries()
+	if queries[len(queries)-1] != update {
+		t.Errorf("Expected the UPDATE with placeholders, got %v", queries)
+	}
+	if params := conn.ExecutedParams(); !reflect.DeepEqual(params, [][]any{{"bob smith", int64(2)}}) {
+		t.Errorf("Expected the value and key to be bound, got %v", params)
+	}
+
+	conn.AddRowsAffected(update, 0)
+	if err := app.runEdit(edit); !errors.Is(err, core.ErrEditRowCount) {
+		t.Errorf("Expected an edit that changed no row to fail, got %v", err)
+	}
+}
+
+func TestApp_cellEdit_Refused(t *testing.T) {
+	app := createTestApp(t)
+	conn := newMockUsersConnection().
+		AddResponse("SELECT name FROM users", []core.Column{{Name: "name"}}, [][]core.Value{{core.StringValue{Value: "alice"}}}).
+		AddResponse("SELECT u.id FROM users u JOIN orders o ON o.user_id = u.id", []core.Column{{Name: "id"}}, [][]core.Value{{core.IntValue{Value: 1}}}).
+		AddResponse("SELECT id, email AS name FROM users", []core.Column{{Name: "id"}, {Name: "name"}}, [][]core.Value{{core.IntValue{Value: 1}, core.StringValue{Value: "a@example.com"}}}).
+		AddResponse("SELECT other AS id, name FROM users", []core.Column{{Name: "id"}, {Name: "name"}}, [][]core.Value{{core.IntValue{Value: 9}, core.StringValue{Value: "alice"}}})
+	app.connection = conn
+	app.config = &core.ConnectionConfig{Name: "mock-db"}
+
+	tests := []struct {
+		query   string
+		column  string
+		wantErr error
+	}{
+		{"SELECT name FROM users", "name", core.ErrEditNoPrimaryKey},
+		{"SELECT u.id FROM users u JOIN orders o ON o.user_id = u.id", "id", core.ErrJoinedResult},
+		{"SELECT id, email AS name FROM users", "name", core.ErrComputedColumns},
+		{"SELECT other AS id, name FROM users", "name", core.ErrComputedColumns},
+	}
+
+	for _, tt := range tests {
+		var buf bytes.Buffer
+		if err := app.processQuery(context.Background(), tt.query, &buf); err != nil {
+			t.Fatalf("Unexpected error: %v", err)
+		}
+		value := "x"
+		if _, _, err := app.cellEdit(app.lastStored.Rows[0], tt.column, &value); !errors.Is(err, tt.wantErr) {
+			t.Errorf("%s: expected %v, got %v", tt.query, tt.wantErr, err)
+		}
+	}
+}
+
+func TestParseEditValue(t *testing.T) {
+	if parseEditValue("null") != nil {
+		t.Error("Expected NULL to set NULL")
+	}
+	if value := parseEditValue("'NULL'"); value == nil || *value != "NULL" {
+		t.Errorf("Expected a quoted NULL to stay a string, got %v", value)
+	}
+	if value := parseEditValue("'it''s'"); *value != "it's" {
+		t.Errorf("Expected doubled quotes to be unescaped, got %q", *value)
+	}
+
+	row, column, value, ok := splitEditArgs("/edit 3 note two  words")
+	if !ok || row != "3" || column != "note" || value != "two  words" {
+		t.Errorf("Expected the value to keep its spaces, got %q %q %q", row, column, value)
+	}
+}
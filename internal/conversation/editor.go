@@ -0,0 +1,133 @@
+package conversation
+
+import (
+	"fmt"
+	"os"
+	"os/exec"
+	"runtime"
+	"strings"
+
+	"sqlterm/internal/core"
+
+	"github.com/chzyer/readline"
+	"golang.org/x/term"
+)
+
+// defaultEditor is used when neither the editor setting nor $VISUAL or $EDITOR is set
+func defaultEditor() string {
+	if runtime.GOOS == "windows" {
+		return "notepad"
+	}
+	return "vi"
+}
+
+// editorCommand is the configured editor, then $VISUAL, then $EDITOR, then the platform default
+func (a *App) editorCommand() string {
+	if a.aiManager != nil {
+		if editor := strings.TrimSpace(a.aiManager.GetConfig().Editor); editor != "" {
+			return editor
+		}
+	}
+	for _, name := range []string{"VISUAL", "EDITOR"} {
+		if editor := strings.TrimSpace(os.Getenv(name)); editor != "" {
+			return editor
+		}
+	}
+	return defaultEditor()
+}
+
+// editText opens text in the editor as a temporary .sql file and returns what was saved
+func (a *App) editText(text string) (string, error) {
+	file, err := os.CreateTemp("", "sqlterm-*.sql")
+	if err != nil {
+		return "", err
+	}
+	defer os.Remove(file.Name())
+
+	_, err = file.WriteString(text)
+	if closeErr := file.Close(); err == nil {
+		err = closeErr
+	}
+	if err != nil {
+		return "", err
+	}
+
+	if err := a.runEditor(file.Name()); err != nil {
+		return "", err
+	}
+	data, err := os.ReadFile(file.Name())
+	return string(data), err
+}
+
+// runEditor opens path in the editor with the terminal handed over to it.
+// Readline is closed first so it stops reading stdin and is opened again
+// afterwards, and the terminal mode is put back even when the editor crashes
+// or leaves the terminal raw.
+func (a *App) runEditor(path string) error {
+	fields := strings.Fields(a.editorCommand())
+	cmd := exec.Command(fields[0], append(fields[1:], path)...)
+	cmd.Stdin, cmd.Stdout, cmd.Stderr = os.Stdin, os.Stdout, os.Stderr
+
+	if a.rl != nil {
+		config := a.rl.Config
+		a.rl.Close()
+		defer func() {
+			rl, err := readline.NewEx(config)
+			if err != nil {
+				fmt.Printf(a.i18nMgr.Get("generic_warning"), err)
+				return
+			}
+			a.rl = rl
+		}()
+	}
+
+	fd := int(os.Stdin.Fd())
+	if state, err := term.GetState(fd); err == nil {
+		defer term.Restore(fd, state)
+	}
+
+	if err := cmd.Run(); err != nil {
+		return fmt.Errorf(a.i18nMgr.Get("editor_failed"), fields[0], err)
+	}
+	return nil
+}
+
+// handleEditor opens the last query, or an empty file, in the external editor
+// and runs the saved statements after confirming how many there are
+func (a *App) handleEditor() error {
+	if a.connection == nil {
+		fmt.Println(a.i18nMgr.Get("no_database_connection"))
+		return nil
+	}
+
+	text, err := a.editText(a.lastQuery)
+	if err != nil {
+		return err
+	}
+
+	statements := core.SplitStatements(a.dbType(), text)
+	if len(statements) == 0 {
+		fmt.Println(a.i18nMgr.Get("editor_nothing_to_run"))
+		return nil
+	}
+
+	fmt.Println(strings.TrimSpace(text))
+	if !a.confirm(fmt.Sprintf(a.i18nMgr.Get("confirm_run_edited"), len(statements))) {
+		a.notify(NotifyInfo, a.i18nMgr.Get("editor_run_cancelled"))
+		return nil
+	}
+	return a.runStatements(statements)
+}
+
+// editBuffer opens the active query buffer in the external editor and keeps what was saved
+func (a *App) editBuffer() error {
+	text, err := a.editText(a.buffers.current().Query)
+	if err != nil {
+		return err
+	}
+
+	a.buffers.setQuery(strings.TrimRight(text, "\n"))
+	a.saveBuffers()
+	a.showBuffer()
+	return nil
+}
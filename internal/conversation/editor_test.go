@@ -0,0 +1,62 @@
+package conversation
+
+import (
+	"os"
+	"path/filepath"
+	"runtime"
+	"strings"
+	"testing"
+)
+
+// fakeEditor installs a shell script as $VISUAL that appends line to the file it is given
+func fakeEditor(t *testing.T, line string) {
+	t.Helper()
+	if runtime.GOOS == "windows" {
+		t.Skip("shell script editor")
+	}
+
+	script := filepath.Join(t.TempDir(), "editor.sh")
+	content := "#!/bin/sh\nprintf '%s\\n' '" + line + "' >> \"$1\"\n"
+	if err := os.WriteFile(script, []byte(content), 0755); err != nil {
+		t.Fatalf("Failed to write editor script: %v", err)
+	}
+	t.Setenv("VISUAL", script)
+}
+
+func TestApp_editText(t *testing.T) {
+	app := createTestApp(t)
+	app.aiManager = nil
+	fakeEditor(t, "SELECT 2;")
+
+	text, err := app.editText("SELECT 1;\n")
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if text != "SELECT 1;\nSELECT 2;\n" {
+		t.Errorf("Expected the saved file to be read back, got %q", text)
+	}
+}
+
+func TestApp_editTextReportsEditorFailure(t *testing.T) {
+	app := createTestApp(t)
+	app.aiManager = nil
+	t.Setenv("VISUAL", filepath.Join(t.TempDir(), "missing-editor"))
+
+	if _, err := app.editText(""); err == nil || !strings.Contains(err.Error(), "missing-editor") {
+		t.Errorf("Expected an error naming the editor, got %v", err)
+	}
+}
+
+func TestApp_editBuffer(t *testing.T) {
+	app := createTestApp(t)
+	app.aiManager = nil
+	app.buffers = loadQueryBuffers(filepath.Join(t.TempDir(), "buffers.yaml"))
+	fakeEditor(t, "SELECT 3;")
+
+	if err := app.editBuffer(); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if buffer := app.buffers.current(); buffer.Query != "SELECT 3;" || !buffer.Dirty {
+		t.Errorf("Expected the edited query to be kept and marked unsaved, got %+v", buffer)
+	}
+}
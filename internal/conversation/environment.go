@@ -0,0 +1,67 @@
+package conversation
+
+import (
+	"fmt"
+	"io"
+	"os"
+
+	"sqlterm/internal/config"
+	"sqlterm/internal/core"
+
+	"golang.org/x/term"
+)
+
+// colorEnabled reports whether output may use ANSI colors
+func colorEnabled() bool {
+	return colorEnabledFor(os.Stdout)
+}
+
+// colorEnabledFor reports whether output written to w may use ANSI colors.
+// Only a terminal gets them; buffers, pipes and files never do.
+func colorEnabledFor(w io.Writer) bool {
+	f, ok := w.(*os.File)
+	return ok && os.Getenv("NO_COLOR") == "" && term.IsTerminal(int(f.Fd()))
+}
+
+// hyperlinksEnabled reports whether result cells holding URLs may be shown as
+// OSC 8 hyperlinks under mode. Output that is not a terminal never gets them.
+func hyperlinksEnabled(mode string) bool {
+	if mode == config.HyperlinksOff || !term.IsTerminal(int(os.Stdout.Fd())) {
+		return false
+	}
+	return mode == config.HyperlinksOn || core.TerminalSupportsHyperlinks(os.Getenv)
+}
+
+// withHyperlinks has opts collect the URL cells it formats for the next
+// result shown, when hyperlinks are enabled
+func (a *App) withHyperlinks(opts core.FormatOptions) core.FormatOptions {
+	if !hyperlinksEnabled(a.displayConfig().HyperlinksMode()) {
+		return opts
+	}
+	if a.links == nil {
+		a.links = core.Hyperlinks{}
+	}
+	opts.Hyperlinks = a.links
+	return opts
+}
+
+// takeHyperlinks returns the URL cells collected for the result being shown and starts afresh
+func (a *App) takeHyperlinks() core.Hyperlinks {
+	links := a.links
+	a.links = nil
+	return links
+}
+
+// allowDestructive asks before a statement that modifies data runs on a
+// connection that confirms them, such as one tagged production
+func (a *App) allowDestructive(query string) bool {
+	if a.config == nil || !a.config.ConfirmsDestructive() || core.IsReadOnlyQuery(a.dbType(), query) {
+		return true
+	}
+
+	if a.confirm(fmt.Sprintf(a.i18nMgr.Get("confirm_destructive_query"), a.config.Label(a.config.Name, colorEnabled()))) {
+		return true
+	}
+	a.notify(NotifyInfo, a.i18nMgr.Get("destructive_query_cancelled"))
+	return false
+}
@@ -0,0 +1,37 @@
+package conversation
+
+import (
+	"bytes"
+	"context"
+	"testing"
+
+	"sqlterm/internal/core"
+)
+
+func TestApp_Flow_ProductionConfirmsDestructive(t *testing.T) {
+	app := createTestApp(t)
+	conn := newMockUsersConnection().AddResponse("DELETE FROM users", nil, nil)
+	app.connection = conn
+	app.config = &core.ConnectionConfig{Name: "prod-db", Environment: core.EnvironmentProduction}
+
+	var buf bytes.Buffer
+	if err := app.processQuery(context.Background(), "SELECT id, name FROM users", &buf); err != nil {
+		t.Fatalf("Expected reads to run without confirmation, got %v", err)
+	}
+
+	// Without a terminal to answer, the confirmation counts as no
+	if err := app.processQuery(context.Background(), "DELETE FROM users", &buf); err != nil {
+		t.Fatalf("Expected a declined statement not to be an error, got %v", err)
+	}
+	if queries := conn.ExecutedQueries(); len(queries) != 1 {
+		t.Errorf("Expected the declined statement not to reach the connection, got %v", queries)
+	}
+
+	app.config = &core.ConnectionConfig{Name: "dev-db", Environment: "development"}
+	if err := app.processQuery(context.Background(), "DELETE FROM users", &buf); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if queries := conn.ExecutedQueries(); len(queries) != 2 {
+		t.Errorf("Expected development connections to run statements without asking, got %v", queries)
+	}
+}
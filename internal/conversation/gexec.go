@@ -0,0 +1,58 @@
+package conversation
+
+import (
+	"fmt"
+	"strings"
+
+	"sqlterm/internal/core"
+)
+
+// handleGexec runs each cell of the first column of the last result as a
+// statement, like psql's \gexec, once the list is confirmed: /gexec. The
+// results are numbered as usual and refer back to the result that made them.
+func (a *App) handleGexec() error {
+	if a.connection == nil {
+		fmt.Println(a.i18nMgr.Get("no_database_connection"))
+		return nil
+	}
+	if a.lastStored == nil || len(a.lastStored.Columns) == 0 {
+		fmt.Println(a.i18nMgr.Get("no_result_to_gexec"))
+		return nil
+	}
+
+	statements, skipped := cellStatements(a.lastStored)
+	if len(statements) == 0 {
+		fmt.Printf(a.i18nMgr.Get("gexec_nothing_to_run"), a.lastStored.Columns[0].Name)
+		return nil
+	}
+	fmt.Printf(a.i18nMgr.Get("gexec_statements"), len(statements), a.lastStored.Columns[0].Name)
+	for i, statement := range statements {
+		fmt.Printf("  %d. %s\n", i+1, a.truncateQuery(statement))
+	}
+	if skipped > 0 {
+		fmt.Printf(a.i18nMgr.Get("gexec_skipped"), skipped)
+	}
+	if !a.confirm(fmt.Sprintf(a.i18nMgr.Get("confirm_gexec"), len(statements))) {
+		a.notify(NotifyInfo, a.i18nMgr.Get("gexec_not_run"))
+		return nil
+	}
+
+	a.generatedBy = a.lastStored.Number
+	defer func() { a.generatedBy = 0 }()
+	return a.runStatements(statements)
+}
+
+// cellStatements returns the first column of stored as statements, leaving
+// out NULL and blank cells, and how many were left out
+func cellStatements(stored *core.StoredResult) ([]string, int) {
+	var statements []string
+	skipped := 0
+	for _, row := range stored.Rows {
+		if len(row) == 0 || row[0] == nil || row[0].IsNull() || strings.TrimSpace(row[0].String()) == "" {
+			skipped++
+			continue
+		}
+		statements = append(statements, strings.TrimSpace(row[0].String()))
+	}
+	return statements, skipped
+}
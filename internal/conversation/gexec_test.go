@@ -0,0 +1,61 @@
+package conversation
+
+import (
+	"bytes"
+	"context"
+	"testing"
+
+	"sqlterm/internal/core"
+)
+
+func TestCellStatements(t *testing.T) {
+	stored := &core.StoredResult{
+		Columns: []core.Column{{Name: "stmt"}, {Name: "note"}},
+		Rows: [][]core.Value{
+			{core.StringValue{Value: "DROP TABLE tmp_a;"}, core.StringValue{Value: "x"}},
+			{core.StringValue{Null: true}, core.StringValue{Value: "x"}},
+			{core.StringValue{Value: "  "}, core.StringValue{Value: "x"}},
+			{core.StringValue{Value: "\n  DROP TABLE tmp_b;\n"}, core.NullValue{}},
+		},
+	}
+
+	statements, skipped := cellStatements(stored)
+	if len(statements) != 2 || statements[0] != "DROP TABLE tmp_a;" || statements[1] != "DROP TABLE tmp_b;" {
+		t.Errorf("Expected the two statements of the first column, got %q", statements)
+	}
+	if skipped != 2 {
+		t.Errorf("Expected the NULL and blank cells to be skipped, got %d", skipped)
+	}
+}
+
+func TestApp_recordResult_GeneratedBy(t *testing.T) {
+	t.Setenv("SQLTERM_CONFIG_DIR", t.TempDir())
+	app := createTestApp(t)
+	app.aiManager = nil
+	app.results = core.NewResultStore(core.DefaultResultCapacity)
+	app.connection = newMockOrdersConnection()
+	app.config = &core.ConnectionConfig{Name: "mock-db", DatabaseType: core.MySQL}
+
+	var buf bytes.Buffer
+	if err := app.processQuery(context.Background(), "SELECT * FROM orders", &buf); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	app.generatedBy = app.lastStored.Number
+	if err := app.processQuery(context.Background(), "SELECT * FROM orders", &buf); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+
+	entry, ok := app.results.Get(2)
+	if !ok || entry.GeneratedBy != 1 {
+		t.Errorf("Expected result #2 to refer to #1, got %+v", entry)
+	}
+}
+
+func TestApp_handleGexec_NoResult(t *testing.T) {
+	app := createTestApp(t)
+	app.connection = newMockOrdersConnection()
+
+	if err := app.handleGexec(); err != nil {
+		t.Errorf("Expected only a message without a result, got %v", err)
+	}
+}
@@ -0,0 +1,121 @@
+package conversation
+
+import (
+	"fmt"
+	"io"
+	"os"
+	"strings"
+
+	"sqlterm/internal/core"
+
+	"github.com/chzyer/readline"
+)
+
+// clearScreenSequence moves the cursor home and erases the terminal
+const clearScreenSequence = "\033[H\033[2J"
+
+// redrawKey clears the screen and prints the prompt line again (Ctrl+L)
+const redrawKey = readline.CharCtrlL
+
+// redactHistory reports whether redact_history is set, so stored query text has its literals replaced
+func (a *App) redactHistory() bool {
+	return a.aiManager != nil && a.aiManager.GetConfig().RedactHistory
+}
+
+// historyLimit is the configured cap on saved history entries; 0 leaves readline's default
+func (a *App) historyLimit() int {
+	if a.aiManager == nil {
+		return 0
+	}
+	return a.aiManager.GetConfig().HistoryLimit
+}
+
+// historyEntry is the history text for an input line. With redaction on,
+// /exec statements have their literals replaced; other lines are kept as typed.
+func (a *App) historyEntry(line string) string {
+	if !a.redactHistory() || !strings.HasPrefix(line, "/exec ") {
+		return line
+	}
+	return core.RedactLiterals(a.dbType(), line)
+}
+
+// saveHistory stores line in the readline history when automatic saving is
+// off, which it is while redaction is on
+func (a *App) saveHistory(line string) {
+	if a.rl == nil || !a.rl.Config.DisableAutoSaveHistory {
+		return
+	}
+	a.rl.SaveHistory(a.historyEntry(line))
+}
+
+// handleClear clears the screen and the results shown so far: /clear [--history].
+// With --history the saved command history is also erased after confirmation.
+func (a *App) handleClear(args []string) error {
+	clearHistory := false
+	for _, arg := range args {
+		if arg != "--history" {
+			return fmt.Errorf(a.i18nMgr.Get("clear_unknown_option"), arg)
+		}
+		clearHistory = true
+	}
+
+	if clearHistory {
+		if !a.confirm(a.i18nMgr.Get("confirm_clear_history")) {
+			fmt.Println(a.i18nMgr.Get("clear_history_cancelled"))
+			return nil
+		}
+		if err := a.clearSavedHistory(); err != nil {
+			return fmt.Errorf(a.i18nMgr.Get("failed_to_clear_history"), err)
+		}
+	}
+
+	a.resetDisplay()
+	a.clearScreen()
+	if clearHistory {
+		a.notify(NotifyInfo, a.i18nMgr.Get("history_cleared"))
+	}
+	return nil
+}
+
+// handleRedraw clears the screen left garbled by another program or a
+// resize, keeping the results so /more, /row and /show still work
+func (a *App) handleRedraw() error {
+	a.clearScreen()
+	return nil
+}
+
+// clearScreen erases the terminal through readline's writer, which turns the
+// escape sequence into console calls on Windows consoles that lack ANSI support
+func (a *App) clearScreen() {
+	var w io.Writer = os.Stdout
+	if a.rl != nil {
+		w = a.rl.Stdout()
+	}
+	fmt.Fprint(w, clearScreenSequence)
+}
+
+// resetDisplay forgets the last result and pending notifications from before a /clear
+func (a *App) resetDisplay() {
+	a.ui.currentQuery = ""
+	a.ui.lastResult = nil
+	a.ui.lastElapsed = 0
+	a.ui.lastRTT = 0
+	a.ui.lastError = nil
+	a.forgetResults()
+	a.notifications.drain()
+}
+
+// clearSavedHistory empties the readline history in memory and in its history file
+func (a *App) clearSavedHistory() error {
+	if a.rl == nil {
+		return nil
+	}
+	a.rl.ResetHistory()
+
+	if historyFile := a.rl.Config.HistoryFile; historyFile != "" {
+		if err := os.Truncate(historyFile, 0); err != nil && !os.IsNotExist(err) {
+			return err
+		}
+	}
+	return nil
+}
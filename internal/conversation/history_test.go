@@ -0,0 +1,59 @@
+package conversation
+
+import (
+	"errors"
+	"testing"
+
+	"sqlterm/internal/core"
+)
+
+func TestApp_historyEntry(t *testing.T) {
+	app := createTestApp(t)
+	if app.aiManager == nil {
+		t.Skip("AI manager unavailable")
+	}
+
+	exec := "/exec SELECT * FROM users WHERE email = 'bob@example.com'"
+	if got := app.historyEntry(exec); got != exec {
+		t.Errorf("Expected history to be kept verbatim without redact_history, got %q", got)
+	}
+
+	app.aiManager.GetConfig().RedactHistory = true
+	testCases := []struct {
+		line     string
+		expected string
+	}{
+		{exec, "/exec SELECT * FROM users WHERE email = ?"},
+		{"/exec SELECT 1 > out_2024.csv", "/exec SELECT ? > out_2024.csv"},
+		{"/connect prod-2", "/connect prod-2"},
+		{"@report.sql 2-5", "@report.sql 2-5"},
+		{"how many users signed up in 2024?", "how many users signed up in 2024?"},
+	}
+	for _, tc := range testCases {
+		if got := app.historyEntry(tc.line); got != tc.expected {
+			t.Errorf("historyEntry(%q) = %q, expected %q", tc.line, got, tc.expected)
+		}
+	}
+}
+
+func TestApp_handleClear(t *testing.T) {
+	app := createTestApp(t)
+	app.ui.currentQuery = "SELECT 1"
+	app.ui.lastResult = core.NewStaticQueryResult(nil, nil)
+	app.ui.lastError = errors.New("syntax error")
+	app.notify(NotifyInfo, "tables refreshed")
+
+	if err := app.handleClear(nil); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if app.ui.currentQuery != "" || app.ui.lastResult != nil || app.ui.lastError != nil {
+		t.Errorf("Expected the displayed results to be reset, got %+v", app.ui)
+	}
+	if pending := app.notifications.drain(); len(pending) != 0 {
+		t.Errorf("Expected no notification to outlive /clear, got %v", pending)
+	}
+
+	if err := app.handleClear([]string{"--all"}); err == nil {
+		t.Error("Expected an unknown option to be rejected")
+	}
+}
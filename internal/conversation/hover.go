@@ -0,0 +1,138 @@
+package conversation
+
+import (
+	"fmt"
+	"strings"
+
+	"sqlterm/internal/core"
+
+	"github.com/chzyer/readline"
+)
+
+// describeKey shows the columns of the table under the cursor without leaving the prompt (Ctrl+G)
+const describeKey = readline.CharBell
+
+// maxHoverColumns bounds how many columns the quick describe lists
+const maxHoverColumns = 10
+
+// keyListener handles the keys bound on the prompt line: describeKey shows
+// the table under the cursor, paletteKey turns the line into a palette search,
+// redrawKey clears the screen and helpKey on an empty line lists the key bindings
+type keyListener struct {
+	app *App
+}
+
+// OnChange implements readline.Listener
+func (l *keyListener) OnChange(line []rune, pos int, key rune) ([]rune, int, bool) {
+	if key == paletteKey {
+		return paletteLine(line)
+	}
+	if key == helpKey && string(line) == string(helpKey) {
+		if l.app.rl != nil {
+			fmt.Fprint(l.app.rl.Stdout(), "\n"+keyHelp(keyBindings(), "", l.app.i18nMgr))
+		}
+		return []rune{}, 0, true
+	}
+	if key == redrawKey {
+		// Returning the line makes readline print the prompt and the typed text again
+		if l.app.rl != nil {
+			l.app.clearScreen()
+		}
+		line, pos = withoutKey(line, pos, redrawKey)
+		return line, pos, true
+	}
+	if key != describeKey {
+		return nil, 0, false
+	}
+
+	cleaned, pos := withoutKey(line, pos, describeKey)
+	if text := l.app.describeAt(cleaned, pos); text != "" && l.app.rl != nil {
+		fmt.Fprint(l.app.rl.Stdout(), text)
+	}
+	return cleaned, pos, true
+}
+
+// withoutKey drops the control character key from line in case readline
+// inserted it, keeping the cursor on the same text
+func withoutKey(line []rune, pos int, key rune) ([]rune, int) {
+	cleaned := make([]rune, 0, len(line))
+	for i, r := range line {
+		if r == key {
+			if i < pos {
+				pos--
+			}
+			continue
+		}
+		cleaned = append(cleaned, r)
+	}
+	return cleaned, pos
+}
+
+// describeAt lists the columns of the table named under the cursor. It only
+// uses what the schema cache can answer quickly and returns "" when the name
+// is not a known table.
+func (a *App) describeAt(line []rune, pos int) string {
+	name := core.IdentifierAt(line, pos)
+	if name == "" || a.connection == nil {
+		return ""
+	}
+
+	schema, conn := a.schema(), a.connection
+	info := waitForCompletion(func() *core.TableInfo {
+		tables, _, err := schema.Tables(conn.ListTables)
+		if err != nil {
+			return nil
+		}
+		table := knownTable(tables, name)
+		if table == "" {
+			return nil
+		}
+		info, _, err := schema.Table(table, func() (*core.TableInfo, error) {
+			return conn.DescribeTable(table)
+		})
+		if err != nil {
+			return nil
+		}
+		return info
+	})
+	if info == nil {
+		return ""
+	}
+	return a.hoverText(info)
+}
+
+// knownTable finds name among tables, ignoring case and trying the last part
+// of a qualified name
+func knownTable(tables []string, name string) string {
+	candidates := []string{name}
+	if i := strings.LastIndex(name, "."); i >= 0 {
+		candidates = append(candidates, name[i+1:])
+	}
+	for _, candidate := range candidates {
+		for _, table := range tables {
+			if strings.EqualFold(table, candidate) {
+				return table
+			}
+		}
+	}
+	return ""
+}
+
+// hoverText is a compact column list: the table name, then a name and type per line
+func (a *App) hoverText(info *core.TableInfo) string {
+	width := 0
+	for _, column := range info.Columns {
+		width = max(width, len(column.Name))
+	}
+
+	var sb strings.Builder
+	sb.WriteString(info.Name + "\n")
+	for i, column := range info.Columns {
+		if i == maxHoverColumns {
+			fmt.Fprintf(&sb, a.i18nMgr.Get("hover_more_columns"), len(info.Columns)-maxHoverColumns)
+			break
+		}
+		fmt.Fprintf(&sb, "  %-*s  %s\n", width, column.Name, column.Type)
+	}
+	return sb.String()
+}
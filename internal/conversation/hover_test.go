@@ -0,0 +1,51 @@
+package conversation
+
+import (
+	"fmt"
+	"strings"
+	"testing"
+
+	"sqlterm/internal/core"
+)
+
+func TestApp_describeAt(t *testing.T) {
+	app := createTestApp(t)
+	app.connection = newMockUsersConnection()
+
+	line := []rune("SELECT * FROM Users WHERE id = 1")
+	text := app.describeAt(line, 16)
+	if !strings.HasPrefix(text, "users\n") || !strings.Contains(text, "name  varchar(255)") {
+		t.Errorf("Expected the columns of users, got %q", text)
+	}
+
+	for _, pos := range []int{2, 7, 27} {
+		if text := app.describeAt(line, pos); text != "" {
+			t.Errorf("Expected nothing for a word that is not a table at %d, got %q", pos, text)
+		}
+	}
+}
+
+func TestApp_hoverTextLimitsColumns(t *testing.T) {
+	app := createTestApp(t)
+	info := &core.TableInfo{Name: "wide"}
+	for i := 0; i < maxHoverColumns+3; i++ {
+		info.Columns = append(info.Columns, core.ColumnInfo{Name: fmt.Sprintf("c%d", i), Type: "int"})
+	}
+
+	lines := strings.Split(strings.TrimSuffix(app.hoverText(info), "\n"), "\n")
+	if len(lines) != maxHoverColumns+2 || !strings.Contains(lines[len(lines)-1], "3 more columns") {
+		t.Errorf("Expected %d columns and a note about the rest, got %q", maxHoverColumns, lines)
+	}
+}
+
+func TestKeyListener_IgnoresOtherKeys(t *testing.T) {
+	listener := &keyListener{app: createTestApp(t)}
+	if _, _, ok := listener.OnChange([]rune("SELECT"), 6, 'T'); ok {
+		t.Error("Expected ordinary keys to leave the line alone")
+	}
+
+	line, pos, ok := listener.OnChange([]rune("FROM x\a"), 7, describeKey)
+	if !ok || string(line) != "FROM x" || pos != 6 {
+		t.Errorf("Expected the key to be removed from the line, got %q at %d", string(line), pos)
+	}
+}
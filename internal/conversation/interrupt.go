@@ -0,0 +1,105 @@
+package conversation
+
+import (
+	"context"
+	"fmt"
+	"os"
+	"os/signal"
+	"strings"
+	"time"
+)
+
+// forceQuitWindow is how close together two Ctrl+C presses must be to force-quit while work is running
+const forceQuitWindow = time.Second
+
+// interruptTracker decides whether a Ctrl+C cancels the running operation or force-quits
+type interruptTracker struct {
+	last time.Time
+}
+
+// press records a Ctrl+C at now and reports whether it is the second press within forceQuitWindow
+func (t *interruptTracker) press(now time.Time) bool {
+	forceQuit := !t.last.IsZero() && now.Sub(t.last) <= forceQuitWindow
+	t.last = now
+	return forceQuit
+}
+
+// promptInterrupt is what a Ctrl+C at the prompt does
+type promptInterrupt int
+
+const (
+	// interruptClear abandons the line typed so far for a fresh prompt
+	interruptClear promptInterrupt = iota
+	// interruptHint leaves the empty prompt and tells how to exit
+	interruptHint
+	// interruptExit leaves sqlterm
+	interruptExit
+)
+
+// promptInterrupts decides what a Ctrl+C at the prompt does, as in a shell:
+// the first clears the line, and a second on the emptied line, or any within
+// forceQuitWindow of the last, exits. Entering a line starts over.
+type promptInterrupts struct {
+	armed bool
+	last  time.Time
+}
+
+// press records a Ctrl+C at now with line typed so far
+func (p *promptInterrupts) press(now time.Time, line string) promptInterrupt {
+	quick := p.armed && now.Sub(p.last) <= forceQuitWindow
+	empty := strings.TrimSpace(line) == ""
+	switch {
+	case quick || p.armed && empty:
+		p.reset()
+		return interruptExit
+	case empty:
+		p.armed, p.last = true, now
+		return interruptHint
+	default:
+		p.armed, p.last = true, now
+		return interruptClear
+	}
+}
+
+// reset forgets earlier presses once a line is entered
+func (p *promptInterrupts) reset() {
+	p.armed, p.last = false, time.Time{}
+}
+
+// interruptContext returns a context that is cancelled by Ctrl+C while a query or AI request runs.
+// Readline is not reading at that point, so SIGINT would otherwise kill the process.
+// A second Ctrl+C within forceQuitWindow quits immediately. Call stop once the work is done.
+func (a *App) interruptContext(parent context.Context) (ctx context.Context, stop func()) {
+	ctx, cancel := context.WithCancel(parent)
+
+	sigCh := make(chan os.Signal, 2)
+	signal.Notify(sigCh, os.Interrupt)
+	done := make(chan struct{})
+
+	go func() {
+		var tracker interruptTracker
+		for {
+			select {
+			case <-sigCh:
+				if tracker.press(time.Now()) {
+					fmt.Println(a.i18nMgr.Get("force_quit"))
+					// Skip the rest of shutdown: closing a busy connection could block
+					a.restoreTerminal()
+					os.Exit(130)
+				}
+				if ctx.Err() == nil {
+					fmt.Println(a.i18nMgr.Get("cancelling_operation"))
+				}
+				cancel()
+			case <-done:
+				return
+			}
+		}
+	}()
+
+	return ctx, func() {
+		signal.Stop(sigCh)
+		close(done)
+		cancel()
+	}
+}
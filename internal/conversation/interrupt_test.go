@@ -0,0 +1,118 @@
+package conversation
+
+import (
+	"context"
+	"testing"
+	"time"
+)
+
+func TestInterruptTracker_press(t *testing.T) {
+	start := time.Now()
+
+	testCases := []struct {
+		name     string
+		presses  []time.Duration
+		expected []bool
+	}{
+		{
+			name:     "Single press cancels",
+			presses:  []time.Duration{0},
+			expected: []bool{false},
+		},
+		{
+			name:     "Double press within a second force-quits",
+			presses:  []time.Duration{0, 500 * time.Millisecond},
+			expected: []bool{false, true},
+		},
+		{
+			name:     "Presses further apart only cancel",
+			presses:  []time.Duration{0, 1500 * time.Millisecond, 3 * time.Second},
+			expected: []bool{false, false, false},
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.name, func(t *testing.T) {
+			var tracker interruptTracker
+			for i, offset := range tc.presses {
+				if got := tracker.press(start.Add(offset)); got != tc.expected[i] {
+					t.Errorf("Press %d: expected force quit %v, got %v", i, tc.expected[i], got)
+				}
+			}
+		})
+	}
+}
+
+func TestPromptInterrupts_press(t *testing.T) {
+	start := time.Now()
+
+	type press struct {
+		at   time.Duration
+		line string
+	}
+	testCases := []struct {
+		name     string
+		presses  []press
+		expected []promptInterrupt
+	}{
+		{
+			name:     "First press clears the line",
+			presses:  []press{{0, "SELECT 1"}},
+			expected: []promptInterrupt{interruptClear},
+		},
+		{
+			name:     "Second press on the cleared line exits",
+			presses:  []press{{0, "SELECT 1"}, {5 * time.Second, ""}},
+			expected: []promptInterrupt{interruptClear, interruptExit},
+		},
+		{
+			name:     "Press on an empty line tells how to exit, the next exits",
+			presses:  []press{{0, ""}, {5 * time.Second, "  "}},
+			expected: []promptInterrupt{interruptHint, interruptExit},
+		},
+		{
+			name:     "Retyped line is cleared again",
+			presses:  []press{{0, "SELECT 1"}, {5 * time.Second, "SELECT 2"}},
+			expected: []promptInterrupt{interruptClear, interruptClear},
+		},
+		{
+			name:     "Double press within a second exits",
+			presses:  []press{{0, "SELECT 1"}, {500 * time.Millisecond, "S"}},
+			expected: []promptInterrupt{interruptClear, interruptExit},
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.name, func(t *testing.T) {
+			var interrupts promptInterrupts
+			for i, p := range tc.presses {
+				if got := interrupts.press(start.Add(p.at), p.line); got != tc.expected[i] {
+					t.Errorf("Press %d: expected %v, got %v", i, tc.expected[i], got)
+				}
+			}
+		})
+	}
+
+	// Entering a line starts over
+	var interrupts promptInterrupts
+	interrupts.press(start, "SELECT 1")
+	interrupts.reset()
+	if got := interrupts.press(start.Add(100*time.Millisecond), ""); got != interruptHint {
+		t.Errorf("Expected a press after an entered line to only tell how to exit, got %v", got)
+	}
+}
+
+func TestApp_interruptContext_Stop(t *testing.T) {
+	app := createTestApp(t)
+
+	ctx, stop := app.interruptContext(context.Background())
+	if ctx.Err() != nil {
+		t.Fatal("Context should not be cancelled before Ctrl+C or stop")
+	}
+
+	stop()
+
+	if ctx.Err() == nil {
+		t.Error("Context should be cancelled after stop")
+	}
+}
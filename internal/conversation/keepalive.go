@@ -0,0 +1,55 @@
+package conversation
+
+import (
+	"context"
+	"time"
+
+	"sqlterm/internal/core"
+)
+
+// keepAliveInterval is how often an idle connection is pinged so servers and proxies do not drop it
+const keepAliveInterval = 5 * time.Minute
+
+// startKeepAlive pings conn from a background goroutine until the connection is replaced or closed
+func (a *App) startKeepAlive(conn core.Connection, name string) {
+	a.stopKeepAlive()
+
+	ctx, cancel := context.WithCancel(context.Background())
+	a.keepAliveCancel = cancel
+	done := a.tasks.register(taskIDs.Add(1), taskKeepAlive, name, cancel)
+
+	go func() {
+		defer done()
+		keepAlive(ctx, conn, keepAliveInterval, func(err error) {
+			a.notify(NotifyWarning, a.i18nMgr.Get("keepalive_failed"), name, err)
+		})
+	}()
+}
+
+func (a *App) stopKeepAlive() {
+	if a.keepAliveCancel != nil {
+		a.keepAliveCancel()
+		a.keepAliveCancel = nil
+	}
+}
+
+// keepAlive pings conn every interval until ctx is done. onFailure is called when the
+// connection goes from healthy to failing, so a dead server is reported once rather than every tick.
+func keepAlive(ctx context.Context, conn core.Connection, interval time.Duration, onFailure func(error)) {
+	ticker := time.NewTicker(interval)
+	defer ticker.Stop()
+
+	healthy := true
+	for {
+		select {
+		case <-ctx.Done():
+			return
+		case <-ticker.C:
+			err := conn.Ping()
+			if err != nil && healthy {
+				onFailure(err)
+			}
+			healthy = err == nil
+		}
+	}
+}
@@ -0,0 +1,51 @@
+package conversation
+
+import (
+	"context"
+	"errors"
+	"sync/atomic"
+	"testing"
+	"time"
+
+	"sqlterm/internal/core/coretest"
+)
+
+func TestKeepAlive_ReportsFailureOnce(t *testing.T) {
+	conn := coretest.NewMockConnection().FailMethod("Ping", errors.New("server has gone away"))
+
+	ctx, cancel := context.WithCancel(context.Background())
+	done := make(chan struct{})
+	var failures atomic.Int32
+
+	go func() {
+		keepAlive(ctx, conn, 5*time.Millisecond, func(err error) { failures.Add(1) })
+		close(done)
+	}()
+
+	time.Sleep(50 * time.Millisecond)
+	cancel()
+
+	select {
+	case <-done:
+	case <-time.After(time.Second):
+		t.Fatal("Expected keepalive to stop when its context is cancelled")
+	}
+
+	if got := failures.Load(); got != 1 {
+		t.Errorf("Expected a single failure report while the server stays down, got %d", got)
+	}
+}
+
+func TestKeepAlive_HealthyConnection(t *testing.T) {
+	conn := coretest.NewMockConnection()
+
+	ctx, cancel := context.WithTimeout(context.Background(), 30*time.Millisecond)
+	defer cancel()
+
+	failed := false
+	keepAlive(ctx, conn, 5*time.Millisecond, func(err error) { failed = true })
+
+	if failed {
+		t.Error("Expected no failure report for a healthy connection")
+	}
+}
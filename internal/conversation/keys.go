@@ -0,0 +1,103 @@
+package conversation
+
+import (
+	"fmt"
+	"strings"
+
+	"sqlterm/internal/core"
+	"sqlterm/internal/i18n"
+)
+
+// helpKey typed on an empty prompt line lists the key bindings
+const helpKey = '?'
+
+// keyState is when a key binding applies
+type keyState int
+
+const (
+	keyAtPrompt keyState = iota
+	keyWhileRunning
+)
+
+// keyBinding is one key SQLTerm or readline handles; Action is the i18n id of
+// what it does
+type keyBinding struct {
+	Key    string
+	State  keyState
+	Action string
+}
+
+// keyBindings lists every key binding, grouped by state. The keys the prompt
+// binds itself are named from their constants so the list follows them.
+func keyBindings() []keyBinding {
+	return []keyBinding{
+		{Key: string(helpKey), State: keyAtPrompt, Action: "key_help"},
+		{Key: "Tab", State: keyAtPrompt, Action: "key_complete"},
+		{Key: "Up/Down", State: keyAtPrompt, Action: "key_history"},
+		{Key: "Ctrl+R", State: keyAtPrompt, Action: "key_history_search"},
+		{Key: "Ctrl+A/Ctrl+E", State: keyAtPrompt, Action: "key_line_ends"},
+		{Key: "Ctrl+W", State: keyAtPrompt, Action: "key_delete_word"},
+		{Key: ctrlKeyName(describeKey), State: keyAtPrompt, Action: "key_describe"},
+		{Key: ctrlKeyName(paletteKey), State: keyAtPrompt, Action: "key_palette"},
+		{Key: ctrlKeyName(redrawKey), State: keyAtPrompt, Action: "key_redraw"},
+		{Key: "Ctrl+C", State: keyAtPrompt, Action: "key_clear_line"},
+		{Key: "Ctrl+D", State: keyAtPrompt, Action: "key_exit"},
+		{Key: "Ctrl+Z", State: keyAtPrompt, Action: "key_suspend"},
+		{Key: "Ctrl+C", State: keyWhileRunning, Action: "key_cancel"},
+		{Key: "Ctrl+Z", State: keyWhileRunning, Action: "key_suspend"},
+	}
+}
+
+// ctrlKeyName names a control character the way the help shows it, e.g. Ctrl+G
+func ctrlKeyName(key rune) string {
+	return "Ctrl+" + string('A'+key-1)
+}
+
+// keyHelp renders bindings grouped by state, keeping those whose key or
+// action contains filter (ignoring case). It returns "" when none match.
+func keyHelp(bindings []keyBinding, filter string, i18nMgr *i18n.Manager) string {
+	filter = strings.ToLower(strings.TrimSpace(filter))
+	headings := map[keyState]string{
+		keyAtPrompt:     i18nMgr.Get("keys_at_prompt"),
+		keyWhileRunning: i18nMgr.Get("keys_while_running"),
+	}
+
+	var sb strings.Builder
+	for _, state := range []keyState{keyAtPrompt, keyWhileRunning} {
+		var matched []keyBinding
+		width := 0
+		for _, binding := range bindings {
+			action := i18nMgr.Get(binding.Action)
+			if binding.State != state || !(strings.Contains(strings.ToLower(binding.Key), filter) ||
+				strings.Contains(strings.ToLower(action), filter)) {
+				continue
+			}
+			matched = append(matched, binding)
+			width = max(width, core.DisplayWidth(binding.Key))
+		}
+		if len(matched) == 0 {
+			continue
+		}
+
+		if sb.Len() > 0 {
+			sb.WriteString("\n")
+		}
+		sb.WriteString(headings[state] + "\n")
+		for _, binding := range matched {
+			padding := strings.Repeat(" ", width-core.DisplayWidth(binding.Key))
+			fmt.Fprintf(&sb, "  %s%s  %s\n", binding.Key, padding, i18nMgr.Get(binding.Action))
+		}
+	}
+	return sb.String()
+}
+
+// handleKeys lists the key bindings, optionally filtered: /keys [filter]
+func (a *App) handleKeys(args []string) error {
+	help := keyHelp(keyBindings(), strings.Join(args, " "), a.i18nMgr)
+	if help == "" {
+		fmt.Printf(a.i18nMgr.Get("keys_no_matches"), strings.Join(args, " "))
+		return nil
+	}
+	fmt.Print(help)
+	return nil
+}
@@ -0,0 +1,73 @@
+package conversation
+
+import (
+	"strings"
+	"testing"
+)
+
+func TestCtrlKeyName(t *testing.T) {
+	if name := ctrlKeyName(describeKey); name != "Ctrl+G" {
+		t.Errorf("Expected Ctrl+G for describeKey, got %q", name)
+	}
+	if name := ctrlKeyName(paletteKey); name != "Ctrl+O" {
+		t.Errorf("Expected Ctrl+O for paletteKey, got %q", name)
+	}
+}
+
+func TestKeyHelp_GroupsByState(t *testing.T) {
+	app := createTestApp(t)
+	help := keyHelp(keyBindings(), "", app.i18nMgr)
+
+	prompt := strings.Index(help, "At the prompt:")
+	running := strings.Index(help, "While a query or AI request runs:")
+	if prompt < 0 || running < prompt {
+		t.Fatalf("Expected the prompt keys before the running keys, got %q", help)
+	}
+	if !strings.Contains(help[:running], "Ctrl+G") || strings.Contains(help[running:], "Ctrl+G") {
+		t.Errorf("Expected Ctrl+G only at the prompt, got %q", help)
+	}
+	if !strings.Contains(help[running:], "Cancel it") {
+		t.Errorf("Expected Ctrl+C to cancel while running, got %q", help)
+	}
+}
+
+func TestKeyHelp_Filters(t *testing.T) {
+	app := createTestApp(t)
+	bindings := keyBindings()
+
+	byKey := keyHelp(bindings, "ctrl+o", app.i18nMgr)
+	if !strings.Contains(byKey, "/palette") || strings.Contains(byKey, "Ctrl+G") || strings.Contains(byKey, "While") {
+		t.Errorf("Expected only Ctrl+O, got %q", byKey)
+	}
+
+	byAction := keyHelp(bindings, "SUSPEND", app.i18nMgr)
+	if strings.Count(byAction, "Ctrl+Z") != 2 {
+		t.Errorf("Expected Ctrl+Z in both states, got %q", byAction)
+	}
+
+	if help := keyHelp(bindings, "no such key", app.i18nMgr); help != "" {
+		t.Errorf("Expected nothing for an unmatched filter, got %q", help)
+	}
+}
+
+func TestKeyListener_HelpKeyOnEmptyLine(t *testing.T) {
+	listener := &keyListener{app: createTestApp(t)}
+
+	line, pos, ok := listener.OnChange([]rune("?"), 1, helpKey)
+	if !ok || len(line) != 0 || pos != 0 {
+		t.Errorf("Expected ? on an empty line to be taken, got %q at %d", string(line), pos)
+	}
+
+	if _, _, ok := listener.OnChange([]rune("what?"), 5, helpKey); ok {
+		t.Error("Expected ? inside a line to be typed as usual")
+	}
+}
+
+func TestKeyListener_RedrawKeepsTheLine(t *testing.T) {
+	listener := &keyListener{app: createTestApp(t)}
+
+	line, pos, ok := listener.OnChange([]rune("SELECT \x0c1"), 8, redrawKey)
+	if !ok || string(line) != "SELECT 1" || pos != 7 {
+		t.Errorf("Expected Ctrl+L to keep the typed line, got %q at %d", string(line), pos)
+	}
+}
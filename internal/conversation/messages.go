@@ -0,0 +1,228 @@
+package conversation
+
+import (
+	"context"
+	"fmt"
+	"sync/atomic"
+	"time"
+
+	"sqlterm/internal/core"
+)
+
+// messageBufferSize is how many updates background tasks can post before they block
+const messageBufferSize = 64
+
+// AppMessage is an update posted by a background task to the prompt loop.
+// Tasks never touch App directly: they get the connection they work on when
+// spawned and report back through App.messages, so the prompt loop stays the
+// only owner of UI state while database calls run.
+type AppMessage interface {
+	appMessage()
+}
+
+// QueryStarted is posted when a query task begins executing
+type QueryStarted struct {
+	ID    uint64
+	Query string
+}
+
+// QueryFinished is posted when a query returns a result set
+type QueryFinished struct {
+	ID      uint64
+	Query   string
+	Result  *core.QueryResult
+	Elapsed time.Duration
+	// RTT is the round trip of the ping sent before the query, 0 when none was
+	RTT time.Duration
+}
+
+// QueryFailed is posted when a query returns an error, including cancellation
+type QueryFailed struct {
+	ID    uint64
+	Query string
+	Err   error
+}
+
+// TablesRefreshed is posted when a table listing completes
+type TablesRefreshed struct {
+	ID     uint64
+	Tables []string
+}
+
+// TablesFailed is posted when a table listing returns an error
+type TablesFailed struct {
+	ID  uint64
+	Err error
+}
+
+// NotificationPosted carries a status message from a background task
+type NotificationPosted struct {
+	Level   NotificationLevel
+	Message string
+}
+
+func (QueryStarted) appMessage()       {}
+func (QueryFinished) appMessage()      {}
+func (QueryFailed) appMessage()        {}
+func (TablesRefreshed) appMessage()    {}
+func (TablesFailed) appMessage()       {}
+func (NotificationPosted) appMessage() {}
+
+// uiState is owned by the prompt loop and only changed by handleMessage
+type uiState struct {
+	running      bool
+	currentQuery string
+	lastResult   *core.QueryResult
+	lastElapsed  time.Duration
+	lastRTT      time.Duration
+	lastError    error
+	tables       []string
+}
+
+// taskIDs numbers background tasks so replies can be matched to their request
+var taskIDs atomic.Uint64
+
+// post sends msg to the prompt loop
+func (a *App) post(msg AppMessage) {
+	a.messages <- msg
+}
+
+// spawnQuery executes query on conn in the background and returns the task ID.
+// Queries to a server are preceded by a ping, so the network share of their
+// time can be told apart from the server's.
+func (a *App) spawnQuery(ctx context.Context, conn core.Connection, query string) uint64 {
+	id := taskIDs.Add(1)
+	ping := a.remoteConnection()
+	// The result keeps reading rows with ctx after the task ends, so cancel is
+	// only called here on failure or by /tasks cancel
+	ctx, cancel := context.WithCancel(ctx)
+	done := a.tasks.register(id, taskQuery, a.connectionName(), cancel)
+
+	go func() {
+		defer done()
+		a.post(QueryStarted{ID: id, Query: query})
+
+		var rtt time.Duration
+		if ping {
+			rtt = core.MeasureRTT(ctx, conn)
+		}
+		start := time.Now()
+		result, err := conn.ExecuteContext(ctx, query)
+		if err != nil {
+			cancel()
+			a.post(QueryFailed{ID: id, Query: query, Err: err})
+			return
+		}
+		a.post(QueryFinished{ID: id, Query: query, Result: result, Elapsed: time.Since(start), RTT: rtt})
+	}()
+
+	return id
+}
+
+// spawnTableRefresh lists the tables of conn in the background and returns the task ID
+func (a *App) spawnTableRefresh(conn core.Connection) uint64 {
+	id := taskIDs.Add(1)
+	done := a.tasks.register(id, taskTables, a.connectionName(), nil)
+
+	go func() {
+		defer done()
+		tables, err := conn.ListTables()
+		if err != nil {
+			a.post(TablesFailed{ID: id, Err: err})
+			return
+		}
+		a.post(TablesRefreshed{ID: id, Tables: tables})
+	}()
+
+	return id
+}
+
+// handleMessage applies a background update to the UI state
+func (a *App) handleMessage(msg AppMessage) {
+	switch m := msg.(type) {
+	case QueryStarted:
+		a.ui.running = true
+		a.ui.currentQuery = m.Query
+		a.ui.lastError = nil
+	case QueryFinished:
+		a.ui.running = false
+		a.ui.currentQuery = ""
+		a.ui.lastResult = m.Result
+		a.ui.lastElapsed = m.Elapsed
+		a.ui.lastRTT = m.RTT
+	case QueryFailed:
+		a.ui.running = false
+		a.ui.currentQuery = ""
+		a.ui.lastError = m.Err
+	case TablesRefreshed:
+		a.ui.tables = m.Tables
+	case TablesFailed:
+		a.ui.lastError = m.Err
+	case NotificationPosted:
+		a.notify(m.Level, "%s", m.Message)
+	}
+}
+
+// awaitTask handles messages until the task with the given ID posts its final message,
+// which is returned. Updates from other tasks are applied as they arrive.
+func (a *App) awaitTask(id uint64) AppMessage {
+	for msg := range a.messages {
+		a.handleMessage(msg)
+		if taskID, ok := finalMessageID(msg); ok && taskID == id {
+			return msg
+		}
+	}
+	return nil
+}
+
+// drainMessages handles every message already queued without blocking
+func (a *App) drainMessages() {
+	for {
+		select {
+		case msg := <-a.messages:
+			a.handleMessage(msg)
+		default:
+			return
+		}
+	}
+}
+
+// finalMessageID reports the task ID of a message that ends a task
+func finalMessageID(msg AppMessage) (uint64, bool) {
+	switch m := msg.(type) {
+	case QueryFinished:
+		return m.ID, true
+	case QueryFailed:
+		return m.ID, true
+	case TablesRefreshed:
+		return m.ID, true
+	case TablesFailed:
+		return m.ID, true
+	default:
+		return 0, false
+	}
+}
+
+// runQuery executes query through the message loop and waits for its result
+func (a *App) runQuery(ctx context.Context, conn core.Connection, query string) (*core.QueryResult, error) {
+	switch m := a.awaitTask(a.spawnQuery(ctx, conn, query)).(type) {
+	case QueryFinished:
+		return m.Result, nil
+	case QueryFailed:
+		return nil, m.Err
+	default:
+		return nil, fmt.Errorf("unexpected message %T", m)
+	}
+}
+
+// refreshTables lists tables through the message loop and waits for the result
+func (a *App) refreshTables(conn core.Connection) ([]string, error) {
+	switch m := a.awaitTask(a.spawnTableRefresh(conn)).(type) {
+	case TablesRefreshed:
+		return m.Tables, nil
+	case TablesFailed:
+		return nil, m.Err
+	default:
+		return nil, fmt.Errorf("unexpected message %T", m)
+	}
+}
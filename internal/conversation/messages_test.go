@@ -0,0 +1,132 @@
+package conversation
+
+import (
+	"context"
+	"errors"
+	"strings"
+	"testing"
+	"time"
+
+	"sqlterm/internal/core"
+	"sqlterm/internal/core/coretest"
+)
+
+func TestApp_handleMessage_QueryTransitions(t *testing.T) {
+	app := createTestApp(t)
+	result := core.NewStaticQueryResult([]core.Column{{Name: "id"}}, nil)
+
+	testCases := []struct {
+		name        string
+		msg         AppMessage
+		wantRunning bool
+		wantQuery   string
+		wantResult  bool
+		wantError   bool
+	}{
+		{
+			name:        "Started",
+			msg:         QueryStarted{ID: 1, Query: "SELECT 1"},
+			wantRunning: true,
+			wantQuery:   "SELECT 1",
+		},
+		{
+			name:       "Finished",
+			msg:        QueryFinished{ID: 1, Query: "SELECT 1", Result: result, Elapsed: time.Millisecond},
+			wantResult: true,
+		},
+		{
+			name:        "Started again",
+			msg:         QueryStarted{ID: 2, Query: "SELECT nope"},
+			wantRunning: true,
+			wantQuery:   "SELECT nope",
+			wantResult:  true,
+		},
+		{
+			name:       "Failed",
+			msg:        QueryFailed{ID: 2, Query: "SELECT nope", Err: errors.New("syntax error")},
+			wantResult: true,
+			wantError:  true,
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.name, func(t *testing.T) {
+			app.handleMessage(tc.msg)
+
+			if app.ui.running != tc.wantRunning {
+				t.Errorf("Expected running=%v, got %v", tc.wantRunning, app.ui.running)
+			}
+			if app.ui.currentQuery != tc.wantQuery {
+				t.Errorf("Expected current query '%s', got '%s'", tc.wantQuery, app.ui.currentQuery)
+			}
+			if (app.ui.lastResult != nil) != tc.wantResult {
+				t.Errorf("Expected last result present=%v", tc.wantResult)
+			}
+			if (app.ui.lastError != nil) != tc.wantError {
+				t.Errorf("Expected last error present=%v, got %v", tc.wantError, app.ui.lastError)
+			}
+		})
+	}
+}
+
+func TestApp_spawnQuery_PostsStartedThenFinished(t *testing.T) {
+	app := createTestApp(t)
+	conn := newMockUsersConnection()
+
+	id := app.spawnQuery(context.Background(), conn, "SELECT id, name FROM users")
+
+	started, ok := (<-app.messages).(QueryStarted)
+	if !ok || started.ID != id {
+		t.Fatalf("Expected QueryStarted for task %d first, got %#v", id, started)
+	}
+
+	finished, ok := (<-app.messages).(QueryFinished)
+	if !ok || finished.ID != id {
+		t.Fatalf("Expected QueryFinished for task %d, got %#v", id, finished)
+	}
+	if len(finished.Result.Columns) != 2 {
+		t.Errorf("Expected 2 columns, got %d", len(finished.Result.Columns))
+	}
+}
+
+func TestApp_runQuery_AppliesOtherMessages(t *testing.T) {
+	app := createTestApp(t)
+	conn := newMockUsersConnection()
+	conn.Latency = 20 * time.Millisecond
+
+	// An update from an unrelated task arrives while the query is running
+	app.post(NotificationPosted{Level: NotifyInfo, Message: "tables refreshed"})
+
+	if _, err := app.runQuery(context.Background(), conn, "SELECT id, name FROM users"); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+
+	if app.ui.running {
+		t.Error("Expected the query to be finished")
+	}
+	if app.ui.lastResult == nil {
+		t.Error("Expected the result to be recorded in UI state")
+	}
+
+	notifications := app.notifications.drain()
+	if len(notifications) != 1 || !strings.Contains(notifications[0].Message, "tables refreshed") {
+		t.Errorf("Expected the unrelated notification to be handled, got %v", notifications)
+	}
+}
+
+func TestApp_refreshTables(t *testing.T) {
+	app := createTestApp(t)
+
+	tables, err := app.refreshTables(newMockUsersConnection())
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if strings.Join(tables, ",") != "orders,users" || strings.Join(app.ui.tables, ",") != "orders,users" {
+		t.Errorf("Expected tables to be returned and recorded, got %v and %v", tables, app.ui.tables)
+	}
+
+	_, err = app.refreshTables(coretest.NewMockConnection().FailMethod("ListTables", errors.New("connection reset")))
+	if err == nil || app.ui.lastError == nil {
+		t.Errorf("Expected the listing error to be returned and recorded, got %v", err)
+	}
+}
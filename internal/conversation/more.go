@@ -0,0 +1,40 @@
+package conversation
+
+import (
+	"fmt"
+
+	"sqlterm/internal/core"
+)
+
+// handleMore shows the next page of the last result that did not fit the row limit
+func (a *App) handleMore() error {
+	if a.config == nil {
+		fmt.Println(a.i18nMgr.Get("no_database_connection"))
+		return nil
+	}
+	if a.moreResult == nil {
+		fmt.Println(a.i18nMgr.Get("no_more_rows"))
+		return nil
+	}
+
+	mdPath, writer, err := a.prepareQueryResultMarkdown()
+	if err != nil {
+		return err
+	}
+
+	page := core.ResultPageMarkdown(a.moreResult, a.columnLayout(a.moreResult.Columns), a.resultFormat(a.moreResult.Columns), a.i18nMgr)
+	if !a.moreResult.Remaining() {
+		a.moreResult = nil
+	}
+
+	_, err = writer.WriteString(page)
+	writer.Close()
+	if err != nil {
+		return fmt.Errorf(a.i18nMgr.Get("failed_to_write_markdown"), err)
+	}
+
+	if err := a.viewMarkdown(mdPath); err != nil {
+		fmt.Printf(a.i18nMgr.Get("generic_warning"), err)
+	}
+	return nil
+}
@@ -0,0 +1,195 @@
+package conversation
+
+import (
+	"context"
+	"fmt"
+	"strconv"
+	"strings"
+	"time"
+
+	"sqlterm/internal/core"
+)
+
+// navEntry is a result /follow moved away from, restored by /back
+type navEntry struct {
+	stored *core.StoredResult
+	query  string
+	crumb  string
+}
+
+// resultSource stamps a result or history entry with the current connection and time
+func (a *App) resultSource() core.ResultSource {
+	source := core.ResultSource{Time: time.Now()}
+	if a.config != nil {
+		source.Connection, source.Database = a.config.Name, a.config.Database
+		source.DatabaseType = a.config.DatabaseType
+	}
+	return source
+}
+
+// dbType is the current connection's database type, which decides how SQL
+// typed for it is read, e.g. whether a backslash escapes a quote. Without a
+// connection it is the zero type.
+func (a *App) dbType() core.DatabaseType {
+	if a.config == nil {
+		return 0
+	}
+	return a.config.DatabaseType
+}
+
+// forgetResults drops the stored results /more, /edit, /follow and /back work on
+func (a *App) forgetResults() {
+	a.moreResult = nil
+	a.lastStored, a.lastQuery, a.lastCrumb = nil, "", ""
+	a.unlimitedQuery = ""
+	a.navStack = nil
+}
+
+// resultRow parses a 1-based row number of the last result into an index
+func (a *App) resultRow(arg string) (int, error) {
+	row, err := strconv.Atoi(arg)
+	if err != nil || row < 1 || row > len(a.lastStored.Rows) {
+		return 0, fmt.Errorf(a.i18nMgr.Get("invalid_result_row"), arg, len(a.lastStored.Rows))
+	}
+	return row - 1, nil
+}
+
+// handleFollow shows the row a foreign key cell of the last result refers to: /follow <row> <column>
+func (a *App) handleFollow(args []string) error {
+	if a.connection == nil {
+		fmt.Println(a.i18nMgr.Get("no_database_connection"))
+		return nil
+	}
+	if len(args) != 2 {
+		fmt.Println(a.i18nMgr.Get("usage_follow"))
+		return nil
+	}
+	if a.lastStored == nil {
+		fmt.Println(a.i18nMgr.Get("no_result_to_follow"))
+		return nil
+	}
+
+	row, err := a.resultRow(args[0])
+	if err != nil {
+		return err
+	}
+	table, lookup, err := a.foreignKeyLookup(a.lastStored.Rows[row], args[1])
+	if err != nil {
+		return fmt.Errorf(a.i18nMgr.Get("follow_not_possible"), err)
+	}
+
+	stored, err := a.runLookup(lookup)
+	if err != nil {
+		return fmt.Errorf(a.i18nMgr.Get("query_execution_failed"), err)
+	}
+	stored.Source = a.resultSource()
+
+	crumb := a.lastCrumb
+	if crumb == "" {
+		crumb = table
+	}
+	a.navStack = append(a.navStack, navEntry{stored: a.lastStored, query: a.lastQuery, crumb: crumb})
+	a.lastStored = stored
+	a.lastQuery = lookup.Preview(a.config.DatabaseType)
+	a.lastCrumb = fmt.Sprintf("%s #%s", lookup.Table, core.FormatISO(lookup.Value))
+	return a.viewStored()
+}
+
+// handleBack returns to the result shown before the last /follow
+func (a *App) handleBack() error {
+	if len(a.navStack) == 0 {
+		fmt.Println(a.i18nMgr.Get("no_previous_result"))
+		return nil
+	}
+
+	previous := a.navStack[len(a.navStack)-1]
+	a.navStack = a.navStack[:len(a.navStack)-1]
+	a.lastStored, a.lastQuery, a.lastCrumb = previous.stored, previous.query, previous.crumb
+	a.lastStored.Rewind()
+	return a.viewStored()
+}
+
+// foreignKeyLookup finds the foreign key of column in the last result's table and
+// builds the lookup of the row its value in row refers to
+func (a *App) foreignKeyLookup(row []core.Value, column string) (string, core.RowLookup, error) {
+	table, err := core.SourceTable(a.dbType(), a.lastQuery)
+	if err != nil {
+		return "", core.RowLookup{}, err
+	}
+	info, _, err := a.cachedTable(table)
+	if err != nil {
+		return "", core.RowLookup{}, err
+	}
+
+	columnIndex := resultColumn(a.lastStored.Columns, column)
+	if columnIndex < 0 {
+		return "", core.RowLookup{}, fmt.Errorf(a.i18nMgr.Get("edit_unknown_column"), column, table)
+	}
+	name := a.lastStored.Columns[columnIndex].Name
+	for _, fk := range info.ForeignKeys {
+		if !strings.EqualFold(fk.Column, name) {
+			continue
+		}
+		if row[columnIndex].IsNull() {
+			return "", core.RowLookup{}, fmt.Errorf(a.i18nMgr.Get("follow_null_value"), name)
+		}
+		return table, core.ForeignKeyLookup(fk, row[columnIndex]), nil
+	}
+	return "", core.RowLookup{}, fmt.Errorf(a.i18nMgr.Get("follow_not_foreign_key"), name, table)
+}
+
+// runLookup runs lookup with its value bound as a parameter and stores the rows
+func (a *App) runLookup(lookup core.RowLookup) (*core.StoredResult, error) {
+	executor, ok := a.connection.(core.ParamExecutor)
+	if !ok {
+		return nil, core.ErrParamsNotSupported
+	}
+
+	ctx, stop := a.interruptContext(context.Background())
+	defer stop()
+
+	query, args := lookup.Statement(a.config.DatabaseType)
+	result, err := executor.ExecuteParams(ctx, query, args...)
+	if err != nil {
+		return nil, err
+	}
+	return core.StoreResult(result, core.MaxStoredRows)
+}
+
+// breadcrumb is the /follow path to the current result, e.g. "orders → users #4521"
+func (a *App) breadcrumb() string {
+	if len(a.navStack) == 0 {
+		return ""
+	}
+	crumbs := make([]string, 0, len(a.navStack)+1)
+	for _, entry := range a.navStack {
+		crumbs = append(crumbs, entry.crumb)
+	}
+	return strings.Join(append(crumbs, a.lastCrumb), " → ")
+}
+
+// viewStored shows the first page of the current stored result under its breadcrumb
+func (a *App) viewStored() error {
+	mdPath, writer, err := a.prepareQueryResultMarkdown()
+	if err != nil {
+		return err
+	}
+
+	if crumb := a.breadcrumb(); crumb != "" {
+		fmt.Fprintf(writer, "**%s**\n\n", crumb)
+	}
+	err = core.SaveResultPageAsMarkdown(a.lastStored, a.columnLayout(a.lastStored.Columns), a.lastQuery, writer, a.resultFormat(a.lastStored.Columns), a.i18nMgr)
+	writer.Close()
+	if err != nil {
+		return err
+	}
+
+	a.moreResult = nil
+	if a.lastStored.Remaining() {
+		a.moreResult = a.lastStored
+	}
+	if err := a.viewMarkdown(mdPath); err != nil {
+		fmt.Printf(a.i18nMgr.Get("generic_warning"), err)
+	}
+	return nil
+}
@@ -0,0 +1,92 @@
+package conversation
+
+import (
+	"bytes"
+	"context"
+	"reflect"
+	"strings"
+	"testing"
+
+	"sqlterm/internal/core"
+	"sqlterm/internal/core/coretest"
+)
+
+func newMockOrdersConnection() *coretest.MockConnection {
+	return newMockUsersConnection().
+		AddTable(&core.TableInfo{
+			Name:        "orders",
+			Columns:     []core.ColumnInfo{{Name: "id"}, {Name: "user_id"}, {Name: "note"}},
+			PrimaryKeys: []string{"id"},
+			ForeignKeys: []core.ForeignKeyInfo{{Column: "user_id", ReferencedTable: "users", ReferencedColumn: "id"}},
+		}).
+		AddResponse("SELECT * FROM orders",
+			[]core.Column{{Name: "id"}, {Name: "user_id"}, {Name: "note"}},
+			[][]core.Value{
+				{core.IntValue{Value: 10}, core.IntValue{Value: 2}, core.StringValue{Value: "gift"}},
+				{core.IntValue{Value: 11}, core.NullValue{}, core.StringValue{Value: "guest"}},
+			}).
+		AddResponse("SELECT * FROM `users` WHERE `id` = ?",
+			[]core.Column{{Name: "id"}, {Name: "name"}},
+			[][]core.Value{{core.IntValue{Value: 2}, core.StringValue{Value: "bob"}}})
+}
+
+func TestApp_foreignKeyLookup(t *testing.T) {
+	app := createTestApp(t)
+	conn := newMockOrdersConnection()
+	app.connection = conn
+	app.config = &core.ConnectionConfig{Name: "mock-db", DatabaseType: core.MySQL}
+
+	var buf bytes.Buffer
+	if err := app.processQuery(context.Background(), "SELECT * FROM orders", &buf); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+
+	table, lookup, err := app.foreignKeyLookup(app.lastStored.Rows[0], "user_id")
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if table != "orders" || lookup.Table != "users" || lookup.Column != "id" {
+		t.Errorf("Expected a lookup of users.id from orders, got %s %+v", table, lookup)
+	}
+
+	stored, err := app.runLookup(lookup)
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if len(stored.Rows) != 1 || stored.Rows[0][1].String() != "bob" {
+		t.Errorf("Expected the referenced user, got %v", stored.Rows)
+	}
+	if params := conn.ExecutedParams(); !reflect.DeepEqual(params, [][]any{{int64(2)}}) {
+		t.Errorf("Expected the key to be bound as a parameter, got %v", params)
+	}
+
+	for _, tt := range []struct{ column, want string }{
+		{"note", "not a foreign key"},
+		{"missing", "not a column"},
+	} {
+		if _, _, err := app.foreignKeyLookup(app.lastStored.Rows[0], tt.column); err == nil || !strings.Contains(err.Error(), tt.want) {
+			t.Errorf("Expected %q for column %s, got %v", tt.want, tt.column, err)
+		}
+	}
+	if _, _, err := app.foreignKeyLookup(app.lastStored.Rows[1], "user_id"); err == nil {
+		t.Error("Expected a NULL foreign key not to be followed")
+	}
+}
+
+func TestApp_breadcrumb(t *testing.T) {
+	app := createTestApp(t)
+	if crumb := app.breadcrumb(); crumb != "" {
+		t.Errorf("Expected no breadcrumb outside /follow, got %q", crumb)
+	}
+
+	app.navStack = []navEntry{{crumb: "orders"}, {crumb: "users #4521"}}
+	app.lastCrumb = "companies #7"
+	if crumb := app.breadcrumb(); crumb != "orders → users #4521 → companies #7" {
+		t.Errorf("Unexpected breadcrumb %q", crumb)
+	}
+
+	app.forgetResults()
+	if app.navStack != nil || app.lastCrumb != "" {
+		t.Error("Expected a new query to clear the navigation stack")
+	}
+}
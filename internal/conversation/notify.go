@@ -0,0 +1,111 @@
+package conversation
+
+import (
+	"fmt"
+	"io"
+	"strings"
+	"sync"
+	"time"
+
+	"sqlterm/internal/core"
+)
+
+// NotificationLevel controls how a notification is styled
+type NotificationLevel int
+
+const (
+	NotifyInfo NotificationLevel = iota
+	NotifySuccess
+	NotifyWarning
+)
+
+func (l NotificationLevel) icon() string {
+	switch l {
+	case NotifySuccess:
+		return "✅"
+	case NotifyWarning:
+		return "⚠️ "
+	default:
+		return "ℹ️ "
+	}
+}
+
+func (l NotificationLevel) color() string {
+	switch l {
+	case NotifySuccess:
+		return "\033[32m"
+	case NotifyWarning:
+		return "\033[33m"
+	default:
+		return "\033[36m"
+	}
+}
+
+// Notification is a short, non-error status message shown on a single line above the prompt
+type Notification struct {
+	Level     NotificationLevel
+	Message   string
+	CreatedAt time.Time
+}
+
+// notificationQueue collects notifications until the next prompt is drawn.
+// The zero value is ready to use.
+type notificationQueue struct {
+	mu    sync.Mutex
+	items []Notification
+}
+
+func (q *notificationQueue) push(level NotificationLevel, message string, now time.Time) {
+	q.mu.Lock()
+	defer q.mu.Unlock()
+	q.items = append(q.items, Notification{
+		Level:     level,
+		Message:   strings.TrimSpace(message),
+		CreatedAt: now,
+	})
+}
+
+// drain removes and returns all queued notifications; each one is shown exactly once
+func (q *notificationQueue) drain() []Notification {
+	q.mu.Lock()
+	defer q.mu.Unlock()
+
+	items := q.items
+	q.items = nil
+	return items
+}
+
+// renderNotification formats a notification as a single line, optionally colored
+func renderNotification(n Notification, color bool) string {
+	message := strings.Join(strings.Fields(n.Message), " ")
+	if !color {
+		return fmt.Sprintf("%s %s\n", n.Level.icon(), message)
+	}
+	return fmt.Sprintf("%s%s %s\033[0m\n", n.Level.color(), n.Level.icon(), message)
+}
+
+// notify queues a non-error status message that is shown once before the next prompt
+func (a *App) notify(level NotificationLevel, format string, args ...any) {
+	a.notifications.push(level, fmt.Sprintf(format, args...), time.Now())
+}
+
+// noteFileWarnings queues a warning for each file under the config directory
+// that could not be used since the last call
+func (a *App) noteFileWarnings() {
+	for _, warning := range core.TakeFileWarnings() {
+		if warning.MovedTo != "" {
+			a.notify(NotifyWarning, a.i18nMgr.Get("file_set_aside"), warning.Path, warning.Err, warning.MovedTo)
+		} else {
+			a.notify(NotifyWarning, a.i18nMgr.Get("file_skipped"), warning.Path, warning.Err)
+		}
+	}
+}
+
+// flushNotifications prints pending notifications as single-line banners
+func (a *App) flushNotifications(w io.Writer) {
+	a.noteFileWarnings()
+	color := colorEnabledFor(w)
+	for _, n := range a.notifications.drain() {
+		fmt.Fprint(w, renderNotification(n, color))
+	}
+}
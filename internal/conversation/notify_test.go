@@ -0,0 +1,92 @@
+package conversation
+
+import (
+	"bytes"
+	"strings"
+	"testing"
+	"time"
+)
+
+func TestNotificationQueue_Drain(t *testing.T) {
+	var q notificationQueue
+	now := time.Now()
+
+	q.push(NotifyInfo, "Schema refreshed", now)
+	q.push(NotifySuccess, "  Exported 3 rows to out.csv\n", now)
+
+	items := q.drain()
+	if len(items) != 2 {
+		t.Fatalf("Expected 2 notifications, got %d", len(items))
+	}
+
+	if items[1].Message != "Exported 3 rows to out.csv" {
+		t.Errorf("Expected message to be trimmed, got '%s'", items[1].Message)
+	}
+
+	if items[0].CreatedAt != now {
+		t.Error("Notification should keep its creation time")
+	}
+
+	if len(q.drain()) != 0 {
+		t.Error("Notifications should only be delivered once")
+	}
+}
+
+func TestRenderNotification(t *testing.T) {
+	testCases := []struct {
+		name     string
+		level    NotificationLevel
+		color    bool
+		expected string
+	}{
+		{
+			name:     "Success without color",
+			level:    NotifySuccess,
+			color:    false,
+			expected: "✅ Done\n",
+		},
+		{
+			name:     "Warning with color",
+			level:    NotifyWarning,
+			color:    true,
+			expected: "\033[33m⚠️  Done\033[0m\n",
+		},
+		{
+			name:     "Info with color",
+			level:    NotifyInfo,
+			color:    true,
+			expected: "\033[36mℹ️  Done\033[0m\n",
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.name, func(t *testing.T) {
+			result := renderNotification(Notification{Level: tc.level, Message: "Done"}, tc.color)
+			if result != tc.expected {
+				t.Errorf("Expected %q, got %q", tc.expected, result)
+			}
+		})
+	}
+}
+
+func TestApp_flushNotifications(t *testing.T) {
+	app := createTestApp(t)
+
+	app.notify(NotifySuccess, "Exported %d rows to %s", 2, "out.csv")
+
+	var buf bytes.Buffer
+	app.flushNotifications(&buf)
+
+	if !strings.Contains(buf.String(), "Exported 2 rows to out.csv") {
+		t.Errorf("Expected flushed notification, got %q", buf.String())
+	}
+	if strings.Contains(buf.String(), "\033[") {
+		t.Errorf("Expected no colors when writing to a buffer, got %q", buf.String())
+	}
+
+	buf.Reset()
+	app.flushNotifications(&buf)
+	if buf.Len() != 0 {
+		t.Errorf("Expected no output on second flush, got %q", buf.String())
+	}
+}
@@ -0,0 +1,246 @@
+package conversation
+
+import (
+	"fmt"
+	"os"
+	"sort"
+	"strconv"
+	"strings"
+
+	"sqlterm/internal/core"
+	"sqlterm/internal/utils"
+)
+
+// paletteKey turns the prompt line into a /palette search (Ctrl+O). Ctrl+P
+// reaches readline as the same key as Up, so it stays with the history.
+const paletteKey = 15
+
+const (
+	// paletteSize is how many matches the palette lists
+	paletteSize = 10
+	// paletteQueries is how many recent queries the palette searches
+	paletteQueries = 20
+	// previewRows is how many rows choosing a table shows
+	previewRows = 10
+)
+
+// paletteKind is what a palette item is, shown as a badge before its name
+type paletteKind int
+
+const (
+	paletteConnection paletteKind = iota
+	paletteTable
+	paletteBuffer
+	paletteQuery
+)
+
+func (k paletteKind) badge(a *App) string {
+	switch k {
+	case paletteConnection:
+		return a.i18nMgr.Get("palette_kind_connection")
+	case paletteTable:
+		return a.i18nMgr.Get("palette_kind_table")
+	case paletteBuffer:
+		return a.i18nMgr.Get("palette_kind_buffer")
+	default:
+		return a.i18nMgr.Get("palette_kind_query")
+	}
+}
+
+// paletteItem is one thing the palette can find: Name is what the search
+// matches and run is what choosing it does
+type paletteItem struct {
+	Kind   paletteKind
+	Name   string
+	Detail string
+	run    func() error
+}
+
+// handlePalette searches saved connections, cached tables, query buffers and
+// recent queries at once, then does the obvious thing with the chosen one:
+// /palette [text]
+func (a *App) handlePalette(args []string) error {
+	matches := rankPalette(strings.Join(args, " "), a.paletteItems())
+	if len(matches) == 0 {
+		fmt.Println(a.i18nMgr.Get("palette_no_matches"))
+		return nil
+	}
+	matches = matches[:min(len(matches), paletteSize)]
+
+	width := 0
+	for _, item := range matches {
+		width = max(width, core.DisplayWidth(item.Kind.badge(a)))
+	}
+	for i, item := range matches {
+		badge := item.Kind.badge(a)
+		badge += strings.Repeat(" ", width-core.DisplayWidth(badge))
+		fmt.Printf("%2d. [%s] %s", i+1, badge, a.truncateQuery(item.Name))
+		if item.Detail != "" {
+			fmt.Printf("  %s", item.Detail)
+		}
+		fmt.Println()
+	}
+
+	choice := a.choose(fmt.Sprintf(a.i18nMgr.Get("palette_choose"), len(matches)), len(matches))
+	if choice < 0 {
+		return nil
+	}
+	return matches[choice].run()
+}
+
+// paletteItems gathers what the palette searches, connections in the same
+// order as /list-connections. It only reads what is already at hand, so it
+// never waits on the server: tables come from the schema cache and are
+// missing until something has listed them.
+func (a *App) paletteItems() []paletteItem {
+	var items []paletteItem
+
+	if connections, _, err := a.configMgr.OrderedConnections(a.connectionOrder()); err == nil {
+		for _, conn := range connections {
+			name := conn.Name
+			items = append(items, paletteItem{
+				Kind:   paletteConnection,
+				Name:   name,
+				Detail: fmt.Sprintf("%s %s", conn.DatabaseType, conn.Database),
+				run:    func() error { return a.handleConnect([]string{name}) },
+			})
+		}
+	}
+
+	if a.connection != nil {
+		for _, table := range a.schema().TableNames() {
+			items = append(items, paletteItem{
+				Kind: paletteTable,
+				Name: table,
+				run:  func() error { return a.previewTable(table) },
+			})
+		}
+	}
+
+	if a.buffers != nil {
+		for i, buffer := range a.buffers.Buffers {
+			items = append(items, paletteItem{
+				Kind:   paletteBuffer,
+				Name:   buffer.Name,
+				Detail: a.bufferSummary(buffer),
+				run: func() error {
+					a.buffers.Active = i
+					a.saveBuffers()
+					a.showBuffer()
+					return nil
+				},
+			})
+		}
+	}
+
+	if a.rl != nil {
+		for _, query := range recentQueries(a.rl.Config.HistoryFile, paletteQueries) {
+			items = append(items, paletteItem{
+				Kind: paletteQuery,
+				Name: query,
+				run:  func() error { return a.loadQuery(query) },
+			})
+		}
+	}
+	return items
+}
+
+// rankPalette returns the items whose names match pattern, best first. Items
+// that rank equally keep the order they were gathered in.
+func rankPalette(pattern string, items []paletteItem) []paletteItem {
+	type ranked struct {
+		item  paletteItem
+		match utils.Match
+	}
+	var matches []ranked
+	for _, item := range items {
+		if match, ok := utils.FuzzyMatch(pattern, item.Name); ok {
+			matches = append(matches, ranked{item, match})
+		}
+	}
+	sort.SliceStable(matches, func(i, j int) bool {
+		if matches[i].match.Tier != matches[j].match.Tier {
+			return matches[i].match.Tier < matches[j].match.Tier
+		}
+		return matches[i].match.Score > matches[j].match.Score
+	})
+
+	items = make([]paletteItem, len(matches))
+	for i, m := range matches {
+		items[i] = m.item
+	}
+	return items
+}
+
+// recentQueries returns up to limit distinct /exec queries from historyFile,
+// most recent first
+func recentQueries(historyFile string, limit int) []string {
+	data, err := os.ReadFile(historyFile)
+	if err != nil {
+		return nil
+	}
+
+	lines := strings.Split(string(data), "\n")
+	seen := make(map[string]bool)
+	var queries []string
+	for i := len(lines) - 1; i >= 0 && len(queries) < limit; i-- {
+		query, ok := execQuery(lines[i])
+		if !ok || query == "" || seen[query] {
+			continue
+		}
+		seen[query] = true
+		queries = append(queries, query)
+	}
+	return queries
+}
+
+// previewTable shows the first rows of table as a normal result
+func (a *App) previewTable(table string) error {
+	query := fmt.Sprintf("SELECT * FROM %s LIMIT %d", core.QuoteIdentifier(a.config.DatabaseType, table), previewRows)
+	return a.executeAndView(query)
+}
+
+// loadQuery puts query on the next prompt line as an /exec to edit or run
+func (a *App) loadQuery(query string) error {
+	line := "/exec " + query
+	if a.rl == nil {
+		fmt.Println(line)
+		return nil
+	}
+	_, err := a.rl.WriteStdin([]byte(line))
+	return err
+}
+
+// choose asks for a number from 1 to n on the prompt line and returns its
+// index, or -1 when the answer is empty or not one of them
+func (a *App) choose(question string, n int) int {
+	if a.rl == nil {
+		return -1
+	}
+
+	a.rl.HistoryDisable()
+	defer a.rl.HistoryEnable()
+	defer a.updatePrompt()
+
+	a.rl.SetPrompt(question)
+	answer, err := a.rl.Readline()
+	if err != nil {
+		return -1
+	}
+	choice, err := strconv.Atoi(strings.TrimSpace(answer))
+	if err != nil || choice < 1 || choice > n {
+		return -1
+	}
+	return choice - 1
+}
+
+// paletteLine turns the prompt line into a /palette search for its text,
+// dropping paletteKey in case readline inserted it
+func paletteLine(line []rune) ([]rune, int, bool) {
+	text := strings.TrimSpace(strings.ReplaceAll(string(line), string(rune(paletteKey)), ""))
+	if !strings.HasPrefix(text, "/palette") {
+		text = strings.TrimSpace("/palette " + text)
+	}
+	text += " "
+	return []rune(text), len([]rune(text)), true
+}
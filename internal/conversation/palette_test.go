@@ -0,0 +1,89 @@
+package conversation
+
+import (
+	"os"
+	"path/filepath"
+	"reflect"
+	"testing"
+
+	"sqlterm/internal/core"
+	"sqlterm/internal/core/coretest"
+)
+
+func TestRankPalette(t *testing.T) {
+	items := []paletteItem{
+		{Kind: paletteConnection, Name: "orders-replica"},
+		{Kind: paletteTable, Name: "customer_orders"},
+		{Kind: paletteTable, Name: "orders"},
+		{Kind: paletteQuery, Name: "SELECT * FROM payments"},
+	}
+
+	var names []string
+	for _, item := range rankPalette("orders", items) {
+		names = append(names, item.Name)
+	}
+	want := []string{"orders-replica", "orders", "customer_orders"}
+	if !reflect.DeepEqual(names, want) {
+		t.Errorf("rankPalette() = %v, want %v", names, want)
+	}
+
+	if got := rankPalette("", items); len(got) != len(items) {
+		t.Errorf("Expected an empty search to list everything, got %d items", len(got))
+	}
+}
+
+func TestApp_paletteItems_CachedOnly(t *testing.T) {
+	app := createTestApp(t)
+	conn := coretest.NewMockConnection().AddTable(&core.TableInfo{Name: "users"})
+	app.connection = conn
+	app.config = &core.ConnectionConfig{Name: "mock-db"}
+	app.buffers = &queryBuffers{Buffers: []*queryBuffer{{Name: "report", Query: "SELECT 1"}}}
+
+	for _, item := range app.paletteItems() {
+		if item.Kind == paletteTable {
+			t.Errorf("Expected no tables before the table list is cached, got %q", item.Name)
+		}
+	}
+
+	if _, _, err := app.schema().Tables(conn.ListTables); err != nil {
+		t.Fatal(err)
+	}
+	kinds := make(map[paletteKind][]string)
+	for _, item := range app.paletteItems() {
+		kinds[item.Kind] = append(kinds[item.Kind], item.Name)
+	}
+	if !reflect.DeepEqual(kinds[paletteTable], []string{"users"}) || !reflect.DeepEqual(kinds[paletteBuffer], []string{"report"}) {
+		t.Errorf("Expected the cached table and the buffer, got %v", kinds)
+	}
+}
+
+func TestRecentQueries(t *testing.T) {
+	history := filepath.Join(t.TempDir(), "history.txt")
+	content := "/exec SELECT 1;\nhow many users?\n/exec SELECT 2;\n/tables\n/exec SELECT 1;\n"
+	if err := os.WriteFile(history, []byte(content), 0644); err != nil {
+		t.Fatal(err)
+	}
+
+	got := recentQueries(history, 5)
+	want := []string{"SELECT 1;", "SELECT 2;"}
+	if !reflect.DeepEqual(got, want) {
+		t.Errorf("recentQueries() = %v, want %v", got, want)
+	}
+}
+
+func TestPaletteLine(t *testing.T) {
+	tests := []struct {
+		line string
+		want string
+	}{
+		{"ord\x0f", "/palette ord "},
+		{"\x0f", "/palette "},
+		{"/palette us\x0f", "/palette us "},
+	}
+	for _, tt := range tests {
+		line, pos, ok := paletteLine([]rune(tt.line))
+		if !ok || string(line) != tt.want || pos != len([]rune(tt.want)) {
+			t.Errorf("paletteLine(%q) = %q at %d, want %q", tt.line, string(line), pos, tt.want)
+		}
+	}
+}
@@ -0,0 +1,63 @@
+package conversation
+
+import (
+	"fmt"
+	"strings"
+	"sync"
+	"time"
+
+	"github.com/chzyer/readline"
+)
+
+// pastedNewline stands in for a line break inside pasted text, so the paste
+// stays on the prompt line until Enter is pressed
+const pastedNewline = '␤'
+
+// pasteGap is how soon after the previous key an Enter must arrive to count
+// as part of a paste; typed keys are much further apart
+const pasteGap = 5 * time.Millisecond
+
+// pasteDetector tells pasted line breaks from typed Enter by how closely they
+// follow the previous key. readline does not report bracketed paste, but a
+// terminal delivers a paste in one burst.
+type pasteDetector struct {
+	mu   sync.Mutex
+	last time.Time
+	now  func() time.Time
+}
+
+func newPasteDetector() *pasteDetector {
+	return &pasteDetector{now: time.Now}
+}
+
+// filter implements readline's FuncFilterInputRune
+func (d *pasteDetector) filter(r rune) (rune, bool) {
+	d.mu.Lock()
+	defer d.mu.Unlock()
+
+	now := d.now()
+	burst := !d.last.IsZero() && now.Sub(d.last) < pasteGap
+	d.last = now
+	if burst && (r == readline.CharEnter || r == readline.CharCtrlJ) {
+		return pastedNewline, true
+	}
+	return r, true
+}
+
+// expandPaste restores the line breaks of pasted text and reports how many
+// lines it holds. Blank lines at either end are dropped.
+func expandPaste(line string) (string, int) {
+	if !strings.ContainsRune(line, pastedNewline) {
+		return line, 1
+	}
+	text := strings.TrimSpace(strings.ReplaceAll(line, string(pastedNewline), "\n"))
+	return text, strings.Count(text, "\n") + 1
+}
+
+// showPaste prints pasted text line by line so it can be checked before it runs
+func (a *App) showPaste(text string, lines int) {
+	fmt.Printf(a.i18nMgr.Get("pasted_lines"), lines)
+	for i, line := range strings.Split(text, "\n") {
+		fmt.Printf("  %2d│ %s\n", i+1, line)
+	}
+}
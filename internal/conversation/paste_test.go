@@ -0,0 +1,62 @@
+package conversation
+
+import (
+	"testing"
+	"time"
+
+	"github.com/chzyer/readline"
+)
+
+func TestPasteDetector_Filter(t *testing.T) {
+	start := time.Date(2026, 3, 1, 12, 0, 0, 0, time.UTC)
+	tests := []struct {
+		name  string
+		keys  []rune
+		gaps  []time.Duration
+		wantR rune
+	}{
+		{"typed enter", []rune{'a', readline.CharEnter}, []time.Duration{0, 200 * time.Millisecond}, readline.CharEnter},
+		{"pasted enter", []rune{'a', readline.CharEnter}, []time.Duration{0, time.Millisecond}, pastedNewline},
+		{"pasted line feed", []rune{'a', readline.CharCtrlJ}, []time.Duration{0, time.Millisecond}, pastedNewline},
+		{"first key", []rune{readline.CharEnter}, []time.Duration{0}, readline.CharEnter},
+		{"other key in a burst", []rune{'a', 'b'}, []time.Duration{0, time.Millisecond}, 'b'},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			now := start
+			detector := &pasteDetector{now: func() time.Time { return now }}
+			var got rune
+			for i, key := range tt.keys {
+				now = now.Add(tt.gaps[i])
+				var process bool
+				if got, process = detector.filter(key); !process {
+					t.Fatalf("Expected key %q to be processed", key)
+				}
+			}
+			if got != tt.wantR {
+				t.Errorf("Last key = %q, want %q", got, tt.wantR)
+			}
+		})
+	}
+}
+
+func TestExpandPaste(t *testing.T) {
+	tests := []struct {
+		name      string
+		line      string
+		wantText  string
+		wantLines int
+	}{
+		{"typed line", "SELECT 1;", "SELECT 1;", 1},
+		{"pasted script", "SELECT 1 -- one␤FROM t;␤", "SELECT 1 -- one\nFROM t;", 2},
+		{"only a trailing break", "SELECT 1;␤", "SELECT 1;", 1},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			text, lines := expandPaste(tt.line)
+			if text != tt.wantText || lines != tt.wantLines {
+				t.Errorf("expandPaste(%q) = %q, %d, want %q, %d", tt.line, text, lines, tt.wantText, tt.wantLines)
+			}
+		})
+	}
+}
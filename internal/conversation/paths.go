@@ -0,0 +1,81 @@
+package conversation
+
+import (
+	"os"
+	"path/filepath"
+	"sort"
+	"strings"
+
+	"sqlterm/internal/core"
+)
+
+// pathSep is appended to completed directory names, matching the platform
+var pathSep = string(filepath.Separator)
+
+// projectRootPrefix marks a file reference relative to the project root, e.g. @//queries/report.sql
+const projectRootPrefix = "//"
+
+// resolveQueryFile finds a file referenced with @
+func resolveQueryFile(filename, projectRoot string) (string, bool) {
+	for _, candidate := range queryFileCandidates(core.ExpandPath(filename), projectRoot) {
+		if info, err := os.Stat(candidate); err == nil && !info.IsDir() {
+			return candidate, true
+		}
+	}
+	return "", false
+}
+
+// queryFileCandidates lists where a file reference may live, in resolution order:
+// an absolute path as-is, then relative to the current directory and ./queries,
+// then relative to the project root and its queries directory
+func queryFileCandidates(filename, projectRoot string) []string {
+	if strings.HasPrefix(filename, projectRootPrefix) {
+		if projectRoot == "" {
+			return nil
+		}
+		return []string{filepath.Join(projectRoot, filename[len(projectRootPrefix):])}
+	}
+	if filepath.IsAbs(filename) {
+		return []string{filename}
+	}
+
+	candidates := []string{filename, filepath.Join("queries", filename)}
+	if projectRoot != "" {
+		candidates = append(candidates, filepath.Join(projectRoot, filename), filepath.Join(projectRoot, "queries", filename))
+	}
+	return candidates
+}
+
+// hasTrailingSeparator reports whether path names a directory by ending in a
+// separator; on Windows both / and \ count
+func hasTrailingSeparator(path string) bool {
+	return path != "" && os.IsPathSeparator(path[len(path)-1])
+}
+
+// isGlobPattern reports whether a file reference contains glob metacharacters
+func isGlobPattern(filename string) bool {
+	return strings.ContainsAny(filename, "*?[")
+}
+
+// expandQueryGlob returns the files matching pattern in lexical order, searching
+// the same places as resolveQueryFile and stopping at the first that matches
+func expandQueryGlob(pattern, projectRoot string) ([]string, error) {
+	for _, candidate := range queryFileCandidates(core.ExpandPath(pattern), projectRoot) {
+		matches, err := filepath.Glob(candidate)
+		if err != nil {
+			return nil, err
+		}
+
+		var files []string
+		for _, match := range matches {
+			if info, err := os.Stat(match); err == nil && !info.IsDir() {
+				files = append(files, match)
+			}
+		}
+		if len(files) > 0 {
+			sort.Strings(files)
+			return files, nil
+		}
+	}
+	return nil, nil
+}
@@ -0,0 +1,79 @@
+package conversation
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+)
+
+func TestResolveQueryFile(t *testing.T) {
+	dir := t.TempDir()
+	wd, _ := os.Getwd()
+	defer os.Chdir(wd)
+	os.Chdir(dir)
+
+	os.MkdirAll(filepath.Join("queries", "reports"), 0755)
+	os.WriteFile("top.sql", []byte("SELECT 1;"), 0644)
+	os.WriteFile(filepath.Join("queries", "reports", "daily.sql"), []byte("SELECT 2;"), 0644)
+
+	testCases := []struct {
+		name     string
+		filename string
+		expected string
+		found    bool
+	}{
+		{name: "Current directory", filename: "top.sql", expected: "top.sql", found: true},
+		{name: "Queries directory", filename: filepath.Join("reports", "daily.sql"), expected: filepath.Join("queries", "reports", "daily.sql"), found: true},
+		{name: "Absolute path", filename: filepath.Join(dir, "top.sql"), expected: filepath.Join(dir, "top.sql"), found: true},
+		{name: "Directory is not a file", filename: "queries", found: false},
+		{name: "Missing", filename: "missing.sql", found: false},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.name, func(t *testing.T) {
+			path, found := resolveQueryFile(tc.filename, "")
+			if found != tc.found || path != tc.expected {
+				t.Errorf("resolveQueryFile(%q) = %q, %v; expected %q, %v", tc.filename, path, found, tc.expected, tc.found)
+			}
+		})
+	}
+}
+
+func TestHasTrailingSeparator(t *testing.T) {
+	testCases := []struct {
+		path     string
+		expected bool
+	}{
+		{"queries" + pathSep, true},
+		{filepath.Join("queries", "reports") + pathSep, true},
+		{"queries", false},
+		{"", false},
+	}
+
+	for _, tc := range testCases {
+		if got := hasTrailingSeparator(tc.path); got != tc.expected {
+			t.Errorf("hasTrailingSeparator(%q) = %v, expected %v", tc.path, got, tc.expected)
+		}
+	}
+}
+
+func TestAutoCompleter_FileCandidatesUsePlatformSeparator(t *testing.T) {
+	app := createTestApp(t)
+	ac := NewAutoCompleter(app)
+
+	dir := t.TempDir()
+	os.MkdirAll(filepath.Join(dir, "reports", "monthly"), 0755)
+	os.WriteFile(filepath.Join(dir, "reports", "daily.sql"), []byte("SELECT 1;"), 0644)
+
+	candidates := ac.getFileCandidates("@" + filepath.Join(dir, "reports") + pathSep)
+
+	expected := map[string]bool{"daily.sql": true, "monthly" + pathSep: true}
+	if len(candidates) != len(expected) {
+		t.Fatalf("Expected %d candidates, got %v", len(expected), candidates)
+	}
+	for _, candidate := range candidates {
+		if !expected[candidate] {
+			t.Errorf("Unexpected candidate %q", candidate)
+		}
+	}
+}
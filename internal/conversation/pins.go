@@ -0,0 +1,119 @@
+package conversation
+
+import (
+	"errors"
+	"fmt"
+	"sort"
+	"strings"
+	"unicode"
+
+	"sqlterm/internal/core"
+)
+
+// handlePin keeps the last result under a name so later queries can use its
+// values as :name.column: /pin [last as] <name>
+func (a *App) handlePin(args []string) error {
+	if len(args) == 3 && args[0] == "last" && args[1] == "as" {
+		args = args[2:]
+	}
+	if len(args) != 1 || !isPinName(args[0]) {
+		fmt.Println(a.i18nMgr.Get("usage_pin"))
+		return nil
+	}
+	if a.lastStored == nil {
+		fmt.Println(a.i18nMgr.Get("no_result_to_pin"))
+		return nil
+	}
+
+	if a.pins == nil {
+		a.pins = make(map[string]*core.StoredResult)
+	}
+	name := args[0]
+	a.pins[name] = a.lastStored
+	a.notify(NotifySuccess, a.i18nMgr.Get("result_pinned"), len(a.lastStored.Rows), name, name)
+	return nil
+}
+
+// handlePins lists the pinned results: /pins [list]
+func (a *App) handlePins(args []string) error {
+	if len(args) > 1 || (len(args) == 1 && args[0] != "list") {
+		fmt.Println(a.i18nMgr.Get("usage_pins"))
+		return nil
+	}
+	if len(a.pins) == 0 {
+		fmt.Println(a.i18nMgr.Get("no_pins"))
+		return nil
+	}
+
+	names := make([]string, 0, len(a.pins))
+	for name := range a.pins {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+	for _, name := range names {
+		pinned := a.pins[name]
+		columns := make([]string, len(pinned.Columns))
+		for i, column := range pinned.Columns {
+			columns[i] = column.Name
+		}
+		fmt.Printf(a.i18nMgr.Get("pin_entry"), pinned.Source.Label(), name, len(pinned.Rows), strings.Join(columns, ", "))
+	}
+	return nil
+}
+
+// handleUnpin forgets a pinned result: /unpin <name>
+func (a *App) handleUnpin(args []string) error {
+	if len(args) != 1 {
+		fmt.Println(a.i18nMgr.Get("usage_unpin"))
+		return nil
+	}
+	if _, ok := a.pins[args[0]]; !ok {
+		fmt.Printf(a.i18nMgr.Get("pin_not_found"), args[0])
+		return nil
+	}
+	delete(a.pins, args[0])
+	fmt.Printf(a.i18nMgr.Get("result_unpinned"), args[0])
+	return nil
+}
+
+// expandPins replaces :name.column references in query with the pinned values
+func (a *App) expandPins(query string) (string, error) {
+	if len(a.pins) == 0 || a.config == nil {
+		return query, nil
+	}
+
+	expansion, err := core.ExpandPins(query, a.config.DatabaseType, a.pins, a.maxPinValues())
+	if errors.Is(err, core.ErrTooManyPinValues) {
+		return "", fmt.Errorf(a.i18nMgr.Get("pin_too_many_values"), err)
+	}
+	if err != nil {
+		return "", err
+	}
+	if expansion.SkippedNulls > 0 {
+		a.notify(NotifyWarning, a.i18nMgr.Get("pin_nulls_skipped"), expansion.SkippedNulls)
+	}
+	return expansion.Query, nil
+}
+
+// maxPinValues is how many values one pin reference may inline
+func (a *App) maxPinValues() int {
+	if a.aiManager != nil {
+		if limit := a.aiManager.GetConfig().MaxPinValues; limit > 0 {
+			return limit
+		}
+	}
+	return core.DefaultMaxPinValues
+}
+
+// isPinName reports whether name can be used in a :name.column reference
+func isPinName(name string) bool {
+	if name == "" {
+		return false
+	}
+	for _, r := range name {
+		if r != '_' && !unicode.IsLetter(r) && !unicode.IsDigit(r) {
+			return false
+		}
+	}
+	return true
+}
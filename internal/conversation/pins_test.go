@@ -0,0 +1,59 @@
+package conversation
+
+import (
+	"bytes"
+	"context"
+	"slices"
+	"testing"
+
+	"sqlterm/internal/core"
+)
+
+func TestApp_pinnedQuery(t *testing.T) {
+	app := createTestApp(t)
+	conn := newMockUsersConnection().
+		AddResponse("SELECT id FROM users", []core.Column{{Name: "id"}},
+			[][]core.Value{{core.IntValue{Value: 4}}, {core.IntValue{Value: 9}}}).
+		AddResponse("SELECT * FROM orders WHERE user_id IN (4, 9)", []core.Column{{Name: "id"}}, nil)
+	app.connection = conn
+	app.config = &core.ConnectionConfig{Name: "mock-db", DatabaseType: core.MySQL}
+
+	var buf bytes.Buffer
+	if err := app.processQuery(context.Background(), "SELECT id FROM users", &buf); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if err := app.handlePin([]string{"last", "as", "ids"}); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if err := app.processQuery(context.Background(), "SELECT * FROM orders WHERE user_id IN (:ids.id)", &buf); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if queries := conn.ExecutedQueries(); !slices.Contains(queries, "SELECT * FROM orders WHERE user_id IN (4, 9)") {
+		t.Errorf("Expected the pinned ids to be inlined, got %v", queries)
+	}
+
+	if err := app.handleUnpin([]string{"ids"}); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if len(app.pins) != 0 {
+		t.Errorf("Expected /unpin to forget the pin, got %v", app.pins)
+	}
+}
+
+func TestIsPinName(t *testing.T) {
+	tests := []struct {
+		name string
+		want bool
+	}{
+		{"ids", true},
+		{"user_ids2", true},
+		{"", false},
+		{"my.ids", false},
+		{"ids-2", false},
+	}
+	for _, tt := range tests {
+		if got := isPinName(tt.name); got != tt.want {
+			t.Errorf("isPinName(%q) = %v, want %v", tt.name, got, tt.want)
+		}
+	}
+}
@@ -0,0 +1,102 @@
+package conversation
+
+import (
+	"context"
+	"fmt"
+	"path/filepath"
+	"strings"
+	"time"
+
+	"sqlterm/internal/core"
+)
+
+// projectRoot returns the discovered project root, or "" outside a project
+func (a *App) projectRoot() string {
+	if a.project == nil {
+		return ""
+	}
+	return a.project.Root
+}
+
+// initSQLTimeout bounds each init statement so a locked server cannot hang connecting
+const initSQLTimeout = 30 * time.Second
+
+// runInitSQL executes the project's init statements on a new connection.
+// Each statement is echoed; failures are reported but do not abort the connection.
+func (a *App) runInitSQL(conn core.Connection) {
+	if a.project == nil {
+		return
+	}
+
+	for _, statement := range a.project.InitSQL {
+		fmt.Printf(a.i18nMgr.Get("init_sql_running"), statement)
+
+		ctx, cancel := context.WithTimeout(context.Background(), initSQLTimeout)
+		result, err := conn.ExecuteContext(ctx, statement)
+		if err == nil {
+			// Drain so drivers that run statements lazily actually execute it
+			for range result.Itor() {
+			}
+			err = result.Error()
+			result.Close()
+		}
+		cancel()
+
+		if err != nil {
+			fmt.Printf(a.i18nMgr.Get("init_sql_failed"), statement, err)
+		}
+	}
+}
+
+// checkReadOnly rejects statements that could modify data when the project is read-only
+func (a *App) checkReadOnly(query string) error {
+	if a.project == nil || !a.project.ReadOnly || core.IsReadOnlyQuery(a.dbType(), query) {
+		return nil
+	}
+	return fmt.Errorf(a.i18nMgr.Get("read_only_query_rejected"), core.ErrReadOnly)
+}
+
+// printProjectStatus shows the active project and the settings it applies
+func (a *App) printProjectStatus() {
+	if a.project == nil {
+		return
+	}
+
+	fmt.Printf(a.i18nMgr.Get("project_root_info"), a.project.Root)
+	if a.project.Settings != "" {
+		fmt.Printf(a.i18nMgr.Get("project_settings_info"), a.project.Settings)
+	}
+	if a.project.ReadOnly {
+		fmt.Println(a.i18nMgr.Get("project_read_only_info"))
+	}
+}
+
+// getProjectFileCandidates offers files under the project root as //path completions.
+// They are only offered when the reference is empty or already starts with //,
+// and not when the project root is the working directory.
+func (ac *AutoCompleter) getProjectFileCandidates(path string) []string {
+	root := ac.app.projectRoot()
+	if root == "" || (path != "" && !strings.HasPrefix(path, projectRootPrefix)) {
+		return nil
+	}
+	if wd, err := filepath.Abs("."); err == nil && wd == root {
+		return nil
+	}
+
+	var candidates []string
+	if path == "" {
+		ac.addFileCandidates(&candidates, root, "", "")
+		for i, candidate := range candidates {
+			candidates[i] = projectRootPrefix + candidate
+		}
+		return candidates
+	}
+
+	rel := path[len(projectRootPrefix):]
+	dir, baseName := filepath.Dir(rel), filepath.Base(rel)
+	if rel == "" || hasTrailingSeparator(rel) {
+		dir, baseName = rel, ""
+	}
+	ac.addFileCandidates(&candidates, filepath.Join(root, dir), baseName, "")
+	return candidates
+}
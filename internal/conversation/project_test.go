@@ -0,0 +1,135 @@
+package conversation
+
+import (
+	"bytes"
+	"context"
+	"errors"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"sqlterm/internal/config"
+	"sqlterm/internal/core"
+	"sqlterm/internal/core/coretest"
+)
+
+// chdirProject creates a project with queries/report.sql and moves into a subdirectory of it
+func chdirProject(t *testing.T) string {
+	t.Helper()
+
+	root := t.TempDir()
+	os.MkdirAll(filepath.Join(root, "queries"), 0755)
+	os.MkdirAll(filepath.Join(root, "docs"), 0755)
+	os.WriteFile(filepath.Join(root, config.ProjectFileName), nil, 0644)
+	os.WriteFile(filepath.Join(root, "queries", "report.sql"), []byte("SELECT 1;"), 0644)
+	os.WriteFile(filepath.Join(root, "setup.sql"), []byte("SELECT 1;"), 0644)
+
+	wd, _ := os.Getwd()
+	t.Cleanup(func() { os.Chdir(wd) })
+	os.Chdir(filepath.Join(root, "docs"))
+	return root
+}
+
+func TestResolveQueryFile_ProjectRoot(t *testing.T) {
+	root := chdirProject(t)
+	report := filepath.Join(root, "queries", "report.sql")
+
+	testCases := []struct {
+		name        string
+		filename    string
+		projectRoot string
+		expected    string
+		found       bool
+	}{
+		{name: "Relative to project root", filename: filepath.Join("queries", "report.sql"), projectRoot: root, expected: report, found: true},
+		{name: "Project queries directory", filename: "report.sql", projectRoot: root, expected: report, found: true},
+		{name: "Explicit project prefix", filename: "//setup.sql", projectRoot: root, expected: filepath.Join(root, "setup.sql"), found: true},
+		{name: "Outside a project", filename: filepath.Join("queries", "report.sql"), found: false},
+		{name: "Project prefix outside a project", filename: "//setup.sql", found: false},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.name, func(t *testing.T) {
+			path, found := resolveQueryFile(tc.filename, tc.projectRoot)
+			if found != tc.found || path != tc.expected {
+				t.Errorf("resolveQueryFile(%q) = %q, %v; expected %q, %v", tc.filename, path, found, tc.expected, tc.found)
+			}
+		})
+	}
+}
+
+func TestAutoCompleter_ProjectFileCandidates(t *testing.T) {
+	root := chdirProject(t)
+	app := createTestApp(t)
+	app.project = &config.Project{Root: root}
+	ac := NewAutoCompleter(app)
+
+	candidates := ac.getFileCandidates("@")
+	expected := map[string]bool{"//setup.sql": false, "//queries" + pathSep: false}
+	for _, candidate := range candidates {
+		if _, ok := expected[candidate]; ok {
+			expected[candidate] = true
+		}
+	}
+	for candidate, found := range expected {
+		if !found {
+			t.Errorf("Expected project candidate %q, got %v", candidate, candidates)
+		}
+	}
+
+	candidates = ac.getFileCandidates("@//queries" + pathSep + "re")
+	if len(candidates) != 1 || candidates[0] != "port.sql" {
+		t.Errorf("Expected completion inside the project root, got %v", candidates)
+	}
+}
+
+func TestApp_Flow_ReadOnlyProject(t *testing.T) {
+	app := createTestApp(t)
+	conn := newMockUsersConnection().AddResponse("DELETE FROM users", nil, nil)
+	app.connection = conn
+	app.config = &core.ConnectionConfig{Name: "mock-db"}
+	app.project = &config.Project{Root: t.TempDir(), ReadOnly: true}
+
+	var buf bytes.Buffer
+	if err := app.processQuery(context.Background(), "SELECT id, name FROM users", &buf); err != nil {
+		t.Fatalf("Expected reads to be allowed, got %v", err)
+	}
+
+	err := app.processQuery(context.Background(), "DELETE FROM users", &buf)
+	if !errors.Is(err, core.ErrReadOnly) {
+		t.Errorf("Expected ErrReadOnly, got %v", err)
+	}
+	if queries := conn.ExecutedQueries(); len(queries) != 1 {
+		t.Errorf("Expected the rejected statement not to reach the connection, got %v", queries)
+	}
+}
+
+func TestApp_runInitSQL(t *testing.T) {
+	app := createTestApp(t)
+	conn := coretest.NewMockConnection().
+		AddQueryError("SET search_path TO app", errors.New("schema app does not exist")).
+		AddResponse("SET time_zone = '+00:00'", nil, nil)
+	app.project = &config.Project{
+		Root:    t.TempDir(),
+		InitSQL: []string{"SET search_path TO app", "SET time_zone = '+00:00'"},
+	}
+
+	app.runInitSQL(conn)
+
+	queries := conn.ExecutedQueries()
+	if len(queries) != 2 || queries[1] != "SET time_zone = '+00:00'" {
+		t.Errorf("Expected every init statement to run despite the failure, got %v", queries)
+	}
+}
+
+func TestApp_formatOptions_ProjectRowLimit(t *testing.T) {
+	app := createTestApp(t)
+	if limit := app.formatOptions().RowLimit; limit != 0 {
+		t.Errorf("Expected no row limit override outside a project, got %d", limit)
+	}
+
+	app.project = &config.Project{Root: t.TempDir(), RowLimit: 50}
+	if limit := app.formatOptions().RowLimit; limit != 50 {
+		t.Errorf("Expected the project row limit, got %d", limit)
+	}
+}
@@ -0,0 +1,108 @@
+package conversation
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+	"sync"
+)
+
+// maxRecentFiles bounds how many executed files are remembered for completion
+const maxRecentFiles = 20
+
+// recentFiles remembers SQL files executed with @, most recent first, so the
+// completer can offer them from any working directory. Files that no longer
+// exist are pruned when the list is loaded. A nil *recentFiles is empty.
+type recentFiles struct {
+	mu    sync.Mutex
+	path  string
+	files []string
+}
+
+// loadRecentFiles reads the list stored at path; a missing file is an empty list
+func loadRecentFiles(path string) *recentFiles {
+	r := &recentFiles{path: path}
+
+	data, err := os.ReadFile(path)
+	if err != nil {
+		return r
+	}
+
+	pruned := false
+	for _, line := range strings.Split(string(data), "\n") {
+		file := strings.TrimSpace(line)
+		if file == "" {
+			continue
+		}
+		if _, err := os.Stat(file); err != nil {
+			pruned = true
+			continue
+		}
+		r.files = append(r.files, file)
+	}
+
+	if pruned {
+		r.save()
+	}
+	return r
+}
+
+// add moves file to the top of the list and saves it
+func (r *recentFiles) add(file string) error {
+	if r == nil {
+		return nil
+	}
+
+	abs, err := filepath.Abs(file)
+	if err != nil {
+		return err
+	}
+
+	r.mu.Lock()
+	defer r.mu.Unlock()
+
+	files := []string{abs}
+	for _, existing := range r.files {
+		if existing != abs {
+			files = append(files, existing)
+		}
+	}
+	if len(files) > maxRecentFiles {
+		files = files[:maxRecentFiles]
+	}
+	r.files = files
+
+	return r.save()
+}
+
+// list returns the remembered files, most recent first
+func (r *recentFiles) list() []string {
+	if r == nil {
+		return nil
+	}
+
+	r.mu.Lock()
+	defer r.mu.Unlock()
+	return append([]string(nil), r.files...)
+}
+
+// save writes the list; the caller must hold r.mu or own r exclusively
+func (r *recentFiles) save() error {
+	if err := os.MkdirAll(filepath.Dir(r.path), 0755); err != nil {
+		return err
+	}
+	return os.WriteFile(r.path, []byte(strings.Join(r.files, "\n")+"\n"), 0644)
+}
+
+// displayPath shows file relative to the working directory when it is below it
+func displayPath(file string) string {
+	wd, err := os.Getwd()
+	if err != nil {
+		return file
+	}
+	rel, err := filepath.Rel(wd, file)
+	if err != nil || rel == ".." || strings.HasPrefix(rel, ".."+pathSep) {
+		return file
+	}
+	return rel
+}
@@ -0,0 +1,103 @@
+package conversation
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+)
+
+func TestRecentFiles_AddAndPrune(t *testing.T) {
+	dir := t.TempDir()
+	store := filepath.Join(dir, "recent_files.txt")
+
+	first := filepath.Join(dir, "first.sql")
+	second := filepath.Join(dir, "second.sql")
+	os.WriteFile(first, []byte("SELECT 1;"), 0644)
+	os.WriteFile(second, []byte("SELECT 2;"), 0644)
+
+	recent := loadRecentFiles(store)
+	recent.add(first)
+	recent.add(second)
+	recent.add(first)
+
+	if got := recent.list(); len(got) != 2 || got[0] != first || got[1] != second {
+		t.Fatalf("Expected most recent first without duplicates, got %v", got)
+	}
+
+	os.Remove(second)
+	reloaded := loadRecentFiles(store)
+	if got := reloaded.list(); len(got) != 1 || got[0] != first {
+		t.Errorf("Expected missing files to be pruned on load, got %v", got)
+	}
+
+	data, _ := os.ReadFile(store)
+	if strings.Contains(string(data), "second.sql") {
+		t.Error("Expected the pruned list to be saved")
+	}
+}
+
+func TestRecentFiles_Limit(t *testing.T) {
+	dir := t.TempDir()
+	recent := loadRecentFiles(filepath.Join(dir, "recent_files.txt"))
+
+	for i := 0; i < maxRecentFiles+5; i++ {
+		recent.add(filepath.Join(dir, strings.Repeat("q", i+1)+".sql"))
+	}
+	if got := len(recent.list()); got != maxRecentFiles {
+		t.Errorf("Expected %d recent files, got %d", maxRecentFiles, got)
+	}
+}
+
+func TestAutoCompleter_RecentFilesFirst(t *testing.T) {
+	app := createTestApp(t)
+	ac := NewAutoCompleter(app)
+
+	elsewhere := t.TempDir()
+	report := filepath.Join(elsewhere, "report.sql")
+	os.WriteFile(report, []byte("SELECT 1;"), 0644)
+
+	app.recentFiles = loadRecentFiles(filepath.Join(t.TempDir(), "recent_files.txt"))
+	app.recentFiles.add(report)
+
+	candidates := ac.getFileCandidates("@" + elsewhere + pathSep + "re")
+	if len(candidates) == 0 || candidates[0] != "port.sql" {
+		t.Errorf("Expected the recent file to be offered first, got %v", candidates)
+	}
+	for _, candidate := range candidates[1:] {
+		if candidate == "port.sql" {
+			t.Error("Expected the recent file not to be offered twice")
+		}
+	}
+}
+
+func TestExpandQueryGlob(t *testing.T) {
+	dir := t.TempDir()
+	wd, _ := os.Getwd()
+	defer os.Chdir(wd)
+	os.Chdir(dir)
+
+	migrations := filepath.Join("queries", "migrations")
+	os.MkdirAll(filepath.Join(migrations, "archive.sql"), 0755)
+	for _, name := range []string{"002_users.sql", "001_init.sql", "010_orders.sql", "notes.txt"} {
+		os.WriteFile(filepath.Join(migrations, name), []byte("SELECT 1;"), 0644)
+	}
+
+	files, err := expandQueryGlob(filepath.Join("migrations", "*.sql"), "")
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+
+	expected := []string{
+		filepath.Join(migrations, "001_init.sql"),
+		filepath.Join(migrations, "002_users.sql"),
+		filepath.Join(migrations, "010_orders.sql"),
+	}
+	if strings.Join(files, ",") != strings.Join(expected, ",") {
+		t.Errorf("Expected %v in lexical order without directories, got %v", expected, files)
+	}
+
+	if _, err := expandQueryGlob("[unclosed", ""); err == nil {
+		t.Error("Expected a malformed pattern to fail")
+	}
+}
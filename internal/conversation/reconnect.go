@@ -0,0 +1,72 @@
+package conversation
+
+import (
+	"context"
+	"errors"
+	"fmt"
+)
+
+// handleReconnect opens the current connection again, e.g. after the server
+// restarted: /reconnect
+func (a *App) handleReconnect() error {
+	if a.connection == nil {
+		fmt.Println(a.i18nMgr.Get("no_database_connection"))
+		return nil
+	}
+	if err := a.reconnect(); !errors.Is(err, context.Canceled) {
+		return err
+	}
+	return nil
+}
+
+// reconnect replaces the current connection with a new one, using the saved
+// settings when the connection was saved so edits to its file take effect.
+// Stopping the attempt with Ctrl+C keeps the old connection and returns
+// context.Canceled.
+func (a *App) reconnect() error {
+	config := a.config
+	if saved, err := a.configMgr.LoadConnection(config.Name); err == nil {
+		config = saved
+	}
+
+	fmt.Printf(a.i18nMgr.Get("connecting_to"), config.Name)
+	conn, err := a.openConnection(config)
+	if errors.Is(err, context.Canceled) {
+		a.notify(NotifyInfo, a.i18nMgr.Get("connect_cancelled"))
+		return err
+	}
+	if err != nil {
+		return err
+	}
+	a.connection.Close()
+	a.SetConnection(conn, config)
+	fmt.Printf(a.i18nMgr.Get("connected_to"), config.Name, config.Database)
+	return nil
+}
+
+// offerReconnect asks what to do about query failing because the connection
+// was lost: reconnect and run it again, edit the saved connection in the
+// editor and then do so, or leave it
+func (a *App) offerReconnect(query string) error {
+	switch a.ask(a.i18nMgr.GetWithArgs("connection_lost_choices", a.config.Name)) {
+	case "r":
+	case "c":
+		if _, err := a.loadConnection(a.config.Name); err != nil {
+			return err
+		}
+		if err := a.runEditor(a.configMgr.ConnectionPath(a.config.Name)); err != nil {
+			return err
+		}
+	default:
+		return nil
+	}
+
+	err := a.reconnect()
+	if errors.Is(err, context.Canceled) {
+		return nil
+	}
+	if err != nil {
+		return err
+	}
+	return a.executeAndView(query)
+}
@@ -0,0 +1,84 @@
+package conversation
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"sqlterm/internal/config"
+	"sqlterm/internal/core"
+)
+
+func TestNewApp_CorruptConfigFiles(t *testing.T) {
+	dir := t.TempDir()
+	t.Setenv(config.ConfigDirEnv, dir)
+	core.TakeFileWarnings()
+
+	garbage := []byte("layouts: [\x00\xff\x00 truncated")
+	for _, name := range []string{"config.yaml", "column_layouts.yaml", filepath.Join("connections", "broken.yaml")} {
+		path := filepath.Join(dir, name)
+		if err := os.MkdirAll(filepath.Dir(path), 0755); err != nil {
+			t.Fatal(err)
+		}
+		if err := os.WriteFile(path, garbage, 0644); err != nil {
+			t.Fatal(err)
+		}
+	}
+	good := "name: local\ndatabase_type: 2\ndatabase: app.db\n"
+	if err := os.WriteFile(filepath.Join(dir, "connections", "local.yaml"), []byte(good), 0600); err != nil {
+		t.Fatal(err)
+	}
+
+	app, err := NewApp()
+	if err != nil {
+		t.Fatalf("Expected NewApp to start despite corrupt files, got %v", err)
+	}
+	if app.rl != nil {
+		defer app.rl.Close()
+	}
+
+	connections, err := app.configMgr.ListConnections()
+	if err != nil || len(connections) != 1 || connections[0].Name != "local" {
+		t.Errorf("Expected the intact connection to load, got %v, %v", connections, err)
+	}
+	if _, err := app.configMgr.LoadConnection("broken"); err == nil {
+		t.Error("Expected connecting to the corrupt connection to still fail")
+	}
+
+	app.noteFileWarnings()
+	var messages []string
+	for _, n := range app.notifications.drain() {
+		messages = append(messages, n.Message)
+	}
+	joined := strings.Join(messages, "\n")
+	for _, name := range []string{"config.yaml", "column_layouts.yaml", "broken.yaml"} {
+		if !strings.Contains(joined, name) {
+			t.Errorf("Expected a warning about %s, got:\n%s", name, joined)
+		}
+	}
+	for _, name := range []string{"config.yaml", "column_layouts.yaml"} {
+		if _, err := os.Stat(filepath.Join(dir, name+core.CorruptSuffix)); err != nil {
+			t.Errorf("Expected %s to be set aside: %v", name, err)
+		}
+	}
+	if _, err := os.Stat(filepath.Join(dir, "connections", "broken.yaml")); err != nil {
+		t.Errorf("Expected the corrupt connection file to be left in place: %v", err)
+	}
+}
+
+func TestLoadQueryBuffers_Corrupt(t *testing.T) {
+	core.TakeFileWarnings()
+	path := filepath.Join(t.TempDir(), "buffers.yaml")
+	if err := os.WriteFile(path, []byte("buffers: [\x00\xff"), 0644); err != nil {
+		t.Fatal(err)
+	}
+
+	buffers := loadQueryBuffers(path)
+	if len(buffers.Buffers) != 1 || buffers.Buffers[0].Name != defaultBufferName {
+		t.Errorf("Expected one empty buffer, got %+v", buffers.Buffers)
+	}
+	if warnings := core.TakeFileWarnings(); len(warnings) != 1 || warnings[0].MovedTo != path+core.CorruptSuffix {
+		t.Errorf("Expected the corrupt buffers file to be set aside, got %+v", warnings)
+	}
+}
@@ -0,0 +1,47 @@
+package conversation
+
+import (
+	"context"
+	"fmt"
+
+	"sqlterm/internal/config"
+)
+
+// startConnectionWatcher keeps the saved connection list in sync with files
+// added, changed or removed by other terminals or sqlterm instances
+func (a *App) startConnectionWatcher() {
+	ctx, cancel := context.WithCancel(context.Background())
+
+	err := a.configMgr.WatchConnections(ctx, func(diff config.ConnectionsDiff) {
+		a.notify(NotifyInfo, a.i18nMgr.Get("connections_reloaded"), diff.String())
+	})
+	if err != nil {
+		cancel()
+		a.notify(NotifyWarning, a.i18nMgr.Get("connections_watch_unavailable"), err)
+		return
+	}
+	a.watchCancel = cancel
+	context.AfterFunc(ctx, a.tasks.register(taskIDs.Add(1), taskWatch, "", cancel))
+}
+
+func (a *App) stopConnectionWatcher() {
+	if a.watchCancel != nil {
+		a.watchCancel()
+		a.watchCancel = nil
+	}
+}
+
+// handleReloadConnections re-reads saved connections on demand
+func (a *App) handleReloadConnections() error {
+	diff, err := a.configMgr.ReloadConnections()
+	if err != nil {
+		return fmt.Errorf(a.i18nMgr.Get("failed_to_list_connections"), err)
+	}
+
+	if diff.Empty() {
+		fmt.Println(a.i18nMgr.Get("connections_unchanged"))
+		return nil
+	}
+	fmt.Printf(a.i18nMgr.Get("connections_reloaded")+"\n", diff.String())
+	return nil
+}
@@ -0,0 +1,166 @@
+package conversation
+
+import (
+	"fmt"
+	"slices"
+	"strconv"
+	"strings"
+
+	"sqlterm/internal/core"
+)
+
+// maxListedResults bounds how many numbered results /show lists
+const maxListedResults = 20
+
+// resultMemoryBudget is the configured memory budget of kept result rows in
+// bytes; 0 leaves the store's default
+func (a *App) resultMemoryBudget() int64 {
+	if a.aiManager == nil {
+		return 0
+	}
+	return int64(a.aiManager.GetConfig().ResultMemoryMB) << 20
+}
+
+// recordResult numbers a stored result and saves the numbering. A result of
+// a statement /gexec generated refers to the result it came from.
+func (a *App) recordResult(query string, stored *core.StoredResult) {
+	stored.GeneratedBy = a.generatedBy
+	stored.Number = a.results.Add(query, stored)
+	if err := a.results.Save(a.redactHistory()); err != nil {
+		fmt.Printf(a.i18nMgr.Get("result_history_save_warning"), err)
+	}
+}
+
+// resultEntry looks up the numbered result named by args, printing usage or
+// why there is none
+func (a *App) resultEntry(args []string, usageID string) (*core.ResultEntry, bool) {
+	if len(args) != 1 {
+		fmt.Println(a.i18nMgr.Get(usageID))
+		return nil, false
+	}
+	number, err := strconv.Atoi(strings.TrimPrefix(args[0], "#"))
+	if err != nil {
+		fmt.Println(a.i18nMgr.Get(usageID))
+		return nil, false
+	}
+	entry, ok := a.results.Get(number)
+	if !ok {
+		fmt.Printf(a.i18nMgr.Get("unknown_result_number"), number)
+		return nil, false
+	}
+	return entry, true
+}
+
+// handleShow shows a numbered result again, or lists recent ones: /show [n]
+func (a *App) handleShow(args []string) error {
+	if len(args) == 0 {
+		a.listResults()
+		return nil
+	}
+	entry, ok := a.resultEntry(args, "usage_show")
+	if !ok {
+		return nil
+	}
+	if entry.Result == nil {
+		fmt.Printf(a.i18nMgr.Get("result_rows_not_kept"), entry.Number, entry.Number)
+		return nil
+	}
+
+	// Only a result from this connection becomes the one /edit, /follow and /more work on
+	if a.config != nil && entry.Source.Connection == a.config.Name {
+		a.forgetResults()
+		a.lastStored, a.lastQuery = entry.Result, entry.Query
+		a.lastStored.Rewind()
+		return a.viewStored()
+	}
+
+	var sb strings.Builder
+	entry.Result.Rewind()
+	if err := core.SaveResultPageAsMarkdown(entry.Result, a.columnLayout(entry.Result.Columns), entry.Query, &sb, a.resultFormat(entry.Result.Columns), a.i18nMgr); err != nil {
+		return err
+	}
+	entry.Result.Rewind()
+	return a.displayMarkdown(sb.String())
+}
+
+// listResults prints the most recent numbered results
+func (a *App) listResults() {
+	entries := a.results.Entries()
+	if len(entries) == 0 {
+		fmt.Println(a.i18nMgr.Get("no_numbered_results"))
+		return
+	}
+	for _, entry := range entries[max(len(entries)-maxListedResults, 0):] {
+		rows := strconv.Itoa(entry.Rows)
+		if entry.Incomplete {
+			rows += "+"
+		}
+		kept := " "
+		if entry.Result != nil {
+			kept = "*"
+		}
+		if entry.GeneratedBy != 0 {
+			rows += " " + a.i18nMgr.GetWithArgs("result_list_generated_by", entry.GeneratedBy)
+		}
+		if len(entry.Notices) > 0 {
+			rows += " " + a.i18nMgr.GetWithArgs("result_list_notices", len(entry.Notices))
+		}
+		fmt.Printf("#%-4d%s %s %s %s\n", entry.Number, kept, entry.Source.Label(),
+			a.i18nMgr.GetWithArgs("result_list_rows", rows), a.truncateQuery(entry.Query))
+	}
+	fmt.Println(a.i18nMgr.Get("result_list_hint"))
+}
+
+// handleSQL prints the query behind a numbered result: /sql <n>
+func (a *App) handleSQL(args []string) error {
+	entry, ok := a.resultEntry(args, "usage_sql")
+	if !ok {
+		return nil
+	}
+	fmt.Println(entry.Query)
+	if entry.Redacted {
+		fmt.Println(a.i18nMgr.Get("result_query_redacted"))
+	}
+	return nil
+}
+
+// handleRerun runs the query behind a numbered result again, on the
+// connection it first ran on: /rerun <n> [--on <connection>]. --on runs it on
+// the named connection instead, connecting to it first.
+func (a *App) handleRerun(args []string) error {
+	target := ""
+	if i := slices.Index(args, "--on"); i >= 0 {
+		if i+1 >= len(args) {
+			fmt.Println(a.i18nMgr.Get("usage_rerun"))
+			return nil
+		}
+		target = args[i+1]
+		args = slices.Delete(slices.Clone(args), i, i+2)
+	}
+	entry, ok := a.resultEntry(args, "usage_rerun")
+	if !ok {
+		return nil
+	}
+	if entry.Redacted {
+		fmt.Println(a.i18nMgr.Get("result_query_redacted"))
+		return nil
+	}
+	if target != "" && (a.config == nil || a.config.Name != target) {
+		config, err := a.loadConnection(target)
+		if err != nil {
+			return err
+		}
+		if connected, err := a.connectTo(config); err != nil || !connected {
+			return err
+		}
+	}
+	if a.connection == nil {
+		fmt.Println(a.i18nMgr.Get("no_database_connection"))
+		return nil
+	}
+	if source := entry.Source.Connection; target == "" && source != "" && source != a.config.Name {
+		fmt.Printf(a.i18nMgr.Get("rerun_other_connection"), entry.Number, source, entry.Number, a.config.Name)
+		return nil
+	}
+	return a.executeAndView(entry.Query)
+}
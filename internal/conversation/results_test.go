@@ -0,0 +1,90 @@
+package conversation
+
+import (
+	"bytes"
+	"context"
+	"strings"
+	"testing"
+
+	"sqlterm/internal/core"
+)
+
+func TestApp_numberedResults(t *testing.T) {
+	t.Setenv("SQLTERM_CONFIG_DIR", t.TempDir())
+	app := createTestApp(t)
+	app.aiManager = nil
+	app.results = core.NewResultStore(core.DefaultResultCapacity)
+	app.connection = newMockOrdersConnection()
+	app.config = &core.ConnectionConfig{Name: "mock-db", DatabaseType: core.MySQL}
+
+	var buf bytes.Buffer
+	if err := app.processQuery(context.Background(), "SELECT * FROM orders", &buf); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if app.lastStored.Number != 1 || !strings.Contains(buf.String(), "(#1) 📊 2 rows") {
+		t.Errorf("Expected the result to be numbered #1 with its row count, got:\n%s", buf.String())
+	}
+
+	app.forgetResults()
+	if err := app.handleShow([]string{"#1"}); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if app.lastStored == nil || app.lastQuery != "SELECT * FROM orders" {
+		t.Error("Expected /show to make #1 the last result again")
+	}
+
+	for _, args := range [][]string{nil, {"x"}, {"7"}} {
+		if err := app.handleSQL(args); err != nil {
+			t.Errorf("handleSQL(%v) should only print a message, got %v", args, err)
+		}
+	}
+}
+
+func TestApp_handleRerun_OtherConnection(t *testing.T) {
+	app := createTestApp(t)
+	app.aiManager = nil
+	app.results = core.NewResultStore(core.DefaultResultCapacity)
+	app.connection = newMockOrdersConnection()
+	app.config = &core.ConnectionConfig{Name: "staging", DatabaseType: core.MySQL}
+
+	stored := &core.StoredResult{Source: core.ResultSource{Connection: "prod"}}
+	number := app.results.Add("SELECT * FROM orders", stored)
+
+	if err := app.handleRerun([]string{"1"}); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if number != 1 || app.lastStored != nil {
+		t.Error("Expected a result from another connection not to run on this one")
+	}
+}
+
+func TestApp_handleRerun_On(t *testing.T) {
+	app := startupApp(t, "")
+	app.results = core.NewResultStore(core.DefaultResultCapacity)
+	app.results.Add("SELECT * FROM orders", &core.StoredResult{Source: core.ResultSource{Connection: "prod"}})
+
+	if err := app.handleRerun([]string{"1", "--on"}); err != nil {
+		t.Fatalf("A missing connection name should only print usage, got %v", err)
+	}
+	if app.connection != nil {
+		t.Fatal("Expected --on without a name not to connect")
+	}
+
+	if err := app.handleRerun([]string{"1", "--on", "shop"}); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if app.config == nil || app.config.Name != "shop" {
+		t.Fatal("Expected --on to connect to the named connection")
+	}
+	if app.lastStored == nil || app.lastQuery != "SELECT * FROM orders" {
+		t.Error("Expected the query to run on the named connection")
+	}
+
+	app.lastStored = nil
+	if err := app.handleRerun([]string{"--on", "shop", "1"}); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if app.lastStored == nil {
+		t.Error("Expected --on naming the current connection to run the query there")
+	}
+}
@@ -0,0 +1,168 @@
+package conversation
+
+import (
+	"errors"
+	"fmt"
+	"strings"
+
+	"sqlterm/internal/core"
+)
+
+// schemaInspector returns the connection's view of routines and triggers, or
+// prints why there is none
+func (a *App) schemaInspector() (core.SchemaInspector, bool) {
+	if a.connection == nil {
+		fmt.Println(a.i18nMgr.Get("no_database_connection"))
+		return nil, false
+	}
+	inspector, ok := a.connection.(core.SchemaInspector)
+	if !ok {
+		fmt.Println(a.i18nMgr.Get("routines_not_supported"))
+	}
+	return inspector, ok
+}
+
+// handleFunctions lists the stored procedures and functions: /functions
+func (a *App) handleFunctions() error {
+	inspector, ok := a.schemaInspector()
+	if !ok {
+		return nil
+	}
+
+	routines, err := inspector.ListRoutines()
+	if err != nil {
+		return a.inspectorError("failed_to_list_routines", err)
+	}
+	if len(routines) == 0 {
+		fmt.Printf(a.i18nMgr.Get("no_routines_found"), a.config.Database)
+		return nil
+	}
+
+	header := []core.Column{
+		{Name: a.i18nMgr.Get("routine_name_header")},
+		{Name: a.i18nMgr.Get("routine_kind_header")},
+		{Name: a.i18nMgr.Get("routine_arguments_header")},
+		{Name: a.i18nMgr.Get("routine_returns_header")},
+	}
+	var rows [][]core.Value
+	for _, routine := range routines {
+		rows = append(rows, []core.Value{
+			core.StringValue{Value: routine.Name},
+			core.StringValue{Value: routine.Kind},
+			core.StringValue{Value: routine.Arguments},
+			core.StringValue{Value: routine.Returns},
+		})
+	}
+
+	title := fmt.Sprintf(a.i18nMgr.Get("routines_in_database"), a.config.Database, len(routines))
+	return a.displayMarkdown(fmt.Sprintf("# ⚙️ %s\n\n%s\n%s\n", title,
+		core.MarkdownTable(header, rows, a.formatOptions()), a.i18nMgr.Get("routines_describe_hint")))
+}
+
+// handleTriggers lists the triggers of the database, or of one table with
+// their definitions: /triggers [table]
+func (a *App) handleTriggers(args []string) error {
+	if len(args) > 1 {
+		fmt.Println(a.i18nMgr.Get("usage_triggers"))
+		return nil
+	}
+	inspector, ok := a.schemaInspector()
+	if !ok {
+		return nil
+	}
+
+	table := ""
+	if len(args) == 1 {
+		table = args[0]
+	}
+	triggers, err := inspector.ListTriggers(table)
+	if err != nil {
+		return a.inspectorError("failed_to_list_triggers", err)
+	}
+	if len(triggers) == 0 {
+		fmt.Println(a.i18nMgr.Get("no_triggers_found"))
+		return nil
+	}
+	return a.displayMarkdown(a.triggersMarkdown(triggers, table != ""))
+}
+
+// triggersMarkdown renders triggers as a table, followed by each definition
+// when withDefinitions is set
+func (a *App) triggersMarkdown(triggers []core.TriggerInfo, withDefinitions bool) string {
+	header := []core.Column{
+		{Name: a.i18nMgr.Get("trigger_name_header")},
+		{Name: a.i18nMgr.Get("trigger_table_header")},
+		{Name: a.i18nMgr.Get("trigger_timing_header")},
+		{Name: a.i18nMgr.Get("trigger_event_header")},
+	}
+	var rows [][]core.Value
+	for _, trigger := range triggers {
+		rows = append(rows, []core.Value{
+			core.StringValue{Value: trigger.Name},
+			core.StringValue{Value: trigger.Table},
+			core.StringValue{Value: trigger.Timing},
+			core.StringValue{Value: trigger.Event},
+		})
+	}
+
+	var sb strings.Builder
+	sb.WriteString(fmt.Sprintf("# 🔔 %s (%d)\n\n", a.i18nMgr.Get("triggers_header"), len(triggers)))
+	sb.WriteString(core.MarkdownTable(header, rows, a.formatOptions()))
+	if withDefinitions {
+		for _, trigger := range triggers {
+			if trigger.Definition != "" {
+				sb.WriteString(fmt.Sprintf("\n## %s\n\n```sql\n%s\n```\n", trigger.Name, strings.TrimSpace(trigger.Definition)))
+			}
+		}
+	}
+	return sb.String()
+}
+
+// describeRoutine shows the routine called name when it is not a table, and
+// reports whether it did. The table list is checked first so a table and a
+// function of the same name still describe the table.
+func (a *App) describeRoutine(name string) bool {
+	inspector, ok := a.connection.(core.SchemaInspector)
+	if !ok {
+		return false
+	}
+	if tables, _, err := a.cachedTables(); err == nil {
+		for _, table := range tables {
+			if strings.EqualFold(table, name) {
+				return false
+			}
+		}
+	}
+
+	routine, err := inspector.DescribeRoutine(name)
+	if err != nil {
+		return false
+	}
+	if err := a.displayMarkdown(a.routineMarkdown(routine)); err != nil {
+		fmt.Printf(a.i18nMgr.Get("generic_warning"), err)
+	}
+	return true
+}
+
+// routineMarkdown renders a routine's signature and definition
+func (a *App) routineMarkdown(routine *core.RoutineInfo) string {
+	var sb strings.Builder
+	sb.WriteString(fmt.Sprintf("# ⚙️ %s: %s\n\n", routine.Kind, routine.Name))
+	sb.WriteString(fmt.Sprintf("**%s:** %s\n\n", a.i18nMgr.Get("routine_arguments_header"), routine.Arguments))
+	if routine.Returns != "" {
+		sb.WriteString(fmt.Sprintf("**%s:** %s\n\n", a.i18nMgr.Get("routine_returns_header"), routine.Returns))
+	}
+	if routine.Definition != "" {
+		sb.WriteString(fmt.Sprintf("```sql\n%s\n```\n", strings.TrimSpace(routine.Definition)))
+	}
+	return sb.String()
+}
+
+// inspectorError explains a failed routine or trigger lookup
+func (a *App) inspectorError(messageID string, err error) error {
+	if errors.Is(err, core.ErrSchemaInspectorNotSupported) {
+		fmt.Println(a.i18nMgr.Get("routines_not_supported"))
+		return nil
+	}
+	return fmt.Errorf(a.i18nMgr.Get(messageID), err)
+}
@@ -0,0 +1,90 @@
+package conversation
+
+import (
+	"strings"
+	"testing"
+
+	"sqlterm/internal/core"
+	"sqlterm/internal/core/coretest"
+)
+
+func TestApp_routineMarkdown(t *testing.T) {
+	app := createTestApp(t)
+	routine := &core.RoutineInfo{
+		Name:       "add_tax",
+		Kind:       "FUNCTION",
+		Arguments:  "amount numeric",
+		Returns:    "numeric",
+		Definition: "CREATE FUNCTION add_tax(amount numeric) RETURNS numeric AS $$ SELECT amount * 1.1 $$ LANGUAGE sql\n",
+	}
+
+	markdown := app.routineMarkdown(routine)
+	for _, expected := range []string{"FUNCTION: add_tax", "amount numeric", "**Returns:** numeric", "```sql\nCREATE FUNCTION add_tax"} {
+		if !strings.Contains(markdown, expected) {
+			t.Errorf("Expected the routine to contain %q, got:\n%s", expected, markdown)
+		}
+	}
+
+	routine.Kind, routine.Returns = "PROCEDURE", ""
+	if markdown := app.routineMarkdown(routine); strings.Contains(markdown, "Returns") {
+		t.Errorf("Expected no return type for a procedure, got:\n%s", markdown)
+	}
+}
+
+func TestApp_triggersMarkdown(t *testing.T) {
+	app := createTestApp(t)
+	triggers := []core.TriggerInfo{
+		{Name: "orders_audit", Table: "orders", Timing: "AFTER", Event: "INSERT", Definition: "CREATE TRIGGER orders_audit AFTER INSERT ON orders BEGIN SELECT 1; END"},
+	}
+
+	listed := app.triggersMarkdown(triggers, false)
+	for _, expected := range []string{"Triggers (1)", "orders_audit", "AFTER", "INSERT"} {
+		if !strings.Contains(listed, expected) {
+			t.Errorf("Expected the trigger list to contain %q, got:\n%s", expected, listed)
+		}
+	}
+	if strings.Contains(listed, "```sql") {
+		t.Errorf("Expected no definitions without a table, got:\n%s", listed)
+	}
+	if withDefinitions := app.triggersMarkdown(triggers, true); !strings.Contains(withDefinitions, "```sql\nCREATE TRIGGER orders_audit") {
+		t.Errorf("Expected the definition for one table, got:\n%s", withDefinitions)
+	}
+}
+
+func TestApp_describeRoutine(t *testing.T) {
+	app := createTestApp(t)
+	app.aiManager = nil
+	mock := coretest.NewMockConnection().
+		AddTable(&core.TableInfo{Name: "users"}).
+		AddRoutine(core.RoutineInfo{Name: "add_tax", Kind: "FUNCTION"}).
+		AddRoutine(core.RoutineInfo{Name: "users", Kind: "PROCEDURE"})
+	app.SetConnection(mock, &core.ConnectionConfig{Name: "mock-db", DatabaseType: core.PostgreSQL})
+
+	if !app.describeRoutine("ADD_TAX") {
+		t.Error("Expected a function name to describe the function")
+	}
+	if app.describeRoutine("users") {
+		t.Error("Expected a table to win over a procedure of the same name")
+	}
+	if app.describeRoutine("missing") {
+		t.Error("Expected an unknown name to fall through to the table describe")
+	}
+}
+
+func TestApp_handleTriggers(t *testing.T) {
+	app := createTestApp(t)
+	if err := app.handleTriggers(nil); err != nil {
+		t.Errorf("handleTriggers() should only print a message without a connection, got %v", err)
+	}
+	if err := app.handleTriggers([]string{"a", "b"}); err != nil {
+		t.Errorf("handleTriggers() should only print usage for too many arguments, got %v", err)
+	}
+
+	app.aiManager = nil
+	mock := coretest.NewMockConnection()
+	mock.FailMethod("ListTriggers", core.ErrSchemaInspectorNotSupported)
+	app.SetConnection(mock, &core.ConnectionConfig{Name: "mock-db", DatabaseType: core.MySQL})
+	if err := app.handleTriggers(nil); err != nil {
+		t.Errorf("Expected an unsupported connection to print a message, got %v", err)
+	}
+}
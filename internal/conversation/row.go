@@ -0,0 +1,92 @@
+package conversation
+
+import (
+	"fmt"
+
+	"sqlterm/internal/core"
+)
+
+// handleRow shows one row of the last result vertically, with each column's
+// declared type and schema comment: /row <n> [--json]
+func (a *App) handleRow(args []string) error {
+	if a.config == nil {
+		fmt.Println(a.i18nMgr.Get("no_database_connection"))
+		return nil
+	}
+
+	asJSON := false
+	var rowArg string
+	for _, arg := range args {
+		if arg == "--json" {
+			asJSON = true
+		} else {
+			rowArg = arg
+		}
+	}
+	if rowArg == "" {
+		fmt.Println(a.i18nMgr.Get("usage_row"))
+		return nil
+	}
+	if a.lastStored == nil {
+		fmt.Println(a.i18nMgr.Get("no_result_for_row"))
+		return nil
+	}
+
+	row, err := a.resultRow(rowArg)
+	if err != nil {
+		return err
+	}
+	values := a.lastStored.Rows[row]
+
+	if asJSON {
+		text, err := core.RowJSON(a.lastStored.Columns, values)
+		if err != nil {
+			return err
+		}
+		fmt.Println(text)
+		return nil
+	}
+
+	mdPath, writer, err := a.prepareQueryResultMarkdown()
+	if err != nil {
+		return err
+	}
+	a.describeResultColumns()
+	fmt.Fprintf(writer, a.i18nMgr.Get("record_title"), row+1)
+	_, err = writer.WriteString(core.RecordMarkdown(a.lastStored.Columns, values, a.sourceTableInfo(), a.withHyperlinks(a.formatOptions()), a.i18nMgr))
+	writer.Close()
+	if err != nil {
+		return err
+	}
+
+	if err := a.viewMarkdown(mdPath); err != nil {
+		fmt.Printf(a.i18nMgr.Get("generic_warning"), err)
+	}
+	return nil
+}
+
+// sourceTableInfo describes the table the last result came from, or nil when
+// it did not come from a single table or the table cannot be described
+func (a *App) sourceTableInfo() *core.TableInfo {
+	table, err := core.SourceTable(a.dbType(), a.lastQuery)
+	if err != nil || a.connection == nil {
+		return nil
+	}
+	info, _, err := a.cachedTable(table)
+	if err != nil {
+		return nil
+	}
+	return info
+}
+
+// describeResultColumns completes the nullability and sizes of the last
+// result's columns from the table it came from, through the schema cache
+func (a *App) describeResultColumns() {
+	if a.connection == nil {
+		return
+	}
+	core.DescribeResultColumns(a.lastStored.Columns, a.dbType(), a.lastQuery, func(table string) (*core.TableInfo, error) {
+		info, _, err := a.cachedTable(table)
+		return info, err
+	})
+}
@@ -0,0 +1,28 @@
+package conversation
+
+import (
+	"bytes"
+	"context"
+	"testing"
+
+	"sqlterm/internal/core"
+)
+
+func TestApp_sourceTableInfo(t *testing.T) {
+	app := createTestApp(t)
+	app.connection = newMockOrdersConnection()
+	app.config = &core.ConnectionConfig{Name: "mock-db", DatabaseType: core.MySQL}
+
+	var buf bytes.Buffer
+	if err := app.processQuery(context.Background(), "SELECT * FROM orders", &buf); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if info := app.sourceTableInfo(); info == nil || info.Name != "orders" {
+		t.Errorf("Expected the details of orders, got %+v", info)
+	}
+
+	app.lastQuery = "SELECT * FROM orders o JOIN users u ON u.id = o.user_id"
+	if info := app.sourceTableInfo(); info != nil {
+		t.Errorf("Expected no table details for a join, got %+v", info)
+	}
+}
@@ -0,0 +1,82 @@
+package conversation
+
+import (
+	"fmt"
+	"strconv"
+
+	"sqlterm/internal/core"
+)
+
+// handleSample shows random rows of a table as a normal result, followed by a
+// profile of each column: /sample <table> [n]
+func (a *App) handleSample(args []string) error {
+	if len(args) == 0 || len(args) > 2 {
+		fmt.Println(a.i18nMgr.Get("usage_sample"))
+		return nil
+	}
+	n := core.DefaultSampleSize
+	if len(args) == 2 {
+		count, err := strconv.Atoi(args[1])
+		if err != nil || count < 1 {
+			fmt.Println(a.i18nMgr.Get("usage_sample"))
+			return nil
+		}
+		n = min(count, core.MaxSampleSize)
+	}
+
+	if a.connection == nil {
+		fmt.Println(a.i18nMgr.Get("no_database_connection"))
+		return nil
+	}
+
+	// Run it like /exec so /more, /copy, /row and export work on the sample
+	before := a.lastStored
+	if err := a.executeAndView(a.sampleQuery(args[0], n)); err != nil {
+		return err
+	}
+	if a.lastStored == nil || a.lastStored == before {
+		return nil
+	}
+	return a.displayMarkdown(a.profileMarkdown(a.lastStored))
+}
+
+// sampleQuery asks the connection for its way of picking random rows
+func (a *App) sampleQuery(table string, n int) string {
+	if sampler, ok := a.connection.(core.TableSampler); ok {
+		return sampler.SampleQuery(table, n)
+	}
+	return core.SampleQuery(a.config.DatabaseType, table, n, 0)
+}
+
+// profileMarkdown renders the column profile of stored as a table
+func (a *App) profileMarkdown(stored *core.StoredResult) string {
+	header := []core.Column{
+		{Name: a.i18nMgr.Get("profile_column_header")},
+		{Name: a.i18nMgr.Get("profile_nulls_header")},
+		{Name: a.i18nMgr.Get("profile_distinct_header")},
+		{Name: a.i18nMgr.Get("profile_min_header")},
+		{Name: a.i18nMgr.Get("profile_max_header")},
+	}
+
+	format := a.formatOptions()
+	cell := func(v core.Value) core.Value {
+		if v == nil {
+			return core.StringValue{Null: true}
+		}
+		return core.StringValue{Value: core.FormatValue(v, format)}
+	}
+
+	var rows [][]core.Value
+	for _, profile := range core.ProfileColumns(stored.Columns, stored.Rows) {
+		rows = append(rows, []core.Value{
+			core.StringValue{Value: profile.Name},
+			core.StringValue{Value: fmt.Sprintf("%.0f%%", profile.Nulls*100)},
+			core.IntValue{Value: int64(profile.Distinct)},
+			cell(profile.Min),
+			cell(profile.Max),
+		})
+	}
+
+	title := fmt.Sprintf(a.i18nMgr.Get("sample_profile_header"), len(stored.Rows))
+	return fmt.Sprintf("## %s\n\n%s", title, core.MarkdownTable(header, rows, format))
+}
@@ -0,0 +1,80 @@
+package conversation
+
+import (
+	"context"
+	"strings"
+	"testing"
+
+	"sqlterm/internal/core"
+	"sqlterm/internal/core/coretest"
+)
+
+func TestApp_sampleQuery(t *testing.T) {
+	app := createTestApp(t)
+	app.connection = coretest.NewMockConnection()
+	app.config = &core.ConnectionConfig{Name: "mock-db", DatabaseType: core.MySQL}
+
+	// The mock does not sample itself, so the generic query for the backend is used
+	if got, want := app.sampleQuery("users", 5), "SELECT * FROM `users` ORDER BY RAND() LIMIT 5"; got != want {
+		t.Errorf("sampleQuery() = %q, want %q", got, want)
+	}
+}
+
+func TestApp_profileMarkdown(t *testing.T) {
+	app := createTestApp(t)
+	stored := &core.StoredResult{
+		Columns: []core.Column{{Name: "id"}, {Name: "email"}},
+		Rows: [][]core.Value{
+			{core.IntValue{Value: 4}, core.StringValue{Value: "b@example.com"}},
+			{core.IntValue{Value: 9}, core.StringValue{Null: true}},
+		},
+	}
+
+	markdown := app.profileMarkdown(stored)
+	for _, expected := range []string{"Profile of 2 sampled rows", "| id", "| email", "50%", "b@example.com", "9"} {
+		if !strings.Contains(markdown, expected) {
+			t.Errorf("Expected the profile to contain %q, got:\n%s", expected, markdown)
+		}
+	}
+}
+
+func TestApp_handleSample_NeedsConnection(t *testing.T) {
+	app := createTestApp(t)
+	for _, args := range [][]string{nil, {"users", "none"}, {"users", "0"}, {"users"}} {
+		if err := app.handleSample(args); err != nil {
+			t.Errorf("handleSample(%v) should only print a message, got %v", args, err)
+		}
+	}
+	if app.lastStored != nil {
+		t.Error("Expected no result without a connection")
+	}
+}
+
+// countingConnection estimates its tables at a fixed size
+type countingConnection struct {
+	*coretest.MockConnection
+	rows  int64
+	exact bool
+}
+
+func (c *countingConnection) EstimateRows(string) (int64, bool, error) { return c.rows, c.exact, nil }
+
+func (c *countingConnection) CountRows(context.Context, string) (int64, error) { return c.rows, nil }
+
+func TestApp_estimatedRows(t *testing.T) {
+	app := createTestApp(t)
+	app.connection = coretest.NewMockConnection()
+	if got := app.estimatedRows("events"); got != "" {
+		t.Errorf("Expected no row line for a connection that cannot count, got %q", got)
+	}
+
+	app.connection = &countingConnection{MockConnection: coretest.NewMockConnection(), rows: 420}
+	if got := app.estimatedRows("events"); !strings.Contains(got, "~420") || !strings.Contains(got, "/count events") {
+		t.Errorf("Expected an estimate pointing at /count, got %q", got)
+	}
+
+	app.connection = &countingConnection{MockConnection: coretest.NewMockConnection(), rows: 420, exact: true}
+	if got := app.estimatedRows("events"); strings.Contains(got, "~") || !strings.Contains(got, "420") {
+		t.Errorf("Expected an exact count without the estimate marker, got %q", got)
+	}
+}
@@ -0,0 +1,75 @@
+package conversation
+
+import (
+	"fmt"
+	"time"
+
+	"sqlterm/internal/core"
+)
+
+// schema returns the metadata cache of the current connection, creating it on first use
+func (a *App) schema() *core.SchemaCache {
+	if a.schemaCache == nil {
+		ttl := core.DefaultSchemaCacheTTL
+		if a.aiManager != nil {
+			if configured, err := a.aiManager.GetConfig().Schema.TTL(); err == nil {
+				ttl = configured
+			}
+		}
+		a.schemaCache = core.NewSchemaCache(ttl)
+	}
+	return a.schemaCache
+}
+
+// cachedTables lists tables through the schema cache, going to the server through the message loop when stale
+func (a *App) cachedTables() ([]string, core.CacheHit, error) {
+	conn := a.connection
+	return a.schema().Tables(func() ([]string, error) {
+		return a.refreshTables(conn)
+	})
+}
+
+// cachedTable describes tableName through the schema cache
+func (a *App) cachedTable(tableName string) (*core.TableInfo, core.CacheHit, error) {
+	conn := a.connection
+	return a.schema().Table(tableName, func() (*core.TableInfo, error) {
+		return conn.DescribeTable(tableName)
+	})
+}
+
+// handleRefresh drops cached table metadata and reloads the table list
+func (a *App) handleRefresh() error {
+	if a.connection == nil {
+		fmt.Println(a.i18nMgr.Get("no_database_connection"))
+		return nil
+	}
+
+	a.schema().Invalidate()
+	tables, _, err := a.cachedTables()
+	if err != nil {
+		return fmt.Errorf(a.i18nMgr.Get("failed_to_list_tables"), err)
+	}
+
+	fmt.Printf(a.i18nMgr.Get("schema_refreshed"), len(tables))
+	return nil
+}
+
+// cacheNote describes how old a cached value is; it is empty for values just fetched
+func (a *App) cacheNote(hit core.CacheHit) string {
+	if !hit.Cached {
+		return ""
+	}
+	return fmt.Sprintf(a.i18nMgr.Get("schema_cached_ago"), formatAge(hit.Age(time.Now())))
+}
+
+// formatAge renders a duration the way people say it: 45s, 3m, 2h
+func formatAge(age time.Duration) string {
+	switch {
+	case age < time.Minute:
+		return fmt.Sprintf("%ds", int(age.Seconds()))
+	case age < time.Hour:
+		return fmt.Sprintf("%dm", int(age.Minutes()))
+	default:
+		return fmt.Sprintf("%dh", int(age.Hours()))
+	}
+}
@@ -0,0 +1,93 @@
+package conversation
+
+import (
+	"errors"
+	"fmt"
+	"time"
+)
+
+// shutdownStepTimeout bounds each shutdown step so a hung server cannot prevent exit
+const shutdownStepTimeout = 2 * time.Second
+
+// errQuit is returned by /quit to leave the main loop through the normal shutdown path
+var errQuit = errors.New("quit")
+
+type shutdownStep struct {
+	name string
+	run  func() error
+}
+
+// shutdownSteps lists the exit sequence in order: release server resources first,
+// then local stores, and restore the terminal last so any warnings stay readable.
+// There is no transaction to roll back: /exec runs its statements in one that
+// ends before it returns, and a BEGIN typed by hand holds a pooled connection
+// sqlterm cannot address, which the server rolls back when the pool closes.
+func (a *App) shutdownSteps() []shutdownStep {
+	var steps []shutdownStep
+
+	if a.connection != nil {
+		conn := a.connection
+		steps = append(steps, shutdownStep{name: "close database connection", run: func() error {
+			a.stopKeepAlive()
+			return conn.Close()
+		}})
+	}
+
+	if a.aiManager != nil {
+		steps = append(steps, shutdownStep{name: "close vector store", run: a.aiManager.CloseVectorStore})
+	}
+
+	steps = append(steps, shutdownStep{name: "save session", run: a.saveSession})
+
+	steps = append(steps, shutdownStep{name: "restore terminal", run: func() error {
+		a.restoreTerminal()
+		return nil
+	}})
+
+	return steps
+}
+
+// saveSession writes the numbered result history and the query buffers one last
+// time. Command history needs no flush: readline appends each line to its file
+// as it is saved.
+func (a *App) saveSession() error {
+	var errs []error
+	if a.results != nil {
+		errs = append(errs, a.results.Save(a.redactHistory()))
+	}
+	if a.buffers != nil {
+		errs = append(errs, a.buffers.save())
+	}
+	return errors.Join(errs...)
+}
+
+// shutdown runs the exit sequence exactly once, whichever exit path gets there first
+func (a *App) shutdown() {
+	a.shutdownOnce.Do(func() {
+		a.stopConnectionWatcher()
+		for _, err := range runShutdownSteps(a.shutdownSteps(), shutdownStepTimeout) {
+			fmt.Printf(a.i18nMgr.Get("shutdown_step_warning"), err)
+		}
+	})
+}
+
+// runShutdownSteps runs each step in order, giving up on a step after timeout
+func runShutdownSteps(steps []shutdownStep, timeout time.Duration) []error {
+	var errs []error
+	for _, step := range steps {
+		done := make(chan error, 1)
+		go func() {
+			done <- step.run()
+		}()
+
+		select {
+		case err := <-done:
+			if err != nil {
+				errs = append(errs, fmt.Errorf("%s: %w", step.name, err))
+			}
+		case <-time.After(timeout):
+			errs = append(errs, fmt.Errorf("%s: timed out after %s", step.name, timeout))
+		}
+	}
+	return errs
+}
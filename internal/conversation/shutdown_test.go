@@ -0,0 +1,116 @@
+package conversation
+
+import (
+	"errors"
+	"path/filepath"
+	"strings"
+	"testing"
+	"time"
+
+	"sqlterm/internal/core"
+	"sqlterm/internal/core/coretest"
+)
+
+type recordingConnection struct {
+	*coretest.MockConnection
+	calls *[]string
+}
+
+func (r *recordingConnection) Close() error {
+	*r.calls = append(*r.calls, "close connection")
+	return nil
+}
+
+func TestApp_shutdownSteps_Order(t *testing.T) {
+	app := createTestApp(t)
+	app.aiManager = nil
+
+	var calls []string
+	app.connection = &recordingConnection{MockConnection: coretest.NewMockConnection(), calls: &calls}
+	app.config = &core.ConnectionConfig{Name: "test-db"}
+
+	steps := app.shutdownSteps()
+	var names []string
+	for _, step := range steps {
+		names = append(names, step.name)
+	}
+	if strings.Join(names, ",") != "close database connection,save session,restore terminal" {
+		t.Errorf("Expected the connection to close first and the terminal to be restored last, got %v", names)
+	}
+
+	app.shutdown()
+	app.shutdown()
+
+	if len(calls) != 1 || calls[0] != "close connection" {
+		t.Errorf("Expected connection to be closed exactly once, got %v", calls)
+	}
+}
+
+func TestApp_saveSession(t *testing.T) {
+	app := createTestApp(t)
+	app.aiManager = nil
+	path := filepath.Join(t.TempDir(), "buffers.yaml")
+	app.buffers = loadQueryBuffers(path)
+	app.buffers.setQuery("SELECT 1")
+
+	if err := app.saveSession(); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if loaded := loadQueryBuffers(path); loaded.current().Query != "SELECT 1" {
+		t.Errorf("Expected the buffers to be saved on shutdown, got %q", loaded.current().Query)
+	}
+}
+
+func TestRunShutdownSteps(t *testing.T) {
+	var order []string
+	release := make(chan struct{})
+	defer close(release)
+
+	steps := []shutdownStep{
+		{name: "first", run: func() error {
+			order = append(order, "first")
+			return nil
+		}},
+		{name: "hung", run: func() error {
+			<-release
+			return nil
+		}},
+		{name: "failing", run: func() error {
+			order = append(order, "failing")
+			return errors.New("boom")
+		}},
+	}
+
+	start := time.Now()
+	errs := runShutdownSteps(steps, 50*time.Millisecond)
+
+	if time.Since(start) > time.Second {
+		t.Error("A hung step should not block shutdown beyond its timeout")
+	}
+
+	if strings.Join(order, ",") != "first,failing" {
+		t.Errorf("Expected steps to run in order, got %v", order)
+	}
+
+	if len(errs) != 2 {
+		t.Fatalf("Expected 2 errors, got %d: %v", len(errs), errs)
+	}
+
+	if !strings.Contains(errs[0].Error(), "hung: timed out") {
+		t.Errorf("Expected timeout error for hung step, got '%v'", errs[0])
+	}
+
+	if !strings.Contains(errs[1].Error(), "failing: boom") {
+		t.Errorf("Expected error from failing step, got '%v'", errs[1])
+	}
+}
+
+func TestApp_processCommand_Quit(t *testing.T) {
+	app := createTestApp(t)
+
+	for _, command := range []string{"/quit", "/exit"} {
+		if err := app.processCommand(command); !errors.Is(err, errQuit) {
+			t.Errorf("Expected %s to return errQuit, got %v", command, err)
+		}
+	}
+}
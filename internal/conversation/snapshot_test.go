@@ -0,0 +1,152 @@
+package conversation
+
+import (
+	"flag"
+	"io"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"sqlterm/internal/core"
+)
+
+// Snapshot tests compare what a view prints against the files in
+// testdata/snapshots, so a change to a footer, a count or a column list shows
+// up in review as a diff of those files.
+//
+// After changing a view on purpose, bless the new output with
+//
+//	go test ./internal/conversation -run TestSnapshot -update
+//
+// (make update-snapshots does the same) and check the rewritten files with
+// git diff before committing them.
+var updateSnapshots = flag.Bool("update", false, "rewrite the snapshot files in testdata/snapshots")
+
+// renderView returns what view prints to stdout
+func renderView(t *testing.T, view func()) string {
+	t.Helper()
+	r, w, err := os.Pipe()
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	stdout := os.Stdout
+	os.Stdout = w
+	defer func() { os.Stdout = stdout }()
+
+	output := make(chan string)
+	go func() {
+		data, _ := io.ReadAll(r)
+		output <- string(data)
+	}()
+	view()
+	w.Close()
+	return <-output
+}
+
+// assertSnapshot compares got with testdata/snapshots/<name>.txt, or rewrites
+// that file when the tests run with -update
+func assertSnapshot(t *testing.T, name, got string) {
+	t.Helper()
+	path := filepath.Join("testdata", "snapshots", name+".txt")
+	if *updateSnapshots {
+		if err := os.MkdirAll(filepath.Dir(path), 0755); err != nil {
+			t.Fatalf("Unexpected error: %v", err)
+		}
+		if err := os.WriteFile(path, []byte(got), 0644); err != nil {
+			t.Fatalf("Unexpected error: %v", err)
+		}
+		return
+	}
+
+	want, err := os.ReadFile(path)
+	if err != nil {
+		t.Fatalf("No snapshot %s; run the tests with -update to write it: %v", path, err)
+	}
+	if got != string(want) {
+		t.Errorf("Output differs from %s; if the change is intended, run the tests with -update.\nwant:\n%s\ngot:\n%s", path, want, got)
+	}
+}
+
+// snapshotApp returns a test app with the orders fixture as its last result
+// when connected, or with nothing connected otherwise
+func snapshotApp(t *testing.T, connected bool) *App {
+	t.Helper()
+	app := createTestApp(t)
+	app.layouts = loadColumnLayouts(filepath.Join(t.TempDir(), "column_layouts.yaml"))
+	if !connected {
+		return app
+	}
+
+	app.connection = newMockOrdersConnection()
+	app.config = &core.ConnectionConfig{Name: "mock-db", DatabaseType: core.MySQL}
+	stored, err := core.StoreResult(core.NewStaticQueryResult(
+		[]core.Column{{Name: "id"}, {Name: "user_id"}, {Name: "note"}},
+		[][]core.Value{
+			{core.IntValue{Value: 10}, core.IntValue{Value: 2}, core.StringValue{Value: "gift"}},
+			{core.IntValue{Value: 11}, core.NullValue{}, core.StringValue{Value: "guest"}},
+		}), core.MaxStoredRows)
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	app.lastStored, app.lastQuery = stored, "SELECT * FROM orders"
+	return app
+}
+
+func TestSnapshot_EmptyViews(t *testing.T) {
+	tests := []struct {
+		name string
+		view func(app *App)
+	}{
+		{"status_disconnected", func(app *App) { app.handleStatus() }},
+		{"more_disconnected", func(app *App) { _ = app.handleMore() }},
+		{"columns_no_result", func(app *App) { _ = app.handleColumns(nil) }},
+		{"errors_none", func(app *App) { _ = app.handleErrors(nil) }},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			app := snapshotApp(t, false)
+			assertSnapshot(t, tt.name, renderView(t, func() { tt.view(app) }))
+		})
+	}
+}
+
+func TestSnapshot_ResultsTable(t *testing.T) {
+	app := snapshotApp(t, true)
+	columns := app.lastStored.Columns
+	page := func() string {
+		format := app.resultFormat(columns)
+		format.RowLimit = 1
+		return core.ResultPageMarkdown(app.lastStored, app.columnLayout(columns), format, app.i18nMgr)
+	}
+	assertSnapshot(t, "results_first_page", page())
+
+	// Moved and resized columns: note first at 3 wide, user_id hidden
+	if err := app.layouts.set(columns, core.ColumnLayout{2, 0}); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if err := app.layouts.setWidth(columns, 2, 3); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	app.lastStored.Rewind()
+	assertSnapshot(t, "results_column_layout", page())
+	assertSnapshot(t, "columns_list", renderView(t, app.listColumns))
+}
+
+func TestSnapshot_ErrorReport(t *testing.T) {
+	app := snapshotApp(t, false)
+	if app.aiManager == nil {
+		t.Skip("AI manager not available")
+	}
+
+	line := "/config display timezone Mars/Olympus"
+	err := app.processLine(line)
+	if err == nil {
+		t.Fatal("Expected an error for an unknown timezone")
+	}
+	assertSnapshot(t, "error_report", renderView(t, func() { app.reportError(line, err) }))
+	if len(app.errorHistory) != 1 || !strings.HasPrefix(app.errorHistory[0].Command, "/config display") {
+		t.Errorf("Expected the error kept for /errors, got %+v", app.errorHistory)
+	}
+}
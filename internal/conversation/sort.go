@@ -0,0 +1,58 @@
+package conversation
+
+import (
+	"fmt"
+	"strings"
+
+	"sqlterm/internal/config"
+	"sqlterm/internal/core"
+)
+
+// sortOptions returns the configured sort_collation and sort_numeric
+func (a *App) sortOptions() core.SortOptions {
+	var display config.DisplayConfig
+	if a.aiManager != nil {
+		display = a.aiManager.GetConfig().Display
+	}
+	return display.SortOptions()
+}
+
+// handleSort orders the rows of the last result by one of its columns:
+// /sort <column> [asc|desc]
+func (a *App) handleSort(args []string) error {
+	if a.lastStored == nil {
+		fmt.Println(a.i18nMgr.Get("no_result_for_sort"))
+		return nil
+	}
+
+	opts := a.sortOptions()
+	switch {
+	case len(args) == 1:
+	case len(args) == 2 && strings.EqualFold(args[1], "asc"):
+	case len(args) == 2 && strings.EqualFold(args[1], "desc"):
+		opts.Descending = true
+	default:
+		fmt.Println(a.i18nMgr.Get("usage_sort"))
+		return nil
+	}
+	column, ok := a.resolveColumns(a.lastStored.Columns, args[:1])
+	if !ok {
+		return nil
+	}
+
+	core.SortRows(a.lastStored.Rows, column[0], opts)
+	a.lastStored.Rewind()
+	if err := a.viewStored(); err != nil {
+		return err
+	}
+
+	numeric := a.i18nMgr.Get("sort_numeric_off")
+	if opts.Numeric {
+		numeric = a.i18nMgr.Get("sort_numeric_on")
+	}
+	fmt.Printf(a.i18nMgr.Get("result_sorted"), a.lastStored.Columns[column[0]].Name, opts.Collation, numeric)
+	if a.lastStored.Incomplete {
+		fmt.Printf(a.i18nMgr.Get("sort_incomplete_result"), len(a.lastStored.Rows))
+	}
+	return nil
+}
@@ -0,0 +1,58 @@
+package conversation
+
+import (
+	"slices"
+	"testing"
+
+	"sqlterm/internal/core"
+)
+
+func TestApp_handleSort(t *testing.T) {
+	app := createTestApp(t)
+	app.aiManager = nil
+	app.config = &core.ConnectionConfig{Name: "mock-db", DatabaseType: core.SQLite}
+
+	if err := app.handleSort([]string{"name"}); err != nil {
+		t.Fatalf("handleSort() should only print a message without a result, got %v", err)
+	}
+
+	app.lastStored = &core.StoredResult{
+		Columns: []core.Column{{Name: "id"}, {Name: "name"}},
+		Rows: [][]core.Value{
+			{core.IntValue{Value: 1}, core.StringValue{Value: "banana"}},
+			{core.IntValue{Value: 2}, core.StringValue{Value: "Zoe"}},
+			{core.IntValue{Value: 3}, core.StringValue{Value: "apple"}},
+		},
+	}
+	names := func() []string {
+		var out []string
+		for _, row := range app.lastStored.Rows {
+			out = append(out, row[1].String())
+		}
+		return out
+	}
+
+	if err := app.handleSort([]string{"NAME"}); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if got, want := names(), []string{"apple", "banana", "Zoe"}; !slices.Equal(got, want) {
+		t.Errorf("Expected case-insensitive order by default, got %v", got)
+	}
+
+	if err := app.handleSort([]string{"id", "desc"}); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if got, want := names(), []string{"apple", "Zoe", "banana"}; !slices.Equal(got, want) {
+		t.Errorf("Expected ids descending, got %v", got)
+	}
+
+	// Bad arguments and unknown columns leave the rows as they are
+	for _, args := range [][]string{nil, {"name", "sideways"}, {"missing"}} {
+		if err := app.handleSort(args); err != nil {
+			t.Errorf("handleSort(%v) should only print a message, got %v", args, err)
+		}
+	}
+	if got, want := names(), []string{"apple", "Zoe", "banana"}; !slices.Equal(got, want) {
+		t.Errorf("Expected the rows unchanged, got %v", got)
+	}
+}
@@ -0,0 +1,227 @@
+package conversation
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"io"
+	"os"
+	"strings"
+	"time"
+
+	"sqlterm/internal/core"
+
+	"golang.org/x/term"
+)
+
+// startupTableWait is how long startup waits for the table list before
+// leaving it to finish in the background
+const startupTableWait = 3 * time.Second
+
+// spinnerInterval is how long each spinner frame stays on screen
+const spinnerInterval = 100 * time.Millisecond
+
+// spinnerFrames are drawn in turn while startup waits on the server. Each is
+// one column wide, so a frame can be redrawn without the label after it.
+var spinnerFrames = []string{"⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"}
+
+// SetAutoConnect controls whether Run connects to the default connection
+// before the first prompt; it is on unless --no-auto-connect is given
+func (a *App) SetAutoConnect(enabled bool) {
+	a.noAutoConnect = !enabled
+}
+
+// SetStartupConnection names the connection Run opens before the first
+// prompt in place of the default one, even without auto-connect
+func (a *App) SetStartupConnection(name string) {
+	a.startupConnection = name
+}
+
+// SetStartupStatements gives statements Run executes in order once connected,
+// before the first prompt, as sqlterm -e does
+func (a *App) SetStartupStatements(statements []string) {
+	a.startupStatements = statements
+}
+
+// defaultConnection names the connection to open at startup: the one given
+// with -C, the project's default_connection, then the one in config.yaml
+func (a *App) defaultConnection() string {
+	if a.startupConnection != "" {
+		return a.startupConnection
+	}
+	if a.project != nil && a.project.DefaultConnection != "" {
+		return a.project.DefaultConnection
+	}
+	if a.aiManager != nil {
+		return a.aiManager.GetConfig().DefaultConnection
+	}
+	return ""
+}
+
+// startup connects to the default connection before the first prompt and
+// warms the table cache, so the first Tab already knows table names. A
+// connection given on the command line is only warmed. A failed connection
+// is reported with a hint and the prompt starts without one.
+func (a *App) startup() {
+	if a.connection == nil {
+		name := a.defaultConnection()
+		if name == "" || (a.noAutoConnect && a.startupConnection == "") {
+			return
+		}
+
+		config, err := a.loadConnection(name)
+		if err == nil && !a.confirmMissingPassword(config) {
+			return
+		}
+		var conn core.Connection
+		if err == nil {
+			conn, err = a.openConnection(config)
+		}
+		if errors.Is(err, context.Canceled) {
+			a.notify(NotifyInfo, a.i18nMgr.Get("connect_cancelled"))
+			return
+		}
+		if err != nil {
+			if config != nil {
+				a.recordConnectionFailure(name, err)
+			}
+			a.notify(NotifyWarning, a.i18nMgr.Get("startup_connect_failed"), name, err)
+			return
+		}
+		a.SetConnection(conn, config)
+		a.recordConnectionUse(config.Name)
+	}
+
+	tables := a.warmTables()
+	a.notify(NotifySuccess, "%s", a.startupStatus(tables))
+}
+
+// runStartupStatements executes the statements given with -e in order, each
+// shown like a query run with /exec and kept in the history, so their
+// results are the first numbered results and Up recalls them
+func (a *App) runStartupStatements() {
+	statements := a.startupStatements
+	a.startupStatements = nil
+	if len(statements) == 0 {
+		return
+	}
+	if a.connection == nil {
+		fmt.Println(a.i18nMgr.Get("no_database_connection"))
+		return
+	}
+
+	for _, statement := range statements {
+		if a.rl != nil {
+			if err := a.rl.SaveHistory(a.historyEntry("/exec " + strings.ReplaceAll(statement, "\n", " "))); err != nil {
+				fmt.Printf(a.i18nMgr.Get("failed_save_command_history_warning"), err)
+			}
+		}
+		fmt.Print(a.i18nMgr.Get("executing_query"))
+		fmt.Printf(a.i18nMgr.Get("query_truncated"), a.truncateQuery(statement))
+		if err := a.executeAndView(statement); err != nil {
+			a.reportError("/exec "+statement, err)
+		}
+		a.drainMessages()
+		a.flushNotifications(os.Stdout)
+	}
+}
+
+// warmTables lists the tables of the connection into the schema cache in the
+// background, waiting up to startupTableWait for them. It returns the number
+// of tables, or -1 when the list is still loading or failed.
+func (a *App) warmTables() int {
+	schema, conn := a.schema(), a.connection
+	done := make(chan int, 1)
+	go func() {
+		tables, _, err := schema.Tables(conn.ListTables)
+		if err != nil {
+			done <- -1
+			return
+		}
+		done <- len(tables)
+	}()
+
+	count := -1
+	a.spin(a.i18nMgr.Get("startup_loading_tables"), func() {
+		select {
+		case count = <-done:
+		case <-time.After(startupTableWait):
+		}
+	})
+	return count
+}
+
+// startupStatus describes the connection in one line, e.g.
+// "Connected to prod-replica (PostgreSQL 15.4), 82 tables cached"
+func (a *App) startupStatus(tables int) string {
+	server := a.config.DatabaseType.String()
+	if backend, ok := core.LookupBackend(a.config.DatabaseType); ok {
+		server = backend.DisplayName
+	}
+	if info, ok := a.connection.(core.ServerInfo); ok {
+		if version, err := info.ServerVersion(); err == nil && versionNumber(version) != "" {
+			server += " " + versionNumber(version)
+		}
+	}
+
+	if tables < 0 {
+		return a.i18nMgr.GetWithArgs("startup_connected_loading", a.config.Name, server)
+	}
+	return a.i18nMgr.GetWithArgs("startup_connected", a.config.Name, server, tables)
+}
+
+// versionNumber picks the version number out of a server's version string,
+// e.g. 15.4 from "PostgreSQL 15.4 on x86_64-pc-linux-gnu" or 8.0.36 from
+// "8.0.36-0ubuntu0.22.04.1"
+func versionNumber(version string) string {
+	for _, field := range strings.Fields(version) {
+		if field[0] < '0' || field[0] > '9' {
+			continue
+		}
+		end := strings.IndexFunc(field, func(r rune) bool { return (r < '0' || r > '9') && r != '.' })
+		if end < 0 {
+			end = len(field)
+		}
+		return strings.TrimRight(field[:end], ".")
+	}
+	return ""
+}
+
+// spin runs work while a spinner and label turn on the terminal. Without a
+// terminal, work just runs.
+func (a *App) spin(label string, work func()) {
+	if !term.IsTerminal(int(os.Stdout.Fd())) {
+		work()
+		return
+	}
+
+	done := make(chan struct{})
+	stopped := make(chan struct{})
+	go func() {
+		defer close(stopped)
+		drawSpinner(os.Stdout, label, spinnerInterval, done)
+	}()
+
+	work()
+	close(done)
+	<-stopped
+}
+
+// drawSpinner writes label once, then only redraws the frame in front of it
+// every interval until done is closed, so a slow terminal link carries one
+// character per frame instead of the whole line
+func drawSpinner(w io.Writer, label string, interval time.Duration, done <-chan struct{}) {
+	fmt.Fprintf(w, "\r%s %s", spinnerFrames[0], label)
+	ticker := time.NewTicker(interval)
+	defer ticker.Stop()
+	for frame := 1; ; frame++ {
+		select {
+		case <-done:
+			// Clear the spinner line so the status takes its place
+			fmt.Fprint(w, "\r\033[K")
+			return
+		case <-ticker.C:
+			fmt.Fprintf(w, "\r%s", spinnerFrames[frame%len(spinnerFrames)])
+		}
+	}
+}
@@ -0,0 +1,192 @@
+package conversation
+
+import (
+	"errors"
+	"strings"
+	"sync"
+	"testing"
+	"time"
+
+	"sqlterm/internal/config"
+	"sqlterm/internal/core"
+)
+
+// startupMockType is a backend whose connections are mocks, so startup can be
+// tested through saved connections. Connections to host "down" fail.
+const startupMockType core.DatabaseType = 150
+
+var registerStartupMock sync.Once
+
+func startupApp(t *testing.T, defaultConnection string) *App {
+	t.Helper()
+	registerStartupMock.Do(func() {
+		err := core.RegisterBackend(startupMockType, core.Backend{
+			Name:        "startupmock",
+			DisplayName: "StartupMock",
+			Connect: func(config *core.ConnectionConfig) (core.Connection, error) {
+				if config.Host == "down" {
+					return nil, errors.New("connection refused")
+				}
+				return newMockOrdersConnection(), nil
+			},
+		})
+		if err != nil {
+			t.Fatalf("Failed to register mock backend: %v", err)
+		}
+	})
+
+	t.Setenv("SQLTERM_CONFIG_DIR", t.TempDir())
+	app := createTestApp(t)
+	app.aiManager = nil
+	for _, conn := range []*core.ConnectionConfig{
+		{Name: "shop", DatabaseType: startupMockType, Host: "db", Database: "shop", Password: "secret"},
+		{Name: "down", DatabaseType: startupMockType, Host: "down", Database: "shop", Password: "secret"},
+		{Name: "nopass", DatabaseType: startupMockType, Host: "db", Database: "shop", Username: "admin"},
+	} {
+		if err := app.configMgr.SaveConnection(conn); err != nil {
+			t.Fatalf("Failed to save connection: %v", err)
+		}
+	}
+	app.project = &config.Project{DefaultConnection: defaultConnection}
+	return app
+}
+
+func TestApp_startup_ConnectsAndWarmsTables(t *testing.T) {
+	app := startupApp(t, "shop")
+
+	app.startup()
+
+	if app.connection == nil || app.config.Name != "shop" {
+		t.Fatal("Expected the default connection to be opened before the first prompt")
+	}
+	notifications := app.notifications.drain()
+	if len(notifications) != 1 || notifications[0].Message != "Connected to shop (StartupMock), 2 tables cached" {
+		t.Errorf("Expected one status line, got %v", notifications)
+	}
+	if tables := app.schema().TableNames(); strings.Join(tables, ",") != "orders,users" {
+		t.Errorf("Expected the table list to be cached for the first Tab, got %v", tables)
+	}
+}
+
+func TestApp_startup_ConnectFailure(t *testing.T) {
+	app := startupApp(t, "down")
+
+	app.startup()
+
+	if app.connection != nil {
+		t.Error("Expected no connection after a failed connect")
+	}
+	notifications := app.notifications.drain()
+	if len(notifications) != 1 || notifications[0].Level != NotifyWarning ||
+		!strings.Contains(notifications[0].Message, "Could not connect to down") ||
+		!strings.Contains(notifications[0].Message, "connection refused") ||
+		!strings.Contains(notifications[0].Message, "/connect") {
+		t.Errorf("Expected the failure with a hint to /connect, got %v", notifications)
+	}
+}
+
+func TestApp_startup_MissingPasswordNotConfirmed(t *testing.T) {
+	app := startupApp(t, "nopass")
+
+	app.startup()
+
+	if app.connection != nil {
+		t.Error("Expected no connection to a remote host without a password unless confirmed")
+	}
+	notifications := app.notifications.drain()
+	if len(notifications) != 1 || notifications[0].Message != "Not connected." {
+		t.Errorf("Expected the connection to be called off, got %v", notifications)
+	}
+}
+
+func TestApp_startup_NoAutoConnect(t *testing.T) {
+	app := startupApp(t, "shop")
+	app.SetAutoConnect(false)
+
+	app.startup()
+
+	if app.connection != nil {
+		t.Error("Expected --no-auto-connect to skip the default connection")
+	}
+	if notifications := app.notifications.drain(); len(notifications) != 0 {
+		t.Errorf("Expected no startup messages, got %v", notifications)
+	}
+}
+
+func TestApp_handleReconnect(t *testing.T) {
+	app := startupApp(t, "shop")
+	app.startup()
+	before := app.connection
+
+	if err := app.handleReconnect(); err != nil {
+		t.Fatalf("handleReconnect() failed: %v", err)
+	}
+	if app.connection == nil || app.connection == before || app.config.Name != "shop" {
+		t.Error("Expected a new connection to the same database")
+	}
+}
+
+func TestVersionNumber(t *testing.T) {
+	tests := []struct{ version, expected string }{
+		{"PostgreSQL 15.4 on x86_64-pc-linux-gnu, compiled by gcc", "15.4"},
+		{"8.0.36-0ubuntu0.22.04.1", "8.0.36"},
+		{"3.45.1", "3.45.1"},
+		{"unknown", ""},
+	}
+	for _, tt := range tests {
+		if got := versionNumber(tt.version); got != tt.expected {
+			t.Errorf("versionNumber(%q) = %q, expected %q", tt.version, got, tt.expected)
+		}
+	}
+}
+
+func TestDrawSpinner_DrawsLabelOnce(t *testing.T) {
+	var buf strings.Builder
+	done := make(chan struct{})
+	stopped := make(chan struct{})
+	go func() {
+		defer close(stopped)
+		drawSpinner(&buf, "Connecting to prod", time.Millisecond, done)
+	}()
+	time.Sleep(30 * time.Millisecond)
+	close(done)
+	<-stopped
+
+	out := buf.String()
+	if strings.Count(out, "Connecting to prod") != 1 {
+		t.Errorf("Expected the label to be drawn once, got %q", out)
+	}
+	if strings.Count(out, "\r") < 3 {
+		t.Errorf("Expected the frame to turn while waiting, got %q", out)
+	}
+	if !strings.HasSuffix(out, "\r\033[K") {
+		t.Errorf("Expected the spinner line to be cleared, got %q", out)
+	}
+}
+
+func TestApp_startup_StartupConnection(t *testing.T) {
+	app := startupApp(t, "down")
+	app.SetAutoConnect(false)
+	app.SetStartupConnection("shop")
+
+	app.startup()
+
+	if app.connection == nil || app.config.Name != "shop" {
+		t.Fatal("Expected the connection given with -C to be opened even without auto-connect")
+	}
+}
+
+func TestApp_runStartupStatements(t *testing.T) {
+	app := startupApp(t, "shop")
+	app.startup()
+	app.SetStartupStatements([]string{"SELECT * FROM orders"})
+
+	app.runStartupStatements()
+
+	if app.lastStored == nil || len(app.lastStored.Rows) != 2 || app.lastQuery != "SELECT * FROM orders" {
+		t.Fatalf("Expected the -e statement to be the last result, got %q", app.lastQuery)
+	}
+	if len(app.startupStatements) != 0 {
+		t.Error("Expected the statements to run only once")
+	}
+}
@@ -0,0 +1,106 @@
+package conversation
+
+import (
+	"fmt"
+	"strconv"
+	"strings"
+	"time"
+
+	"sqlterm/internal/core"
+)
+
+// statusField is one labelled line of /status
+type statusField struct {
+	Label string
+	Value string
+}
+
+func (a *App) handleStatus() {
+	a.printProjectStatus()
+
+	if a.connection == nil {
+		fmt.Println(a.i18nMgr.Get("status_not_connected"))
+		fmt.Println(a.i18nMgr.Get("use_connect_to_establish_connection"))
+		return
+	}
+
+	fmt.Printf(a.i18nMgr.Get("status_connected")+"\n", a.config.Name)
+	fields, err := a.statusFields(time.Now())
+	fmt.Print(formatStatusFields(fields))
+	if err != nil {
+		a.notify(NotifyWarning, a.i18nMgr.Get("status_session_unavailable"), err)
+	}
+}
+
+// statusFields gathers what /status shows about the current connection. The
+// error is from asking the server for session details; the fields known
+// locally are returned regardless.
+func (a *App) statusFields(now time.Time) ([]statusField, error) {
+	label := a.i18nMgr.Get
+	fields := []statusField{
+		{label("status_connection"), fmt.Sprintf("%s (%s)", a.config.Name, a.config.DatabaseType)},
+	}
+	if b, ok := core.LookupBackend(a.config.DatabaseType); !ok || !b.FileBased {
+		fields = append(fields, statusField{label("status_host"), a.config.Host + ":" + strconv.Itoa(a.config.Port)})
+	}
+
+	var details core.SessionDetails
+	var err error
+	if server, ok := a.connection.(core.ServerInfo); ok {
+		if version, versionErr := server.ServerVersion(); versionErr == nil {
+			fields = append(fields, statusField{label("status_server_version"), version})
+		}
+		details, err = server.SessionDetails()
+	}
+	if details.User == "" {
+		details.User = a.config.Username
+	}
+	if details.Database == "" {
+		details.Database = a.config.Database
+	}
+
+	for _, field := range []statusField{
+		{label("status_user"), details.User},
+		{label("status_database"), details.Database},
+		{label("status_schema"), details.Schema},
+		{label("status_search_path"), details.SearchPath},
+		{label("status_time_zone"), details.TimeZone},
+		{label("status_autocommit"), details.Autocommit},
+		{label("status_ssl"), details.SSL},
+	} {
+		if field.Value != "" {
+			fields = append(fields, field)
+		}
+	}
+
+	if pooled, ok := a.connection.(core.PoolReporter); ok {
+		if pool, err := pooled.Pool(); err == nil {
+			fields = append(fields, statusField{label("status_pool"), pool.String()})
+		}
+	}
+	if !a.connectedAt.IsZero() {
+		fields = append(fields, statusField{label("status_uptime"), now.Sub(a.connectedAt).Round(time.Second).String()})
+	}
+	cached := label("status_tables_not_loaded")
+	if a.schemaCache != nil {
+		if n := a.schemaCache.CachedTables(); n > 0 {
+			cached = strconv.Itoa(n)
+		}
+	}
+	fields = append(fields, statusField{label("status_cached_tables"), cached})
+	return fields, err
+}
+
+// formatStatusFields lines the values up after the widest label
+func formatStatusFields(fields []statusField) string {
+	width := 0
+	for _, field := range fields {
+		width = max(width, core.DisplayWidth(field.Label))
+	}
+
+	var sb strings.Builder
+	for _, field := range fields {
+		fmt.Fprintf(&sb, "   %s%s  %s\n", field.Label, strings.Repeat(" ", width-core.DisplayWidth(field.Label)), field.Value)
+	}
+	return sb.String()
+}
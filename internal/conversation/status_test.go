@@ -0,0 +1,119 @@
+package conversation
+
+import (
+	"errors"
+	"testing"
+	"time"
+
+	"sqlterm/internal/core"
+	"sqlterm/internal/core/coretest"
+)
+
+// sessionConnection reports fixed server details
+type sessionConnection struct {
+	*coretest.MockConnection
+	details core.SessionDetails
+	err     error
+}
+
+func (c *sessionConnection) ServerVersion() (string, error) { return "PostgreSQL 16.2", nil }
+
+func (c *sessionConnection) ServerVariable(name string) (string, error) {
+	return "", core.ErrUnknownServerVariable
+}
+
+func (c *sessionConnection) SessionDetails() (core.SessionDetails, error) {
+	return c.details, c.err
+}
+
+func statusValues(fields []statusField) map[string]string {
+	values := make(map[string]string, len(fields))
+	for _, field := range fields {
+		values[field.Label] = field.Value
+	}
+	return values
+}
+
+func TestApp_StatusFields(t *testing.T) {
+	now := time.Date(2026, 3, 1, 12, 0, 0, 0, time.UTC)
+
+	t.Run("session details", func(t *testing.T) {
+		app := createTestApp(t)
+		app.config = &core.ConnectionConfig{Name: "prod", DatabaseType: core.PostgreSQL, Host: "db", Port: 5432, Username: "app", Database: "shop"}
+		app.connection = &sessionConnection{
+			MockConnection: coretest.NewMockConnection(),
+			details: core.SessionDetails{
+				User: "reporting", Database: "shop", Schema: "sales", SearchPath: "sales, public",
+				TimeZone: "Australia/Sydney", Autocommit: "on", SSL: "on",
+			},
+		}
+		app.connectedAt = now.Add(-90 * time.Minute)
+		app.schema().Tables(func() ([]string, error) { return []string{"orders", "customers"}, nil })
+
+		fields, err := app.statusFields(now)
+		if err != nil {
+			t.Fatalf("Unexpected error: %v", err)
+		}
+		values := statusValues(fields)
+		want := map[string]string{
+			"Connection":    "prod (postgres)",
+			"Host":          "db:5432",
+			"Server":        "PostgreSQL 16.2",
+			"User":          "reporting",
+			"Schema":        "sales",
+			"Search path":   "sales, public",
+			"Time zone":     "Australia/Sydney",
+			"Autocommit":    "on",
+			"SSL":           "on",
+			"Connected for": "1h30m0s",
+			"Cached tables": "2",
+		}
+		for label, value := range want {
+			if values[label] != value {
+				t.Errorf("%s = %q, want %q", label, values[label], value)
+			}
+		}
+	})
+
+	t.Run("no server details", func(t *testing.T) {
+		app := createTestApp(t)
+		app.config = &core.ConnectionConfig{Name: "local", DatabaseType: core.SQLite, Database: "app.db"}
+		app.connection = coretest.NewMockConnection()
+
+		fields, err := app.statusFields(now)
+		if err != nil {
+			t.Fatalf("Unexpected error: %v", err)
+		}
+		values := statusValues(fields)
+		if values["Database"] != "app.db" || values["Cached tables"] != "not loaded yet" {
+			t.Errorf("Expected the configured database and no cached tables, got %v", values)
+		}
+		for _, label := range []string{"Host", "Schema", "SSL", "Connected for"} {
+			if _, ok := values[label]; ok {
+				t.Errorf("Expected no %s for a SQLite connection, got %q", label, values[label])
+			}
+		}
+	})
+
+	t.Run("session query fails", func(t *testing.T) {
+		app := createTestApp(t)
+		app.config = &core.ConnectionConfig{Name: "prod", DatabaseType: core.MySQL, Host: "db", Port: 3306, Username: "app", Database: "shop"}
+		app.connection = &sessionConnection{MockConnection: coretest.NewMockConnection(), err: errors.New("denied")}
+
+		fields, err := app.statusFields(now)
+		if err == nil {
+			t.Fatal("Expected the session error to be returned")
+		}
+		if values := statusValues(fields); values["User"] != "app" || values["Server"] != "PostgreSQL 16.2" {
+			t.Errorf("Expected the locally known fields to remain, got %v", values)
+		}
+	})
+}
+
+func TestFormatStatusFields(t *testing.T) {
+	got := formatStatusFields([]statusField{{"User", "app"}, {"Time zone", "UTC"}, {"时区", "UTC"}})
+	want := "   User       app\n   Time zone  UTC\n   时区       UTC\n"
+	if got != want {
+		t.Errorf("formatStatusFields() =\n%s\nwant\n%s", got, want)
+	}
+}
@@ -0,0 +1,151 @@
+package conversation
+
+import (
+	"context"
+	"fmt"
+	"sort"
+	"strconv"
+	"sync"
+	"time"
+
+	"sqlterm/internal/core"
+)
+
+// Kinds of background task shown by /tasks
+const (
+	taskQuery     = "query"
+	taskTables    = "tables"
+	taskKeepAlive = "keepalive"
+	taskWatch     = "watch"
+)
+
+// taskInfo describes a running background task
+type taskInfo struct {
+	ID      uint64
+	Kind    string
+	Target  string
+	Started time.Time
+
+	cancel context.CancelFunc
+}
+
+// taskRegistry tracks background tasks so /tasks can list and cancel them.
+// The zero value is ready to use.
+type taskRegistry struct {
+	mu    sync.Mutex
+	tasks map[uint64]*taskInfo
+}
+
+// register records a task under id and returns the function that removes it.
+// Tasks defer that function first thing so they are removed even if they
+// panic. cancel may be nil for tasks that cannot be stopped.
+func (r *taskRegistry) register(id uint64, kind, target string, cancel context.CancelFunc) func() {
+	r.mu.Lock()
+	defer r.mu.Unlock()
+
+	if r.tasks == nil {
+		r.tasks = make(map[uint64]*taskInfo)
+	}
+	r.tasks[id] = &taskInfo{ID: id, Kind: kind, Target: target, Started: time.Now(), cancel: cancel}
+
+	var once sync.Once
+	return func() {
+		once.Do(func() {
+			r.mu.Lock()
+			defer r.mu.Unlock()
+			delete(r.tasks, id)
+		})
+	}
+}
+
+// list returns the running tasks, oldest first
+func (r *taskRegistry) list() []taskInfo {
+	r.mu.Lock()
+	defer r.mu.Unlock()
+
+	tasks := make([]taskInfo, 0, len(r.tasks))
+	for _, task := range r.tasks {
+		tasks = append(tasks, *task)
+	}
+	sort.Slice(tasks, func(i, j int) bool { return tasks[i].ID < tasks[j].ID })
+	return tasks
+}
+
+// cancel stops the task with the given ID. It reports false when there is no
+// such task or it cannot be cancelled.
+func (r *taskRegistry) cancel(id uint64) (found, cancelled bool) {
+	r.mu.Lock()
+	task, ok := r.tasks[id]
+	r.mu.Unlock()
+
+	if !ok {
+		return false, false
+	}
+	if task.cancel == nil {
+		return true, false
+	}
+	task.cancel()
+	return true, true
+}
+
+// connectionName is the name tasks report as their target
+func (a *App) connectionName() string {
+	if a.config == nil {
+		return ""
+	}
+	return a.config.Name
+}
+
+// handleTasks lists background tasks or cancels one: /tasks [cancel <id>]
+func (a *App) handleTasks(args []string) error {
+	if len(args) == 0 {
+		a.listTasks()
+		return nil
+	}
+	if len(args) != 2 || args[0] != "cancel" {
+		fmt.Println(a.i18nMgr.Get("usage_tasks"))
+		return nil
+	}
+
+	id, err := strconv.ParseUint(args[1], 10, 64)
+	if err != nil {
+		fmt.Println(a.i18nMgr.Get("usage_tasks"))
+		return nil
+	}
+	switch found, cancelled := a.tasks.cancel(id); {
+	case !found:
+		fmt.Printf(a.i18nMgr.Get("task_not_found"), id)
+	case !cancelled:
+		fmt.Printf(a.i18nMgr.Get("task_not_cancellable"), id)
+	default:
+		a.notify(NotifySuccess, a.i18nMgr.Get("task_cancelled"), id)
+	}
+	return nil
+}
+
+// listTasks prints the running background tasks
+func (a *App) listTasks() {
+	tasks := a.tasks.list()
+	if len(tasks) == 0 {
+		fmt.Println(a.i18nMgr.Get("no_background_tasks"))
+		return
+	}
+
+	columns := []core.Column{
+		{Name: a.i18nMgr.Get("task_id_header")},
+		{Name: a.i18nMgr.Get("task_kind_header")},
+		{Name: a.i18nMgr.Get("task_connection_header")},
+		{Name: a.i18nMgr.Get("task_elapsed_header")},
+	}
+	rows := make([][]core.Value, len(tasks))
+	now := time.Now()
+	for i, task := range tasks {
+		rows[i] = []core.Value{
+			core.IntValue{Value: int64(task.ID)},
+			core.StringValue{Value: task.Kind},
+			core.StringValue{Value: task.Target},
+			core.StringValue{Value: formatAge(now.Sub(task.Started))},
+		}
+	}
+	fmt.Print(core.MarkdownTable(columns, rows, a.formatOptions()))
+}
@@ -0,0 +1,89 @@
+package conversation
+
+import (
+	"context"
+	"strconv"
+	"testing"
+)
+
+func TestTaskRegistry_ListAndCancel(t *testing.T) {
+	var tasks taskRegistry
+	ctx, cancel := context.WithCancel(context.Background())
+	doneQuery := tasks.register(2, taskQuery, "prod", cancel)
+	doneTables := tasks.register(1, taskTables, "prod", nil)
+
+	listed := tasks.list()
+	if len(listed) != 2 || listed[0].ID != 1 || listed[1].ID != 2 {
+		t.Fatalf("Expected both tasks oldest first, got %+v", listed)
+	}
+	if listed[1].Kind != taskQuery || listed[1].Target != "prod" {
+		t.Errorf("Expected the query task on prod, got %+v", listed[1])
+	}
+
+	tests := []struct {
+		name          string
+		id            uint64
+		wantFound     bool
+		wantCancelled bool
+	}{
+		{"cancellable task", 2, true, true},
+		{"task without cancel", 1, true, false},
+		{"unknown task", 99, false, false},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			found, cancelled := tasks.cancel(tt.id)
+			if found != tt.wantFound || cancelled != tt.wantCancelled {
+				t.Errorf("cancel(%d) = %v, %v, want %v, %v", tt.id, found, cancelled, tt.wantFound, tt.wantCancelled)
+			}
+		})
+	}
+	if ctx.Err() == nil {
+		t.Error("Expected cancelling the task to cancel its context")
+	}
+
+	doneQuery()
+	doneQuery()
+	doneTables()
+	if listed := tasks.list(); len(listed) != 0 {
+		t.Errorf("Expected finished tasks to be removed, got %+v", listed)
+	}
+}
+
+func TestTaskRegistry_RemovesPanickedTask(t *testing.T) {
+	var tasks taskRegistry
+	finished := make(chan struct{})
+
+	go func() {
+		defer close(finished)
+		defer func() { _ = recover() }()
+		defer tasks.register(1, taskQuery, "prod", nil)()
+		panic("boom")
+	}()
+	<-finished
+
+	if listed := tasks.list(); len(listed) != 0 {
+		t.Errorf("Expected a task that panicked to be removed, got %+v", listed)
+	}
+}
+
+func TestApp_handleTasksCancel(t *testing.T) {
+	app := createTestApp(t)
+	ctx, cancel := context.WithCancel(context.Background())
+	done := app.tasks.register(taskIDs.Add(1), taskKeepAlive, "prod", cancel)
+	defer done()
+
+	id := app.tasks.list()[0].ID
+	if err := app.handleTasks([]string{"cancel", "x"}); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if ctx.Err() != nil {
+		t.Fatal("Expected an invalid ID not to cancel anything")
+	}
+	if err := app.handleTasks([]string{"cancel", strconv.FormatUint(id, 10)}); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if ctx.Err() == nil {
+		t.Error("Expected /tasks cancel to cancel the task")
+	}
+}
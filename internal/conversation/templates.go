@@ -0,0 +1,74 @@
+package conversation
+
+import (
+	"errors"
+	"fmt"
+	"os"
+	"path/filepath"
+
+	"sqlterm/internal/config"
+)
+
+// handleConnectTemplate connects with a connection made from a template:
+// /connect-template <template> name=value... [--save]. Without arguments it
+// lists the templates and the values each takes. --save also saves the
+// connection, unless one of that name exists already.
+func (a *App) handleConnectTemplate(args []string) error {
+	save := false
+	var rest []string
+	for _, arg := range args {
+		if arg == "--save" {
+			save = true
+			continue
+		}
+		rest = append(rest, arg)
+	}
+	if len(rest) == 0 {
+		return a.listTemplates()
+	}
+
+	template, err := a.configMgr.LoadTemplate(rest[0])
+	if err != nil {
+		return errors.New(a.i18nMgr.GetWithArgs("failed_to_load_template", rest[0], err))
+	}
+	values, err := config.ParseTemplateValues(rest[1:])
+	if err != nil {
+		return err
+	}
+	conn, err := template.Expand(values)
+	if err != nil {
+		return err
+	}
+
+	connected, err := a.connectTo(conn)
+	if err != nil || !connected || !save {
+		return err
+	}
+	if _, err := os.Stat(a.configMgr.ConnectionPath(conn.Name)); err == nil {
+		fmt.Printf(a.i18nMgr.Get("template_connection_exists"), conn.Name)
+		return nil
+	}
+	if err := a.configMgr.SaveConnection(conn); err != nil {
+		fmt.Printf(a.i18nMgr.Get("failed_save_connection_warning"), err)
+		return nil
+	}
+	a.notify(NotifySuccess, a.i18nMgr.Get("connection_saved"))
+	return nil
+}
+
+// listTemplates prints the connection templates with the values each takes
+func (a *App) listTemplates() error {
+	templates, err := a.configMgr.ListTemplates()
+	if err != nil {
+		return err
+	}
+	if len(templates) == 0 {
+		fmt.Printf(a.i18nMgr.Get("no_connection_templates"), filepath.Dir(a.configMgr.TemplatePath("name")))
+		return nil
+	}
+	fmt.Println(a.i18nMgr.Get("connection_templates"))
+	for _, template := range templates {
+		fmt.Printf("  %-16s %s\n", template.Name, template.Usage())
+	}
+	return nil
+}
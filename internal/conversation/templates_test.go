@@ -0,0 +1,81 @@
+package conversation
+
+import (
+	"errors"
+	"os"
+	"path/filepath"
+	"slices"
+	"strings"
+	"testing"
+
+	"sqlterm/internal/config"
+)
+
+func templateApp(t *testing.T) *App {
+	t.Helper()
+	app := startupApp(t, "")
+	path := app.configMgr.TemplatePath("tenant")
+	if err := os.MkdirAll(filepath.Dir(path), 0755); err != nil {
+		t.Fatal(err)
+	}
+	data := "database_type: startupmock\nhost: db\ndatabase: \"shop_{tenant}\"\npassword: secret\n"
+	if err := os.WriteFile(path, []byte(data), 0600); err != nil {
+		t.Fatal(err)
+	}
+	return app
+}
+
+func TestApp_handleConnectTemplate(t *testing.T) {
+	app := templateApp(t)
+
+	if err := app.handleConnectTemplate([]string{"tenant", "tenant=acme"}); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if app.connection == nil || app.config.Name != "tenant-acme" || app.config.Database != "shop_acme" {
+		t.Fatalf("Expected to be connected to the expanded connection, got %+v", app.config)
+	}
+	if _, err := app.configMgr.LoadConnection("tenant-acme"); err == nil {
+		t.Error("Expected the connection not to be saved without --save")
+	}
+
+	if err := app.handleConnectTemplate([]string{"tenant", "--save", "tenant=globex"}); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if saved, err := app.configMgr.LoadConnection("tenant-globex"); err != nil || saved.Database != "shop_globex" {
+		t.Errorf("Expected --save to save the connection, got %+v, %v", saved, err)
+	}
+
+	err := app.handleConnectTemplate([]string{"tenant"})
+	if !errors.Is(err, config.ErrTemplateValues) {
+		t.Errorf("Expected the missing value to be reported, got %v", err)
+	}
+	if app.config.Name != "tenant-globex" {
+		t.Errorf("Expected a failed expansion to keep the connection, got %s", app.config.Name)
+	}
+	if err := app.handleConnectTemplate([]string{"missing"}); err == nil {
+		t.Error("Expected an unknown template to fail")
+	}
+	if err := app.handleConnectTemplate(nil); err != nil {
+		t.Errorf("Expected the templates to be listed, got %v", err)
+	}
+}
+
+func TestAutoCompleter_ConnectTemplate(t *testing.T) {
+	app := templateApp(t)
+	ac := NewAutoCompleter(app)
+
+	for _, tc := range []struct {
+		line     string
+		expected []string
+	}{
+		{"/connect-template ten", []string{"ant"}},
+		{"/connect-template tenant ", []string{"tenant=", "--save"}},
+		{"/connect-template tenant tenant=acme ", []string{"--save"}},
+		{"/connect-template tenant tenant=ac", nil},
+	} {
+		candidates, _ := ac.getArgumentCandidates(strings.Fields(tc.line), tc.line)
+		if !slices.Equal(candidates, tc.expected) {
+			t.Errorf("%q: expected %q, got %q", tc.line, tc.expected, candidates)
+		}
+	}
+}
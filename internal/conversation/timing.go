@@ -0,0 +1,35 @@
+package conversation
+
+import (
+	"context"
+	"time"
+
+	"sqlterm/internal/config"
+	"sqlterm/internal/core"
+)
+
+// remoteConnection reports whether the current connection reaches its
+// database over the network, which file-based databases do not
+func (a *App) remoteConnection() bool {
+	if a.config == nil {
+		return false
+	}
+	backend, ok := core.LookupBackend(a.config.DatabaseType)
+	return ok && !backend.FileBased
+}
+
+// queryTiming completes the timing of the query that just finished, whose
+// rows took fetch to read. With server_timing on, the server is also asked how
+// long it spends on the query, which runs it a second time.
+func (a *App) queryTiming(ctx context.Context, query string, fetch time.Duration) core.QueryTiming {
+	timing := core.QueryTiming{Total: a.ui.lastElapsed + fetch, RTT: a.ui.lastRTT}
+
+	var display config.DisplayConfig
+	if a.aiManager != nil {
+		display = a.aiManager.GetConfig().Display
+	}
+	if display.ServerTiming && a.remoteConnection() {
+		timing.Server, _ = core.MeasureServerTime(ctx, a.connection, a.dbType(), query)
+	}
+	return timing
+}
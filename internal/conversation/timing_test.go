@@ -0,0 +1,64 @@
+package conversation
+
+import (
+	"bytes"
+	"context"
+	"strings"
+	"testing"
+	"time"
+
+	"sqlterm/internal/core"
+)
+
+func TestApp_queryTiming(t *testing.T) {
+	t.Setenv("SQLTERM_CONFIG_DIR", t.TempDir())
+	app := createTestApp(t)
+	if app.aiManager == nil {
+		t.Skip("AI manager unavailable")
+	}
+	app.results = core.NewResultStore(core.DefaultResultCapacity)
+	conn := newMockOrdersConnection().AddServerTime("SELECT * FROM orders", 140*time.Millisecond)
+	conn.Latency = 5 * time.Millisecond
+	app.connection = conn
+	app.config = &core.ConnectionConfig{Name: "mock-db", DatabaseType: core.PostgreSQL}
+
+	var buf bytes.Buffer
+	if err := app.processQuery(context.Background(), "SELECT * FROM orders", &buf); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	timing := app.lastStored.Timing
+	if timing.RTT < conn.Latency || timing.Total < conn.Latency || timing.Server != 0 {
+		t.Errorf("Expected a ping before the query and no server time by default, got %+v", timing)
+	}
+	if !strings.Contains(buf.String(), "(network ~") {
+		t.Errorf("Expected the summary line to break the time down, got:\n%s", buf.String())
+	}
+
+	display := app.aiManager.GetConfig().Display
+	display.ServerTiming = true
+	if err := app.aiManager.SetDisplay(display); err != nil {
+		t.Fatalf("Failed to turn server timing on: %v", err)
+	}
+	if err := app.processQuery(context.Background(), "SELECT * FROM orders", &bytes.Buffer{}); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if app.lastStored.Timing.Server != 140*time.Millisecond {
+		t.Errorf("Expected the server's own time with server_timing on, got %+v", app.lastStored.Timing)
+	}
+	if entry, ok := app.results.Get(app.lastStored.Number); !ok || entry.Timing != app.lastStored.Timing {
+		t.Errorf("Expected the timing to be kept in the result history, got %+v", entry)
+	}
+
+	// A local database file has no network to time
+	app.config = &core.ConnectionConfig{Name: "local", DatabaseType: core.SQLite}
+	buf.Reset()
+	if err := app.processQuery(context.Background(), "SELECT * FROM orders", &buf); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if timing := app.lastStored.Timing; timing.RTT != 0 || timing.Server != 0 {
+		t.Errorf("Expected no ping or server time for SQLite, got %+v", timing)
+	}
+	if !strings.Contains(buf.String(), "· total ") || strings.Contains(buf.String(), "network") {
+		t.Errorf("Expected only the total for SQLite, got:\n%s", buf.String())
+	}
+}
@@ -0,0 +1,117 @@
+package conversation
+
+import (
+	"fmt"
+	"strings"
+
+	"sqlterm/internal/core"
+)
+
+// handleWhere narrows the last result's query with a condition on one of its
+// columns and runs it again: /where <column> <op> [value], or /where <column>
+// in <row>[,<row>...] to match the values of those rows. With only a column
+// it asks for the operator and value. The new query is shown before it runs
+// and saved to the history, so Up brings it back to edit.
+func (a *App) handleWhere(args []string) error {
+	if a.connection == nil {
+		fmt.Println(a.i18nMgr.Get("no_database_connection"))
+		return nil
+	}
+	if len(args) == 0 {
+		fmt.Println(a.i18nMgr.Get("usage_where"))
+		return nil
+	}
+	if a.lastStored == nil {
+		fmt.Println(a.i18nMgr.Get("no_result_for_where"))
+		return nil
+	}
+	column, ok := a.resolveColumns(a.lastStored.Columns, args[:1])
+	if !ok {
+		return nil
+	}
+
+	args = args[1:]
+	if len(args) == 0 {
+		if args = a.askFilter(); args == nil {
+			return nil
+		}
+	}
+	op, ok := core.ParseFilterOp(args[0])
+	if !ok {
+		fmt.Println(a.i18nMgr.Get("usage_where"))
+		return nil
+	}
+	filter := core.Filter{Column: a.lastStored.Columns[column[0]].Name, Op: op}
+	switch {
+	case op == core.FilterIn:
+		for _, arg := range strings.FieldsFunc(strings.Join(args[1:], ","), func(r rune) bool { return r == ',' || r == ' ' }) {
+			row, err := a.resultRow(arg)
+			if err != nil {
+				return err
+			}
+			filter.Values = append(filter.Values, a.lastStored.Rows[row][column[0]])
+		}
+	case len(args) > 1:
+		filter.Values = []core.Value{core.ParseFilterValue(strings.Join(args[1:], " "), a.columnSample(column[0]))}
+	}
+
+	condition, err := filter.Condition(a.dbType())
+	if err != nil {
+		return fmt.Errorf(a.i18nMgr.Get("where_not_possible"), err)
+	}
+	query, err := core.RefineQuery(a.dbType(), a.lastQuery, condition)
+	if err != nil {
+		return fmt.Errorf(a.i18nMgr.Get("where_not_possible"), err)
+	}
+
+	if a.rl != nil {
+		if err := a.rl.SaveHistory(a.historyEntry("/exec " + strings.ReplaceAll(query, "\n", " "))); err != nil {
+			fmt.Printf(a.i18nMgr.Get("failed_save_command_history_warning"), err)
+		}
+	}
+	fmt.Printf(a.i18nMgr.Get("where_query"), query)
+	return a.executeAndView(query)
+}
+
+// askFilter asks for the operator and value of /where on the prompt line,
+// returning them as arguments, or nil when an answer is left empty
+func (a *App) askFilter() []string {
+	op := a.ask(a.i18nMgr.GetWithArgs("where_ask_operator", strings.Join(filterOpNames(), " ")))
+	if op == "" {
+		return nil
+	}
+
+	question := "where_ask_value"
+	parsed, _ := core.ParseFilterOp(op)
+	switch parsed {
+	case core.FilterIsNull, core.FilterNotNull:
+		return []string{op}
+	case core.FilterIn:
+		question = "where_ask_rows"
+	}
+	value := a.askText(a.i18nMgr.Get(question))
+	if value == "" {
+		return nil
+	}
+	return []string{op, value}
+}
+
+// filterOpNames lists the operators /where takes
+func filterOpNames() []string {
+	ops := make([]string, len(core.FilterOps))
+	for i, op := range core.FilterOps {
+		ops[i] = string(op)
+	}
+	return ops
+}
+
+// columnSample returns the first value of column in the last result that is
+// not NULL, which tells how a typed value should be read
+func (a *App) columnSample(column int) core.Value {
+	for _, row := range a.lastStored.Rows {
+		if column < len(row) && !row[column].IsNull() {
+			return row[column]
+		}
+	}
+	return nil
+}
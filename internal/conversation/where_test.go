@@ -0,0 +1,98 @@
+package conversation
+
+import (
+	"bytes"
+	"context"
+	"errors"
+	"testing"
+
+	"sqlterm/internal/core"
+)
+
+func TestApp_handleWhere(t *testing.T) {
+	app := createTestApp(t)
+	conn := newMockOrdersConnection()
+	app.connection = conn
+	app.config = &core.ConnectionConfig{Name: "mock-db", DatabaseType: core.MySQL}
+
+	if err := app.handleWhere([]string{"user_id", "=", "2"}); err != nil {
+		t.Fatalf("handleWhere() should only print a message without a result, got %v", err)
+	}
+
+	var buf bytes.Buffer
+	if err := app.processQuery(context.Background(), "SELECT * FROM orders", &buf); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	columns := []core.Column{{Name: "id"}, {Name: "user_id"}, {Name: "note"}}
+	in := "(`user_id` IN (2) OR `user_id` IS NULL)"
+	conn.AddResponse("SELECT * FROM orders WHERE `user_id` = 2", columns,
+		[][]core.Value{{core.IntValue{Value: 10}, core.IntValue{Value: 2}, core.StringValue{Value: "gift"}}}).
+		AddResponse("SELECT * FROM orders WHERE "+in, columns, nil).
+		AddResponse("SELECT * FROM orders WHERE ("+in+") AND `note` LIKE 'g%'", columns, nil)
+
+	lastQuery := func() string {
+		queries := conn.ExecutedQueries()
+		return queries[len(queries)-1]
+	}
+
+	if err := app.handleWhere([]string{"USER_ID", "=", "2"}); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if got := lastQuery(); got != "SELECT * FROM orders WHERE `user_id` = 2" {
+		t.Errorf("Expected the number compared as a number, got %q", got)
+	}
+	if len(app.lastStored.Rows) != 1 {
+		t.Errorf("Expected the refined result shown, got %d rows", len(app.lastStored.Rows))
+	}
+
+	// IN takes the values of rows of the result, here of the original query
+	if err := app.processQuery(context.Background(), "SELECT * FROM orders", &buf); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if err := app.handleWhere([]string{"user_id", "in", "1,2"}); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if got := lastQuery(); got != "SELECT * FROM orders WHERE "+in {
+		t.Errorf("Expected the rows' values with NULL matched, got %q", got)
+	}
+
+	// A second condition is ANDed with the first
+	if err := app.handleWhere([]string{"note", "like", "g%"}); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if got, want := lastQuery(), "SELECT * FROM orders WHERE ("+in+") AND `note` LIKE 'g%'"; got != want {
+		t.Errorf("Expected %q, got %q", want, got)
+	}
+
+	// Bad arguments and unknown columns only print a message
+	before := len(conn.ExecutedQueries())
+	for _, args := range [][]string{nil, {"missing", "=", "1"}, {"id", "~", "1"}} {
+		if err := app.handleWhere(args); err != nil {
+			t.Errorf("handleWhere(%v) should only print a message, got %v", args, err)
+		}
+	}
+	if err := app.handleWhere([]string{"id", ">"}); !errors.Is(err, core.ErrFilterValue) {
+		t.Errorf("Expected a comparison without a value to fail, got %v", err)
+	}
+	if err := app.handleWhere([]string{"id", "in", "9"}); err == nil {
+		t.Error("Expected a row past the result to fail")
+	}
+	if len(conn.ExecutedQueries()) != before {
+		t.Error("Expected nothing run for bad arguments")
+	}
+}
+
+func TestApp_handleWhere_CannotRefine(t *testing.T) {
+	app := createTestApp(t)
+	app.connection = newMockOrdersConnection()
+	app.config = &core.ConnectionConfig{Name: "mock-db", DatabaseType: core.MySQL}
+	app.lastStored = &core.StoredResult{
+		Columns: []core.Column{{Name: "id"}},
+		Rows:    [][]core.Value{{core.IntValue{Value: 1}}},
+	}
+	app.lastQuery = "SELECT id FROM orders UNION SELECT id FROM refunds"
+
+	if err := app.handleWhere([]string{"id", "=", "1"}); !errors.Is(err, core.ErrCannotRefine) {
+		t.Errorf("Expected ErrCannotRefine, got %v", err)
+	}
+}
@@ -0,0 +1,174 @@
+package core
+
+import (
+	"database/sql"
+	"errors"
+	"fmt"
+	"strconv"
+	"strings"
+	"time"
+)
+
+var (
+	// ErrServerAdminNotSupported is returned when the backend has no concept of server sessions
+	ErrServerAdminNotSupported = errors.New("server session management is not supported for this database type")
+	// ErrInsufficientPrivileges is returned when the connected user may not inspect or terminate sessions
+	ErrInsufficientPrivileges = errors.New("insufficient privileges")
+	// ErrProcessNotFound is returned when the session to terminate does not exist
+	ErrProcessNotFound = errors.New("process not found")
+)
+
+// ServerAdmin exposes server-side session management
+type ServerAdmin interface {
+	ListProcesses() ([]ProcessInfo, error)
+	KillProcess(id int64) error
+}
+
+// ProcessInfo describes a single session on the database server
+type ProcessInfo struct {
+	ID       int64
+	User     string
+	Database string
+	State    string
+	Duration time.Duration
+	Query    string
+}
+
+func (c *SQLConnection) ListProcesses() ([]ProcessInfo, error) {
+	var query string
+	switch c.config.DatabaseType {
+	case MySQL:
+		query = "SHOW FULL PROCESSLIST"
+	case PostgreSQL:
+		query = `
+			SELECT pid, usename, datname, state,
+			       COALESCE(EXTRACT(EPOCH FROM (now() - query_start)), 0)::bigint,
+			       query
+			FROM pg_stat_activity
+			WHERE datname IS NOT NULL
+			ORDER BY query_start`
+	case SQLite:
+		return nil, ErrServerAdminNotSupported
+	default:
+		return nil, fmt.Errorf("unsupported database type: %v", c.config.DatabaseType)
+	}
+
+	rows, err := c.db.Query(query)
+	if err != nil {
+		return nil, wrapAdminError("failed to list processes", err)
+	}
+	defer rows.Close()
+
+	columns, err := rows.Columns()
+	if err != nil {
+		return nil, fmt.Errorf("failed to get columns: %w", err)
+	}
+
+	var processes []ProcessInfo
+	for rows.Next() {
+		values := make([]sql.NullString, len(columns))
+		valuePtrs := make([]any, len(columns))
+		for i := range values {
+			valuePtrs[i] = &values[i]
+		}
+		if err := rows.Scan(valuePtrs...); err != nil {
+			return nil, fmt.Errorf("failed to scan process row: %w", err)
+		}
+
+		var process ProcessInfo
+		if c.config.DatabaseType == MySQL {
+			// Map by column name: MariaDB appends extra columns such as Progress
+			var command, state string
+			for i, col := range columns {
+				value := values[i].String
+				switch strings.ToLower(col) {
+				case "id":
+					process.ID, _ = strconv.ParseInt(value, 10, 64)
+				case "user":
+					process.User = value
+				case "db":
+					process.Database = value
+				case "command":
+					command = value
+				case "state":
+					state = value
+				case "time":
+					seconds, _ := strconv.ParseInt(value, 10, 64)
+					process.Duration = time.Duration(seconds) * time.Second
+				case "info":
+					process.Query = value
+				}
+			}
+			process.State = command
+			if state != "" {
+				process.State = fmt.Sprintf("%s (%s)", command, state)
+			}
+		} else {
+			process.ID, _ = strconv.ParseInt(values[0].String, 10, 64)
+			process.User = values[1].String
+			process.Database = values[2].String
+			process.State = values[3].String
+			seconds, _ := strconv.ParseInt(values[4].String, 10, 64)
+			process.Duration = time.Duration(seconds) * time.Second
+			process.Query = values[5].String
+		}
+
+		processes = append(processes, process)
+	}
+
+	if err := rows.Err(); err != nil {
+		return nil, wrapAdminError("failed to list processes", err)
+	}
+
+	return processes, nil
+}
+
+func (c *SQLConnection) KillProcess(id int64) error {
+	switch c.config.DatabaseType {
+	case MySQL:
+		if _, err := c.db.Exec(fmt.Sprintf("KILL %d", id)); err != nil {
+			// ER_NO_SUCH_THREAD
+			if code, ok := serverErrorCode(err); ok && code == "1094" {
+				return fmt.Errorf("%w: %d", ErrProcessNotFound, id)
+			}
+			return wrapAdminError("failed to kill process", err)
+		}
+		return nil
+	case PostgreSQL:
+		var terminated bool
+		if err := c.db.QueryRow("SELECT pg_terminate_backend($1)", id).Scan(&terminated); err != nil {
+			return wrapAdminError("failed to kill process", err)
+		}
+		if !terminated {
+			return fmt.Errorf("%w: %d", ErrProcessNotFound, id)
+		}
+		return nil
+	case SQLite:
+		return ErrServerAdminNotSupported
+	default:
+		return fmt.Errorf("unsupported database type: %v", c.config.DatabaseType)
+	}
+}
+
+// wrapAdminError marks privilege failures with ErrInsufficientPrivileges so callers can explain them
+func wrapAdminError(message string, err error) error {
+	if isPrivilegeError(err) {
+		return fmt.Errorf("%s: %w: %v", message, ErrInsufficientPrivileges, err)
+	}
+	return fmt.Errorf("%s: %w", message, err)
+}
+
+func isPrivilegeError(err error) bool {
+	code, ok := serverErrorCode(err)
+	if !ok {
+		return false
+	}
+
+	switch code {
+	case "1095", // MySQL ER_KILL_DENIED_ERROR
+		"1227", // MySQL ER_SPECIFIC_ACCESS_DENIED_ERROR
+		"42501": // PostgreSQL insufficient_privilege
+		return true
+	}
+	return false
+}
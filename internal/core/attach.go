@@ -0,0 +1,112 @@
+package core
+
+import (
+	"fmt"
+	"path/filepath"
+	"strings"
+	"unicode"
+)
+
+// SQLiteMainSchema is the schema name of the database a SQLite connection was opened with
+const SQLiteMainSchema = "main"
+
+// AttachStatement attaches the SQLite database file at path under alias
+func AttachStatement(path, alias string) string {
+	return fmt.Sprintf("ATTACH DATABASE %s AS %s", sqlLiteral(SQLite, SQLitePath(path)), QuoteIdentifier(SQLite, alias))
+}
+
+// DetachStatement detaches the SQLite database attached under alias
+func DetachStatement(alias string) string {
+	return "DETACH DATABASE " + QuoteIdentifier(SQLite, alias)
+}
+
+// AttachAlias derives an alias from a database file name: its base name
+// without the extension, with characters that need quoting replaced by _
+func AttachAlias(path string) string {
+	base := filepath.Base(SQLitePath(path))
+	base = strings.TrimSuffix(base, filepath.Ext(base))
+
+	alias := strings.Map(func(r rune) rune {
+		if isIdentRune(r) {
+			return r
+		}
+		return '_'
+	}, base)
+	if alias == "" || unicode.IsDigit([]rune(alias)[0]) {
+		alias = "db_" + alias
+	}
+	return alias
+}
+
+// sqliteSchemas lists the attached SQLite databases after main, leaving out temp
+func (c *SQLConnection) sqliteSchemas() ([]string, error) {
+	rows, err := c.db.Query("PRAGMA database_list")
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+
+	var schemas []string
+	for rows.Next() {
+		var seq int
+		var name, file string
+		if err := rows.Scan(&seq, &name, &file); err != nil {
+			return nil, err
+		}
+		if name != SQLiteMainSchema && name != "temp" {
+			schemas = append(schemas, name)
+		}
+	}
+	return schemas, rows.Err()
+}
+
+// listSQLiteTables lists the tables of the main database by name and those of
+// attached databases as alias.table
+func (c *SQLConnection) listSQLiteTables() ([]string, error) {
+	schemas, err := c.sqliteSchemas()
+	if err != nil {
+		return nil, fmt.Errorf("failed to list tables: %w", err)
+	}
+
+	tables, err := c.queryNames("SELECT name FROM sqlite_master WHERE type='table'", "")
+	if err != nil {
+		return nil, err
+	}
+	for _, schema := range schemas {
+		query := fmt.Sprintf("SELECT name FROM %s.sqlite_master WHERE type='table'", QuoteIdentifier(SQLite, schema))
+		attached, err := c.queryNames(query, schema+".")
+		if err != nil {
+			return nil, err
+		}
+		tables = append(tables, attached...)
+	}
+	return tables, nil
+}
+
+// queryNames reads a single column of names, prefixing each one
+func (c *SQLConnection) queryNames(query, prefix string) ([]string, error) {
+	rows, err := c.db.Query(query)
+	if err != nil {
+		return nil, fmt.Errorf("failed to list tables: %w", err)
+	}
+	defer rows.Close()
+
+	var names []string
+	for rows.Next() {
+		var name string
+		if err := rows.Scan(&name); err != nil {
+			return nil, fmt.Errorf("failed to scan table name: %w", err)
+		}
+		names = append(names, prefix+name)
+	}
+	return names, nil
+}
+
+// sqlitePragma builds a table PRAGMA. For alias.table it is run against the
+// attached database.
+func sqlitePragma(pragma, tableName string) string {
+	if schema, table, ok := strings.Cut(tableName, "."); ok {
+		return fmt.Sprintf("PRAGMA %s.%s(%s)", QuoteIdentifier(SQLite, unquoteIdentifier(schema)), pragma, QuoteIdentifier(SQLite, unquoteIdentifier(table)))
+	}
+	return fmt.Sprintf("PRAGMA %s(%s)", pragma, tableName)
+}
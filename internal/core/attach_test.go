@@ -0,0 +1,73 @@
+//go:build !no_sqlite
+
+package core
+
+import (
+	"path/filepath"
+	"slices"
+	"testing"
+)
+
+func TestAttachAlias(t *testing.T) {
+	testCases := map[string]string{
+		"other.db":              "other",
+		"/data/sales-2024.db":   "sales_2024",
+		"~/archive/2023.sqlite": "db_2023",
+	}
+	for path, expected := range testCases {
+		if got := AttachAlias(path); got != expected {
+			t.Errorf("AttachAlias(%q) = %q, expected %q", path, got, expected)
+		}
+	}
+}
+
+func TestSQLiteAttach(t *testing.T) {
+	dir := t.TempDir()
+	conn, err := NewConnection(&ConnectionConfig{Name: "main", DatabaseType: SQLite, Database: filepath.Join(dir, "main.db")})
+	if err != nil {
+		t.Fatalf("Failed to open SQLite: %v", err)
+	}
+	defer conn.Close()
+
+	statements := []string{
+		"CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT)",
+		"INSERT INTO users VALUES (1, 'alice')",
+		AttachStatement(filepath.Join(dir, "other's.db"), "other"),
+		"CREATE TABLE other.orders (id INTEGER PRIMARY KEY, user_id INTEGER REFERENCES users(id), total REAL)",
+		"INSERT INTO other.orders VALUES (10, 1, 9.5)",
+	}
+	for _, statement := range statements {
+		execAndDrain(t, conn, statement)
+	}
+
+	tables, err := conn.ListTables()
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if !slices.Contains(tables, "users") || !slices.Contains(tables, "other.orders") {
+		t.Errorf("Expected main tables by name and attached ones qualified, got %v", tables)
+	}
+
+	info, err := conn.DescribeTable("other.orders")
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if len(info.Columns) != 3 || !slices.Equal(info.PrimaryKeys, []string{"id"}) || len(info.ForeignKeys) != 1 {
+		t.Errorf("Expected the attached table's columns and keys, got %+v", info)
+	}
+
+	result, err := conn.Execute("SELECT u.name, o.total FROM users u JOIN other.orders o ON o.user_id = u.id")
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	stored, err := StoreResult(result, 10)
+	if err != nil || len(stored.Rows) != 1 || stored.Rows[0][0].String() != "alice" {
+		t.Errorf("Expected the cross-database join to return alice's order, got %+v (%v)", stored, err)
+	}
+
+	execAndDrain(t, conn, DetachStatement("other"))
+	tables, _ = conn.ListTables()
+	if slices.Contains(tables, "other.orders") {
+		t.Errorf("Expected detached tables to be gone, got %v", tables)
+	}
+}
@@ -0,0 +1,441 @@
+package core
+
+import (
+	"bufio"
+	"context"
+	"crypto/sha256"
+	"encoding/hex"
+	"encoding/json"
+	"fmt"
+	"os"
+	"os/user"
+	"path/filepath"
+	"sync"
+	"time"
+)
+
+// Audit log rotation: the active file is renamed to .1 (and older files shifted
+// up to .AuditLogBackups) once it grows past DefaultAuditLogMaxSize
+const (
+	DefaultAuditLogMaxSize = 10 << 20
+	AuditLogBackups        = 3
+)
+
+// AuditEntry is one executed statement, stored as a JSON line. With redaction on,
+// Statement and Hash describe the statement with its literals replaced.
+type AuditEntry struct {
+	Time       time.Time `json:"time"`
+	Connection string    `json:"connection"`
+	User       string    `json:"user"`
+	Hash       string    `json:"hash"`
+	Statement  string    `json:"statement"`
+	DurationMS int64     `json:"duration_ms"`
+	Rows       int       `json:"rows"`
+	Error      string    `json:"error,omitempty"`
+}
+
+// Success reports whether the statement completed without error
+func (e AuditEntry) Success() bool {
+	return e.Error == ""
+}
+
+// StatementHash identifies a statement independently of where it was logged
+func StatementHash(statement string) string {
+	sum := sha256.Sum256([]byte(statement))
+	return hex.EncodeToString(sum[:8])
+}
+
+// AuditLog appends entries to an append-only file readable only by its owner.
+// It is safe for concurrent use.
+type AuditLog struct {
+	mu      sync.Mutex
+	path    string
+	maxSize int64
+}
+
+// NewAuditLog writes to path, rotating once the file exceeds maxSize bytes; maxSize <= 0 disables rotation
+func NewAuditLog(path string, maxSize int64) *AuditLog {
+	return &AuditLog{path: path, maxSize: maxSize}
+}
+
+// Path returns the active log file
+func (l *AuditLog) Path() string {
+	return l.path
+}
+
+// Append writes entry as one line, rotating first when the file is full
+func (l *AuditLog) Append(entry AuditEntry) error {
+	line, err := json.Marshal(entry)
+	if err != nil {
+		return fmt.Errorf("failed to encode audit entry: %w", err)
+	}
+	line = append(line, '\n')
+
+	l.mu.Lock()
+	defer l.mu.Unlock()
+
+	if err := os.MkdirAll(filepath.Dir(l.path), 0700); err != nil {
+		return fmt.Errorf("failed to create audit log directory: %w", err)
+	}
+	if err := l.rotate(int64(len(line))); err != nil {
+		return err
+	}
+
+	file, err := os.OpenFile(l.path, os.O_APPEND|os.O_CREATE|os.O_WRONLY, 0600)
+	if err != nil {
+		return fmt.Errorf("failed to open audit log: %w", err)
+	}
+	defer file.Close()
+
+	if _, err := file.Write(line); err != nil {
+		return fmt.Errorf("failed to write audit log: %w", err)
+	}
+	return file.Sync()
+}
+
+// rotate shifts the log files when appending size bytes would exceed maxSize; the caller must hold l.mu
+func (l *AuditLog) rotate(size int64) error {
+	if l.maxSize <= 0 {
+		return nil
+	}
+	info, err := os.Stat(l.path)
+	if err != nil || info.Size() == 0 || info.Size()+size <= l.maxSize {
+		return nil
+	}
+
+	for i := AuditLogBackups - 1; i >= 1; i-- {
+		os.Rename(fmt.Sprintf("%s.%d", l.path, i), fmt.Sprintf("%s.%d", l.path, i+1))
+	}
+	if err := os.Rename(l.path, l.path+".1"); err != nil {
+		return fmt.Errorf("failed to rotate audit log: %w", err)
+	}
+	return nil
+}
+
+// Tail returns up to the last n entries, oldest first, reading rotated files when the active one is short
+func (l *AuditLog) Tail(n int) ([]AuditEntry, error) {
+	l.mu.Lock()
+	defer l.mu.Unlock()
+
+	var entries []AuditEntry
+	for i := 0; i <= AuditLogBackups && len(entries) < n; i++ {
+		path := l.path
+		if i > 0 {
+			path = fmt.Sprintf("%s.%d", l.path, i)
+		}
+
+		fileEntries, err := readAuditFile(path)
+		if os.IsNotExist(err) {
+			break
+		}
+		if err != nil {
+			return nil, err
+		}
+		entries = append(fileEntries, entries...)
+	}
+
+	if len(entries) > n {
+		entries = entries[len(entries)-n:]
+	}
+	return entries, nil
+}
+
+func readAuditFile(path string) ([]AuditEntry, error) {
+	file, err := os.Open(path)
+	if err != nil {
+		return nil, err
+	}
+	defer file.Close()
+
+	var entries []AuditEntry
+	scanner := bufio.NewScanner(file)
+	scanner.Buffer(make([]byte, 64*1024), 16<<20)
+	for scanner.Scan() {
+		var entry AuditEntry
+		if err := json.Unmarshal(scanner.Bytes(), &entry); err != nil {
+			// Skip a line torn by a crash rather than hide everything after it
+			continue
+		}
+		entries = append(entries, entry)
+	}
+	if err := scanner.Err(); err != nil {
+		return nil, fmt.Errorf("failed to read audit log: %w", err)
+	}
+	return entries, nil
+}
+
+// AuditedConnection records every statement executed through it in an AuditLog.
+// Successful statements are logged when their result is closed, so the entry
+// carries the full duration and the number of rows read.
+type AuditedConnection struct {
+	Connection
+	log    *AuditLog
+	config *ConnectionConfig
+	user   string
+
+	// Redact replaces literals with ? before statements are written
+	Redact bool
+	// OnError is called when an entry cannot be written; the statement itself is not affected
+	OnError func(error)
+}
+
+// NewAuditedConnection wraps conn so statements are appended to log
+func NewAuditedConnection(conn Connection, config *ConnectionConfig, log *AuditLog) *AuditedConnection {
+	return &AuditedConnection{
+		Connection: conn,
+		log:        log,
+		config:     config,
+		user:       auditUser(config),
+	}
+}
+
+// Log returns the audit log statements are written to
+func (c *AuditedConnection) Log() *AuditLog {
+	return c.log
+}
+
+func (c *AuditedConnection) Execute(query string) (*QueryResult, error) {
+	return c.ExecuteContext(context.Background(), query)
+}
+
+func (c *AuditedConnection) ExecuteContext(ctx context.Context, query string) (*QueryResult, error) {
+	start := time.Now()
+	result, err := c.Connection.ExecuteContext(ctx, query)
+	if err != nil {
+		c.record(query, start, 0, err)
+		return nil, err
+	}
+
+	result.OnClose(func(rowsRead int, err error) {
+		c.record(query, start, rowsRead, err)
+	})
+	return result, nil
+}
+
+// ExecuteParams forwards to the wrapped connection when it can bind parameters.
+// The statement is logged with its placeholders, not the bound values.
+func (c *AuditedConnection) ExecuteParams(ctx context.Context, query string, args ...any) (*QueryResult, error) {
+	executor, ok := c.Connection.(ParamExecutor)
+	if !ok {
+		return nil, ErrParamsNotSupported
+	}
+
+	start := time.Now()
+	result, err := executor.ExecuteParams(ctx, query, args...)
+	if err != nil {
+		c.record(query, start, 0, err)
+		return nil, err
+	}
+
+	result.OnClose(func(rowsRead int, err error) {
+		c.record(query, start, rowsRead, err)
+	})
+	return result, nil
+}
+
+// ExecParams forwards to the wrapped connection when it can report changed
+// rows. The statement is logged with its placeholders and the rows changed.
+func (c *AuditedConnection) ExecParams(ctx context.Context, query string, args ...any) (int64, error) {
+	executor, ok := c.Connection.(RowsExecutor)
+	if !ok {
+		return 0, ErrParamsNotSupported
+	}
+
+	start := time.Now()
+	rows, err := executor.ExecParams(ctx, query, args...)
+	c.record(query, start, int(rows), err)
+	return rows, err
+}
+
+// ListProcesses forwards to the wrapped connection when it supports server administration
+func (c *AuditedConnection) ListProcesses() ([]ProcessInfo, error) {
+	admin, ok := c.Connection.(ServerAdmin)
+	if !ok {
+		return nil, ErrServerAdminNotSupported
+	}
+	return admin.ListProcesses()
+}
+
+// KillProcess forwards to the wrapped connection when it supports server administration
+func (c *AuditedConnection) KillProcess(id int64) error {
+	admin, ok := c.Connection.(ServerAdmin)
+	if !ok {
+		return ErrServerAdminNotSupported
+	}
+	return admin.KillProcess(id)
+}
+
+// ServerVersion forwards to the wrapped connection when it reports server details
+func (c *AuditedConnection) ServerVersion() (string, error) {
+	server, ok := c.Connection.(ServerInfo)
+	if !ok {
+		return "", fmt.Errorf("server version is not available for %s", c.config.DatabaseType)
+	}
+	return server.ServerVersion()
+}
+
+// ServerVariable forwards to the wrapped connection when it reports server details
+func (c *AuditedConnection) ServerVariable(name string) (string, error) {
+	server, ok := c.Connection.(ServerInfo)
+	if !ok {
+		return "", fmt.Errorf("server variables are not available for %s", c.config.DatabaseType)
+	}
+	return server.ServerVariable(name)
+}
+
+// SessionDetails forwards to the wrapped connection when it reports server details
+func (c *AuditedConnection) SessionDetails() (SessionDetails, error) {
+	server, ok := c.Connection.(ServerInfo)
+	if !ok {
+		return SessionDetails{}, fmt.Errorf("session details are not available for %s", c.config.DatabaseType)
+	}
+	return server.SessionDetails()
+}
+
+// SampleQuery forwards to the wrapped connection when it samples tables itself
+func (c *AuditedConnection) SampleQuery(table string, n int) string {
+	if sampler, ok := c.Connection.(TableSampler); ok {
+		return sampler.SampleQuery(table, n)
+	}
+	return SampleQuery(c.config.DatabaseType, table, n, 0)
+}
+
+// EstimateRows forwards to the wrapped connection when it can count rows
+func (c *AuditedConnection) EstimateRows(table string) (int64, bool, error) {
+	counter, ok := c.Connection.(RowCounter)
+	if !ok {
+		return 0, false, ErrRowCountNotSupported
+	}
+	return counter.EstimateRows(table)
+}
+
+// CountRows forwards to the wrapped connection when it can count rows
+func (c *AuditedConnection) CountRows(ctx context.Context, table string) (int64, error) {
+	counter, ok := c.Connection.(RowCounter)
+	if !ok {
+		return 0, ErrRowCountNotSupported
+	}
+	return counter.CountRows(ctx, table)
+}
+
+// Begin forwards to the wrapped connection when it supports transactions.
+// Statements in the transaction are logged as they run, so the log also
+// holds those a rollback undid.
+func (c *AuditedConnection) Begin(ctx context.Context) (Transaction, error) {
+	transactor, ok := c.Connection.(Transactor)
+	if !ok {
+		return nil, ErrTransactionsNotSupported
+	}
+	tx, err := transactor.Begin(ctx)
+	if err != nil {
+		return nil, err
+	}
+	return &auditedTransaction{Transaction: tx, conn: c}, nil
+}
+
+// auditedTransaction logs the statements of a transaction to its connection's audit log
+type auditedTransaction struct {
+	Transaction
+	conn *AuditedConnection
+}
+
+func (t *auditedTransaction) ExecuteContext(ctx context.Context, query string) (*QueryResult, error) {
+	start := time.Now()
+	result, err := t.Transaction.ExecuteContext(ctx, query)
+	if err != nil {
+		t.conn.record(query, start, 0, err)
+		return nil, err
+	}
+
+	result.OnClose(func(rowsRead int, err error) {
+		t.conn.record(query, start, rowsRead, err)
+	})
+	return result, nil
+}
+
+// ListRoutines forwards to the wrapped connection when it can inspect routines
+func (c *AuditedConnection) ListRoutines() ([]RoutineInfo, error) {
+	inspector, ok := c.Connection.(SchemaInspector)
+	if !ok {
+		return nil, ErrSchemaInspectorNotSupported
+	}
+	return inspector.ListRoutines()
+}
+
+// ListTriggers forwards to the wrapped connection when it can inspect triggers
+func (c *AuditedConnection) ListTriggers(table string) ([]TriggerInfo, error) {
+	inspector, ok := c.Connection.(SchemaInspector)
+	if !ok {
+		return nil, ErrSchemaInspectorNotSupported
+	}
+	return inspector.ListTriggers(table)
+}
+
+// DescribeRoutine forwards to the wrapped connection when it can inspect routines
+func (c *AuditedConnection) DescribeRoutine(name string) (*RoutineInfo, error) {
+	inspector, ok := c.Connection.(SchemaInspector)
+	if !ok {
+		return nil, ErrSchemaInspectorNotSupported
+	}
+	return inspector.DescribeRoutine(name)
+}
+
+// PingContext forwards to the wrapped connection, falling back to Ping
+func (c *AuditedConnection) PingContext(ctx context.Context) error {
+	if pinger, ok := c.Connection.(interface{ PingContext(context.Context) error }); ok {
+		return pinger.PingContext(ctx)
+	}
+	return c.Connection.Ping()
+}
+
+// ServerTime forwards to the wrapped connection when it can report server time.
+// The EXPLAIN ANALYZE it runs is not audited: it only measures a query that was.
+func (c *AuditedConnection) ServerTime(ctx context.Context, query string) (time.Duration, error) {
+	timer, ok := c.Connection.(ServerTimer)
+	if !ok {
+		return 0, ErrServerTimeNotSupported
+	}
+	return timer.ServerTime(ctx, query)
+}
+
+// Pool forwards to the wrapped connection when it is backed by a connection pool
+func (c *AuditedConnection) Pool() (PoolSettings, error) {
+	pooled, ok := c.Connection.(PoolReporter)
+	if !ok {
+		return PoolSettings{}, ErrPoolNotSupported
+	}
+	return pooled.Pool()
+}
+
+func (c *AuditedConnection) record(query string, start time.Time, rows int, err error) {
+	if c.Redact {
+		query = RedactLiterals(c.config.DatabaseType, query)
+	}
+	entry := AuditEntry{
+		Time:       start.UTC(),
+		Connection: c.config.Name,
+		User:       c.user,
+		Hash:       StatementHash(query),
+		Statement:  query,
+		DurationMS: time.Since(start).Milliseconds(),
+		Rows:       rows,
+	}
+	if err != nil {
+		entry.Error = err.Error()
+	}
+
+	if err := c.log.Append(entry); err != nil && c.OnError != nil {
+		c.OnError(err)
+	}
+}
+
+// auditUser names who ran the statement: the database user, or the OS user for file databases
+func auditUser(config *ConnectionConfig) string {
+	if config.Username != "" {
+		return config.Username
+	}
+	if current, err := user.Current(); err == nil {
+		return current.Username
+	}
+	return ""
+}
@@ -0,0 +1,133 @@
+//go:build !no_sqlite
+
+package core
+
+import (
+	"os"
+	"path/filepath"
+	"runtime"
+	"strings"
+	"testing"
+)
+
+// execAndDrain runs query the way the app does: read every row, then close the result
+func execAndDrain(t *testing.T, conn Connection, query string) {
+	t.Helper()
+
+	result, err := conn.Execute(query)
+	if err != nil {
+		return
+	}
+	for range result.Itor() {
+	}
+	result.Close()
+}
+
+func TestAuditedConnection_SQLite(t *testing.T) {
+	dir := t.TempDir()
+	config := &ConnectionConfig{Name: "audited", DatabaseType: SQLite, Database: filepath.Join(dir, "app.db"), AuditLog: true}
+
+	inner, err := NewConnection(config)
+	if err != nil {
+		t.Fatalf("Failed to open SQLite: %v", err)
+	}
+	defer inner.Close()
+
+	log := NewAuditLog(filepath.Join(dir, "audit", "audited.log"), DefaultAuditLogMaxSize)
+	conn := NewAuditedConnection(inner, config, log)
+
+	statements := []string{
+		"CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT)",
+		"INSERT INTO users (name) VALUES ('alice'), ('bob')",
+		"SELECT id, name FROM users",
+		"SELECT * FROM missing",
+	}
+	for _, statement := range statements {
+		execAndDrain(t, conn, statement)
+	}
+
+	entries, err := log.Tail(10)
+	if err != nil {
+		t.Fatalf("Failed to read audit log: %v", err)
+	}
+	if len(entries) != len(statements) {
+		t.Fatalf("Expected %d entries, got %d", len(statements), len(entries))
+	}
+
+	for i, entry := range entries {
+		if entry.Statement != statements[i] || entry.Hash != StatementHash(statements[i]) {
+			t.Errorf("Entry %d: expected statement %q with its hash, got %q (%s)", i, statements[i], entry.Statement, entry.Hash)
+		}
+		if entry.Connection != "audited" || entry.Time.IsZero() {
+			t.Errorf("Entry %d: expected connection and time to be recorded, got %+v", i, entry)
+		}
+	}
+	if entries[2].Rows != 2 || !entries[2].Success() {
+		t.Errorf("Expected the SELECT to succeed with 2 rows, got %+v", entries[2])
+	}
+	if entries[3].Success() || !strings.Contains(entries[3].Error, "missing") {
+		t.Errorf("Expected the failing statement to be logged with its error, got %+v", entries[3])
+	}
+
+	if runtime.GOOS != "windows" {
+		info, err := os.Stat(log.Path())
+		if err != nil {
+			t.Fatalf("Failed to stat audit log: %v", err)
+		}
+		if perm := info.Mode().Perm(); perm != 0600 {
+			t.Errorf("Expected audit log permissions 0600, got %o", perm)
+		}
+	}
+}
+
+func TestAuditLog_Rotation(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "audit.log")
+	log := NewAuditLog(path, 300)
+
+	for i := 0; i < 10; i++ {
+		if err := log.Append(AuditEntry{Connection: "db", Statement: "SELECT 1", Hash: StatementHash("SELECT 1")}); err != nil {
+			t.Fatalf("Unexpected error: %v", err)
+		}
+	}
+
+	if _, err := os.Stat(path + ".1"); err != nil {
+		t.Errorf("Expected the log to be rotated to %s.1: %v", path, err)
+	}
+	if _, err := os.Stat(path + ".4"); !os.IsNotExist(err) {
+		t.Errorf("Expected at most %d rotated files", AuditLogBackups)
+	}
+	if info, err := os.Stat(path); err != nil || info.Size() > 300 {
+		t.Errorf("Expected the active log to stay under the size limit, got %v", info)
+	}
+
+	entries, err := log.Tail(3)
+	if err != nil || len(entries) != 3 {
+		t.Errorf("Expected tail to span rotated files, got %d entries (%v)", len(entries), err)
+	}
+}
+
+func TestAuditedConnection_Redact(t *testing.T) {
+	dir := t.TempDir()
+	config := &ConnectionConfig{Name: "redacted", DatabaseType: SQLite, Database: ":memory:"}
+
+	inner, err := NewConnection(config)
+	if err != nil {
+		t.Fatalf("Failed to open SQLite: %v", err)
+	}
+	defer inner.Close()
+
+	conn := NewAuditedConnection(inner, config, NewAuditLog(filepath.Join(dir, "audit.log"), 0))
+	conn.Redact = true
+	execAndDrain(t, conn, "SELECT 'bob@example.com', 987654321")
+
+	data, err := os.ReadFile(filepath.Join(dir, "audit.log"))
+	if err != nil {
+		t.Fatalf("Failed to read audit log: %v", err)
+	}
+	if strings.Contains(string(data), "bob@example.com") || strings.Contains(string(data), "987654321") {
+		t.Errorf("Expected literals to be redacted, got %s", data)
+	}
+	if !strings.Contains(string(data), StatementHash("SELECT ?, ?")) {
+		t.Errorf("Expected the hash of the redacted statement, got %s", data)
+	}
+}
@@ -0,0 +1,113 @@
+package core
+
+import (
+	"database/sql"
+	"database/sql/driver"
+	"errors"
+	"fmt"
+	"io"
+	"net"
+	"strings"
+)
+
+var (
+	// ErrAuthenticationFailed marks a connection the server refused for its username or password
+	ErrAuthenticationFailed = errors.New("authentication failed")
+	// ErrServerUnreachable marks a connection that never reached the server
+	ErrServerUnreachable = errors.New("server unreachable")
+)
+
+// MissingPassword reports whether connecting with config would log in to
+// another machine without a password, which is more often a forgotten field
+// than intended. File databases and local servers, which may trust the
+// operating system user instead, are never reported.
+func MissingPassword(config *ConnectionConfig) bool {
+	if config.Password != "" {
+		return false
+	}
+	if backend, ok := LookupBackend(config.DatabaseType); !ok || backend.FileBased {
+		return false
+	}
+	return !isLocalHost(config.Host)
+}
+
+// isLocalHost accepts localhost, loopback addresses and Unix socket
+// directories, which is how PostgreSQL takes a socket as the host
+func isLocalHost(host string) bool {
+	host = strings.Trim(strings.TrimSpace(host), "[]")
+	if host == "" || strings.EqualFold(host, "localhost") || strings.HasPrefix(host, "/") {
+		return true
+	}
+	ip := net.ParseIP(host)
+	return ip != nil && ip.IsLoopback()
+}
+
+// classifyConnectError marks err from reaching the server with
+// ErrAuthenticationFailed or ErrServerUnreachable when it is one of those,
+// so the message can point at the password or at the host
+func classifyConnectError(err error) error {
+	if err == nil {
+		return nil
+	}
+	if isAuthenticationError(err) {
+		return fmt.Errorf("%w: %w", ErrAuthenticationFailed, err)
+	}
+	var opErr *net.OpError
+	var dnsErr *net.DNSError
+	if errors.As(err, &opErr) || errors.As(err, &dnsErr) {
+		return fmt.Errorf("%w: %w", ErrServerUnreachable, err)
+	}
+	return err
+}
+
+func isAuthenticationError(err error) bool {
+	code, ok := serverErrorCode(err)
+	if !ok {
+		return false
+	}
+
+	switch code {
+	case "1045", // MySQL ER_ACCESS_DENIED_ERROR
+		"1698",  // MySQL ER_ACCESS_DENIED_NO_PASSWORD_ERROR
+		"28P01", // PostgreSQL invalid_password
+		"28000": // PostgreSQL invalid_authorization_specification
+		return true
+	}
+	return false
+}
+
+// lostConnectionErrors mean the connection is gone whichever driver returns them
+var lostConnectionErrors = []error{ErrServerUnreachable, driver.ErrBadConn, sql.ErrConnDone, io.EOF, io.ErrUnexpectedEOF}
+
+// IsConnectionLost reports whether err means the connection to the server
+// went away, so the statement may well work once reconnected: the network
+// failed, or the server closed the connection or could not be reached
+func IsConnectionLost(err error) bool {
+	if err == nil {
+		return false
+	}
+	var opErr *net.OpError
+	if errors.As(err, &opErr) || isAny(err, lostConnectionErrors) {
+		return true
+	}
+
+	registry.RLock()
+	defer registry.RUnlock()
+
+	for _, b := range registry.backends {
+		if isAny(err, b.lostConnection) {
+			return true
+		}
+	}
+	return false
+}
+
+// isAny reports whether err matches one of targets
+func isAny(err error, targets []error) bool {
+	for _, target := range targets {
+		if errors.Is(err, target) {
+			return true
+		}
+	}
+	return false
+}
@@ -0,0 +1,89 @@
+package core
+
+import (
+	"database/sql/driver"
+	"errors"
+	"fmt"
+	"net"
+	"testing"
+
+	"gopkg.in/yaml.v3"
+)
+
+func TestMissingPassword(t *testing.T) {
+	tests := []struct {
+		name   string
+		config ConnectionConfig
+		want   bool
+	}{
+		{"remote without password", ConnectionConfig{DatabaseType: PostgreSQL, Host: "prod-db.internal"}, true},
+		{"remote with password", ConnectionConfig{DatabaseType: PostgreSQL, Host: "prod-db.internal", Password: "secret"}, false},
+		{"localhost", ConnectionConfig{DatabaseType: MySQL, Host: "localhost"}, false},
+		{"loopback address", ConnectionConfig{DatabaseType: MySQL, Host: "127.0.0.1"}, false},
+		{"IPv6 loopback", ConnectionConfig{DatabaseType: MySQL, Host: "::1"}, false},
+		{"Unix socket", ConnectionConfig{DatabaseType: PostgreSQL, Host: "/var/run/postgresql"}, false},
+		{"no host", ConnectionConfig{DatabaseType: PostgreSQL}, false},
+		{"sqlite", ConnectionConfig{DatabaseType: SQLite, Host: "files.internal", Database: "app.db"}, false},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := MissingPassword(&tt.config); got != tt.want {
+				t.Errorf("MissingPassword() = %v, want %v", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestMissingPassword_EmptyOrOmitted(t *testing.T) {
+	// A saved connection without a password field and one with an empty password read the same
+	for _, saved := range []string{
+		"name: prod\ndatabase_type: 1\nhost: prod-db.internal\nusername: admin\n",
+		"name: prod\ndatabase_type: 1\nhost: prod-db.internal\nusername: admin\npassword: \"\"\n",
+	} {
+		var config ConnectionConfig
+		if err := yaml.Unmarshal([]byte(saved), &config); err != nil {
+			t.Fatalf("Failed to read connection: %v", err)
+		}
+		if !MissingPassword(&config) {
+			t.Errorf("Expected a missing password for\n%s", saved)
+		}
+	}
+}
+
+func TestClassifyConnectError(t *testing.T) {
+	refused := &net.OpError{Op: "dial", Net: "tcp", Err: errors.New("connection refused")}
+	if err := classifyConnectError(refused); !errors.Is(err, ErrServerUnreachable) || !errors.Is(err, refused) {
+		t.Errorf("Expected a refused dial to be unreachable, got %v", err)
+	}
+
+	other := errors.New("database \"shop\" does not exist")
+	if err := classifyConnectError(other); err != other {
+		t.Errorf("Expected other errors unchanged, got %v", err)
+	}
+	if classifyConnectError(nil) != nil {
+		t.Error("Expected nil for nil")
+	}
+}
+
+func TestIsConnectionLost(t *testing.T) {
+	tests := []struct {
+		name string
+		err  error
+		want bool
+	}{
+		{"bad connection", fmt.Errorf("failed to execute query: %w", driver.ErrBadConn), true},
+		{"reset by peer", &net.OpError{Op: "read", Net: "tcp", Err: errors.New("connection reset by peer")}, true},
+		{"unreachable", classifyConnectError(&net.OpError{Op: "dial", Net: "tcp", Err: errors.New("connection refused")}), true},
+		{"syntax error", errors.New(`near "FORM": syntax error`), false},
+		{"nil", nil, false},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := IsConnectionLost(tt.err); got != tt.want {
+				t.Errorf("IsConnectionLost(%v) = %v, want %v", tt.err, got, tt.want)
+			}
+		})
+	}
+}
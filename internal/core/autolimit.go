@@ -0,0 +1,126 @@
+package core
+
+import (
+	"fmt"
+	"strings"
+)
+
+// unlimitableKeywords at the top level of a SELECT mean it already bounds its
+// rows, or takes no LIMIT after them
+var unlimitableKeywords = []string{"LIMIT", "FETCH", "OFFSET", "TOP", "INTO", "FOR", "PROCEDURE"}
+
+// compoundKeywords join the results of several SELECTs
+var compoundKeywords = []string{"UNION", "INTERSECT", "EXCEPT"}
+
+// aggregateFunctions fold all the rows they read into one value
+var aggregateFunctions = []string{
+	"COUNT", "SUM", "AVG", "MIN", "MAX", "TOTAL", "EVERY", "BOOL_AND", "BOOL_OR",
+	"STRING_AGG", "ARRAY_AGG", "GROUP_CONCAT", "JSON_AGG", "JSONB_AGG", "JSON_ARRAYAGG",
+	"JSON_OBJECTAGG", "JSON_GROUP_ARRAY", "STDDEV", "STDDEV_POP", "STDDEV_SAMP",
+	"VARIANCE", "VAR_POP", "VAR_SAMP",
+}
+
+// AutoLimit returns query with LIMIT limit added when it is a single SELECT
+// that could return any number of rows: its outer query has no LIMIT, FETCH
+// FIRST or OFFSET, and is not made only of aggregates, which return one row
+// without GROUP BY. A LIMIT inside a subquery or a CTE does not count. The
+// query is read with the lexer, so strings and comments are never mistaken
+// for clauses. limit 0 or less turns the safeguard off.
+func AutoLimit(dbType DatabaseType, query string, limit int) (string, bool) {
+	if limit <= 0 {
+		return query, false
+	}
+	statements := SplitStatements(dbType, query)
+	if len(statements) != 1 || !unboundedSelect(sqlTokens(dbType, statements[0])) {
+		return query, false
+	}
+	// On its own line so a trailing -- comment cannot swallow it
+	return fmt.Sprintf("%s\nLIMIT %d", statements[0], limit), true
+}
+
+// unboundedSelect reports whether tokens are a SELECT, or a WITH ending in
+// one, whose outer query may return any number of rows
+func unboundedSelect(tokens []string) bool {
+	if len(tokens) == 0 || !containsFold([]string{"SELECT", "WITH"}, tokens[0]) {
+		return false
+	}
+
+	selectAt, fromAt := -1, -1
+	grouped, compound := false, false
+	depth := 0
+	for i, token := range tokens {
+		switch token {
+		case "(":
+			depth++
+			continue
+		case ")":
+			depth--
+			continue
+		}
+		// A data-modifying CTE writes whatever the outer query returns
+		if containsFold(writingKeywords, token) {
+			return false
+		}
+		if depth > 0 {
+			continue
+		}
+
+		switch keyword := strings.ToUpper(token); {
+		case containsFold(unlimitableKeywords, keyword):
+			return false
+		case keyword == "SELECT" && selectAt < 0:
+			selectAt = i
+		case keyword == "FROM" && selectAt >= 0 && fromAt < 0:
+			fromAt = i
+		case keyword == "GROUP":
+			grouped = true
+		case containsFold(compoundKeywords, keyword):
+			compound = true
+		}
+	}
+
+	// Without FROM a SELECT returns a single row
+	if selectAt < 0 || fromAt < 0 {
+		return false
+	}
+	return grouped || compound || !hasAggregate(tokens[selectAt+1:fromAt])
+}
+
+// hasAggregate reports whether a select list calls an aggregate function,
+// leaving out window functions (COUNT(*) OVER ...) and scalar subqueries,
+// which give a value per row
+func hasAggregate(list []string) bool {
+	for i := 0; i < len(list); i++ {
+		if list[i] != "(" {
+			continue
+		}
+		end := closingParen(list, i)
+		if i+1 < len(list) && strings.EqualFold(list[i+1], "SELECT") {
+			i = end
+			continue
+		}
+		window := end+1 < len(list) && strings.EqualFold(list[end+1], "OVER")
+		if i > 0 && containsFold(aggregateFunctions, list[i-1]) && !window {
+			return true
+		}
+	}
+	return false
+}
+
+// closingParen returns the index of the parenthesis closing the one at open,
+// or the last index when it is never closed
+func closingParen(tokens []string, open int) int {
+	depth := 0
+	for i := open; i < len(tokens); i++ {
+		switch tokens[i] {
+		case "(":
+			depth++
+		case ")":
+			depth--
+			if depth == 0 {
+				return i
+			}
+		}
+	}
+	return len(tokens) - 1
+}
@@ -0,0 +1,71 @@
+package core
+
+import "testing"
+
+func TestAutoLimit(t *testing.T) {
+	testCases := []struct {
+		name     string
+		query    string
+		expected string // empty when the query is left alone
+	}{
+		{"Plain select", "SELECT * FROM users", "SELECT * FROM users\nLIMIT 500"},
+		{"Terminating semicolon", "SELECT * FROM users;", "SELECT * FROM users\nLIMIT 500"},
+		{"Trailing comment", "SELECT * FROM users -- no limit", "SELECT * FROM users -- no limit\nLIMIT 500"},
+		{"LIMIT in a string", "SELECT * FROM notes WHERE body = 'LIMIT 5'", "SELECT * FROM notes WHERE body = 'LIMIT 5'\nLIMIT 500"},
+		{"LIMIT in a comment", "SELECT * FROM users /* LIMIT 5 */", "SELECT * FROM users /* LIMIT 5 */\nLIMIT 500"},
+		{
+			"LIMIT in a derived table",
+			"SELECT * FROM (SELECT * FROM orders ORDER BY id LIMIT 10) o JOIN users u ON u.id = o.user_id",
+			"SELECT * FROM (SELECT * FROM orders ORDER BY id LIMIT 10) o JOIN users u ON u.id = o.user_id\nLIMIT 500",
+		},
+		{
+			"LIMIT in a WHERE subquery",
+			"SELECT * FROM users WHERE id IN (SELECT user_id FROM orders LIMIT 5)",
+			"SELECT * FROM users WHERE id IN (SELECT user_id FROM orders LIMIT 5)\nLIMIT 500",
+		},
+		{
+			"LIMIT in a CTE",
+			"WITH recent AS (SELECT * FROM orders LIMIT 10) SELECT * FROM recent",
+			"WITH recent AS (SELECT * FROM orders LIMIT 10) SELECT * FROM recent\nLIMIT 500",
+		},
+		{"Grouped aggregate", "SELECT status, count(*) FROM users GROUP BY status", "SELECT status, count(*) FROM users GROUP BY status\nLIMIT 500"},
+		{"Window function", "SELECT id, count(*) OVER () FROM users", "SELECT id, count(*) OVER () FROM users\nLIMIT 500"},
+		{
+			"Aggregate in a scalar subquery",
+			"SELECT id, (SELECT max(total) FROM orders o WHERE o.user_id = u.id) FROM users u",
+			"SELECT id, (SELECT max(total) FROM orders o WHERE o.user_id = u.id) FROM users u\nLIMIT 500",
+		},
+		{"Outer LIMIT", "SELECT * FROM users LIMIT 10", ""},
+		{"Outer LIMIT after a CTE", "WITH r AS (SELECT * FROM orders) SELECT * FROM r LIMIT 5", ""},
+		{"FETCH FIRST", "SELECT * FROM users FETCH FIRST 10 ROWS ONLY", ""},
+		{"OFFSET", "SELECT * FROM users OFFSET 10", ""},
+		{"Locking read", "SELECT * FROM users FOR UPDATE", ""},
+		{"Aggregate only", "SELECT count(*) FROM users", ""},
+		{"Aggregate in an expression", "SELECT coalesce(sum(total), 0) FROM orders", ""},
+		{"No FROM", "SELECT 1", ""},
+		{"Data-modifying CTE", "WITH gone AS (DELETE FROM users RETURNING *) SELECT * FROM gone", ""},
+		{"Not a select", "UPDATE users SET name = 'x'", ""},
+		{"Several statements", "SELECT * FROM a; SELECT * FROM b", ""},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.name, func(t *testing.T) {
+			got, applied := AutoLimit(MySQL, tc.query, 500)
+			if tc.expected == "" {
+				if applied || got != tc.query {
+					t.Errorf("Expected the query to be left alone, got %q", got)
+				}
+				return
+			}
+			if !applied || got != tc.expected {
+				t.Errorf("Expected %q, got %q (applied %v)", tc.expected, got, applied)
+			}
+		})
+	}
+}
+
+func TestAutoLimit_Off(t *testing.T) {
+	if got, applied := AutoLimit(MySQL, "SELECT * FROM users", 0); applied || got != "SELECT * FROM users" {
+		t.Errorf("Expected no limit when turned off, got %q", got)
+	}
+}
@@ -0,0 +1,198 @@
+package core
+
+import (
+	"database/sql"
+	"errors"
+	"fmt"
+	"sort"
+	"strings"
+	"sync"
+)
+
+var (
+	// ErrBackendNotCompiled is returned when a connection uses a backend excluded from this build
+	ErrBackendNotCompiled = errors.New("backend not included in this build")
+	// ErrBackendExists is returned when registering a database type or name twice
+	ErrBackendExists = errors.New("backend already registered")
+)
+
+// Backend describes a database type and how to connect to it.
+// Built-in drivers attach Connect from files guarded by build tags, so a slim
+// binary can leave them out with e.g. -tags no_mysql,no_postgres.
+type Backend struct {
+	// Name is the canonical name used in saved connections and on the command line
+	Name string
+	// DisplayName is shown in menus, e.g. "PostgreSQL"
+	DisplayName string
+	// Aliases are extra names accepted by ParseDatabaseType
+	Aliases []string
+	// DefaultPort is suggested when no port is given; 0 means none
+	DefaultPort int
+	// FileBased backends connect to a local file and need no host, port or credentials
+	FileBased bool
+	// Connect opens a connection; nil when the driver is not compiled into this build
+	Connect func(config *ConnectionConfig) (Connection, error)
+
+	// openSQL opens the concrete connection for database/sql backends
+	openSQL func(config *ConnectionConfig) (*SQLConnection, error)
+	// errorCode extracts the server error code from a driver error, if it is one
+	errorCode func(err error) (string, bool)
+	// errorPosition finds where in query a driver error points, if it says
+	errorPosition func(err error, query string) (ErrorPosition, bool)
+	// lostConnection are driver errors meaning the connection to the server is gone
+	lostConnection []error
+	// notices collects the warnings and notices of a statement; nil when the backend has none
+	notices noticeCollector
+	// noticesOptIn marks a collector that costs a round trip per statement, so
+	// it only runs for connections with show_warnings set
+	noticesOptIn bool
+}
+
+// The built-in types are always known so saved connections stay readable
+// even when their driver is left out of the build.
+var registry = struct {
+	sync.RWMutex
+	backends map[DatabaseType]*Backend
+}{
+	backends: map[DatabaseType]*Backend{
+		MySQL:      {Name: "mysql", DisplayName: "MySQL", DefaultPort: 3306},
+		PostgreSQL: {Name: "postgres", DisplayName: "PostgreSQL", Aliases: []string{"postgresql"}, DefaultPort: 5432},
+		SQLite:     {Name: "sqlite", DisplayName: "SQLite", Aliases: []string{"sqlite3"}, FileBased: true},
+	},
+}
+
+// RegisterBackend adds a database type. Third-party backends should pick a
+// DatabaseType of 100 or above; lower values are reserved for sqlterm itself.
+func RegisterBackend(dbType DatabaseType, b Backend) error {
+	if b.Name == "" || b.Connect == nil {
+		return fmt.Errorf("backend %d needs a name and a connect function", dbType)
+	}
+
+	registry.Lock()
+	defer registry.Unlock()
+
+	if _, ok := registry.backends[dbType]; ok {
+		return fmt.Errorf("%w: database type %d", ErrBackendExists, dbType)
+	}
+	for _, name := range append([]string{b.Name}, b.Aliases...) {
+		if _, ok := lookupName(name); ok {
+			return fmt.Errorf("%w: %s", ErrBackendExists, name)
+		}
+	}
+
+	if b.DisplayName == "" {
+		b.DisplayName = b.Name
+	}
+	registry.backends[dbType] = &b
+	return nil
+}
+
+// enableSQLBackend attaches a database/sql driver to a built-in type
+func enableSQLBackend(dbType DatabaseType, driverName string, dsn func(config *ConnectionConfig) string, errorCode func(err error) (string, bool),
+	errorPosition func(err error, query string) (ErrorPosition, bool)) {
+	registry.Lock()
+	defer registry.Unlock()
+
+	b := registry.backends[dbType]
+	b.errorCode = errorCode
+	b.errorPosition = errorPosition
+	b.openSQL = func(config *ConnectionConfig) (*SQLConnection, error) {
+		db, err := sql.Open(driverName, dsn(config))
+		if err != nil {
+			return nil, fmt.Errorf("failed to open database: %w", err)
+		}
+		// File databases keep every statement on one connection; see EffectivePool
+		EffectivePool(config).apply(db)
+		return &SQLConnection{db: db, config: config}, nil
+	}
+	// The registry only hands out the Connection interface
+	b.Connect = func(config *ConnectionConfig) (Connection, error) {
+		conn, err := b.openSQL(config)
+		if err != nil {
+			return nil, err
+		}
+		return conn, nil
+	}
+}
+
+// addLostConnectionErrors records driver errors that mean the connection to
+// the server is gone, beyond the ones database/sql knows
+func addLostConnectionErrors(dbType DatabaseType, errs ...error) {
+	registry.Lock()
+	defer registry.Unlock()
+
+	b := registry.backends[dbType]
+	b.lostConnection = append(b.lostConnection, errs...)
+}
+
+// LookupBackend returns the registered backend for dbType
+func LookupBackend(dbType DatabaseType) (Backend, bool) {
+	registry.RLock()
+	defer registry.RUnlock()
+
+	b, ok := registry.backends[dbType]
+	if !ok {
+		return Backend{}, false
+	}
+	return *b, true
+}
+
+// RegisteredBackends lists every known database type in order
+func RegisteredBackends() []DatabaseType {
+	registry.RLock()
+	defer registry.RUnlock()
+
+	types := make([]DatabaseType, 0, len(registry.backends))
+	for dbType := range registry.backends {
+		types = append(types, dbType)
+	}
+	sort.Slice(types, func(i, j int) bool { return types[i] < types[j] })
+	return types
+}
+
+// EnabledBackends lists the database types that can be connected to in this build
+func EnabledBackends() []DatabaseType {
+	var enabled []DatabaseType
+	for _, dbType := range RegisteredBackends() {
+		if b, _ := LookupBackend(dbType); b.Connect != nil {
+			enabled = append(enabled, dbType)
+		}
+	}
+	return enabled
+}
+
+// lookupName finds a backend by name or alias; the caller must hold the registry lock
+func lookupName(name string) (DatabaseType, bool) {
+	name = strings.ToLower(name)
+	for dbType, b := range registry.backends {
+		if b.Name == name || containsFold(b.Aliases, name) {
+			return dbType, true
+		}
+	}
+	return 0, false
+}
+
+func containsFold(values []string, target string) bool {
+	for _, value := range values {
+		if strings.EqualFold(value, target) {
+			return true
+		}
+	}
+	return false
+}
+
+// serverErrorCode returns the server error code carried by err, whichever driver produced it
+func serverErrorCode(err error) (string, bool) {
+	registry.RLock()
+	defer registry.RUnlock()
+
+	for _, b := range registry.backends {
+		if b.errorCode == nil {
+			continue
+		}
+		if code, ok := b.errorCode(err); ok {
+			return code, true
+		}
+	}
+	return "", false
+}
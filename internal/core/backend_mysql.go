@@ -0,0 +1,63 @@
+//go:build !no_mysql
+
+package core
+
+import (
+	"context"
+	"database/sql"
+	"errors"
+	"fmt"
+	"strconv"
+
+	"github.com/go-sql-driver/mysql"
+)
+
+func init() {
+	enableSQLBackend(MySQL, "mysql",
+		func(config *ConnectionConfig) string {
+			return fmt.Sprintf("%s:%s@tcp(%s:%d)/%s?parseTime=true",
+				config.Username, config.Password, config.Host, config.Port, config.Database)
+		},
+		func(err error) (string, bool) {
+			var mysqlErr *mysql.MySQLError
+			if errors.As(err, &mysqlErr) {
+				return strconv.Itoa(int(mysqlErr.Number)), true
+			}
+			return "", false
+		},
+		func(err error, query string) (ErrorPosition, bool) {
+			var mysqlErr *mysql.MySQLError
+			if errors.As(err, &mysqlErr) {
+				return mysqlErrorPosition(mysqlErr.Message, query)
+			}
+			return ErrorPosition{}, false
+		},
+	)
+	// The driver reports a connection the server closed mid-query as invalid
+	addLostConnectionErrors(MySQL, mysql.ErrInvalidConn)
+	// SHOW WARNINGS is a round trip of its own, so it runs only when asked for
+	setNoticeCollector(MySQL, mysqlWarnings, true)
+}
+
+// mysqlWarnings asks for the warnings of the statement once its rows are
+// read. The driver does not pass on the warning count, so SHOW WARNINGS runs
+// after every statement on a connection with show_warnings set.
+func mysqlWarnings(ctx context.Context, conn *sql.Conn) func() []Notice {
+	return func() []Notice {
+		rows, err := conn.QueryContext(ctx, "SHOW WARNINGS")
+		if err != nil {
+			return nil
+		}
+		defer rows.Close()
+
+		var notices []Notice
+		for rows.Next() {
+			var notice Notice
+			if err := rows.Scan(&notice.Level, &notice.Code, &notice.Message); err != nil {
+				return notices
+			}
+			notices = append(notices, notice)
+		}
+		return notices
+	}
+}
@@ -0,0 +1,78 @@
+//go:build !no_postgres
+
+package core
+
+import (
+	"context"
+	"database/sql"
+	"database/sql/driver"
+	"errors"
+	"fmt"
+
+	"github.com/lib/pq"
+)
+
+func init() {
+	enableSQLBackend(PostgreSQL, "postgres",
+		func(config *ConnectionConfig) string {
+			dsn := fmt.Sprintf("host=%s port=%d user=%s password=%s dbname=%s sslmode=disable",
+				config.Host, config.Port, config.Username, config.Password, config.Database)
+			if config.ReadOnly {
+				// Sent to the server as a setting, so every pooled session starts read-only
+				dsn += " default_transaction_read_only=on"
+			}
+			return dsn
+		},
+		func(err error) (string, bool) {
+			var pqErr *pq.Error
+			if errors.As(err, &pqErr) {
+				return string(pqErr.Code), true
+			}
+			return "", false
+		},
+		func(err error, query string) (ErrorPosition, bool) {
+			var pqErr *pq.Error
+			if errors.As(err, &pqErr) && pqErr.Position != "" {
+				return postgresErrorPosition(pqErr.Position, query)
+			}
+			return ErrorPosition{}, false
+		},
+	)
+	setNoticeCollector(PostgreSQL, postgresNotices, false)
+}
+
+// postgresNotices collects the NOTICE, WARNING and other messages the server
+// sends while the statement runs, e.g. from RAISE NOTICE
+func postgresNotices(_ context.Context, conn *sql.Conn) func() []Notice {
+	var notices []Notice
+	setHandler := func(handler func(*pq.Error)) {
+		conn.Raw(func(driverConn any) error {
+			if c, ok := driverConn.(driver.Conn); ok {
+				pq.SetNoticeHandler(c, handler)
+			}
+			return nil
+		})
+	}
+
+	setHandler(func(notice *pq.Error) {
+		if len(notices) < maxNotices {
+			notices = append(notices, postgresNotice(notice))
+		}
+	})
+	return func() []Notice {
+		setHandler(nil)
+		return notices
+	}
+}
+
+// postgresNotice converts a notice, leaving out the code of plain messages
+func postgresNotice(notice *pq.Error) Notice {
+	n := Notice{Level: notice.Severity, Message: notice.Message}
+	if code := string(notice.Code); code != "00000" {
+		n.Code = code
+	}
+	if notice.Detail != "" {
+		n.Message += " (" + notice.Detail + ")"
+	}
+	return n
+}
@@ -0,0 +1,39 @@
+//go:build !no_sqlite
+
+package core
+
+import (
+	"errors"
+	"strings"
+
+	"github.com/mattn/go-sqlite3"
+)
+
+func init() {
+	enableSQLBackend(SQLite, "sqlite3",
+		func(config *ConnectionConfig) string {
+			path := SQLitePath(config.Database)
+			if config.ReadOnly {
+				path += sqliteParamSeparator(path) + "_query_only=true"
+			}
+			return path
+		},
+		nil,
+		func(err error, query string) (ErrorPosition, bool) {
+			var sqliteErr sqlite3.Error
+			if errors.As(err, &sqliteErr) {
+				return sqliteErrorPosition(sqliteErr.Error(), query)
+			}
+			return ErrorPosition{}, false
+		},
+	)
+}
+
+// sqliteParamSeparator returns what joins another driver parameter to path:
+// & when a file: URI already has a query, ? otherwise
+func sqliteParamSeparator(path string) string {
+	if strings.Contains(path, "?") {
+		return "&"
+	}
+	return "?"
+}
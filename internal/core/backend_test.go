@@ -0,0 +1,151 @@
+package core
+
+import (
+	"errors"
+	"strings"
+	"testing"
+)
+
+const dummyType DatabaseType = 100
+
+func registerDummyBackend(t *testing.T) *int {
+	t.Helper()
+
+	connects := 0
+	err := RegisterBackend(dummyType, Backend{
+		Name:        "dummydb",
+		DisplayName: "DummyDB",
+		Aliases:     []string{"dummy"},
+		DefaultPort: 4242,
+		Connect: func(config *ConnectionConfig) (Connection, error) {
+			connects++
+			return nil, errors.New("dummy connect")
+		},
+	})
+	if err != nil {
+		t.Fatalf("Failed to register dummy backend: %v", err)
+	}
+
+	t.Cleanup(func() {
+		registry.Lock()
+		delete(registry.backends, dummyType)
+		registry.Unlock()
+	})
+	return &connects
+}
+
+func TestEnabledBackends_Default(t *testing.T) {
+	enabled := EnabledBackends()
+	if len(enabled) != 3 {
+		t.Errorf("Expected all backends in a default build, got %v", enabled)
+	}
+}
+
+func TestRegisterBackend_Dummy(t *testing.T) {
+	connects := registerDummyBackend(t)
+
+	dbType, err := ParseDatabaseType("DUMMY")
+	if err != nil || dbType != dummyType {
+		t.Errorf("Expected alias to resolve to dummy type, got %v, %v", dbType, err)
+	}
+
+	if dummyType.String() != "dummydb" {
+		t.Errorf("Expected name 'dummydb', got '%s'", dummyType.String())
+	}
+
+	if GetDefaultPort(dummyType) != 4242 {
+		t.Errorf("Expected default port 4242, got %d", GetDefaultPort(dummyType))
+	}
+
+	if _, err := NewConnection(&ConnectionConfig{DatabaseType: dummyType}); err == nil || err.Error() != "dummy connect" {
+		t.Errorf("Expected dummy connect error, got %v", err)
+	}
+	if *connects != 1 {
+		t.Errorf("Expected connect to be called once, got %d", *connects)
+	}
+
+	found := false
+	for _, enabled := range EnabledBackends() {
+		found = found || enabled == dummyType
+	}
+	if !found {
+		t.Error("Expected dummy backend to be enabled")
+	}
+}
+
+func TestRegisterBackend_Duplicates(t *testing.T) {
+	registerDummyBackend(t)
+	connect := func(config *ConnectionConfig) (Connection, error) { return nil, nil }
+
+	testCases := []struct {
+		name    string
+		dbType  DatabaseType
+		backend Backend
+	}{
+		{
+			name:    "Same type",
+			dbType:  dummyType,
+			backend: Backend{Name: "other", Connect: connect},
+		},
+		{
+			name:    "Name clashes with built-in",
+			dbType:  101,
+			backend: Backend{Name: "mysql", Connect: connect},
+		},
+		{
+			name:    "Alias clashes with built-in",
+			dbType:  102,
+			backend: Backend{Name: "pg2", Aliases: []string{"postgresql"}, Connect: connect},
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.name, func(t *testing.T) {
+			if err := RegisterBackend(tc.dbType, tc.backend); !errors.Is(err, ErrBackendExists) {
+				t.Errorf("Expected ErrBackendExists, got %v", err)
+			}
+		})
+	}
+
+	if err := RegisterBackend(103, Backend{Name: "noconnect"}); err == nil {
+		t.Error("Expected a backend without a connect function to be rejected")
+	}
+}
+
+func TestNewConnection_BackendNotCompiled(t *testing.T) {
+	registry.Lock()
+	saved := *registry.backends[MySQL]
+	registry.backends[MySQL].Connect = nil
+	registry.Unlock()
+	defer func() {
+		registry.Lock()
+		*registry.backends[MySQL] = saved
+		registry.Unlock()
+	}()
+
+	_, err := NewConnection(&ConnectionConfig{DatabaseType: MySQL})
+	if !errors.Is(err, ErrBackendNotCompiled) {
+		t.Fatalf("Expected ErrBackendNotCompiled, got %v", err)
+	}
+
+	if !strings.Contains(err.Error(), "does not include MySQL support") {
+		t.Errorf("Expected error to name the missing backend, got '%s'", err.Error())
+	}
+
+	if MySQL.String() != "mysql" {
+		t.Errorf("Types left out of the build should keep their name, got '%s'", MySQL.String())
+	}
+}
+
+func TestNewConnection_UnsupportedType(t *testing.T) {
+	_, err := NewConnection(&ConnectionConfig{DatabaseType: DatabaseType(99)})
+	if err == nil || errors.Is(err, ErrBackendNotCompiled) {
+		t.Errorf("Expected unsupported database type error, got %v", err)
+	}
+}
+
+func TestIsPrivilegeError(t *testing.T) {
+	if isPrivilegeError(errors.New("plain error")) {
+		t.Error("Errors without a server code should not be privilege errors")
+	}
+}
@@ -0,0 +1,196 @@
+package core
+
+import (
+	"errors"
+	"fmt"
+	"io"
+	"strconv"
+	"strings"
+	"sync"
+	"time"
+
+	"sqlterm/internal/i18n"
+)
+
+const (
+	// DefaultCheckTimeout bounds how long one connection may take to connect and answer a ping
+	DefaultCheckTimeout = 5 * time.Second
+	// DefaultCheckConcurrency is how many connections are checked at once
+	DefaultCheckConcurrency = 5
+)
+
+// ErrCheckTimeout is returned for a connection that did not answer within the check timeout
+var ErrCheckTimeout = errors.New("no answer")
+
+// CheckResult is the outcome of connecting to and pinging one saved connection
+type CheckResult struct {
+	Config  *ConnectionConfig
+	Latency time.Duration
+	// Version is the server version, when the connection reports it
+	Version string
+	Err     error
+}
+
+// CheckConnections connects to and pings each of configs, at most concurrency
+// at a time, giving each up to timeout. connect opens a connection, usually
+// NewConnection. Results are returned in the order of configs.
+func CheckConnections(configs []*ConnectionConfig, connect func(*ConnectionConfig) (Connection, error), concurrency int, timeout time.Duration) []CheckResult {
+	results := make([]CheckResult, len(configs))
+	slots := make(chan struct{}, max(concurrency, 1))
+
+	var wg sync.WaitGroup
+	for i, config := range configs {
+		wg.Add(1)
+		go func() {
+			defer wg.Done()
+			slots <- struct{}{}
+			defer func() { <-slots }()
+			results[i] = checkConnection(config, connect, timeout)
+		}()
+	}
+	wg.Wait()
+	return results
+}
+
+// checkConnection gives up waiting after timeout; a connection that answers
+// later is closed by the goroutine still waiting on it
+func checkConnection(config *ConnectionConfig, connect func(*ConnectionConfig) (Connection, error), timeout time.Duration) CheckResult {
+	done := make(chan CheckResult, 1)
+	go func() {
+		result := CheckResult{Config: config}
+		defer func() {
+			if r := recover(); r != nil {
+				result.Err = fmt.Errorf("%v", r)
+			}
+			done <- result
+		}()
+
+		start := time.Now()
+		conn, err := connect(config)
+		if err != nil {
+			result.Err = err
+			return
+		}
+		defer conn.Close()
+
+		if err := conn.Ping(); err != nil {
+			result.Err = err
+			return
+		}
+		result.Latency = time.Since(start)
+		if server, ok := conn.(ServerInfo); ok {
+			result.Version, _ = server.ServerVersion()
+		}
+	}()
+
+	select {
+	case result := <-done:
+		return result
+	case <-time.After(timeout):
+		return CheckResult{Config: config, Err: fmt.Errorf("%w after %s", ErrCheckTimeout, timeout)}
+	}
+}
+
+// WriteCheckResults writes results as a table, with the status in green or
+// red when color is set. Columns are padded on the plain text so colors do not
+// upset the alignment.
+func WriteCheckResults(w io.Writer, results []CheckResult, color bool, i18nMgr *i18n.Manager) error {
+	rows := [][]string{{
+		i18nMgr.Get("check_name_header"),
+		i18nMgr.Get("check_type_header"),
+		i18nMgr.Get("check_host_header"),
+		i18nMgr.Get("check_status_header"),
+		i18nMgr.Get("check_latency_header"),
+		i18nMgr.Get("check_version_header"),
+		i18nMgr.Get("check_error_header"),
+	}}
+	for _, result := range results {
+		status, latency, message := i18nMgr.Get("check_ok"), result.Latency.Round(time.Millisecond).String(), ""
+		if result.Err != nil {
+			status, latency, message = i18nMgr.Get("check_fail"), "-", firstLine(result.Err.Error())
+		}
+		rows = append(rows, []string{
+			result.Config.Name,
+			result.Config.DatabaseType.String(),
+			checkHost(result.Config),
+			status,
+			latency,
+			firstLine(result.Version),
+			message,
+		})
+	}
+
+	widths := make([]int, len(rows[0]))
+	for _, row := range rows {
+		for i, cell := range row {
+			widths[i] = max(widths[i], len([]rune(cell)))
+		}
+	}
+
+	const statusColumn = 3
+	var sb strings.Builder
+	for r, row := range rows {
+		for i, cell := range row {
+			padded := cell + strings.Repeat(" ", widths[i]-len([]rune(cell)))
+			if i == len(row)-1 {
+				padded = cell
+			}
+			if color && r > 0 && i == statusColumn {
+				code := "32"
+				if results[r-1].Err != nil {
+					code = "31"
+				}
+				padded = "\033[" + code + "m" + padded + "\033[0m"
+			}
+			sb.WriteString(padded)
+			if i < len(row)-1 {
+				sb.WriteString("  ")
+			}
+		}
+		sb.WriteString("\n")
+	}
+
+	_, err := io.WriteString(w, sb.String())
+	return err
+}
+
+// CheckRecord is the JSON form of a CheckResult
+type CheckRecord struct {
+	Name      string `json:"name"`
+	Type      string `json:"type"`
+	Host      string `json:"host"`
+	OK        bool   `json:"ok"`
+	LatencyMS int64  `json:"latency_ms,omitempty"`
+	Version   string `json:"version,omitempty"`
+	Error     string `json:"error,omitempty"`
+}
+
+// Record returns the JSON form of the result
+func (r CheckResult) Record() CheckRecord {
+	record := CheckRecord{
+		Name:    r.Config.Name,
+		Type:    r.Config.DatabaseType.String(),
+		Host:    checkHost(r.Config),
+		OK:      r.Err == nil,
+		Version: firstLine(r.Version),
+	}
+	if r.Err != nil {
+		record.Error = firstLine(r.Err.Error())
+	} else {
+		record.LatencyMS = r.Latency.Milliseconds()
+	}
+	return record
+}
+
+// checkHost is where a connection points: host:port, or the file of a file-based database
+func checkHost(config *ConnectionConfig) string {
+	if b, ok := LookupBackend(config.DatabaseType); ok && b.FileBased {
+		return config.Database
+	}
+	return config.Host + ":" + strconv.Itoa(config.Port)
+}
+
+func firstLine(s string) string {
+	line, _, _ := strings.Cut(s, "\n")
+	return strings.TrimSpace(line)
+}
@@ -0,0 +1,120 @@
+package core
+
+import (
+	"bytes"
+	"errors"
+	"strings"
+	"sync"
+	"testing"
+	"time"
+
+	"sqlterm/internal/i18n"
+)
+
+// pingConnection answers Ping and Close; other methods are not used by checks
+type pingConnection struct {
+	Connection
+}
+
+func (pingConnection) Ping() error { return nil }
+func (pingConnection) Close() error { return nil }
+
+func TestCheckConnections(t *testing.T) {
+	configs := []*ConnectionConfig{
+		{Name: "local", DatabaseType: PostgreSQL, Host: "localhost", Port: 5432},
+		{Name: "down", DatabaseType: MySQL, Host: "db.internal", Port: 3306},
+		{Name: "slow", DatabaseType: MySQL, Host: "far.away", Port: 3306},
+	}
+	connect := func(config *ConnectionConfig) (Connection, error) {
+		switch config.Name {
+		case "down":
+			return nil, errors.New("connection refused")
+		case "slow":
+			time.Sleep(time.Second)
+		}
+		return pingConnection{}, nil
+	}
+
+	results := CheckConnections(configs, connect, 2, 100*time.Millisecond)
+	if len(results) != 3 {
+		t.Fatalf("Expected a result per connection, got %d", len(results))
+	}
+	for i, result := range results {
+		if result.Config != configs[i] {
+			t.Errorf("Expected results in the order of the connections, got %s at %d", result.Config.Name, i)
+		}
+	}
+	if results[0].Err != nil {
+		t.Errorf("Expected local to pass, got %v", results[0].Err)
+	}
+	if results[1].Err == nil || !strings.Contains(results[1].Err.Error(), "refused") {
+		t.Errorf("Expected the connect error for down, got %v", results[1].Err)
+	}
+	if !errors.Is(results[2].Err, ErrCheckTimeout) {
+		t.Errorf("Expected slow to time out, got %v", results[2].Err)
+	}
+}
+
+func TestCheckConnections_BoundsConcurrency(t *testing.T) {
+	var mu sync.Mutex
+	running, peak := 0, 0
+	connect := func(config *ConnectionConfig) (Connection, error) {
+		mu.Lock()
+		running++
+		peak = max(peak, running)
+		mu.Unlock()
+
+		time.Sleep(20 * time.Millisecond)
+
+		mu.Lock()
+		running--
+		mu.Unlock()
+		return pingConnection{}, nil
+	}
+
+	configs := make([]*ConnectionConfig, 12)
+	for i := range configs {
+		configs[i] = &ConnectionConfig{Name: "db", DatabaseType: SQLite}
+	}
+	CheckConnections(configs, connect, 5, time.Second)
+	if peak > 5 {
+		t.Errorf("Expected at most 5 checks at once, got %d", peak)
+	}
+}
+
+func TestWriteCheckResults(t *testing.T) {
+	i18nMgr, err := i18n.NewManager("en_au")
+	if err != nil {
+		t.Fatalf("Failed to create i18n manager: %v", err)
+	}
+	results := []CheckResult{
+		{Config: &ConnectionConfig{Name: "local", DatabaseType: SQLite, Database: "/tmp/app.db"}, Latency: 3 * time.Millisecond, Version: "3.45.1"},
+		{Config: &ConnectionConfig{Name: "prod", DatabaseType: PostgreSQL, Host: "db", Port: 5432}, Err: errors.New("timeout\nmore detail")},
+	}
+
+	var plain bytes.Buffer
+	if err := WriteCheckResults(&plain, results, false, i18nMgr); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	lines := strings.Split(strings.TrimRight(plain.String(), "\n"), "\n")
+	if len(lines) != 3 || strings.Contains(plain.String(), "\033[") {
+		t.Fatalf("Expected a plain header and two rows, got:\n%s", plain.String())
+	}
+	if !strings.Contains(lines[1], "/tmp/app.db") || !strings.Contains(lines[1], "3ms") || !strings.Contains(lines[1], "3.45.1") {
+		t.Errorf("Expected the file, latency and version of local, got %q", lines[1])
+	}
+	if !strings.Contains(lines[2], "db:5432") || !strings.HasSuffix(lines[2], "timeout") {
+		t.Errorf("Expected the host and first error line of prod, got %q", lines[2])
+	}
+	if strings.Index(lines[1], "ok") != strings.Index(lines[2], "fail") {
+		t.Errorf("Expected the status column to line up:\n%s", plain.String())
+	}
+
+	var colored bytes.Buffer
+	if err := WriteCheckResults(&colored, results, true, i18nMgr); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if !strings.Contains(colored.String(), "\033[32mok") || !strings.Contains(colored.String(), "\033[31mfail") {
+		t.Errorf("Expected colored statuses, got %q", colored.String())
+	}
+}
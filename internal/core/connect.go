@@ -0,0 +1,64 @@
+package core
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"time"
+)
+
+// DefaultConnectTimeout is how long a connection attempt may take unless configured otherwise
+const DefaultConnectTimeout = 10 * time.Second
+
+// ErrConnectTimeout marks a connection attempt that gave up waiting for the server
+var ErrConnectTimeout = errors.New("no answer in time")
+
+// Connect opens config and pings it, giving up after timeout, or never when
+// timeout is 0, and stopping when ctx is cancelled. A ping that does not stop
+// with its context is left to finish in the background and its connection
+// closed then, so Connect returns as soon as it gives up. A timeout is
+// reported as ErrServerUnreachable and ErrConnectTimeout; a cancelled
+// attempt returns ctx's error.
+func Connect(ctx context.Context, config *ConnectionConfig, timeout time.Duration) (Connection, error) {
+	conn, err := NewConnection(config)
+	if err != nil {
+		return nil, err
+	}
+	attempt, cancel := ctx, context.CancelFunc(func() {})
+	if timeout > 0 {
+		attempt, cancel = context.WithTimeout(ctx, timeout)
+	}
+	defer cancel()
+
+	done := make(chan error, 1)
+	go func() { done <- pingContext(attempt, conn) }()
+	select {
+	case err := <-done:
+		if err == nil {
+			return conn, nil
+		}
+		conn.Close()
+		if attempt.Err() == nil {
+			return nil, err
+		}
+	case <-attempt.Done():
+		go func() {
+			<-done
+			conn.Close()
+		}()
+	}
+
+	if ctx.Err() != nil {
+		return nil, ctx.Err()
+	}
+	return nil, fmt.Errorf("%w: %w after %s", ErrServerUnreachable, ErrConnectTimeout, timeout)
+}
+
+// pingContext pings conn until ctx ends, or until it answers when conn
+// cannot stop early
+func pingContext(ctx context.Context, conn Connection) error {
+	if pinger, ok := conn.(interface{ PingContext(context.Context) error }); ok {
+		return pinger.PingContext(ctx)
+	}
+	return conn.Ping()
+}
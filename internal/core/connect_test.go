@@ -0,0 +1,122 @@
+package core
+
+import (
+	"context"
+	"errors"
+	"testing"
+	"time"
+)
+
+// stallConnection answers Ping once release is closed, as a server that
+// doesn't respond would, and reports Close on closed
+type stallConnection struct {
+	Connection
+	pingErr error
+	release chan struct{}
+	closed  chan struct{}
+}
+
+func newStallConnection() *stallConnection {
+	return &stallConnection{release: make(chan struct{}), closed: make(chan struct{})}
+}
+
+func (s *stallConnection) Ping() error {
+	<-s.release
+	return s.pingErr
+}
+
+func (s *stallConnection) Close() error {
+	close(s.closed)
+	return nil
+}
+
+// registerStallBackend registers a backend whose connections are conn
+func registerStallBackend(t *testing.T, conn *stallConnection) *ConnectionConfig {
+	t.Helper()
+	const stallType DatabaseType = 105
+	if err := RegisterBackend(stallType, Backend{
+		Name: "stalldb",
+		Connect: func(config *ConnectionConfig) (Connection, error) {
+			return conn, nil
+		},
+	}); err != nil {
+		t.Fatalf("Failed to register backend: %v", err)
+	}
+	t.Cleanup(func() {
+		registry.Lock()
+		delete(registry.backends, stallType)
+		registry.Unlock()
+	})
+	return &ConnectionConfig{Name: "stall", DatabaseType: stallType, Host: "far.away"}
+}
+
+func expectClosed(t *testing.T, conn *stallConnection) {
+	t.Helper()
+	select {
+	case <-conn.closed:
+	case <-time.After(time.Second):
+		t.Error("Expected the abandoned connection to be closed")
+	}
+}
+
+func TestConnect(t *testing.T) {
+	conn := newStallConnection()
+	close(conn.release)
+	config := registerStallBackend(t, conn)
+
+	got, err := Connect(context.Background(), config, time.Second)
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if got != conn {
+		t.Errorf("Expected the backend's connection, got %v", got)
+	}
+	select {
+	case <-conn.closed:
+		t.Error("Expected the connection left open")
+	default:
+	}
+}
+
+func TestConnect_PingFails(t *testing.T) {
+	conn := newStallConnection()
+	conn.pingErr = ErrAuthenticationFailed
+	close(conn.release)
+	config := registerStallBackend(t, conn)
+
+	if _, err := Connect(context.Background(), config, time.Second); !errors.Is(err, ErrAuthenticationFailed) {
+		t.Errorf("Expected the ping error, got %v", err)
+	}
+	expectClosed(t, conn)
+}
+
+func TestConnect_Timeout(t *testing.T) {
+	conn := newStallConnection()
+	config := registerStallBackend(t, conn)
+
+	start := time.Now()
+	_, err := Connect(context.Background(), config, 50*time.Millisecond)
+	if elapsed := time.Since(start); elapsed > time.Second {
+		t.Errorf("Expected Connect to give up after the timeout, took %v", elapsed)
+	}
+	if !errors.Is(err, ErrConnectTimeout) || !errors.Is(err, ErrServerUnreachable) {
+		t.Errorf("Expected an unreachable server timeout, got %v", err)
+	}
+
+	close(conn.release)
+	expectClosed(t, conn)
+}
+
+func TestConnect_Cancelled(t *testing.T) {
+	conn := newStallConnection()
+	config := registerStallBackend(t, conn)
+
+	ctx, cancel := context.WithCancel(context.Background())
+	time.AfterFunc(50*time.Millisecond, cancel)
+	if _, err := Connect(ctx, config, 0); !errors.Is(err, context.Canceled) {
+		t.Errorf("Expected context.Canceled, got %v", err)
+	}
+
+	close(conn.release)
+	expectClosed(t, conn)
+}
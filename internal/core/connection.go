@@ -1,74 +1,105 @@
 package core
 
 import (
+	"context"
 	"database/sql"
 	"fmt"
-
-	_ "github.com/go-sql-driver/mysql"
-	_ "github.com/lib/pq"
-	_ "github.com/mattn/go-sqlite3"
 )
 
 type Connection interface {
 	Ping() error
 	Execute(query string) (*QueryResult, error)
+	ExecuteContext(ctx context.Context, query string) (*QueryResult, error)
 	ListTables() ([]string, error)
 	DescribeTable(tableName string) (*TableInfo, error)
 	Close() error
 }
 
-type connection struct {
+// ParamExecutor is implemented by connections that can bind statement parameters
+type ParamExecutor interface {
+	ExecuteParams(ctx context.Context, query string, args ...any) (*QueryResult, error)
+}
+
+// RowsExecutor is implemented by connections that can run a statement that
+// returns no rows, with bound parameters, and tell how many rows it changed
+type RowsExecutor interface {
+	ExecParams(ctx context.Context, query string, args ...any) (int64, error)
+}
+
+// SQLConnection is the Connection implementation for database/sql drivers.
+// Use OpenSQLConnection when you need its concrete methods, such as DB.
+type SQLConnection struct {
 	db     *sql.DB
 	config *ConnectionConfig
 }
 
-func NewConnection(config *ConnectionConfig) (Connection, error) {
-	var dsn string
-	var driverName string
-
-	switch config.DatabaseType {
-	case MySQL:
-		driverName = "mysql"
-		dsn = fmt.Sprintf("%s:%s@tcp(%s:%d)/%s?parseTime=true",
-			config.Username, config.Password, config.Host, config.Port, config.Database)
-	case PostgreSQL:
-		driverName = "postgres"
-		dsn = fmt.Sprintf("host=%s port=%d user=%s password=%s dbname=%s sslmode=disable",
-			config.Host, config.Port, config.Username, config.Password, config.Database)
-	case SQLite:
-		driverName = "sqlite3"
-		dsn = config.Database
-	default:
-		return nil, fmt.Errorf("unsupported database type: %v", config.DatabaseType)
+// OpenSQLConnection opens a connection for a built-in database/sql backend
+func OpenSQLConnection(config *ConnectionConfig) (*SQLConnection, error) {
+	b, ok := LookupBackend(config.DatabaseType)
+	if !ok || b.openSQL == nil {
+		return nil, fmt.Errorf("%s is not a database/sql backend in this build", config.DatabaseType)
 	}
+	return b.openSQL(config)
+}
 
-	db, err := sql.Open(driverName, dsn)
-	if err != nil {
-		return nil, fmt.Errorf("failed to open database: %w", err)
-	}
+// DB returns the underlying connection pool
+func (c *SQLConnection) DB() *sql.DB {
+	return c.db
+}
+
+// Config returns the configuration the connection was opened with
+func (c *SQLConnection) Config() *ConnectionConfig {
+	return c.config
+}
 
-	conn := &connection{
-		db:     db,
-		config: config,
+// NewConnection opens a connection through the backend registered for config.DatabaseType
+func NewConnection(config *ConnectionConfig) (Connection, error) {
+	b, ok := LookupBackend(config.DatabaseType)
+	if !ok {
+		return nil, fmt.Errorf("%w %q: connection %s needs a newer sqlterm, or a build that includes its backend",
+			ErrUnknownDatabaseType, config.TypeName(), config.Name)
+	}
+	if b.Connect == nil {
+		return nil, fmt.Errorf("%w: this build does not include %s support; rebuild without the no_%s build tag",
+			ErrBackendNotCompiled, b.DisplayName, b.Name)
 	}
 
-	return conn, nil
+	conn, err := b.Connect(config)
+	return conn, redactConnectError(err, config.Password)
 }
 
-func (c *connection) Ping() error {
-	return c.db.Ping()
+// Ping checks the server answers. A failure is marked with
+// ErrAuthenticationFailed or ErrServerUnreachable when the error tells which,
+// and never shows the password.
+func (c *SQLConnection) Ping() error {
+	return classifyConnectError(redactConnectError(c.db.Ping(), c.config.Password))
 }
 
-func (c *connection) Execute(query string) (*QueryResult, error) {
-	rows, err := c.db.Query(query)
+func (c *SQLConnection) Execute(query string) (*QueryResult, error) {
+	return c.ExecuteContext(context.Background(), query)
+}
+
+// ExecuteContext runs query until ctx is cancelled; ctx must stay alive while the result is read
+func (c *SQLConnection) ExecuteContext(ctx context.Context, query string) (*QueryResult, error) {
+	return c.query(ctx, query)
+}
+
+// ExecuteParams runs query with args bound to its placeholders
+func (c *SQLConnection) ExecuteParams(ctx context.Context, query string, args ...any) (*QueryResult, error) {
+	return c.query(ctx, query, args...)
+}
+
+// ExecParams runs a statement that returns no rows with args bound to its
+// placeholders and returns how many rows it changed
+func (c *SQLConnection) ExecParams(ctx context.Context, query string, args ...any) (int64, error) {
+	result, err := c.db.ExecContext(ctx, query, args...)
 	if err != nil {
-		return nil, fmt.Errorf("failed to execute query: %w", err)
+		return 0, fmt.Errorf("failed to execute query: %w", err)
 	}
-
-	return NewQueryResult(rows)
+	return result.RowsAffected()
 }
 
-func (c *connection) ListTables() ([]string, error) {
+func (c *SQLConnection) ListTables() ([]string, error) {
 	var query string
 	switch c.config.DatabaseType {
 	case MySQL:
@@ -76,7 +107,7 @@ func (c *connection) ListTables() ([]string, error) {
 	case PostgreSQL:
 		query = "SELECT tablename FROM pg_tables WHERE schemaname = 'public'"
 	case SQLite:
-		query = "SELECT name FROM sqlite_master WHERE type='table'"
+		return c.listSQLiteTables()
 	default:
 		return nil, fmt.Errorf("unsupported database type: %v", c.config.DatabaseType)
 	}
@@ -99,19 +130,20 @@ func (c *connection) ListTables() ([]string, error) {
 	return tables, nil
 }
 
-func (c *connection) DescribeTable(tableName string) (*TableInfo, error) {
+func (c *SQLConnection) DescribeTable(tableName string) (*TableInfo, error) {
 	var query string
 	switch c.config.DatabaseType {
 	case MySQL:
-		query = fmt.Sprintf("DESCRIBE %s", tableName)
+		query = fmt.Sprintf("SHOW FULL COLUMNS FROM %s", tableName)
 	case PostgreSQL:
 		query = fmt.Sprintf(`
-			SELECT column_name, data_type, is_nullable, column_default, ''
+			SELECT column_name, data_type, is_nullable, column_default, '',
+			       COALESCE(col_description(format('%%I.%%I', table_schema, table_name)::regclass, ordinal_position), '')
 			FROM information_schema.columns
 			WHERE table_name = '%s'
 			ORDER BY ordinal_position`, tableName)
 	case SQLite:
-		query = fmt.Sprintf("PRAGMA table_info(%s)", tableName)
+		query = sqlitePragma("table_info", tableName)
 	default:
 		return nil, fmt.Errorf("unsupported database type: %v", c.config.DatabaseType)
 	}
@@ -134,13 +166,14 @@ func (c *connection) DescribeTable(tableName string) (*TableInfo, error) {
 
 		switch c.config.DatabaseType {
 		case MySQL:
-			var key, extra string
-			err = rows.Scan(&column.Name, &column.Type, &nullable, &key, &defaultVal, &extra)
+			var key, extra, privileges string
+			var collation sql.NullString
+			err = rows.Scan(&column.Name, &column.Type, &collation, &nullable, &key, &defaultVal, &extra, &privileges, &column.Comment)
 			column.Key = key
 			column.Extra = extra
 		case PostgreSQL:
 			var extra string
-			err = rows.Scan(&column.Name, &column.Type, &nullable, &defaultVal, &extra)
+			err = rows.Scan(&column.Name, &column.Type, &nullable, &defaultVal, &extra, &column.Comment)
 			column.Extra = extra
 		case SQLite:
 			var cid int
@@ -155,7 +188,8 @@ func (c *connection) DescribeTable(tableName string) (*TableInfo, error) {
 			return nil, fmt.Errorf("failed to scan column info: %w", err)
 		}
 
-		column.Nullable = (nullable == "YES" || nullable == "1")
+		// SQLite reports notnull rather than nullable
+		column.Nullable = nullable == "YES" || (c.config.DatabaseType == SQLite && nullable == "0")
 		if defaultVal != nil {
 			var defaultStr string
 			switch v := defaultVal.(type) {
@@ -196,7 +230,7 @@ func (c *connection) DescribeTable(tableName string) (*TableInfo, error) {
 	return tableInfo, nil
 }
 
-func (c *connection) getPrimaryKeys(tableName string) ([]string, error) {
+func (c *SQLConnection) getPrimaryKeys(tableName string) ([]string, error) {
 	var query string
 	switch c.config.DatabaseType {
 	case MySQL:
@@ -215,7 +249,7 @@ func (c *connection) getPrimaryKeys(tableName string) ([]string, error) {
 			WHERE i.indrelid = '%s'::regclass AND i.indisprimary
 			ORDER BY a.attnum`, tableName)
 	case SQLite:
-		query = fmt.Sprintf("PRAGMA table_info(%s)", tableName)
+		query = sqlitePragma("table_info", tableName)
 	default:
 		return nil, fmt.Errorf("unsupported database type: %v", c.config.DatabaseType)
 	}
@@ -254,7 +288,7 @@ func (c *connection) getPrimaryKeys(tableName string) ([]string, error) {
 	return primaryKeys, nil
 }
 
-func (c *connection) getConstraints(tableName string) ([]ConstraintInfo, error) {
+func (c *SQLConnection) getConstraints(tableName string) ([]ConstraintInfo, error) {
 	var query string
 	switch c.config.DatabaseType {
 	case MySQL:
@@ -302,7 +336,7 @@ func (c *connection) getConstraints(tableName string) ([]ConstraintInfo, error)
 	return constraints, nil
 }
 
-func (c *connection) getForeignKeys(tableName string) ([]ForeignKeyInfo, error) {
+func (c *SQLConnection) getForeignKeys(tableName string) ([]ForeignKeyInfo, error) {
 	var query string
 	switch c.config.DatabaseType {
 	case MySQL:
@@ -323,7 +357,7 @@ func (c *connection) getForeignKeys(tableName string) ([]ForeignKeyInfo, error)
 			JOIN information_schema.referential_constraints rc ON tc.constraint_name = rc.constraint_name
 			WHERE tc.constraint_type = 'FOREIGN KEY' AND tc.table_name = '%s'`, tableName)
 	case SQLite:
-		query = fmt.Sprintf("PRAGMA foreign_key_list(%s)", tableName)
+		query = sqlitePragma("foreign_key_list", tableName)
 	default:
 		return nil, fmt.Errorf("unsupported database type: %v", c.config.DatabaseType)
 	}
@@ -359,6 +393,6 @@ func (c *connection) getForeignKeys(tableName string) ([]ForeignKeyInfo, error)
 	return foreignKeys, nil
 }
 
-func (c *connection) Close() error {
+func (c *SQLConnection) Close() error {
 	return c.db.Close()
 }
@@ -0,0 +1,91 @@
+package core
+
+import (
+	"errors"
+
+	"gopkg.in/yaml.v3"
+)
+
+// ErrUnknownDatabaseType is returned when connecting with a database type no backend in this build registers
+var ErrUnknownDatabaseType = errors.New("unknown database type")
+
+// UnknownDatabaseType stands for a database type a connection file names but
+// this build does not know, such as one added by a newer sqlterm
+const UnknownDatabaseType DatabaseType = -1
+
+// TypeName is the connection's database type as its file names it
+func (c *ConnectionConfig) TypeName() string {
+	if c.typeName != "" {
+		return c.typeName
+	}
+	return c.DatabaseType.String()
+}
+
+// UnmarshalYAML reads a connection file. database_type may be a number, as
+// sqlterm writes it, or a backend name; a name this build does not know
+// gives UnknownDatabaseType rather than an error, so the connection is still
+// listed and only fails to connect. Settings this version does not know are
+// kept in Extra.
+func (c *ConnectionConfig) UnmarshalYAML(node *yaml.Node) error {
+	// fields has the same layout without these methods, so decoding does not recurse
+	type fields ConnectionConfig
+
+	typeNode := mappingValue(node, "database_type")
+	if typeNode == nil || typeNode.Kind != yaml.ScalarNode || typeNode.ShortTag() != "!!str" {
+		return node.Decode((*fields)(c))
+	}
+
+	rest := *node
+	rest.Content = withoutKey(node.Content, "database_type")
+	if err := rest.Decode((*fields)(c)); err != nil {
+		return err
+	}
+	if dbType, err := ParseDatabaseType(typeNode.Value); err == nil {
+		c.DatabaseType = dbType
+	} else {
+		c.DatabaseType, c.typeName = UnknownDatabaseType, typeNode.Value
+	}
+	return nil
+}
+
+// MarshalYAML writes a connection file, with a database type this build did
+// not know written back the way it was read
+func (c ConnectionConfig) MarshalYAML() (any, error) {
+	type fields ConnectionConfig
+	if c.typeName == "" {
+		return fields(c), nil
+	}
+
+	var node yaml.Node
+	if err := node.Encode(fields(c)); err != nil {
+		return nil, err
+	}
+	if typeNode := mappingValue(&node, "database_type"); typeNode != nil {
+		typeNode.SetString(c.typeName)
+	}
+	return &node, nil
+}
+
+// mappingValue returns the value of key in a mapping node, or nil
+func mappingValue(node *yaml.Node, key string) *yaml.Node {
+	if node.Kind != yaml.MappingNode {
+		return nil
+	}
+	for i := 0; i+1 < len(node.Content); i += 2 {
+		if node.Content[i].Value == key {
+			return node.Content[i+1]
+		}
+	}
+	return nil
+}
+
+// withoutKey returns the key and value nodes of a mapping without key
+func withoutKey(content []*yaml.Node, key string) []*yaml.Node {
+	var kept []*yaml.Node
+	for i := 0; i+1 < len(content); i += 2 {
+		if content[i].Value != key {
+			kept = append(kept, content[i], content[i+1])
+		}
+	}
+	return kept
+}
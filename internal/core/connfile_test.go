@@ -0,0 +1,85 @@
+package core
+
+import (
+	"errors"
+	"strings"
+	"testing"
+
+	"gopkg.in/yaml.v3"
+)
+
+// futureConnection is a connection file as a newer sqlterm might write it
+const futureConnection = `name: prod
+database_type: 1
+host: db.internal
+port: 5432
+database: shop
+username: admin
+ssl: false
+tls:
+    mode: verify-full
+    ca_file: ~/certs/ca.pem
+options:
+    application_name: sqlterm
+`
+
+func TestConnectionConfig_KeepsUnknownSettings(t *testing.T) {
+	var config ConnectionConfig
+	if err := yaml.Unmarshal([]byte(futureConnection), &config); err != nil {
+		t.Fatalf("Failed to read a newer connection file: %v", err)
+	}
+	if config.DatabaseType != PostgreSQL || config.Host != "db.internal" {
+		t.Errorf("Expected the known settings to be read, got %+v", config)
+	}
+	if len(config.Extra) != 2 {
+		t.Errorf("Expected tls and options to be kept, got %v", config.Extra)
+	}
+
+	// Editing a known field and saving keeps the newer settings
+	config.Database = "shop_v2"
+	data, err := yaml.Marshal(&config)
+	if err != nil {
+		t.Fatalf("Failed to write connection: %v", err)
+	}
+	var again ConnectionConfig
+	if err := yaml.Unmarshal(data, &again); err != nil {
+		t.Fatalf("Failed to read the written connection: %v", err)
+	}
+	tls, _ := again.Extra["tls"].(map[string]any)
+	if again.Database != "shop_v2" || tls["mode"] != "verify-full" || tls["ca_file"] != "~/certs/ca.pem" {
+		t.Errorf("Expected the round trip to keep every setting, got:\n%s", data)
+	}
+}
+
+func TestConnectionConfig_DatabaseTypeName(t *testing.T) {
+	var config ConnectionConfig
+	if err := yaml.Unmarshal([]byte("name: prod\ndatabase_type: postgres\nhost: db.internal\n"), &config); err != nil {
+		t.Fatalf("Failed to read connection: %v", err)
+	}
+	if config.DatabaseType != PostgreSQL || config.TypeName() != "postgres" {
+		t.Errorf("Expected a backend name to be read as its type, got %v", config.DatabaseType)
+	}
+}
+
+func TestConnectionConfig_UnknownDatabaseType(t *testing.T) {
+	var config ConnectionConfig
+	if err := yaml.Unmarshal([]byte("name: analytics\ndatabase_type: cockroach\nhost: crdb.internal\n"), &config); err != nil {
+		t.Fatalf("Expected a connection of an unknown type to still be read, got %v", err)
+	}
+	if config.DatabaseType != UnknownDatabaseType || config.TypeName() != "cockroach" || config.Host != "crdb.internal" {
+		t.Errorf("Unexpected connection %+v", config)
+	}
+
+	_, err := NewConnection(&config)
+	if !errors.Is(err, ErrUnknownDatabaseType) || !strings.Contains(err.Error(), `"cockroach"`) || !strings.Contains(err.Error(), "newer sqlterm") {
+		t.Errorf("Expected an error asking for a newer sqlterm, got %v", err)
+	}
+
+	data, err := yaml.Marshal(&config)
+	if err != nil {
+		t.Fatalf("Failed to write connection: %v", err)
+	}
+	if !strings.Contains(string(data), "database_type: cockroach\n") {
+		t.Errorf("Expected the type to be written back as read, got:\n%s", data)
+	}
+}
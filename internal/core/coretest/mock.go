@@ -0,0 +1,325 @@
+// Package coretest provides an in-memory core.Connection for tests that should not need a real database.
+package coretest
+
+import (
+	"context"
+	"fmt"
+	"sort"
+	"strings"
+	"sync"
+	"time"
+
+	"sqlterm/internal/core"
+)
+
+// Response is a canned result for a query
+type Response struct {
+	Columns []core.Column
+	Rows    [][]core.Value
+	Err     error
+	// Notices are reported by the result once it is read
+	Notices []core.Notice
+	// RowsAffected is what ExecParams reports for the query
+	RowsAffected int64
+}
+
+// MockConnection implements core.Connection over in-memory fixtures.
+// Errors can be injected per method ("Ping", "ListTables", "DescribeTable",
+// "ListRoutines", "ListTriggers", "ServerTime", "Close")
+// and per query through canned responses. Latency delays every call and honours
+// context cancellation in ExecuteContext.
+type MockConnection struct {
+	mu          sync.Mutex
+	tables      map[string]*core.TableInfo
+	routines    []core.RoutineInfo
+	triggers    []core.TriggerInfo
+	responses   map[string]Response
+	errors      map[string]error
+	// serverTimes are reported by ServerTime per query
+	serverTimes map[string]time.Duration
+
+	Latency time.Duration
+	Queries []string
+	// Params holds the arguments of each ExecuteParams and ExecParams call
+	Params [][]any
+	Closed bool
+}
+
+// NewMockConnection creates an empty mock connection
+func NewMockConnection() *MockConnection {
+	return &MockConnection{
+		tables:      make(map[string]*core.TableInfo),
+		responses:   make(map[string]Response),
+		errors:      make(map[string]error),
+		serverTimes: make(map[string]time.Duration),
+	}
+}
+
+// AddTable registers a table returned by ListTables and DescribeTable
+func (m *MockConnection) AddTable(info *core.TableInfo) *MockConnection {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	m.tables[info.Name] = info
+	return m
+}
+
+// AddRoutine registers a procedure or function returned by ListRoutines and DescribeRoutine
+func (m *MockConnection) AddRoutine(info core.RoutineInfo) *MockConnection {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	m.routines = append(m.routines, info)
+	return m
+}
+
+// AddTrigger registers a trigger returned by ListTriggers
+func (m *MockConnection) AddTrigger(info core.TriggerInfo) *MockConnection {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	m.triggers = append(m.triggers, info)
+	return m
+}
+
+// AddResponse registers the result returned when query is executed
+func (m *MockConnection) AddResponse(query string, columns []core.Column, rows [][]core.Value) *MockConnection {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	m.responses[normalizeQuery(query)] = Response{Columns: columns, Rows: rows}
+	return m
+}
+
+// AddNotices makes the response to query report notices, as a server's
+// warnings about it; register the response with AddResponse first
+func (m *MockConnection) AddNotices(query string, notices ...core.Notice) *MockConnection {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	response := m.responses[normalizeQuery(query)]
+	response.Notices = append(response.Notices, notices...)
+	m.responses[normalizeQuery(query)] = response
+	return m
+}
+
+// AddRowsAffected makes ExecParams report n changed rows for query; register
+// the response with AddResponse first
+func (m *MockConnection) AddRowsAffected(query string, n int64) *MockConnection {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	response := m.responses[normalizeQuery(query)]
+	response.RowsAffected = n
+	m.responses[normalizeQuery(query)] = response
+	return m
+}
+
+// AddServerTime makes ServerTime report d for query
+func (m *MockConnection) AddServerTime(query string, d time.Duration) *MockConnection {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	m.serverTimes[normalizeQuery(query)] = d
+	return m
+}
+
+// AddQueryError makes executing query fail with err
+func (m *MockConnection) AddQueryError(query string, err error) *MockConnection {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	m.responses[normalizeQuery(query)] = Response{Err: err}
+	return m
+}
+
+// FailMethod makes the named method fail with err
+func (m *MockConnection) FailMethod(method string, err error) *MockConnection {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	m.errors[method] = err
+	return m
+}
+
+// ExecutedQueries returns a copy of the queries run so far
+func (m *MockConnection) ExecutedQueries() []string {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	return append([]string(nil), m.Queries...)
+}
+
+func (m *MockConnection) Ping() error {
+	m.wait()
+	return m.methodError("Ping")
+}
+
+// PingContext answers like Ping but stops waiting when ctx ends
+func (m *MockConnection) PingContext(ctx context.Context) error {
+	if m.Latency > 0 {
+		select {
+		case <-time.After(m.Latency):
+		case <-ctx.Done():
+			return ctx.Err()
+		}
+	}
+	return m.methodError("Ping")
+}
+
+func (m *MockConnection) Execute(query string) (*core.QueryResult, error) {
+	return m.ExecuteContext(context.Background(), query)
+}
+
+func (m *MockConnection) ExecuteContext(ctx context.Context, query string) (*core.QueryResult, error) {
+	if m.Latency > 0 {
+		select {
+		case <-time.After(m.Latency):
+		case <-ctx.Done():
+			return nil, fmt.Errorf("failed to execute query: %w", ctx.Err())
+		}
+	}
+
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	m.Queries = append(m.Queries, query)
+
+	response, ok := m.responses[normalizeQuery(query)]
+	if !ok {
+		return nil, fmt.Errorf("failed to execute query: no canned response for %q", query)
+	}
+	if response.Err != nil {
+		return nil, fmt.Errorf("failed to execute query: %w", response.Err)
+	}
+
+	result := core.NewStaticQueryResult(response.Columns, response.Rows)
+	result.AddNotices(response.Notices...)
+	return result, nil
+}
+
+// ExecuteParams records args and answers like ExecuteContext
+func (m *MockConnection) ExecuteParams(ctx context.Context, query string, args ...any) (*core.QueryResult, error) {
+	m.mu.Lock()
+	m.Params = append(m.Params, args)
+	m.mu.Unlock()
+	return m.ExecuteContext(ctx, query)
+}
+
+// ExecParams records the query and args like ExecuteParams and reports the
+// rows set with AddRowsAffected
+func (m *MockConnection) ExecParams(ctx context.Context, query string, args ...any) (int64, error) {
+	result, err := m.ExecuteParams(ctx, query, args...)
+	if err != nil {
+		return 0, err
+	}
+	result.Close()
+
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	return m.responses[normalizeQuery(query)].RowsAffected, nil
+}
+
+// ExecutedParams returns a copy of the arguments passed to ExecuteParams so far
+func (m *MockConnection) ExecutedParams() [][]any {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	return append([][]any(nil), m.Params...)
+}
+
+func (m *MockConnection) ListTables() ([]string, error) {
+	m.wait()
+	if err := m.methodError("ListTables"); err != nil {
+		return nil, err
+	}
+
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	tables := make([]string, 0, len(m.tables))
+	for name := range m.tables {
+		tables = append(tables, name)
+	}
+	sort.Strings(tables)
+	return tables, nil
+}
+
+func (m *MockConnection) DescribeTable(tableName string) (*core.TableInfo, error) {
+	m.wait()
+	if err := m.methodError("DescribeTable"); err != nil {
+		return nil, err
+	}
+
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	info, ok := m.tables[tableName]
+	if !ok {
+		return nil, fmt.Errorf("failed to describe table: table %s does not exist", tableName)
+	}
+	return info, nil
+}
+
+func (m *MockConnection) ListRoutines() ([]core.RoutineInfo, error) {
+	if err := m.methodError("ListRoutines"); err != nil {
+		return nil, err
+	}
+
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	return append([]core.RoutineInfo(nil), m.routines...), nil
+}
+
+func (m *MockConnection) ListTriggers(table string) ([]core.TriggerInfo, error) {
+	if err := m.methodError("ListTriggers"); err != nil {
+		return nil, err
+	}
+
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	var triggers []core.TriggerInfo
+	for _, trigger := range m.triggers {
+		if table == "" || trigger.Table == table {
+			triggers = append(triggers, trigger)
+		}
+	}
+	return triggers, nil
+}
+
+func (m *MockConnection) DescribeRoutine(name string) (*core.RoutineInfo, error) {
+	routines, err := m.ListRoutines()
+	if err != nil {
+		return nil, err
+	}
+	for _, routine := range routines {
+		if strings.EqualFold(routine.Name, name) {
+			return &routine, nil
+		}
+	}
+	return nil, fmt.Errorf("%w: %s", core.ErrRoutineNotFound, name)
+}
+
+// ServerTime reports the time registered with AddServerTime, without running the query
+func (m *MockConnection) ServerTime(ctx context.Context, query string) (time.Duration, error) {
+	if err := m.methodError("ServerTime"); err != nil {
+		return 0, err
+	}
+
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	d, ok := m.serverTimes[normalizeQuery(query)]
+	if !ok {
+		return 0, core.ErrServerTimeNotSupported
+	}
+	return d, nil
+}
+
+func (m *MockConnection) Close() error {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	m.Closed = true
+	return m.errors["Close"]
+}
+
+func (m *MockConnection) wait() {
+	if m.Latency > 0 {
+		time.Sleep(m.Latency)
+	}
+}
+
+func (m *MockConnection) methodError(method string) error {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	return m.errors[method]
+}
+
+func normalizeQuery(query string) string {
+	return strings.TrimSuffix(strings.TrimSpace(query), ";")
+}
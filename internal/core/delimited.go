@@ -0,0 +1,273 @@
+package core
+
+import (
+	"bufio"
+	"bytes"
+	"fmt"
+	"io"
+	"os"
+	"path/filepath"
+	"strings"
+)
+
+// DefaultNullString marks NULL in TSV output, as mysql's batch mode does
+const DefaultNullString = `\N`
+
+// Export formats accepted by NewRowWriter
+const (
+	ExportCSV  = "csv"
+	ExportTSV  = "tsv"
+	ExportNull = "null"
+	ExportJSON = "json"
+)
+
+// ExportFormats lists the formats accepted by NewRowWriter
+var ExportFormats = []string{ExportCSV, ExportTSV, ExportNull, ExportJSON}
+
+// ExportFlushRows is how many rows StreamResult writes between flushes and progress reports
+const ExportFlushRows = 10000
+
+// ExportProgress is told how many rows an export has written so far
+type ExportProgress func(rows int)
+
+// RowWriter writes a result to a file or stream one row at a time
+type RowWriter interface {
+	WriteHeaders(columns []string) error
+	WriteRow(row []Value) error
+	Close() error
+}
+
+// DelimitedWriter writes rows for line-oriented tools such as awk, cut and xargs.
+// Fields are separated by tabs and records end with recordEnd: a newline for
+// TSV, or a NUL byte for null-delimited output. Backslashes and separators
+// inside values are escaped as \\, \t, \n and \r so every record stays on its
+// own line and NULL stays distinct from a value spelt like NullString.
+type DelimitedWriter struct {
+	writer    *bufio.Writer
+	closer    io.Closer
+	recordEnd byte
+	// NullString is written for NULL values
+	NullString string
+	// format renders values as displayed; nil writes machine-readable ISO/raw values
+	format *FormatOptions
+}
+
+// NewTSVWriter writes tab-separated records, one per line
+func NewTSVWriter(w io.Writer) *DelimitedWriter {
+	return newDelimitedWriter(w, '\n')
+}
+
+// NewNullDelimitedWriter writes tab-separated records ending in a NUL byte,
+// keeping newlines inside values as they are
+func NewNullDelimitedWriter(w io.Writer) *DelimitedWriter {
+	return newDelimitedWriter(w, 0)
+}
+
+func newDelimitedWriter(w io.Writer, recordEnd byte) *DelimitedWriter {
+	writer := &DelimitedWriter{
+		writer:     bufio.NewWriter(w),
+		recordEnd:  recordEnd,
+		NullString: DefaultNullString,
+	}
+	if closer, ok := w.(io.Closer); ok {
+		writer.closer = closer
+	}
+	return writer
+}
+
+func (w *DelimitedWriter) WriteHeaders(columns []string) error {
+	fields := make([]string, len(columns))
+	for i, column := range columns {
+		fields[i] = w.escape(column)
+	}
+	return w.writeRecord(fields)
+}
+
+func (w *DelimitedWriter) WriteRow(row []Value) error {
+	fields := make([]string, len(row))
+	for i, val := range row {
+		switch {
+		case val.IsNull():
+			fields[i] = w.NullString
+		case w.format != nil:
+			fields[i] = w.escape(FormatValue(val, *w.format))
+		default:
+			fields[i] = w.escape(FormatISO(val))
+		}
+	}
+	return w.writeRecord(fields)
+}
+
+func (w *DelimitedWriter) writeRecord(fields []string) error {
+	if _, err := w.writer.WriteString(strings.Join(fields, "\t")); err != nil {
+		return err
+	}
+	return w.writer.WriteByte(w.recordEnd)
+}
+
+// escape protects the separators; null-delimited records may hold newlines as they are
+func (w *DelimitedWriter) escape(s string) string {
+	if !strings.ContainsAny(s, "\\\t\n\r") {
+		return s
+	}
+
+	var sb strings.Builder
+	for _, r := range s {
+		switch {
+		case r == '\\':
+			sb.WriteString(`\\`)
+		case r == '\t':
+			sb.WriteString(`\t`)
+		case r == '\n' && w.recordEnd == '\n':
+			sb.WriteString(`\n`)
+		case r == '\r' && w.recordEnd == '\n':
+			sb.WriteString(`\r`)
+		default:
+			sb.WriteRune(r)
+		}
+	}
+	return sb.String()
+}
+
+// WriteMetadata writes meta as '#' comment records ahead of the header
+func (w *DelimitedWriter) WriteMetadata(meta ExportMetadata) error {
+	return writeLines(w.writer, metadataComment(meta), w.recordEnd)
+}
+
+// WriteRowCount ends the export with a '#' comment record counting its rows
+func (w *DelimitedWriter) WriteRowCount(rows int) error {
+	return writeLines(w.writer, []string{rowCountComment(rows)}, w.recordEnd)
+}
+
+// Flush writes buffered records to the destination
+func (w *DelimitedWriter) Flush() error {
+	return w.writer.Flush()
+}
+
+// Close flushes buffered output and closes the destination if it can be closed
+func (w *DelimitedWriter) Close() error {
+	err := w.writer.Flush()
+	if w.closer != nil {
+		if closeErr := w.closer.Close(); err == nil {
+			err = closeErr
+		}
+	}
+	return err
+}
+
+// ExportFormatForPath picks the export format from a file extension, defaulting to CSV
+func ExportFormatForPath(path string) string {
+	if strings.EqualFold(filepath.Ext(path), ".tsv") {
+		return ExportTSV
+	}
+	return ExportCSV
+}
+
+// NewRowWriter creates a writer for exportFormat that writes to w
+func NewRowWriter(w io.Writer, exportFormat string, format *FormatOptions) (RowWriter, error) {
+	switch exportFormat {
+	case ExportCSV:
+		return newCSVWriter(w, format), nil
+	case ExportTSV, ExportNull:
+		var writer *DelimitedWriter
+		if exportFormat == ExportTSV {
+			writer = NewTSVWriter(w)
+		} else {
+			writer = NewNullDelimitedWriter(w)
+		}
+		writer.format = format
+		return writer, nil
+	case ExportJSON:
+		return NewJSONWriter(w), nil
+	default:
+		return nil, fmt.Errorf("unknown export format %q (expected one of %s)", exportFormat, strings.Join(ExportFormats, ", "))
+	}
+}
+
+// WriteResult writes every row of result to writer, preceded by the column
+// names when header is set, and closes result. It returns the number of rows written.
+func WriteResult(result *QueryResult, writer RowWriter, header bool) (int, error) {
+	return StreamResult(result, writer, header, nil)
+}
+
+// StreamResult writes result like WriteResult, flushing writer every
+// ExportFlushRows rows so buffered output stays small however many rows there
+// are. progress, when not nil, is called after each flush.
+func StreamResult(result *QueryResult, writer RowWriter, header bool, progress ExportProgress) (int, error) {
+	count := 0
+	defer result.Close()
+	flusher, _ := writer.(interface{ Flush() error })
+
+	if header {
+		if err := writer.WriteHeaders(result.ColumnNames()); err != nil {
+			return count, fmt.Errorf("failed to write headers: %w", err)
+		}
+	}
+
+	for row := range result.Itor() {
+		if err := writer.WriteRow(row); err != nil {
+			return count, fmt.Errorf("failed to write row: %w", err)
+		}
+		count++
+
+		if count%ExportFlushRows != 0 {
+			continue
+		}
+		if flusher != nil {
+			if err := flusher.Flush(); err != nil {
+				return count, fmt.Errorf("failed to flush output: %w", err)
+			}
+		}
+		if progress != nil {
+			progress(count)
+		}
+	}
+
+	if err := result.Error(); err != nil {
+		return count, fmt.Errorf("failed to fetch data: %w", err)
+	}
+	return count, nil
+}
+
+// ResultString renders every row of result in exportFormat and closes result.
+// It holds the whole output in memory, so it is meant for small results and
+// tests; exports go through StreamResult.
+func ResultString(result *QueryResult, exportFormat string, header bool) (string, error) {
+	var buf bytes.Buffer
+	writer, err := NewRowWriter(&buf, exportFormat, nil)
+	if err != nil {
+		result.Close()
+		return "", err
+	}
+
+	_, err = WriteResult(result, writer, header)
+	if closeErr := writer.Close(); err == nil && closeErr != nil {
+		err = closeErr
+	}
+	return buf.String(), err
+}
+
+// SaveQueryResultToFile streams all rows to filePath as CSV, or as TSV for a
+// .tsv file. A nil format keeps values machine-readable, and a nil meta
+// leaves out the metadata comments. progress, when not nil, is called every
+// ExportFlushRows rows.
+func SaveQueryResultToFile(result *QueryResult, filePath string, format *FormatOptions, meta *ExportMetadata, progress ExportProgress) (int, error) {
+	file, err := os.Create(filePath)
+	if err != nil {
+		result.Close()
+		return 0, fmt.Errorf("failed to create export file: %w", err)
+	}
+
+	writer, err := NewRowWriter(file, ExportFormatForPath(filePath), format)
+	if err != nil {
+		file.Close()
+		result.Close()
+		return 0, err
+	}
+
+	count, err := StreamResultWithMetadata(result, writer, true, meta, progress)
+	if closeErr := writer.Close(); err == nil && closeErr != nil {
+		err = fmt.Errorf("failed to write export file: %w", closeErr)
+	}
+	return count, err
+}
@@ -0,0 +1,174 @@
+package core
+
+import (
+	"bytes"
+	"os"
+	"path/filepath"
+	"testing"
+)
+
+// delimitedFixture has values holding the separators and a value spelt like the NULL marker
+func delimitedFixture() *QueryResult {
+	return NewStaticQueryResult(
+		[]Column{{Name: "id"}, {Name: "note"}, {Name: "path"}},
+		[][]Value{
+			{IntValue{Value: 1}, StringValue{Value: "tab\there"}, StringValue{Value: `C:\tmp`}},
+			{IntValue{Value: 2}, StringValue{Value: "two\nlines"}, StringValue{Null: true}},
+			{IntValue{Value: 3}, StringValue{Value: `\N`}, StringValue{Value: "cr\r\n"}},
+		},
+	)
+}
+
+func TestDelimitedWriter(t *testing.T) {
+	tests := []struct {
+		name       string
+		format     string
+		header     bool
+		nullString string
+		want       string
+	}{
+		{
+			name:   "tsv",
+			format: ExportTSV,
+			header: true,
+			want: "id\tnote\tpath\n" +
+				"1\ttab\\there\tC:\\\\tmp\n" +
+				"2\ttwo\\nlines\t\\N\n" +
+				"3\t\\\\N\tcr\\r\\n\n",
+		},
+		{
+			name:       "tsv without header and custom null",
+			format:     ExportTSV,
+			nullString: "NULL",
+			want: "1\ttab\\there\tC:\\\\tmp\n" +
+				"2\ttwo\\nlines\tNULL\n" +
+				"3\t\\\\N\tcr\\r\\n\n",
+		},
+		{
+			name:   "null-delimited keeps newlines",
+			format: ExportNull,
+			header: true,
+			want: "id\tnote\tpath\x00" +
+				"1\ttab\\there\tC:\\\\tmp\x00" +
+				"2\ttwo\nlines\t\\N\x00" +
+				"3\t\\\\N\tcr\r\n\x00",
+		},
+		{
+			name:   "csv",
+			format: ExportCSV,
+			header: true,
+			want: "id,note,path\n" +
+				"1,tab\there,C:\\tmp\n" +
+				"2,\"two\nlines\",\n" +
+				"3,\\N,\"cr\r\n\"\n",
+		},
+		{
+			name:   "json",
+			format: ExportJSON,
+			header: true,
+			want: `{"id":1,"note":"tab\there","path":"C:\\tmp"}` + "\n" +
+				`{"id":2,"note":"two\nlines","path":null}` + "\n" +
+				`{"id":3,"note":"\\N","path":"cr\r\n"}` + "\n",
+		},
+		{
+			name:   "json without header keys by position",
+			format: ExportJSON,
+			want: `{"1":1,"2":"tab\there","3":"C:\\tmp"}` + "\n" +
+				`{"1":2,"2":"two\nlines","3":null}` + "\n" +
+				`{"1":3,"2":"\\N","3":"cr\r\n"}` + "\n",
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			var buf bytes.Buffer
+			writer, err := NewRowWriter(&buf, tt.format, nil)
+			if err != nil {
+				t.Fatalf("Unexpected error: %v", err)
+			}
+			if tt.nullString != "" {
+				writer.(*DelimitedWriter).NullString = tt.nullString
+			}
+
+			count, err := WriteResult(delimitedFixture(), writer, tt.header)
+			if err != nil || count != 3 {
+				t.Fatalf("Expected 3 rows written, got %d (%v)", count, err)
+			}
+			if err := writer.Close(); err != nil {
+				t.Fatalf("Unexpected error closing writer: %v", err)
+			}
+			if got := buf.String(); got != tt.want {
+				t.Errorf("Output mismatch\ngot:  %q\nwant: %q", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestNewRowWriter_UnknownFormat(t *testing.T) {
+	if _, err := NewRowWriter(&bytes.Buffer{}, "xml", nil); err == nil {
+		t.Error("Expected an error for an unknown format")
+	}
+}
+
+func TestSaveQueryResultToFile_TSVByExtension(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "out.tsv")
+	if _, err := SaveQueryResultToFile(delimitedFixture(), path, nil, nil, nil); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+
+	data, err := os.ReadFile(path)
+	if err != nil {
+		t.Fatalf("Failed to read export: %v", err)
+	}
+	if !bytes.HasPrefix(data, []byte("id\tnote\tpath\n1\ttab\\there")) {
+		t.Errorf("Expected a .tsv export to be tab-separated, got %q", data)
+	}
+}
+
+func TestStreamResult_FlushesAndReportsProgress(t *testing.T) {
+	rows := make([][]Value, 2*ExportFlushRows+500)
+	for i := range rows {
+		rows[i] = []Value{IntValue{Value: int64(i)}}
+	}
+	result := NewStaticQueryResult([]Column{{Name: "n"}}, rows)
+
+	var buf bytes.Buffer
+	writer, err := NewRowWriter(&buf, ExportCSV, nil)
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	var reported []int
+	var flushed []int
+	count, err := StreamResult(result, writer, true, func(rows int) {
+		reported = append(reported, rows)
+		flushed = append(flushed, bytes.Count(buf.Bytes(), []byte("\n")))
+	})
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+
+	if count != len(rows) {
+		t.Errorf("Expected %d rows written, got %d", len(rows), count)
+	}
+	if len(reported) != 2 || reported[0] != ExportFlushRows || reported[1] != 2*ExportFlushRows {
+		t.Errorf("Expected progress every %d rows, got %v", ExportFlushRows, reported)
+	}
+	// The header and every row so far reach the destination before progress is reported
+	if len(flushed) != 2 || flushed[0] != ExportFlushRows+1 || flushed[1] != 2*ExportFlushRows+1 {
+		t.Errorf("Expected rows to be flushed before each progress report, got %v lines", flushed)
+	}
+}
+
+func TestResultString(t *testing.T) {
+	got, err := ResultString(delimitedFixture(), ExportTSV, false)
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if got != "1\ttab\\there\tC:\\\\tmp\n2\ttwo\\nlines\t\\N\n3\t\\\\N\tcr\\r\\n\n" {
+		t.Errorf("Unexpected output %q", got)
+	}
+
+	if _, err := ResultString(delimitedFixture(), "xml", true); err == nil {
+		t.Error("Expected an error for an unknown format")
+	}
+}
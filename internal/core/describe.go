@@ -0,0 +1,107 @@
+package core
+
+import (
+	"errors"
+	"fmt"
+	"strings"
+
+	"sqlterm/internal/i18n"
+)
+
+// ErrTableNotFound is returned when describing a table the database does not have
+var ErrTableNotFound = errors.New("no such table")
+
+// TableExists reports whether conn has table, and its name as the database
+// lists it. Names are matched ignoring case, as unquoted identifiers are on
+// most servers.
+func TableExists(conn Connection, table string) (string, bool, error) {
+	tables, err := conn.ListTables()
+	if err != nil {
+		return "", false, err
+	}
+	for _, name := range tables {
+		if name == table {
+			return name, true, nil
+		}
+	}
+	for _, name := range tables {
+		if strings.EqualFold(name, table) {
+			return name, true, nil
+		}
+	}
+	return "", false, nil
+}
+
+// TableMarkdown describes a table's columns, keys and constraints as markdown,
+// as /describe and sqlterm describe show it
+func TableMarkdown(tableInfo *TableInfo, i18nMgr *i18n.Manager) string {
+	var sb strings.Builder
+
+	// Title
+	sb.WriteString(fmt.Sprintf("# 📊 %s: %s\n\n", i18nMgr.Get("table_header"), tableInfo.Name))
+
+	// Columns section
+	sb.WriteString(fmt.Sprintf("## 📋 %s\n\n", i18nMgr.Get("columns_header")))
+	sb.WriteString(i18nMgr.Get("column_table_header"))
+	sb.WriteString(i18nMgr.Get("column_table_separator"))
+
+	for _, col := range tableInfo.Columns {
+		nullable := i18nMgr.Get("not_nullable")
+		if col.Nullable {
+			nullable = i18nMgr.Get("nullable")
+		}
+
+		key := ""
+		if col.Key != "" {
+			key = fmt.Sprintf(i18nMgr.Get("key_format"), col.Key)
+		}
+
+		defaultVal := ""
+		if col.Default != nil {
+			defaultVal = fmt.Sprintf("`%s`", *col.Default)
+		}
+
+		sb.WriteString(fmt.Sprintf("| **%s** | `%s` | %s | %s | %s |\n",
+			col.Name, col.Type, nullable, key, defaultVal))
+	}
+
+	// Primary keys section
+	if len(tableInfo.PrimaryKeys) > 0 {
+		sb.WriteString(fmt.Sprintf("\n## 🔑 %s\n\n", i18nMgr.Get("primary_keys_header")))
+		for _, pk := range tableInfo.PrimaryKeys {
+			sb.WriteString(fmt.Sprintf("- **%s**\n", pk))
+		}
+	}
+
+	// Constraints section
+	if len(tableInfo.Constraints) > 0 {
+		sb.WriteString(fmt.Sprintf("\n## ⚠️ %s\n\n", i18nMgr.Get("constraints_header")))
+		for _, constraint := range tableInfo.Constraints {
+			sb.WriteString(fmt.Sprintf("### %s (%s)\n", constraint.Name, constraint.Type))
+			sb.WriteString(fmt.Sprintf("- **%s:** %s\n", i18nMgr.Get("column_header"), constraint.Column))
+			if constraint.Check != "" {
+				sb.WriteString(fmt.Sprintf("- **%s:** `%s`\n", i18nMgr.Get("check_header"), constraint.Check))
+			}
+			sb.WriteString("\n")
+		}
+	}
+
+	// Foreign keys section
+	if len(tableInfo.ForeignKeys) > 0 {
+		sb.WriteString(fmt.Sprintf("\n## 🔗 %s\n\n", i18nMgr.Get("foreign_keys_header")))
+		for _, fk := range tableInfo.ForeignKeys {
+			sb.WriteString(fmt.Sprintf("### %s\n", fk.Name))
+			sb.WriteString(fmt.Sprintf("- **%s:** %s\n", i18nMgr.Get("column_header"), fk.Column))
+			sb.WriteString(fmt.Sprintf("- **References:** %s.%s\n", fk.ReferencedTable, fk.ReferencedColumn))
+			if fk.OnDelete != "" {
+				sb.WriteString(fmt.Sprintf("- **On Delete:** %s\n", fk.OnDelete))
+			}
+			if fk.OnUpdate != "" {
+				sb.WriteString(fmt.Sprintf("- **On Update:** %s\n", fk.OnUpdate))
+			}
+			sb.WriteString("\n")
+		}
+	}
+
+	return sb.String()
+}
@@ -0,0 +1,305 @@
+package core
+
+import (
+	"encoding/hex"
+	"errors"
+	"fmt"
+	"regexp"
+	"strconv"
+	"strings"
+	"unicode"
+	"unicode/utf8"
+)
+
+var (
+	// ErrNotSingleTable is returned for results that do not come from a plain single-table SELECT
+	ErrNotSingleTable = errors.New("the result does not come from a single-table SELECT")
+	// ErrJoinedResult is returned for results of a query that combines tables
+	ErrJoinedResult = errors.New("the result comes from a query with joins")
+	// ErrComputedColumns is returned for results whose columns are renamed or
+	// computed, so they cannot be told apart from the table's own columns
+	ErrComputedColumns = errors.New("the result has renamed or computed columns")
+	// ErrEditRowCount is returned when an edit did not change exactly one row
+	ErrEditRowCount = errors.New("the edit did not change exactly one row")
+	// ErrEditNoPrimaryKey is returned when the result does not hold every primary key column
+	ErrEditNoPrimaryKey = errors.New("the result does not include the table's primary key")
+	// ErrParamsNotSupported is returned by connections that cannot bind statement parameters
+	ErrParamsNotSupported = errors.New("bind parameters are not supported by this connection")
+)
+
+// clauseKeywords end the FROM clause of a SELECT
+var clauseKeywords = []string{"WHERE", "ORDER", "LIMIT", "OFFSET", "FETCH", "FOR", "WINDOW", "HAVING", "GROUP"}
+
+// SourceTable returns the table a query's rows come from. The query must be a
+// single SELECT from one table, without joins, unions, grouping or DISTINCT,
+// so each result row is exactly one table row.
+func SourceTable(dbType DatabaseType, query string) (string, error) {
+	table, _, err := sourceTable(dbType, query)
+	return table, err
+}
+
+// EditableTable is SourceTable for results whose cells can be written back.
+// The select list may only name columns, or *, so each result column is the
+// table column of that name rather than an alias or expression.
+func EditableTable(dbType DatabaseType, query string) (string, error) {
+	table, selectList, err := sourceTable(dbType, query)
+	if err != nil {
+		return "", err
+	}
+	if !plainColumns(dbType, selectList) {
+		return "", ErrComputedColumns
+	}
+	return table, nil
+}
+
+// sourceTable is SourceTable that also returns the tokens of the select list
+func sourceTable(dbType DatabaseType, query string) (string, []string, error) {
+	statements := SplitStatements(dbType, query)
+	if len(statements) != 1 {
+		return "", nil, ErrNotSingleTable
+	}
+	tokens := sqlTokens(dbType, statements[0])
+	if len(tokens) == 0 || !strings.EqualFold(tokens[0], "SELECT") {
+		return "", nil, ErrNotSingleTable
+	}
+
+	from := -1
+	depth := 0
+	for i, token := range tokens {
+		switch token {
+		case "(":
+			depth++
+		case ")":
+			depth--
+		}
+		if depth > 0 {
+			continue
+		}
+
+		switch strings.ToUpper(token) {
+		case "JOIN", "UNION", "INTERSECT", "EXCEPT":
+			return "", nil, ErrJoinedResult
+		case "DISTINCT", "GROUP":
+			return "", nil, ErrNotSingleTable
+		case "FROM":
+			if from < 0 {
+				from = i
+			}
+		}
+	}
+	if from < 0 || from+1 >= len(tokens) || tokens[from+1] == "(" {
+		return "", nil, ErrNotSingleTable
+	}
+
+	// FROM a, b is a join as well
+	for _, token := range tokens[from+2:] {
+		if containsFold(clauseKeywords, token) {
+			break
+		}
+		if token == "," {
+			return "", nil, ErrJoinedResult
+		}
+	}
+	return unquoteIdentifier(tokens[from+1]), tokens[1:from], nil
+}
+
+// plainColumns reports whether the tokens of a select list only name columns,
+// possibly table-qualified, or *: no aliases, expressions or literals. MySQL
+// reads "name" as a string, so it is only a column elsewhere.
+func plainColumns(dbType DatabaseType, tokens []string) bool {
+	if len(tokens) == 0 {
+		return false
+	}
+	afterDot := false // the previous token ends in a dot, so a name may follow
+	for i, token := range tokens {
+		first := i == 0 || tokens[i-1] == ","
+		switch {
+		case token == ",":
+			if first || afterDot {
+				return false
+			}
+		case token == ".":
+			if first || afterDot {
+				return false
+			}
+		case token == "*" || token[0] == '"' && dbType != MySQL || token[0] == '`' || isIdentStart(token):
+			if !first && !afterDot {
+				return false
+			}
+		default:
+			return false
+		}
+		afterDot = strings.HasSuffix(token, ".")
+		if token == "*" && i+1 < len(tokens) && tokens[i+1] != "," {
+			return false
+		}
+	}
+	return !afterDot && tokens[len(tokens)-1] != ","
+}
+
+// isIdentStart reports whether token is an unquoted name, not a number
+func isIdentStart(token string) bool {
+	r, _ := utf8.DecodeRuneInString(token)
+	return r == '_' || unicode.IsLetter(r)
+}
+
+// unquoteIdentifier removes the quotes around a quoted identifier
+func unquoteIdentifier(name string) string {
+	if len(name) >= 2 && (name[0] == '"' || name[0] == '`') && name[len(name)-1] == name[0] {
+		quote := string(name[0])
+		return strings.ReplaceAll(name[1:len(name)-1], quote+quote, quote)
+	}
+	return name
+}
+
+// QuoteIdentifier quotes name for dbType: backticks for MySQL, double quotes
+// otherwise. Each part of a schema-qualified name is quoted separately.
+func QuoteIdentifier(dbType DatabaseType, name string) string {
+	quote := `"`
+	if dbType == MySQL {
+		quote = "`"
+	}
+
+	parts := strings.Split(name, ".")
+	for i, part := range parts {
+		parts[i] = quote + strings.ReplaceAll(part, quote, quote+quote) + quote
+	}
+	return strings.Join(parts, ".")
+}
+
+// KeyValue is one primary key column of the row being edited and its value
+type KeyValue struct {
+	Column string
+	Value  Value
+}
+
+// CellEdit changes one column of a table row identified by its primary key
+type CellEdit struct {
+	Table  string
+	Column string
+	// Value is the new value; nil sets the column to NULL
+	Value *string
+	// Key holds every primary key column of the row
+	Key []KeyValue
+}
+
+// Statement returns the UPDATE with bind parameters for dbType and the values to bind
+func (e CellEdit) Statement(dbType DatabaseType) (string, []any) {
+	var args []any
+	query := e.build(dbType, func(value any) string {
+		args = append(args, value)
+		if dbType == PostgreSQL {
+			return "$" + strconv.Itoa(len(args))
+		}
+		return "?"
+	})
+	return query, args
+}
+
+// Preview returns the UPDATE with its values written as literals, for review before it runs
+func (e CellEdit) Preview(dbType DatabaseType) string {
+	return e.build(dbType, func(value any) string {
+		return sqlLiteral(dbType, value)
+	})
+}
+
+func (e CellEdit) build(dbType DatabaseType, param func(any) string) string {
+	var value any
+	if e.Value != nil {
+		value = *e.Value
+	}
+
+	var sb strings.Builder
+	fmt.Fprintf(&sb, "UPDATE %s SET %s = %s WHERE ", QuoteIdentifier(dbType, e.Table), QuoteIdentifier(dbType, e.Column), param(value))
+	for i, key := range e.Key {
+		if i > 0 {
+			sb.WriteString(" AND ")
+		}
+		fmt.Fprintf(&sb, "%s = %s", QuoteIdentifier(dbType, key.Column), param(sqlArg(key.Value)))
+	}
+	return sb.String()
+}
+
+// StoredValue returns the new value of e as it should be kept in a stored
+// result whose cell held old, with the same type as old where the new value
+// reads as one, so numbers stay numbers after an edit
+func (e CellEdit) StoredValue(old Value) Value {
+	if e.Value == nil {
+		return NullValue{}
+	}
+	text := *e.Value
+	switch old.(type) {
+	case IntValue:
+		if n, err := strconv.ParseInt(text, 10, 64); err == nil {
+			return IntValue{Value: n}
+		}
+	case FloatValue:
+		if f, err := strconv.ParseFloat(text, 64); err == nil {
+			return FloatValue{Value: f}
+		}
+	case DecimalValue:
+		if plainNumber.MatchString(text) {
+			return DecimalValue{Value: text}
+		}
+	case BoolValue:
+		if b, err := strconv.ParseBool(text); err == nil {
+			return BoolValue{Value: b}
+		}
+	case BytesValue:
+		return BytesValue{Value: []byte(text)}
+	}
+	return StringValue{Value: text}
+}
+
+// plainNumber matches a number written the plain decimal way, e.g. -1.5e3
+var plainNumber = regexp.MustCompile(`^-?\d+(\.\d+)?([eE][-+]?\d+)?$`)
+
+// sqlArg converts a result value to a value database/sql can bind
+func sqlArg(v Value) any {
+	if v == nil || v.IsNull() {
+		return nil
+	}
+
+	switch t := v.(type) {
+	case IntValue:
+		return t.Value
+	case FloatValue:
+		return t.Value
+	case BoolValue:
+		return t.Value
+	case BytesValue:
+		return t.Value
+	default:
+		return FormatISO(v)
+	}
+}
+
+// sqlLiteral writes value as a SQL literal. MySQL treats backslashes in strings
+// as escapes, so they are doubled there. PostgreSQL has no X'..' for bytes, so
+// they are written as a bytea escape there.
+func sqlLiteral(dbType DatabaseType, value any) string {
+	switch v := value.(type) {
+	case nil:
+		return "NULL"
+	case int64:
+		return strconv.FormatInt(v, 10)
+	case float64:
+		return strconv.FormatFloat(v, 'g', -1, 64)
+	case bool:
+		if v {
+			return "TRUE"
+		}
+		return "FALSE"
+	case []byte:
+		if dbType == PostgreSQL {
+			return `'\x` + hex.EncodeToString(v) + "'::bytea"
+		}
+		return "X'" + hex.EncodeToString(v) + "'"
+	default:
+		s := fmt.Sprint(v)
+		if dbType == MySQL {
+			s = strings.ReplaceAll(s, `\`, `\\`)
+		}
+		return "'" + strings.ReplaceAll(s, "'", "''") + "'"
+	}
+}
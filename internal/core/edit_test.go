@@ -0,0 +1,166 @@
+package core
+
+import (
+	"errors"
+	"reflect"
+	"testing"
+)
+
+func TestSourceTable(t *testing.T) {
+	tests := []struct {
+		query   string
+		want    string
+		wantErr error
+	}{
+		{"SELECT id, name FROM users WHERE id > 10 ORDER BY name, id", "users", nil},
+		{"select * from `order items` limit 5;", "order items", nil},
+		{"SELECT * FROM public.users u", "public.users", nil},
+		{"SELECT * FROM users WHERE note = 'a JOIN b'", "users", nil},
+		{"SELECT * FROM users WHERE id IN (SELECT user_id FROM orders)", "users", nil},
+		{"SELECT * FROM users u JOIN orders o ON o.user_id = u.id", "", ErrJoinedResult},
+		{"SELECT * FROM users, orders", "", ErrJoinedResult},
+		{"SELECT id FROM users UNION SELECT id FROM admins", "", ErrJoinedResult},
+		{"SELECT DISTINCT name FROM users", "", ErrNotSingleTable},
+		{"SELECT name, count(*) FROM users GROUP BY name", "", ErrNotSingleTable},
+		{"SELECT * FROM (SELECT * FROM users) t", "", ErrNotSingleTable},
+		{"UPDATE users SET name = 'x'", "", ErrNotSingleTable},
+		{"SELECT 1; SELECT 2", "", ErrNotSingleTable},
+	}
+
+	for _, tt := range tests {
+		got, err := SourceTable(MySQL, tt.query)
+		if got != tt.want || !errors.Is(err, tt.wantErr) {
+			t.Errorf("SourceTable(%q) = %q, %v; want %q, %v", tt.query, got, err, tt.want, tt.wantErr)
+		}
+	}
+}
+
+func TestEditableTable(t *testing.T) {
+	tests := []struct {
+		query   string
+		want    string
+		wantErr error
+	}{
+		{"SELECT id, name FROM users WHERE id > 10", "users", nil},
+		{"SELECT u.id, u.*, `users`.`email` FROM users u", "users", nil},
+		{"SELECT id, \"name\" FROM users", "", ErrComputedColumns},
+		{"SELECT *, id FROM users", "users", nil},
+		{"SELECT email AS name FROM users", "", ErrComputedColumns},
+		{"SELECT email name FROM users", "", ErrComputedColumns},
+		{"SELECT id, upper(name) FROM users", "", ErrComputedColumns},
+		{"SELECT id, 'x' FROM users", "", ErrComputedColumns},
+		{"SELECT 1 FROM users", "", ErrComputedColumns},
+		{"SELECT * FROM users u JOIN orders o ON o.user_id = u.id", "", ErrJoinedResult},
+	}
+
+	for _, tt := range tests {
+		got, err := EditableTable(MySQL, tt.query)
+		if got != tt.want || !errors.Is(err, tt.wantErr) {
+			t.Errorf("EditableTable(%q) = %q, %v; want %q, %v", tt.query, got, err, tt.want, tt.wantErr)
+		}
+	}
+	if got, err := EditableTable(PostgreSQL, `SELECT "id", "u"."name" FROM users u`); got != "users" || err != nil {
+		t.Errorf("Expected quoted columns to be plain on PostgreSQL, got %q, %v", got, err)
+	}
+}
+
+func TestCellEdit(t *testing.T) {
+	quoted := "O'Brien \\ Sons"
+	tests := []struct {
+		name        string
+		edit        CellEdit
+		dbType      DatabaseType
+		wantQuery   string
+		wantArgs    []any
+		wantPreview string
+	}{
+		{
+			name:        "string escaping on MySQL",
+			edit:        CellEdit{Table: "users", Column: "name", Value: &quoted, Key: []KeyValue{{"id", IntValue{Value: 7}}}},
+			dbType:      MySQL,
+			wantQuery:   "UPDATE `users` SET `name` = ? WHERE `id` = ?",
+			wantArgs:    []any{quoted, int64(7)},
+			wantPreview: "UPDATE `users` SET `name` = 'O''Brien \\\\ Sons' WHERE `id` = 7",
+		},
+		{
+			name:        "NULL assignment on PostgreSQL",
+			edit:        CellEdit{Table: "users", Column: "deleted_at", Key: []KeyValue{{"id", IntValue{Value: 7}}}},
+			dbType:      PostgreSQL,
+			wantQuery:   `UPDATE "users" SET "deleted_at" = $1 WHERE "id" = $2`,
+			wantArgs:    []any{nil, int64(7)},
+			wantPreview: `UPDATE "users" SET "deleted_at" = NULL WHERE "id" = 7`,
+		},
+		{
+			name: "composite primary key on SQLite",
+			edit: CellEdit{Table: "order items", Column: "qty", Value: &quoted, Key: []KeyValue{
+				{"order_id", IntValue{Value: 3}},
+				{"sku", StringValue{Value: "A'1"}},
+			}},
+			dbType:      SQLite,
+			wantQuery:   `UPDATE "order items" SET "qty" = ? WHERE "order_id" = ? AND "sku" = ?`,
+			wantArgs:    []any{quoted, int64(3), "A'1"},
+			wantPreview: `UPDATE "order items" SET "qty" = 'O''Brien \ Sons' WHERE "order_id" = 3 AND "sku" = 'A''1'`,
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			query, args := tt.edit.Statement(tt.dbType)
+			if query != tt.wantQuery || !reflect.DeepEqual(args, tt.wantArgs) {
+				t.Errorf("Statement() = %q %v, want %q %v", query, args, tt.wantQuery, tt.wantArgs)
+			}
+			if preview := tt.edit.Preview(tt.dbType); preview != tt.wantPreview {
+				t.Errorf("Preview() = %q, want %q", preview, tt.wantPreview)
+			}
+		})
+	}
+}
+
+func TestCellEdit_StoredValue(t *testing.T) {
+	value := func(s string) CellEdit { return CellEdit{Value: &s} }
+	tests := []struct {
+		edit CellEdit
+		old  Value
+		want Value
+	}{
+		{value("42"), IntValue{Value: 7}, IntValue{Value: 42}},
+		{value("1.5"), FloatValue{Value: 2}, FloatValue{Value: 1.5}},
+		{value("19.90"), DecimalValue{Value: "5.00"}, DecimalValue{Value: "19.90"}},
+		{value("true"), BoolValue{}, BoolValue{Value: true}},
+		{value("many"), IntValue{Value: 7}, StringValue{Value: "many"}},
+		{value("bob"), StringValue{Null: true}, StringValue{Value: "bob"}},
+		{CellEdit{}, IntValue{Value: 7}, NullValue{}},
+	}
+	for _, tt := range tests {
+		if got := tt.edit.StoredValue(tt.old); !reflect.DeepEqual(got, tt.want) {
+			t.Errorf("StoredValue(%#v) = %#v, want %#v", tt.old, got, tt.want)
+		}
+	}
+}
+
+func TestQuoteIdentifier(t *testing.T) {
+	if got := QuoteIdentifier(PostgreSQL, `public.we"ird`); got != `"public"."we""ird"` {
+		t.Errorf("Expected each part quoted with quotes doubled, got %s", got)
+	}
+	if got := QuoteIdentifier(MySQL, "a`b"); got != "`a``b`" {
+		t.Errorf("Expected backticks doubled, got %s", got)
+	}
+}
+
+func TestForeignKeyLookup(t *testing.T) {
+	fk := ForeignKeyInfo{Column: "user_id", ReferencedTable: "users", ReferencedColumn: "id"}
+	lookup := ForeignKeyLookup(fk, IntValue{Value: 4521})
+
+	query, args := lookup.Statement(PostgreSQL)
+	if query != `SELECT * FROM "users" WHERE "id" = $1` || !reflect.DeepEqual(args, []any{int64(4521)}) {
+		t.Errorf("Statement() = %q %v", query, args)
+	}
+	if preview := lookup.Preview(MySQL); preview != "SELECT * FROM `users` WHERE `id` = 4521" {
+		t.Errorf("Preview() = %q", preview)
+	}
+
+	lookup = ForeignKeyLookup(ForeignKeyInfo{ReferencedTable: "codes", ReferencedColumn: "code"}, StringValue{Value: "it's"})
+	if preview := lookup.Preview(SQLite); preview != `SELECT * FROM "codes" WHERE "code" = 'it''s'` {
+		t.Errorf("Expected the value to be escaped, got %q", preview)
+	}
+}
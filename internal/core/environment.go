@@ -0,0 +1,81 @@
+package core
+
+import (
+	"sort"
+	"strings"
+)
+
+// EnvironmentProduction marks connections that confirm statements which modify data
+const EnvironmentProduction = "production"
+
+// Environments are the tags offered when a connection is added
+var Environments = []string{"development", "staging", EnvironmentProduction}
+
+// connectionColors maps color names to ANSI SGR codes
+var connectionColors = map[string]string{
+	"red":     "31",
+	"green":   "32",
+	"yellow":  "33",
+	"blue":    "34",
+	"magenta": "35",
+	"cyan":    "36",
+}
+
+// environmentColors are used when a connection has an environment but no color
+var environmentColors = map[string]string{
+	EnvironmentProduction: "red",
+	"staging":             "yellow",
+}
+
+// ConnectionColors returns the accepted color names in order
+func ConnectionColors() []string {
+	names := make([]string, 0, len(connectionColors))
+	for name := range connectionColors {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+	return names
+}
+
+// IsConnectionColor reports whether name is an accepted color
+func IsConnectionColor(name string) bool {
+	_, ok := connectionColors[strings.ToLower(name)]
+	return ok
+}
+
+// IsProduction reports whether the connection is tagged as production ("prod" is accepted too)
+func (c *ConnectionConfig) IsProduction() bool {
+	env := strings.ToLower(strings.TrimSpace(c.Environment))
+	return env == EnvironmentProduction || env == "prod"
+}
+
+// ConfirmsDestructive reports whether statements that modify data need confirming
+func (c *ConnectionConfig) ConfirmsDestructive() bool {
+	return c.ConfirmDestructive || c.IsProduction()
+}
+
+// colorCode returns the ANSI code for the connection's color, falling back to
+// the default for its environment, or "" when it has neither
+func (c *ConnectionConfig) colorCode() string {
+	name := strings.ToLower(c.Color)
+	if name == "" {
+		env := strings.ToLower(strings.TrimSpace(c.Environment))
+		if c.IsProduction() {
+			env = EnvironmentProduction
+		}
+		name = environmentColors[env]
+	}
+	return connectionColors[name]
+}
+
+// Label formats text for the prompt and connection list: tagged with the
+// connection's environment and, when color is set, in the connection's color
+func (c *ConnectionConfig) Label(text string, color bool) string {
+	if env := strings.TrimSpace(c.Environment); env != "" {
+		text += " [" + env + "]"
+	}
+	if code := c.colorCode(); color && code != "" {
+		text = "\033[" + code + "m" + text + "\033[0m"
+	}
+	return text
+}
@@ -0,0 +1,47 @@
+package core
+
+import "testing"
+
+func TestConnectionConfig_Label(t *testing.T) {
+	tests := []struct {
+		name   string
+		config ConnectionConfig
+		color  bool
+		want   string
+	}{
+		{"no environment", ConnectionConfig{}, true, "shop"},
+		{"environment tag without color", ConnectionConfig{Environment: "staging"}, false, "shop [staging]"},
+		{"production defaults to red", ConnectionConfig{Environment: "production"}, true, "\033[31mshop [production]\033[0m"},
+		{"prod alias", ConnectionConfig{Environment: "prod"}, true, "\033[31mshop [prod]\033[0m"},
+		{"explicit color wins", ConnectionConfig{Environment: "production", Color: "Magenta"}, true, "\033[35mshop [production]\033[0m"},
+		{"color without environment", ConnectionConfig{Color: "green"}, true, "\033[32mshop\033[0m"},
+		{"unknown color", ConnectionConfig{Color: "mauve"}, true, "shop"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := tt.config.Label("shop", tt.color); got != tt.want {
+				t.Errorf("Label() = %q, want %q", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestConnectionConfig_ConfirmsDestructive(t *testing.T) {
+	tests := []struct {
+		config ConnectionConfig
+		want   bool
+	}{
+		{ConnectionConfig{}, false},
+		{ConnectionConfig{Environment: "staging"}, false},
+		{ConnectionConfig{Environment: "Production"}, true},
+		{ConnectionConfig{Environment: "prod"}, true},
+		{ConnectionConfig{ConfirmDestructive: true}, true},
+	}
+
+	for _, tt := range tests {
+		if got := tt.config.ConfirmsDestructive(); got != tt.want {
+			t.Errorf("ConfirmsDestructive() for %+v = %v, want %v", tt.config, got, tt.want)
+		}
+	}
+}
@@ -0,0 +1,189 @@
+package core
+
+import (
+	"fmt"
+	"regexp"
+	"strconv"
+	"strings"
+	"unicode/utf8"
+)
+
+// ErrorPosition is where in a statement the server found an error. Line and
+// Column count from 1, Column in characters.
+type ErrorPosition struct {
+	Line   int
+	Column int
+}
+
+// QueryErrorPosition finds where in query the error err points, whichever
+// driver produced it. It reports false when the error carries no position.
+func QueryErrorPosition(err error, query string) (ErrorPosition, bool) {
+	registry.RLock()
+	defer registry.RUnlock()
+
+	for _, b := range registry.backends {
+		if b.errorPosition == nil {
+			continue
+		}
+		if pos, ok := b.errorPosition(err, query); ok {
+			return pos, true
+		}
+	}
+	return ErrorPosition{}, false
+}
+
+// WithErrorPointer adds the line of query that err points at to err, with a
+// caret under the column, when the driver reports where the error is
+func WithErrorPointer(err error, query string) error {
+	pos, ok := QueryErrorPosition(err, query)
+	if !ok {
+		return err
+	}
+	return fmt.Errorf("%w\n%s", err, ErrorPointer(query, pos))
+}
+
+// positionAtOffset turns a 1-based character offset into query, the way
+// PostgreSQL reports positions, into a line and column
+func positionAtOffset(query string, offset int) (ErrorPosition, bool) {
+	runes := []rune(query)
+	if offset < 1 || offset > len(runes)+1 {
+		return ErrorPosition{}, false
+	}
+	pos := ErrorPosition{Line: 1, Column: 1}
+	for _, r := range runes[:offset-1] {
+		if r == '\n' {
+			pos.Line++
+			pos.Column = 1
+			continue
+		}
+		pos.Column++
+	}
+	return pos, true
+}
+
+// postgresErrorPosition reads the Position field of a PostgreSQL error
+func postgresErrorPosition(position, query string) (ErrorPosition, bool) {
+	offset, err := strconv.Atoi(position)
+	if err != nil {
+		return ErrorPosition{}, false
+	}
+	return positionAtOffset(query, offset)
+}
+
+// mysqlNear matches the end of a MySQL syntax error: the rest of the
+// statement from the error on, then the line it starts on
+var mysqlNear = regexp.MustCompile(`(?s)near '(.*)' at line (\d+)$`)
+
+// mysqlErrorPosition reads a MySQL syntax error such as "... near 'FORM
+// users' at line 1". The column is where the quoted text starts on that line;
+// MySQL quotes nothing when the statement ended too early.
+func mysqlErrorPosition(message, query string) (ErrorPosition, bool) {
+	match := mysqlNear.FindStringSubmatch(message)
+	if match == nil {
+		return ErrorPosition{}, false
+	}
+	line, _ := strconv.Atoi(match[2])
+	lines := strings.Split(query, "\n")
+	if line < 1 || line > len(lines) {
+		return ErrorPosition{}, false
+	}
+
+	near := strings.SplitN(match[1], "\n", 2)[0]
+	text := strings.TrimRight(lines[line-1], "\r")
+	if near == "" {
+		// The statement ended early: point after its last character
+		last := strings.TrimRight(query, " \t\r\n;")
+		return positionAtOffset(query, len([]rune(last))+1)
+	}
+	i := strings.Index(text, near)
+	if i < 0 {
+		return ErrorPosition{}, false
+	}
+	return ErrorPosition{Line: line, Column: len([]rune(text[:i])) + 1}, true
+}
+
+// sqliteNear matches the token SQLite names in a syntax error, e.g. near "FORM": syntax error
+var sqliteNear = regexp.MustCompile(`^near "(.*)": syntax error`)
+
+// sqliteErrorPosition reads a SQLite syntax error. SQLite names the token but
+// not where it is, so this points at the first place outside a string or
+// comment where that token stands on its own.
+func sqliteErrorPosition(message, query string) (ErrorPosition, bool) {
+	match := sqliteNear.FindStringSubmatch(message)
+	if match == nil || match[1] == "" {
+		return ErrorPosition{}, false
+	}
+	token := match[1]
+	code := codeMask(query)
+	for from := 0; from < len(query); {
+		i := strings.Index(query[from:], token)
+		if i < 0 {
+			break
+		}
+		start, end := from+i, from+i+len(token)
+		if code[start] && !endsWithIdentRune(query[:start]) && !startsWithIdentRune(query[end:]) {
+			return positionAtOffset(query, len([]rune(query[:start]))+1)
+		}
+		from = start + 1
+	}
+	return ErrorPosition{}, false
+}
+
+// codeMask marks each byte of sql that is outside a string literal, quoted
+// identifier or comment
+func codeMask(sql string) []bool {
+	mask := make([]bool, len(sql))
+	for i := 0; i < len(sql); i++ {
+		switch {
+		case sql[i] == '\'' || sql[i] == '"' || sql[i] == '`':
+			quote := sql[i]
+			for i++; i < len(sql) && sql[i] != quote; i++ {
+			}
+		case strings.HasPrefix(sql[i:], "--"):
+			for i < len(sql) && sql[i] != '\n' {
+				i++
+			}
+		case strings.HasPrefix(sql[i:], "/*"):
+			end := strings.Index(sql[i+2:], "*/")
+			if end < 0 {
+				return mask
+			}
+			i += end + 3
+		default:
+			mask[i] = true
+		}
+	}
+	return mask
+}
+
+func startsWithIdentRune(s string) bool {
+	r, _ := utf8.DecodeRuneInString(s)
+	return r != utf8.RuneError && isIdentRune(r)
+}
+
+// ErrorPointer shows the line of query at pos with a caret under the
+// column, e.g.
+//
+//	LINE 2: SELECT * FORM users
+//	                 ^
+func ErrorPointer(query string, pos ErrorPosition) string {
+	lines := strings.Split(query, "\n")
+	if pos.Line < 1 || pos.Line > len(lines) {
+		return ""
+	}
+	prefix := "LINE " + strconv.Itoa(pos.Line) + ": "
+	text := strings.TrimRight(lines[pos.Line-1], "\r")
+	runes := []rune(text)
+	column := max(min(pos.Column, len(runes)+1), 1)
+	// Tabs keep their width so the caret lines up with the text above it
+	var indent strings.Builder
+	indent.WriteString(strings.Repeat(" ", len(prefix)))
+	for _, r := range runes[:column-1] {
+		if r == '\t' {
+			indent.WriteByte('\t')
+			continue
+		}
+		indent.WriteString(strings.Repeat(" ", max(DisplayWidth(string(r)), 1)))
+	}
+	return prefix + text + "\n" + indent.String() + "^"
+}
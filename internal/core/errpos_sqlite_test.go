@@ -0,0 +1,27 @@
+//go:build !no_sqlite
+
+package core
+
+import (
+	"path/filepath"
+	"strings"
+	"testing"
+)
+
+func TestSQLiteSyntaxErrorPointer(t *testing.T) {
+	conn, err := NewConnection(&ConnectionConfig{Name: "errpos", DatabaseType: SQLite, Database: filepath.Join(t.TempDir(), "app.db")})
+	if err != nil {
+		t.Fatalf("Failed to open SQLite: %v", err)
+	}
+	defer conn.Close()
+
+	query := "SELECT *\nFORM users"
+	_, err = conn.Execute(query)
+	if err == nil {
+		t.Fatal("Expected a syntax error")
+	}
+	got := WithErrorPointer(err, query).Error()
+	if !strings.HasSuffix(got, "\nLINE 2: FORM users\n        ^") {
+		t.Errorf("Expected a pointer at FORM, got %q", got)
+	}
+}
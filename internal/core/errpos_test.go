@@ -0,0 +1,69 @@
+package core
+
+import "testing"
+
+func TestPostgresErrorPosition(t *testing.T) {
+	// syntax error at or near "FORM", position 11
+	pos, ok := postgresErrorPosition("11", "SELECT id\nFORM users")
+	if !ok || pos != (ErrorPosition{Line: 2, Column: 1}) {
+		t.Errorf("Expected line 2, column 1, got %+v, %v", pos, ok)
+	}
+
+	// Positions count characters, not bytes
+	pos, ok = postgresErrorPosition("20", "SELECT 'café' AS x FORM users")
+	if !ok || pos != (ErrorPosition{Line: 1, Column: 20}) {
+		t.Errorf("Expected line 1, column 20, got %+v, %v", pos, ok)
+	}
+
+	if _, ok := postgresErrorPosition("", "SELECT 1"); ok {
+		t.Error("Expected no position when the error has none")
+	}
+}
+
+func TestMySQLErrorPosition(t *testing.T) {
+	const prefix = "You have an error in your SQL syntax; check the manual that corresponds to your MySQL server version for the right syntax to use "
+	tests := []struct {
+		name    string
+		message string
+		query   string
+		want    ErrorPosition
+		ok      bool
+	}{
+		{"second line", prefix + "near 'FORM users\nWHERE id = 1' at line 2", "SELECT id, name\n  FORM users\nWHERE id = 1", ErrorPosition{Line: 2, Column: 3}, true},
+		{"ended early", prefix + "near '' at line 1", "SELECT * FROM;", ErrorPosition{Line: 1, Column: 14}, true},
+		{"not a syntax error", "Table 'shop.userz' doesn't exist", "SELECT * FROM userz", ErrorPosition{}, false},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			pos, ok := mysqlErrorPosition(tt.message, tt.query)
+			if ok != tt.ok || pos != tt.want {
+				t.Errorf("mysqlErrorPosition() = %+v, %v, want %+v, %v", pos, ok, tt.want, tt.ok)
+			}
+		})
+	}
+}
+
+func TestSQLiteErrorPosition(t *testing.T) {
+	// The token in the string literal and the longer identifier are skipped
+	pos, ok := sqliteErrorPosition(`near "FORM": syntax error`, "SELECT 'FORM' AS FORMAT\nFORM users")
+	if !ok || pos != (ErrorPosition{Line: 2, Column: 1}) {
+		t.Errorf("Expected line 2, column 1, got %+v, %v", pos, ok)
+	}
+
+	if _, ok := sqliteErrorPosition("no such table: userz", "SELECT * FROM userz"); ok {
+		t.Error("Expected no position for an error that names no token")
+	}
+}
+
+func TestErrorPointer(t *testing.T) {
+	got := ErrorPointer("SELECT id\n\tFORM users", ErrorPosition{Line: 2, Column: 2})
+	want := "LINE 2: \tFORM users\n        \t^"
+	if got != want {
+		t.Errorf("ErrorPointer() =\n%s\nwant\n%s", got, want)
+	}
+
+	if got := ErrorPointer("SELECT 1", ErrorPosition{Line: 3, Column: 1}); got != "" {
+		t.Errorf("Expected nothing for a line past the end, got %q", got)
+	}
+}
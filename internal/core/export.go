@@ -7,48 +7,77 @@ import (
 	"os"
 	"path/filepath"
 	"sqlterm/internal/i18n"
+	"strconv"
 	"strings"
 	"time"
 )
 
-func ToMarkdown(result *QueryResult, limit int, i18nMgr *i18n.Manager) string {
-	count := 0
-	defer result.Close()
+func ToMarkdown(result *QueryResult, limit int, format FormatOptions, i18nMgr *i18n.Manager) string {
+	stored, err := StoreResult(result, limit)
+	if err != nil {
+		return fmt.Sprint(i18nMgr.Get("query_error"), err)
+	}
 
+	var sb strings.Builder
+	sb.WriteString(MarkdownTable(stored.Columns, stored.NextPage(limit), format))
+
+	// Add truncation note if limited
+	if stored.Incomplete {
+		sb.WriteString(fmt.Sprintf("\n%s\n", i18nMgr.GetWithArgs("markdown_truncation_note", limit)))
+	}
+
+	return sb.String()
+}
+
+// MarkdownTable renders rows as a markdown table. Cells wider than the cell
+// width are cut short, and columns are padded by terminal width so CJK text
+// stays aligned.
+func MarkdownTable(columns []Column, rows [][]Value, format FormatOptions) string {
 	var sb strings.Builder
 
 	// Calculate column widths
-	widths := make([]int, len(result.Columns))
-	rowsToProcess := make([][]string, 0)
-	for i, col := range result.Columns {
-		widths[i] = len(col.Name)
+	widths := make([]int, len(columns))
+	rowsToProcess := make([][]string, 0, len(rows))
+	for i, col := range columns {
+		widths[i] = DisplayWidth(col.Name)
 	}
 
-	for row := range result.Itor() {
-		line := make([]string, len(result.Columns))
+	for _, row := range rows {
+		line := make([]string, len(columns))
 		rowsToProcess = append(rowsToProcess, line)
 		for i, val := range row {
-			if i < len(widths) && len(val.String()) > widths[i] {
-				widths[i] = len(val.String())
+			text := FormatCell(val, format)
+			if i < len(widths) {
+				widths[i] = max(widths[i], DisplayWidth(text))
+			}
+			if i < len(line) {
+				line[i] = text
 			}
-			line[i] = val.String()
-		}
-		count++
-		if count >= limit {
-			break
 		}
 	}
 
-	if result.Error() != nil {
-		sb.WriteString(fmt.Sprint(i18nMgr.Get("query_error"), result.Error()))
-		return sb.String()
+	headers := make([]string, len(columns))
+	for i, col := range columns {
+		headers[i] = col.Name
+	}
+	if len(format.ColumnWidths) > 0 {
+		fitTable(columns, rows, format, widths, headers, rowsToProcess)
+	}
+	if format.Hyperlinks != nil {
+		for r, row := range rows {
+			for i, val := range row {
+				if i < len(columns) {
+					format.Hyperlinks.add(rowsToProcess[r][i], val)
+				}
+			}
+		}
 	}
 
 	// Write header
 	sb.WriteString("| ")
-	for i, col := range result.Columns {
-		sb.WriteString(fmt.Sprintf("%-*s", widths[i], col.Name))
-		if i < len(result.Columns)-1 {
+	for i := range columns {
+		sb.WriteString(padRight(headers[i], widths[i]))
+		if i < len(columns)-1 {
 			sb.WriteString(" | ")
 		}
 	}
@@ -56,37 +85,84 @@ func ToMarkdown(result *QueryResult, limit int, i18nMgr *i18n.Manager) string {
 
 	// Write separator
 	sb.WriteString("|")
-	for i := range result.Columns {
+	for i := range columns {
 		sb.WriteString(strings.Repeat("-", widths[i]+2))
-		if i < len(result.Columns)-1 {
+		if i < len(columns)-1 {
 			sb.WriteString("|")
 		}
 	}
 	sb.WriteString("|\n")
 
-	// Write rows (limited)
+	// Write rows
 	for _, row := range rowsToProcess {
 		sb.WriteString("| ")
 		for i, val := range row {
-			if i < len(widths) {
-				sb.WriteString(fmt.Sprintf("%-*s", widths[i], val))
-			}
-			if i < len(result.Columns)-1 {
+			sb.WriteString(padRight(val, widths[i]))
+			if i < len(columns)-1 {
 				sb.WriteString(" | ")
 			}
 		}
 		sb.WriteString(" |\n")
 	}
 
-	// Add truncation note if limited
-	if limit > 0 && count >= limit {
-		sb.WriteString(fmt.Sprintf("\n%s\n", i18nMgr.GetWithArgs("markdown_truncation_note", limit)))
+	return sb.String()
+}
+
+// fitTable resizes the columns of a table to the widths chosen with
+// /columns width, fitting it in format.TableWidth, and formats the headers
+// and cells of each resized column again to its new width
+func fitTable(columns []Column, rows [][]Value, format FormatOptions, widths []int, headers []string, lines [][]string) {
+	fitted := FitColumnWidths(widths, format.ColumnWidths, format.TableWidth)
+	for i, width := range fitted {
+		if width == widths[i] {
+			continue
+		}
+		widths[i] = width
+		cellFormat := format
+		cellFormat.MaxCellWidth = width
+		for r, row := range rows {
+			if i < len(row) {
+				lines[r][i] = FormatCell(row[i], cellFormat)
+			}
+		}
+		if DisplayWidth(headers[i]) > width {
+			headers[i] = FormatCell(StringValue{Value: columns[i].Name}, FormatOptions{MaxCellWidth: width, HideTruncatedLength: true})
+		}
+	}
+}
+
+// padRight pads s with spaces to width terminal columns
+func padRight(s string, width int) string {
+	return s + strings.Repeat(" ", max(width-DisplayWidth(s), 0))
+}
+
+// ResultPageMarkdown renders the next page of stored in layout, sized by the
+// row limit, followed by a footer saying how to see the rest when rows remain
+func ResultPageMarkdown(stored *StoredResult, layout ColumnLayout, format FormatOptions, i18nMgr *i18n.Manager) string {
+	first := stored.Shown() + 1
+	columns, page := layout.Apply(stored.Columns, stored.NextPage(format.rowLimit()))
+
+	var sb strings.Builder
+	sb.WriteString(MarkdownTable(columns, page, format))
+
+	last := stored.Shown()
+	switch {
+	case stored.Remaining() && stored.Incomplete:
+		sb.WriteString(fmt.Sprintf("\n%s\n", i18nMgr.GetWithArgs("result_more_footer_over",
+			first, last, groupThousands(strconv.Itoa(len(stored.Rows)), true))))
+	case stored.Remaining():
+		sb.WriteString(fmt.Sprintf("\n%s\n", i18nMgr.GetWithArgs("result_more_footer",
+			first, last, groupThousands(strconv.Itoa(len(stored.Rows)), true))))
+	case stored.Incomplete:
+		sb.WriteString(fmt.Sprintf("\n%s\n", i18nMgr.GetWithArgs("result_stored_limit_footer",
+			groupThousands(strconv.Itoa(len(stored.Rows)), true))))
 	}
 
 	return sb.String()
 }
 
-func SaveQueryResultAsMarkdown(result *QueryResult, query string, connection string, resultWriter io.Writer, i18nMgr *i18n.Manager) error {
+// SaveResultPageAsMarkdown writes query and the first page of stored in layout to resultWriter
+func SaveResultPageAsMarkdown(stored *StoredResult, layout ColumnLayout, query string, resultWriter io.Writer, format FormatOptions, i18nMgr *i18n.Manager) error {
 	// Format the SQL query for better readability
 	formatter := NewSQLFormatter()
 	formattedQuery := formatter.Format(query)
@@ -94,9 +170,24 @@ func SaveQueryResultAsMarkdown(result *QueryResult, query string, connection str
 	// Create markdown content
 	var content strings.Builder
 	content.WriteString(fmt.Sprintf("%s\n```sql\n%s\n```\n\n", i18nMgr.Get("markdown_query_header"), formattedQuery))
+	if source := stored.Source; source.Connection != "" {
+		content.WriteString(i18nMgr.GetWithArgs("markdown_result_source", source.Connection, source.Database, source.Time.Format("2006-01-02 15:04:05")) + "\n\n")
+	}
 
-	// Add the markdown table (limited to 20 rows)
-	content.WriteString(ToMarkdown(result, 20, i18nMgr))
+	// Add the markdown table (limited to the configured row count)
+	content.WriteString(ResultPageMarkdown(stored, layout, format, i18nMgr))
+	if stored.Number > 0 {
+		rows := groupThousands(strconv.Itoa(len(stored.Rows)), true)
+		if stored.Incomplete {
+			rows += "+"
+		}
+		footer := i18nMgr.GetWithArgs("result_number_footer", stored.Number, rows)
+		if stored.Timing.Total > 0 {
+			footer = i18nMgr.GetWithArgs("result_number_footer_timed", stored.Number, rows, stored.Timing.Label(i18nMgr))
+		}
+		content.WriteString("\n" + footer + "\n")
+	}
+	content.WriteString(NoticesMarkdown(stored.Notices, i18nMgr))
 	content.WriteString("\n\n")
 
 	// Write to file
@@ -109,21 +200,23 @@ func SaveQueryResultAsMarkdown(result *QueryResult, query string, connection str
 
 // StreamCSVWriter handles streaming CSV writes for large result sets
 type StreamCSVWriter struct {
-	file   *os.File
 	writer *csv.Writer
+	out    io.Writer
+	closer io.Closer
+	// format renders values as displayed; nil writes machine-readable ISO/raw values
+	format *FormatOptions
 }
 
-func NewStreamCSVWriter(filePath string) (*StreamCSVWriter, error) {
-	file, err := os.Create(filePath)
-	if err != nil {
-		return nil, fmt.Errorf("failed to create CSV file: %w", err)
+func newCSVWriter(w io.Writer, format *FormatOptions) *StreamCSVWriter {
+	writer := &StreamCSVWriter{
+		writer: csv.NewWriter(w),
+		out:    w,
+		format: format,
 	}
-
-	writer := csv.NewWriter(file)
-	return &StreamCSVWriter{
-		file:   file,
-		writer: writer,
-	}, nil
+	if closer, ok := w.(io.Closer); ok {
+		writer.closer = closer
+	}
+	return writer
 }
 
 func (w *StreamCSVWriter) WriteHeaders(columns []string) error {
@@ -133,47 +226,49 @@ func (w *StreamCSVWriter) WriteHeaders(columns []string) error {
 func (w *StreamCSVWriter) WriteRow(row []Value) error {
 	record := make([]string, len(row))
 	for i, val := range row {
-		record[i] = val.String()
+		if w.format != nil {
+			record[i] = FormatValue(val, *w.format)
+		} else {
+			record[i] = FormatISO(val)
+		}
 	}
 	return w.writer.Write(record)
 }
 
-func (w *StreamCSVWriter) Close() error {
+// Flush writes buffered records to the destination
+func (w *StreamCSVWriter) Flush() error {
 	w.writer.Flush()
-	if err := w.writer.Error(); err != nil {
-		w.file.Close()
-		return fmt.Errorf("CSV writer error: %w", err)
-	}
-	return w.file.Close()
+	return w.writer.Error()
 }
 
-func SaveQueryResultAsStreamingCSV(result *QueryResult, filePath string) (int, error) {
-	count := 0
-	defer result.Close()
-	writer, err := NewStreamCSVWriter(filePath)
-	if err != nil {
-		return count, err
+// WriteMetadata writes meta as '#' comment lines ahead of the header
+func (w *StreamCSVWriter) WriteMetadata(meta ExportMetadata) error {
+	if err := w.Flush(); err != nil {
+		return err
 	}
-	defer writer.Close()
+	return writeLines(w.out, metadataComment(meta), '\n')
+}
 
-	// Write headers
-	if err := writer.WriteHeaders(result.ColumnNames()); err != nil {
-		return count, fmt.Errorf("failed to write CSV headers: %w", err)
+// WriteRowCount ends the export with a '#' comment line counting its rows
+func (w *StreamCSVWriter) WriteRowCount(rows int) error {
+	if err := w.Flush(); err != nil {
+		return err
 	}
+	return writeLines(w.out, []string{rowCountComment(rows)}, '\n')
+}
 
-	// Write rows one by one
-	for row := range result.Itor() {
-		if err := writer.WriteRow(row); err != nil {
-			return count, fmt.Errorf("failed to write CSV row: %w", err)
+func (w *StreamCSVWriter) Close() error {
+	w.writer.Flush()
+	if err := w.writer.Error(); err != nil {
+		if w.closer != nil {
+			w.closer.Close()
 		}
-		count++
+		return fmt.Errorf("CSV writer error: %w", err)
 	}
-
-	if result.Error() != nil {
-		return count, fmt.Errorf("failed to fetch data: %w", err)
+	if w.closer != nil {
+		return w.closer.Close()
 	}
-
-	return count, nil
+	return nil
 }
 
 // GenerateNumberedCSVPath creates a numbered CSV filename for multiple queries
@@ -189,7 +284,7 @@ func GenerateNumberedCSVPath(baseFilePath string, queryIndex int) string {
 	return filepath.Join(dir, fmt.Sprintf("%s-%d%s", nameWithoutExt, queryIndex, ext))
 }
 
-func SaveFileQueryResultsAsMarkdown(filename string, queryResults []QueryResultWithQuery, connection string, configDir string, i18nMgr *i18n.Manager) (string, error) {
+func SaveFileQueryResultsAsMarkdown(filename string, queryResults []QueryResultWithQuery, connection string, configDir string, format FormatOptions, i18nMgr *i18n.Manager) (string, error) {
 	// Create sessions directory structure
 	sessionDir := filepath.Join(configDir, "sessions", connection)
 	if err := os.MkdirAll(sessionDir, 0755); err != nil {
@@ -213,8 +308,8 @@ func SaveFileQueryResultsAsMarkdown(filename string, queryResults []QueryResultW
 		content.WriteString(fmt.Sprintf("## %s %d\n\n", i18nMgr.Get("query_header"), i+1))
 		content.WriteString(fmt.Sprintf("**SQL:**\n```sql\n%s\n```\n\n", qr.Query))
 
-		// Add the markdown table (limited to 20 rows)
-		content.WriteString(ToMarkdown(qr.Result, 20, i18nMgr))
+		// Add the markdown table (limited to the configured row count)
+		content.WriteString(ToMarkdown(qr.Result, format.rowLimit(), format, i18nMgr))
 		content.WriteString("\n\n")
 	}
 
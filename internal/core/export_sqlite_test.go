@@ -0,0 +1,67 @@
+//go:build !no_sqlite
+
+package core
+
+import (
+	"bufio"
+	"os"
+	"path/filepath"
+	"runtime"
+	"testing"
+)
+
+// exportRows is large enough that holding the export in memory would show
+const exportRows = 200000
+
+func TestSQLiteExport_LargeResultStreams(t *testing.T) {
+	conn, err := NewConnection(&ConnectionConfig{Name: "export", DatabaseType: SQLite, Database: filepath.Join(t.TempDir(), "app.db")})
+	if err != nil {
+		t.Fatalf("Failed to open SQLite: %v", err)
+	}
+	defer conn.Close()
+
+	result, err := conn.Execute(`WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < 200000)
+		SELECT i AS id, 'row number ' || i AS label, i * 1.5 AS amount FROM n`)
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+
+	var before, peak runtime.MemStats
+	runtime.GC()
+	runtime.ReadMemStats(&before)
+	path := filepath.Join(t.TempDir(), "export.csv")
+	progressCalls := 0
+	count, err := SaveQueryResultToFile(result, path, nil, nil, func(rows int) {
+		progressCalls++
+		var now runtime.MemStats
+		runtime.ReadMemStats(&now)
+		if now.HeapAlloc > peak.HeapAlloc {
+			peak = now
+		}
+	})
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+
+	if count != exportRows || progressCalls != exportRows/ExportFlushRows {
+		t.Errorf("Expected %d rows with %d progress reports, got %d rows and %d reports", exportRows, exportRows/ExportFlushRows, count, progressCalls)
+	}
+
+	file, err := os.Open(path)
+	if err != nil {
+		t.Fatalf("Failed to open export: %v", err)
+	}
+	defer file.Close()
+	lines := 0
+	for scanner := bufio.NewScanner(file); scanner.Scan(); {
+		lines++
+	}
+	if lines != exportRows+1 {
+		t.Errorf("Expected a header and %d rows in the file, got %d lines", exportRows, lines)
+	}
+
+	// Holding on to the rows would take several times this bound
+	if peak.HeapAlloc > before.HeapAlloc && peak.HeapAlloc-before.HeapAlloc > 32<<20 {
+		t.Errorf("Expected the export to stream, but the heap grew by %d bytes", peak.HeapAlloc-before.HeapAlloc)
+	}
+}
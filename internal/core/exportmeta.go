@@ -0,0 +1,95 @@
+package core
+
+import (
+	"encoding/json"
+	"fmt"
+	"io"
+	"strconv"
+	"strings"
+	"time"
+)
+
+// ExportMetadata records where an exported result came from, so a file read
+// long after the export still says which query produced it
+type ExportMetadata struct {
+	Query      string    `json:"query"`
+	Connection string    `json:"connection"`
+	Database   string    `json:"database,omitempty"`
+	ExportedAt time.Time `json:"exported_at"`
+	Version    string    `json:"sqlterm_version"`
+}
+
+// MetadataWriter is implemented by row writers that can carry ExportMetadata.
+// WriteMetadata comes before the header and rows; WriteRowCount comes after
+// the last row, since the size of a streamed result is only known at the end.
+type MetadataWriter interface {
+	WriteMetadata(meta ExportMetadata) error
+	WriteRowCount(rows int) error
+}
+
+// StreamResultWithMetadata streams result like StreamResult. When meta is not
+// nil and writer supports it, the rows are preceded by meta and followed by
+// their count; otherwise the output is the same as StreamResult's.
+func StreamResultWithMetadata(result *QueryResult, writer RowWriter, header bool, meta *ExportMetadata, progress ExportProgress) (int, error) {
+	metaWriter, ok := writer.(MetadataWriter)
+	if meta == nil || !ok {
+		return StreamResult(result, writer, header, progress)
+	}
+
+	if err := metaWriter.WriteMetadata(*meta); err != nil {
+		result.Close()
+		return 0, fmt.Errorf("failed to write metadata: %w", err)
+	}
+	count, err := StreamResult(result, writer, header, progress)
+	if err != nil {
+		return count, err
+	}
+	if err := metaWriter.WriteRowCount(count); err != nil {
+		return count, fmt.Errorf("failed to write metadata: %w", err)
+	}
+	return count, nil
+}
+
+// metadataComment renders meta as '#' comment lines for CSV and TSV files.
+// Each line of a multi-line query gets its own comment line.
+func metadataComment(meta ExportMetadata) []string {
+	query := strings.Split(strings.TrimSpace(meta.Query), "\n")
+	lines := []string{"# query: " + strings.TrimRight(query[0], "\r")}
+	for _, line := range query[1:] {
+		lines = append(lines, "#   "+strings.TrimRight(line, "\r"))
+	}
+
+	lines = append(lines, "# connection: "+meta.Connection)
+	if meta.Database != "" {
+		lines = append(lines, "# database: "+meta.Database)
+	}
+	return append(lines,
+		"# exported_at: "+meta.ExportedAt.UTC().Format(time.RFC3339),
+		"# sqlterm_version: "+meta.Version,
+	)
+}
+
+// rowCountComment is the comment line that ends a CSV or TSV export with metadata
+func rowCountComment(rows int) string {
+	return "# rows: " + strconv.Itoa(rows)
+}
+
+// writeLines writes each line followed by end
+func writeLines(w io.Writer, lines []string, end byte) error {
+	for _, line := range lines {
+		if _, err := io.WriteString(w, line+string(end)); err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+// jsonMetadataLine wraps value in a {"_metadata": ...} line, which no row can
+// be mistaken for unless a column is named _metadata
+func jsonMetadataLine(value any) ([]byte, error) {
+	line, err := json.Marshal(map[string]any{"_metadata": value})
+	if err != nil {
+		return nil, err
+	}
+	return append(line, '\n'), nil
+}
@@ -0,0 +1,88 @@
+package core
+
+import (
+	"bytes"
+	"encoding/json"
+	"strings"
+	"testing"
+	"time"
+)
+
+func exportMetadataFixture() *ExportMetadata {
+	return &ExportMetadata{
+		Query:      "SELECT id, note, path\nFROM notes",
+		Connection: "prod-replica",
+		Database:   "shop",
+		ExportedAt: time.Date(2026, 10, 16, 9, 30, 0, 0, time.UTC),
+		Version:    "1.4.0",
+	}
+}
+
+func exportWith(t *testing.T, format string, meta *ExportMetadata) string {
+	t.Helper()
+	var buf bytes.Buffer
+	writer, err := NewRowWriter(&buf, format, nil)
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if _, err := StreamResultWithMetadata(delimitedFixture(), writer, true, meta, nil); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if err := writer.Close(); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	return buf.String()
+}
+
+func TestStreamResultWithMetadata_CSV(t *testing.T) {
+	got := exportWith(t, ExportCSV, exportMetadataFixture())
+
+	header := "# query: SELECT id, note, path\n" +
+		"#   FROM notes\n" +
+		"# connection: prod-replica\n" +
+		"# database: shop\n" +
+		"# exported_at: 2026-10-16T09:30:00Z\n" +
+		"# sqlterm_version: 1.4.0\n" +
+		"id,note,path\n"
+	if !strings.HasPrefix(got, header) {
+		t.Errorf("Expected the metadata comments before the header, got %q", got)
+	}
+	if !strings.HasSuffix(got, "\n# rows: 3\n") {
+		t.Errorf("Expected the row count last, got %q", got)
+	}
+}
+
+func TestStreamResultWithMetadata_JSON(t *testing.T) {
+	lines := strings.Split(strings.TrimSuffix(exportWith(t, ExportJSON, exportMetadataFixture()), "\n"), "\n")
+	if len(lines) != 5 {
+		t.Fatalf("Expected a metadata line, 3 rows and a row count, got %q", lines)
+	}
+
+	var first struct {
+		Metadata ExportMetadata `json:"_metadata"`
+	}
+	if err := json.Unmarshal([]byte(lines[0]), &first); err != nil {
+		t.Fatalf("Expected the first line to be JSON: %v", err)
+	}
+	want := *exportMetadataFixture()
+	exportedAt := first.Metadata.ExportedAt
+	first.Metadata.ExportedAt = want.ExportedAt
+	if first.Metadata != want || !exportedAt.Equal(want.ExportedAt) {
+		t.Errorf("Expected the metadata to round-trip, got %+v", first.Metadata)
+	}
+	if lines[4] != `{"_metadata":{"rows":3}}` {
+		t.Errorf("Expected the row count last, got %q", lines[4])
+	}
+}
+
+func TestStreamResultWithMetadata_OffMatchesStreamResult(t *testing.T) {
+	for _, format := range ExportFormats {
+		want, err := ResultString(delimitedFixture(), format, true)
+		if err != nil {
+			t.Fatalf("Unexpected error: %v", err)
+		}
+		if got := exportWith(t, format, nil); got != want {
+			t.Errorf("Expected %s output without metadata to be unchanged, got %q, want %q", format, got, want)
+		}
+	}
+}
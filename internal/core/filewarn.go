@@ -0,0 +1,68 @@
+package core
+
+import (
+	"fmt"
+	"os"
+	"sync"
+)
+
+// CorruptSuffix is added to a file that could not be parsed, so it is kept for
+// inspection but not read again
+const CorruptSuffix = ".corrupt"
+
+// FileWarning describes a file under the config directory that could not be
+// used. sqlterm carries on without it.
+type FileWarning struct {
+	Path string
+	// MovedTo is where an unparseable file was set aside, or "" when it was left in place
+	MovedTo string
+	Err     error
+}
+
+var fileWarnings struct {
+	sync.Mutex
+	pending []FileWarning
+}
+
+// WarnFile records that path could not be read or parsed, to be reported by
+// whoever next calls TakeFileWarnings. With setAside the file is renamed with
+// CorruptSuffix so it does not trip up the next start.
+func WarnFile(path string, err error, setAside bool) {
+	warning := FileWarning{Path: path, Err: err}
+	if setAside {
+		if moved, renameErr := setAsideFile(path); renameErr == nil {
+			warning.MovedTo = moved
+		}
+	}
+
+	fileWarnings.Lock()
+	defer fileWarnings.Unlock()
+	for i, pending := range fileWarnings.pending {
+		if pending.Path == path {
+			fileWarnings.pending[i] = warning
+			return
+		}
+	}
+	fileWarnings.pending = append(fileWarnings.pending, warning)
+}
+
+// TakeFileWarnings returns the warnings recorded since the last call
+func TakeFileWarnings() []FileWarning {
+	fileWarnings.Lock()
+	defer fileWarnings.Unlock()
+	warnings := fileWarnings.pending
+	fileWarnings.pending = nil
+	return warnings
+}
+
+// setAsideFile renames path to path.corrupt, or path.corrupt.N when that is taken
+func setAsideFile(path string) (string, error) {
+	target := path + CorruptSuffix
+	for n := 1; ; n++ {
+		if _, err := os.Lstat(target); os.IsNotExist(err) {
+			break
+		}
+		target = fmt.Sprintf("%s%s.%d", path, CorruptSuffix, n)
+	}
+	return target, os.Rename(path, target)
+}
@@ -0,0 +1,42 @@
+package core
+
+import (
+	"errors"
+	"os"
+	"path/filepath"
+	"testing"
+)
+
+func TestWarnFile(t *testing.T) {
+	TakeFileWarnings()
+	dir := t.TempDir()
+	path := filepath.Join(dir, "buffers.yaml")
+	parseErr := errors.New("yaml: line 1: did not find expected key")
+
+	for i, wantMoved := range []string{path + CorruptSuffix, path + CorruptSuffix + ".1"} {
+		if err := os.WriteFile(path, []byte("\x00garbage"), 0644); err != nil {
+			t.Fatal(err)
+		}
+		WarnFile(path, parseErr, true)
+
+		warnings := TakeFileWarnings()
+		if len(warnings) != 1 || warnings[0].MovedTo != wantMoved || !errors.Is(warnings[0].Err, parseErr) {
+			t.Fatalf("Attempt %d: expected one warning moved to %s, got %+v", i+1, wantMoved, warnings)
+		}
+		if _, err := os.Stat(path); !os.IsNotExist(err) {
+			t.Errorf("Attempt %d: expected the corrupt file to be set aside", i+1)
+		}
+		if _, err := os.Stat(wantMoved); err != nil {
+			t.Errorf("Attempt %d: expected the corrupt file at %s: %v", i+1, wantMoved, err)
+		}
+	}
+
+	WarnFile(filepath.Join(dir, "connections", "prod.yaml"), parseErr, false)
+	WarnFile(filepath.Join(dir, "connections", "prod.yaml"), parseErr, false)
+	if warnings := TakeFileWarnings(); len(warnings) != 1 || warnings[0].MovedTo != "" {
+		t.Errorf("Expected one warning for a file left in place, got %+v", warnings)
+	}
+	if warnings := TakeFileWarnings(); len(warnings) != 0 {
+		t.Errorf("Expected warnings to be taken once, got %+v", warnings)
+	}
+}
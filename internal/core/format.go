@@ -0,0 +1,245 @@
+package core
+
+import (
+	"fmt"
+	"strconv"
+	"strings"
+	"time"
+	"unicode"
+)
+
+// FormatOptions controls how values are rendered for display
+type FormatOptions struct {
+	// DateTimeLayout is a Go time layout for DateTime and DateTimeTz values; empty uses the defaults
+	DateTimeLayout string
+	// Location is the zone DateTimeTz values are shown in; nil means UTC
+	Location *time.Location
+	// ThousandsSeparator groups the integer part of numbers with commas
+	ThousandsSeparator bool
+	// FloatPrecision is the number of digits after the decimal point; negative means shortest form
+	FloatPrecision int
+	// RowLimit is how many rows result tables show; 0 means DefaultRowLimit
+	RowLimit int
+	// MaxCellWidth is how many terminal columns a table cell may take; 0 means
+	// DefaultMaxCellWidth and a negative width never cuts values short
+	MaxCellWidth int
+	// HideTruncatedLength leaves the full length off cells that were cut short
+	HideTruncatedLength bool
+	// ColumnWidths sets the width of table columns by position, as chosen
+	// with /columns width; 0 leaves a column sized by its values
+	ColumnWidths []int
+	// TableWidth is how many terminal columns a table with ColumnWidths may
+	// take, narrowing its auto-sized columns to fit; 0 means no limit
+	TableWidth int
+	// Hyperlinks, when not nil, collects the URL cells of tables by the text
+	// they show, so the rendered table can link them to the full URL
+	Hyperlinks Hyperlinks
+}
+
+// DefaultRowLimit is how many rows a result table shows unless configured otherwise
+const DefaultRowLimit = 200
+
+// DefaultMaxCellWidth is how wide a table cell may be unless configured otherwise
+const DefaultMaxCellWidth = 80
+
+// truncationMark ends a value that was cut short
+const truncationMark = "…"
+
+// DefaultQueryWidth is how wide a query is shown in lists and progress messages
+const DefaultQueryWidth = 50
+
+// elisionMark ends a line that was elided
+const elisionMark = "..."
+
+// DefaultFormatOptions returns the options matching each value's own String form
+func DefaultFormatOptions() FormatOptions {
+	return FormatOptions{FloatPrecision: -1}
+}
+
+// rowLimit resolves RowLimit, falling back to DefaultRowLimit
+func (o FormatOptions) rowLimit() int {
+	if o.RowLimit <= 0 {
+		return DefaultRowLimit
+	}
+	return o.RowLimit
+}
+
+// maxCellWidth resolves MaxCellWidth, falling back to DefaultMaxCellWidth
+func (o FormatOptions) maxCellWidth() int {
+	if o.MaxCellWidth == 0 {
+		return DefaultMaxCellWidth
+	}
+	return o.MaxCellWidth
+}
+
+// FormatCell renders a value for a table cell. A value wider than the cell
+// width is cut short and ends in "…", followed by its full length unless
+// HideTruncatedLength is set: characters for text and a size for binary
+// values, e.g. "… (312 chars)" or "… (4.1 KB)". The result never takes more
+// columns than the cell width, so the column stays aligned.
+func FormatCell(v Value, opts FormatOptions) string {
+	text := FormatValue(v, opts)
+	limit := opts.maxCellWidth()
+	if limit < 0 || DisplayWidth(text) <= limit {
+		return text
+	}
+
+	suffix := truncationMark
+	if !opts.HideTruncatedLength {
+		if bytes, ok := v.(BytesValue); ok {
+			suffix += " (" + FormatSize(int64(len(bytes.Value))) + ")"
+		} else {
+			suffix += fmt.Sprintf(" (%d chars)", len([]rune(text)))
+		}
+		if DisplayWidth(suffix) >= limit {
+			suffix = truncationMark
+		}
+	}
+	return truncateWidth(text, limit-DisplayWidth(suffix)) + suffix
+}
+
+// truncateWidth returns the longest prefix of s that fits in width terminal columns
+func truncateWidth(s string, width int) string {
+	used := 0
+	for i, r := range s {
+		w := runeWidth(r)
+		if used+w > width {
+			return s[:i]
+		}
+		used += w
+	}
+	return s
+}
+
+// ElideLine fits s on one line of at most width terminal columns: runs of
+// whitespace, line breaks included, become single spaces and a longer line is
+// cut short ending in "...". Only as much of s is read as is shown, so a
+// minified query of megabytes costs no more than a short one.
+func ElideLine(s string, width int) string {
+	var sb strings.Builder
+	used, keep := 0, 0 // keep is how much of sb fits beside the mark
+	add := func(r rune) bool {
+		w := runeWidth(r)
+		if used+w > width {
+			return false
+		}
+		sb.WriteRune(r)
+		used += w
+		if used <= width-len(elisionMark) {
+			keep = sb.Len()
+		}
+		return true
+	}
+
+	space := false
+	for _, r := range strings.TrimSpace(s) {
+		if unicode.IsSpace(r) {
+			space = true
+			continue
+		}
+		if space && !add(' ') || !add(r) {
+			return sb.String()[:keep] + elisionMark
+		}
+		space = false
+	}
+	return sb.String()
+}
+
+// DisplayWidth counts terminal columns, treating CJK characters as two wide
+func DisplayWidth(s string) int {
+	width := 0
+	for _, r := range s {
+		width += runeWidth(r)
+	}
+	return width
+}
+
+func runeWidth(r rune) int {
+	if unicode.In(r, unicode.Han, unicode.Hangul, unicode.Hiragana, unicode.Katakana) || (r >= 0xFF01 && r <= 0xFF60) {
+		return 2
+	}
+	return 1
+}
+
+// FormatSize renders a byte count for people, e.g. "512 B" or "4.1 KB"
+func FormatSize(n int64) string {
+	const unit = 1024
+	if n < unit {
+		return fmt.Sprintf("%d B", n)
+	}
+	size, suffix := float64(n)/unit, "KB"
+	for _, next := range []string{"MB", "GB", "TB"} {
+		if size < unit {
+			break
+		}
+		size, suffix = size/unit, next
+	}
+	return fmt.Sprintf("%.1f %s", size, suffix)
+}
+
+// FormatValue renders a value for display according to opts
+func FormatValue(v Value, opts FormatOptions) string {
+	if v.IsNull() {
+		return ""
+	}
+
+	switch t := v.(type) {
+	case IntValue:
+		return groupThousands(strconv.FormatInt(t.Value, 10), opts.ThousandsSeparator)
+	case FloatValue:
+		if opts.FloatPrecision < 0 && !opts.ThousandsSeparator {
+			return t.String()
+		}
+		return groupThousands(strconv.FormatFloat(t.Value, 'f', opts.FloatPrecision, 64), opts.ThousandsSeparator)
+	case DecimalValue:
+		return groupThousands(t.Value, opts.ThousandsSeparator)
+	case DateTimeValue:
+		if opts.DateTimeLayout != "" {
+			return t.Value.Format(opts.DateTimeLayout)
+		}
+		return t.String()
+	case DateTimeTzValue:
+		loc := opts.Location
+		if loc == nil {
+			loc = time.UTC
+		}
+		layout := opts.DateTimeLayout
+		if layout == "" {
+			layout = DateTimeTzLayout
+		}
+		return t.Value.In(loc).Format(layout)
+	default:
+		return v.String()
+	}
+}
+
+// groupThousands inserts commas into the integer part of a plain decimal number
+func groupThousands(number string, enabled bool) string {
+	if !enabled {
+		return number
+	}
+
+	sign := ""
+	if strings.HasPrefix(number, "-") || strings.HasPrefix(number, "+") {
+		sign, number = number[:1], number[1:]
+	}
+
+	integer, fraction := number, ""
+	if dot := strings.IndexByte(number, '.'); dot >= 0 {
+		integer, fraction = number[:dot], number[dot:]
+	}
+	// Leave anything unexpected (exponents, NaN) untouched
+	if strings.IndexFunc(integer, func(r rune) bool { return r < '0' || r > '9' }) >= 0 {
+		return sign + number
+	}
+
+	var sb strings.Builder
+	for i, digit := range integer {
+		if i > 0 && (len(integer)-i)%3 == 0 {
+			sb.WriteByte(',')
+		}
+		sb.WriteRune(digit)
+	}
+
+	return sign + sb.String() + fraction
+}
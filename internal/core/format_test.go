@@ -0,0 +1,281 @@
+package core
+
+import (
+	"strings"
+	"testing"
+	"time"
+)
+
+func TestFormatValue(t *testing.T) {
+	instant := time.Date(2024, 3, 9, 3, 30, 0, 0, time.UTC)
+	sydney := time.FixedZone("AEDT", 11*60*60)
+	two := 2
+
+	testCases := []struct {
+		name     string
+		value    Value
+		opts     FormatOptions
+		expected string
+	}{
+		{
+			name:     "Timestamp in UTC",
+			value:    DateTimeTzValue{Value: instant},
+			opts:     DefaultFormatOptions(),
+			expected: "2024-03-09 03:30:00+0000",
+		},
+		{
+			name:     "Same timestamp in another timezone",
+			value:    DateTimeTzValue{Value: instant},
+			opts:     FormatOptions{Location: sydney, FloatPrecision: -1},
+			expected: "2024-03-09 14:30:00+1100",
+		},
+		{
+			name:     "Custom datetime layout",
+			value:    DateTimeValue{Value: instant},
+			opts:     FormatOptions{DateTimeLayout: "02/01/2006 15:04", FloatPrecision: -1},
+			expected: "09/03/2024 03:30",
+		},
+		{
+			name:     "Integer with thousands separator",
+			value:    IntValue{Value: -1234567},
+			opts:     FormatOptions{ThousandsSeparator: true, FloatPrecision: -1},
+			expected: "-1,234,567",
+		},
+		{
+			name:     "Small integer unchanged",
+			value:    IntValue{Value: 999},
+			opts:     FormatOptions{ThousandsSeparator: true, FloatPrecision: -1},
+			expected: "999",
+		},
+		{
+			name:     "Float with fixed precision",
+			value:    FloatValue{Value: 3.14159},
+			opts:     FormatOptions{FloatPrecision: two},
+			expected: "3.14",
+		},
+		{
+			name:     "Float shortest form by default",
+			value:    FloatValue{Value: 1234567.89},
+			opts:     DefaultFormatOptions(),
+			expected: "1.23456789e+06",
+		},
+		{
+			name:     "Float with separator and precision",
+			value:    FloatValue{Value: 1234567.891},
+			opts:     FormatOptions{ThousandsSeparator: true, FloatPrecision: two},
+			expected: "1,234,567.89",
+		},
+		{
+			name:     "Decimal keeps its digits",
+			value:    DecimalValue{Value: "12345678901234567890.0123456789"},
+			opts:     FormatOptions{ThousandsSeparator: true, FloatPrecision: two},
+			expected: "12,345,678,901,234,567,890.0123456789",
+		},
+		{
+			name:     "NULL renders empty",
+			value:    NullValue{},
+			opts:     DefaultFormatOptions(),
+			expected: "",
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.name, func(t *testing.T) {
+			if result := FormatValue(tc.value, tc.opts); result != tc.expected {
+				t.Errorf("Expected '%s', got '%s'", tc.expected, result)
+			}
+		})
+	}
+}
+
+func TestFormatCell(t *testing.T) {
+	alphabet := StringValue{Value: "abcdefghijklmnopqrstuvwxyz"}
+
+	testCases := []struct {
+		name     string
+		value    Value
+		opts     FormatOptions
+		expected string
+	}{
+		{
+			name:     "Short value is left alone",
+			value:    StringValue{Value: "short"},
+			opts:     FormatOptions{MaxCellWidth: 20, FloatPrecision: -1},
+			expected: "short",
+		},
+		{
+			name:     "Long text notes its length",
+			value:    alphabet,
+			opts:     FormatOptions{MaxCellWidth: 20, FloatPrecision: -1},
+			expected: "abcdefgh… (26 chars)",
+		},
+		{
+			name:     "Length can be hidden",
+			value:    alphabet,
+			opts:     FormatOptions{MaxCellWidth: 10, HideTruncatedLength: true, FloatPrecision: -1},
+			expected: "abcdefghi…",
+		},
+		{
+			name:     "Narrow cell drops the length",
+			value:    alphabet,
+			opts:     FormatOptions{MaxCellWidth: 8, FloatPrecision: -1},
+			expected: "abcdefg…",
+		},
+		{
+			name:     "Binary value notes its size",
+			value:    BytesValue{Value: make([]byte, 4200)},
+			opts:     FormatOptions{MaxCellWidth: 20, FloatPrecision: -1},
+			expected: `\x00000000… (4.1 KB)`,
+		},
+		{
+			name:     "Wide characters count twice",
+			value:    StringValue{Value: "数据库连接字符串"},
+			opts:     FormatOptions{MaxCellWidth: 10, HideTruncatedLength: true, FloatPrecision: -1},
+			expected: "数据库连…",
+		},
+		{
+			name:     "Negative width never cuts",
+			value:    alphabet,
+			opts:     FormatOptions{MaxCellWidth: -1, FloatPrecision: -1},
+			expected: "abcdefghijklmnopqrstuvwxyz",
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.name, func(t *testing.T) {
+			result := FormatCell(tc.value, tc.opts)
+			if result != tc.expected {
+				t.Errorf("Expected '%s', got '%s'", tc.expected, result)
+			}
+			if tc.opts.MaxCellWidth > 0 && DisplayWidth(result) > tc.opts.MaxCellWidth {
+				t.Errorf("'%s' is %d columns wide, more than %d", result, DisplayWidth(result), tc.opts.MaxCellWidth)
+			}
+		})
+	}
+}
+
+func TestFormatCell_DefaultWidth(t *testing.T) {
+	long := StringValue{Value: strings.Repeat("x", 500)}
+	result := FormatCell(long, DefaultFormatOptions())
+	if DisplayWidth(result) != DefaultMaxCellWidth {
+		t.Errorf("Expected a cell %d columns wide, got %d", DefaultMaxCellWidth, DisplayWidth(result))
+	}
+	if !strings.HasSuffix(result, "… (500 chars)") {
+		t.Errorf("Expected the full length at the end, got '%s'", result)
+	}
+}
+
+func TestFormatSize(t *testing.T) {
+	testCases := map[int64]string{
+		0:                      "0 B",
+		512:                    "512 B",
+		4200:                   "4.1 KB",
+		3 * 1024 * 1024:        "3.0 MB",
+		5 * 1024 * 1024 * 1024: "5.0 GB",
+	}
+	for n, expected := range testCases {
+		if result := FormatSize(n); result != expected {
+			t.Errorf("FormatSize(%d): expected '%s', got '%s'", n, expected, result)
+		}
+	}
+}
+
+func TestMarkdownTable_TruncatedCellStaysAligned(t *testing.T) {
+	columns := []Column{{Name: "id"}, {Name: "body"}}
+	rows := [][]Value{
+		{IntValue{Value: 1}, StringValue{Value: strings.Repeat("长", 30)}},
+		{IntValue{Value: 2}, StringValue{Value: "short"}},
+	}
+
+	table := MarkdownTable(columns, rows, FormatOptions{MaxCellWidth: 24, FloatPrecision: -1})
+	lines := strings.Split(strings.TrimSuffix(table, "\n"), "\n")
+	if !strings.Contains(lines[2], "… (30 chars)") {
+		t.Fatalf("Expected the long cell to be cut short, got:\n%s", table)
+	}
+	for _, line := range lines[1:] {
+		if DisplayWidth(line) != DisplayWidth(lines[0]) {
+			t.Errorf("Rows are not aligned:\n%s", table)
+			break
+		}
+	}
+}
+
+func TestMarkdownTable_ColumnWidths(t *testing.T) {
+	columns := []Column{{Name: "id"}, {Name: "description"}, {Name: "note"}}
+	rows := [][]Value{
+		{IntValue{Value: 1}, StringValue{Value: strings.Repeat("x", 70)}, StringValue{Value: "short note"}},
+	}
+
+	// note is set to 6 wide; description gives up space so the table fits in 40
+	format := FormatOptions{FloatPrecision: -1, HideTruncatedLength: true, ColumnWidths: []int{0, 0, 6}, TableWidth: 40}
+	table := MarkdownTable(columns, rows, format)
+	lines := strings.Split(strings.TrimSuffix(table, "\n"), "\n")
+	for _, line := range lines {
+		if DisplayWidth(line) != 40 {
+			t.Errorf("Expected every line to be 40 wide, got %d:\n%s", DisplayWidth(line), table)
+			break
+		}
+	}
+	if !strings.Contains(lines[2], "| short… |") {
+		t.Errorf("Expected the note cut to its set width, got:\n%s", table)
+	}
+	if !strings.HasPrefix(lines[0], "| id | description") {
+		t.Errorf("Expected headers that fit to be kept whole, got:\n%s", table)
+	}
+
+	// A set width wider than the cell limit shows more of the value
+	format = FormatOptions{FloatPrecision: -1, MaxCellWidth: 20, ColumnWidths: []int{0, 70, 0}}
+	if table := MarkdownTable(columns, rows, format); !strings.Contains(table, strings.Repeat("x", 70)) {
+		t.Errorf("Expected the whole value in a 70 wide column, got:\n%s", table)
+	}
+}
+
+func TestElideLine(t *testing.T) {
+	testCases := []struct {
+		name     string
+		text     string
+		width    int
+		expected string
+	}{
+		{"Fits", "SELECT * FROM users", 50, "SELECT * FROM users"},
+		{"Cut short", "SELECT id, name, email FROM users", 20, "SELECT id, name, ..."},
+		{"Line breaks become spaces", "SELECT *\n  FROM users\r\n\tWHERE id = 1", 50, "SELECT * FROM users WHERE id = 1"},
+		{"Surrounding space dropped", "\n  SELECT 1;\n", 50, "SELECT 1;"},
+		{"Wide characters count twice", "SELECT '数据库连接字符串'", 16, "SELECT '数据..."},
+		{"Exact fit", "abcdefghij", 10, "abcdefghij"},
+		{"Empty", "", 10, ""},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.name, func(t *testing.T) {
+			result := ElideLine(tc.text, tc.width)
+			if result != tc.expected {
+				t.Errorf("Expected '%s', got '%s'", tc.expected, result)
+			}
+			if DisplayWidth(result) > tc.width {
+				t.Errorf("'%s' is wider than %d columns", result, tc.width)
+			}
+		})
+	}
+}
+
+// TestElideLine_LongLine guards against eliding costing as much as the text
+// is long: a pasted minified statement can be hundreds of kilobytes
+func TestElideLine_LongLine(t *testing.T) {
+	query := "INSERT INTO t VALUES " + strings.Repeat("(1,'abc'),", 10_000) + "(1,'abc')"
+	if len(query) < 100_000 {
+		t.Fatalf("Expected a query of at least 100 KB, got %d bytes", len(query))
+	}
+
+	start := time.Now()
+	var result string
+	for range 1000 {
+		result = ElideLine(query, 120)
+	}
+	if elapsed := time.Since(start); elapsed > 100*time.Millisecond {
+		t.Errorf("Eliding a 100 KB line 1000 times took %v, expected well under 100ms", elapsed)
+	}
+	if DisplayWidth(result) != 120 || !strings.HasSuffix(result, "...") {
+		t.Errorf("Expected a 120-column line ending in '...', got '%s'", result)
+	}
+}
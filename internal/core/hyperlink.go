@@ -0,0 +1,97 @@
+package core
+
+import (
+	"net/url"
+	"slices"
+	"strconv"
+	"strings"
+)
+
+// Hyperlinks maps the text a table shows for a URL cell, which may be cut
+// short, to the full URL, so rendered output can be made clickable
+type Hyperlinks map[string]string
+
+// LinkTarget returns the URL a value links to: text that parses as an http or
+// https URL with a host
+func LinkTarget(v Value) (string, bool) {
+	text, ok := v.(StringValue)
+	if !ok || strings.ContainsAny(text.Value, " \t\r\n") {
+		return "", false
+	}
+	u, err := url.Parse(text.Value)
+	if err != nil || u.Host == "" || (!strings.EqualFold(u.Scheme, "http") && !strings.EqualFold(u.Scheme, "https")) {
+		return "", false
+	}
+	return text.Value, true
+}
+
+// Hyperlink wraps text in an OSC 8 escape sequence pointing to target. The
+// sequence takes no terminal columns, so text keeps its width.
+func Hyperlink(target, text string) string {
+	return "\x1b]8;;" + target + "\x1b\\" + text + "\x1b]8;;\x1b\\"
+}
+
+// add records that shown links to the URL in v, if v holds one
+func (h Hyperlinks) add(shown string, v Value) {
+	if h == nil || strings.TrimSpace(shown) == "" {
+		return
+	}
+	if target, ok := LinkTarget(v); ok {
+		h[shown] = target
+	}
+}
+
+// Apply wraps every shown text in rendered in a hyperlink to its URL. It runs
+// on output already laid out, so the links change no widths. Longer texts are
+// linked first, and a text is only linked where it is not part of a longer
+// one.
+func (h Hyperlinks) Apply(rendered string) string {
+	if len(h) == 0 {
+		return rendered
+	}
+
+	shown := make([]string, 0, len(h))
+	for text := range h {
+		shown = append(shown, text)
+	}
+	slices.SortFunc(shown, func(a, b string) int { return len(b) - len(a) })
+
+	// Placeholders keep a linked text from being found again inside a longer one
+	const mark = "\x00"
+	placeholders := make([]string, 0, 2*len(shown))
+	for i, text := range shown {
+		placeholder := mark + strconv.Itoa(i) + mark
+		rendered = strings.ReplaceAll(rendered, text, placeholder)
+		placeholders = append(placeholders, placeholder, Hyperlink(h[text], text))
+	}
+	return strings.NewReplacer(placeholders...).Replace(rendered)
+}
+
+// TerminalSupportsHyperlinks guesses from the environment, read through
+// getenv, whether the terminal shows OSC 8 hyperlinks. Terminals that don't
+// understand them may print the escape sequences, so unknown terminals are
+// assumed not to.
+func TerminalSupportsHyperlinks(getenv func(string) string) bool {
+	termName := getenv("TERM")
+	if termName == "dumb" || termName == "linux" {
+		return false
+	}
+
+	switch getenv("TERM_PROGRAM") {
+	case "iTerm.app", "WezTerm", "vscode", "ghostty", "Hyper", "Tabby", "rio":
+		return true
+	}
+	if getenv("WT_SESSION") != "" || getenv("KONSOLE_VERSION") != "" || getenv("DOMTERM") != "" {
+		return true
+	}
+	if version, err := strconv.Atoi(getenv("VTE_VERSION")); err == nil && version >= 5000 {
+		return true
+	}
+	for _, name := range []string{"kitty", "alacritty", "foot", "wezterm", "ghostty", "contour"} {
+		if strings.Contains(termName, name) {
+			return true
+		}
+	}
+	colorTerm := getenv("COLORTERM")
+	return colorTerm == "truecolor" || colorTerm == "24bit"
+}
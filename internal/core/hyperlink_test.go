@@ -0,0 +1,131 @@
+package core
+
+import (
+	"bytes"
+	"strings"
+	"testing"
+)
+
+func TestLinkTarget(t *testing.T) {
+	tests := []struct {
+		name  string
+		value Value
+		want  string
+		ok    bool
+	}{
+		{"https URL", StringValue{Value: "https://example.com/a?b=1"}, "https://example.com/a?b=1", true},
+		{"http URL", StringValue{Value: "HTTP://example.com"}, "HTTP://example.com", true},
+		{"other scheme", StringValue{Value: "ftp://example.com/file"}, "", false},
+		{"no host", StringValue{Value: "https:///path"}, "", false},
+		{"plain text", StringValue{Value: "example.com"}, "", false},
+		{"text around a URL", StringValue{Value: "see https://example.com"}, "", false},
+		{"NULL", StringValue{Null: true}, "", false},
+		{"not text", IntValue{Value: 42}, "", false},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got, ok := LinkTarget(tt.value)
+			if got != tt.want || ok != tt.ok {
+				t.Errorf("Expected (%q, %v), got (%q, %v)", tt.want, tt.ok, got, ok)
+			}
+		})
+	}
+}
+
+func TestMarkdownTable_Hyperlinks(t *testing.T) {
+	invoice := "https://example.com/orders/12345/invoice"
+	columns := []Column{{Name: "id"}, {Name: "invoice"}}
+	rows := [][]Value{
+		{IntValue{Value: 1}, StringValue{Value: invoice}},
+		{IntValue{Value: 2}, StringValue{Value: "pending"}},
+	}
+
+	plain := FormatOptions{FloatPrecision: -1, MaxCellWidth: 20, HideTruncatedLength: true}
+	linked := plain
+	linked.Hyperlinks = Hyperlinks{}
+	table := MarkdownTable(columns, rows, linked)
+	if table != MarkdownTable(columns, rows, plain) {
+		t.Errorf("Expected collecting links to leave the table as it was, got:\n%s", table)
+	}
+	if strings.Contains(table, "\x1b") {
+		t.Errorf("Expected no escape sequences in the markdown, got %q", table)
+	}
+	if len(linked.Hyperlinks) != 1 || linked.Hyperlinks["https://example.com…"] != invoice {
+		t.Fatalf("Expected the cut-short cell linked to the full URL, got %v", linked.Hyperlinks)
+	}
+
+	rendered := linked.Hyperlinks.Apply(table)
+	want := "| 1  | \x1b]8;;https://example.com/orders/12345/invoice\x1b\\https://example.com…\x1b]8;;\x1b\\ |"
+	if !strings.Contains(rendered, want) {
+		t.Errorf("Expected the cell wrapped in an OSC 8 hyperlink, got %q", rendered)
+	}
+	if strings.Contains(rendered, "pending\x1b") {
+		t.Errorf("Expected text that isn't a URL left alone, got %q", rendered)
+	}
+}
+
+func TestHyperlinks_ApplyPrefersLongerText(t *testing.T) {
+	links := Hyperlinks{
+		"https://a.io":   "https://a.io",
+		"https://a.io/x": "https://a.io/x",
+	}
+	got := links.Apply("https://a.io/x and https://a.io")
+	want := Hyperlink("https://a.io/x", "https://a.io/x") + " and " + Hyperlink("https://a.io", "https://a.io")
+	if got != want {
+		t.Errorf("Expected %q, got %q", want, got)
+	}
+	if got := Hyperlinks(nil).Apply("https://a.io"); got != "https://a.io" {
+		t.Errorf("Expected no links to leave the text alone, got %q", got)
+	}
+}
+
+func TestHyperlinks_CSVExportHasNoEscapes(t *testing.T) {
+	format := FormatOptions{FloatPrecision: -1, Hyperlinks: Hyperlinks{}}
+	var buf bytes.Buffer
+	writer, err := NewRowWriter(&buf, ExportCSV, &format)
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	result := NewStaticQueryResult(
+		[]Column{{Name: "id"}, {Name: "url"}},
+		[][]Value{{IntValue{Value: 1}, StringValue{Value: "https://example.com/orders/12345/invoice"}}},
+	)
+	if _, err := StreamResult(result, writer, true, nil); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if err := writer.Close(); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+
+	if got := buf.String(); got != "id,url\n1,https://example.com/orders/12345/invoice\n" {
+		t.Errorf("Expected the URL exported as plain text, got %q", got)
+	}
+}
+
+func TestTerminalSupportsHyperlinks(t *testing.T) {
+	tests := []struct {
+		name string
+		env  map[string]string
+		want bool
+	}{
+		{"iTerm2", map[string]string{"TERM": "xterm-256color", "TERM_PROGRAM": "iTerm.app"}, true},
+		{"kitty", map[string]string{"TERM": "xterm-kitty"}, true},
+		{"GNOME Terminal", map[string]string{"TERM": "xterm-256color", "VTE_VERSION": "7200"}, true},
+		{"old VTE", map[string]string{"TERM": "xterm", "VTE_VERSION": "4802"}, false},
+		{"Windows Terminal", map[string]string{"WT_SESSION": "abc"}, true},
+		{"truecolor", map[string]string{"TERM": "xterm-256color", "COLORTERM": "truecolor"}, true},
+		{"plain xterm", map[string]string{"TERM": "xterm-256color"}, false},
+		{"dumb", map[string]string{"TERM": "dumb", "COLORTERM": "truecolor"}, false},
+		{"nothing set", map[string]string{}, false},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			getenv := func(key string) string { return tt.env[key] }
+			if got := TerminalSupportsHyperlinks(getenv); got != tt.want {
+				t.Errorf("Expected %v, got %v", tt.want, got)
+			}
+		})
+	}
+}
@@ -0,0 +1,33 @@
+package core
+
+import "strings"
+
+// IdentifierAt returns the identifier under rune offset pos of line, or just
+// before it when pos is at the end of a word, with quotes removed. A qualified
+// name such as schema.table is returned whole. It returns "" when pos is not
+// on an identifier.
+func IdentifierAt(line []rune, pos int) string {
+	inName := func(i int) bool {
+		return i >= 0 && i < len(line) && (isIdentRune(line[i]) || line[i] == '.' || line[i] == '"' || line[i] == '`')
+	}
+	if !inName(pos) {
+		pos--
+	}
+	if !inName(pos) {
+		return ""
+	}
+
+	start, end := pos, pos+1
+	for inName(start - 1) {
+		start--
+	}
+	for inName(end) {
+		end++
+	}
+
+	parts := strings.Split(strings.Trim(string(line[start:end]), "."), ".")
+	for i, part := range parts {
+		parts[i] = unquoteIdentifier(part)
+	}
+	return strings.Join(parts, ".")
+}
@@ -0,0 +1,28 @@
+package core
+
+import "testing"
+
+func TestIdentifierAt(t *testing.T) {
+	testCases := []struct {
+		name     string
+		line     string
+		pos      int
+		expected string
+	}{
+		{name: "Inside a name", line: "SELECT * FROM users WHERE id = 1", pos: 16, expected: "users"},
+		{name: "End of the line", line: "SELECT * FROM users", pos: 19, expected: "users"},
+		{name: "Just after a name", line: "SELECT * FROM users u", pos: 19, expected: "users"},
+		{name: "Quoted name without spaces", line: "SELECT * FROM `orders`", pos: 17, expected: "orders"},
+		{name: "Qualified name", line: `SELECT * FROM public."users"`, pos: 25, expected: "public.users"},
+		{name: "Between words", line: "SELECT  *", pos: 7, expected: ""},
+		{name: "Empty line", line: "", pos: 0, expected: ""},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.name, func(t *testing.T) {
+			if got := IdentifierAt([]rune(tc.line), tc.pos); got != tc.expected {
+				t.Errorf("Expected %q, got %q", tc.expected, got)
+			}
+		})
+	}
+}
@@ -0,0 +1,150 @@
+package core
+
+import (
+	"database/sql"
+	"errors"
+	"fmt"
+	"strings"
+)
+
+// ErrUnknownServerVariable is returned when the server has no variable by the requested name
+var ErrUnknownServerVariable = errors.New("unknown server variable")
+
+// ServerInfo exposes details about the database server behind a connection
+type ServerInfo interface {
+	ServerVersion() (string, error)
+	// ServerVariable reads one session setting, e.g. time_zone on MySQL or TimeZone on PostgreSQL
+	ServerVariable(name string) (string, error)
+	SessionDetails() (SessionDetails, error)
+}
+
+// SessionDetails describes the server session behind a connection. Fields the
+// backend has no notion of are left empty.
+type SessionDetails struct {
+	User     string
+	Database string
+	// Schema and SearchPath are only reported by PostgreSQL
+	Schema     string
+	SearchPath string
+	TimeZone   string
+	// Autocommit is "on" or "off"
+	Autocommit string
+	// SSL is "on" or "off", or empty when the server does not say
+	SSL string
+}
+
+func (c *SQLConnection) ServerVersion() (string, error) {
+	var query string
+	switch c.config.DatabaseType {
+	case MySQL, PostgreSQL:
+		query = "SELECT version()"
+	case SQLite:
+		query = "SELECT sqlite_version()"
+	default:
+		return "", fmt.Errorf("unsupported database type: %v", c.config.DatabaseType)
+	}
+
+	var version string
+	if err := c.db.QueryRow(query).Scan(&version); err != nil {
+		return "", fmt.Errorf("failed to get server version: %w", err)
+	}
+	return version, nil
+}
+
+func (c *SQLConnection) ServerVariable(name string) (string, error) {
+	if !isVariableName(name) {
+		return "", fmt.Errorf("%w: %s", ErrUnknownServerVariable, name)
+	}
+
+	var query string
+	var args []any
+	switch c.config.DatabaseType {
+	case MySQL:
+		query = "SELECT @@SESSION." + name
+	case PostgreSQL:
+		// missing_ok makes an unknown name come back as NULL rather than an error
+		query, args = "SELECT current_setting($1, true)", []any{name}
+	case SQLite:
+		query = "PRAGMA " + name
+	default:
+		return "", fmt.Errorf("unsupported database type: %v", c.config.DatabaseType)
+	}
+
+	var value *string
+	if err := c.db.QueryRow(query, args...).Scan(&value); err != nil {
+		if unknownVariable(err) {
+			return "", fmt.Errorf("%w: %s", ErrUnknownServerVariable, name)
+		}
+		return "", fmt.Errorf("failed to read server variable %s: %w", name, err)
+	}
+	if value == nil {
+		return "", fmt.Errorf("%w: %s", ErrUnknownServerVariable, name)
+	}
+	return *value, nil
+}
+
+func (c *SQLConnection) SessionDetails() (SessionDetails, error) {
+	var details SessionDetails
+	switch c.config.DatabaseType {
+	case MySQL:
+		var autocommit int
+		err := c.db.QueryRow("SELECT CURRENT_USER(), COALESCE(DATABASE(), ''), @@SESSION.time_zone, @@SESSION.autocommit").
+			Scan(&details.User, &details.Database, &details.TimeZone, &autocommit)
+		if err != nil {
+			return SessionDetails{}, fmt.Errorf("failed to read session details: %w", err)
+		}
+		details.Autocommit = onOff(autocommit != 0)
+
+		// Ssl_cipher is empty on a plain connection
+		var name, cipher string
+		if err := c.db.QueryRow("SHOW SESSION STATUS LIKE 'Ssl_cipher'").Scan(&name, &cipher); err == nil {
+			details.SSL = onOff(cipher != "")
+		}
+	case PostgreSQL:
+		err := c.db.QueryRow("SELECT current_user, current_database(), COALESCE(current_schema(), ''), current_setting('search_path'), current_setting('TimeZone')").
+			Scan(&details.User, &details.Database, &details.Schema, &details.SearchPath, &details.TimeZone)
+		if err != nil {
+			return SessionDetails{}, fmt.Errorf("failed to read session details: %w", err)
+		}
+		// PostgreSQL has no autocommit switch; statements outside BEGIN commit on their own
+		details.Autocommit = onOff(true)
+
+		// pg_stat_ssl exists from 9.5; older servers simply do not say
+		var ssl bool
+		if err := c.db.QueryRow("SELECT ssl FROM pg_stat_ssl WHERE pid = pg_backend_pid()").Scan(&ssl); err == nil {
+			details.SSL = onOff(ssl)
+		}
+	case SQLite:
+		details.Database = c.config.Database
+		// date('now') and friends always work in UTC
+		details.TimeZone = "UTC"
+		details.Autocommit = onOff(true)
+	default:
+		return SessionDetails{}, fmt.Errorf("unsupported database type: %v", c.config.DatabaseType)
+	}
+	return details, nil
+}
+
+// unknownVariable reports whether err is the server rejecting a variable name:
+// MySQL error 1193, or SQLite returning nothing for an unknown PRAGMA
+func unknownVariable(err error) bool {
+	if errors.Is(err, sql.ErrNoRows) {
+		return true
+	}
+	code, ok := serverErrorCode(err)
+	return ok && code == "1193"
+}
+
+// isVariableName accepts names such as time_zone or TimeZone, which are safe to splice into a query
+func isVariableName(name string) bool {
+	return name != "" && strings.IndexFunc(name, func(r rune) bool {
+		return r != '_' && (r < 'a' || r > 'z') && (r < 'A' || r > 'Z') && (r < '0' || r > '9')
+	}) < 0
+}
+
+func onOff(on bool) string {
+	if on {
+		return "on"
+	}
+	return "off"
+}
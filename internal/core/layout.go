@@ -0,0 +1,113 @@
+package core
+
+import (
+	"crypto/sha256"
+	"encoding/hex"
+	"strings"
+)
+
+// ColumnLayout lists the columns of a result to show, by index, in display
+// order. A nil layout shows every column in query order.
+type ColumnLayout []int
+
+// ColumnSignature identifies a result by its column names, so a layout chosen
+// for one run of a query applies to the next
+func ColumnSignature(columns []Column) string {
+	names := make([]string, len(columns))
+	for i, column := range columns {
+		names[i] = column.Name
+	}
+	sum := sha256.Sum256([]byte(strings.Join(names, "\x00")))
+	return hex.EncodeToString(sum[:8])
+}
+
+// Valid reports whether the layout fits a result of n columns: at least one
+// column, each index in range and none repeated
+func (l ColumnLayout) Valid(n int) bool {
+	if len(l) == 0 {
+		return false
+	}
+	seen := make(map[int]bool, len(l))
+	for _, i := range l {
+		if i < 0 || i >= n || seen[i] {
+			return false
+		}
+		seen[i] = true
+	}
+	return true
+}
+
+// Apply returns the columns and rows in layout order, leaving hidden columns
+// out. The rows passed in are not changed.
+func (l ColumnLayout) Apply(columns []Column, rows [][]Value) ([]Column, [][]Value) {
+	if l == nil {
+		return columns, rows
+	}
+
+	visible := make([]Column, len(l))
+	for i, index := range l {
+		visible[i] = columns[index]
+	}
+	reordered := make([][]Value, len(rows))
+	for r, row := range rows {
+		reordered[r] = make([]Value, len(l))
+		for i, index := range l {
+			if index < len(row) {
+				reordered[r][i] = row[index]
+			}
+		}
+	}
+	return visible, reordered
+}
+
+// MinColumnWidth is the narrowest a table column is made to fit, enough for
+// a character and the truncation mark
+const MinColumnWidth = 3
+
+// FitColumnWidths decides how wide each column of a table is. natural is the
+// width each column's header and values need, and a positive manual width is
+// used as it is. When total is positive and the table with its borders would
+// be wider than total terminal columns, the widest auto-sized columns are
+// narrowed, down to MinColumnWidth, until it fits. Manual widths are never
+// narrowed, so a table of them alone may stay wider.
+func FitColumnWidths(natural, manual []int, total int) []int {
+	widths := make([]int, len(natural))
+	used := tableBorderWidth(len(natural))
+	var auto []int
+	for i, width := range natural {
+		if i < len(manual) && manual[i] > 0 {
+			widths[i] = manual[i]
+		} else {
+			widths[i] = width
+			auto = append(auto, i)
+		}
+		used += widths[i]
+	}
+
+	excess := used - total
+	if total <= 0 || excess <= 0 {
+		return widths
+	}
+	// Lower a cap over the auto-sized columns one step at a time, so the
+	// widest give up space first and narrow ones keep theirs
+	limit := 0
+	for _, i := range auto {
+		limit = max(limit, widths[i])
+	}
+	for excess > 0 && limit > MinColumnWidth {
+		limit--
+		for _, i := range auto {
+			if excess > 0 && widths[i] > limit {
+				widths[i]--
+				excess--
+			}
+		}
+	}
+	return widths
+}
+
+// tableBorderWidth is how many terminal columns the borders and padding of a
+// markdown table of n columns take: "| " before, " | " between and " |" after
+func tableBorderWidth(n int) int {
+	return 3*n + 1
+}
@@ -0,0 +1,85 @@
+package core
+
+import (
+	"slices"
+	"testing"
+)
+
+func TestColumnLayout_Apply(t *testing.T) {
+	columns := []Column{{Name: "id"}, {Name: "payload"}, {Name: "created_at"}}
+	rows := [][]Value{{IntValue{Value: 1}, StringValue{Value: "{}"}, StringValue{Value: "today"}}}
+
+	visible, reordered := ColumnLayout{2, 0}.Apply(columns, rows)
+	if len(visible) != 2 || visible[0].Name != "created_at" || visible[1].Name != "id" {
+		t.Errorf("Expected created_at then id, got %+v", visible)
+	}
+	if reordered[0][0].String() != "today" || reordered[0][1].String() != "1" {
+		t.Errorf("Expected the row values to follow the layout, got %v", reordered[0])
+	}
+	if rows[0][0].String() != "1" || len(rows[0]) != 3 {
+		t.Error("Expected the original rows to stay unchanged")
+	}
+
+	if visible, _ := ColumnLayout(nil).Apply(columns, rows); len(visible) != 3 {
+		t.Errorf("Expected a nil layout to show every column, got %+v", visible)
+	}
+}
+
+func TestColumnLayout_Valid(t *testing.T) {
+	tests := []struct {
+		name   string
+		layout ColumnLayout
+		want   bool
+	}{
+		{"reordered subset", ColumnLayout{2, 0}, true},
+		{"empty", ColumnLayout{}, false},
+		{"out of range", ColumnLayout{0, 3}, false},
+		{"repeated", ColumnLayout{1, 1}, false},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := tt.layout.Valid(3); got != tt.want {
+				t.Errorf("Valid(3) = %v, want %v", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestColumnSignature(t *testing.T) {
+	a := ColumnSignature([]Column{{Name: "id"}, {Name: "name"}})
+	if a != ColumnSignature([]Column{{Name: "id", Type: "INT"}, {Name: "name"}}) {
+		t.Error("Expected the signature to depend only on column names")
+	}
+	if a == ColumnSignature([]Column{{Name: "name"}, {Name: "id"}}) {
+		t.Error("Expected column order to change the signature")
+	}
+	if ColumnSignature([]Column{{Name: "a,b"}}) == ColumnSignature([]Column{{Name: "a"}, {Name: "b"}}) {
+		t.Error("Expected names not to run together")
+	}
+}
+
+func TestFitColumnWidths(t *testing.T) {
+	tests := []struct {
+		name    string
+		natural []int
+		manual  []int
+		total   int
+		want    []int
+	}{
+		{"no manual widths, no limit", []int{4, 30, 10}, nil, 0, []int{4, 30, 10}},
+		{"manual widths kept without a limit", []int{4, 30, 10}, []int{0, 12, 40}, 0, []int{4, 12, 40}},
+		// Borders take 3*3+1 = 10, so 60 leaves 50 for the columns
+		{"fits already", []int{4, 30, 10}, []int{0, 0, 16}, 60, []int{4, 30, 16}},
+		{"widest auto column narrowed first", []int{4, 80, 10}, []int{0, 0, 20}, 60, []int{4, 26, 20}},
+		{"auto columns narrowed to the same cap", []int{40, 40, 4}, []int{0, 0, 0}, 50, []int{18, 18, 4}},
+		{"manual width never narrowed", []int{4, 80, 10}, []int{0, 0, 100}, 60, []int{3, 3, 100}},
+		{"narrow columns keep their width", []int{2, 80, 10}, nil, 40, []int{2, 18, 10}},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := FitColumnWidths(tt.natural, tt.manual, tt.total); !slices.Equal(got, tt.want) {
+				t.Errorf("FitColumnWidths(%v, %v, %d) = %v, want %v", tt.natural, tt.manual, tt.total, got, tt.want)
+			}
+		})
+	}
+}
@@ -0,0 +1,208 @@
+package core
+
+import (
+	"strings"
+	"unicode"
+	"unicode/utf8"
+)
+
+// sqlTokens splits query into words, quoted identifiers and single punctuation
+// characters. String literals, dollar-quoted ones included, become ? and
+// comments are dropped. dbType
+// decides which strings a backslash escapes in, see stringSpan.
+func sqlTokens(dbType DatabaseType, query string) []string {
+	spans := sqlTokenSpans(dbType, query)
+	tokens := make([]string, len(spans))
+	for i, span := range spans {
+		tokens[i] = span.text
+	}
+	return tokens
+}
+
+// tokenSpan is a token of sqlTokens and the bytes of the query it came from
+type tokenSpan struct {
+	text       string
+	start, end int
+}
+
+// sqlTokenSpans is sqlTokens with where each token is in query
+func sqlTokenSpans(dbType DatabaseType, query string) []tokenSpan {
+	var tokens []tokenSpan
+	for i := 0; i < len(query); {
+		r, size := utf8.DecodeRuneInString(query[i:])
+		rest := query[i:]
+
+		switch {
+		case unicode.IsSpace(r):
+			i += size
+		case lineComment(dbType, rest):
+			end := strings.IndexByte(rest, '\n')
+			if end < 0 {
+				return tokens
+			}
+			i += end + 1
+		case strings.HasPrefix(rest, "/*"):
+			end := strings.Index(rest[2:], "*/")
+			if end < 0 {
+				return tokens
+			}
+			i += end + 4
+		case r == '\'' || isStringPrefix(rest):
+			end, _ := stringSpan(dbType, rest)
+			tokens = append(tokens, tokenSpan{RedactedLiteral, i, i + end})
+			i += end
+		case r == '"' || r == '`':
+			end := quotedEnd(rest, byte(r), false)
+			tokens = append(tokens, tokenSpan{rest[:end], i, i + end})
+			i += end
+		case r == '$' && !endsWithIdentRune(query[:i]):
+			tag, ok := dollarTag(rest)
+			if !ok {
+				tokens = append(tokens, tokenSpan{"$", i, i + 1})
+				i++
+				continue
+			}
+			end := len(rest)
+			if n := strings.Index(rest[len(tag):], tag); n >= 0 {
+				end = len(tag) + n + len(tag)
+			}
+			tokens = append(tokens, tokenSpan{RedactedLiteral, i, i + end})
+			i += end
+		case isIdentRune(r):
+			end := size
+			for end < len(rest) {
+				next, nextSize := utf8.DecodeRuneInString(rest[end:])
+				if !isIdentRune(next) && next != '.' {
+					break
+				}
+				end += nextSize
+			}
+			tokens = append(tokens, tokenSpan{rest[:end], i, i + end})
+			i += end
+		default:
+			tokens = append(tokens, tokenSpan{string(r), i, i + size})
+			i += size
+		}
+	}
+	return tokens
+}
+
+// lineComment reports whether s starts a comment that runs to the end of the
+// line: -- on every database, and # on MySQL, where PostgreSQL reads # as an
+// operator
+func lineComment(dbType DatabaseType, s string) bool {
+	return strings.HasPrefix(s, "--") || dbType == MySQL && strings.HasPrefix(s, "#")
+}
+
+// stringSpan returns the length of the string literal at the start of s,
+// including its quotes and any E, N, X or B prefix, and whether it is closed.
+// A backslash escapes the next character on MySQL and in PostgreSQL's E''
+// strings. Elsewhere, as in standard PostgreSQL strings and on SQLite, it is an
+// ordinary character, so 'C:\' ends at its second quote.
+func stringSpan(dbType DatabaseType, s string) (int, bool) {
+	prefix := 0
+	if s[0] != '\'' {
+		prefix = 1
+	}
+	backslash := dbType == MySQL || s[0] == 'E' || s[0] == 'e'
+	end, closed := quotedSpan(s[prefix:], '\'', backslash)
+	return prefix + end, closed
+}
+
+// stringEnd is stringSpan without whether the string is closed; an
+// unterminated string runs to the end of s
+func stringEnd(dbType DatabaseType, s string) int {
+	end, _ := stringSpan(dbType, s)
+	return end
+}
+
+// quotedEnd returns the length of the quoted section at the start of s, including
+// both quotes. A doubled quote is an escaped quote; with backslash set, a
+// backslash escapes the next character. An unterminated quote runs to the end of s.
+func quotedEnd(s string, quote byte, backslash bool) int {
+	end, _ := quotedSpan(s, quote, backslash)
+	return end
+}
+
+// quotedSpan is quotedEnd that also reports whether the closing quote was found
+func quotedSpan(s string, quote byte, backslash bool) (int, bool) {
+	for i := 1; i < len(s); i++ {
+		switch s[i] {
+		case '\\':
+			if backslash {
+				i++
+			}
+		case quote:
+			if i+1 < len(s) && s[i+1] == quote {
+				i++
+				continue
+			}
+			return i + 1, true
+		}
+	}
+	return len(s), false
+}
+
+// isStringPrefix reports whether s starts with a prefixed string literal such
+// as E'', N'', X'' or B''
+func isStringPrefix(s string) bool {
+	if len(s) < 2 || s[1] != '\'' {
+		return false
+	}
+	switch s[0] {
+	case 'E', 'e', 'N', 'n', 'X', 'x', 'B', 'b':
+		return true
+	}
+	return false
+}
+
+// dollarTag returns the opening $tag$ of a dollar-quoted string at the start of s
+func dollarTag(s string) (string, bool) {
+	for i := 1; i < len(s); i++ {
+		r, size := utf8.DecodeRuneInString(s[i:])
+		if r == '$' {
+			return s[:i+1], true
+		}
+		if !(unicode.IsLetter(r) || r == '_' || i > 1 && unicode.IsDigit(r)) {
+			return "", false
+		}
+		i += size - 1
+	}
+	return "", false
+}
+
+// isIdentRune reports whether r can be part of an unquoted identifier or keyword
+func isIdentRune(r rune) bool {
+	return r == '_' || unicode.IsLetter(r) || unicode.IsDigit(r)
+}
+
+// endsWithIdentRune reports whether s ends inside an identifier, so a following
+// $ or : belongs to it rather than starting a quote or parameter
+func endsWithIdentRune(s string) bool {
+	r, _ := utf8.DecodeLastRuneInString(s)
+	return r != utf8.RuneError && isIdentRune(r)
+}
+
+// stripLeadingComments removes the comments and whitespace before the first
+// token of statement, # comments included on MySQL
+func stripLeadingComments(dbType DatabaseType, statement string) string {
+	for {
+		statement = strings.TrimSpace(statement)
+		switch {
+		case lineComment(dbType, statement):
+			end := strings.Index(statement, "\n")
+			if end < 0 {
+				return ""
+			}
+			statement = statement[end+1:]
+		case strings.HasPrefix(statement, "/*"):
+			end := strings.Index(statement, "*/")
+			if end < 0 {
+				return ""
+			}
+			statement = statement[end+2:]
+		default:
+			return statement
+		}
+	}
+}
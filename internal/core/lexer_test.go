@@ -0,0 +1,152 @@
+package core
+
+import (
+	"reflect"
+	"testing"
+)
+
+func TestSQLTokens(t *testing.T) {
+	testCases := []struct {
+		name     string
+		query    string
+		expected []string
+	}{
+		{
+			name:     "Words and punctuation",
+			query:    "SELECT a, b.c FROM t WHERE (x >= 1)",
+			expected: []string{"SELECT", "a", ",", "b.c", "FROM", "t", "WHERE", "(", "x", ">", "=", "1", ")"},
+		},
+		{
+			name:     "Strings become ?",
+			query:    "SELECT 'a;b', 'it''s' FROM t",
+			expected: []string{"SELECT", "?", ",", "?", "FROM", "t"},
+		},
+		{
+			name:     "Dollar quotes become ?",
+			query:    "SELECT $$a'b$$, $t$x$t$, $1",
+			expected: []string{"SELECT", "?", ",", "?", ",", "$", "1"},
+		},
+		{
+			name:     "Quoted identifiers are kept",
+			query:    "SELECT \"my col\", `other` FROM t",
+			expected: []string{"SELECT", "\"my col\"", ",", "`other`", "FROM", "t"},
+		},
+		{
+			name:     "Comments are dropped",
+			query:    "SELECT /* DELETE */ 1 -- DROP\nFROM t",
+			expected: []string{"SELECT", "1", "FROM", "t"},
+		},
+		{
+			name:     "MySQL # comments are dropped",
+			query:    "SELECT 1 # don't DELETE\nFROM t",
+			expected: []string{"SELECT", "1", "FROM", "t"},
+		},
+		{
+			name:     "Keywords stuck to punctuation",
+			query:    "WITH d AS(DELETE FROM t)",
+			expected: []string{"WITH", "d", "AS", "(", "DELETE", "FROM", "t", ")"},
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.name, func(t *testing.T) {
+			if got := sqlTokens(MySQL, tc.query); !reflect.DeepEqual(got, tc.expected) {
+				t.Errorf("Expected %q, got %q", tc.expected, got)
+			}
+		})
+	}
+}
+
+func TestSQLTokenSpans(t *testing.T) {
+	query := "SELECT 'x' FROM t"
+	spans := sqlTokenSpans(MySQL, query)
+	if len(spans) != 4 {
+		t.Fatalf("Expected 4 tokens, got %v", spans)
+	}
+	if literal := spans[1]; literal.text != RedactedLiteral || query[literal.start:literal.end] != "'x'" {
+		t.Errorf("Expected the literal's span to cover it, got %+v", literal)
+	}
+}
+
+func TestQuotedSpan(t *testing.T) {
+	testCases := []struct {
+		name      string
+		s         string
+		backslash bool
+		end       int
+		closed    bool
+	}{
+		{"Simple", "'abc' x", false, 5, true},
+		{"Doubled quote", "'it''s' x", false, 7, true},
+		{"Backslash escape", `'a\'b' x`, true, 6, true},
+		{"Backslash kept literally", `'a\' x`, false, 4, true},
+		{"Unterminated", "'abc", false, 4, false},
+		{"Identifier", "\"a\"\"b\" x", false, 6, true},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.name, func(t *testing.T) {
+			end, closed := quotedSpan(tc.s, tc.s[0], tc.backslash)
+			if end != tc.end || closed != tc.closed {
+				t.Errorf("Expected %d, %v, got %d, %v", tc.end, tc.closed, end, closed)
+			}
+		})
+	}
+}
+
+func TestStringSpan(t *testing.T) {
+	testCases := []struct {
+		dbType DatabaseType
+		s      string
+		end    int
+		closed bool
+	}{
+		{PostgreSQL, `'C:\' x`, 5, true},
+		{SQLite, `'C:\' x`, 5, true},
+		{MySQL, `'C:\' x`, 7, false},
+		{MySQL, `'C:\\' x`, 6, true},
+		{PostgreSQL, `E'it\'s' x`, 8, true},
+		{PostgreSQL, `N'ab' x`, 5, true},
+	}
+
+	for _, tc := range testCases {
+		end, closed := stringSpan(tc.dbType, tc.s)
+		if end != tc.end || closed != tc.closed {
+			t.Errorf("stringSpan(%s, %q) = %d, %v, expected %d, %v", tc.dbType, tc.s, end, closed, tc.end, tc.closed)
+		}
+	}
+}
+
+func TestDollarTag(t *testing.T) {
+	for s, expected := range map[string]string{"$$ body $$": "$$", "$fn$ body $fn$": "$fn$", "$_1$x": "$_1$"} {
+		if tag, ok := dollarTag(s); !ok || tag != expected {
+			t.Errorf("Expected %q in %q, got %q, %v", expected, s, tag, ok)
+		}
+	}
+	for _, s := range []string{"$1", "$", "$a b$"} {
+		if tag, ok := dollarTag(s); ok {
+			t.Errorf("Expected no tag in %q, got %q", s, tag)
+		}
+	}
+}
+
+func TestStripLeadingComments(t *testing.T) {
+	testCases := map[string]string{
+		"  SELECT 1":                     "SELECT 1",
+		"-- note\nSELECT 1":              "SELECT 1",
+		"/* a */ -- b\n /* c */SELECT 1": "SELECT 1",
+		"-- only a comment":              "",
+		"/* unterminated":                "",
+	}
+	for statement, expected := range testCases {
+		if got := stripLeadingComments(MySQL, statement); got != expected {
+			t.Errorf("%q: expected %q, got %q", statement, expected, got)
+		}
+	}
+	if got := stripLeadingComments(MySQL, "# note\nSELECT 1"); got != "SELECT 1" {
+		t.Errorf("Expected MySQL's # comment to be stripped, got %q", got)
+	}
+	if got := stripLeadingComments(PostgreSQL, "#> '{a}'"); got != "#> '{a}'" {
+		t.Errorf("Expected # to stay on PostgreSQL, got %q", got)
+	}
+}
@@ -0,0 +1,34 @@
+package core
+
+import "fmt"
+
+// RowLookup selects the rows of Table whose Column equals Value, such as the
+// row a foreign key value refers to
+type RowLookup struct {
+	Table  string
+	Column string
+	Value  Value
+}
+
+// ForeignKeyLookup returns the lookup of the row that value in fk's column refers to
+func ForeignKeyLookup(fk ForeignKeyInfo, value Value) RowLookup {
+	return RowLookup{Table: fk.ReferencedTable, Column: fk.ReferencedColumn, Value: value}
+}
+
+// Statement returns the SELECT with a bind parameter for dbType and the value to bind
+func (l RowLookup) Statement(dbType DatabaseType) (string, []any) {
+	placeholder := "?"
+	if dbType == PostgreSQL {
+		placeholder = "$1"
+	}
+	return l.build(dbType, placeholder), []any{sqlArg(l.Value)}
+}
+
+// Preview returns the SELECT with the value written as a literal, for display
+func (l RowLookup) Preview(dbType DatabaseType) string {
+	return l.build(dbType, sqlLiteral(dbType, sqlArg(l.Value)))
+}
+
+func (l RowLookup) build(dbType DatabaseType, value string) string {
+	return fmt.Sprintf("SELECT * FROM %s WHERE %s = %s", QuoteIdentifier(dbType, l.Table), QuoteIdentifier(dbType, l.Column), value)
+}
@@ -15,6 +15,7 @@ type MarkdownRenderer struct {
 	width   int
 	height  int
 	i18nMgr *i18n.Manager
+	links   Hyperlinks
 }
 
 // NewMarkdownRenderer creates a new markdown renderer with terminal dimensions
@@ -34,6 +35,12 @@ func NewMarkdownRenderer(i18nMgr *i18n.Manager) *MarkdownRenderer {
 	}
 }
 
+// WithHyperlinks makes the rendered output link the shown texts in links to their URLs
+func (mr *MarkdownRenderer) WithHyperlinks(links Hyperlinks) *MarkdownRenderer {
+	mr.links = links
+	return mr
+}
+
 // RenderAndDisplay renders markdown content and displays it with consistent formatting
 func (mr *MarkdownRenderer) RenderAndDisplay(markdown string) error {
 	// Create a glamour renderer
@@ -70,7 +77,7 @@ func (mr *MarkdownRenderer) displayWithFormatting(content string) {
 	fmt.Println(strings.Repeat("─", min(mr.width, 80)))
 
 	// Display the rendered markdown
-	fmt.Print(content)
+	fmt.Print(mr.links.Apply(content))
 
 	// Print a footer
 	fmt.Println(strings.Repeat("─", min(mr.width, 80)))
@@ -0,0 +1,76 @@
+package core
+
+import (
+	"context"
+	"database/sql"
+	"strings"
+
+	"sqlterm/internal/i18n"
+)
+
+// maxNotices bounds how many warnings and notices are kept for one statement
+const maxNotices = 100
+
+// Notice is a warning or message the server sent about a statement that did
+// not fail, e.g. a MySQL truncation warning or a PostgreSQL RAISE NOTICE
+type Notice struct {
+	// Level is the server's severity, e.g. "Warning", "Note" or "NOTICE"
+	Level string `yaml:"level" json:"level"`
+	// Code is the server's code for the message, empty when it has none
+	Code    string `yaml:"code,omitempty" json:"code,omitempty"`
+	Message string `yaml:"message" json:"message"`
+}
+
+// String renders the notice on one line, e.g. "Warning 1265: Data truncated for column 'code' at row 1"
+func (n Notice) String() string {
+	if n.Code == "" {
+		return n.Level + ": " + n.Message
+	}
+	return n.Level + " " + n.Code + ": " + n.Message
+}
+
+// noticeCollector starts collecting the warnings and notices the server sends
+// about the next statement on conn. The returned stop is called once the
+// statement's rows are closed and returns what was collected.
+type noticeCollector func(ctx context.Context, conn *sql.Conn) (stop func() []Notice)
+
+// setNoticeCollector makes statements on dbType report what the server sends
+// about them through QueryResult.Notices. With optIn, only connections with
+// show_warnings set collect them.
+func setNoticeCollector(dbType DatabaseType, collect noticeCollector, optIn bool) {
+	registry.Lock()
+	defer registry.Unlock()
+
+	registry.backends[dbType].notices = collect
+	registry.backends[dbType].noticesOptIn = optIn
+}
+
+// AddNotices records warnings or notices about the statement, keeping at most
+// maxNotices of them
+func (r *QueryResult) AddNotices(notices ...Notice) {
+	r.notices = append(r.notices, notices[:min(len(notices), max(maxNotices-len(r.notices), 0))]...)
+}
+
+// Notices returns the warnings and notices the server sent about the
+// statement. They are complete once the result is closed.
+func (r *QueryResult) Notices() []Notice {
+	return r.notices
+}
+
+// NoticesMarkdown renders notices as a quote under a result, one per line, so
+// they stand apart from the rows; empty when there are none
+func NoticesMarkdown(notices []Notice, i18nMgr *i18n.Manager) string {
+	if len(notices) == 0 {
+		return ""
+	}
+	var sb strings.Builder
+	sb.WriteString("\n")
+	for i, notice := range notices {
+		if i > 0 {
+			// A blank quoted line keeps each notice its own paragraph
+			sb.WriteString(">\n")
+		}
+		sb.WriteString(i18nMgr.GetWithArgs("markdown_notice", strings.Join(strings.Fields(notice.String()), " ")) + "\n")
+	}
+	return sb.String()
+}
@@ -0,0 +1,25 @@
+//go:build !no_postgres
+
+package core
+
+import (
+	"testing"
+
+	"github.com/lib/pq"
+)
+
+func TestPostgresNotice(t *testing.T) {
+	tests := []struct {
+		notice   pq.Error
+		expected Notice
+	}{
+		{pq.Error{Severity: "NOTICE", Code: "00000", Message: "hello 42"}, Notice{Level: "NOTICE", Message: "hello 42"}},
+		{pq.Error{Severity: "WARNING", Code: "01000", Message: "careful", Detail: "the table is large"},
+			Notice{Level: "WARNING", Code: "01000", Message: "careful (the table is large)"}},
+	}
+	for _, tt := range tests {
+		if got := postgresNotice(&tt.notice); got != tt.expected {
+			t.Errorf("Expected %+v, got %+v", tt.expected, got)
+		}
+	}
+}
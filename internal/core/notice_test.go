@@ -0,0 +1,76 @@
+package core
+
+import (
+	"strings"
+	"testing"
+
+	"sqlterm/internal/i18n"
+)
+
+func TestNotice_String(t *testing.T) {
+	if got := (Notice{Level: "Warning", Code: "1265", Message: "Data truncated for column 'code' at row 1"}).String(); got != "Warning 1265: Data truncated for column 'code' at row 1" {
+		t.Errorf("Unexpected notice line %q", got)
+	}
+	if got := (Notice{Level: "NOTICE", Message: "hello 42"}).String(); got != "NOTICE: hello 42" {
+		t.Errorf("Unexpected notice line %q", got)
+	}
+}
+
+func TestStoreResult_Notices(t *testing.T) {
+	result := NewStaticQueryResult(nil, nil)
+	result.AddNotices(Notice{Level: "Warning", Code: "1265", Message: "Data truncated"})
+	stored, err := StoreResult(result, 10)
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if len(stored.Notices) != 1 || stored.Notices[0].Code != "1265" {
+		t.Errorf("Expected the notice kept with the rows, got %+v", stored.Notices)
+	}
+
+	// A closed result reports what the connection collected while closing it
+	result = NewStaticQueryResult(nil, nil)
+	result.OnClose(func(int, error) {
+		result.AddNotices(Notice{Level: "NOTICE", Message: "done"})
+	})
+	if stored, _ := StoreResult(result, 10); len(stored.Notices) != 1 {
+		t.Errorf("Expected the notice collected on close, got %+v", stored.Notices)
+	}
+}
+
+func TestQueryResult_AddNotices_Bounded(t *testing.T) {
+	result := NewStaticQueryResult(nil, nil)
+	for range maxNotices + 5 {
+		result.AddNotices(Notice{Level: "Warning", Message: "again"}, Notice{Level: "Note", Message: "and again"})
+	}
+	if got := len(result.Notices()); got != maxNotices {
+		t.Errorf("Expected at most %d notices, got %d", maxNotices, got)
+	}
+}
+
+func TestNoticesMarkdown(t *testing.T) {
+	i18nMgr, err := i18n.NewManager("en_au")
+	if err != nil {
+		t.Fatalf("Failed to create i18n manager: %v", err)
+	}
+	if got := NoticesMarkdown(nil, i18nMgr); got != "" {
+		t.Errorf("Expected nothing without notices, got %q", got)
+	}
+
+	got := NoticesMarkdown([]Notice{
+		{Level: "Warning", Code: "1265", Message: "Data truncated"},
+		{Level: "NOTICE", Message: "line one\nline two"},
+	}, i18nMgr)
+	expected := "\n> ⚠️ Warning 1265: Data truncated\n>\n> ⚠️ NOTICE: line one line two\n"
+	if got != expected {
+		t.Errorf("Expected %q, got %q", expected, got)
+	}
+
+	stored := &StoredResult{Columns: []Column{{Name: "id"}}, Notices: []Notice{{Level: "Warning", Message: "Data truncated"}}}
+	var sb strings.Builder
+	if err := SaveResultPageAsMarkdown(stored, nil, "INSERT INTO t VALUES (1)", &sb, FormatOptions{RowLimit: 10}, i18nMgr); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if !strings.Contains(sb.String(), "> ⚠️ Warning: Data truncated") {
+		t.Errorf("Expected the notice under the result, got:\n%s", sb.String())
+	}
+}
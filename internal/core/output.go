@@ -0,0 +1,146 @@
+package core
+
+import (
+	"encoding/json"
+	"fmt"
+	"io"
+)
+
+// OutputMode selects what a command writes besides errors
+type OutputMode int
+
+const (
+	// OutputText writes human-readable data and status lines
+	OutputText OutputMode = iota
+	// OutputQuiet writes data only
+	OutputQuiet
+	// OutputJSON writes data as JSON documents only
+	OutputJSON
+)
+
+// OutputSink keeps a command's data apart from its status lines, so scripts
+// can read stdout without the progress messages meant for people. Errors are
+// reported by the caller on Err in every mode.
+type OutputSink struct {
+	Mode OutputMode
+	Out  io.Writer
+	Err  io.Writer
+}
+
+// JSON reports whether data should be written with Document
+func (s *OutputSink) JSON() bool {
+	return s.Mode == OutputJSON
+}
+
+// Statusf writes a status line, which only text mode shows
+func (s *OutputSink) Statusf(format string, args ...any) {
+	if s.Mode == OutputText {
+		fmt.Fprintf(s.Out, format, args...)
+	}
+}
+
+// Statusln writes msg as a status line, which only text mode shows
+func (s *OutputSink) Statusln(msg string) {
+	if s.Mode == OutputText {
+		fmt.Fprintln(s.Out, msg)
+	}
+}
+
+// Dataf writes human-readable data, which JSON mode replaces with Document
+func (s *OutputSink) Dataf(format string, args ...any) {
+	if s.Mode != OutputJSON {
+		fmt.Fprintf(s.Out, format, args...)
+	}
+}
+
+// Document writes v as one line of JSON. Called once per item it produces
+// newline-delimited JSON.
+func (s *OutputSink) Document(v any) error {
+	return json.NewEncoder(s.Out).Encode(v)
+}
+
+// ConnectionRecord is the JSON form of a saved connection. The password is never included.
+type ConnectionRecord struct {
+	Name        string `json:"name"`
+	Type        string `json:"type"`
+	Host        string `json:"host,omitempty"`
+	Port        int    `json:"port,omitempty"`
+	Database    string `json:"database"`
+	Username    string `json:"username,omitempty"`
+	Environment string `json:"environment,omitempty"`
+	Color       string `json:"color,omitempty"`
+}
+
+// Record returns the JSON form of the connection. File-based databases have no host or port.
+func (c *ConnectionConfig) Record() ConnectionRecord {
+	record := ConnectionRecord{
+		Name:        c.Name,
+		Type:        c.TypeName(),
+		Database:    c.Database,
+		Username:    c.Username,
+		Environment: c.Environment,
+		Color:       c.Color,
+	}
+	if b, ok := LookupBackend(c.DatabaseType); !ok || !b.FileBased {
+		record.Host = c.Host
+		record.Port = c.Port
+	}
+	return record
+}
+
+// TableRecord is the JSON form of a described table
+type TableRecord struct {
+	Name        string             `json:"name"`
+	Columns     []ColumnRecord     `json:"columns"`
+	PrimaryKeys []string           `json:"primary_keys,omitempty"`
+	ForeignKeys []ForeignKeyRecord `json:"foreign_keys,omitempty"`
+}
+
+// ColumnRecord is the JSON form of a table column. Default is null when the column has none.
+type ColumnRecord struct {
+	Name     string  `json:"name"`
+	Type     string  `json:"type"`
+	Nullable bool    `json:"nullable"`
+	Key      string  `json:"key,omitempty"`
+	Default  *string `json:"default"`
+	Comment  string  `json:"comment,omitempty"`
+}
+
+// ForeignKeyRecord is the JSON form of a foreign key
+type ForeignKeyRecord struct {
+	Name       string `json:"name"`
+	Column     string `json:"column"`
+	References string `json:"references"`
+	OnDelete   string `json:"on_delete,omitempty"`
+	OnUpdate   string `json:"on_update,omitempty"`
+}
+
+// Record returns the JSON form of the table
+func (t *TableInfo) Record() TableRecord {
+	record := TableRecord{Name: t.Name, Columns: make([]ColumnRecord, 0, len(t.Columns)), PrimaryKeys: t.PrimaryKeys}
+	for _, col := range t.Columns {
+		record.Columns = append(record.Columns, col.Record())
+	}
+	for _, fk := range t.ForeignKeys {
+		record.ForeignKeys = append(record.ForeignKeys, ForeignKeyRecord{
+			Name:       fk.Name,
+			Column:     fk.Column,
+			References: fk.ReferencedTable + "." + fk.ReferencedColumn,
+			OnDelete:   fk.OnDelete,
+			OnUpdate:   fk.OnUpdate,
+		})
+	}
+	return record
+}
+
+// Record returns the JSON form of the table column
+func (c ColumnInfo) Record() ColumnRecord {
+	return ColumnRecord{
+		Name:     c.Name,
+		Type:     c.Type,
+		Nullable: c.Nullable,
+		Key:      c.Key,
+		Default:  c.Default,
+		Comment:  c.Comment,
+	}
+}
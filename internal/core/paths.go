@@ -0,0 +1,35 @@
+package core
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+)
+
+// ExpandPath turns user-typed paths into ones the OS accepts: surrounding quotes
+// (added by "Copy as path" on Windows) are removed and a leading ~ becomes the
+// home directory. Separators are left alone, so both / and \ work on Windows.
+func ExpandPath(path string) string {
+	path = strings.TrimSpace(path)
+	if len(path) >= 2 && (path[0] == '"' && path[len(path)-1] == '"' || path[0] == '\'' && path[len(path)-1] == '\'') {
+		path = path[1 : len(path)-1]
+	}
+
+	if path == "~" || strings.HasPrefix(path, "~") && len(path) > 1 && os.IsPathSeparator(path[1]) {
+		if home, err := os.UserHomeDir(); err == nil {
+			return filepath.Join(home, path[1:])
+		}
+	}
+	return path
+}
+
+// SQLitePath normalises the database file of a SQLite connection. Plain paths,
+// including drive-letter paths such as C:\data\app.db, are expanded and cleaned;
+// file: URIs and :memory: are passed to the driver untouched.
+func SQLitePath(database string) string {
+	database = ExpandPath(database)
+	if database == "" || database == ":memory:" || strings.HasPrefix(database, "file:") {
+		return database
+	}
+	return filepath.Clean(database)
+}
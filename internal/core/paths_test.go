@@ -0,0 +1,61 @@
+package core
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+)
+
+func TestExpandPath(t *testing.T) {
+	home, err := os.UserHomeDir()
+	if err != nil {
+		t.Skipf("No home directory: %v", err)
+	}
+	report := filepath.Join("reports", "daily.sql")
+
+	testCases := []struct {
+		name     string
+		input    string
+		expected string
+	}{
+		{name: "Relative path untouched", input: report, expected: report},
+		{name: "Home directory", input: "~", expected: home},
+		{name: "Inside home directory", input: "~" + string(filepath.Separator) + report, expected: filepath.Join(home, report)},
+		{name: "Tilde inside a name", input: "~backup.sql", expected: "~backup.sql"},
+		{name: "Double quotes from Copy as path", input: `"` + report + `"`, expected: report},
+		{name: "Single quotes", input: "'" + report + "'", expected: report},
+		{name: "Surrounding spaces", input: "  " + report + " ", expected: report},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.name, func(t *testing.T) {
+			if got := ExpandPath(tc.input); got != tc.expected {
+				t.Errorf("ExpandPath(%q) = %q, expected %q", tc.input, got, tc.expected)
+			}
+		})
+	}
+}
+
+func TestSQLitePath(t *testing.T) {
+	dbPath := filepath.Join(t.TempDir(), "data", "app.db")
+
+	testCases := []struct {
+		name     string
+		input    string
+		expected string
+	}{
+		{name: "Absolute path", input: dbPath, expected: dbPath},
+		{name: "Quoted path", input: `"` + dbPath + `"`, expected: dbPath},
+		{name: "Uncleaned path", input: filepath.Join(filepath.Dir(dbPath), ".", "app.db"), expected: dbPath},
+		{name: "Memory database", input: ":memory:", expected: ":memory:"},
+		{name: "URI", input: "file:app.db?mode=ro", expected: "file:app.db?mode=ro"},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.name, func(t *testing.T) {
+			if got := SQLitePath(tc.input); got != tc.expected {
+				t.Errorf("SQLitePath(%q) = %q, expected %q", tc.input, got, tc.expected)
+			}
+		})
+	}
+}
@@ -0,0 +1,142 @@
+package core
+
+import (
+	"errors"
+	"fmt"
+	"strings"
+	"unicode/utf8"
+)
+
+// DefaultMaxPinValues bounds how many values one pin reference expands to
+const DefaultMaxPinValues = 1000
+
+var (
+	// ErrTooManyPinValues is returned when a pin reference would inline more values than allowed
+	ErrTooManyPinValues = errors.New("too many pinned values to inline")
+	// ErrUnknownPinColumn is returned when a pin reference names a column the pinned result lacks
+	ErrUnknownPinColumn = errors.New("the pinned result has no such column")
+)
+
+// PinExpansion is a query with its pin references replaced
+type PinExpansion struct {
+	Query string
+	// SkippedNulls counts the NULL values left out of the lists
+	SkippedNulls int
+}
+
+// ExpandPins replaces each :name.column reference to a result in pins with
+// the comma-separated literals of that column's values, e.g. for use in
+// IN (...). NULLs are skipped, and a column with no other values becomes NULL
+// so the list stays valid SQL. References inside quotes or comments, and
+// names that are not pinned, are left alone.
+func ExpandPins(query string, dbType DatabaseType, pins map[string]*StoredResult, maxValues int) (PinExpansion, error) {
+	var sb strings.Builder
+	expansion := PinExpansion{}
+
+	for i := 0; i < len(query); {
+		rest := query[i:]
+		skip := 0
+		switch {
+		case lineComment(dbType, rest):
+			if skip = strings.IndexByte(rest, '\n') + 1; skip == 0 {
+				skip = len(rest)
+			}
+		case strings.HasPrefix(rest, "/*"):
+			if end := strings.Index(rest[2:], "*/"); end >= 0 {
+				skip = end + 4
+			} else {
+				skip = len(rest)
+			}
+		case rest[0] == '\'' || isStringPrefix(rest) && !endsWithIdentRune(query[:i]):
+			skip = stringEnd(dbType, rest)
+		case rest[0] == '"' || rest[0] == '`':
+			skip = quotedEnd(rest, rest[0], false)
+		case rest[0] == '$' && !endsWithIdentRune(query[:i]):
+			skip = 1
+			if tag, ok := dollarTag(rest); ok {
+				if end := strings.Index(rest[len(tag):], tag); end >= 0 {
+					skip = len(tag) + end + len(tag)
+				} else {
+					skip = len(rest)
+				}
+			}
+		case rest[0] == ':' && !strings.HasSuffix(query[:i], ":") && !endsWithIdentRune(query[:i]):
+			name, column, n := pinReference(rest[1:])
+			pinned, ok := pins[name]
+			if n == 0 || !ok {
+				skip = 1
+				break
+			}
+			list, nulls, err := pinnedList(dbType, pinned, column, maxValues)
+			if err != nil {
+				return PinExpansion{}, fmt.Errorf("%w: :%s.%s", err, name, column)
+			}
+			sb.WriteString(list)
+			expansion.SkippedNulls += nulls
+			i += 1 + n
+			continue
+		default:
+			skip = 1
+		}
+		sb.WriteString(rest[:skip])
+		i += skip
+	}
+
+	expansion.Query = sb.String()
+	return expansion, nil
+}
+
+// pinReference parses name.column at the start of s, returning the bytes it spans or 0
+func pinReference(s string) (string, string, int) {
+	name := identPrefix(s)
+	if name == "" || len(s) <= len(name) || s[len(name)] != '.' {
+		return "", "", 0
+	}
+	column := identPrefix(s[len(name)+1:])
+	if column == "" {
+		return "", "", 0
+	}
+	return name, column, len(name) + 1 + len(column)
+}
+
+func identPrefix(s string) string {
+	end := 0
+	for end < len(s) {
+		r, size := utf8.DecodeRuneInString(s[end:])
+		if !isIdentRune(r) {
+			break
+		}
+		end += size
+	}
+	return s[:end]
+}
+
+// pinnedList writes the non-NULL values of column in pinned as literals
+func pinnedList(dbType DatabaseType, pinned *StoredResult, column string, maxValues int) (string, int, error) {
+	index := -1
+	for i, c := range pinned.Columns {
+		if c.Name == column || (index < 0 && strings.EqualFold(c.Name, column)) {
+			index = i
+		}
+	}
+	if index < 0 {
+		return "", 0, ErrUnknownPinColumn
+	}
+
+	var literals []string
+	nulls := 0
+	for _, row := range pinned.Rows {
+		if index >= len(row) || row[index] == nil || row[index].IsNull() {
+			nulls++
+			continue
+		}
+		if len(literals) == maxValues {
+			return "", 0, fmt.Errorf("%w (limit %d)", ErrTooManyPinValues, maxValues)
+		}
+		literals = append(literals, filterLiteral(dbType, row[index]))
+	}
+	if len(literals) == 0 {
+		return "NULL", nulls, nil
+	}
+	return strings.Join(literals, ", "), nulls, nil
+}
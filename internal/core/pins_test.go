@@ -0,0 +1,72 @@
+package core
+
+import (
+	"errors"
+	"testing"
+)
+
+func TestExpandPins(t *testing.T) {
+	pins := map[string]*StoredResult{
+		"ids": {
+			Columns: []Column{{Name: "id"}, {Name: "name"}, {Name: "total"}},
+			Rows: [][]Value{
+				{IntValue{Value: 1}, StringValue{Value: "O'Brien"}, DecimalValue{Value: "19.90"}},
+				{IntValue{Value: 2}, StringValue{Null: true}, DecimalValue{Value: "5.00"}},
+				{IntValue{Value: 3}, StringValue{Value: `back\slash`}, DecimalValue{Null: true}},
+			},
+		},
+		"empty": {Columns: []Column{{Name: "id"}}},
+	}
+
+	tests := []struct {
+		name      string
+		query     string
+		dbType    DatabaseType
+		want      string
+		wantNulls int
+	}{
+		{"integers", "SELECT * FROM orders WHERE user_id IN (:ids.id)", PostgreSQL,
+			"SELECT * FROM orders WHERE user_id IN (1, 2, 3)", 0},
+		{"strings with quotes skip NULLs", "SELECT 1 WHERE name IN (:ids.name)", PostgreSQL,
+			`SELECT 1 WHERE name IN ('O''Brien', 'back\slash')`, 1},
+		{"MySQL doubles backslashes", "SELECT 1 WHERE name IN (:ids.name)", MySQL,
+			`SELECT 1 WHERE name IN ('O''Brien', 'back\\slash')`, 1},
+		{"decimals stay numbers", "SELECT 1 WHERE total IN (:ids.total)", PostgreSQL,
+			"SELECT 1 WHERE total IN (19.90, 5.00)", 1},
+		{"column name ignores case", "SELECT :ids.ID", SQLite, "SELECT 1, 2, 3", 0},
+		{"no values", "SELECT 1 WHERE id IN (:empty.id)", SQLite, "SELECT 1 WHERE id IN (NULL)", 0},
+		{"inside a string", "SELECT ':ids.id' -- :ids.id", SQLite, "SELECT ':ids.id' -- :ids.id", 0},
+		{"PostgreSQL cast", "SELECT id::ids.id FROM t", PostgreSQL, "SELECT id::ids.id FROM t", 0},
+		{"not pinned", "SELECT :other.id", SQLite, "SELECT :other.id", 0},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			expansion, err := ExpandPins(tt.query, tt.dbType, pins, DefaultMaxPinValues)
+			if err != nil {
+				t.Fatalf("Unexpected error: %v", err)
+			}
+			if expansion.Query != tt.want {
+				t.Errorf("ExpandPins() = %q, want %q", expansion.Query, tt.want)
+			}
+			if expansion.SkippedNulls != tt.wantNulls {
+				t.Errorf("Expected %d skipped NULLs, got %d", tt.wantNulls, expansion.SkippedNulls)
+			}
+		})
+	}
+}
+
+func TestExpandPins_Errors(t *testing.T) {
+	pins := map[string]*StoredResult{
+		"ids": {Columns: []Column{{Name: "id"}}, Rows: intRows(3)},
+	}
+
+	if _, err := ExpandPins("SELECT :ids.id", SQLite, pins, 2); !errors.Is(err, ErrTooManyPinValues) {
+		t.Errorf("Expected ErrTooManyPinValues above the limit, got %v", err)
+	}
+	if _, err := ExpandPins("SELECT :ids.id", SQLite, pins, 3); err != nil {
+		t.Errorf("Expected exactly the limit to be allowed, got %v", err)
+	}
+	if _, err := ExpandPins("SELECT :ids.missing", SQLite, pins, 3); !errors.Is(err, ErrUnknownPinColumn) {
+		t.Errorf("Expected ErrUnknownPinColumn, got %v", err)
+	}
+}
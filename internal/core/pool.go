@@ -0,0 +1,161 @@
+package core
+
+import (
+	"context"
+	"database/sql"
+	"errors"
+	"fmt"
+	"strconv"
+	"time"
+)
+
+var (
+	// ErrAcquireTimeout is returned when no pooled connection frees up within the acquire timeout
+	ErrAcquireTimeout = errors.New("timed out waiting for a free connection")
+	// ErrPoolNotSupported is returned by connections that are not backed by a connection pool
+	ErrPoolNotSupported = errors.New("connection pooling is not supported for this database type")
+)
+
+// defaultIdleConnections is how many idle connections database/sql keeps by default
+const defaultIdleConnections = 2
+
+// PoolSettings is how the connection pool of a connection is sized
+type PoolSettings struct {
+	// MaxConnections caps open connections to the server; 0 is unlimited
+	MaxConnections int
+	// MinConnections is how many idle connections are kept open for reuse
+	MinConnections int
+	// AcquireTimeout bounds the wait for a free connection; 0 waits as long as the statement may
+	AcquireTimeout time.Duration
+	// IdleTimeout closes connections left idle this long; 0 keeps them
+	IdleTimeout time.Duration
+}
+
+// EffectivePool returns the pool settings of config, with anything left at
+// 0 taken from the defaults. File databases always use one connection, since
+// state such as attached databases belongs to a single connection.
+func EffectivePool(config *ConnectionConfig) PoolSettings {
+	pool := PoolSettings{
+		MaxConnections: max(config.MaxConnections, 0),
+		MinConnections: defaultIdleConnections,
+		AcquireTimeout: time.Duration(max(config.AcquireTimeoutSecs, 0)) * time.Second,
+		IdleTimeout:    time.Duration(max(config.IdleTimeoutSecs, 0)) * time.Second,
+	}
+	if config.MinConnections > 0 {
+		pool.MinConnections = config.MinConnections
+	}
+	if backend, ok := LookupBackend(config.DatabaseType); ok && backend.FileBased {
+		pool.MaxConnections = 1
+	}
+	if pool.MaxConnections > 0 {
+		pool.MinConnections = min(pool.MinConnections, pool.MaxConnections)
+	}
+	return pool
+}
+
+// apply sizes the pool of db
+func (p PoolSettings) apply(db *sql.DB) {
+	db.SetMaxOpenConns(p.MaxConnections)
+	db.SetMaxIdleConns(p.MinConnections)
+	db.SetConnMaxIdleTime(p.IdleTimeout)
+}
+
+// String describes the settings in one line, e.g.
+// "max 4, min 1, acquire timeout 10s, idle timeout 5m0s"
+func (p PoolSettings) String() string {
+	maxConnections, acquire, idle := "unlimited", "none", "none"
+	if p.MaxConnections > 0 {
+		maxConnections = strconv.Itoa(p.MaxConnections)
+	}
+	if p.AcquireTimeout > 0 {
+		acquire = p.AcquireTimeout.String()
+	}
+	if p.IdleTimeout > 0 {
+		idle = p.IdleTimeout.String()
+	}
+	return fmt.Sprintf("max %s, min %d, acquire timeout %s, idle timeout %s", maxConnections, p.MinConnections, acquire, idle)
+}
+
+// PoolReporter is implemented by connections backed by a connection pool
+type PoolReporter interface {
+	// Pool returns the settings the pool was opened with
+	Pool() (PoolSettings, error)
+}
+
+// Pool returns the settings the connection's pool was opened with
+func (c *SQLConnection) Pool() (PoolSettings, error) {
+	return EffectivePool(c.config), nil
+}
+
+// query runs query on the pool. With an acquire timeout, a connection is
+// taken from the pool first so a full pool fails with ErrAcquireTimeout
+// rather than waiting on the statement's context alone; it goes back to the
+// pool when the result is closed. Backends that report warnings and notices
+// also run the statement on a connection of its own, so what the server
+// sends about it can be collected there.
+func (c *SQLConnection) query(ctx context.Context, query string, args ...any) (*QueryResult, error) {
+	timeout := EffectivePool(c.config).AcquireTimeout
+	backend, _ := LookupBackend(c.config.DatabaseType)
+	collect := backend.notices
+	if backend.noticesOptIn && !c.config.ShowWarnings {
+		collect = nil
+	}
+	if timeout <= 0 && collect == nil {
+		rows, err := c.db.QueryContext(ctx, query, args...)
+		if err != nil {
+			return nil, fmt.Errorf("failed to execute query: %w", err)
+		}
+		return NewQueryResult(rows)
+	}
+
+	conn, err := c.acquire(ctx, timeout)
+	if err != nil {
+		return nil, err
+	}
+	stop := func() []Notice { return nil }
+	if collect != nil {
+		stop = collect(ctx, conn)
+	}
+
+	rows, err := conn.QueryContext(ctx, query, args...)
+	if err != nil {
+		stop()
+		conn.Close()
+		return nil, fmt.Errorf("failed to execute query: %w", err)
+	}
+	result, err := NewQueryResult(rows)
+	if err != nil {
+		rows.Close()
+		stop()
+		conn.Close()
+		return nil, err
+	}
+	result.OnClose(func(int, error) {
+		result.AddNotices(stop()...)
+		conn.Close()
+	})
+	return result, nil
+}
+
+// acquire takes a connection from the pool, failing with ErrAcquireTimeout
+// when none is free within timeout; 0 waits as long as ctx does
+func (c *SQLConnection) acquire(ctx context.Context, timeout time.Duration) (*sql.Conn, error) {
+	if timeout <= 0 {
+		conn, err := c.db.Conn(ctx)
+		if err != nil {
+			return nil, fmt.Errorf("failed to execute query: %w", err)
+		}
+		return conn, nil
+	}
+
+	acquireCtx, cancel := context.WithTimeout(ctx, timeout)
+	conn, err := c.db.Conn(acquireCtx)
+	cancel()
+	if err != nil {
+		if ctx.Err() == nil && errors.Is(err, context.DeadlineExceeded) {
+			return nil, fmt.Errorf("%w after %s", ErrAcquireTimeout, timeout)
+		}
+		return nil, fmt.Errorf("failed to execute query: %w", err)
+	}
+	return conn, nil
+}
@@ -0,0 +1,79 @@
+//go:build !no_sqlite
+
+package core
+
+import (
+	"errors"
+	"path/filepath"
+	"testing"
+	"time"
+)
+
+// openPoolTestDB opens a SQLite connection, which pools a single connection
+func openPoolTestDB(t *testing.T, acquireTimeoutSecs int) Connection {
+	t.Helper()
+	conn, err := NewConnection(&ConnectionConfig{Name: "pool", DatabaseType: SQLite,
+		Database: filepath.Join(t.TempDir(), "pool.db"), AcquireTimeoutSecs: acquireTimeoutSecs})
+	if err != nil {
+		t.Fatalf("Failed to open SQLite: %v", err)
+	}
+	t.Cleanup(func() { conn.Close() })
+	return conn
+}
+
+func TestSQLConnection_SecondQueryWaitsForTheConnection(t *testing.T) {
+	conn := openPoolTestDB(t, 0)
+	first, err := conn.Execute("SELECT 1 UNION ALL SELECT 2")
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+
+	done := make(chan error, 1)
+	go func() {
+		second, err := conn.Execute("SELECT 3")
+		if err == nil {
+			second.Close()
+		}
+		done <- err
+	}()
+
+	select {
+	case err := <-done:
+		t.Fatalf("Expected the second query to wait while the first holds the connection, got %v", err)
+	case <-time.After(100 * time.Millisecond):
+	}
+
+	first.Close()
+	select {
+	case err := <-done:
+		if err != nil {
+			t.Errorf("Expected the second query to run once the connection was free, got %v", err)
+		}
+	case <-time.After(5 * time.Second):
+		t.Fatal("Expected the second query to run once the connection was free")
+	}
+}
+
+func TestSQLConnection_AcquireTimeout(t *testing.T) {
+	conn := openPoolTestDB(t, 1)
+	first, err := conn.Execute("SELECT 1 UNION ALL SELECT 2")
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	defer first.Close()
+
+	start := time.Now()
+	if _, err := conn.Execute("SELECT 3"); !errors.Is(err, ErrAcquireTimeout) {
+		t.Fatalf("Expected ErrAcquireTimeout while the only connection is busy, got %v", err)
+	}
+	if waited := time.Since(start); waited < time.Second {
+		t.Errorf("Expected to wait the acquire timeout, waited %s", waited)
+	}
+
+	first.Close()
+	result, err := conn.Execute("SELECT 3")
+	if err != nil {
+		t.Fatalf("Expected the connection to be back in the pool, got %v", err)
+	}
+	result.Close()
+}
@@ -0,0 +1,59 @@
+package core
+
+import (
+	"testing"
+	"time"
+)
+
+func TestEffectivePool(t *testing.T) {
+	testCases := []struct {
+		name   string
+		config ConnectionConfig
+		want   PoolSettings
+	}{
+		{
+			name:   "Defaults",
+			config: ConnectionConfig{DatabaseType: PostgreSQL},
+			want:   PoolSettings{MinConnections: defaultIdleConnections},
+		},
+		{
+			name: "Configured",
+			config: ConnectionConfig{DatabaseType: MySQL, MaxConnections: 4, MinConnections: 1,
+				AcquireTimeoutSecs: 10, IdleTimeoutSecs: 300},
+			want: PoolSettings{MaxConnections: 4, MinConnections: 1, AcquireTimeout: 10 * time.Second, IdleTimeout: 5 * time.Minute},
+		},
+		{
+			name:   "Idle connections never exceed the maximum",
+			config: ConnectionConfig{DatabaseType: MySQL, MaxConnections: 1, MinConnections: 3},
+			want:   PoolSettings{MaxConnections: 1, MinConnections: 1},
+		},
+		{
+			name:   "File databases use one connection",
+			config: ConnectionConfig{DatabaseType: SQLite, MaxConnections: 8},
+			want:   PoolSettings{MaxConnections: 1, MinConnections: 1},
+		},
+		{
+			name:   "Negative values are ignored",
+			config: ConnectionConfig{DatabaseType: PostgreSQL, MaxConnections: -1, AcquireTimeoutSecs: -5},
+			want:   PoolSettings{MinConnections: defaultIdleConnections},
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.name, func(t *testing.T) {
+			if got := EffectivePool(&tc.config); got != tc.want {
+				t.Errorf("EffectivePool() = %+v, want %+v", got, tc.want)
+			}
+		})
+	}
+}
+
+func TestPoolSettings_String(t *testing.T) {
+	if got := (PoolSettings{MinConnections: 2}).String(); got != "max unlimited, min 2, acquire timeout none, idle timeout none" {
+		t.Errorf("Unexpected description %q", got)
+	}
+	pool := PoolSettings{MaxConnections: 4, MinConnections: 1, AcquireTimeout: 10 * time.Second, IdleTimeout: 5 * time.Minute}
+	if got := pool.String(); got != "max 4, min 1, acquire timeout 10s, idle timeout 5m0s" {
+		t.Errorf("Unexpected description %q", got)
+	}
+}
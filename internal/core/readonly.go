@@ -0,0 +1,120 @@
+package core
+
+import (
+	"errors"
+	"strings"
+)
+
+// ErrReadOnly is returned when a statement is rejected because the session is read-only
+var ErrReadOnly = errors.New("read-only mode")
+
+// readOnlyKeywords start statements that only read data
+var readOnlyKeywords = []string{"SELECT", "WITH", "SHOW", "DESCRIBE", "DESC", "EXPLAIN", "VALUES", "TABLE", "PRAGMA", "SET", "USE"}
+
+// writingKeywords are rejected anywhere in a statement, at any depth: a WITH
+// may hold a data-modifying CTE, EXPLAIN ANALYZE runs the statement it
+// explains, SELECT ... INTO creates a table or writes a file, and FOR UPDATE
+// locks rows as a write would
+var writingKeywords = []string{"INSERT", "UPDATE", "DELETE", "MERGE", "INTO"}
+
+// writingFunctions change the database or the server when called, so a SELECT
+// that calls one is not read-only. set_config can also turn off the session's
+// server-side read-only setting.
+var writingFunctions = []string{
+	"set_config", "nextval", "setval", "pg_terminate_backend", "pg_cancel_backend",
+	"pg_reload_conf", "pg_rotate_logfile", "pg_switch_wal", "pg_create_restore_point",
+	"lo_create", "lo_import", "lo_export", "lo_unlink", "lo_put", "lo_truncate",
+	"pg_advisory_lock", "pg_advisory_xact_lock", "dblink_exec",
+	"get_lock", "release_lock", "release_all_locks",
+}
+
+// readingPragmas are the SQLite pragmas that take an argument only to say what
+// to read, e.g. PRAGMA table_info(users). Any other pragma given a value sets it.
+var readingPragmas = []string{
+	"table_info", "table_xinfo", "table_list", "index_info", "index_xinfo", "index_list",
+	"foreign_key_list", "foreign_key_check", "integrity_check", "quick_check",
+}
+
+// writingPragmas change the database without being given a value
+var writingPragmas = []string{"optimize", "incremental_vacuum", "wal_checkpoint"}
+
+// IsReadOnlyQuery reports whether every statement in query only reads data.
+// Session settings such as SET and USE are allowed so init SQL keeps working,
+// but not SET GLOBAL, which changes every session, or one that makes the
+// session writable. Calls to writingFunctions such as set_config are refused. Statements are read with the lexer, so keywords inside
+// strings and comments are ignored and ones next to a parenthesis are not.
+func IsReadOnlyQuery(dbType DatabaseType, query string) bool {
+	for _, statement := range SplitStatements(dbType, query) {
+		tokens := sqlTokens(dbType, statement)
+		if len(tokens) == 0 {
+			continue
+		}
+		if !containsFold(readOnlyKeywords, tokens[0]) {
+			return false
+		}
+		for i, token := range tokens[1:] {
+			if containsFold(writingKeywords, token) || callsWritingFunction(tokens[i+1:]) {
+				return false
+			}
+		}
+
+		switch strings.ToUpper(tokens[0]) {
+		case "PRAGMA":
+			if !readOnlyPragma(tokens[1:]) {
+				return false
+			}
+		case "SET":
+			if !sessionSetting(tokens[1:]) {
+				return false
+			}
+		}
+	}
+	return true
+}
+
+// callsWritingFunction reports whether tokens start with a call to one of
+// writingFunctions, which may be schema-qualified, e.g. pg_catalog.set_config(
+func callsWritingFunction(tokens []string) bool {
+	if len(tokens) < 2 || tokens[1] != "(" {
+		return false
+	}
+	name := tokens[0][strings.LastIndexByte(tokens[0], '.')+1:]
+	return containsFold(writingFunctions, name)
+}
+
+// readOnlyPragma reports whether the tokens after PRAGMA only read a setting
+// or the schema: PRAGMA name, or PRAGMA name(arg) for one of readingPragmas
+func readOnlyPragma(tokens []string) bool {
+	if len(tokens) == 0 {
+		return true
+	}
+	// The name may be schema-qualified, e.g. main.journal_mode
+	name := tokens[0][strings.LastIndexByte(tokens[0], '.')+1:]
+	if containsFold(writingPragmas, name) {
+		return false
+	}
+	if len(tokens) == 1 {
+		return true
+	}
+	return tokens[1] == "(" && containsFold(readingPragmas, name)
+}
+
+// sessionSetting reports whether the tokens after SET change only this
+// session and leave it read-only: not SET GLOBAL or PERSIST, @@global.name,
+// or a transaction or read_only setting that makes the session writable
+func sessionSetting(tokens []string) bool {
+	for i, token := range tokens {
+		lower := strings.ToLower(token)
+		switch {
+		case lower == "global" || lower == "persist" || lower == "persist_only":
+			return false
+		case strings.HasPrefix(lower, "global.") || strings.HasPrefix(lower, "persist."):
+			return false
+		case strings.Contains(lower, "read_only") || strings.Contains(lower, "query_only"):
+			return false
+		case lower == "read" && i+1 < len(tokens) && strings.EqualFold(tokens[i+1], "WRITE"):
+			return false
+		}
+	}
+	return true
+}
@@ -0,0 +1,42 @@
+//go:build !no_sqlite
+
+package core
+
+import (
+	"path/filepath"
+	"testing"
+)
+
+func TestSQLiteReadOnlySession(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "app.db")
+	conn, err := NewConnection(&ConnectionConfig{Name: "app", DatabaseType: SQLite, Database: path})
+	if err != nil {
+		t.Fatalf("Failed to open SQLite: %v", err)
+	}
+	execAndDrain(t, conn, "CREATE TABLE users (id INTEGER PRIMARY KEY)")
+	conn.Close()
+
+	readOnly, err := NewConnection(&ConnectionConfig{Name: "app", DatabaseType: SQLite, Database: path, ReadOnly: true})
+	if err != nil {
+		t.Fatalf("Failed to open SQLite read-only: %v", err)
+	}
+	defer readOnly.Close()
+
+	run := func(query string) error {
+		result, err := readOnly.Execute(query)
+		if err != nil {
+			return err
+		}
+		defer result.Close()
+		for range result.Itor() {
+		}
+		return result.Error()
+	}
+	if err := run("SELECT * FROM users"); err != nil {
+		t.Errorf("Expected reading to work, got %v", err)
+	}
+	// The statement check would catch this; the server refuses it as well
+	if err := run("INSERT INTO users VALUES (1)"); err == nil {
+		t.Error("Expected the read-only session to refuse a write")
+	}
+}
@@ -0,0 +1,68 @@
+package core
+
+import "testing"
+
+func TestIsReadOnlyQuery(t *testing.T) {
+	testCases := []struct {
+		query    string
+		expected bool
+	}{
+		{"SELECT * FROM users", true},
+		{"  show tables;", true},
+		{"-- check\nEXPLAIN SELECT 1", true},
+		{"WITH recent AS (SELECT * FROM orders) SELECT * FROM recent", true},
+		{"SET search_path TO app", true},
+		{"", true},
+		{"INSERT INTO users VALUES (1)", false},
+		{"SELECT 1; DELETE FROM users", false},
+		{"SELECT ';DELETE FROM users' AS note", true},
+		{"WITH gone AS (DELETE FROM users RETURNING *) SELECT * FROM gone", false},
+		{"EXPLAIN ANALYZE UPDATE users SET name = 'x'", false},
+		{"DROP TABLE users", false},
+		{"/* sneaky */ TRUNCATE logs", false},
+		{"WITH d AS(DELETE FROM t RETURNING *) SELECT * FROM d", false},
+		{"SELECT * FROM (SELECT 1) x WHERE EXISTS(UPDATE t SET a = 1)", false},
+		{"SELECT * INTO newtable FROM users", false},
+		{"SELECT * FROM users INTO OUTFILE '/tmp/users'", false},
+		{"SELECT * FROM users FOR UPDATE", false},
+		{"SELECT 'DELETE', \"update\" FROM t -- INSERT", true},
+		{"PRAGMA table_info(users)", true},
+		{"PRAGMA main.journal_mode", true},
+		{"PRAGMA writable_schema=1", false},
+		{"PRAGMA journal_mode(WAL)", false},
+		{"PRAGMA optimize", false},
+		{"SET NAMES utf8mb4", true},
+		{"SET GLOBAL max_connections = 1", false},
+		{"SET @@global.max_connections = 1", false},
+		{"SET PERSIST sql_mode = ''", false},
+		{"SET SESSION CHARACTERISTICS AS TRANSACTION READ WRITE", false},
+		{"SET default_transaction_read_only = off", false},
+	}
+
+	for _, tc := range testCases {
+		if got := IsReadOnlyQuery(MySQL, tc.query); got != tc.expected {
+			t.Errorf("IsReadOnlyQuery(%q) = %v, expected %v", tc.query, got, tc.expected)
+		}
+	}
+	postgres := []struct {
+		query    string
+		expected bool
+	}{
+		{"SELECT $$it's$$ AS note", true},
+		{"SELECT $body$DELETE FROM t$body$", true},
+		{"WITH x AS (SELECT $a$'$a$), d AS (DELETE FROM t RETURNING 1) SELECT $a$'$a$", false},
+		{"SELECT $1::int", true},
+		{"SELECT set_config('default_transaction_read_only', 'off', false)", false},
+		{"SELECT pg_catalog.set_config('x', 'y', true)", false},
+		{"SELECT nextval('orders_id_seq')", false},
+		{"SELECT pg_terminate_backend(42)", false},
+		{"SELECT lo_unlink(1234)", false},
+		{"SELECT current_setting('search_path')", true},
+		{"SELECT nextval FROM counters", true},
+	}
+	for _, tc := range postgres {
+		if got := IsReadOnlyQuery(PostgreSQL, tc.query); got != tc.expected {
+			t.Errorf("IsReadOnlyQuery(PostgreSQL, %q) = %v, expected %v", tc.query, got, tc.expected)
+		}
+	}
+}
@@ -0,0 +1,204 @@
+package core
+
+import (
+	"bufio"
+	"encoding/json"
+	"io"
+	"strconv"
+	"strings"
+
+	"sqlterm/internal/i18n"
+)
+
+// RecordMarkdown renders one row vertically, a line per column with its name,
+// declared type and value. Types and comments come from info when given; the
+// comment column is left out when no column has one. Columns that cannot hold
+// NULL are marked NOT NULL.
+func RecordMarkdown(columns []Column, row []Value, info *TableInfo, format FormatOptions, i18nMgr *i18n.Manager) string {
+	declared := make(map[string]ColumnInfo)
+	hasComments := false
+	if info != nil {
+		for _, column := range info.Columns {
+			declared[strings.ToLower(column.Name)] = column
+			hasComments = hasComments || column.Comment != ""
+		}
+	}
+
+	header := []Column{
+		{Name: i18nMgr.Get("record_column_header")},
+		{Name: i18nMgr.Get("record_type_header")},
+		{Name: i18nMgr.Get("record_value_header")},
+	}
+	if hasComments {
+		header = append(header, Column{Name: i18nMgr.Get("record_comment_header")})
+	}
+
+	lines := make([][]Value, 0, len(columns))
+	for i, column := range columns {
+		columnType := column.TypeLabel()
+		detail, ok := declared[strings.ToLower(column.Name)]
+		if ok && detail.Type != "" {
+			columnType = detail.Type
+		}
+		nullable := column.Nullable
+		if nullable == nil && ok {
+			nullable = &detail.Nullable
+		}
+		if nullable != nil && !*nullable {
+			columnType += " NOT NULL"
+		}
+
+		value := "NULL"
+		if i < len(row) && !row[i].IsNull() {
+			value = FormatValue(row[i], format)
+		}
+
+		line := []Value{StringValue{Value: column.Name}, StringValue{Value: columnType}, StringValue{Value: value}}
+		if hasComments {
+			line = append(line, StringValue{Value: detail.Comment})
+		}
+		lines = append(lines, line)
+	}
+
+	// This is where long values are read in full
+	format.MaxCellWidth = -1
+	return MarkdownTable(header, lines, format)
+}
+
+// RowJSON renders one row as a JSON object with the columns in result order.
+// Numbers and booleans keep their JSON types, NULL becomes null and other
+// values are written in their ISO/raw form.
+func RowJSON(columns []Column, row []Value) (string, error) {
+	var sb strings.Builder
+	sb.WriteString("{")
+	for i, column := range columns {
+		if i > 0 {
+			sb.WriteString(",")
+		}
+		key, err := json.Marshal(column.Name)
+		if err != nil {
+			return "", err
+		}
+
+		var value Value = NullValue{}
+		if i < len(row) {
+			value = row[i]
+		}
+		encoded, err := json.Marshal(value)
+		if err != nil {
+			return "", err
+		}
+		sb.WriteString("\n  ")
+		sb.Write(key)
+		sb.WriteString(": ")
+		sb.Write(encoded)
+	}
+	sb.WriteString("\n}")
+	return sb.String(), nil
+}
+
+// JSONWriter writes each row as a JSON object on its own line, keyed by the
+// column names given to WriteHeaders. Without headers the keys are column
+// numbers counting from 1.
+type JSONWriter struct {
+	writer  *bufio.Writer
+	closer  io.Closer
+	columns []string
+}
+
+// NewJSONWriter writes newline-delimited JSON to w
+func NewJSONWriter(w io.Writer) *JSONWriter {
+	writer := &JSONWriter{writer: bufio.NewWriter(w)}
+	if closer, ok := w.(io.Closer); ok {
+		writer.closer = closer
+	}
+	return writer
+}
+
+func (w *JSONWriter) WriteHeaders(columns []string) error {
+	w.columns = columns
+	return nil
+}
+
+func (w *JSONWriter) WriteRow(row []Value) error {
+	w.writer.WriteByte('{')
+	for i, val := range row {
+		if i > 0 {
+			w.writer.WriteByte(',')
+		}
+		name := strconv.Itoa(i + 1)
+		if i < len(w.columns) {
+			name = w.columns[i]
+		}
+		key, err := json.Marshal(name)
+		if err != nil {
+			return err
+		}
+		encoded, err := json.Marshal(val)
+		if err != nil {
+			return err
+		}
+		w.writer.Write(key)
+		w.writer.WriteByte(':')
+		w.writer.Write(encoded)
+	}
+	_, err := w.writer.WriteString("}\n")
+	return err
+}
+
+// WriteMetadata writes meta as a first {"_metadata": {...}} line
+func (w *JSONWriter) WriteMetadata(meta ExportMetadata) error {
+	line, err := jsonMetadataLine(meta)
+	if err != nil {
+		return err
+	}
+	_, err = w.writer.Write(line)
+	return err
+}
+
+// WriteRowCount ends the export with a {"_metadata": {"rows": n}} line
+func (w *JSONWriter) WriteRowCount(rows int) error {
+	line, err := jsonMetadataLine(map[string]int{"rows": rows})
+	if err != nil {
+		return err
+	}
+	_, err = w.writer.Write(line)
+	return err
+}
+
+// Flush writes buffered rows to the destination
+func (w *JSONWriter) Flush() error {
+	return w.writer.Flush()
+}
+
+// Close flushes buffered output and closes the destination if it can be closed
+func (w *JSONWriter) Close() error {
+	err := w.writer.Flush()
+	if w.closer != nil {
+		if closeErr := w.closer.Close(); err == nil {
+			err = closeErr
+		}
+	}
+	return err
+}
+
+func jsonValue(v Value) any {
+	if v.IsNull() {
+		return nil
+	}
+	switch t := v.(type) {
+	case IntValue:
+		return t.Value
+	case FloatValue:
+		return t.Value
+	case BoolValue:
+		return t.Value
+	case DecimalValue:
+		return json.Number(t.Value)
+	case BytesValue:
+		// encoding/json writes []byte as base64
+		return t.Value
+	default:
+		return FormatISO(v)
+	}
+}
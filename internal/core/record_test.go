@@ -0,0 +1,69 @@
+package core
+
+import (
+	"strings"
+	"testing"
+
+	"sqlterm/internal/i18n"
+)
+
+func TestRecordMarkdown(t *testing.T) {
+	i18nMgr, err := i18n.NewManager("en_au")
+	if err != nil {
+		t.Fatalf("Failed to create i18n manager: %v", err)
+	}
+
+	columns := []Column{{Name: "id", Type: "INT"}, {Name: "email", Type: "VARCHAR"}}
+	row := []Value{IntValue{Value: 7}, StringValue{Null: true}}
+	info := &TableInfo{Columns: []ColumnInfo{
+		{Name: "id", Type: "bigint"},
+		{Name: "email", Type: "varchar(255)", Comment: "Login address"},
+	}}
+
+	md := RecordMarkdown(columns, row, info, DefaultFormatOptions(), i18nMgr)
+	lines := strings.Split(strings.TrimSpace(md), "\n")
+	if len(lines) != 4 || !strings.Contains(lines[0], "Comment") {
+		t.Fatalf("Expected a header, separator and one line per column, got:\n%s", md)
+	}
+	if !strings.Contains(lines[2], "bigint") {
+		t.Errorf("Expected the declared type to be shown, got %q", lines[2])
+	}
+	if !strings.Contains(lines[3], "NULL") || !strings.Contains(lines[3], "Login address") {
+		t.Errorf("Expected the NULL value and the column comment, got %q", lines[3])
+	}
+
+	md = RecordMarkdown(columns, row, nil, DefaultFormatOptions(), i18nMgr)
+	if strings.Contains(md, "Comment") || !strings.Contains(md, "VARCHAR") {
+		t.Errorf("Expected result types and no comment column without table details, got:\n%s", md)
+	}
+	if strings.Contains(md, "NOT NULL") {
+		t.Errorf("Expected no NOT NULL mark when nullability is unknown, got:\n%s", md)
+	}
+
+	notNull := false
+	columns[1].Nullable, columns[1].Length = &notNull, 80
+	md = RecordMarkdown(columns, row, nil, DefaultFormatOptions(), i18nMgr)
+	if !strings.Contains(md, "VARCHAR(80) NOT NULL") {
+		t.Errorf("Expected the result column's size and nullability, got:\n%s", md)
+	}
+}
+
+func TestRowJSON(t *testing.T) {
+	columns := []Column{{Name: "name"}, {Name: "id"}, {Name: "price"}, {Name: "active"}, {Name: "note"}}
+	row := []Value{
+		StringValue{Value: "a \"quoted\" name"},
+		IntValue{Value: 42},
+		DecimalValue{Value: "19.90"},
+		BoolValue{Value: true},
+		StringValue{Null: true},
+	}
+
+	got, err := RowJSON(columns, row)
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	expected := "{\n  \"name\": \"a \\\"quoted\\\" name\",\n  \"id\": 42,\n  \"price\": 19.90,\n  \"active\": true,\n  \"note\": null\n}"
+	if got != expected {
+		t.Errorf("Expected columns in result order with JSON types:\n%s\ngot:\n%s", expected, got)
+	}
+}
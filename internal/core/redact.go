@@ -0,0 +1,196 @@
+package core
+
+import (
+	"regexp"
+	"strings"
+	"unicode/utf8"
+)
+
+// RedactedLiteral replaces each string and numeric literal in redacted queries
+const RedactedLiteral = "?"
+
+// RedactLiterals replaces string and numeric literals in query with ? so the
+// query can be stored without the values it was run with. Identifiers, quoted
+// identifiers, comments, keywords and bind parameters ($1, ?, :name) are kept.
+// Strings may use '' or \' escapes, a prefix such as E'', N'' or X'', or
+// PostgreSQL dollar quoting ($$...$$, $tag$...$tag$). Backslash escapes are
+// read as dbType reads them.
+func RedactLiterals(dbType DatabaseType, query string) string {
+	var sb strings.Builder
+	sb.Grow(len(query))
+
+	prev := rune(0) // last rune copied to the output, used to tell literals from identifiers
+	for i := 0; i < len(query); {
+		r, size := utf8.DecodeRuneInString(query[i:])
+		rest := query[i:]
+
+		switch {
+		case lineComment(dbType, rest):
+			end := strings.IndexByte(rest, '\n')
+			if end < 0 {
+				end = len(rest)
+			}
+			sb.WriteString(rest[:end])
+			i += end
+			prev = ' '
+			continue
+
+		case strings.HasPrefix(rest, "/*"):
+			end := strings.Index(rest[2:], "*/")
+			if end < 0 {
+				end = len(rest)
+			} else {
+				end += 4
+			}
+			sb.WriteString(rest[:end])
+			i += end
+			prev = ' '
+			continue
+
+		case r == '"' || r == '`':
+			end := quotedEnd(rest, byte(r), false)
+			sb.WriteString(rest[:end])
+			i += end
+			prev = r
+			continue
+
+		case r == '\'':
+			i += stringEnd(dbType, rest)
+			sb.WriteString(RedactedLiteral)
+			prev = '\''
+			continue
+
+		case isStringPrefix(rest) && !isIdentRune(prev):
+			// E'...', N'...', X'...', B'...' and similar: the prefix belongs to the literal
+			i += stringEnd(dbType, rest)
+			sb.WriteString(RedactedLiteral)
+			prev = '\''
+			continue
+
+		case r == '$' && !isIdentRune(prev):
+			if tag, ok := dollarTag(rest); ok {
+				body := rest[len(tag):]
+				end := strings.Index(body, tag)
+				if end < 0 {
+					i = len(query)
+				} else {
+					i += len(tag) + end + len(tag)
+				}
+				sb.WriteString(RedactedLiteral)
+				prev = '\''
+				continue
+			}
+
+		case isNumberStart(rest) && !isIdentRune(prev) && prev != '$' && prev != ':':
+			i += numberEnd(rest)
+			sb.WriteString(RedactedLiteral)
+			prev = '0'
+			continue
+		}
+
+		sb.WriteRune(r)
+		i += size
+		prev = r
+	}
+
+	return sb.String()
+}
+
+func isNumberStart(s string) bool {
+	if s[0] >= '0' && s[0] <= '9' {
+		return true
+	}
+	return s[0] == '.' && len(s) > 1 && s[1] >= '0' && s[1] <= '9'
+}
+
+// numberEnd returns the length of the numeric literal at the start of s: digits,
+// a fraction, an exponent, or a 0x hex number
+func numberEnd(s string) int {
+	if len(s) > 2 && s[0] == '0' && (s[1] == 'x' || s[1] == 'X') {
+		i := 2
+		for i < len(s) && isHexDigit(s[i]) {
+			i++
+		}
+		return i
+	}
+
+	i := 0
+	for i < len(s) && (s[i] >= '0' && s[i] <= '9' || s[i] == '.') {
+		i++
+	}
+	if i < len(s) && (s[i] == 'e' || s[i] == 'E') {
+		j := i + 1
+		if j < len(s) && (s[j] == '+' || s[j] == '-') {
+			j++
+		}
+		if j < len(s) && s[j] >= '0' && s[j] <= '9' {
+			for j < len(s) && s[j] >= '0' && s[j] <= '9' {
+				j++
+			}
+			i = j
+		}
+	}
+	return i
+}
+
+func isHexDigit(c byte) bool {
+	return c >= '0' && c <= '9' || c >= 'a' && c <= 'f' || c >= 'A' && c <= 'F'
+}
+
+// RedactedPassword replaces passwords in connection strings and connection errors
+const RedactedPassword = "****"
+
+var (
+	// userinfoPassword matches user:secret@ in a URL or MySQL DSN. The secret
+	// runs to the last @ before the host, since MySQL DSNs leave an @ in a
+	// password unescaped. A slash ends the match so scheme:// is not taken
+	// for a user; URLs escape it.
+	userinfoPassword = regexp.MustCompile("([^\\s:/@'\"`]+:)[^\\s/]+@")
+	// settingPassword matches password=secret in a key=value connection string
+	settingPassword = regexp.MustCompile(`(?i)(\bpassword\s*=\s*)('(?:[^'\\]|\\.)*'|[^\s;&]+)`)
+)
+
+// RedactDSN masks the passwords in the connection strings found in s, both
+// user:secret@host URLs and DSNs and password=secret settings, so s can be
+// shown or logged
+func RedactDSN(s string) string {
+	s = userinfoPassword.ReplaceAllString(s, "${1}"+RedactedPassword+"@")
+	return settingPassword.ReplaceAllString(s, "${1}"+RedactedPassword)
+}
+
+// minEchoedPassword is the shortest password hidden wherever it appears in
+// an error; shorter ones would mask ordinary words and are only hidden
+// inside connection strings
+const minEchoedPassword = 4
+
+// redactConnectError hides password, and any connection string, in the
+// message of err. The original error stays reachable for errors.Is and As.
+func redactConnectError(err error, password string) error {
+	if err == nil {
+		return nil
+	}
+	// The password goes first: masking a connection string can leave part of
+	// it behind, and then it is no longer found whole
+	message := err.Error()
+	if len(password) >= minEchoedPassword {
+		message = strings.ReplaceAll(message, password, RedactedPassword)
+	}
+	message = RedactDSN(message)
+	if message == err.Error() {
+		return err
+	}
+	return &redactedError{message: message, err: err}
+}
+
+type redactedError struct {
+	message string
+	err     error
+}
+
+func (e *redactedError) Error() string {
+	return e.message
+}
+
+func (e *redactedError) Unwrap() error {
+	return e.err
+}
@@ -0,0 +1,227 @@
+package core
+
+import (
+	"errors"
+	"fmt"
+	"strconv"
+	"strings"
+)
+
+var (
+	// ErrCannotRefine is returned for queries RefineQuery cannot add a condition to
+	ErrCannotRefine = errors.New("can't refine this query")
+	// ErrFilterValue is returned for a filter whose operator lacks the values it compares with
+	ErrFilterValue = errors.New("the filter needs a value")
+)
+
+// FilterOp is how a Filter compares a column
+type FilterOp string
+
+// Filter operators. The NULL tests take no value and IN takes any number.
+const (
+	FilterEqual        FilterOp = "="
+	FilterNotEqual     FilterOp = "!="
+	FilterGreater      FilterOp = ">"
+	FilterLess         FilterOp = "<"
+	FilterGreaterEqual FilterOp = ">="
+	FilterLessEqual    FilterOp = "<="
+	FilterLike         FilterOp = "like"
+	FilterIsNull       FilterOp = "null"
+	FilterNotNull      FilterOp = "notnull"
+	FilterIn           FilterOp = "in"
+)
+
+// FilterOps lists the operators in the order they are offered
+var FilterOps = []FilterOp{
+	FilterEqual, FilterNotEqual, FilterGreater, FilterLess, FilterGreaterEqual, FilterLessEqual,
+	FilterLike, FilterIsNull, FilterNotNull, FilterIn,
+}
+
+// ParseFilterOp checks an operator name, ignoring case
+func ParseFilterOp(name string) (FilterOp, bool) {
+	if name == "<>" {
+		return FilterNotEqual, true
+	}
+	for _, op := range FilterOps {
+		if strings.EqualFold(name, string(op)) {
+			return op, true
+		}
+	}
+	return "", false
+}
+
+// Filter is a condition on one column of a result
+type Filter struct {
+	Column string
+	Op     FilterOp
+	Values []Value
+}
+
+// Condition writes the filter as SQL for dbType. It is NULL-safe: = NULL
+// becomes IS NULL, != keeps the rows where the column is NULL, and IN
+// matches NULL when NULL is one of its values.
+func (f Filter) Condition(dbType DatabaseType) (string, error) {
+	column := QuoteIdentifier(dbType, f.Column)
+	switch f.Op {
+	case FilterIsNull:
+		return column + " IS NULL", nil
+	case FilterNotNull:
+		return column + " IS NOT NULL", nil
+	case FilterIn:
+		return inCondition(dbType, column, f.Values)
+	}
+
+	if len(f.Values) != 1 {
+		return "", fmt.Errorf("%w: %s takes one", ErrFilterValue, f.Op)
+	}
+	value := f.Values[0]
+	switch {
+	case f.Op == FilterEqual && value.IsNull():
+		return column + " IS NULL", nil
+	case f.Op == FilterNotEqual && value.IsNull():
+		return column + " IS NOT NULL", nil
+	case value.IsNull():
+		return "", fmt.Errorf("%w: %s cannot compare with NULL", ErrFilterValue, f.Op)
+	case f.Op == FilterNotEqual:
+		return fmt.Sprintf("(%s <> %s OR %s IS NULL)", column, filterLiteral(dbType, value), column), nil
+	case f.Op == FilterLike:
+		return fmt.Sprintf("%s LIKE %s", column, filterLiteral(dbType, value)), nil
+	}
+	return fmt.Sprintf("%s %s %s", column, f.Op, filterLiteral(dbType, value)), nil
+}
+
+// inCondition matches column against the distinct values, and NULL when
+// one of them is NULL
+func inCondition(dbType DatabaseType, column string, values []Value) (string, error) {
+	var literals []string
+	seen := make(map[string]bool)
+	hasNull := false
+	for _, value := range values {
+		if value.IsNull() {
+			hasNull = true
+			continue
+		}
+		literal := filterLiteral(dbType, value)
+		if !seen[literal] {
+			seen[literal] = true
+			literals = append(literals, literal)
+		}
+	}
+
+	switch {
+	case len(literals) == 0 && !hasNull:
+		return "", fmt.Errorf("%w: IN takes at least one", ErrFilterValue)
+	case len(literals) == 0:
+		return column + " IS NULL", nil
+	}
+	in := fmt.Sprintf("%s IN (%s)", column, strings.Join(literals, ", "))
+	if hasNull {
+		return fmt.Sprintf("(%s OR %s IS NULL)", in, column), nil
+	}
+	return in, nil
+}
+
+// filterLiteral writes a value as a literal; decimals keep their digits
+func filterLiteral(dbType DatabaseType, v Value) string {
+	if d, ok := v.(DecimalValue); ok {
+		return d.Value
+	}
+	return sqlLiteral(dbType, sqlArg(v))
+}
+
+// ParseFilterValue reads a value typed for a filter on a column holding
+// values like sample: NULL, a number or boolean when the column holds
+// those, or else text. Text in single quotes is always text.
+func ParseFilterValue(text string, sample Value) Value {
+	if len(text) >= 2 && text[0] == '\'' && text[len(text)-1] == '\'' {
+		return StringValue{Value: strings.ReplaceAll(text[1:len(text)-1], "''", "'")}
+	}
+	if strings.EqualFold(text, "NULL") {
+		return NullValue{}
+	}
+	switch sample.(type) {
+	case IntValue, FloatValue, DecimalValue:
+		// Only plain decimals go into the SQL unquoted; ParseFloat would also
+		// take inf, nan, 0x1p-2 or 1_000
+		if plainNumber.MatchString(text) {
+			return DecimalValue{Value: text}
+		}
+	case BoolValue:
+		if b, err := strconv.ParseBool(text); err == nil {
+			return BoolValue{Value: b}
+		}
+	}
+	return StringValue{Value: text}
+}
+
+// refineClauses are the clauses of a SELECT that come after WHERE
+var refineClauses = []string{"GROUP", "HAVING", "WINDOW", "ORDER", "LIMIT", "OFFSET", "FETCH", "FOR"}
+
+// RefineQuery adds condition to the WHERE clause of a single-table SELECT,
+// ANDed with a condition already there, and returns the new query. The query
+// is read with the lexer, so GROUP BY, ORDER BY and LIMIT stay after the
+// WHERE clause and nothing in strings or comments is taken for a clause.
+// Queries with joins, unions, CTEs or a subquery in FROM are not refined.
+func RefineQuery(dbType DatabaseType, query, condition string) (string, error) {
+	statements := SplitStatements(dbType, query)
+	if len(statements) != 1 {
+		return "", ErrCannotRefine
+	}
+	statement := statements[0]
+	tokens := sqlTokenSpans(dbType, statement)
+	if len(tokens) == 0 || !strings.EqualFold(tokens[0].text, "SELECT") {
+		return "", ErrCannotRefine
+	}
+
+	from, where, clause := -1, -1, -1
+	depth := 0
+	for i, token := range tokens {
+		switch token.text {
+		case "(":
+			depth++
+		case ")":
+			depth--
+		}
+		if depth > 0 || token.text == ")" {
+			continue
+		}
+
+		keyword := strings.ToUpper(token.text)
+		switch {
+		case containsFold(compoundKeywords, keyword), keyword == "JOIN", keyword == "INTO":
+			return "", ErrCannotRefine
+		case keyword == "FROM" && from < 0:
+			from = i
+		case from < 0:
+		case token.text == "," && where < 0 && clause < 0:
+			// FROM a, b
+			return "", ErrCannotRefine
+		case keyword == "WHERE" && where < 0 && clause < 0:
+			where = i
+		case containsFold(refineClauses, keyword) && clause < 0:
+			clause = i
+		}
+	}
+	if from < 0 || from+1 >= len(tokens) || tokens[from+1].text == "(" || depth != 0 {
+		return "", ErrCannotRefine
+	}
+
+	if where >= 0 {
+		last := len(tokens) - 1
+		if clause >= 0 {
+			last = clause - 1
+		}
+		if last <= where {
+			return "", ErrCannotRefine
+		}
+		start, end := tokens[where+1].start, tokens[last].end
+		return statement[:start] + "(" + statement[start:end] + ") AND " + condition + statement[end:], nil
+	}
+
+	last := len(tokens) - 1
+	if clause >= 0 {
+		last = clause - 1
+	}
+	end := tokens[last].end
+	return statement[:end] + " WHERE " + condition + statement[end:], nil
+}
@@ -0,0 +1,153 @@
+package core
+
+import (
+	"errors"
+	"testing"
+)
+
+func TestRefineQuery(t *testing.T) {
+	const condition = `"status" = 'paid'`
+	tests := []struct {
+		name     string
+		query    string
+		expected string
+	}{
+		{"no WHERE", "SELECT * FROM orders", `SELECT * FROM orders WHERE "status" = 'paid'`},
+		{"semicolon", "SELECT * FROM orders;", `SELECT * FROM orders WHERE "status" = 'paid'`},
+		{"alias", "SELECT o.id FROM orders o", `SELECT o.id FROM orders o WHERE "status" = 'paid'`},
+		{"existing WHERE", "SELECT * FROM orders WHERE total > 10 OR total IS NULL",
+			`SELECT * FROM orders WHERE (total > 10 OR total IS NULL) AND "status" = 'paid'`},
+		{"WHERE before ORDER BY and LIMIT", "SELECT * FROM orders WHERE id > 5 ORDER BY id DESC LIMIT 10",
+			`SELECT * FROM orders WHERE (id > 5) AND "status" = 'paid' ORDER BY id DESC LIMIT 10`},
+		{"ORDER BY", "SELECT * FROM orders ORDER BY id", `SELECT * FROM orders WHERE "status" = 'paid' ORDER BY id`},
+		{"auto LIMIT on its own line", "SELECT * FROM orders\nLIMIT 1000", "SELECT * FROM orders WHERE \"status\" = 'paid'\nLIMIT 1000"},
+		{"GROUP BY", "SELECT status, COUNT(*) FROM orders GROUP BY status HAVING COUNT(*) > 1",
+			`SELECT status, COUNT(*) FROM orders WHERE "status" = 'paid' GROUP BY status HAVING COUNT(*) > 1`},
+		{"WHERE and GROUP BY", "SELECT status, COUNT(*) FROM orders WHERE id > 5 GROUP BY status",
+			`SELECT status, COUNT(*) FROM orders WHERE (id > 5) AND "status" = 'paid' GROUP BY status`},
+		{"keywords in strings and subqueries", "SELECT * FROM orders WHERE note = 'ORDER BY x' AND id IN (SELECT id FROM refunds LIMIT 5)",
+			`SELECT * FROM orders WHERE (note = 'ORDER BY x' AND id IN (SELECT id FROM refunds LIMIT 5)) AND "status" = 'paid'`},
+		{"FROM inside a function", "SELECT EXTRACT(YEAR FROM created) FROM orders LIMIT 3",
+			`SELECT EXTRACT(YEAR FROM created) FROM orders WHERE "status" = 'paid' LIMIT 3`},
+		{"trailing comment", "SELECT * FROM orders WHERE id > 5 -- recent",
+			`SELECT * FROM orders WHERE (id > 5) AND "status" = 'paid' -- recent`},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got, err := RefineQuery(PostgreSQL, tt.query, condition)
+			if err != nil {
+				t.Fatalf("Unexpected error: %v", err)
+			}
+			if got != tt.expected {
+				t.Errorf("Expected:\n%s\ngot:\n%s", tt.expected, got)
+			}
+		})
+	}
+}
+
+func TestRefineQuery_CannotRefine(t *testing.T) {
+	queries := []string{
+		"SELECT * FROM orders o JOIN users u ON u.id = o.user_id",
+		"SELECT * FROM orders, users",
+		"SELECT id FROM orders UNION SELECT id FROM refunds",
+		"SELECT * FROM (SELECT * FROM orders) o",
+		"WITH recent AS (SELECT * FROM orders) SELECT * FROM recent",
+		"SELECT 1",
+		"UPDATE orders SET status = 'paid'",
+		"SELECT * FROM orders; SELECT * FROM users",
+		"SELECT * FROM orders WHERE",
+	}
+	for _, query := range queries {
+		if got, err := RefineQuery(PostgreSQL, query, "id = 1"); !errors.Is(err, ErrCannotRefine) {
+			t.Errorf("Expected %q not to be refined, got %q, %v", query, got, err)
+		}
+	}
+}
+
+func TestFilter_Condition(t *testing.T) {
+	tests := []struct {
+		name     string
+		filter   Filter
+		dbType   DatabaseType
+		expected string
+	}{
+		{"equal", Filter{"status", FilterEqual, []Value{StringValue{Value: "it's"}}}, PostgreSQL, `"status" = 'it''s'`},
+		{"equal NULL", Filter{"status", FilterEqual, []Value{NullValue{}}}, PostgreSQL, `"status" IS NULL`},
+		{"not equal keeps NULLs", Filter{"total", FilterNotEqual, []Value{DecimalValue{Value: "19.90"}}}, MySQL, "(`total` <> 19.90 OR `total` IS NULL)"},
+		{"not equal NULL", Filter{"total", FilterNotEqual, []Value{StringValue{Null: true}}}, MySQL, "`total` IS NOT NULL"},
+		{"greater", Filter{"id", FilterGreater, []Value{IntValue{Value: 5}}}, SQLite, `"id" > 5`},
+		{"like", Filter{"name", FilterLike, []Value{StringValue{Value: "Ad%"}}}, SQLite, `"name" LIKE 'Ad%'`},
+		{"is null", Filter{"name", FilterIsNull, nil}, SQLite, `"name" IS NULL`},
+		{"not null", Filter{"name", FilterNotNull, nil}, SQLite, `"name" IS NOT NULL`},
+		{"in", Filter{"id", FilterIn, []Value{IntValue{Value: 1}, IntValue{Value: 3}, IntValue{Value: 1}}}, SQLite, `"id" IN (1, 3)`},
+		{"in with NULL", Filter{"id", FilterIn, []Value{IntValue{Value: 1}, IntValue{Null: true}}}, SQLite, `("id" IN (1) OR "id" IS NULL)`},
+		{"in only NULL", Filter{"id", FilterIn, []Value{NullValue{}}}, SQLite, `"id" IS NULL`},
+		{"bytes", Filter{"hash", FilterEqual, []Value{BytesValue{Value: []byte{0xde, 0xad}}}}, MySQL, "`hash` = X'dead'"},
+		{"bytes as bytea", Filter{"hash", FilterEqual, []Value{BytesValue{Value: []byte{0xde, 0xad}}}}, PostgreSQL, `"hash" = '\xdead'::bytea`},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got, err := tt.filter.Condition(tt.dbType)
+			if err != nil {
+				t.Fatalf("Unexpected error: %v", err)
+			}
+			if got != tt.expected {
+				t.Errorf("Expected %s, got %s", tt.expected, got)
+			}
+		})
+	}
+
+	for _, filter := range []Filter{
+		{"id", FilterGreater, []Value{NullValue{}}},
+		{"id", FilterEqual, nil},
+		{"id", FilterIn, nil},
+	} {
+		if got, err := filter.Condition(SQLite); !errors.Is(err, ErrFilterValue) {
+			t.Errorf("Expected %+v to need a value, got %q, %v", filter, got, err)
+		}
+	}
+}
+
+func TestParseFilterValue(t *testing.T) {
+	tests := []struct {
+		name     string
+		text     string
+		sample   Value
+		expected Value
+	}{
+		{"number in a number column", "42", IntValue{Value: 1}, DecimalValue{Value: "42"}},
+		{"text in a number column", "abc", IntValue{Value: 1}, StringValue{Value: "abc"}},
+		{"exponent", "-1.5e3", FloatValue{Value: 1}, DecimalValue{Value: "-1.5e3"}},
+		{"infinity is text", "inf", FloatValue{Value: 1}, StringValue{Value: "inf"}},
+		{"NaN is text", "NaN", DecimalValue{Value: "1"}, StringValue{Value: "NaN"}},
+		{"hex float is text", "0x1p-2", FloatValue{Value: 1}, StringValue{Value: "0x1p-2"}},
+		{"underscores are text", "1_000", IntValue{Value: 1}, StringValue{Value: "1_000"}},
+		{"number in a text column", "42", StringValue{Value: "x"}, StringValue{Value: "42"}},
+		{"boolean", "true", BoolValue{}, BoolValue{Value: true}},
+		{"NULL", "null", StringValue{Value: "x"}, NullValue{}},
+		{"quoted", "'NULL'", StringValue{Value: "x"}, StringValue{Value: "NULL"}},
+		{"quoted number", "'42'", IntValue{Value: 1}, StringValue{Value: "42"}},
+		{"no sample", "42", nil, StringValue{Value: "42"}},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := ParseFilterValue(tt.text, tt.sample); got != tt.expected {
+				t.Errorf("Expected %#v, got %#v", tt.expected, got)
+			}
+		})
+	}
+}
+
+func TestParseFilterOp(t *testing.T) {
+	for name, expected := range map[string]FilterOp{"=": FilterEqual, "<>": FilterNotEqual, "LIKE": FilterLike, "notnull": FilterNotNull, "In": FilterIn} {
+		if got, ok := ParseFilterOp(name); !ok || got != expected {
+			t.Errorf("ParseFilterOp(%q) = %q, %v, expected %q", name, got, ok, expected)
+		}
+	}
+	if _, ok := ParseFilterOp("~"); ok {
+		t.Error("Expected an unknown operator to be rejected")
+	}
+}
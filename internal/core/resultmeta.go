@@ -0,0 +1,114 @@
+package core
+
+import (
+	"fmt"
+	"strconv"
+	"strings"
+)
+
+// sizedTypes are the column types whose declared size DescribeResultColumns looks for
+var sizedTypes = []string{"CHAR", "VARCHAR", "BPCHAR", "NCHAR", "NVARCHAR", "BINARY", "VARBINARY", "DECIMAL", "NUMERIC"}
+
+// DescribeResultColumns fills in the nullability and sizes the driver left
+// out of columns, from the table a single-table SELECT reads. The table is
+// only described when some column is still missing something, so describe
+// may be a plain DescribeTable or a cached lookup. Columns are matched to
+// table columns by name; expressions and other queries keep what the driver
+// reported.
+func DescribeResultColumns(columns []Column, dbType DatabaseType, query string, describe func(table string) (*TableInfo, error)) {
+	incomplete := false
+	for _, column := range columns {
+		incomplete = incomplete || column.Nullable == nil || missingSize(column)
+	}
+	if !incomplete {
+		return
+	}
+	table, err := SourceTable(dbType, query)
+	if err != nil {
+		return
+	}
+	info, err := describe(table)
+	if err != nil || info == nil {
+		return
+	}
+
+	declared := make(map[string]ColumnInfo, len(info.Columns))
+	for _, column := range info.Columns {
+		declared[strings.ToLower(column.Name)] = column
+	}
+	for i := range columns {
+		detail, ok := declared[strings.ToLower(columns[i].Name)]
+		if !ok {
+			continue
+		}
+		if columns[i].Nullable == nil {
+			nullable := detail.Nullable
+			columns[i].Nullable = &nullable
+		}
+		if columns[i].Length != 0 || columns[i].Precision != 0 || !containsFold(sizedTypes, baseType(detail.Type)) {
+			continue
+		}
+		size, scale := declaredSize(detail.Type)
+		if isDecimalType(baseType(detail.Type)) {
+			columns[i].Precision, columns[i].Scale = size, scale
+		} else {
+			columns[i].Length = size
+		}
+	}
+}
+
+// TypeLabel renders the column's type with its size when known, e.g.
+// VARCHAR(100) or NUMERIC(10,2)
+func (c Column) TypeLabel() string {
+	switch {
+	case strings.Contains(c.Type, "("):
+		// SQLite reports the declared type, size included
+		return c.Type
+	case c.Precision > 0:
+		return fmt.Sprintf("%s(%d,%d)", c.Type, c.Precision, c.Scale)
+	case c.Length > 0:
+		return fmt.Sprintf("%s(%d)", c.Type, c.Length)
+	}
+	return c.Type
+}
+
+// missingSize reports whether a column of a sized type has no size yet.
+// SQLite reports no type for expressions, and its declared types carry the
+// size, so columns without a type count as missing one too.
+func missingSize(column Column) bool {
+	if column.Length != 0 || column.Precision != 0 {
+		return false
+	}
+	return column.Type == "" || containsFold(sizedTypes, baseType(column.Type))
+}
+
+// declaredSize reads the size of a declared type such as VARCHAR(255) or
+// DECIMAL(10,2); it is 0, 0 when the type has none
+func declaredSize(declared string) (size, scale int64) {
+	open := strings.IndexByte(declared, '(')
+	end := strings.IndexByte(declared, ')')
+	if open < 0 || end < open {
+		return 0, 0
+	}
+	parts := strings.Split(declared[open+1:end], ",")
+	size, err := strconv.ParseInt(strings.TrimSpace(parts[0]), 10, 64)
+	if err != nil {
+		return 0, 0
+	}
+	if len(parts) > 1 {
+		scale, _ = strconv.ParseInt(strings.TrimSpace(parts[1]), 10, 64)
+	}
+	return size, scale
+}
+
+// baseType returns a declared type without its size, e.g. VARCHAR for varchar(255)
+func baseType(declared string) string {
+	if open := strings.IndexByte(declared, '('); open >= 0 {
+		declared = declared[:open]
+	}
+	return strings.ToUpper(strings.TrimSpace(declared))
+}
+
+func isDecimalType(columnType string) bool {
+	return columnType == "DECIMAL" || columnType == "NUMERIC"
+}
@@ -0,0 +1,42 @@
+//go:build !no_sqlite
+
+package core
+
+import (
+	"path/filepath"
+	"testing"
+)
+
+func TestSQLiteResultColumnNullability(t *testing.T) {
+	conn, err := NewConnection(&ConnectionConfig{Name: "meta", DatabaseType: SQLite, Database: filepath.Join(t.TempDir(), "app.db")})
+	if err != nil {
+		t.Fatalf("Failed to open SQLite: %v", err)
+	}
+	defer conn.Close()
+
+	execAndDrain(t, conn, "CREATE TABLE users (id INTEGER PRIMARY KEY, email VARCHAR(100) NOT NULL, note TEXT)")
+
+	info, err := conn.DescribeTable("users")
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if info.Columns[1].Nullable || !info.Columns[2].Nullable {
+		t.Errorf("Expected email NOT NULL and note nullable, got %+v", info.Columns)
+	}
+
+	query := "SELECT email, note FROM users"
+	result, err := conn.Execute(query)
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	columns := result.Columns
+	result.Close()
+
+	DescribeResultColumns(columns, SQLite, query, conn.DescribeTable)
+	if columns[0].Nullable == nil || *columns[0].Nullable || columns[0].Length != 100 {
+		t.Errorf("Expected email to round-trip as NOT NULL with length 100, got %+v", columns[0])
+	}
+	if columns[1].Nullable == nil || !*columns[1].Nullable {
+		t.Errorf("Expected note to round-trip as nullable, got %+v", columns[1])
+	}
+}
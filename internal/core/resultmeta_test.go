@@ -0,0 +1,78 @@
+package core
+
+import (
+	"errors"
+	"testing"
+)
+
+func TestDescribeResultColumns(t *testing.T) {
+	info := &TableInfo{Name: "users", Columns: []ColumnInfo{
+		{Name: "id", Type: "int(11)"},
+		{Name: "email", Type: "varchar(255)"},
+		{Name: "balance", Type: "decimal(10,2)", Nullable: true},
+	}}
+	described := 0
+	describe := func(table string) (*TableInfo, error) {
+		described++
+		if table != "users" {
+			return nil, errors.New("unknown table")
+		}
+		return info, nil
+	}
+
+	columns := []Column{{Name: "id", Type: "INT"}, {Name: "EMAIL", Type: "VARCHAR"}, {Name: "balance", Type: "DECIMAL"}, {Name: "total"}}
+	DescribeResultColumns(columns, PostgreSQL, "SELECT id, email, balance, 1 AS total FROM users", describe)
+
+	if columns[0].Nullable == nil || *columns[0].Nullable || columns[0].Length != 0 {
+		t.Errorf("Expected id to be NOT NULL without a length, got %+v", columns[0])
+	}
+	if columns[1].Nullable == nil || *columns[1].Nullable || columns[1].Length != 255 {
+		t.Errorf("Expected email to be NOT NULL with length 255, got %+v", columns[1])
+	}
+	if columns[2].Nullable == nil || !*columns[2].Nullable || columns[2].Precision != 10 || columns[2].Scale != 2 {
+		t.Errorf("Expected balance to be nullable DECIMAL(10,2), got %+v", columns[2])
+	}
+	if columns[3].Nullable != nil {
+		t.Errorf("Expected an expression to stay unknown, got %+v", columns[3])
+	}
+
+	// Nothing is looked up when the driver said everything, or for joins
+	notNull := false
+	complete := []Column{{Name: "id", Type: "INT", Nullable: &notNull}}
+	DescribeResultColumns(complete, PostgreSQL, "SELECT id FROM users", describe)
+	DescribeResultColumns([]Column{{Name: "id"}}, PostgreSQL, "SELECT u.id FROM users u JOIN orders o ON o.user_id = u.id", describe)
+	if described != 1 {
+		t.Errorf("Expected one table lookup, got %d", described)
+	}
+}
+
+func TestColumn_TypeLabel(t *testing.T) {
+	testCases := map[string]Column{
+		"VARCHAR(100)":  {Type: "VARCHAR", Length: 100},
+		"NUMERIC(12,4)": {Type: "NUMERIC", Precision: 12, Scale: 4},
+		"INT":           {Type: "INT"},
+		"VARCHAR(20)":   {Type: "VARCHAR(20)", Length: 20},
+	}
+	for expected, column := range testCases {
+		if got := column.TypeLabel(); got != expected {
+			t.Errorf("TypeLabel(%+v) = %q, expected %q", column, got, expected)
+		}
+	}
+}
+
+func TestDeclaredSize(t *testing.T) {
+	testCases := []struct {
+		declared    string
+		size, scale int64
+	}{
+		{"varchar(255)", 255, 0},
+		{"DECIMAL(10, 2)", 10, 2},
+		{"character varying", 0, 0},
+		{"enum('a','b')", 0, 0},
+	}
+	for _, tc := range testCases {
+		if size, scale := declaredSize(tc.declared); size != tc.size || scale != tc.scale {
+			t.Errorf("declaredSize(%q) = %d, %d, expected %d, %d", tc.declared, size, scale, tc.size, tc.scale)
+		}
+	}
+}
@@ -0,0 +1,226 @@
+package core
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"slices"
+
+	"gopkg.in/yaml.v3"
+)
+
+const (
+	// DefaultResultCapacity is how many results keep their rows in a ResultStore
+	DefaultResultCapacity = 10
+	// DefaultResultMemory is how many bytes the kept rows of a ResultStore may take
+	DefaultResultMemory int64 = 256 << 20
+	// MaxResultEntries bounds how many numbered queries a ResultStore remembers
+	MaxResultEntries = 200
+)
+
+// ResultEntry is one numbered query of a session
+type ResultEntry struct {
+	Number int    `yaml:"number"`
+	Query  string `yaml:"query"`
+	// Redacted reports that Query had its literals replaced when it was saved
+	Redacted   bool         `yaml:"redacted,omitempty"`
+	Source     ResultSource `yaml:"source"`
+	Rows       int          `yaml:"rows"`
+	Incomplete bool         `yaml:"incomplete,omitempty"`
+	Timing     QueryTiming  `yaml:"timing,omitempty"`
+	// GeneratedBy is the number of the result whose cells the query came from, e.g. by /gexec
+	GeneratedBy int `yaml:"generated_by,omitempty"`
+	// Notices are the warnings and notices the server sent about the query
+	Notices []Notice `yaml:"notices,omitempty"`
+	// Result holds the rows; it is nil once evicted and for entries read from disk
+	Result *StoredResult `yaml:"-"`
+
+	// size is the estimated memory of Result's rows
+	size int64
+}
+
+// ResultStore numbers the results of a session and keeps the rows of the
+// most recently used ones, as many as fit in its capacity and memory budget.
+// Older results lose their rows but keep their number, query and source, so
+// they can still be run again. A nil *ResultStore numbers nothing.
+type ResultStore struct {
+	path     string
+	capacity int
+	budget   int64
+	entries  []*ResultEntry
+	// used holds the numbers of entries with rows, least recently used first
+	used []int
+	last int
+}
+
+// NewResultStore returns an empty store keeping the rows of capacity results
+// within DefaultResultMemory
+func NewResultStore(capacity int) *ResultStore {
+	return &ResultStore{capacity: max(capacity, 1), budget: DefaultResultMemory}
+}
+
+// SetMemoryBudget caps the estimated memory of the kept rows at bytes,
+// dropping the rows of the least recently used results that no longer fit.
+// A budget of 0 or less restores DefaultResultMemory.
+func (s *ResultStore) SetMemoryBudget(bytes int64) {
+	if s == nil {
+		return
+	}
+	if bytes <= 0 {
+		bytes = DefaultResultMemory
+	}
+	s.budget = bytes
+	s.evict()
+}
+
+// KeptSize is the estimated memory of the rows the store keeps
+func (s *ResultStore) KeptSize() int64 {
+	if s == nil {
+		return 0
+	}
+	var size int64
+	for _, number := range s.used {
+		if entry := s.find(number); entry != nil {
+			size += entry.size
+		}
+	}
+	return size
+}
+
+// LoadResultStore reads the entries saved at path, so numbering carries on
+// where the previous session stopped. A missing file starts at #1, and a
+// corrupt one is set aside and reported.
+func LoadResultStore(path string, capacity int) *ResultStore {
+	s := NewResultStore(capacity)
+	s.path = path
+
+	data, err := os.ReadFile(path)
+	if err != nil {
+		if !os.IsNotExist(err) {
+			WarnFile(path, err, false)
+		}
+		return s
+	}
+	var saved struct {
+		Last    int            `yaml:"last"`
+		Entries []*ResultEntry `yaml:"entries"`
+	}
+	if err := yaml.Unmarshal(data, &saved); err != nil {
+		WarnFile(path, err, true)
+		return s
+	}
+	s.last, s.entries = saved.Last, saved.Entries
+	for _, entry := range s.entries {
+		s.last = max(s.last, entry.Number)
+	}
+	return s
+}
+
+// Add numbers a result of query and keeps its rows, evicting the rows of the
+// least recently used results when the store is full or over its budget
+func (s *ResultStore) Add(query string, stored *StoredResult) int {
+	if s == nil {
+		return 0
+	}
+	s.last++
+	s.entries = append(s.entries, &ResultEntry{
+		Number:      s.last,
+		Query:       query,
+		Source:      stored.Source,
+		Rows:        len(stored.Rows),
+		Incomplete:  stored.Incomplete,
+		Timing:      stored.Timing,
+		GeneratedBy: stored.GeneratedBy,
+		Notices:     stored.Notices,
+		Result:      stored,
+		size:        stored.EstimatedSize(),
+	})
+	if len(s.entries) > MaxResultEntries {
+		dropped := s.entries[0]
+		s.entries = s.entries[1:]
+		s.used = slices.DeleteFunc(s.used, func(n int) bool { return n == dropped.Number })
+	}
+
+	s.touch(s.last)
+	s.evict()
+	return s.last
+}
+
+// Get returns the entry numbered number, marking its rows as recently used
+func (s *ResultStore) Get(number int) (*ResultEntry, bool) {
+	if s == nil {
+		return nil, false
+	}
+	entry := s.find(number)
+	if entry == nil {
+		return nil, false
+	}
+	if entry.Result != nil {
+		s.touch(number)
+	}
+	return entry, true
+}
+
+// Entries returns the remembered entries, oldest first
+func (s *ResultStore) Entries() []*ResultEntry {
+	if s == nil {
+		return nil
+	}
+	return slices.Clone(s.entries)
+}
+
+// Save writes the entries without their rows to the file the store was
+// loaded from. With redact set, queries are saved with their literals
+// replaced, and cannot be run again after a restart.
+func (s *ResultStore) Save(redact bool) error {
+	if s == nil || s.path == "" {
+		return nil
+	}
+	saved := struct {
+		Last    int           `yaml:"last"`
+		Entries []ResultEntry `yaml:"entries"`
+	}{Last: s.last}
+	for _, entry := range s.entries {
+		copied := *entry
+		if redact && !copied.Redacted {
+			// Servers quote the values a statement used in their warnings, so those go too
+			copied.Query, copied.Redacted, copied.Notices = RedactLiterals(copied.Source.DatabaseType, copied.Query), true, nil
+		}
+		saved.Entries = append(saved.Entries, copied)
+	}
+
+	data, err := yaml.Marshal(saved)
+	if err != nil {
+		return fmt.Errorf("failed to marshal result history: %w", err)
+	}
+	if err := os.MkdirAll(filepath.Dir(s.path), 0755); err != nil {
+		return err
+	}
+	return os.WriteFile(s.path, data, 0600)
+}
+
+func (s *ResultStore) find(number int) *ResultEntry {
+	for _, entry := range s.entries {
+		if entry.Number == number {
+			return entry
+		}
+	}
+	return nil
+}
+
+// evict drops the rows of the least recently used results until no more
+// than capacity keep theirs and those fit in the budget. The most recently
+// used result keeps its rows even when they alone are over the budget.
+func (s *ResultStore) evict() {
+	for len(s.used) > 1 && (len(s.used) > s.capacity || s.KeptSize() > s.budget) {
+		if entry := s.find(s.used[0]); entry != nil {
+			entry.Result = nil
+		}
+		s.used = s.used[1:]
+	}
+}
+
+// touch moves number to the most recently used end of the LRU order
+func (s *ResultStore) touch(number int) {
+	s.used = append(slices.DeleteFunc(s.used, func(n int) bool { return n == number }), number)
+}
@@ -0,0 +1,177 @@
+package core
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+	"time"
+)
+
+func storedRows(n int) *StoredResult {
+	stored := &StoredResult{Columns: []Column{{Name: "n"}}, Source: ResultSource{Connection: "local", Time: time.Now()}}
+	for i := range n {
+		stored.Rows = append(stored.Rows, []Value{IntValue{Value: int64(i)}})
+	}
+	return stored
+}
+
+func TestResultStore_EvictsLeastRecentlyUsedRows(t *testing.T) {
+	s := NewResultStore(2)
+	for i := 1; i <= 3; i++ {
+		if number := s.Add("SELECT "+strings.Repeat("1", i), storedRows(i)); number != i {
+			t.Fatalf("Expected result #%d, got #%d", i, number)
+		}
+	}
+
+	first, ok := s.Get(1)
+	if !ok || first.Result != nil || first.Rows != 1 || first.Query != "SELECT 1" {
+		t.Errorf("Expected #1 to keep its metadata but not its rows, got %+v", first)
+	}
+
+	// Reading #2 makes #3 the least recently used
+	if second, _ := s.Get(2); second.Result == nil {
+		t.Fatal("Expected #2 to keep its rows")
+	}
+	s.Add("SELECT 4", storedRows(4))
+	if third, _ := s.Get(3); third.Result != nil {
+		t.Error("Expected #3 to lose its rows once #2 was used more recently")
+	}
+	if second, _ := s.Get(2); second.Result == nil {
+		t.Error("Expected #2 to keep its rows")
+	}
+	if _, ok := s.Get(9); ok {
+		t.Error("Expected no entry for an unknown number")
+	}
+}
+
+// wideRows is a result of n rows of one text column width bytes wide
+func wideRows(n, width int) *StoredResult {
+	stored := &StoredResult{Columns: []Column{{Name: "body"}}}
+	for range n {
+		stored.Rows = append(stored.Rows, []Value{StringValue{Value: strings.Repeat("x", width)}})
+	}
+	return stored
+}
+
+func TestStoredResult_EstimatedSize(t *testing.T) {
+	if size := (&StoredResult{}).EstimatedSize(); size != 0 {
+		t.Errorf("Expected an empty result to take nothing, got %d", size)
+	}
+
+	small, large := wideRows(100, 1000).EstimatedSize(), wideRows(10000, 1000).EstimatedSize()
+	if small < 100*1000 || small > 2*100*1000 {
+		t.Errorf("Expected 100 rows of 1000 bytes to be near 100 kB, got %d", small)
+	}
+	if large != 100*small {
+		t.Errorf("Expected the estimate to grow with the rows, got %d for 100 times %d", large, small)
+	}
+}
+
+func TestResultStore_MemoryBudget(t *testing.T) {
+	const rowBytes = 10000
+	s := NewResultStore(DefaultResultCapacity)
+	resultSize := wideRows(100, rowBytes).EstimatedSize()
+	s.SetMemoryBudget(2*resultSize + resultSize/2)
+
+	for range 5 {
+		s.Add("SELECT body FROM pages", wideRows(100, rowBytes))
+		if kept := s.KeptSize(); kept > 2*resultSize+resultSize/2 {
+			t.Fatalf("Expected the kept rows to stay within the budget, got %d", kept)
+		}
+	}
+	for number := 1; number <= 5; number++ {
+		entry, _ := s.Get(number)
+		if kept := entry.Result != nil; kept != (number >= 4) {
+			t.Errorf("Expected only the two latest results to keep their rows, #%d kept: %v", number, kept)
+		}
+		if entry.Rows != 100 || entry.Query == "" {
+			t.Errorf("Expected #%d to keep its metadata, got %+v", number, entry)
+		}
+	}
+
+	// A result over the budget by itself still keeps its rows while it is the latest
+	s.Add("SELECT body FROM archive", wideRows(1000, rowBytes))
+	if latest, _ := s.Get(6); latest.Result == nil {
+		t.Error("Expected the latest result to keep its rows")
+	}
+	if kept, _ := s.Get(5); kept.Result != nil {
+		t.Error("Expected older results to lose their rows to make room")
+	}
+
+	// Lowering the budget evicts at once
+	s.SetMemoryBudget(1)
+	s.Add("SELECT 1", storedRows(1))
+	if previous, _ := s.Get(6); previous.Result != nil {
+		t.Error("Expected a lower budget to drop the rows that no longer fit")
+	}
+}
+
+func TestResultStore_BoundsEntries(t *testing.T) {
+	s := NewResultStore(DefaultResultCapacity)
+	for range MaxResultEntries + 5 {
+		s.Add("SELECT 1", storedRows(1))
+	}
+	entries := s.Entries()
+	if len(entries) != MaxResultEntries || entries[0].Number != 6 {
+		t.Errorf("Expected the oldest entries to be dropped, got %d starting at #%d", len(entries), entries[0].Number)
+	}
+}
+
+func TestResultStore_SaveAndLoad(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "result_history.yaml")
+	s := LoadResultStore(path, 2)
+	warned := storedRows(3)
+	warned.Notices = []Notice{{Level: "Warning", Code: "1292", Message: "Truncated incorrect DOUBLE value: 'a@example.com'"}}
+	s.Add("SELECT * FROM users WHERE email = 'a@example.com'", warned)
+	s.Add("SELECT 2", storedRows(1))
+	if err := s.Save(false); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+
+	loaded := LoadResultStore(path, 2)
+	entry, ok := loaded.Get(1)
+	if !ok || entry.Query != "SELECT * FROM users WHERE email = 'a@example.com'" || entry.Rows != 3 || entry.Source.Connection != "local" || entry.Result != nil {
+		t.Errorf("Expected #1 to be loaded without rows, got %+v", entry)
+	}
+	if len(entry.Notices) != 1 || entry.Notices[0].Code != "1292" {
+		t.Errorf("Expected #1 to keep its warning, got %+v", entry.Notices)
+	}
+	if number := loaded.Add("SELECT 3", storedRows(1)); number != 3 {
+		t.Errorf("Expected numbering to carry on at #3, got #%d", number)
+	}
+
+	if err := loaded.Save(true); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	redacted, _ := LoadResultStore(path, 2).Get(1)
+	if !redacted.Redacted || strings.Contains(redacted.Query, "a@example.com") || len(redacted.Notices) != 0 {
+		t.Errorf("Expected the saved query to be redacted, got %+v", redacted)
+	}
+}
+
+func TestLoadResultStore_CorruptFile(t *testing.T) {
+	TakeFileWarnings()
+	path := filepath.Join(t.TempDir(), "result_history.yaml")
+	if err := os.WriteFile(path, []byte("entries: [unclosed"), 0600); err != nil {
+		t.Fatal(err)
+	}
+
+	s := LoadResultStore(path, 2)
+	if number := s.Add("SELECT 1", storedRows(1)); number != 1 {
+		t.Errorf("Expected numbering to start again at #1, got #%d", number)
+	}
+	if warnings := TakeFileWarnings(); len(warnings) != 1 {
+		t.Errorf("Expected the corrupt file to be reported, got %+v", warnings)
+	}
+}
+
+func TestResultStore_Nil(t *testing.T) {
+	var s *ResultStore
+	if number := s.Add("SELECT 1", storedRows(1)); number != 0 {
+		t.Errorf("Expected a nil store to number nothing, got #%d", number)
+	}
+	if _, ok := s.Get(1); ok || s.Entries() != nil || s.Save(false) != nil {
+		t.Error("Expected a nil store to hold nothing")
+	}
+}
@@ -0,0 +1,291 @@
+package core
+
+import (
+	"database/sql"
+	"errors"
+	"fmt"
+	"strings"
+)
+
+var (
+	// ErrRoutineNotFound is returned when no procedure or function has the requested name
+	ErrRoutineNotFound = errors.New("routine not found")
+	// ErrSchemaInspectorNotSupported is returned by connections that cannot list routines and triggers
+	ErrSchemaInspectorNotSupported = errors.New("procedures, functions and triggers are not available for this connection")
+)
+
+// SchemaInspector lists the code kept in a database: stored procedures,
+// functions and triggers
+type SchemaInspector interface {
+	ListRoutines() ([]RoutineInfo, error)
+	// ListTriggers returns the triggers on table, or on every table when table is empty
+	ListTriggers(table string) ([]TriggerInfo, error)
+	// DescribeRoutine returns the routine called name with its definition
+	DescribeRoutine(name string) (*RoutineInfo, error)
+}
+
+// RoutineInfo describes a stored procedure or function
+type RoutineInfo struct {
+	Name string
+	// Kind is PROCEDURE or FUNCTION, or AGGREGATE and WINDOW on PostgreSQL
+	Kind      string
+	Arguments string
+	// Returns is empty for procedures
+	Returns string
+	// Definition is the full CREATE statement, filled in by DescribeRoutine
+	Definition string
+}
+
+// TriggerInfo describes a trigger on a table
+type TriggerInfo struct {
+	Name  string
+	Table string
+	// Timing is BEFORE, AFTER or INSTEAD OF
+	Timing string
+	// Event is INSERT, UPDATE, DELETE or TRUNCATE, or several joined with OR
+	Event      string
+	Definition string
+}
+
+// ListRoutines returns the procedures and functions of the current database,
+// or of the schemas on the search path on PostgreSQL. SQLite has none.
+func (c *SQLConnection) ListRoutines() ([]RoutineInfo, error) {
+	var query string
+	switch c.config.DatabaseType {
+	case MySQL:
+		query = `
+			SELECT r.ROUTINE_NAME, r.ROUTINE_TYPE,
+			       COALESCE((SELECT GROUP_CONCAT(CONCAT_WS(' ', p.PARAMETER_MODE, p.PARAMETER_NAME, p.DTD_IDENTIFIER)
+			                                 ORDER BY p.ORDINAL_POSITION SEPARATOR ', ')
+			                 FROM information_schema.PARAMETERS p
+			                 WHERE p.SPECIFIC_SCHEMA = r.ROUTINE_SCHEMA AND p.SPECIFIC_NAME = r.SPECIFIC_NAME
+			                   AND p.ORDINAL_POSITION > 0), ''),
+			       COALESCE(r.DTD_IDENTIFIER, '')
+			FROM information_schema.ROUTINES r
+			WHERE r.ROUTINE_SCHEMA = DATABASE()
+			ORDER BY r.ROUTINE_NAME`
+	case PostgreSQL:
+		query = `
+			SELECT p.proname,
+			       CASE p.prokind WHEN 'p' THEN 'PROCEDURE' WHEN 'a' THEN 'AGGREGATE' WHEN 'w' THEN 'WINDOW' ELSE 'FUNCTION' END,
+			       pg_get_function_arguments(p.oid),
+			       COALESCE(pg_get_function_result(p.oid), '')
+			FROM pg_proc p
+			JOIN pg_namespace n ON n.oid = p.pronamespace
+			WHERE n.nspname = ANY (current_schemas(false))
+			ORDER BY p.proname, p.oid`
+	case SQLite:
+		return nil, nil
+	default:
+		return nil, fmt.Errorf("unsupported database type: %v", c.config.DatabaseType)
+	}
+
+	rows, err := c.db.Query(query)
+	if err != nil {
+		return nil, fmt.Errorf("failed to list routines: %w", err)
+	}
+	defer rows.Close()
+
+	var routines []RoutineInfo
+	for rows.Next() {
+		var routine RoutineInfo
+		if err := rows.Scan(&routine.Name, &routine.Kind, &routine.Arguments, &routine.Returns); err != nil {
+			return nil, fmt.Errorf("failed to scan routine: %w", err)
+		}
+		routines = append(routines, routine)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, fmt.Errorf("failed to list routines: %w", err)
+	}
+	return routines, nil
+}
+
+// DescribeRoutine returns the routine called name with its CREATE statement.
+// Overloaded PostgreSQL functions are described together, one definition
+// after the other.
+func (c *SQLConnection) DescribeRoutine(name string) (*RoutineInfo, error) {
+	routines, err := c.ListRoutines()
+	if err != nil {
+		return nil, err
+	}
+
+	var found *RoutineInfo
+	var definitions []string
+	for _, routine := range routines {
+		if !strings.EqualFold(routine.Name, name) {
+			continue
+		}
+		definition, err := c.routineDefinition(routine)
+		if err != nil {
+			return nil, err
+		}
+		definitions = append(definitions, definition)
+		if found == nil {
+			found = &routine
+		}
+	}
+	if found == nil {
+		return nil, fmt.Errorf("%w: %s", ErrRoutineNotFound, name)
+	}
+	found.Definition = strings.Join(definitions, "\n\n")
+	return found, nil
+}
+
+// routineDefinition returns the CREATE statement of routine
+func (c *SQLConnection) routineDefinition(routine RoutineInfo) (string, error) {
+	switch c.config.DatabaseType {
+	case MySQL:
+		// SHOW CREATE puts the statement in its third column, after the name and sql_mode
+		rows, err := c.db.Query(fmt.Sprintf("SHOW CREATE %s %s", routine.Kind, QuoteIdentifier(MySQL, routine.Name)))
+		if err != nil {
+			return "", fmt.Errorf("failed to read routine definition: %w", err)
+		}
+		defer rows.Close()
+
+		columns, err := rows.Columns()
+		if err != nil {
+			return "", fmt.Errorf("failed to get columns: %w", err)
+		}
+		values := make([]sql.NullString, len(columns))
+		valuePtrs := make([]any, len(columns))
+		for i := range values {
+			valuePtrs[i] = &values[i]
+		}
+		if !rows.Next() {
+			return "", fmt.Errorf("%w: %s", ErrRoutineNotFound, routine.Name)
+		}
+		if err := rows.Scan(valuePtrs...); err != nil {
+			return "", fmt.Errorf("failed to read routine definition: %w", err)
+		}
+		if len(values) < 3 || !values[2].Valid {
+			// NULL when the user may run the routine but not see its body
+			return "", fmt.Errorf("failed to read routine definition: %w", ErrInsufficientPrivileges)
+		}
+		return values[2].String, nil
+	case PostgreSQL:
+		if routine.Kind == "AGGREGATE" {
+			// pg_get_functiondef cannot show aggregates
+			return fmt.Sprintf("-- %s %s(%s)", routine.Kind, routine.Name, routine.Arguments), nil
+		}
+		var definition string
+		err := c.db.QueryRow(`
+			SELECT pg_get_functiondef(p.oid)
+			FROM pg_proc p
+			JOIN pg_namespace n ON n.oid = p.pronamespace
+			WHERE n.nspname = ANY (current_schemas(false)) AND p.proname = $1
+			  AND pg_get_function_arguments(p.oid) = $2
+			LIMIT 1`, routine.Name, routine.Arguments).Scan(&definition)
+		if err != nil {
+			return "", fmt.Errorf("failed to read routine definition: %w", err)
+		}
+		return strings.TrimSpace(definition), nil
+	default:
+		return "", fmt.Errorf("%w: %s", ErrRoutineNotFound, routine.Name)
+	}
+}
+
+// ListTriggers returns the triggers on table, or on every table of the
+// current database when table is empty
+func (c *SQLConnection) ListTriggers(table string) ([]TriggerInfo, error) {
+	var query string
+	switch c.config.DatabaseType {
+	case MySQL:
+		query = `
+			SELECT TRIGGER_NAME, EVENT_OBJECT_TABLE, ACTION_TIMING, EVENT_MANIPULATION, ACTION_STATEMENT
+			FROM information_schema.TRIGGERS
+			WHERE TRIGGER_SCHEMA = DATABASE() AND (? = '' OR EVENT_OBJECT_TABLE = ?)
+			ORDER BY EVENT_OBJECT_TABLE, TRIGGER_NAME`
+	case PostgreSQL:
+		// tgtype bits: 2 BEFORE, 4 INSERT, 8 DELETE, 16 UPDATE, 32 TRUNCATE, 64 INSTEAD OF
+		query = `
+			SELECT t.tgname, c.relname,
+			       CASE WHEN t.tgtype & 2 <> 0 THEN 'BEFORE' WHEN t.tgtype & 64 <> 0 THEN 'INSTEAD OF' ELSE 'AFTER' END,
+			       concat_ws(' OR ',
+			                 CASE WHEN t.tgtype & 4 <> 0 THEN 'INSERT' END,
+			                 CASE WHEN t.tgtype & 16 <> 0 THEN 'UPDATE' END,
+			                 CASE WHEN t.tgtype & 8 <> 0 THEN 'DELETE' END,
+			                 CASE WHEN t.tgtype & 32 <> 0 THEN 'TRUNCATE' END),
+			       pg_get_triggerdef(t.oid, true)
+			FROM pg_trigger t
+			JOIN pg_class c ON c.oid = t.tgrelid
+			JOIN pg_namespace n ON n.oid = c.relnamespace
+			WHERE NOT t.tgisinternal AND n.nspname = ANY (current_schemas(false))
+			  AND ($1 = '' OR c.relname = $1)
+			ORDER BY c.relname, t.tgname`
+	case SQLite:
+		return c.listSQLiteTriggers(table)
+	default:
+		return nil, fmt.Errorf("unsupported database type: %v", c.config.DatabaseType)
+	}
+
+	args := []any{table}
+	if c.config.DatabaseType == MySQL {
+		args = append(args, table)
+	}
+	rows, err := c.db.Query(query, args...)
+	if err != nil {
+		return nil, fmt.Errorf("failed to list triggers: %w", err)
+	}
+	defer rows.Close()
+
+	var triggers []TriggerInfo
+	for rows.Next() {
+		var trigger TriggerInfo
+		if err := rows.Scan(&trigger.Name, &trigger.Table, &trigger.Timing, &trigger.Event, &trigger.Definition); err != nil {
+			return nil, fmt.Errorf("failed to scan trigger: %w", err)
+		}
+		triggers = append(triggers, trigger)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, fmt.Errorf("failed to list triggers: %w", err)
+	}
+	return triggers, nil
+}
+
+// listSQLiteTriggers reads triggers from sqlite_master, taking the timing and
+// event from each CREATE TRIGGER statement since SQLite keeps nothing else
+func (c *SQLConnection) listSQLiteTriggers(table string) ([]TriggerInfo, error) {
+	rows, err := c.db.Query(`
+		SELECT name, tbl_name, COALESCE(sql, '')
+		FROM sqlite_master
+		WHERE type = 'trigger' AND (?1 = '' OR tbl_name = ?1)
+		ORDER BY tbl_name, name`, table)
+	if err != nil {
+		return nil, fmt.Errorf("failed to list triggers: %w", err)
+	}
+	defer rows.Close()
+
+	var triggers []TriggerInfo
+	for rows.Next() {
+		var trigger TriggerInfo
+		if err := rows.Scan(&trigger.Name, &trigger.Table, &trigger.Definition); err != nil {
+			return nil, fmt.Errorf("failed to scan trigger: %w", err)
+		}
+		trigger.Timing, trigger.Event = TriggerTiming(trigger.Definition)
+		triggers = append(triggers, trigger)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, fmt.Errorf("failed to list triggers: %w", err)
+	}
+	return triggers, nil
+}
+
+// TriggerTiming reads the timing and event of a CREATE TRIGGER statement.
+// SQLite runs triggers BEFORE when the statement names no timing.
+func TriggerTiming(statement string) (timing, event string) {
+	timing = "BEFORE"
+	for _, token := range sqlTokens(SQLite, statement) {
+		switch keyword := strings.ToUpper(token); keyword {
+		case "BEFORE", "AFTER":
+			timing = keyword
+		case "INSTEAD":
+			timing = "INSTEAD OF"
+		case "INSERT", "UPDATE", "DELETE":
+			return timing, keyword
+		case "ON":
+			// The event comes before ON <table>
+			return timing, ""
+		}
+	}
+	return timing, ""
+}
@@ -0,0 +1,78 @@
+//go:build !no_sqlite
+
+package core
+
+import (
+	"errors"
+	"path/filepath"
+	"testing"
+)
+
+func TestTriggerTiming(t *testing.T) {
+	testCases := []struct {
+		statement string
+		timing    string
+		event     string
+	}{
+		{"CREATE TRIGGER t AFTER INSERT ON orders BEGIN SELECT 1; END", "AFTER", "INSERT"},
+		{"create trigger if not exists t before delete on orders begin select 1; end", "BEFORE", "DELETE"},
+		{"CREATE TRIGGER t INSTEAD OF UPDATE OF total ON order_view BEGIN SELECT 1; END", "INSTEAD OF", "UPDATE"},
+		{"CREATE TRIGGER t UPDATE ON orders BEGIN SELECT 1; END", "BEFORE", "UPDATE"},
+		{"CREATE TRIGGER \"after\" INSERT ON orders BEGIN SELECT 1; END", "BEFORE", "INSERT"},
+	}
+	for _, tc := range testCases {
+		timing, event := TriggerTiming(tc.statement)
+		if timing != tc.timing || event != tc.event {
+			t.Errorf("TriggerTiming(%q) = %q, %q, expected %q, %q", tc.statement, timing, event, tc.timing, tc.event)
+		}
+	}
+}
+
+func TestSQLiteTriggers(t *testing.T) {
+	conn, err := NewConnection(&ConnectionConfig{Name: "triggers", DatabaseType: SQLite, Database: filepath.Join(t.TempDir(), "app.db")})
+	if err != nil {
+		t.Fatalf("Failed to open SQLite: %v", err)
+	}
+	defer conn.Close()
+
+	statements := []string{
+		"CREATE TABLE orders (id INTEGER PRIMARY KEY, total REAL)",
+		"CREATE TABLE order_log (order_id INTEGER)",
+		"CREATE TRIGGER orders_logged AFTER INSERT ON orders BEGIN INSERT INTO order_log VALUES (new.id); END",
+		"CREATE TRIGGER log_checked BEFORE DELETE ON order_log BEGIN SELECT 1; END",
+	}
+	for _, statement := range statements {
+		execAndDrain(t, conn, statement)
+	}
+
+	inspector, ok := conn.(SchemaInspector)
+	if !ok {
+		t.Fatal("Expected SQLite connections to list routines and triggers")
+	}
+	all, err := inspector.ListTriggers("")
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if len(all) != 2 || all[0].Name != "log_checked" || all[1].Name != "orders_logged" {
+		t.Fatalf("Expected both triggers ordered by table, got %+v", all)
+	}
+
+	orders, err := inspector.ListTriggers("orders")
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if len(orders) != 1 {
+		t.Fatalf("Expected one trigger on orders, got %+v", orders)
+	}
+	if trigger := orders[0]; trigger.Table != "orders" || trigger.Timing != "AFTER" || trigger.Event != "INSERT" || trigger.Definition == "" {
+		t.Errorf("Unexpected trigger %+v", trigger)
+	}
+
+	routines, err := inspector.ListRoutines()
+	if err != nil || len(routines) != 0 {
+		t.Errorf("Expected SQLite to have no routines, got %v, %v", routines, err)
+	}
+	if _, err := inspector.DescribeRoutine("orders"); !errors.Is(err, ErrRoutineNotFound) {
+		t.Errorf("Expected ErrRoutineNotFound, got %v", err)
+	}
+}
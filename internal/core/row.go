@@ -0,0 +1,281 @@
+package core
+
+import (
+	"encoding/json"
+	"errors"
+	"fmt"
+	"iter"
+	"strconv"
+	"strings"
+)
+
+var (
+	// ErrNoSuchColumn is returned when a row is read by a column name it does not have
+	ErrNoSuchColumn = errors.New("no such column")
+	// ErrValueType is returned when a column's value cannot be read as the type asked for
+	ErrValueType = errors.New("value has another type")
+)
+
+// Values marshal to their natural JSON types: numbers, strings and booleans,
+// exact decimals as JSON numbers, binary data as base64, dates and times in
+// ISO 8601 and NULL as null.
+func (s StringValue) MarshalJSON() ([]byte, error) { return json.Marshal(jsonValue(s)) }
+func (i IntValue) MarshalJSON() ([]byte, error) { return json.Marshal(jsonValue(i)) }
+func (f FloatValue) MarshalJSON() ([]byte, error) { return json.Marshal(jsonValue(f)) }
+func (b BoolValue) MarshalJSON() ([]byte, error) { return json.Marshal(jsonValue(b)) }
+func (d DecimalValue) MarshalJSON() ([]byte, error) { return json.Marshal(jsonValue(d)) }
+func (b BytesValue) MarshalJSON() ([]byte, error) { return json.Marshal(jsonValue(b)) }
+func (n NullValue) MarshalJSON() ([]byte, error) { return []byte("null"), nil }
+func (d DateValue) MarshalJSON() ([]byte, error) { return json.Marshal(jsonValue(d)) }
+func (t TimeValue) MarshalJSON() ([]byte, error) { return json.Marshal(jsonValue(t)) }
+func (d DateTimeValue) MarshalJSON() ([]byte, error) { return json.Marshal(jsonValue(d)) }
+func (d DateTimeTzValue) MarshalJSON() ([]byte, error) { return json.Marshal(jsonValue(d)) }
+
+// Row is one row of a result together with its columns, so its values can
+// be read by column name
+type Row struct {
+	Columns []Column
+	Values  []Value
+}
+
+// Rows reads the remaining rows of the result as Rows. Check Error once done.
+func (r *QueryResult) Rows() iter.Seq[Row] {
+	return func(yield func(Row) bool) {
+		for values := range r.Itor() {
+			if !yield(Row{Columns: r.Columns, Values: values}) {
+				return
+			}
+		}
+	}
+}
+
+// Value returns the value of column, matched exactly or else ignoring case
+func (r Row) Value(column string) (Value, error) {
+	index := -1
+	for i, col := range r.Columns {
+		if col.Name == column {
+			index = i
+			break
+		}
+		if index < 0 && strings.EqualFold(col.Name, column) {
+			index = i
+		}
+	}
+	if index < 0 {
+		names := (&QueryResult{Columns: r.Columns}).ColumnNames()
+		return nil, fmt.Errorf("%w %q; the row has %s", ErrNoSuchColumn, column, strings.Join(names, ", "))
+	}
+	if index >= len(r.Values) || r.Values[index] == nil {
+		return NullValue{}, nil
+	}
+	return r.Values[index], nil
+}
+
+// MarshalJSON writes the row as a JSON object keyed by column name, in
+// column order
+func (r Row) MarshalJSON() ([]byte, error) {
+	var sb strings.Builder
+	sb.WriteByte('{')
+	for i, column := range r.Columns {
+		if i > 0 {
+			sb.WriteByte(',')
+		}
+		key, err := json.Marshal(column.Name)
+		if err != nil {
+			return nil, err
+		}
+		var value Value = NullValue{}
+		if i < len(r.Values) && r.Values[i] != nil {
+			value = r.Values[i]
+		}
+		encoded, err := json.Marshal(value)
+		if err != nil {
+			return nil, err
+		}
+		sb.Write(key)
+		sb.WriteByte(':')
+		sb.Write(encoded)
+	}
+	sb.WriteByte('}')
+	return []byte(sb.String()), nil
+}
+
+// ResultRecord is the JSON form of a query result
+type ResultRecord struct {
+	Columns []ResultColumnRecord `json:"columns"`
+	Rows    []Row                `json:"rows"`
+}
+
+// ResultColumnRecord is the JSON form of a result column. Nullable is left
+// out when neither the driver nor the table says.
+type ResultColumnRecord struct {
+	Name      string `json:"name"`
+	Type      string `json:"type"`
+	Nullable  *bool  `json:"nullable,omitempty"`
+	Length    int64  `json:"length,omitempty"`
+	Precision int64  `json:"precision,omitempty"`
+	Scale     int64  `json:"scale,omitempty"`
+}
+
+// Record returns the JSON form of the column
+func (c Column) Record() ResultColumnRecord {
+	return ResultColumnRecord{
+		Name:      c.Name,
+		Type:      c.Type,
+		Nullable:  c.Nullable,
+		Length:    c.Length,
+		Precision: c.Precision,
+		Scale:     c.Scale,
+	}
+}
+
+// MarshalJSON writes the column as its Record
+func (c Column) MarshalJSON() ([]byte, error) {
+	return json.Marshal(c.Record())
+}
+
+// MarshalJSON writes the column as its Record
+func (c ColumnInfo) MarshalJSON() ([]byte, error) {
+	return json.Marshal(c.Record())
+}
+
+// MarshalJSON reads the remaining rows and writes the result as its
+// columns and an array of row objects. A result that fails while being read
+// returns its error.
+func (r *QueryResult) MarshalJSON() ([]byte, error) {
+	record := ResultRecord{Columns: make([]ResultColumnRecord, 0, len(r.Columns)), Rows: []Row{}}
+	for _, column := range r.Columns {
+		record.Columns = append(record.Columns, column.Record())
+	}
+	for row := range r.Rows() {
+		record.Rows = append(record.Rows, row)
+	}
+	if err := r.Error(); err != nil {
+		return nil, err
+	}
+	return json.Marshal(record)
+}
+
+// FieldType lists the Go types a column can be read as with Field. The
+// pointer types read NULL as nil.
+type FieldType interface {
+	int64 | float64 | string | bool | *int64 | *float64 | *string | *bool
+}
+
+// Field reads column of row as T. Integers read as float64 too, exact
+// decimals as float64 when they fit, integers 0 and 1 as bool and any value
+// as string, in its ISO/raw form. NULL needs a pointer type. The errors
+// name the column and the type it holds.
+//
+//	id, err := core.Field[int64](row, "id")
+//	note, err := core.Field[*string](row, "note")
+func Field[T FieldType](row Row, column string) (T, error) {
+	var zero T
+	v, err := row.Value(column)
+	if err != nil {
+		return zero, err
+	}
+
+	var out any
+	var ok bool
+	switch any(zero).(type) {
+	case int64:
+		out, ok = fieldInt(v)
+	case float64:
+		out, ok = fieldFloat(v)
+	case string:
+		out, ok = FormatISO(v), !v.IsNull()
+	case bool:
+		out, ok = fieldBool(v)
+	case *int64:
+		out, ok = fieldPointer(v, fieldInt)
+	case *float64:
+		out, ok = fieldPointer(v, fieldFloat)
+	case *string:
+		out, ok = fieldPointer(v, func(v Value) (string, bool) { return FormatISO(v), true })
+	case *bool:
+		out, ok = fieldPointer(v, fieldBool)
+	}
+	if !ok {
+		return zero, fmt.Errorf("%w: column %q is %s, not %T", ErrValueType, column, valueTypeName(v), zero)
+	}
+	return out.(T), nil
+}
+
+func fieldInt(v Value) (int64, bool) {
+	i, ok := v.(IntValue)
+	return i.Value, ok && !i.Null
+}
+
+func fieldFloat(v Value) (float64, bool) {
+	if v.IsNull() {
+		return 0, false
+	}
+	switch t := v.(type) {
+	case FloatValue:
+		return t.Value, true
+	case IntValue:
+		return float64(t.Value), true
+	case DecimalValue:
+		f, err := strconv.ParseFloat(t.Value, 64)
+		return f, err == nil
+	}
+	return 0, false
+}
+
+func fieldBool(v Value) (bool, bool) {
+	if v.IsNull() {
+		return false, false
+	}
+	switch t := v.(type) {
+	case BoolValue:
+		return t.Value, true
+	case IntValue:
+		// MySQL has no boolean type and returns BOOLEAN columns as TINYINT(1)
+		return t.Value == 1, t.Value == 0 || t.Value == 1
+	}
+	return false, false
+}
+
+// fieldPointer reads v with read, or as nil when it is NULL
+func fieldPointer[T any](v Value, read func(Value) (T, bool)) (*T, bool) {
+	if v.IsNull() {
+		return nil, true
+	}
+	value, ok := read(v)
+	if !ok {
+		return nil, false
+	}
+	return &value, true
+}
+
+// valueTypeName describes v for errors, e.g. "NULL" or "text (abc)"
+func valueTypeName(v Value) string {
+	if v.IsNull() {
+		return "NULL"
+	}
+	var kind string
+	switch v.(type) {
+	case StringValue:
+		kind = "text"
+	case IntValue:
+		kind = "an integer"
+	case FloatValue:
+		kind = "a float"
+	case BoolValue:
+		kind = "a boolean"
+	case DecimalValue:
+		kind = "a decimal"
+	case BytesValue:
+		kind = "binary"
+	case DateValue:
+		kind = "a date"
+	case TimeValue:
+		kind = "a time"
+	case DateTimeValue, DateTimeTzValue:
+		kind = "a timestamp"
+	default:
+		kind = fmt.Sprintf("%T", v)
+	}
+	return fmt.Sprintf("%s (%s)", kind, ElideLine(FormatISO(v), 40))
+}
@@ -0,0 +1,221 @@
+package core
+
+import (
+	"encoding/json"
+	"errors"
+	"fmt"
+	"strings"
+	"testing"
+	"time"
+)
+
+func TestValue_MarshalJSON(t *testing.T) {
+	tests := []struct {
+		name     string
+		value    Value
+		expected string
+	}{
+		{"string", StringValue{Value: "a \"b\""}, `"a \"b\""`},
+		{"int", IntValue{Value: -42}, `-42`},
+		{"float", FloatValue{Value: 1.5}, `1.5`},
+		{"bool", BoolValue{Value: true}, `true`},
+		{"decimal keeps its digits", DecimalValue{Value: "12345678901234567890.0123456789"}, `12345678901234567890.0123456789`},
+		{"bytes as base64", BytesValue{Value: []byte{0xde, 0xad, 0xbe, 0xef}}, `"3q2+7w=="`},
+		{"date", DateValue{Value: time.Date(2024, 3, 9, 0, 0, 0, 0, time.UTC)}, `"2024-03-09"`},
+		{"time", TimeValue{Value: time.Date(0, 1, 1, 13, 5, 0, 0, time.UTC)}, `"13:05:00"`},
+		{"datetime", DateTimeValue{Value: time.Date(2024, 3, 9, 13, 5, 0, 0, time.UTC)}, `"2024-03-09T13:05:00"`},
+		{"datetime with zone", DateTimeTzValue{Value: time.Date(2024, 3, 9, 13, 5, 0, 0, time.FixedZone("", 3600))}, `"2024-03-09T12:05:00Z"`},
+		{"null string", StringValue{Null: true}, `null`},
+		{"null int", IntValue{Null: true}, `null`},
+		{"null bytes", BytesValue{Null: true}, `null`},
+		{"NULL", NullValue{}, `null`},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got, err := json.Marshal(tt.value)
+			if err != nil {
+				t.Fatalf("Unexpected error: %v", err)
+			}
+			if string(got) != tt.expected {
+				t.Errorf("Expected %s, got %s", tt.expected, got)
+			}
+		})
+	}
+}
+
+func TestQueryResult_MarshalJSON(t *testing.T) {
+	notNull := false
+	result := NewStaticQueryResult(
+		[]Column{{Name: "id", Type: "INT", Nullable: &notNull}, {Name: "note", Type: "VARCHAR", Length: 80}},
+		[][]Value{
+			{IntValue{Value: 1}, StringValue{Value: "gift"}},
+			{IntValue{Value: 2}, StringValue{Null: true}},
+		},
+	)
+
+	got, err := json.Marshal(result)
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	expected := `{"columns":[{"name":"id","type":"INT","nullable":false},{"name":"note","type":"VARCHAR","length":80}],` +
+		`"rows":[{"id":1,"note":"gift"},{"id":2,"note":null}]}`
+	if string(got) != expected {
+		t.Errorf("Expected %s, got %s", expected, got)
+	}
+
+	empty, err := json.Marshal(NewStaticQueryResult([]Column{{Name: "id"}}, nil))
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if !strings.Contains(string(empty), `"rows":[]`) {
+		t.Errorf("Expected an empty result to have an empty rows array, got %s", empty)
+	}
+}
+
+func TestColumnInfo_MarshalJSON(t *testing.T) {
+	got, err := json.Marshal(ColumnInfo{Name: "id", Type: "int", Key: "PRI"})
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if expected := `{"name":"id","type":"int","nullable":false,"key":"PRI","default":null}`; string(got) != expected {
+		t.Errorf("Expected %s, got %s", expected, got)
+	}
+}
+
+func fieldRow() Row {
+	return Row{
+		Columns: []Column{
+			{Name: "id"}, {Name: "price"}, {Name: "total"}, {Name: "active"}, {Name: "flag"},
+			{Name: "name"}, {Name: "created"}, {Name: "note"},
+		},
+		Values: []Value{
+			IntValue{Value: 7},
+			FloatValue{Value: 2.5},
+			DecimalValue{Value: "19.90"},
+			BoolValue{Value: true},
+			IntValue{Value: 0},
+			StringValue{Value: "Ada"},
+			DateValue{Value: time.Date(2024, 3, 9, 0, 0, 0, 0, time.UTC)},
+			StringValue{Null: true},
+		},
+	}
+}
+
+func TestField(t *testing.T) {
+	row := fieldRow()
+	check := func(name string, got, expected any, err error) {
+		t.Helper()
+		if err != nil {
+			t.Errorf("%s: unexpected error: %v", name, err)
+		} else if got != expected {
+			t.Errorf("%s: expected %v, got %v", name, expected, got)
+		}
+	}
+
+	id, err := Field[int64](row, "id")
+	check("int64", id, int64(7), err)
+	price, err := Field[float64](row, "price")
+	check("float64", price, 2.5, err)
+	fromInt, err := Field[float64](row, "id")
+	check("float64 from an integer", fromInt, 7.0, err)
+	total, err := Field[float64](row, "total")
+	check("float64 from a decimal", total, 19.9, err)
+	active, err := Field[bool](row, "active")
+	check("bool", active, true, err)
+	flag, err := Field[bool](row, "flag")
+	check("bool from TINYINT(1)", flag, false, err)
+	name, err := Field[string](row, "name")
+	check("string", name, "Ada", err)
+	created, err := Field[string](row, "created")
+	check("string from a date", created, "2024-03-09", err)
+	upper, err := Field[int64](row, "ID")
+	check("name in another case", upper, int64(7), err)
+
+	note, err := Field[*string](row, "note")
+	if err != nil || note != nil {
+		t.Errorf("Expected NULL read as nil, got %v, %v", note, err)
+	}
+	idPtr, err := Field[*int64](row, "id")
+	if err != nil || idPtr == nil || *idPtr != 7 {
+		t.Errorf("Expected a pointer to 7, got %v, %v", idPtr, err)
+	}
+	activePtr, err := Field[*bool](row, "active")
+	if err != nil || activePtr == nil || !*activePtr {
+		t.Errorf("Expected a pointer to true, got %v, %v", activePtr, err)
+	}
+	pricePtr, err := Field[*float64](row, "price")
+	if err != nil || pricePtr == nil || *pricePtr != 2.5 {
+		t.Errorf("Expected a pointer to 2.5, got %v, %v", pricePtr, err)
+	}
+}
+
+func TestField_Errors(t *testing.T) {
+	row := fieldRow()
+	tests := []struct {
+		name     string
+		read     func() error
+		target   error
+		expected string
+	}{
+		{"unknown column", func() error { _, err := Field[int64](row, "missing"); return err }, ErrNoSuchColumn,
+			`no such column "missing"; the row has id, price, total, active, flag, name, created, note`},
+		{"text as int64", func() error { _, err := Field[int64](row, "name"); return err }, ErrValueType,
+			`value has another type: column "name" is text (Ada), not int64`},
+		{"NULL as string", func() error { _, err := Field[string](row, "note"); return err }, ErrValueType,
+			`value has another type: column "note" is NULL, not string`},
+		{"float as int64", func() error { _, err := Field[*int64](row, "price"); return err }, ErrValueType,
+			`value has another type: column "price" is a float (2.5), not *int64`},
+		{"integer 7 as bool", func() error { _, err := Field[bool](row, "id"); return err }, ErrValueType,
+			`value has another type: column "id" is an integer (7), not bool`},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			err := tt.read()
+			if !errors.Is(err, tt.target) {
+				t.Fatalf("Expected %v, got %v", tt.target, err)
+			}
+			if err.Error() != tt.expected {
+				t.Errorf("Expected %q, got %q", tt.expected, err.Error())
+			}
+		})
+	}
+}
+
+func ExampleField() {
+	result := NewStaticQueryResult(
+		[]Column{{Name: "id"}, {Name: "email"}, {Name: "verified"}},
+		[][]Value{
+			{IntValue{Value: 1}, StringValue{Value: "ada@example.com"}, BoolValue{Value: true}},
+			{IntValue{Value: 2}, StringValue{Null: true}, BoolValue{Value: false}},
+		},
+	)
+
+	type user struct {
+		ID       int64
+		Email    *string
+		Verified bool
+	}
+	for row := range result.Rows() {
+		var u user
+		var err error
+		if u.ID, err = Field[int64](row, "id"); err != nil {
+			panic(err)
+		}
+		if u.Email, err = Field[*string](row, "email"); err != nil {
+			panic(err)
+		}
+		if u.Verified, err = Field[bool](row, "verified"); err != nil {
+			panic(err)
+		}
+		email := "none"
+		if u.Email != nil {
+			email = *u.Email
+		}
+		fmt.Println(u.ID, email, u.Verified)
+	}
+	// Output:
+	// 1 ada@example.com true
+	// 2 none false
+}
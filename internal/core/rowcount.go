@@ -0,0 +1,37 @@
+package core
+
+import (
+	"context"
+	"errors"
+	"fmt"
+)
+
+// ErrRowCountNotSupported is returned by connections that cannot count the rows of a table
+var ErrRowCountNotSupported = errors.New("row counts are not available for this connection")
+
+// RowCounter reports how many rows a table has. EstimateRows is cheap enough
+// to show with every description; CountRows scans the table, so it only runs
+// when asked for.
+type RowCounter interface {
+	// EstimateRows returns roughly how many rows table has and whether the figure is exact
+	EstimateRows(table string) (rows int64, exact bool, err error)
+	// CountRows runs COUNT(*) on table until it finishes or ctx is cancelled
+	CountRows(ctx context.Context, table string) (int64, error)
+}
+
+// EstimateRows implements RowCounter from the statistics the server keeps.
+// SQLite keeps none, so there the count is exact.
+func (c *SQLConnection) EstimateRows(table string) (int64, bool, error) {
+	rows, err := c.estimateRows(table)
+	return rows, c.config.DatabaseType == SQLite, err
+}
+
+// CountRows implements RowCounter
+func (c *SQLConnection) CountRows(ctx context.Context, table string) (int64, error) {
+	var rows int64
+	query := "SELECT COUNT(*) FROM " + QuoteIdentifier(c.config.DatabaseType, table)
+	if err := c.db.QueryRowContext(ctx, query).Scan(&rows); err != nil {
+		return 0, fmt.Errorf("failed to count rows of %s: %w", table, err)
+	}
+	return rows, nil
+}
@@ -0,0 +1,39 @@
+//go:build !no_sqlite
+
+package core
+
+import (
+	"context"
+	"errors"
+	"path/filepath"
+	"testing"
+)
+
+func TestSQLiteRowCounter(t *testing.T) {
+	conn, err := NewConnection(&ConnectionConfig{Name: "count", DatabaseType: SQLite, Database: filepath.Join(t.TempDir(), "app.db")})
+	if err != nil {
+		t.Fatalf("Failed to open SQLite: %v", err)
+	}
+	defer conn.Close()
+
+	execAndDrain(t, conn, "CREATE TABLE events (id INTEGER PRIMARY KEY)")
+	execAndDrain(t, conn, "INSERT INTO events (id) VALUES (1), (2), (3)")
+
+	counter, ok := conn.(RowCounter)
+	if !ok {
+		t.Fatal("Expected SQLite connections to count rows")
+	}
+	rows, exact, err := counter.EstimateRows("events")
+	if err != nil || rows != 3 || !exact {
+		t.Errorf("Expected an exact estimate of 3 rows, got %d, %v, %v", rows, exact, err)
+	}
+	if rows, err := counter.CountRows(context.Background(), "events"); err != nil || rows != 3 {
+		t.Errorf("Expected 3 rows, got %d, %v", rows, err)
+	}
+
+	ctx, cancel := context.WithCancel(context.Background())
+	cancel()
+	if _, err := counter.CountRows(ctx, "events"); !errors.Is(err, context.Canceled) {
+		t.Errorf("Expected a cancelled count to fail with context.Canceled, got %v", err)
+	}
+}
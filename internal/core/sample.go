@@ -0,0 +1,138 @@
+package core
+
+import (
+	"database/sql"
+	"fmt"
+	"strings"
+)
+
+const (
+	// DefaultSampleSize is how many rows /sample fetches when no count is given
+	DefaultSampleSize = 10
+	// MaxSampleSize bounds how many rows one sample may fetch
+	MaxSampleSize = 1000
+	// sampleSortLimit is the estimated table size above which a sample thins
+	// the rows before sorting them, instead of sorting the whole table
+	sampleSortLimit = 100_000
+	// sampleOversample is how many times n rows the thinning aims to keep, so
+	// an unlucky draw still leaves enough to pick n from
+	sampleOversample = 10
+)
+
+// TableSampler picks random rows of a table the way its backend does best
+type TableSampler interface {
+	// SampleQuery returns a SELECT of n random rows of table
+	SampleQuery(table string, n int) string
+}
+
+// SampleQuery builds a SELECT of n random rows of table for dbType. rows is
+// the estimated size of the table, or 0 when unknown. Tables above
+// sampleSortLimit rows are thinned first so the random sort stays small:
+// PostgreSQL reads a TABLESAMPLE SYSTEM share of the pages, and MySQL and
+// SQLite keep a random share of the rows.
+func SampleQuery(dbType DatabaseType, table string, n int, rows int64) string {
+	from := QuoteIdentifier(dbType, table)
+	random := "random()"
+	if dbType == MySQL {
+		random = "RAND()"
+	}
+
+	thin := ""
+	if rows > sampleSortLimit {
+		share := float64(n*sampleOversample) / float64(rows)
+		switch dbType {
+		case PostgreSQL:
+			thin = fmt.Sprintf(" TABLESAMPLE SYSTEM (%.4g)", min(share*100, 100))
+		case MySQL:
+			thin = fmt.Sprintf(" WHERE RAND() < %.4g", share)
+		case SQLite:
+			// random() returns an integer, so keep the rows where it is a multiple of rows/(n*oversample)
+			thin = fmt.Sprintf(" WHERE random() %% %d = 0", rows/int64(n*sampleOversample))
+		}
+	}
+	return fmt.Sprintf("SELECT * FROM %s%s ORDER BY %s LIMIT %d", from, thin, random, n)
+}
+
+// SampleQuery implements TableSampler, using the table size the server
+// estimates to decide whether the table needs thinning
+func (c *SQLConnection) SampleQuery(table string, n int) string {
+	rows, err := c.estimateRows(table)
+	if err != nil {
+		// The sample itself reports a missing table; without an estimate it sorts every row
+		rows = 0
+	}
+	return SampleQuery(c.config.DatabaseType, table, n, rows)
+}
+
+// estimateRows returns roughly how many rows table has: the statistics the
+// server keeps on MySQL and PostgreSQL, and an exact count on SQLite
+func (c *SQLConnection) estimateRows(table string) (int64, error) {
+	var query string
+	var args []any
+	switch c.config.DatabaseType {
+	case MySQL:
+		schema, name := "", table
+		if i := strings.LastIndex(table, "."); i >= 0 {
+			schema, name = table[:i], table[i+1:]
+		}
+		query = "SELECT TABLE_ROWS FROM information_schema.TABLES WHERE TABLE_SCHEMA = COALESCE(NULLIF(?, ''), DATABASE()) AND TABLE_NAME = ?"
+		args = []any{schema, name}
+	case PostgreSQL:
+		query, args = "SELECT reltuples::bigint FROM pg_class WHERE oid = to_regclass($1)", []any{QuoteIdentifier(PostgreSQL, table)}
+	case SQLite:
+		query = "SELECT count(*) FROM " + QuoteIdentifier(SQLite, table)
+	default:
+		return 0, fmt.Errorf("unsupported database type: %v", c.config.DatabaseType)
+	}
+
+	var rows sql.NullInt64
+	if err := c.db.QueryRow(query, args...).Scan(&rows); err != nil {
+		return 0, fmt.Errorf("failed to estimate rows of %s: %w", table, err)
+	}
+	// PostgreSQL reports -1 for a table that has never been analysed
+	return max(rows.Int64, 0), nil
+}
+
+// ColumnProfile summarises the values of one column
+type ColumnProfile struct {
+	Name string
+	// Nulls is the share of NULL values, from 0 to 1
+	Nulls    float64
+	Distinct int
+	// Min and Max are nil when every value is NULL
+	Min Value
+	Max Value
+}
+
+// ProfileColumns summarises each column of rows: how many values are NULL,
+// how many distinct values there are and the smallest and largest. On a
+// sample they describe the sampled rows, so the distinct count is a lower
+// bound for the whole table.
+func ProfileColumns(columns []Column, rows [][]Value) []ColumnProfile {
+	profiles := make([]ColumnProfile, len(columns))
+	for i, column := range columns {
+		profile := ColumnProfile{Name: column.Name}
+		seen := make(map[string]bool)
+		nulls := 0
+		for _, row := range rows {
+			if i >= len(row) || row[i].IsNull() {
+				nulls++
+				continue
+			}
+			value := row[i]
+			seen[FormatISO(value)] = true
+			if profile.Min == nil || CompareValues(value, profile.Min) < 0 {
+				profile.Min = value
+			}
+			if profile.Max == nil || CompareValues(value, profile.Max) > 0 {
+				profile.Max = value
+			}
+		}
+		if len(rows) > 0 {
+			profile.Nulls = float64(nulls) / float64(len(rows))
+		}
+		profile.Distinct = len(seen)
+		profiles[i] = profile
+	}
+	return profiles
+}
@@ -0,0 +1,55 @@
+package core
+
+import "testing"
+
+func TestSampleQuery(t *testing.T) {
+	tests := []struct {
+		name   string
+		dbType DatabaseType
+		table  string
+		rows   int64
+		want   string
+	}{
+		{"mysql small table sorts every row", MySQL, "users", 500, "SELECT * FROM `users` ORDER BY RAND() LIMIT 10"},
+		{"mysql large table thins first", MySQL, "shop.orders", 1_000_000, "SELECT * FROM `shop`.`orders` WHERE RAND() < 0.0001 ORDER BY RAND() LIMIT 10"},
+		{"postgres unknown size sorts every row", PostgreSQL, "users", 0, `SELECT * FROM "users" ORDER BY random() LIMIT 10`},
+		{"postgres large table uses tablesample", PostgreSQL, "events", 2_000_000, `SELECT * FROM "events" TABLESAMPLE SYSTEM (0.005) ORDER BY random() LIMIT 10`},
+		{"sqlite large table keeps every nth row", SQLite, "log", 300_000, `SELECT * FROM "log" WHERE random() % 3000 = 0 ORDER BY random() LIMIT 10`},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := SampleQuery(tt.dbType, tt.table, 10, tt.rows); got != tt.want {
+				t.Errorf("SampleQuery() =\n%s\nwant\n%s", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestProfileColumns(t *testing.T) {
+	columns := []Column{{Name: "id"}, {Name: "city"}, {Name: "deleted_at"}}
+	rows := [][]Value{
+		{IntValue{Value: 3}, StringValue{Value: "Perth"}, StringValue{Null: true}},
+		{IntValue{Value: 10}, StringValue{Value: "Adelaide"}, StringValue{Null: true}},
+		{IntValue{Value: 7}, StringValue{Null: true}, StringValue{Null: true}},
+		{IntValue{Value: 1}, StringValue{Value: "Perth"}, StringValue{Null: true}},
+	}
+
+	profiles := ProfileColumns(columns, rows)
+	if len(profiles) != 3 {
+		t.Fatalf("Expected a profile per column, got %d", len(profiles))
+	}
+
+	id := profiles[0]
+	if id.Nulls != 0 || id.Distinct != 4 || id.Min.String() != "1" || id.Max.String() != "10" {
+		t.Errorf("Unexpected id profile: %+v", id)
+	}
+	city := profiles[1]
+	if city.Nulls != 0.25 || city.Distinct != 2 || city.Min.String() != "Adelaide" || city.Max.String() != "Perth" {
+		t.Errorf("Unexpected city profile: %+v", city)
+	}
+	deleted := profiles[2]
+	if deleted.Nulls != 1 || deleted.Distinct != 0 || deleted.Min != nil || deleted.Max != nil {
+		t.Errorf("Expected an all-NULL column to have no min or max, got %+v", deleted)
+	}
+}
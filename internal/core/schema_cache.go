@@ -0,0 +1,152 @@
+package core
+
+import (
+	"strings"
+	"sync"
+	"time"
+)
+
+// DefaultSchemaCacheTTL is how long table lists and details are reused before asking the server again
+const DefaultSchemaCacheTTL = 5 * time.Minute
+
+// SchemaCache keeps the table list and table details of one connection so
+// repeated lookups do not go back to the server. A zero TTL disables caching.
+// It is safe for concurrent use.
+type SchemaCache struct {
+	mu  sync.Mutex
+	ttl time.Duration
+	now func() time.Time
+
+	tables   []string
+	tablesAt time.Time
+	details  map[string]cachedTable
+}
+
+type cachedTable struct {
+	info      *TableInfo
+	fetchedAt time.Time
+}
+
+// CacheHit describes a value served from the cache
+type CacheHit struct {
+	Cached    bool
+	FetchedAt time.Time
+}
+
+// Age is how long ago the value was fetched from the server
+func (h CacheHit) Age(now time.Time) time.Duration {
+	return now.Sub(h.FetchedAt)
+}
+
+// NewSchemaCache creates an empty cache whose entries stay fresh for ttl
+func NewSchemaCache(ttl time.Duration) *SchemaCache {
+	return &SchemaCache{
+		ttl:     ttl,
+		now:     time.Now,
+		details: make(map[string]cachedTable),
+	}
+}
+
+// Tables returns the cached table list, calling fetch when it is missing or stale
+func (c *SchemaCache) Tables(fetch func() ([]string, error)) ([]string, CacheHit, error) {
+	c.mu.Lock()
+	if c.tables != nil && c.fresh(c.tablesAt) {
+		tables, hit := c.tables, CacheHit{Cached: true, FetchedAt: c.tablesAt}
+		c.mu.Unlock()
+		return tables, hit, nil
+	}
+	c.mu.Unlock()
+
+	tables, err := fetch()
+	if err != nil {
+		return nil, CacheHit{}, err
+	}
+
+	c.mu.Lock()
+	defer c.mu.Unlock()
+	c.tables = tables
+	c.tablesAt = c.now()
+	return tables, CacheHit{FetchedAt: c.tablesAt}, nil
+}
+
+// Table returns the cached details of tableName, calling fetch when they are missing or stale
+func (c *SchemaCache) Table(tableName string, fetch func() (*TableInfo, error)) (*TableInfo, CacheHit, error) {
+	key := strings.ToLower(tableName)
+
+	c.mu.Lock()
+	if entry, ok := c.details[key]; ok && c.fresh(entry.fetchedAt) {
+		c.mu.Unlock()
+		return entry.info, CacheHit{Cached: true, FetchedAt: entry.fetchedAt}, nil
+	}
+	c.mu.Unlock()
+
+	info, err := fetch()
+	if err != nil {
+		return nil, CacheHit{}, err
+	}
+
+	c.mu.Lock()
+	defer c.mu.Unlock()
+	fetchedAt := c.now()
+	c.details[key] = cachedTable{info: info, fetchedAt: fetchedAt}
+	return info, CacheHit{FetchedAt: fetchedAt}, nil
+}
+
+// CachedTables is how many tables the cached table list holds, fresh or not
+func (c *SchemaCache) CachedTables() int {
+	c.mu.Lock()
+	defer c.mu.Unlock()
+	return len(c.tables)
+}
+
+// TableNames returns the cached table list, fresh or not, without asking the
+// server; nil when it has not been fetched
+func (c *SchemaCache) TableNames() []string {
+	c.mu.Lock()
+	defer c.mu.Unlock()
+	return c.tables
+}
+
+// Invalidate drops everything so the next lookup goes to the server
+func (c *SchemaCache) Invalidate() {
+	c.mu.Lock()
+	defer c.mu.Unlock()
+	c.tables = nil
+	c.tablesAt = time.Time{}
+	c.details = make(map[string]cachedTable)
+}
+
+// InvalidateAfter drops the cache when query changes the schema and reports whether it did
+func (c *SchemaCache) InvalidateAfter(dbType DatabaseType, query string) bool {
+	if !IsDDL(dbType, query) {
+		return false
+	}
+	c.Invalidate()
+	return true
+}
+
+// fresh reports whether a value fetched at fetchedAt can still be served; the caller must hold c.mu
+func (c *SchemaCache) fresh(fetchedAt time.Time) bool {
+	return c.ttl > 0 && c.now().Sub(fetchedAt) < c.ttl
+}
+
+// ddlKeywords start statements that can add, drop or reshape tables, or
+// attach and detach SQLite databases holding them
+var ddlKeywords = []string{"CREATE", "ALTER", "DROP", "RENAME", "TRUNCATE", "ATTACH", "DETACH"}
+
+// IsDDL reports whether query is a schema-changing statement. Leading comments are skipped.
+func IsDDL(dbType DatabaseType, query string) bool {
+	for _, statement := range SplitStatements(dbType, query) {
+		fields := strings.Fields(statement)
+		if len(fields) == 0 {
+			continue
+		}
+		for _, keyword := range ddlKeywords {
+			if strings.EqualFold(fields[0], keyword) {
+				return true
+			}
+		}
+	}
+	return false
+}
+
@@ -0,0 +1,113 @@
+package core
+
+import (
+	"errors"
+	"testing"
+	"time"
+)
+
+func newTestSchemaCache(ttl time.Duration) (*SchemaCache, *time.Time) {
+	now := time.Date(2024, 1, 1, 12, 0, 0, 0, time.UTC)
+	cache := NewSchemaCache(ttl)
+	cache.now = func() time.Time { return now }
+	return cache, &now
+}
+
+func TestSchemaCache_Tables(t *testing.T) {
+	cache, now := newTestSchemaCache(time.Minute)
+	fetches := 0
+	fetch := func() ([]string, error) {
+		fetches++
+		return []string{"users"}, nil
+	}
+
+	if _, hit, _ := cache.Tables(fetch); hit.Cached {
+		t.Error("Expected the first lookup to go to the server")
+	}
+
+	*now = now.Add(30 * time.Second)
+	_, hit, _ := cache.Tables(fetch)
+	if !hit.Cached || fetches != 1 {
+		t.Errorf("Expected a fresh cached list, got cached=%v after %d fetches", hit.Cached, fetches)
+	}
+	if hit.Age(*now) != 30*time.Second {
+		t.Errorf("Expected age 30s, got %v", hit.Age(*now))
+	}
+
+	*now = now.Add(time.Minute)
+	if _, hit, _ := cache.Tables(fetch); hit.Cached || fetches != 2 {
+		t.Errorf("Expected a stale list to be fetched again, got cached=%v after %d fetches", hit.Cached, fetches)
+	}
+}
+
+func TestSchemaCache_TableDetails(t *testing.T) {
+	cache, _ := newTestSchemaCache(time.Minute)
+	fetches := 0
+	fetch := func() (*TableInfo, error) {
+		fetches++
+		return &TableInfo{Name: "users"}, nil
+	}
+
+	cache.Table("users", fetch)
+	if _, hit, _ := cache.Table("USERS", fetch); !hit.Cached || fetches != 1 {
+		t.Errorf("Expected table names to be cached case-insensitively, got %d fetches", fetches)
+	}
+
+	cache.Invalidate()
+	if _, hit, _ := cache.Table("users", fetch); hit.Cached || fetches != 2 {
+		t.Errorf("Expected invalidation to force a fetch, got %d fetches", fetches)
+	}
+}
+
+func TestSchemaCache_ErrorsAreNotCached(t *testing.T) {
+	cache, _ := newTestSchemaCache(time.Minute)
+
+	_, _, err := cache.Tables(func() ([]string, error) { return nil, errors.New("connection reset") })
+	if err == nil {
+		t.Fatal("Expected the fetch error to be returned")
+	}
+
+	if _, hit, _ := cache.Tables(func() ([]string, error) { return []string{}, nil }); hit.Cached {
+		t.Error("Expected a failed fetch not to populate the cache")
+	}
+}
+
+func TestSchemaCache_ZeroTTLDisablesCaching(t *testing.T) {
+	cache, _ := newTestSchemaCache(0)
+	fetches := 0
+	fetch := func() ([]string, error) {
+		fetches++
+		return []string{"users"}, nil
+	}
+
+	cache.Tables(fetch)
+	cache.Tables(fetch)
+	if fetches != 2 {
+		t.Errorf("Expected every lookup to fetch with a zero TTL, got %d fetches", fetches)
+	}
+}
+
+func TestIsDDL(t *testing.T) {
+	testCases := []struct {
+		query    string
+		expected bool
+	}{
+		{"CREATE TABLE t (id INT)", true},
+		{"  alter table users add column age int", true},
+		{"-- cleanup\nDROP TABLE old_users;", true},
+		{"/* rename */ RENAME TABLE a TO b", true},
+		{"SELECT 1; TRUNCATE logs", true},
+		{"ATTACH DATABASE 'other.db' AS other", true},
+		{"detach other", true},
+		{"SELECT * FROM created_tables", false},
+		{"INSERT INTO users VALUES (1)", false},
+		{"-- DROP TABLE users", false},
+		{"", false},
+	}
+
+	for _, tc := range testCases {
+		if got := IsDDL(MySQL, tc.query); got != tc.expected {
+			t.Errorf("IsDDL(%q) = %v, expected %v", tc.query, got, tc.expected)
+		}
+	}
+}
@@ -0,0 +1,181 @@
+package core
+
+import (
+	"bytes"
+	"cmp"
+	"math"
+	"math/big"
+	"slices"
+	"strings"
+	"time"
+
+	"golang.org/x/text/collate"
+	"golang.org/x/text/language"
+)
+
+// Collations for comparing text when rows are sorted client-side
+const (
+	// CollationUnicode orders text as a reader would: case and accents only
+	// break ties, so "apple" and "Apple" sit together before "banana"
+	CollationUnicode = "unicode"
+	// CollationBytes orders text by its UTF-8 bytes, putting "Z" before "a"
+	CollationBytes = "bytes"
+)
+
+// SortOptions controls how SortRows orders a result
+type SortOptions struct {
+	// Collation is CollationUnicode or CollationBytes; empty means CollationUnicode
+	Collation string
+	// Numeric compares numbers in text by value, so "9" comes before "10"
+	Numeric    bool
+	Descending bool
+}
+
+// SortRows orders rows by the column at index column, keeping rows that
+// compare equal in their current order. Numbers, dates and times compare by
+// value whatever the options; text follows the collation. NULLs always go
+// last so the values stay at the top in either direction.
+func SortRows(rows [][]Value, column int, opts SortOptions) {
+	compareText := textComparer(opts)
+	slices.SortStableFunc(rows, func(a, b []Value) int {
+		x, y := a[column], b[column]
+		switch xNull, yNull := isNullValue(x), isNullValue(y); {
+		case xNull && yNull:
+			return 0
+		case xNull:
+			return 1
+		case yNull:
+			return -1
+		}
+
+		c := compareValues(x, y, compareText)
+		if opts.Descending {
+			return -c
+		}
+		return c
+	})
+}
+
+// textComparer returns the comparison SortRows uses for text
+func textComparer(opts SortOptions) func(a, b string) int {
+	if opts.Collation == CollationBytes {
+		if !opts.Numeric {
+			return strings.Compare
+		}
+		return func(a, b string) int {
+			if c, ok := compareNumericText(a, b); ok {
+				return c
+			}
+			return strings.Compare(a, b)
+		}
+	}
+
+	var options []collate.Option
+	if opts.Numeric {
+		// Also orders digits inside text, such as "file9" before "file10"
+		options = append(options, collate.Numeric)
+	}
+	collator := collate.New(language.Und, options...)
+	return func(a, b string) int {
+		if c := collator.CompareString(a, b); c != 0 {
+			return c
+		}
+		// Equal to the collator, as with some unassigned characters; keep a stable total order
+		return strings.Compare(a, b)
+	}
+}
+
+// compareValues compares two non-NULL values. Values of the same kind compare
+// by value; anything else, such as mixed types in one SQLite column, compares
+// as text.
+func compareValues(a, b Value, compareText func(a, b string) int) int {
+	if x, ok := numericValue(a); ok {
+		if y, ok := numericValue(b); ok {
+			return x.Cmp(y)
+		}
+	}
+	if x, ok := timeValue(a); ok {
+		if y, ok := timeValue(b); ok {
+			return x.Compare(y)
+		}
+	}
+	switch x := a.(type) {
+	case BoolValue:
+		if y, ok := b.(BoolValue); ok {
+			return cmp.Compare(boolRank(x.Value), boolRank(y.Value))
+		}
+	case BytesValue:
+		if y, ok := b.(BytesValue); ok {
+			return bytes.Compare(x.Value, y.Value)
+		}
+	}
+	return compareText(a.String(), b.String())
+}
+
+// compareNumericText compares a and b by value when both are numbers
+func compareNumericText(a, b string) (int, bool) {
+	x, ok := new(big.Float).SetString(strings.TrimSpace(a))
+	if !ok {
+		return 0, false
+	}
+	y, ok := new(big.Float).SetString(strings.TrimSpace(b))
+	if !ok {
+		return 0, false
+	}
+	return x.Cmp(y), true
+}
+
+// numericValue returns the value of an integer, float or decimal
+func numericValue(v Value) (*big.Float, bool) {
+	switch n := v.(type) {
+	case IntValue:
+		return new(big.Float).SetInt64(n.Value), true
+	case FloatValue:
+		// NaN has no place in an order, and big.Float cannot hold it
+		if math.IsNaN(n.Value) {
+			return nil, false
+		}
+		return big.NewFloat(n.Value), true
+	case DecimalValue:
+		f, ok := new(big.Float).SetString(n.Value)
+		return f, ok
+	}
+	return nil, false
+}
+
+// timeValue returns the time of a date, time or timestamp
+func timeValue(v Value) (time.Time, bool) {
+	switch t := v.(type) {
+	case DateValue:
+		return t.Value, true
+	case TimeValue:
+		return t.Value, true
+	case DateTimeValue:
+		return t.Value, true
+	case DateTimeTzValue:
+		return t.Value, true
+	}
+	return time.Time{}, false
+}
+
+func boolRank(b bool) int {
+	if b {
+		return 1
+	}
+	return 0
+}
+
+func isNullValue(v Value) bool {
+	return v == nil || v.IsNull()
+}
+
+// ParseCollation checks a collation name, returning it in canonical form
+func ParseCollation(name string) (string, bool) {
+	switch strings.ToLower(name) {
+	case "", CollationUnicode:
+		return CollationUnicode, true
+	case CollationBytes:
+		return CollationBytes, true
+	}
+	return "", false
+}
@@ -0,0 +1,118 @@
+package core
+
+import (
+	"slices"
+	"testing"
+	"time"
+)
+
+func sortedColumn(values []Value, opts SortOptions) []string {
+	rows := make([][]Value, len(values))
+	for i, value := range values {
+		rows[i] = []Value{value}
+	}
+	SortRows(rows, 0, opts)
+
+	keys := make([]string, len(rows))
+	for i, row := range rows {
+		keys[i] = row[0].String()
+		if row[0].IsNull() {
+			keys[i] = "NULL"
+		}
+	}
+	return keys
+}
+
+func textValues(values ...string) []Value {
+	out := make([]Value, len(values))
+	for i, value := range values {
+		out[i] = StringValue{Value: value}
+	}
+	return out
+}
+
+func TestSortRows_Collation(t *testing.T) {
+	names := textValues("banana", "Zebra", "Éclair", "apple", "eclair", "Apple", "cherry")
+
+	tests := []struct {
+		name string
+		opts SortOptions
+		want []string
+	}{
+		{"unicode by default", SortOptions{}, []string{"apple", "Apple", "banana", "cherry", "eclair", "Éclair", "Zebra"}},
+		{"bytes", SortOptions{Collation: CollationBytes}, []string{"Apple", "Zebra", "apple", "banana", "cherry", "eclair", "Éclair"}},
+		{"unicode descending", SortOptions{Collation: CollationUnicode, Descending: true}, []string{"Zebra", "Éclair", "eclair", "cherry", "banana", "Apple", "apple"}},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := sortedColumn(names, tt.opts); !slices.Equal(got, tt.want) {
+				t.Errorf("Expected %v, got %v", tt.want, got)
+			}
+		})
+	}
+}
+
+func TestSortRows_Numeric(t *testing.T) {
+	values := textValues("10", "9", "file10", "100", "file9", "2")
+
+	tests := []struct {
+		name string
+		opts SortOptions
+		want []string
+	}{
+		{"unicode as text", SortOptions{}, []string{"10", "100", "2", "9", "file10", "file9"}},
+		{"unicode numeric", SortOptions{Numeric: true}, []string{"2", "9", "10", "100", "file9", "file10"}},
+		{"bytes numeric", SortOptions{Collation: CollationBytes, Numeric: true}, []string{"2", "9", "10", "100", "file10", "file9"}},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := sortedColumn(values, tt.opts); !slices.Equal(got, tt.want) {
+				t.Errorf("Expected %v, got %v", tt.want, got)
+			}
+		})
+	}
+}
+
+func TestSortRows_TypedValuesAndNulls(t *testing.T) {
+	numbers := []Value{IntValue{Value: 10}, NullValue{}, FloatValue{Value: 2.5}, DecimalValue{Value: "9.75"}, IntValue{Null: true}, IntValue{Value: -3}}
+	if got, want := sortedColumn(numbers, SortOptions{}), []string{"-3", "2.5", "9.75", "10", "NULL", "NULL"}; !slices.Equal(got, want) {
+		t.Errorf("Expected numbers by value with NULLs last, got %v", got)
+	}
+	if got, want := sortedColumn(numbers, SortOptions{Descending: true}), []string{"10", "9.75", "2.5", "-3", "NULL", "NULL"}; !slices.Equal(got, want) {
+		t.Errorf("Expected NULLs to stay last when descending, got %v", got)
+	}
+
+	day := func(d int) Value { return DateValue{Value: time.Date(2024, time.January, d, 0, 0, 0, 0, time.UTC)} }
+	if got, want := sortedColumn([]Value{day(20), day(3), day(11)}, SortOptions{}), []string{"2024-01-03", "2024-01-11", "2024-01-20"}; !slices.Equal(got, want) {
+		t.Errorf("Expected dates in calendar order, got %v", got)
+	}
+}
+
+func TestSortRows_Stable(t *testing.T) {
+	rows := [][]Value{
+		{StringValue{Value: "b"}, IntValue{Value: 1}},
+		{StringValue{Value: "a"}, IntValue{Value: 2}},
+		{StringValue{Value: "b"}, IntValue{Value: 3}},
+		{StringValue{Value: "a"}, IntValue{Value: 4}},
+	}
+	SortRows(rows, 0, SortOptions{})
+
+	var order []string
+	for _, row := range rows {
+		order = append(order, row[1].String())
+	}
+	if want := []string{"2", "4", "1", "3"}; !slices.Equal(order, want) {
+		t.Errorf("Expected equal keys to keep their order, got %v", order)
+	}
+}
+
+func TestParseCollation(t *testing.T) {
+	for name, want := range map[string]string{"": CollationUnicode, "Unicode": CollationUnicode, "BYTES": CollationBytes} {
+		if got, ok := ParseCollation(name); !ok || got != want {
+			t.Errorf("ParseCollation(%q) = %q, %v, expected %q", name, got, ok, want)
+		}
+	}
+	if _, ok := ParseCollation("icu"); ok {
+		t.Error("Expected an unknown collation to be rejected")
+	}
+}
@@ -0,0 +1,114 @@
+package core
+
+import "strings"
+
+// SplitStatements splits sql at semicolons that are outside string literals,
+// quoted identifiers and comments. Each statement is trimmed and has its
+// leading comments removed; statements holding only comments are skipped.
+// The last statement does not need a terminating semicolon. dbType decides
+// which strings a backslash escapes in, see stringSpan.
+func SplitStatements(dbType DatabaseType, sql string) []string {
+	var statements []string
+	start := 0
+	add := func(end int) {
+		if statement := strings.TrimSpace(stripLeadingComments(dbType, sql[start:end])); statement != "" {
+			statements = append(statements, statement)
+		}
+	}
+
+	for _, end := range terminators(dbType, sql) {
+		add(end)
+		start = end + 1
+	}
+	add(len(sql))
+	return statements
+}
+
+// LastTerminator returns the byte offset of the last semicolon in sql that ends
+// a statement, or -1 when there is none
+func LastTerminator(dbType DatabaseType, sql string) int {
+	ends := terminators(dbType, sql)
+	if len(ends) == 0 {
+		return -1
+	}
+	return ends[len(ends)-1]
+}
+
+// StatementComplete reports whether sql is ready to run: no string, quoted
+// identifier, block comment or parenthesis is left open, and its last
+// statement ends with a semicolon. Only a comment or a "> file.csv" export
+// may follow that semicolon.
+func StatementComplete(dbType DatabaseType, sql string) bool {
+	ends, open := scanStatements(dbType, sql)
+	if open || len(ends) == 0 {
+		return false
+	}
+	after := stripLeadingComments(dbType, sql[ends[len(ends)-1]+1:])
+	return after == "" || strings.HasPrefix(after, ">")
+}
+
+// terminators returns the offsets of semicolons outside quotes and comments
+func terminators(dbType DatabaseType, sql string) []int {
+	ends, _ := scanStatements(dbType, sql)
+	return ends
+}
+
+// scanStatements returns the offsets of semicolons outside quotes and
+// comments, and whether sql ends inside a quote, block comment or parentheses
+func scanStatements(dbType DatabaseType, sql string) ([]int, bool) {
+	var ends []int
+	depth := 0
+	for i := 0; i < len(sql); {
+		rest := sql[i:]
+		switch {
+		case lineComment(dbType, rest):
+			end := strings.IndexByte(rest, '\n')
+			if end < 0 {
+				return ends, depth > 0
+			}
+			i += end + 1
+		case strings.HasPrefix(rest, "/*"):
+			end := strings.Index(rest[2:], "*/")
+			if end < 0 {
+				return ends, true
+			}
+			i += end + 4
+		case rest[0] == '\'' || isStringPrefix(rest) && !endsWithIdentRune(sql[:i]):
+			end, closed := stringSpan(dbType, rest)
+			if !closed {
+				return ends, true
+			}
+			i += end
+		case rest[0] == '"' || rest[0] == '`':
+			end, closed := quotedSpan(rest, rest[0], false)
+			if !closed {
+				return ends, true
+			}
+			i += end
+		case rest[0] == '$' && !endsWithIdentRune(sql[:i]):
+			tag, ok := dollarTag(rest)
+			if !ok {
+				i++
+				continue
+			}
+			end := strings.Index(rest[len(tag):], tag)
+			if end < 0 {
+				return ends, true
+			}
+			i += len(tag) + end + len(tag)
+		case rest[0] == '(':
+			depth++
+			i++
+		case rest[0] == ')':
+			depth = max(depth-1, 0)
+			i++
+		case rest[0] == ';':
+			ends = append(ends, i)
+			i++
+		default:
+			i++
+		}
+	}
+	return ends, depth > 0
+}
+
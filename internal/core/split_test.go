@@ -0,0 +1,171 @@
+package core
+
+import (
+	"reflect"
+	"testing"
+)
+
+func TestSplitStatements(t *testing.T) {
+	testCases := []struct {
+		name     string
+		sql      string
+		expected []string
+	}{
+		{
+			name:     "Semicolon inside single quotes",
+			sql:      "SELECT ';' AS sep; SELECT 2;",
+			expected: []string{"SELECT ';' AS sep", "SELECT 2"},
+		},
+		{
+			name:     "Escaped quotes",
+			sql:      `SELECT 'it''s; fine', 'a\'; b'; SELECT 2`,
+			expected: []string{`SELECT 'it''s; fine', 'a\'; b'`, "SELECT 2"},
+		},
+		{
+			name:     "Semicolon inside a line comment",
+			sql:      "-- comment; note\nSELECT 1;\nSELECT 2 -- trailing; note\n;",
+			expected: []string{"SELECT 1", "SELECT 2 -- trailing; note"},
+		},
+		{
+			name:     "Semicolon inside a block comment",
+			sql:      "/* setup; part 1 */ SELECT 1; SELECT /* x; y */ 2;",
+			expected: []string{"SELECT 1", "SELECT /* x; y */ 2"},
+		},
+		{
+			name:     "Quoted identifiers",
+			sql:      "SELECT \"a;b\", `c;d` FROM t; SELECT 2",
+			expected: []string{"SELECT \"a;b\", `c;d` FROM t", "SELECT 2"},
+		},
+		{
+			name:     "Dollar quoted function body",
+			sql:      "CREATE FUNCTION f() RETURNS int AS $$ BEGIN RETURN 1; END; $$ LANGUAGE plpgsql; SELECT f()",
+			expected: []string{"CREATE FUNCTION f() RETURNS int AS $$ BEGIN RETURN 1; END; $$ LANGUAGE plpgsql", "SELECT f()"},
+		},
+		{
+			name:     "No trailing semicolon",
+			sql:      "SELECT 1;\nSELECT 2",
+			expected: []string{"SELECT 1", "SELECT 2"},
+		},
+		{
+			name:     "Only comments and empty statements",
+			sql:      "-- nothing here\n;;  /* still nothing */",
+			expected: nil,
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.name, func(t *testing.T) {
+			if got := SplitStatements(MySQL, tc.sql); !reflect.DeepEqual(got, tc.expected) {
+				t.Errorf("SplitStatements(%q)\n got      %q\n expected %q", tc.sql, got, tc.expected)
+			}
+		})
+	}
+}
+
+func TestLastTerminator(t *testing.T) {
+	testCases := []struct {
+		sql      string
+		expected int
+	}{
+		{"SELECT 1;", 8},
+		{"SELECT ';'", -1},
+		{"SELECT 1 -- done;", -1},
+		{"SELECT 1; > out.csv", 8},
+		{"SELECT 'unterminated;", -1},
+		{"", -1},
+	}
+
+	for _, tc := range testCases {
+		if got := LastTerminator(MySQL, tc.sql); got != tc.expected {
+			t.Errorf("LastTerminator(%q) = %d, expected %d", tc.sql, got, tc.expected)
+		}
+	}
+}
+
+func TestStatementComplete(t *testing.T) {
+	testCases := []struct {
+		sql      string
+		expected bool
+	}{
+		{"SELECT 1;", true},
+		{"SELECT 1", false},
+		{"WITH t AS (\n  SELECT 1;", false},
+		{"WITH t AS (\n  SELECT 1\n)\nSELECT * FROM t;", true},
+		{"SELECT 'it''s;", false},
+		{"SELECT 'done';", true},
+		{"SELECT 1 /* note;", false},
+		{"SELECT $$ body; $$;", true},
+		{"SELECT $$ body;", false},
+		{"SELECT 1; -- done", true},
+		{"SELECT 1; > out.csv", true},
+		{"SELECT 1; SELECT 2", false},
+		{"SELECT 1 -- done;", false},
+	}
+
+	for _, tc := range testCases {
+		if got := StatementComplete(MySQL, tc.sql); got != tc.expected {
+			t.Errorf("StatementComplete(%q) = %v, expected %v", tc.sql, got, tc.expected)
+		}
+	}
+}
+
+func TestSplitStatements_Backslashes(t *testing.T) {
+	testCases := []struct {
+		name     string
+		dbType   DatabaseType
+		sql      string
+		expected []string
+	}{
+		{"PostgreSQL string ending in a backslash", PostgreSQL, `SELECT 'C:\'; SELECT 2`, []string{`SELECT 'C:\'`, "SELECT 2"}},
+		{"SQLite string ending in a backslash", SQLite, `SELECT 'dir\'; SELECT 2`, []string{`SELECT 'dir\'`, "SELECT 2"}},
+		{"MySQL backslash escape", MySQL, `SELECT 'a\'; b'; SELECT 2`, []string{`SELECT 'a\'; b'`, "SELECT 2"}},
+		{"PostgreSQL E string escape", PostgreSQL, `SELECT E'a\'; b'; SELECT 2`, []string{`SELECT E'a\'; b'`, "SELECT 2"}},
+		{"PostgreSQL E string ending in a backslash", PostgreSQL, `SELECT e'\\'; SELECT 2`, []string{`SELECT e'\\'`, "SELECT 2"}},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.name, func(t *testing.T) {
+			if got := SplitStatements(tc.dbType, tc.sql); !reflect.DeepEqual(got, tc.expected) {
+				t.Errorf("SplitStatements(%q)\n got      %q\n expected %q", tc.sql, got, tc.expected)
+			}
+		})
+	}
+}
+
+func TestStatementComplete_Backslashes(t *testing.T) {
+	testCases := []struct {
+		dbType   DatabaseType
+		sql      string
+		expected bool
+	}{
+		{PostgreSQL, `SELECT 'C:\';`, true},
+		{SQLite, `SELECT 'C:\';`, true},
+		{MySQL, `SELECT 'C:\';`, false},
+		{MySQL, `SELECT 'C:\\';`, true},
+		{PostgreSQL, `SELECT E'C:\';`, false},
+	}
+
+	for _, tc := range testCases {
+		if got := StatementComplete(tc.dbType, tc.sql); got != tc.expected {
+			t.Errorf("StatementComplete(%s, %q) = %v, expected %v", tc.dbType, tc.sql, got, tc.expected)
+		}
+	}
+}
+
+func TestSplitStatements_HashComments(t *testing.T) {
+	sql := "SELECT 1; # don't\nSELECT 2; # only a comment"
+	if got, expected := SplitStatements(MySQL, sql), []string{"SELECT 1", "SELECT 2"}; !reflect.DeepEqual(got, expected) {
+		t.Errorf("SplitStatements(MySQL, %q)\n got      %q\n expected %q", sql, got, expected)
+	}
+	if !StatementComplete(MySQL, "SELECT 1; # don't") {
+		t.Error("Expected a statement followed by a # comment to be complete on MySQL")
+	}
+	if StatementComplete(MySQL, "SELECT 1 # done;") {
+		t.Error("Expected the semicolon inside a # comment not to end the statement")
+	}
+
+	// PostgreSQL reads # as an operator
+	if got := SplitStatements(PostgreSQL, "SELECT 5 # 3; SELECT 2"); len(got) != 2 {
+		t.Errorf("Expected # to be an operator on PostgreSQL, got %q", got)
+	}
+}
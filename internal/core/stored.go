@@ -0,0 +1,129 @@
+package core
+
+import "time"
+
+// MaxStoredRows bounds how many rows of one result are kept in memory for paging
+const MaxStoredRows = 100000
+
+// StoredResult holds the rows of a finished query so they can be shown a page
+// at a time without running the query again
+type StoredResult struct {
+	Columns []Column
+	Rows    [][]Value
+	// Incomplete reports that the query returned more rows than were stored
+	Incomplete bool
+	// Source is the connection the result came from, when known
+	Source ResultSource
+	// Number is the result's place in the session's numbered history, 0 when it has none
+	Number int
+	// Timing is how long the query took, zero when it was not measured
+	Timing QueryTiming
+	// GeneratedBy is the Number of the result whose cells the query came from, 0 when typed
+	GeneratedBy int
+	// Notices are the warnings and notices the server sent about the query
+	Notices []Notice
+
+	shown int
+}
+
+// ResultSource records which connection produced a result and when, so results
+// kept across connection switches can be told apart
+type ResultSource struct {
+	Connection string
+	Database   string
+	Time       time.Time
+	// DatabaseType is how the result's query is read when it is redacted
+	DatabaseType DatabaseType `yaml:"database_type,omitempty"`
+}
+
+// Label renders the source for history listings, e.g. "[14:02:11 prod-replica]"
+func (s ResultSource) Label() string {
+	if s.Connection == "" {
+		return "[" + s.Time.Format("15:04:05") + "]"
+	}
+	return "[" + s.Time.Format("15:04:05") + " " + s.Connection + "]"
+}
+
+// StoreResult reads up to maxRows rows of result and closes it. One extra row
+// is read to tell whether the result was cut off.
+func StoreResult(result *QueryResult, maxRows int) (*StoredResult, error) {
+	stored := &StoredResult{Columns: result.Columns}
+	for row := range result.Itor() {
+		if len(stored.Rows) >= maxRows {
+			stored.Incomplete = true
+			break
+		}
+		stored.Rows = append(stored.Rows, row)
+	}
+	// The server's notices are only known once the result is closed
+	result.Close()
+	if err := result.Error(); err != nil {
+		return nil, err
+	}
+	stored.Notices = result.Notices()
+	return stored, nil
+}
+
+// NextPage returns up to n rows following those already shown
+func (s *StoredResult) NextPage(n int) [][]Value {
+	end := min(s.shown+n, len(s.Rows))
+	page := s.Rows[s.shown:end]
+	s.shown = end
+	return page
+}
+
+// Rewind starts paging from the first row again
+func (s *StoredResult) Rewind() {
+	s.shown = 0
+}
+
+// Shown is how many rows have been returned by NextPage so far
+func (s *StoredResult) Shown() int {
+	return s.shown
+}
+
+// sizeSampleRows is how many rows EstimatedSize measures to find the average row
+const sizeSampleRows = 100
+
+// EstimatedSize is roughly how many bytes the rows take in memory: the
+// average size of up to sizeSampleRows rows spread over the result, times
+// the number of rows
+func (s *StoredResult) EstimatedSize() int64 {
+	if len(s.Rows) == 0 {
+		return 0
+	}
+	step := max(len(s.Rows)/sizeSampleRows, 1)
+	var sampled, bytes int64
+	for i := 0; i < len(s.Rows); i += step {
+		bytes += rowSize(s.Rows[i])
+		sampled++
+	}
+	return bytes / sampled * int64(len(s.Rows))
+}
+
+// rowSize estimates the memory of one row: the slice header, then for each
+// value an interface header and the value itself, with the text or bytes it
+// points to. Numbers, booleans and times are counted at the largest of them.
+func rowSize(row []Value) int64 {
+	size := int64(24)
+	for _, value := range row {
+		size += 16
+		switch v := value.(type) {
+		case StringValue:
+			size += 24 + int64(len(v.Value))
+		case DecimalValue:
+			size += 24 + int64(len(v.Value))
+		case BytesValue:
+			size += 32 + int64(cap(v.Value))
+		case nil, NullValue:
+		default:
+			size += 32
+		}
+	}
+	return size
+}
+
+// Remaining reports whether stored rows are left to show
+func (s *StoredResult) Remaining() bool {
+	return s.shown < len(s.Rows)
+}
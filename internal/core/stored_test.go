@@ -0,0 +1,104 @@
+package core
+
+import (
+	"errors"
+	"strings"
+	"testing"
+	"time"
+
+	"sqlterm/internal/i18n"
+)
+
+func intRows(n int) [][]Value {
+	rows := make([][]Value, n)
+	for i := range rows {
+		rows[i] = []Value{IntValue{Value: int64(i + 1)}}
+	}
+	return rows
+}
+
+func TestStoreResult(t *testing.T) {
+	columns := []Column{{Name: "id"}}
+
+	stored, err := StoreResult(NewStaticQueryResult(columns, intRows(5)), 5)
+	if err != nil || len(stored.Rows) != 5 || stored.Incomplete {
+		t.Errorf("Expected all 5 rows without truncation, got %d rows, incomplete=%v, err=%v", len(stored.Rows), stored.Incomplete, err)
+	}
+
+	stored, _ = StoreResult(NewStaticQueryResult(columns, intRows(6)), 5)
+	if len(stored.Rows) != 5 || !stored.Incomplete {
+		t.Errorf("Expected 5 stored rows of an incomplete result, got %d, incomplete=%v", len(stored.Rows), stored.Incomplete)
+	}
+
+	var pages []int
+	for stored.Remaining() {
+		pages = append(pages, len(stored.NextPage(2)))
+	}
+	if len(pages) != 3 || pages[2] != 1 || stored.Shown() != 5 {
+		t.Errorf("Expected pages of 2, 2 and 1 rows, got %v", pages)
+	}
+}
+
+func TestResultPageMarkdown(t *testing.T) {
+	i18nMgr, err := i18n.NewManager("en_au")
+	if err != nil {
+		t.Fatalf("Failed to create i18n manager: %v", err)
+	}
+	stored, _ := StoreResult(NewStaticQueryResult([]Column{{Name: "id"}}, intRows(2500)), MaxStoredRows)
+	format := FormatOptions{RowLimit: 1000, FloatPrecision: -1}
+
+	first := ResultPageMarkdown(stored, nil, format, i18nMgr)
+	if !strings.Contains(first, "| 1000 |") || strings.Contains(first, "| 1001 |") {
+		t.Error("Expected the first page to hold rows 1-1000")
+	}
+	if !strings.Contains(first, "rows 1–1000 of 2,500") || !strings.Contains(first, "/more") {
+		t.Errorf("Expected a footer pointing to /more, got %q", first[strings.LastIndex(first, "\n\n"):])
+	}
+
+	ResultPageMarkdown(stored, nil, format, i18nMgr)
+	last := ResultPageMarkdown(stored, nil, format, i18nMgr)
+	if !strings.Contains(last, "| 2500 |") || strings.Contains(last, "/more") {
+		t.Error("Expected the last page to end the result without a /more footer")
+	}
+}
+
+func TestStoreResult_ReadError(t *testing.T) {
+	result := NewStaticQueryResult(nil, nil)
+	result.err = errors.New("connection reset")
+	if _, err := StoreResult(result, 10); err == nil {
+		t.Error("Expected the read error to be returned")
+	}
+}
+
+func TestResultSource_Label(t *testing.T) {
+	at := time.Date(2024, 5, 1, 14, 2, 11, 0, time.Local)
+	tests := []struct {
+		source ResultSource
+		want   string
+	}{
+		{ResultSource{Connection: "prod-replica", Database: "shop", Time: at}, "[14:02:11 prod-replica]"},
+		{ResultSource{Time: at}, "[14:02:11]"},
+	}
+	for _, tt := range tests {
+		if got := tt.source.Label(); got != tt.want {
+			t.Errorf("Label() = %q, want %q", got, tt.want)
+		}
+	}
+}
+
+func TestSaveResultPageAsMarkdown_Source(t *testing.T) {
+	i18nMgr, err := i18n.NewManager("en_au")
+	if err != nil {
+		t.Fatalf("Failed to create i18n manager: %v", err)
+	}
+	stored, _ := StoreResult(NewStaticQueryResult([]Column{{Name: "id"}}, intRows(1)), MaxStoredRows)
+	stored.Source = ResultSource{Connection: "prod-replica", Database: "shop", Time: time.Now()}
+
+	var sb strings.Builder
+	if err := SaveResultPageAsMarkdown(stored, nil, "SELECT id FROM t", &sb, DefaultFormatOptions(), i18nMgr); err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if !strings.Contains(sb.String(), "Ran on prod-replica (database shop)") {
+		t.Errorf("Expected the result to name its connection, got:\n%s", sb.String())
+	}
+}
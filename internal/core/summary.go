@@ -0,0 +1,72 @@
+package core
+
+import (
+	"math"
+	"time"
+)
+
+// summaryStatementWidth is how many columns of each statement a run summary shows
+const summaryStatementWidth = 60
+
+// Statement statuses in run summaries and history exports
+const (
+	StatusOK        = "ok"
+	StatusError     = "error"
+	StatusCancelled = "cancelled"
+	// StatusIncomplete marks a history entry that returned more rows than were kept
+	StatusIncomplete = "incomplete"
+)
+
+// StatementOutcome is how one statement of a multi-statement run went
+type StatementOutcome struct {
+	Query    string
+	Duration time.Duration
+	Rows     int
+	Status   string
+}
+
+// summaryColumns are the columns shared by run summaries and history exports
+var summaryColumns = []Column{{Name: "#"}, {Name: "statement"}, {Name: "duration_ms"}, {Name: "rows"}, {Name: "status"}}
+
+// StatementSummary lays out outcomes as a result with a row per statement:
+// its number, the start of its SQL, how long it took, its rows and status
+func StatementSummary(outcomes []StatementOutcome) *StoredResult {
+	summary := &StoredResult{Columns: summaryColumns}
+	for i, outcome := range outcomes {
+		summary.Rows = append(summary.Rows, summaryRow(i+1, ElideLine(outcome.Query, summaryStatementWidth), outcome.Duration, outcome.Rows, outcome.Status))
+	}
+	return summary
+}
+
+// HistoryExport lays out the saved history entries run at or after since
+// like a run summary, with the full SQL and the connection and time of each
+func HistoryExport(entries []*ResultEntry, since time.Time) *StoredResult {
+	export := &StoredResult{Columns: append(append([]Column{}, summaryColumns...), Column{Name: "connection"}, Column{Name: "time"})}
+	for _, entry := range entries {
+		if entry.Source.Time.Before(since) {
+			continue
+		}
+		status := StatusOK
+		if entry.Incomplete {
+			status = StatusIncomplete
+		}
+		row := summaryRow(entry.Number, entry.Query, entry.Timing.Total, entry.Rows, status)
+		export.Rows = append(export.Rows, append(row,
+			StringValue{Value: entry.Source.Connection},
+			DateTimeTzValue{Value: entry.Source.Time},
+		))
+	}
+	return export
+}
+
+func summaryRow(number int, query string, duration time.Duration, rows int, status string) []Value {
+	// Tenths of a millisecond tell fast statements apart without implying more precision
+	ms := math.Round(float64(duration)/float64(time.Millisecond)*10) / 10
+	return []Value{
+		IntValue{Value: int64(number)},
+		StringValue{Value: query},
+		FloatValue{Value: ms},
+		IntValue{Value: int64(rows)},
+		StringValue{Value: status},
+	}
+}
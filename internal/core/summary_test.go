@@ -0,0 +1,55 @@
+package core
+
+import (
+	"strings"
+	"testing"
+	"time"
+)
+
+func TestStatementSummary(t *testing.T) {
+	long := "SELECT " + strings.Repeat("a, ", 30) + "b FROM t"
+	summary := StatementSummary([]StatementOutcome{
+		{Query: "INSERT INTO t VALUES (1)", Duration: 1234567 * time.Nanosecond, Rows: 0, Status: StatusOK},
+		{Query: long, Duration: 2 * time.Second, Rows: 42, Status: StatusError},
+	})
+
+	if len(summary.Columns) != 5 || len(summary.Rows) != 2 {
+		t.Fatalf("Expected 5 columns and 2 rows, got %d and %d", len(summary.Columns), len(summary.Rows))
+	}
+	first := summary.Rows[0]
+	if first[0].(IntValue).Value != 1 || first[2].(FloatValue).Value != 1.2 || first[4].(StringValue).Value != StatusOK {
+		t.Errorf("Unexpected first row: %v", first)
+	}
+	second := summary.Rows[1]
+	if got := second[1].(StringValue).Value; got == long || len([]rune(got)) > summaryStatementWidth {
+		t.Errorf("Expected the statement to be elided to %d columns, got %q", summaryStatementWidth, got)
+	}
+	if second[2].(FloatValue).Value != 2000 || second[3].(IntValue).Value != 42 {
+		t.Errorf("Unexpected second row: %v", second)
+	}
+}
+
+func TestHistoryExport(t *testing.T) {
+	now := time.Date(2026, 10, 16, 12, 0, 0, 0, time.UTC)
+	entries := []*ResultEntry{
+		{Number: 1, Query: "SELECT 1", Source: ResultSource{Connection: "old", Time: now.AddDate(0, 0, -10)}},
+		{Number: 2, Query: "SELECT 2", Rows: 1000, Incomplete: true, Source: ResultSource{Connection: "dev", Time: now.AddDate(0, 0, -1)},
+			Timing: QueryTiming{Total: 15 * time.Millisecond}},
+	}
+
+	export := HistoryExport(entries, now.AddDate(0, 0, -7))
+	if len(export.Columns) != 7 || len(export.Rows) != 1 {
+		t.Fatalf("Expected 7 columns and 1 row, got %d and %d", len(export.Columns), len(export.Rows))
+	}
+	row := export.Rows[0]
+	if row[0].(IntValue).Value != 2 || row[2].(FloatValue).Value != 15 || row[4].(StringValue).Value != StatusIncomplete {
+		t.Errorf("Unexpected row: %v", row)
+	}
+	if row[5].(StringValue).Value != "dev" {
+		t.Errorf("Expected the connection of the entry, got %v", row[5])
+	}
+
+	if all := HistoryExport(entries, time.Time{}); len(all.Rows) != 2 {
+		t.Errorf("Expected every entry without a since, got %d", len(all.Rows))
+	}
+}
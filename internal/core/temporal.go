@@ -0,0 +1,190 @@
+package core
+
+import (
+	"strings"
+	"time"
+)
+
+// Display layouts for temporal values
+const (
+	DateLayout       = "2006-01-02"
+	TimeLayout       = "15:04:05"
+	DateTimeLayout   = "2006-01-02 15:04:05"
+	DateTimeTzLayout = "2006-01-02 15:04:05-0700"
+)
+
+// ISO 8601 layouts used for machine-readable output such as CSV
+const (
+	isoTimeLayout     = "15:04:05.999999999"
+	isoDateTimeLayout = "2006-01-02T15:04:05.999999999"
+)
+
+// DateValue is a calendar date without a time of day
+type DateValue struct {
+	Value time.Time
+	Null  bool
+}
+
+func (d DateValue) String() string {
+	if d.Null {
+		return ""
+	}
+	return d.Value.Format(DateLayout)
+}
+
+func (d DateValue) IsNull() bool {
+	return d.Null
+}
+
+// TimeValue is a time of day without a date; only the clock fields of Value are meaningful
+type TimeValue struct {
+	Value time.Time
+	Null  bool
+}
+
+func (t TimeValue) String() string {
+	if t.Null {
+		return ""
+	}
+	return t.Value.Format(TimeLayout)
+}
+
+func (t TimeValue) IsNull() bool {
+	return t.Null
+}
+
+// DateTimeValue is a date and time without a time zone (DATETIME, TIMESTAMP WITHOUT TIME ZONE)
+type DateTimeValue struct {
+	Value time.Time
+	Null  bool
+}
+
+func (d DateTimeValue) String() string {
+	if d.Null {
+		return ""
+	}
+	return d.Value.Format(DateTimeLayout)
+}
+
+func (d DateTimeValue) IsNull() bool {
+	return d.Null
+}
+
+// DateTimeTzValue is an instant in time, normalised to UTC (TIMESTAMPTZ)
+type DateTimeTzValue struct {
+	Value time.Time
+	Null  bool
+}
+
+func (d DateTimeTzValue) String() string {
+	if d.Null {
+		return ""
+	}
+	return d.Value.UTC().Format(DateTimeTzLayout)
+}
+
+func (d DateTimeTzValue) IsNull() bool {
+	return d.Null
+}
+
+// convertTemporal maps a driver time to the Value matching the column's database type.
+// Unknown types keep the zone so no information is dropped.
+func convertTemporal(columnType string, t time.Time) Value {
+	switch columnType {
+	case "DATE":
+		return DateValue{Value: t}
+	case "TIME", "TIMETZ":
+		return TimeValue{Value: t}
+	case "DATETIME", "TIMESTAMP":
+		return DateTimeValue{Value: t}
+	default:
+		return DateTimeTzValue{Value: t.UTC()}
+	}
+}
+
+// parseTimeOfDay parses a TIME column delivered as text. MySQL TIME can exceed
+// 24 hours (it is really an interval), in which case ok is false.
+func parseTimeOfDay(s string) (time.Time, bool) {
+	t, err := time.Parse(isoTimeLayout, strings.TrimSpace(s))
+	if err != nil {
+		return time.Time{}, false
+	}
+	return t, true
+}
+
+// FormatISO renders a value in its machine-readable form: ISO 8601 for temporal values
+// and the plain string form for everything else
+func FormatISO(v Value) string {
+	if v.IsNull() {
+		return ""
+	}
+
+	switch t := v.(type) {
+	case DateValue:
+		return t.Value.Format(DateLayout)
+	case TimeValue:
+		return t.Value.Format(isoTimeLayout)
+	case DateTimeValue:
+		return t.Value.Format(isoDateTimeLayout)
+	case DateTimeTzValue:
+		return t.Value.UTC().Format(time.RFC3339Nano)
+	default:
+		return v.String()
+	}
+}
+
+// CompareValues orders two values of a column for sorting results: NULLs first,
+// numbers numerically, temporal values chronologically, and anything else by text
+func CompareValues(a, b Value) int {
+	switch {
+	case a.IsNull() && b.IsNull():
+		return 0
+	case a.IsNull():
+		return -1
+	case b.IsNull():
+		return 1
+	}
+
+	if ta, ok := temporalInstant(a); ok {
+		if tb, ok := temporalInstant(b); ok {
+			return ta.Compare(tb)
+		}
+	}
+
+	switch va := a.(type) {
+	case IntValue:
+		if vb, ok := b.(IntValue); ok {
+			return compareOrdered(va.Value, vb.Value)
+		}
+	case FloatValue:
+		if vb, ok := b.(FloatValue); ok {
+			return compareOrdered(va.Value, vb.Value)
+		}
+	}
+
+	return strings.Compare(a.String(), b.String())
+}
+
+func temporalInstant(v Value) (time.Time, bool) {
+	switch t := v.(type) {
+	case DateValue:
+		return t.Value, true
+	case TimeValue:
+		return t.Value, true
+	case DateTimeValue:
+		return t.Value, true
+	case DateTimeTzValue:
+		return t.Value, true
+	}
+	return time.Time{}, false
+}
+
+func compareOrdered[T int64 | float64](a, b T) int {
+	switch {
+	case a < b:
+		return -1
+	case a > b:
+		return 1
+	}
+	return 0
+}
@@ -0,0 +1,117 @@
+package core
+
+import (
+	"testing"
+	"time"
+)
+
+func TestTemporalValues_String(t *testing.T) {
+	instant := time.Date(2024, 3, 9, 14, 30, 5, 123000000, time.FixedZone("AEDT", 11*60*60))
+
+	testCases := []struct {
+		name    string
+		value   Value
+		display string
+		iso     string
+	}{
+		{
+			name:    "Date",
+			value:   DateValue{Value: instant},
+			display: "2024-03-09",
+			iso:     "2024-03-09",
+		},
+		{
+			name:    "Time",
+			value:   TimeValue{Value: instant},
+			display: "14:30:05",
+			iso:     "14:30:05.123",
+		},
+		{
+			name:    "DateTime",
+			value:   DateTimeValue{Value: instant},
+			display: "2024-03-09 14:30:05",
+			iso:     "2024-03-09T14:30:05.123",
+		},
+		{
+			name:    "DateTimeTz in UTC",
+			value:   DateTimeTzValue{Value: instant},
+			display: "2024-03-09 03:30:05+0000",
+			iso:     "2024-03-09T03:30:05.123Z",
+		},
+		{
+			name:    "Null date",
+			value:   DateValue{Null: true},
+			display: "",
+			iso:     "",
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.name, func(t *testing.T) {
+			if tc.value.String() != tc.display {
+				t.Errorf("Expected display '%s', got '%s'", tc.display, tc.value.String())
+			}
+
+			if FormatISO(tc.value) != tc.iso {
+				t.Errorf("Expected ISO '%s', got '%s'", tc.iso, FormatISO(tc.value))
+			}
+		})
+	}
+}
+
+func TestCompareValues(t *testing.T) {
+	day := func(d int) DateValue {
+		return DateValue{Value: time.Date(2024, 1, d, 0, 0, 0, 0, time.UTC)}
+	}
+
+	testCases := []struct {
+		name     string
+		a, b     Value
+		expected int
+	}{
+		{
+			name:     "Dates compare chronologically, not lexically",
+			a:        DateValue{Value: time.Date(999, 12, 31, 0, 0, 0, 0, time.UTC)},
+			b:        day(1),
+			expected: -1,
+		},
+		{
+			name:     "Equal dates",
+			a:        day(5),
+			b:        day(5),
+			expected: 0,
+		},
+		{
+			name:     "Instants in different zones",
+			a:        DateTimeTzValue{Value: time.Date(2024, 1, 1, 10, 0, 0, 0, time.FixedZone("AEDT", 11*60*60))},
+			b:        DateTimeTzValue{Value: time.Date(2024, 1, 1, 0, 0, 0, 0, time.UTC)},
+			expected: -1,
+		},
+		{
+			name:     "Integers numerically",
+			a:        IntValue{Value: 10},
+			b:        IntValue{Value: 9},
+			expected: 1,
+		},
+		{
+			name:     "NULL sorts first",
+			a:        NullValue{},
+			b:        day(1),
+			expected: -1,
+		},
+		{
+			name:     "Strings lexically",
+			a:        StringValue{Value: "apple"},
+			b:        StringValue{Value: "banana"},
+			expected: -1,
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.name, func(t *testing.T) {
+			if result := CompareValues(tc.a, tc.b); result != tc.expected {
+				t.Errorf("Expected %d, got %d", tc.expected, result)
+			}
+		})
+	}
+}
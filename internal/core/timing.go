@@ -0,0 +1,176 @@
+package core
+
+import (
+	"context"
+	"database/sql"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"regexp"
+	"strconv"
+	"strings"
+	"time"
+
+	"sqlterm/internal/i18n"
+)
+
+// ErrServerTimeNotSupported is returned when a connection cannot report server execution time
+var ErrServerTimeNotSupported = errors.New("server execution time is not supported for this database type")
+
+// QueryTiming records where the time of one query went
+type QueryTiming struct {
+	// Total is the wall-clock time measured client-side, from sending the query to the last row stored
+	Total time.Duration `yaml:"total"`
+	// RTT is the round trip of a ping sent just before the query; 0 when not measured
+	RTT time.Duration `yaml:"rtt,omitempty"`
+	// Server is the execution time the server reported; 0 when unknown
+	Server time.Duration `yaml:"server,omitempty"`
+}
+
+// Breakdown splits Total into network and server shares. The server's own
+// figure is used when known; otherwise one ping round trip stands in for the
+// network. ok is false when neither was measured.
+func (t QueryTiming) Breakdown() (network, server time.Duration, ok bool) {
+	switch {
+	case t.Server > 0:
+		server = min(t.Server, t.Total)
+		return t.Total - server, server, true
+	case t.RTT > 0:
+		network = min(t.RTT, t.Total)
+		return network, t.Total - network, true
+	}
+	return 0, 0, false
+}
+
+// Label renders the timing for a result's summary line, e.g.
+// "total 840 ms (network ~700 ms, server ~140 ms)"
+func (t QueryTiming) Label(i18nMgr *i18n.Manager) string {
+	if network, server, ok := t.Breakdown(); ok {
+		return i18nMgr.GetWithArgs("timing_breakdown", FormatMillis(t.Total), FormatMillis(network), FormatMillis(server))
+	}
+	return i18nMgr.GetWithArgs("timing_total", FormatMillis(t.Total))
+}
+
+// FormatMillis renders d in milliseconds, or in seconds from 10 s up
+func FormatMillis(d time.Duration) string {
+	switch {
+	case d >= 10*time.Second:
+		return strconv.FormatFloat(d.Seconds(), 'f', 1, 64) + " s"
+	case d > 0 && d < time.Millisecond:
+		return "<1 ms"
+	}
+	return strconv.FormatInt(d.Milliseconds(), 10) + " ms"
+}
+
+// MeasureRTT pings conn and returns how long the round trip took, or 0 when
+// the ping fails or ctx ends first
+func MeasureRTT(ctx context.Context, conn Connection) time.Duration {
+	start := time.Now()
+	if err := pingContext(ctx, conn); err != nil {
+		return 0
+	}
+	return time.Since(start)
+}
+
+// ServerTimer is implemented by connections that can report how long the
+// server itself spends on a query
+type ServerTimer interface {
+	// ServerTime runs query again under EXPLAIN ANALYZE in a read-only
+	// transaction and returns the execution time the server reports
+	ServerTime(ctx context.Context, query string) (time.Duration, error)
+}
+
+// MeasureServerTime asks conn how long the server spends on query. Only a
+// single SELECT is measured, since measuring runs the query a second time;
+// anything else, and any failure, leaves the server time unknown.
+func MeasureServerTime(ctx context.Context, conn Connection, dbType DatabaseType, query string) (time.Duration, bool) {
+	timer, ok := conn.(ServerTimer)
+	if !ok {
+		return 0, false
+	}
+	statements := SplitStatements(dbType, query)
+	if len(statements) != 1 || !IsReadOnlyQuery(dbType, query) {
+		return 0, false
+	}
+	fields := strings.Fields(statements[0])
+	if len(fields) == 0 || !containsFold([]string{"SELECT", "WITH"}, fields[0]) {
+		return 0, false
+	}
+
+	server, err := timer.ServerTime(ctx, statements[0])
+	if err != nil || server <= 0 {
+		return 0, false
+	}
+	return server, true
+}
+
+// PingContext pings the server until ctx ends, marking failures as Ping does
+func (c *SQLConnection) PingContext(ctx context.Context) error {
+	return classifyConnectError(redactConnectError(c.db.PingContext(ctx), c.config.Password))
+}
+
+// ServerTime runs query under EXPLAIN ANALYZE and reads the execution time
+// from the plan. The transaction is read-only and rolled back, so a query
+// that turns out to write fails instead of running twice.
+func (c *SQLConnection) ServerTime(ctx context.Context, query string) (time.Duration, error) {
+	var explain string
+	switch c.config.DatabaseType {
+	case MySQL:
+		explain = "EXPLAIN ANALYZE " + query
+	case PostgreSQL:
+		explain = "EXPLAIN (ANALYZE, FORMAT JSON) " + query
+	default:
+		return 0, ErrServerTimeNotSupported
+	}
+
+	tx, err := c.db.BeginTx(ctx, &sql.TxOptions{ReadOnly: true})
+	if err != nil {
+		return 0, fmt.Errorf("failed to start read-only transaction: %w", err)
+	}
+	defer tx.Rollback()
+
+	var plan string
+	if err := tx.QueryRowContext(ctx, explain).Scan(&plan); err != nil {
+		return 0, fmt.Errorf("failed to analyse query: %w", err)
+	}
+	if c.config.DatabaseType == PostgreSQL {
+		return postgresPlanTime(plan)
+	}
+	return mysqlPlanTime(plan)
+}
+
+// postgresPlanTime reads planning plus execution time from EXPLAIN (ANALYZE, FORMAT JSON)
+func postgresPlanTime(plan string) (time.Duration, error) {
+	var plans []struct {
+		PlanningTime  float64 `json:"Planning Time"`
+		ExecutionTime float64 `json:"Execution Time"`
+	}
+	if err := json.Unmarshal([]byte(plan), &plans); err != nil {
+		return 0, fmt.Errorf("failed to read plan: %w", err)
+	}
+	if len(plans) == 0 {
+		return 0, errors.New("empty plan")
+	}
+	return millis(plans[0].PlanningTime + plans[0].ExecutionTime), nil
+}
+
+// mysqlActualTime matches the "actual time=first..last" of a plan node
+var mysqlActualTime = regexp.MustCompile(`actual time=[0-9.]+\.\.([0-9.]+)`)
+
+// mysqlPlanTime reads the time of the top node of an EXPLAIN ANALYZE tree,
+// which covers every node below it
+func mysqlPlanTime(plan string) (time.Duration, error) {
+	match := mysqlActualTime.FindStringSubmatch(plan)
+	if match == nil {
+		return 0, errors.New("no timing in plan")
+	}
+	ms, err := strconv.ParseFloat(match[1], 64)
+	if err != nil {
+		return 0, fmt.Errorf("failed to read plan time: %w", err)
+	}
+	return millis(ms), nil
+}
+
+func millis(ms float64) time.Duration {
+	return time.Duration(ms * float64(time.Millisecond))
+}
@@ -0,0 +1,133 @@
+package core
+
+import (
+	"context"
+	"testing"
+	"time"
+
+	"sqlterm/internal/i18n"
+)
+
+func TestQueryTiming_Breakdown(t *testing.T) {
+	tests := []struct {
+		name            string
+		timing          QueryTiming
+		network, server time.Duration
+		ok              bool
+	}{
+		{"server reported", QueryTiming{Total: 840 * time.Millisecond, RTT: 650 * time.Millisecond, Server: 140 * time.Millisecond}, 700 * time.Millisecond, 140 * time.Millisecond, true},
+		{"ping only", QueryTiming{Total: 840 * time.Millisecond, RTT: 700 * time.Millisecond}, 700 * time.Millisecond, 140 * time.Millisecond, true},
+		{"server longer than total", QueryTiming{Total: 100 * time.Millisecond, Server: 150 * time.Millisecond}, 0, 100 * time.Millisecond, true},
+		{"ping longer than total", QueryTiming{Total: 100 * time.Millisecond, RTT: 150 * time.Millisecond}, 100 * time.Millisecond, 0, true},
+		{"nothing measured", QueryTiming{Total: 840 * time.Millisecond}, 0, 0, false},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			network, server, ok := tt.timing.Breakdown()
+			if network != tt.network || server != tt.server || ok != tt.ok {
+				t.Errorf("Breakdown() = %v, %v, %v, expected %v, %v, %v", network, server, ok, tt.network, tt.server, tt.ok)
+			}
+		})
+	}
+}
+
+func TestQueryTiming_Label(t *testing.T) {
+	i18nMgr, err := i18n.NewManager("en_au")
+	if err != nil {
+		t.Fatalf("Failed to create i18n manager: %v", err)
+	}
+
+	timing := QueryTiming{Total: 840 * time.Millisecond, Server: 140 * time.Millisecond}
+	if got := timing.Label(i18nMgr); got != "total 840 ms (network ~700 ms, server ~140 ms)" {
+		t.Errorf("Unexpected label %q", got)
+	}
+	if got := (QueryTiming{Total: 3 * time.Millisecond}).Label(i18nMgr); got != "total 3 ms" {
+		t.Errorf("Unexpected label without a breakdown %q", got)
+	}
+}
+
+func TestFormatMillis(t *testing.T) {
+	tests := map[time.Duration]string{
+		0:                        "0 ms",
+		300 * time.Microsecond:   "<1 ms",
+		840 * time.Millisecond:   "840 ms",
+		9999 * time.Millisecond:  "9999 ms",
+		12345 * time.Millisecond: "12.3 s",
+	}
+	for d, expected := range tests {
+		if got := FormatMillis(d); got != expected {
+			t.Errorf("FormatMillis(%v) = %q, expected %q", d, got, expected)
+		}
+	}
+}
+
+func TestPostgresPlanTime(t *testing.T) {
+	plan := `[{"Plan": {"Node Type": "Seq Scan", "Actual Total Time": 0.02}, "Planning Time": 0.25, "Triggers": [], "Execution Time": 139.75}]`
+	got, err := postgresPlanTime(plan)
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if got != 140*time.Millisecond {
+		t.Errorf("Expected planning plus execution time of 140ms, got %v", got)
+	}
+
+	if _, err := postgresPlanTime("not json"); err == nil {
+		t.Error("Expected an unreadable plan to fail")
+	}
+}
+
+func TestMySQLPlanTime(t *testing.T) {
+	plan := "-> Limit: 10 row(s)  (cost=1.25 rows=10) (actual time=0.0512..140.5 rows=10 loops=1)\n" +
+		"    -> Table scan on users  (cost=1.25 rows=10) (actual time=0.0498..0.0604 rows=10 loops=1)\n"
+	got, err := mysqlPlanTime(plan)
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	if got != 140500*time.Microsecond {
+		t.Errorf("Expected the top node's time of 140.5ms, got %v", got)
+	}
+
+	if _, err := mysqlPlanTime("-> Rows fetched before execution"); err == nil {
+		t.Error("Expected a plan without timing to fail")
+	}
+}
+
+// serverTimer reports a fixed server time and records what it was asked to measure
+type serverTimer struct {
+	Connection
+	server  time.Duration
+	err     error
+	queries []string
+}
+
+func (s *serverTimer) ServerTime(ctx context.Context, query string) (time.Duration, error) {
+	s.queries = append(s.queries, query)
+	return s.server, s.err
+}
+
+func TestMeasureServerTime(t *testing.T) {
+	timer := &serverTimer{server: 140 * time.Millisecond}
+
+	if got, ok := MeasureServerTime(context.Background(), timer, PostgreSQL, "SELECT * FROM users;"); !ok || got != 140*time.Millisecond {
+		t.Errorf("Expected the server time of a SELECT, got %v, %v", got, ok)
+	}
+	for _, query := range []string{
+		"DELETE FROM users",
+		"SHOW TABLES",
+		"SELECT 1; SELECT 2",
+		"WITH gone AS (DELETE FROM users RETURNING *) SELECT * FROM gone",
+	} {
+		if _, ok := MeasureServerTime(context.Background(), timer, PostgreSQL, query); ok {
+			t.Errorf("Expected %q not to be measured", query)
+		}
+	}
+	if len(timer.queries) != 1 || timer.queries[0] != "SELECT * FROM users" {
+		t.Errorf("Expected only the SELECT to be run again, without its semicolon, got %q", timer.queries)
+	}
+
+	timer.err = ErrServerTimeNotSupported
+	if _, ok := MeasureServerTime(context.Background(), timer, PostgreSQL, "SELECT 1"); ok {
+		t.Error("Expected a failed measurement to leave the server time unknown")
+	}
+}
@@ -0,0 +1,146 @@
+package core
+
+import (
+	"context"
+	"database/sql"
+	"errors"
+	"fmt"
+	"slices"
+	"strings"
+)
+
+// ErrTransactionsNotSupported is returned by connections that cannot run statements in a transaction
+var ErrTransactionsNotSupported = errors.New("transactions are not supported for this connection")
+
+// Transaction runs statements that take effect together on Commit, or not at all on Rollback
+type Transaction interface {
+	ExecuteContext(ctx context.Context, query string) (*QueryResult, error)
+	Commit() error
+	Rollback() error
+}
+
+// Transactor is implemented by connections that can run statements in a transaction
+type Transactor interface {
+	Begin(ctx context.Context) (Transaction, error)
+}
+
+// Begin implements Transactor. The transaction holds one pooled connection
+// until it is committed or rolled back.
+func (c *SQLConnection) Begin(ctx context.Context) (Transaction, error) {
+	tx, err := c.db.BeginTx(ctx, nil)
+	if err != nil {
+		return nil, fmt.Errorf("failed to begin transaction: %w", err)
+	}
+	return &sqlTransaction{tx: tx}, nil
+}
+
+type sqlTransaction struct {
+	tx *sql.Tx
+}
+
+func (t *sqlTransaction) ExecuteContext(ctx context.Context, query string) (*QueryResult, error) {
+	rows, err := t.tx.QueryContext(ctx, query)
+	if err != nil {
+		return nil, fmt.Errorf("failed to execute query: %w", err)
+	}
+	return NewQueryResult(rows)
+}
+
+func (t *sqlTransaction) Commit() error {
+	return t.tx.Commit()
+}
+
+func (t *sqlTransaction) Rollback() error {
+	return t.tx.Rollback()
+}
+
+// StatementError is returned by RunInTransaction when a statement fails. The
+// transaction has been rolled back by then.
+type StatementError struct {
+	// Index counts statements from 1
+	Index int
+	Query string
+	Err   error
+}
+
+func (e *StatementError) Error() string {
+	return fmt.Sprintf("statement %d failed, all changes rolled back: %v", e.Index, e.Err)
+}
+
+func (e *StatementError) Unwrap() error {
+	return e.Err
+}
+
+// RunInTransaction runs statements in one transaction on conn, handing each
+// result to each, which must close it. Every statement is committed together;
+// when one fails, or each returns an error for it, none of them is.
+func RunInTransaction(ctx context.Context, conn Connection, statements []string, each func(i int, result *QueryResult) error) error {
+	transactor, ok := conn.(Transactor)
+	if !ok {
+		return ErrTransactionsNotSupported
+	}
+	tx, err := transactor.Begin(ctx)
+	if err != nil {
+		return err
+	}
+
+	for i, statement := range statements {
+		result, err := tx.ExecuteContext(ctx, statement)
+		if err == nil {
+			err = each(i, result)
+		}
+		if err != nil {
+			if rollbackErr := tx.Rollback(); rollbackErr != nil && !errors.Is(rollbackErr, sql.ErrTxDone) {
+				err = errors.Join(err, fmt.Errorf("failed to roll back: %w", rollbackErr))
+			}
+			return &StatementError{Index: i + 1, Query: statement, Err: err}
+		}
+	}
+	if err := tx.Commit(); err != nil {
+		return fmt.Errorf("failed to commit: %w", err)
+	}
+	return nil
+}
+
+// transactionControl start statements that end or start a transaction themselves
+var transactionControl = []string{"BEGIN", "START", "COMMIT", "ROLLBACK", "END"}
+
+// BreaksTransaction reports whether statement cannot be part of a larger
+// transaction on dbType: it commits or rolls back by itself, or the server
+// refuses to run it inside one. MySQL commits implicitly before schema
+// changes, so a failure after one cannot undo it.
+func BreaksTransaction(dbType DatabaseType, statement string) bool {
+	fields := strings.Fields(strings.ToUpper(stripLeadingComments(dbType, statement)))
+	if len(fields) == 0 {
+		return false
+	}
+	keyword := strings.TrimSuffix(fields[0], ";")
+	if containsFold(transactionControl, keyword) {
+		// ROLLBACK TO SAVEPOINT stays inside the transaction
+		return !(keyword == "ROLLBACK" && len(fields) > 1 && fields[1] == "TO")
+	}
+
+	switch dbType {
+	case MySQL:
+		// Temporary tables are the exception that does not commit
+		if len(fields) > 1 && fields[1] == "TEMPORARY" {
+			return false
+		}
+		return containsFold(ddlKeywords, keyword) || containsFold([]string{"LOCK", "UNLOCK", "GRANT", "REVOKE"}, keyword)
+	case PostgreSQL:
+		switch keyword {
+		case "VACUUM":
+			return true
+		case "CREATE", "DROP":
+			return len(fields) > 1 && (fields[1] == "DATABASE" || fields[1] == "TABLESPACE") ||
+				slices.Contains(fields, "CONCURRENTLY")
+		case "REINDEX":
+			return slices.Contains(fields, "CONCURRENTLY")
+		case "ALTER":
+			return len(fields) > 1 && fields[1] == "SYSTEM"
+		}
+	case SQLite:
+		return keyword == "VACUUM" || keyword == "ATTACH" || keyword == "DETACH"
+	}
+	return false
+}
@@ -0,0 +1,64 @@
+//go:build !no_sqlite
+
+package core
+
+import (
+	"context"
+	"errors"
+	"path/filepath"
+	"testing"
+)
+
+func countEvents(t *testing.T, conn Connection) int64 {
+	t.Helper()
+	rows, err := conn.(RowCounter).CountRows(context.Background(), "events")
+	if err != nil {
+		t.Fatalf("Unexpected error: %v", err)
+	}
+	return rows
+}
+
+func TestRunInTransaction_SQLite(t *testing.T) {
+	conn, err := NewConnection(&ConnectionConfig{Name: "atomic", DatabaseType: SQLite, Database: filepath.Join(t.TempDir(), "app.db")})
+	if err != nil {
+		t.Fatalf("Failed to open SQLite: %v", err)
+	}
+	defer conn.Close()
+	execAndDrain(t, conn, "CREATE TABLE events (id INTEGER PRIMARY KEY, name TEXT NOT NULL)")
+
+	drain := func(_ int, result *QueryResult) error {
+		for range result.Itor() {
+		}
+		result.Close()
+		return result.Error()
+	}
+
+	t.Run("a failing statement undoes the ones before it", func(t *testing.T) {
+		err := RunInTransaction(context.Background(), conn, []string{
+			"INSERT INTO events (id, name) VALUES (1, 'signup')",
+			"INSERT INTO events (id, name) VALUES (2, 'login')",
+			"INSERT INTO events (id, name) VALUES (3, NULL)",
+		}, drain)
+
+		var statementErr *StatementError
+		if !errors.As(err, &statementErr) || statementErr.Index != 3 {
+			t.Fatalf("Expected statement 3 to fail, got %v", err)
+		}
+		if rows := countEvents(t, conn); rows != 0 {
+			t.Errorf("Expected the rollback to leave no rows, got %d", rows)
+		}
+	})
+
+	t.Run("all statements commit together", func(t *testing.T) {
+		err := RunInTransaction(context.Background(), conn, []string{
+			"INSERT INTO events (id, name) VALUES (1, 'signup')",
+			"INSERT INTO events (id, name) VALUES (2, 'login')",
+		}, drain)
+		if err != nil {
+			t.Fatalf("Unexpected error: %v", err)
+		}
+		if rows := countEvents(t, conn); rows != 2 {
+			t.Errorf("Expected 2 committed rows, got %d", rows)
+		}
+	})
+}
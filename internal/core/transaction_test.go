@@ -0,0 +1,29 @@
+package core
+
+import "testing"
+
+func TestBreaksTransaction(t *testing.T) {
+	tests := []struct {
+		dbType    DatabaseType
+		statement string
+		want      bool
+	}{
+		{MySQL, "INSERT INTO users (id) VALUES (1)", false},
+		{MySQL, "ALTER TABLE users ADD COLUMN age INT", true},
+		{MySQL, "-- add the index\nCREATE INDEX users_email ON users (email)", true},
+		{MySQL, "CREATE TEMPORARY TABLE scratch (id INT)", false},
+		{PostgreSQL, "ALTER TABLE users ADD COLUMN age INT", false},
+		{PostgreSQL, "CREATE INDEX CONCURRENTLY users_email ON users (email)", true},
+		{PostgreSQL, "VACUUM ANALYZE users", true},
+		{SQLite, "CREATE TABLE notes (id INTEGER)", false},
+		{SQLite, "ATTACH 'archive.db' AS archive", true},
+		{SQLite, "COMMIT", true},
+		{PostgreSQL, "ROLLBACK TO SAVEPOINT before_update", false},
+	}
+
+	for _, tt := range tests {
+		if got := BreaksTransaction(tt.dbType, tt.statement); got != tt.want {
+			t.Errorf("BreaksTransaction(%v, %q) = %v, want %v", tt.dbType, tt.statement, got, tt.want)
+		}
+	}
+}
@@ -2,8 +2,10 @@ package core
 
 import (
 	"database/sql"
+	"encoding/hex"
 	"fmt"
 	"iter"
+	"math"
 	"strings"
 	"time"
 )
@@ -17,42 +19,30 @@ const (
 )
 
 func (dt DatabaseType) String() string {
-	switch dt {
-	case MySQL:
-		return "mysql"
-	case PostgreSQL:
-		return "postgres"
-	case SQLite:
-		return "sqlite"
-	default:
-		return "unknown"
+	if b, ok := LookupBackend(dt); ok {
+		return b.Name
 	}
+	return "unknown"
 }
 
 func ParseDatabaseType(s string) (DatabaseType, error) {
-	switch strings.ToLower(s) {
-	case "mysql":
-		return MySQL, nil
-	case "postgres", "postgresql":
-		return PostgreSQL, nil
-	case "sqlite", "sqlite3":
-		return SQLite, nil
-	default:
-		return 0, fmt.Errorf("unsupported database type: %s. Supported types: mysql, postgres, sqlite", s)
+	registry.RLock()
+	dbType, ok := lookupName(s)
+	registry.RUnlock()
+	if ok {
+		return dbType, nil
+	}
+
+	var names []string
+	for _, dbType := range RegisteredBackends() {
+		names = append(names, dbType.String())
 	}
+	return 0, fmt.Errorf("unsupported database type: %s. Supported types: %s", s, strings.Join(names, ", "))
 }
 
 func GetDefaultPort(dbType DatabaseType) int {
-	switch dbType {
-	case MySQL:
-		return 3306
-	case PostgreSQL:
-		return 5432
-	case SQLite:
-		return 0
-	default:
-		return 0
-	}
+	b, _ := LookupBackend(dbType)
+	return b.DefaultPort
 }
 
 type ConnectionConfig struct {
@@ -64,6 +54,34 @@ type ConnectionConfig struct {
 	Username     string       `yaml:"username"`
 	Password     string       `yaml:"password,omitempty"`
 	SSL          bool         `yaml:"ssl"`
+	// AuditLog records every statement run on this connection
+	AuditLog bool `yaml:"audit_log,omitempty"`
+	// AuditLogPath overrides the default audit/<name>.log under the config directory
+	AuditLogPath string `yaml:"audit_log_path,omitempty"`
+	// Environment tags the connection, e.g. "production", in the prompt and connection list
+	Environment string `yaml:"environment,omitempty"`
+	// Color of the connection in the prompt and connection list; see ConnectionColors
+	Color string `yaml:"color,omitempty"`
+	// ConfirmDestructive asks before running statements that modify data or schema.
+	// Production connections always ask.
+	ConfirmDestructive bool `yaml:"confirm_destructive,omitempty"`
+	// Pool sizing; 0 keeps the default. File databases always use one connection.
+	MaxConnections     int `yaml:"max_connections,omitempty"`
+	MinConnections     int `yaml:"min_connections,omitempty"`
+	AcquireTimeoutSecs int `yaml:"acquire_timeout_secs,omitempty"`
+	IdleTimeoutSecs    int `yaml:"idle_timeout_secs,omitempty"`
+	// ShowWarnings runs SHOW WARNINGS after each MySQL statement to show its
+	// warnings under the result; it costs one more round trip per statement
+	ShowWarnings bool `yaml:"show_warnings,omitempty"`
+	// ReadOnly opens the session read-only on the server, where the backend
+	// supports it, for a read-only project. It is never saved.
+	ReadOnly bool `yaml:"-"`
+	// Extra keeps settings this version does not know, such as ones a newer
+	// sqlterm wrote, so saving the connection does not drop them
+	Extra map[string]any `yaml:",inline"`
+
+	// typeName is the database_type the file named when this build does not know it
+	typeName string
 }
 
 type Value interface {
@@ -135,6 +153,40 @@ func (b BoolValue) IsNull() bool {
 	return b.Null
 }
 
+// DecimalValue holds an exact NUMERIC/DECIMAL in its textual form
+type DecimalValue struct {
+	Value string
+	Null  bool
+}
+
+func (d DecimalValue) String() string {
+	if d.Null {
+		return ""
+	}
+	return d.Value
+}
+
+func (d DecimalValue) IsNull() bool {
+	return d.Null
+}
+
+// BytesValue holds binary data, rendered in PostgreSQL's hex format
+type BytesValue struct {
+	Value []byte
+	Null  bool
+}
+
+func (b BytesValue) String() string {
+	if b.Null {
+		return ""
+	}
+	return "\\x" + hex.EncodeToString(b.Value)
+}
+
+func (b BytesValue) IsNull() bool {
+	return b.Null
+}
+
 type NullValue struct{}
 
 func (n NullValue) String() string {
@@ -145,15 +197,31 @@ func (n NullValue) IsNull() bool {
 	return true
 }
 
+// Column describes a result column. Name and Type always come from the
+// driver; the rest is best effort and zero when unknown. Drivers report
+// different parts of it (MySQL nullability and decimal sizes, PostgreSQL
+// lengths and decimal sizes, SQLite none), and DescribeResultColumns fills
+// the gaps from the table of a single-table SELECT.
 type Column struct {
 	Name string
 	Type string
+	// Nullable is nil when neither the driver nor the table says
+	Nullable *bool
+	// Length is the maximum length of a bounded text or binary column
+	Length int64
+	// Precision and Scale size DECIMAL and NUMERIC columns
+	Precision int64
+	Scale     int64
 }
 
 type QueryResult struct {
 	Columns []Column
 	rows    *sql.Rows
+	static  [][]Value
 	err     error
+	read    int
+	onClose func(rowsRead int, err error)
+	notices []Notice
 }
 
 func (r *QueryResult) ColumnNames() []string {
@@ -177,7 +245,18 @@ func NewQueryResult(rows *sql.Rows) (*QueryResult, error) {
 	for i, tp := range columnTypes {
 		columns[i] = Column{
 			Name: columnNames[i],
-			Type: tp.Name(),
+			Type: tp.DatabaseTypeName(),
+		}
+		if nullable, ok := tp.Nullable(); ok {
+			columns[i].Nullable = &nullable
+		}
+		// Unbounded types such as TEXT report the largest length there is
+		if length, ok := tp.Length(); ok && length > 0 && length < math.MaxInt32 {
+			columns[i].Length = length
+		}
+		// MySQL also sizes floats and fractional seconds this way
+		if precision, scale, ok := tp.DecimalSize(); ok && isDecimalType(columns[i].Type) && precision < math.MaxInt32 {
+			columns[i].Precision, columns[i].Scale = precision, scale
 		}
 	}
 
@@ -187,7 +266,37 @@ func NewQueryResult(rows *sql.Rows) (*QueryResult, error) {
 	}, nil
 }
 
+// NewStaticQueryResult builds a result over rows already held in memory
+func NewStaticQueryResult(columns []Column, rows [][]Value) *QueryResult {
+	return &QueryResult{
+		Columns: columns,
+		static:  rows,
+	}
+}
+
+// OnClose registers fn to run once when the result is closed, with the number
+// of rows read and any error hit while reading them. Functions registered
+// earlier run first.
+func (r *QueryResult) OnClose(fn func(rowsRead int, err error)) {
+	if previous := r.onClose; previous != nil {
+		r.onClose = func(rowsRead int, err error) {
+			previous(rowsRead, err)
+			fn(rowsRead, err)
+		}
+		return
+	}
+	r.onClose = fn
+}
+
 func (r *QueryResult) Close() error {
+	if r.onClose != nil {
+		onClose := r.onClose
+		r.onClose = nil
+		defer onClose(r.read, r.err)
+	}
+	if r.rows == nil {
+		return nil
+	}
 	return r.rows.Close()
 }
 
@@ -205,43 +314,73 @@ func assambleRow(columns []Column, rows *sql.Rows) ([]Value, error) {
 
 	row := make([]Value, len(columns))
 	for i, val := range values {
-		if val == nil {
-			row[i] = NullValue{}
-		} else {
-			switch v := val.(type) {
-			case string:
-				row[i] = StringValue{Value: v}
-			case []byte:
-				row[i] = StringValue{Value: string(v)}
-			case int64:
-				row[i] = IntValue{Value: v}
-			case float64:
-				row[i] = FloatValue{Value: v}
-			case bool:
-				row[i] = BoolValue{Value: v}
-			case time.Time:
-				// Format datetime/timestamp to "2006-01-02 15:04:05-0700"
-				row[i] = StringValue{Value: v.Format("2006-01-02 15:04:05-0700")}
-			default:
-				row[i] = StringValue{Value: fmt.Sprintf("%v", v)}
+		row[i] = convertValue(columns[i], val)
+	}
+	return row, nil
+}
+
+// convertValue maps a scanned driver value to a Value, using the column's database type
+// where the driver hands back raw bytes
+func convertValue(column Column, val any) Value {
+	switch v := val.(type) {
+	case nil:
+		return NullValue{}
+	case string:
+		return StringValue{Value: v}
+	case []byte:
+		switch column.Type {
+		case "NUMERIC", "DECIMAL":
+			// Keep the exact text; converting to float64 loses precision
+			return DecimalValue{Value: string(v)}
+		case "BYTEA", "BINARY", "VARBINARY":
+			return BytesValue{Value: v}
+		case "TIME":
+			if t, ok := parseTimeOfDay(string(v)); ok {
+				return TimeValue{Value: t}
 			}
+			return StringValue{Value: string(v)}
+		default:
+			// UUIDs and arrays arrive in their text form, e.g. {a,b,c}
+			return StringValue{Value: string(v)}
 		}
+	case int64:
+		return IntValue{Value: v}
+	case float64:
+		return FloatValue{Value: v}
+	case bool:
+		return BoolValue{Value: v}
+	case time.Time:
+		return convertTemporal(column.Type, v)
+	default:
+		return StringValue{Value: fmt.Sprintf("%v", v)}
 	}
-	return row, nil
 }
 
 func (r *QueryResult) Itor() iter.Seq[[]Value] {
 	return func(yield func([]Value) bool) {
+		if r.rows == nil {
+			for _, row := range r.static {
+				r.read++
+				if !yield(row) {
+					return
+				}
+			}
+			return
+		}
 		for r.rows.Next() {
 			row, err := assambleRow(r.Columns, r.rows)
 			if err != nil {
 				r.err = err
 				return
 			}
+			r.read++
 			if !yield(row) {
 				return
 			}
 		}
+		if err := r.rows.Err(); err != nil {
+			r.err = err
+		}
 	}
 }
 
@@ -280,4 +419,6 @@ type ColumnInfo struct {
 	Key      string
 	Default  *string
 	Extra    string
+	// Comment is the column comment from the schema; SQLite has none
+	Comment string
 }
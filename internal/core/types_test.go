@@ -1,7 +1,9 @@
 package core
 
 import (
+	"fmt"
 	"testing"
+	"time"
 )
 
 func TestParseDatabaseType(t *testing.T) {
@@ -380,3 +382,126 @@ func TestGenerateNumberedCSVPath(t *testing.T) {
 		})
 	}
 }
+
+func TestDecimalValue(t *testing.T) {
+	value := DecimalValue{Value: "12345678901234567890.123456789"}
+	if value.IsNull() {
+		t.Error("Non-null decimal should not report IsNull")
+	}
+	if value.String() != "12345678901234567890.123456789" {
+		t.Errorf("Expected decimal to keep full precision, got '%s'", value.String())
+	}
+
+	if (DecimalValue{Null: true}).String() != "" {
+		t.Error("Null decimal should render as empty string")
+	}
+}
+
+func TestBytesValue(t *testing.T) {
+	value := BytesValue{Value: []byte{0xde, 0xad, 0xbe, 0xef}}
+	if value.String() != `\xdeadbeef` {
+		t.Errorf("Expected hex rendering, got '%s'", value.String())
+	}
+
+	if (BytesValue{Null: true}).String() != "" {
+		t.Error("Null bytes should render as empty string")
+	}
+}
+
+func TestConvertValue(t *testing.T) {
+	timestamp := time.Date(2024, 3, 9, 14, 30, 0, 0, time.FixedZone("AEDT", 11*60*60))
+
+	testCases := []struct {
+		name       string
+		columnType string
+		input      any
+		expected   Value
+	}{
+		{
+			name:       "NULL",
+			columnType: "TEXT",
+			input:      nil,
+			expected:   NullValue{},
+		},
+		{
+			name:       "UUID as text",
+			columnType: "UUID",
+			input:      []byte("a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11"),
+			expected:   StringValue{Value: "a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11"},
+		},
+		{
+			name:       "Text array",
+			columnType: "_TEXT",
+			input:      []byte("{a,b,c}"),
+			expected:   StringValue{Value: "{a,b,c}"},
+		},
+		{
+			name:       "PostgreSQL NUMERIC",
+			columnType: "NUMERIC",
+			input:      []byte("3.141592653589793238"),
+			expected:   DecimalValue{Value: "3.141592653589793238"},
+		},
+		{
+			name:       "MySQL DECIMAL",
+			columnType: "DECIMAL",
+			input:      []byte("10.50"),
+			expected:   DecimalValue{Value: "10.50"},
+		},
+		{
+			name:       "BYTEA",
+			columnType: "BYTEA",
+			input:      []byte{0x01, 0xff},
+			expected:   BytesValue{Value: []byte{0x01, 0xff}},
+		},
+		{
+			name:       "Integer",
+			columnType: "INT8",
+			input:      int64(42),
+			expected:   IntValue{Value: 42},
+		},
+		{
+			name:       "TIMESTAMPTZ normalised to UTC",
+			columnType: "TIMESTAMPTZ",
+			input:      timestamp,
+			expected:   DateTimeTzValue{Value: timestamp},
+		},
+		{
+			name:       "DATETIME without zone",
+			columnType: "DATETIME",
+			input:      timestamp,
+			expected:   DateTimeValue{Value: timestamp},
+		},
+		{
+			name:       "DATE",
+			columnType: "DATE",
+			input:      timestamp,
+			expected:   DateValue{Value: timestamp},
+		},
+		{
+			name:       "MySQL TIME as text",
+			columnType: "TIME",
+			input:      []byte("08:15:00"),
+			expected:   TimeValue{Value: time.Date(0, 1, 1, 8, 15, 0, 0, time.UTC)},
+		},
+		{
+			name:       "MySQL TIME interval beyond a day",
+			columnType: "TIME",
+			input:      []byte("838:59:59"),
+			expected:   StringValue{Value: "838:59:59"},
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.name, func(t *testing.T) {
+			result := convertValue(Column{Name: "col", Type: tc.columnType}, tc.input)
+
+			if fmt.Sprintf("%T", result) != fmt.Sprintf("%T", tc.expected) {
+				t.Fatalf("Expected %T, got %T", tc.expected, result)
+			}
+
+			if result.String() != tc.expected.String() {
+				t.Errorf("Expected '%s', got '%s'", tc.expected.String(), result.String())
+			}
+		})
+	}
+}
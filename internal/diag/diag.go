@@ -0,0 +1,224 @@
+// Package diag collects runtime diagnostics for bug reports.
+package diag
+
+import (
+	"fmt"
+	"os"
+	"runtime"
+	"runtime/debug"
+	"strings"
+
+	"sqlterm/internal/config"
+	"sqlterm/internal/core"
+
+	"golang.org/x/term"
+)
+
+// Version information, set from main through cli.SetVersionInfo
+var (
+	Version   = "dev"
+	BuildTime = "unknown"
+	GitCommit = "unknown"
+)
+
+// SetVersionInfo records the build information reported by diagnostics
+func SetVersionInfo(version, buildTime, gitCommit string) {
+	Version = version
+	BuildTime = buildTime
+	GitCommit = gitCommit
+}
+
+// backendDrivers maps each supported backend to the Go module implementing its driver
+var backendDrivers = []struct {
+	backend core.DatabaseType
+	module  string
+}{
+	{core.MySQL, "github.com/go-sql-driver/mysql"},
+	{core.PostgreSQL, "github.com/lib/pq"},
+	{core.SQLite, "github.com/mattn/go-sqlite3"},
+}
+
+// DriverInfo describes a database driver compiled into this build
+type DriverInfo struct {
+	Backend string
+	Module  string
+	Version string
+}
+
+// ConnectionInfo describes the active connection with credentials left out
+type ConnectionInfo struct {
+	Name          string
+	Type          string
+	Host          string
+	Database      string
+	ServerVersion string
+	Pool          string
+}
+
+// Report is a snapshot of the runtime environment
+type Report struct {
+	Version          string
+	BuildTime        string
+	GitCommit        string
+	GoVersion        string
+	Platform         string
+	Drivers          []DriverInfo
+	ConfigDir        string
+	ProjectRoot      string
+	SavedConnections int
+	TerminalSize     string
+	ColorSupport     string
+	Connection       *ConnectionInfo
+}
+
+// Collect gathers diagnostics. configMgr and conn may be nil.
+func Collect(configMgr *config.Manager, conn core.Connection, connConfig *core.ConnectionConfig) Report {
+	report := Report{
+		Version:      Version,
+		BuildTime:    BuildTime,
+		GitCommit:    GitCommit,
+		GoVersion:    runtime.Version(),
+		Platform:     runtime.GOOS + "/" + runtime.GOARCH,
+		Drivers:      drivers(),
+		TerminalSize: terminalSize(),
+		ColorSupport: colorSupport(),
+	}
+
+	if wd, err := os.Getwd(); err == nil {
+		if project, err := config.FindProject(wd); err == nil && project != nil {
+			report.ProjectRoot = project.Root
+		}
+	}
+
+	if configMgr != nil {
+		report.ConfigDir = configMgr.GetConfigDir()
+		if connections, err := configMgr.ListConnections(); err == nil {
+			report.SavedConnections = len(connections)
+		}
+	}
+
+	if conn != nil && connConfig != nil {
+		info := &ConnectionInfo{
+			Name:     connConfig.Name,
+			Type:     connConfig.DatabaseType.String(),
+			Database: connConfig.Database,
+		}
+		if connConfig.DatabaseType != core.SQLite {
+			info.Host = fmt.Sprintf("%s:%d", connConfig.Host, connConfig.Port)
+		}
+		if server, ok := conn.(core.ServerInfo); ok {
+			version, err := server.ServerVersion()
+			if err != nil {
+				version = "unavailable (" + core.RedactDSN(err.Error()) + ")"
+			}
+			info.ServerVersion = version
+		}
+		if pooled, ok := conn.(core.PoolReporter); ok {
+			if pool, err := pooled.Pool(); err == nil {
+				info.Pool = pool.String()
+			}
+		}
+		report.Connection = info
+	}
+
+	return report
+}
+
+// String renders the report as plain text suitable for pasting into an issue
+func (r Report) String() string {
+	var sb strings.Builder
+
+	fmt.Fprintf(&sb, "sqlterm %s (built %s, commit %s)\n", r.Version, r.BuildTime, r.GitCommit)
+	fmt.Fprintf(&sb, "go: %s %s\n", r.GoVersion, r.Platform)
+
+	sb.WriteString("drivers:\n")
+	for _, driver := range r.Drivers {
+		fmt.Fprintf(&sb, "  %s: %s %s\n", driver.Backend, driver.Module, driver.Version)
+	}
+
+	fmt.Fprintf(&sb, "config dir: %s\n", valueOr(r.ConfigDir, "unknown"))
+	fmt.Fprintf(&sb, "project root: %s\n", valueOr(r.ProjectRoot, "none"))
+	fmt.Fprintf(&sb, "saved connections: %d\n", r.SavedConnections)
+	fmt.Fprintf(&sb, "terminal: %s, color: %s\n", r.TerminalSize, r.ColorSupport)
+
+	if r.Connection == nil {
+		sb.WriteString("connection: none\n")
+		return sb.String()
+	}
+
+	sb.WriteString("connection:\n")
+	fmt.Fprintf(&sb, "  name: %s\n", r.Connection.Name)
+	fmt.Fprintf(&sb, "  type: %s\n", r.Connection.Type)
+	fmt.Fprintf(&sb, "  database: %s\n", r.Connection.Database)
+	if r.Connection.Host != "" {
+		fmt.Fprintf(&sb, "  host: %s\n", r.Connection.Host)
+	}
+	fmt.Fprintf(&sb, "  server version: %s\n", valueOr(r.Connection.ServerVersion, "unknown"))
+	if r.Connection.Pool != "" {
+		fmt.Fprintf(&sb, "  pool: %s\n", r.Connection.Pool)
+	}
+
+	return sb.String()
+}
+
+func drivers() []DriverInfo {
+	versions := map[string]string{}
+	if info, ok := debug.ReadBuildInfo(); ok {
+		for _, dep := range info.Deps {
+			if dep.Replace != nil {
+				dep = dep.Replace
+			}
+			versions[dep.Path] = dep.Version
+		}
+	}
+
+	enabled := map[core.DatabaseType]bool{}
+	for _, dbType := range core.EnabledBackends() {
+		enabled[dbType] = true
+	}
+
+	result := make([]DriverInfo, 0, len(backendDrivers))
+	for _, driver := range backendDrivers {
+		if !enabled[driver.backend] {
+			continue
+		}
+		result = append(result, DriverInfo{
+			Backend: driver.backend.String(),
+			Module:  driver.module,
+			Version: valueOr(versions[driver.module], "unknown"),
+		})
+	}
+	return result
+}
+
+func terminalSize() string {
+	width, height, err := term.GetSize(int(os.Stdout.Fd()))
+	if err != nil {
+		return "not a terminal"
+	}
+	return fmt.Sprintf("%dx%d", width, height)
+}
+
+func colorSupport() string {
+	switch {
+	case os.Getenv("NO_COLOR") != "":
+		return "disabled (NO_COLOR)"
+	case !term.IsTerminal(int(os.Stdout.Fd())):
+		return "none (not a terminal)"
+	case os.Getenv("COLORTERM") == "truecolor" || os.Getenv("COLORTERM") == "24bit":
+		return "truecolor"
+	case strings.Contains(os.Getenv("TERM"), "256color"):
+		return "256"
+	case os.Getenv("TERM") == "" || os.Getenv("TERM") == "dumb":
+		return "none (TERM=" + os.Getenv("TERM") + ")"
+	default:
+		return "basic"
+	}
+}
+
+func valueOr(value, fallback string) string {
+	if value == "" {
+		return fallback
+	}
+	return value
+}
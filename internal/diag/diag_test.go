@@ -0,0 +1,52 @@
+package diag
+
+import (
+	"strings"
+	"testing"
+
+	"sqlterm/internal/core"
+	"sqlterm/internal/core/coretest"
+)
+
+func TestCollect_RedactsCredentials(t *testing.T) {
+	connConfig := &core.ConnectionConfig{
+		Name:         "prod",
+		DatabaseType: core.PostgreSQL,
+		Host:         "db.example.com",
+		Port:         5432,
+		Database:     "orders",
+		Username:     "admin",
+		Password:     "hunter2",
+	}
+
+	report := Collect(nil, coretest.NewMockConnection(), connConfig)
+	output := report.String()
+
+	if strings.Contains(output, "hunter2") {
+		t.Errorf("Diagnostics must not include the password:\n%s", output)
+	}
+
+	for _, expected := range []string{"name: prod", "type: postgres", "host: db.example.com:5432", "database: orders"} {
+		if !strings.Contains(output, expected) {
+			t.Errorf("Expected diagnostics to contain '%s', got:\n%s", expected, output)
+		}
+	}
+}
+
+func TestCollect_ListsDrivers(t *testing.T) {
+	SetVersionInfo("1.2.3", "2024-01-01", "abc123")
+	defer SetVersionInfo("dev", "unknown", "unknown")
+
+	report := Collect(nil, nil, nil)
+
+	if len(report.Drivers) != 3 {
+		t.Fatalf("Expected 3 drivers, got %d", len(report.Drivers))
+	}
+
+	output := report.String()
+	for _, expected := range []string{"sqlterm 1.2.3", "commit abc123", "github.com/go-sql-driver/mysql", "github.com/lib/pq", "github.com/mattn/go-sqlite3", "connection: none"} {
+		if !strings.Contains(output, expected) {
+			t.Errorf("Expected diagnostics to contain '%s', got:\n%s", expected, output)
+		}
+	}
+}
@@ -63,13 +63,19 @@ func (m *Manager) EnsureSessionDir(connectionName string) error {
 }
 
 func (m *Manager) ViewMarkdown(filePath string) error {
+	return m.ViewMarkdownWithLinks(filePath, nil)
+}
+
+// ViewMarkdownWithLinks shows a markdown file with the shown texts in links
+// made clickable; the file itself is left without escape sequences
+func (m *Manager) ViewMarkdownWithLinks(filePath string, links core.Hyperlinks) error {
 	// Read the markdown file
 	content, err := os.ReadFile(filePath)
 	if err != nil {
 		return fmt.Errorf("failed to read markdown file: %w", err)
 	}
 
-	return m.DisplayMarkdown(string(content))
+	return core.NewMarkdownRenderer(m.i18nMgr).WithHyperlinks(links).RenderAndDisplay(string(content))
 }
 
 func (m *Manager) DisplayMarkdown(markdown string) error {
@@ -116,14 +122,17 @@ func (m *Manager) getSessionConfig(connectionName string) (*SessionConfig, error
 		}, nil
 	}
 
+	// A broken session.yaml falls back to the defaults rather than failing the connection
 	data, err := os.ReadFile(configPath)
 	if err != nil {
-		return nil, fmt.Errorf("failed to read session config: %w", err)
+		core.WarnFile(configPath, fmt.Errorf("failed to read session config: %w", err), false)
+		return &SessionConfig{CleanupRetentionDays: 30}, nil
 	}
 
 	var config SessionConfig
 	if err := yaml.Unmarshal(data, &config); err != nil {
-		return nil, fmt.Errorf("failed to parse session config: %w", err)
+		core.WarnFile(configPath, fmt.Errorf("failed to parse session config: %w", err), true)
+		return &SessionConfig{CleanupRetentionDays: 30}, nil
 	}
 
 	// Set default if not specified
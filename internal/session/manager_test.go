@@ -6,6 +6,7 @@ import (
 	"testing"
 	"time"
 
+	"sqlterm/internal/core"
 	"sqlterm/internal/i18n"
 )
 
@@ -230,6 +231,32 @@ func TestManager_getSessionConfig(t *testing.T) {
 	}
 }
 
+func TestManager_getSessionConfig_Corrupt(t *testing.T) {
+	tmpDir := t.TempDir()
+	manager := createTestManager(t, tmpDir)
+	core.TakeFileWarnings()
+
+	connectionName := "corrupt-config"
+	if err := manager.EnsureSessionDir(connectionName); err != nil {
+		t.Fatalf("Failed to create session dir: %v", err)
+	}
+	configPath := manager.getSessionConfigPath(connectionName)
+	if err := os.WriteFile(configPath, []byte("cleanup_retention_days: [\x00\xff"), 0644); err != nil {
+		t.Fatal(err)
+	}
+
+	config, err := manager.getSessionConfig(connectionName)
+	if err != nil {
+		t.Fatalf("Expected a corrupt session config to fall back to defaults, got %v", err)
+	}
+	if config.CleanupRetentionDays != 30 {
+		t.Errorf("Expected default retention days, got %d", config.CleanupRetentionDays)
+	}
+	if warnings := core.TakeFileWarnings(); len(warnings) != 1 || warnings[0].MovedTo != configPath+core.CorruptSuffix {
+		t.Errorf("Expected the corrupt file to be set aside and reported, got %+v", warnings)
+	}
+}
+
 func TestManager_ErrorHandling(t *testing.T) {
 	// Test with invalid directory
 	invalidDir := "/nonexistent/path/that/should/not/exist"
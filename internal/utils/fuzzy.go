@@ -0,0 +1,135 @@
+package utils
+
+import (
+	"sort"
+	"strings"
+	"unicode"
+)
+
+// Match tiers, best first. Prefix matches always rank above substring matches,
+// which rank above fuzzy ones, so completion stays predictable.
+const (
+	TierPrefix = iota
+	TierSubstring
+	TierFuzzy
+)
+
+// Bonuses used to score fuzzy matches
+const (
+	fuzzyMatchScore      = 1
+	fuzzyContiguousBonus = 5
+	fuzzyBoundaryBonus   = 3
+)
+
+// Match is a candidate that matched a pattern
+type Match struct {
+	Text  string
+	Tier  int
+	Score int
+	// Positions holds the rune offsets in Text of the matched characters
+	Positions []int
+}
+
+// FuzzyMatch matches pattern against text ignoring case. It tries a prefix,
+// then a substring, then a subsequence of text. Higher scores are better
+// within a tier: prefixes that also match case win, substrings score higher
+// at word boundaries and nearer the start, and subsequences score for runs of
+// adjacent characters and characters starting a word.
+func FuzzyMatch(pattern, text string) (Match, bool) {
+	p, t := []rune(strings.ToLower(pattern)), []rune(strings.ToLower(text))
+	if len(p) != len([]rune(pattern)) || len(t) != len([]rune(text)) {
+		// Lowercasing changed the length; fall back to exact runes
+		p, t = []rune(pattern), []rune(text)
+	}
+	original := []rune(text)
+
+	if start := runeIndex(t, p); start >= 0 {
+		match := Match{Text: text, Positions: span(start, len(p))}
+		switch {
+		case start == 0:
+			match.Tier = TierPrefix
+			if strings.HasPrefix(text, pattern) {
+				match.Score = 1
+			}
+		default:
+			match.Tier = TierSubstring
+			match.Score = -start
+			if isBoundary(original, start) {
+				match.Score += len(t)
+			}
+		}
+		return match, true
+	}
+
+	match := Match{Text: text, Tier: TierFuzzy}
+	next := 0
+	for i, r := range t {
+		if next == len(p) {
+			break
+		}
+		if r != p[next] {
+			continue
+		}
+		match.Score += fuzzyMatchScore
+		if n := len(match.Positions); n > 0 && match.Positions[n-1] == i-1 {
+			match.Score += fuzzyContiguousBonus
+		}
+		if isBoundary(original, i) {
+			match.Score += fuzzyBoundaryBonus
+		}
+		match.Positions = append(match.Positions, i)
+		next++
+	}
+	if next < len(p) {
+		return Match{}, false
+	}
+	return match, true
+}
+
+// RankMatches returns the candidates matching pattern, best first. Candidates
+// that rank equally keep their original order.
+func RankMatches(pattern string, candidates []string) []Match {
+	var matches []Match
+	for _, candidate := range candidates {
+		if match, ok := FuzzyMatch(pattern, candidate); ok {
+			matches = append(matches, match)
+		}
+	}
+	sort.SliceStable(matches, func(i, j int) bool {
+		if matches[i].Tier != matches[j].Tier {
+			return matches[i].Tier < matches[j].Tier
+		}
+		return matches[i].Score > matches[j].Score
+	})
+	return matches
+}
+
+func runeIndex(text, pattern []rune) int {
+	for i := 0; i+len(pattern) <= len(text); i++ {
+		if string(text[i:i+len(pattern)]) == string(pattern) {
+			return i
+		}
+	}
+	return -1
+}
+
+func span(start, n int) []int {
+	positions := make([]int, n)
+	for i := range positions {
+		positions[i] = start + i
+	}
+	return positions
+}
+
+// isBoundary reports whether the rune at i starts a word: the first rune, one
+// after a separator, or an upper case letter after a lower case one
+func isBoundary(text []rune, i int) bool {
+	if i == 0 {
+		return true
+	}
+	prev, cur := text[i-1], text[i]
+	if !unicode.IsLetter(prev) && !unicode.IsDigit(prev) {
+		return true
+	}
+	return unicode.IsLower(prev) && unicode.IsUpper(cur)
+}
@@ -0,0 +1,85 @@
+package utils
+
+import (
+	"reflect"
+	"testing"
+)
+
+func matchTexts(matches []Match) []string {
+	texts := make([]string, len(matches))
+	for i, match := range matches {
+		texts[i] = match.Text
+	}
+	return texts
+}
+
+func TestRankMatches(t *testing.T) {
+	testCases := []struct {
+		name       string
+		pattern    string
+		candidates []string
+		expected   []string
+	}{
+		{
+			name:       "Case-insensitive prefix",
+			pattern:    "proDB",
+			candidates: []string{"staging", "ProdDB"},
+			expected:   []string{"ProdDB"},
+		},
+		{
+			name:       "Exact case prefix ranks first",
+			pattern:    "Prod",
+			candidates: []string{"production", "Prod_replica"},
+			expected:   []string{"Prod_replica", "production"},
+		},
+		{
+			name:       "Prefix before substring before fuzzy",
+			pattern:    "user",
+			candidates: []string{"u_s_e_r_log", "app_users", "users", "tbl_users"},
+			expected:   []string{"users", "app_users", "tbl_users", "u_s_e_r_log"},
+		},
+		{
+			name:       "Substring at a word boundary ranks higher",
+			pattern:    "log",
+			candidates: []string{"catalog", "audit_log"},
+			expected:   []string{"audit_log", "catalog"},
+		},
+		{
+			name:       "Contiguous fuzzy match ranks higher",
+			pattern:    "ordit",
+			candidates: []string{"o_r_d_i_t", "order_items"},
+			expected:   []string{"order_items", "o_r_d_i_t"},
+		},
+		{
+			name:       "Equal matches keep their order",
+			pattern:    "",
+			candidates: []string{"users", "orders"},
+			expected:   []string{"users", "orders"},
+		},
+		{
+			name:       "No match",
+			pattern:    "xyz",
+			candidates: []string{"users"},
+			expected:   []string{},
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.name, func(t *testing.T) {
+			got := matchTexts(RankMatches(tc.pattern, tc.candidates))
+			if !reflect.DeepEqual(got, tc.expected) {
+				t.Errorf("Expected %v, got %v", tc.expected, got)
+			}
+		})
+	}
+}
+
+func TestFuzzyMatch_Positions(t *testing.T) {
+	match, ok := FuzzyMatch("oit", "order_items")
+	if !ok || match.Tier != TierFuzzy {
+		t.Fatalf("Expected a fuzzy match, got %+v", match)
+	}
+	if !reflect.DeepEqual(match.Positions, []int{0, 6, 7}) {
+		t.Errorf("Expected the matched characters at 0, 6 and 7, got %v", match.Positions)
+	}
+}
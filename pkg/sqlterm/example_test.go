@@ -0,0 +1,50 @@
+package sqlterm_test
+
+import (
+	"fmt"
+	"log"
+
+	"sqlterm/pkg/sqlterm"
+)
+
+func Example() {
+	conn, err := sqlterm.Connect(&sqlterm.ConnectionConfig{
+		DatabaseType: sqlterm.SQLite,
+		Database:     "file:example?mode=memory&cache=shared",
+	})
+	if err != nil {
+		log.Fatal(err)
+	}
+	defer conn.Close()
+
+	for _, statement := range []string{
+		"CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT)",
+		"INSERT INTO users (name) VALUES ('alice'), ('bob')",
+	} {
+		result, err := conn.Execute(statement)
+		if err != nil {
+			log.Fatal(err)
+		}
+		// Statements run as rows are read, so drain the result before closing it
+		for range result.Itor() {
+		}
+		result.Close()
+	}
+
+	result, err := conn.Execute("SELECT id, name FROM users ORDER BY id")
+	if err != nil {
+		log.Fatal(err)
+	}
+	defer result.Close()
+
+	for row := range result.Itor() {
+		fmt.Println(row[0], row[1])
+	}
+	if err := result.Error(); err != nil {
+		log.Fatal(err)
+	}
+
+	// Output:
+	// 1 alice
+	// 2 bob
+}
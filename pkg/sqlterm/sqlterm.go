@@ -0,0 +1,158 @@
+// Package sqlterm is the public API for embedding sqlterm's connection, query
+// and schema layer in other tools.
+//
+// It re-exports the stable parts of the internal core package; anything not
+// exported here may change between releases. Value, DatabaseType and the error
+// values are expected to grow: switch statements over them should always have
+// a default case.
+//
+// A minimal session:
+//
+//	conn, err := sqlterm.Connect(&sqlterm.ConnectionConfig{
+//		DatabaseType: sqlterm.SQLite,
+//		Database:     "app.db",
+//	})
+//	if err != nil {
+//		return err
+//	}
+//	defer conn.Close()
+//
+//	result, err := conn.Execute("SELECT id, name FROM users")
+//	if err != nil {
+//		return err
+//	}
+//	defer result.Close()
+//
+//	for row := range result.Itor() {
+//		fmt.Println(row[0], row[1])
+//	}
+//	return result.Error()
+package sqlterm
+
+import (
+	"sqlterm/internal/core"
+)
+
+// Connections and schema inspection
+type (
+	// Connection runs queries and inspects the schema of one database
+	Connection = core.Connection
+	// ServerAdmin is implemented by connections that can list and terminate server sessions
+	ServerAdmin = core.ServerAdmin
+	// ServerInfo is implemented by connections that can report the server version
+	ServerInfo = core.ServerInfo
+	// ConnectionConfig describes how to reach a database
+	ConnectionConfig = core.ConnectionConfig
+	// DatabaseType identifies a registered backend
+	DatabaseType = core.DatabaseType
+	// Backend describes a database type and how to connect to it
+	Backend = core.Backend
+	// TableInfo describes a table's columns, keys and constraints
+	TableInfo = core.TableInfo
+	// ColumnInfo describes a single table column
+	ColumnInfo = core.ColumnInfo
+	// ConstraintInfo describes a UNIQUE or CHECK constraint
+	ConstraintInfo = core.ConstraintInfo
+	// ForeignKeyInfo describes a foreign key
+	ForeignKeyInfo = core.ForeignKeyInfo
+	// ProcessInfo describes a session on the database server
+	ProcessInfo = core.ProcessInfo
+)
+
+// Query results and values
+type (
+	// QueryResult streams the rows of a query; always Close it
+	QueryResult = core.QueryResult
+	// Column names a result column and its database type, with best-effort nullability and size
+	Column = core.Column
+	// Value is a single cell of a result row
+	Value = core.Value
+
+	StringValue     = core.StringValue
+	IntValue        = core.IntValue
+	FloatValue      = core.FloatValue
+	BoolValue       = core.BoolValue
+	DecimalValue    = core.DecimalValue
+	BytesValue      = core.BytesValue
+	DateValue       = core.DateValue
+	TimeValue       = core.TimeValue
+	DateTimeValue   = core.DateTimeValue
+	DateTimeTzValue = core.DateTimeTzValue
+	NullValue       = core.NullValue
+
+	// FormatOptions controls how FormatValue renders values
+	FormatOptions = core.FormatOptions
+)
+
+// Built-in database types
+const (
+	MySQL      = core.MySQL
+	PostgreSQL = core.PostgreSQL
+	SQLite     = core.SQLite
+)
+
+// Errors returned by connections; compare with errors.Is
+var (
+	ErrBackendNotCompiled      = core.ErrBackendNotCompiled
+	ErrBackendExists           = core.ErrBackendExists
+	ErrServerAdminNotSupported = core.ErrServerAdminNotSupported
+	ErrInsufficientPrivileges  = core.ErrInsufficientPrivileges
+	ErrProcessNotFound         = core.ErrProcessNotFound
+)
+
+// Connect opens a connection through the backend registered for config.DatabaseType
+func Connect(config *ConnectionConfig) (Connection, error) {
+	return core.NewConnection(config)
+}
+
+// ParseDatabaseType resolves a backend name or alias such as "postgresql"
+func ParseDatabaseType(name string) (DatabaseType, error) {
+	return core.ParseDatabaseType(name)
+}
+
+// DefaultPort returns the usual port for a backend, or 0 if it has none
+func DefaultPort(dbType DatabaseType) int {
+	return core.GetDefaultPort(dbType)
+}
+
+// RegisterBackend adds a third-party backend; use a DatabaseType of 100 or above
+func RegisterBackend(dbType DatabaseType, backend Backend) error {
+	return core.RegisterBackend(dbType, backend)
+}
+
+// EnabledBackends lists the database types that can be connected to in this build
+func EnabledBackends() []DatabaseType {
+	return core.EnabledBackends()
+}
+
+// NewStaticQueryResult builds a result over rows held in memory, for backends that do not use database/sql
+func NewStaticQueryResult(columns []Column, rows [][]Value) *QueryResult {
+	return core.NewStaticQueryResult(columns, rows)
+}
+
+// DescribeResultColumns fills in the nullability and sizes the driver left
+// out of columns, from the table a single-table SELECT reads. dbType is the
+// database query was written for.
+func DescribeResultColumns(columns []Column, dbType DatabaseType, query string, conn Connection) {
+	core.DescribeResultColumns(columns, dbType, query, conn.DescribeTable)
+}
+
+// DefaultFormatOptions returns the options matching each value's own String form
+func DefaultFormatOptions() FormatOptions {
+	return core.DefaultFormatOptions()
+}
+
+// FormatValue renders a value for display
+func FormatValue(v Value, opts FormatOptions) string {
+	return core.FormatValue(v, opts)
+}
+
+// FormatISO renders a value in a machine-readable form, ISO 8601 for dates and times
+func FormatISO(v Value) string {
+	return core.FormatISO(v)
+}
+
+// CompareValues orders two values of the same column for sorting
+func CompareValues(a, b Value) int {
+	return core.CompareValues(a, b)
+}